@@ -0,0 +1,270 @@
+package vectors
+
+import (
+	"bytes"
+	"encoding/hex"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"unicode/utf8"
+
+	"github.com/stretchr/testify/require"
+
+	codectypes "github.com/cosmos/cosmos-sdk/codec/types"
+	sdk "github.com/cosmos/cosmos-sdk/types"
+
+	abci "github.com/cometbft/cometbft/abci/types"
+
+	transfertypes "github.com/cosmos/ibc-go/v8/modules/apps/transfer/types"
+	clienttypes "github.com/cosmos/ibc-go/v8/modules/core/02-client/types"
+	connectiontypes "github.com/cosmos/ibc-go/v8/modules/core/03-connection/types"
+	channeltypes "github.com/cosmos/ibc-go/v8/modules/core/04-channel/types"
+	commitmenttypes "github.com/cosmos/ibc-go/v8/modules/core/23-commitment/types"
+	ibcexported "github.com/cosmos/ibc-go/v8/modules/core/exported"
+	ibctm "github.com/cosmos/ibc-go/v8/modules/light-clients/07-tendermint"
+	ibctesting "github.com/cosmos/ibc-go/v8/testing"
+)
+
+// The directory the corpus is recorded into, which the `ibc_go` tests of
+// `ibc-testkit` run ibc-rs against.
+const corpusDirVar = "IBC_GO_CORPUS_DIR"
+
+// The prefix of the keys of the localhost client, which ibc-go updates in
+// every block rather than in the handlers of the messages.
+const localhostClientPrefix = "clients/" + ibcexported.LocalhostClientID + "/"
+
+type corpusHost struct {
+	ChainID        string `json:"chain_id"`
+	RevisionNumber uint64 `json:"revision_number"`
+	Height         uint64 `json:"height"`
+	Timestamp      uint64 `json:"timestamp"`
+}
+
+type corpusEvent struct {
+	Type       string      `json:"type"`
+	Attributes [][2]string `json:"attributes"`
+}
+
+type corpusAccepted struct {
+	Events []corpusEvent      `json:"events"`
+	Writes map[string]*string `json:"writes"`
+}
+
+type corpusRejected struct {
+	Reason string `json:"reason"`
+}
+
+type corpusOutcome struct {
+	Accepted *corpusAccepted `json:"accepted,omitempty"`
+	Rejected *corpusRejected `json:"rejected,omitempty"`
+}
+
+type corpusCase struct {
+	Name     string          `json:"name"`
+	Host     corpusHost      `json:"host"`
+	Msgs     []string        `json:"msgs"`
+	Outcomes []corpusOutcome `json:"outcomes"`
+}
+
+// The messages of each case, dispatched on chain A from a state without any
+// IBC client, connection or channel. Chain B is only the chain its client
+// tracks.
+var corpusCases = []struct {
+	name string
+	msgs func(chainA, chainB *ibctesting.TestChain) []sdk.Msg
+}{
+	{"create_client", func(chainA, chainB *ibctesting.TestChain) []sdk.Msg {
+		return []sdk.Msg{createClient(chainA, chainB)}
+	}},
+	{"connection_open_init", func(chainA, chainB *ibctesting.TestChain) []sdk.Msg {
+		return []sdk.Msg{createClient(chainA, chainB), connectionOpenInit(chainA, "07-tendermint-0")}
+	}},
+	{"channel_open_init", func(chainA, chainB *ibctesting.TestChain) []sdk.Msg {
+		return []sdk.Msg{
+			createClient(chainA, chainB),
+			connectionOpenInit(chainA, "07-tendermint-0"),
+			channelOpenInit(chainA, "connection-0", transfertypes.Version),
+		}
+	}},
+	{"rejections", func(chainA, chainB *ibctesting.TestChain) []sdk.Msg {
+		return []sdk.Msg{
+			connectionOpenInit(chainA, "07-tendermint-9"),
+			createClient(chainA, chainB),
+			channelOpenInit(chainA, "connection-9", transfertypes.Version),
+			connectionOpenInit(chainA, "07-tendermint-0"),
+			channelOpenInit(chainA, "connection-0", "ics20-2"),
+		}
+	}},
+}
+
+func signer(chain *ibctesting.TestChain) string {
+	return chain.SenderAccount.GetAddress().String()
+}
+
+// createClient creates a client of chainB on chainA, the way the endpoints of
+// ibc-go's test paths do.
+func createClient(chainA, chainB *ibctesting.TestChain) sdk.Msg {
+	tmConfig := ibctesting.NewTendermintConfig()
+	height := chainB.LastHeader.GetHeight().(clienttypes.Height)
+	clientState := ibctm.NewClientState(
+		chainB.ChainID, tmConfig.TrustLevel, tmConfig.TrustingPeriod, tmConfig.UnbondingPeriod,
+		tmConfig.MaxClockDrift, height, commitmenttypes.GetSDKSpecs(), ibctesting.UpgradePath,
+	)
+	msg, err := clienttypes.NewMsgCreateClient(clientState, chainB.LastHeader.ConsensusState(), signer(chainA))
+	require.NoError(chainA.TB, err)
+	return msg
+}
+
+func connectionOpenInit(chain *ibctesting.TestChain, clientID string) sdk.Msg {
+	return connectiontypes.NewMsgConnectionOpenInit(
+		clientID, "07-tendermint-0", commitmenttypes.NewMerklePrefix([]byte("ibc")),
+		ibctesting.ConnectionVersion, 0, signer(chain),
+	)
+}
+
+func channelOpenInit(chain *ibctesting.TestChain, connectionID, version string) sdk.Msg {
+	return channeltypes.NewMsgChannelOpenInit(
+		transfertypes.PortID, version, channeltypes.UNORDERED, []string{connectionID},
+		transfertypes.PortID, signer(chain),
+	)
+}
+
+// ibcStore returns the entries of the IBC store of chain, by key.
+func ibcStore(chain *ibctesting.TestChain) map[string][]byte {
+	store := chain.GetContext().KVStore(chain.GetSimApp().GetKey(ibcexported.StoreKey))
+	iterator := store.Iterator(nil, nil)
+	defer iterator.Close()
+
+	entries := map[string][]byte{}
+	for ; iterator.Valid(); iterator.Next() {
+		entries[string(iterator.Key())] = bytes.Clone(iterator.Value())
+	}
+	return entries
+}
+
+// storeWrites returns the hex of the value written under each key going from
+// before to after, or nil for the deleted keys. The keys that are not paths,
+// and those of the localhost client, are left out.
+func storeWrites(before, after map[string][]byte) map[string]*string {
+	isPath := func(key string) bool {
+		return utf8.ValidString(key) && !strings.HasPrefix(key, localhostClientPrefix)
+	}
+
+	writes := map[string]*string{}
+	for key, value := range after {
+		if previous, found := before[key]; isPath(key) && (!found || !bytes.Equal(previous, value)) {
+			encoded := hex.EncodeToString(value)
+			writes[key] = &encoded
+		}
+	}
+	for key := range before {
+		if _, found := after[key]; isPath(key) && !found {
+			writes[key] = nil
+		}
+	}
+	return writes
+}
+
+// ibcEvents returns the events of the IBC handlers among events, without the
+// events of the SDK, such as those of fees and of the messages of the
+// transaction, and without the index of the message that emitted them.
+func ibcEvents(events []abci.Event) []corpusEvent {
+	ibcEventTypes := map[string]bool{
+		clienttypes.EventTypeCreateClient:           true,
+		connectiontypes.EventTypeConnectionOpenInit: true,
+		channeltypes.EventTypeChannelOpenInit:       true,
+	}
+	ibcModules := map[string]bool{
+		clienttypes.AttributeValueCategory:     true,
+		connectiontypes.AttributeValueCategory: true,
+		channeltypes.AttributeValueCategory:    true,
+	}
+
+	recorded := []corpusEvent{}
+	for _, event := range events {
+		attributes := [][2]string{}
+		for _, attribute := range event.Attributes {
+			if attribute.Key != "msg_index" {
+				attributes = append(attributes, [2]string{attribute.Key, attribute.Value})
+			}
+		}
+
+		isModuleEvent := event.Type == sdk.EventTypeMessage &&
+			len(attributes) == 1 &&
+			attributes[0][0] == sdk.AttributeKeyModule &&
+			ibcModules[attributes[0][1]]
+		if ibcEventTypes[event.Type] || isModuleEvent {
+			recorded = append(recorded, corpusEvent{Type: event.Type, Attributes: attributes})
+		}
+	}
+	return recorded
+}
+
+// recordCase dispatches msgs on chainA, each in a transaction of its own, and
+// returns what ibc-go did with them.
+func recordCase(t *testing.T, name string, chainA *ibctesting.TestChain, msgs []sdk.Msg) corpusCase {
+	ctx := chainA.GetContext()
+	height := clienttypes.GetSelfHeight(ctx)
+	recorded := corpusCase{
+		Name: name,
+		Host: corpusHost{
+			ChainID:        chainA.ChainID,
+			RevisionNumber: height.RevisionNumber,
+			Height:         height.RevisionHeight,
+			Timestamp:      uint64(ctx.BlockTime().UnixNano()),
+		},
+	}
+
+	for _, msg := range msgs {
+		anyMsg, err := codectypes.NewAnyWithValue(msg)
+		require.NoError(t, err)
+		bz, err := anyMsg.Marshal()
+		require.NoError(t, err)
+		recorded.Msgs = append(recorded.Msgs, hex.EncodeToString(bz))
+
+		before := ibcStore(chainA)
+		res, err := chainA.SendMsgs(msg)
+		if err != nil {
+			recorded.Outcomes = append(recorded.Outcomes, corpusOutcome{
+				Rejected: &corpusRejected{Reason: err.Error()},
+			})
+		} else {
+			recorded.Outcomes = append(recorded.Outcomes, corpusOutcome{
+				Accepted: &corpusAccepted{
+					Events: ibcEvents(res.Events),
+					Writes: storeWrites(before, ibcStore(chainA)),
+				},
+			})
+		}
+
+		// The sequence of the sender is bumped by rejected transactions too,
+		// which the test chain only tracks for accepted ones.
+		chainA.SenderAccount = chainA.GetSimApp().AccountKeeper.GetAccount(
+			chainA.GetContext(), chainA.SenderAccount.GetAddress(),
+		)
+	}
+	return recorded
+}
+
+// TestRecordCorpus records the regression cases of ibc-go into the directory
+// named by `IBC_GO_CORPUS_DIR`, one JSON file per case.
+func TestRecordCorpus(t *testing.T) {
+	dir := os.Getenv(corpusDirVar)
+	if dir == "" {
+		t.Skipf("`%s` is not set", corpusDirVar)
+	}
+	require.NoError(t, os.MkdirAll(dir, 0o755))
+
+	for _, tc := range corpusCases {
+		coordinator := ibctesting.NewCoordinator(t, 2)
+		chainA := coordinator.GetChain(ibctesting.GetChainID(1))
+		chainB := coordinator.GetChain(ibctesting.GetChainID(2))
+
+		recorded := recordCase(t, tc.name, chainA, tc.msgs(chainA, chainB))
+		bz, err := json.MarshalIndent(recorded, "", "  ")
+		require.NoError(t, err)
+		require.NoError(t, os.WriteFile(filepath.Join(dir, tc.name+".json"), bz, 0o644))
+	}
+}
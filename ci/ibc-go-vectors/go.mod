@@ -0,0 +1,13 @@
+module github.com/cosmos/ibc-rs/ci/ibc-go-vectors
+
+go 1.21
+
+require (
+	github.com/cosmos/cosmos-sdk v0.50.1
+	github.com/cosmos/ibc-go/v8 v8.0.0
+	github.com/stretchr/testify v1.8.4
+)
+
+// The same replacement as ibc-go v8, which replacements of dependencies do
+// not carry over to.
+replace github.com/syndtr/goleveldb => github.com/syndtr/goleveldb v1.0.1-0.20210819022825-2ae1ddf74ef7
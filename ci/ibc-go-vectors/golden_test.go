@@ -0,0 +1,181 @@
+package vectors
+
+import (
+	"bufio"
+	"encoding/hex"
+	"fmt"
+	"os"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+
+	"github.com/cosmos/cosmos-sdk/codec"
+	codectypes "github.com/cosmos/cosmos-sdk/codec/types"
+	sdk "github.com/cosmos/cosmos-sdk/types"
+
+	transfertypes "github.com/cosmos/ibc-go/v8/modules/apps/transfer/types"
+	clienttypes "github.com/cosmos/ibc-go/v8/modules/core/02-client/types"
+	connectiontypes "github.com/cosmos/ibc-go/v8/modules/core/03-connection/types"
+	channeltypes "github.com/cosmos/ibc-go/v8/modules/core/04-channel/types"
+	commitmenttypes "github.com/cosmos/ibc-go/v8/modules/core/23-commitment/types"
+	ibctesting "github.com/cosmos/ibc-go/v8/testing"
+)
+
+// The golden vectors of `crates/ibc-testkit/tests/golden/mod.rs`.
+const goldenPath = "../../crates/ibc-testkit/tests/data/golden/vectors.txt"
+
+const (
+	packetData       = `{"amount":"100","denom":"uatom","receiver":"cosmos1receiver","sender":"cosmos1sender"}`
+	ack              = `{"result":"AQ=="}`
+	timeoutTimestamp = 1_700_000_000_000_000_000
+)
+
+type marshaler interface {
+	Marshal() ([]byte, error)
+}
+
+func goldenPacket(timeoutHeight clienttypes.Height) channeltypes.Packet {
+	return channeltypes.NewPacket(
+		[]byte(packetData), 1, "transfer", "channel-0", "transfer", "channel-0",
+		timeoutHeight, timeoutTimestamp,
+	)
+}
+
+func protoHex(t *testing.T, msg marshaler) string {
+	bz, err := msg.Marshal()
+	require.NoError(t, err)
+	return hex.EncodeToString(bz)
+}
+
+// readGolden returns the vectors of the fixture, by key.
+func readGolden(t *testing.T) map[string]string {
+	file, err := os.Open(goldenPath)
+	require.NoError(t, err)
+	defer file.Close()
+
+	vectors := map[string]string{}
+	scanner := bufio.NewScanner(file)
+	scanner.Buffer(nil, 1<<20)
+	for scanner.Scan() {
+		line := scanner.Text()
+		if line == "" || strings.HasPrefix(line, "#") {
+			continue
+		}
+		key, value, found := strings.Cut(line, " = ")
+		require.True(t, found, "malformed golden vector: %s", line)
+		vectors[key] = value
+	}
+	require.NoError(t, scanner.Err())
+	return vectors
+}
+
+// collectAttributes adds the attributes of the events of type eventType to
+// vectors, under their `event.{type}.{key}` vector.
+func collectAttributes(vectors map[string]string, events sdk.Events, eventType string) {
+	for _, event := range events {
+		if event.Type != eventType {
+			continue
+		}
+		for _, attribute := range event.Attributes {
+			vectors[fmt.Sprintf("event.%s.%s", eventType, attribute.Key)] = attribute.Value
+		}
+	}
+}
+
+// eventVectors returns the attributes of the events that ibc-go emits for
+// sending the golden packet on the first transfer channel of a test chain,
+// and for writing its acknowledgement on the counterparty.
+func eventVectors(t *testing.T, packet channeltypes.Packet) map[string]string {
+	coordinator := ibctesting.NewCoordinator(t, 2)
+	chainA := coordinator.GetChain(ibctesting.GetChainID(1))
+	chainB := coordinator.GetChain(ibctesting.GetChainID(2))
+
+	path := ibctesting.NewPath(chainA, chainB)
+	path.EndpointA.ChannelConfig.PortID = ibctesting.TransferPort
+	path.EndpointB.ChannelConfig.PortID = ibctesting.TransferPort
+	path.EndpointA.ChannelConfig.Version = transfertypes.Version
+	path.EndpointB.ChannelConfig.Version = transfertypes.Version
+	coordinator.Setup(path)
+
+	require.Equal(t, packet.SourceChannel, path.EndpointA.ChannelID)
+	require.Equal(t, packet.DestinationChannel, path.EndpointB.ChannelID)
+
+	vectors := map[string]string{}
+
+	ctxA := chainA.GetContext()
+	sequence, err := chainA.App.GetIBCKeeper().ChannelKeeper.SendPacket(
+		ctxA,
+		chainA.GetChannelCapability(packet.SourcePort, packet.SourceChannel),
+		packet.SourcePort,
+		packet.SourceChannel,
+		packet.TimeoutHeight,
+		packet.TimeoutTimestamp,
+		packet.Data,
+	)
+	require.NoError(t, err)
+	require.Equal(t, packet.Sequence, sequence)
+	collectAttributes(vectors, ctxA.EventManager().Events(), channeltypes.EventTypeSendPacket)
+
+	ctxB := chainB.GetContext()
+	err = chainB.App.GetIBCKeeper().ChannelKeeper.WriteAcknowledgement(
+		ctxB,
+		chainB.GetChannelCapability(packet.DestinationPort, packet.DestinationChannel),
+		packet,
+		channeltypes.NewResultAcknowledgement([]byte{1}),
+	)
+	require.NoError(t, err)
+	collectAttributes(vectors, ctxB.EventManager().Events(), channeltypes.EventTypeWriteAck)
+
+	return vectors
+}
+
+func TestGoldenVectors(t *testing.T) {
+	cdc := codec.NewProtoCodec(codectypes.NewInterfaceRegistry())
+
+	packetWithTimeout := goldenPacket(clienttypes.NewHeight(1, 1000))
+	packetWithoutTimeout := goldenPacket(clienttypes.ZeroHeight())
+
+	channelEnd := channeltypes.NewChannel(
+		channeltypes.OPEN,
+		channeltypes.UNORDERED,
+		channeltypes.NewCounterparty("transfer", "channel-1"),
+		[]string{"connection-0"},
+		transfertypes.Version,
+	)
+
+	connectionEnd := connectiontypes.NewConnectionEnd(
+		connectiontypes.OPEN,
+		"07-tendermint-0",
+		connectiontypes.NewCounterparty(
+			"07-tendermint-1", "connection-1", commitmenttypes.NewMerklePrefix([]byte("ibc")),
+		),
+		connectiontypes.GetCompatibleVersions(),
+		0,
+	)
+
+	msgRecvPacket := channeltypes.NewMsgRecvPacket(
+		packetWithTimeout, []byte("proof"), clienttypes.NewHeight(0, 5), "cosmos1relayer",
+	)
+
+	actual := map[string]string{
+		"proto.packet":                        protoHex(t, &packetWithTimeout),
+		"proto.packet_no_timeout_height":      protoHex(t, &packetWithoutTimeout),
+		"proto.channel_end":                   protoHex(t, &channelEnd),
+		"proto.connection_end":                protoHex(t, &connectionEnd),
+		"proto.msg_recv_packet":               protoHex(t, msgRecvPacket),
+		"commitment.packet":                   hex.EncodeToString(channeltypes.CommitPacket(cdc, &packetWithTimeout)),
+		"commitment.packet_no_timeout_height": hex.EncodeToString(channeltypes.CommitPacket(cdc, &packetWithoutTimeout)),
+		"commitment.ack":                      hex.EncodeToString(channeltypes.CommitAcknowledgement([]byte(ack))),
+	}
+	for key, value := range eventVectors(t, packetWithTimeout) {
+		actual[key] = value
+	}
+
+	expected := readGolden(t)
+	require.NotEmpty(t, expected)
+	for key, value := range expected {
+		require.Contains(t, actual, key, "ibc-go has no golden vector `%s`", key)
+		require.Equal(t, value, actual[key], "golden vector `%s` diverged", key)
+	}
+}
@@ -0,0 +1,25 @@
+//! Defines the fee payment error type
+
+use displaydoc::Display;
+use ibc_core_handler_types::error::ContextError;
+use ibc_primitives::impl_error_sources;
+
+#[derive(Display, Debug)]
+pub enum FeeError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// relayer address must not be empty
+    EmptyRelayer,
+}
+
+impl_error_sources!(FeeError {
+    ibc {
+        Self::ContextError(e) => e,
+    }
+});
+
+impl From<ContextError> for FeeError {
+    fn from(err: ContextError) -> FeeError {
+        Self::ContextError(err)
+    }
+}
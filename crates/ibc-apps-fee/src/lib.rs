@@ -0,0 +1,26 @@
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+// https://github.com/cosmos/ibc-rs/issues/342
+#![allow(clippy::result_large_err)]
+//! Queries of the relayer incentivization of [fee payment](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md) (ICS-29),
+//! through which relayers discover the packets they are paid to relay on
+//! fee-enabled hosts.
+//!
+//! This crate does not implement the fee middleware itself: the hosts running
+//! one expose its state through a [`FeeQueryContext`](query::FeeQueryContext).
+
+pub mod error;
+pub mod query;
+
+/// ICS29 application current version.
+pub const VERSION: &str = "ics29-1";
@@ -0,0 +1,129 @@
+//! Computation of the results of the `IncentivizedPackets` and
+//! `CounterpartyPayee` gRPC queries of the fee module of ibc-go, against the
+//! state of the fee middleware of the host.
+
+use ibc_core_channel_types::packet::Sequence;
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+
+use super::error::FeeError;
+
+/// The number of results of a page whose request sets no limit, as in the
+/// Cosmos SDK.
+pub const DEFAULT_PAGE_LIMIT: usize = 100;
+
+/// Identifies a packet by the port and channel it was sent on, and its
+/// sequence. Packets are ordered by port, channel and sequence, in this order.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PacketId {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+}
+
+/// An incentivized packet, along with the fees escrowed for relaying it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifiedPacketFees<F> {
+    pub packet_id: PacketId,
+    pub packet_fees: Vec<F>,
+}
+
+/// The page of results a query should return, as in the pagination of the
+/// Cosmos SDK queries.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PageRequest<K> {
+    /// The key of the first result of the page, as returned along with the
+    /// previous page, or `None` for the first page.
+    pub key: Option<K>,
+    /// The maximum number of results of the page, or `0` for the
+    /// [`DEFAULT_PAGE_LIMIT`].
+    pub limit: usize,
+}
+
+impl<K> Default for PageRequest<K> {
+    fn default() -> Self {
+        Self {
+            key: None,
+            limit: 0,
+        }
+    }
+}
+
+/// A page of query results.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PageResponse<T, K> {
+    pub results: Vec<T>,
+    /// The key of the first result of the next page, to be passed in the
+    /// [`PageRequest`] for it, or `None` if this page is the last one.
+    pub next_key: Option<K>,
+}
+
+/// Methods required to query the state of the fee middleware of the host.
+pub trait FeeQueryContext {
+    /// A fee escrowed for relaying a packet, e.g. the receive, acknowledgement
+    /// and timeout fees of the host, along with their payer.
+    type PacketFee;
+
+    /// Returns at most `limit` incentivized packets, in increasing order of
+    /// their identifiers, starting from `start` if given.
+    fn incentivized_packets_from(
+        &self,
+        start: Option<&PacketId>,
+        limit: usize,
+    ) -> Result<Vec<IdentifiedPacketFees<Self::PacketFee>>, FeeError>;
+
+    /// Returns the address on the counterparty chain to which the fees earned
+    /// by `relayer` for relaying packets to `channel_id` are paid, if
+    /// `relayer` registered one.
+    fn get_counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, FeeError>;
+}
+
+/// Returns the requested page of the incentivized packets of the host, in
+/// increasing order of their identifiers, as the `IncentivizedPackets` query.
+pub fn incentivized_packets<Ctx>(
+    ctx: &Ctx,
+    page: &PageRequest<PacketId>,
+) -> Result<PageResponse<IdentifiedPacketFees<Ctx::PacketFee>, PacketId>, FeeError>
+where
+    Ctx: FeeQueryContext + ?Sized,
+{
+    let limit = match page.limit {
+        0 => DEFAULT_PAGE_LIMIT,
+        limit => limit,
+    };
+
+    // one more packet than the page holds tells whether there is a next page
+    let mut results = ctx.incentivized_packets_from(page.key.as_ref(), limit.saturating_add(1))?;
+
+    let next_key = if results.len() > limit {
+        results.truncate(limit + 1);
+        results.pop().map(|packet| packet.packet_id)
+    } else {
+        None
+    };
+
+    Ok(PageResponse { results, next_key })
+}
+
+/// Returns the address on the counterparty chain to which the fees earned by
+/// `relayer` for relaying packets to `channel_id` are paid, if registered, as
+/// the `CounterpartyPayee` query.
+pub fn counterparty_payee<Ctx>(
+    ctx: &Ctx,
+    channel_id: &ChannelId,
+    relayer: &Signer,
+) -> Result<Option<Signer>, FeeError>
+where
+    Ctx: FeeQueryContext + ?Sized,
+{
+    if relayer.as_ref().is_empty() {
+        return Err(FeeError::EmptyRelayer);
+    }
+
+    ctx.get_counterparty_payee(channel_id, relayer)
+}
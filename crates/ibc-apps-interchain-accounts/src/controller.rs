@@ -0,0 +1,145 @@
+//! Implements the sending of transactions by the owners of interchain
+//! accounts, through which hosts embed controller logic that decides, with
+//! [`InterchainAccountControllerContext::authorize_send_tx`], which
+//! transactions each owner may submit.
+
+use ibc_core_channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core_channel::handler::send_packet::{send_packet_execute, send_packet_validate};
+use ibc_core_channel_types::packet::{Packet, Sequence};
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_handler_types::path::{ChannelEndPath, SeqSendPath};
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+
+use super::error::InterchainAccountError;
+use super::msgs::send_tx::MsgSendTx;
+
+/// Methods required by the controller of interchain accounts, to be
+/// implemented by the hosts embedding it.
+pub trait InterchainAccountControllerContext {
+    /// Returns the channel of `port_id` currently open on `connection_id`, if
+    /// any, i.e. the one through which the interchain account is reached.
+    fn active_channel_id(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Result<Option<ChannelId>, InterchainAccountError>;
+
+    /// Checks that the owner of `msg` may submit its transaction, e.g. that a
+    /// liquid staking protocol only delegates on behalf of its own accounts.
+    /// Returns [`InterchainAccountError::UnauthorizedSendTx`] otherwise.
+    fn authorize_send_tx(&self, msg: &MsgSendTx) -> Result<(), InterchainAccountError>;
+}
+
+/// Sends the transaction of `msg` to its interchain account. Equivalent to
+/// calling [`send_tx_validate`], followed by [`send_tx_execute`].
+pub fn send_tx<SendPacketCtx, IcaCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<Sequence, InterchainAccountError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    IcaCtx: InterchainAccountControllerContext,
+{
+    send_tx_validate(send_packet_ctx_a, ica_ctx_a, msg.clone())?;
+    send_tx_execute(send_packet_ctx_a, ica_ctx_a, msg)
+}
+
+/// Validates the sending of the transaction of `msg`. If this succeeds, then
+/// it is legal to send it with [`send_tx_execute`].
+pub fn send_tx_validate<SendPacketCtx, IcaCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<(), InterchainAccountError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    IcaCtx: InterchainAccountControllerContext,
+{
+    if msg.packet_data.data.is_empty() {
+        return Err(InterchainAccountError::InvalidPacketData {
+            reason: "packet carries no transaction".to_string(),
+        });
+    }
+
+    ica_ctx_a.authorize_send_tx(&msg)?;
+
+    let packet = build_packet(send_packet_ctx_a, ica_ctx_a, msg)?;
+    send_packet_validate(send_packet_ctx_a, &packet)?;
+
+    Ok(())
+}
+
+/// Sends the transaction of `msg`, returning the sequence of its packet. A
+/// prior call to [`send_tx_validate`] MUST have succeeded.
+pub fn send_tx_execute<SendPacketCtx, IcaCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<Sequence, InterchainAccountError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    IcaCtx: InterchainAccountControllerContext,
+{
+    let packet = build_packet(send_packet_ctx_a, ica_ctx_a, msg)?;
+    let sequence = packet.seq_on_a;
+    send_packet_execute(send_packet_ctx_a, packet)?;
+
+    Ok(sequence)
+}
+
+/// Builds the packet carrying the transaction of `msg` over the active channel
+/// of the controller port of its owner, timing out `msg.relative_timeout` after
+/// the current host timestamp.
+fn build_packet<SendPacketCtx, IcaCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<Packet, InterchainAccountError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    IcaCtx: InterchainAccountControllerContext,
+{
+    if msg.relative_timeout.is_zero() {
+        return Err(InterchainAccountError::ZeroRelativeTimeout);
+    }
+
+    let port_id_on_a =
+        PortId::ica_controller(msg.owner.as_ref()).map_err(InterchainAccountError::InvalidOwner)?;
+    let chan_id_on_a = ica_ctx_a
+        .active_channel_id(&msg.connection_id, &port_id_on_a)?
+        .ok_or_else(|| InterchainAccountError::ActiveChannelNotFound {
+            connection_id: msg.connection_id.clone(),
+            port_id: port_id_on_a.clone(),
+        })?;
+
+    let chan_end_on_a =
+        send_packet_ctx_a.channel_end(&ChannelEndPath::new(&port_id_on_a, &chan_id_on_a))?;
+    let port_id_on_b = chan_end_on_a.counterparty().port_id().clone();
+    let chan_id_on_b = chan_end_on_a
+        .counterparty()
+        .channel_id()
+        .ok_or_else(|| InterchainAccountError::DestinationChannelNotFound {
+            port_id: port_id_on_a.clone(),
+            channel_id: chan_id_on_a.clone(),
+        })?
+        .clone();
+
+    let seq_on_a = send_packet_ctx_a
+        .get_next_sequence_send(&SeqSendPath::new(&port_id_on_a, &chan_id_on_a))?;
+
+    let timeout_timestamp_on_b = (send_packet_ctx_a.host_timestamp()? + msg.relative_timeout)
+        .map_err(InterchainAccountError::TimestampOverflow)?;
+
+    Ok(Packet {
+        seq_on_a,
+        port_id_on_a,
+        chan_id_on_a,
+        port_id_on_b,
+        chan_id_on_b,
+        data: msg.packet_data.to_json(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b,
+    })
+}
@@ -0,0 +1,49 @@
+//! Defines the interchain accounts error type
+
+use displaydoc::Display;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, IdentifierError, PortId};
+use ibc_primitives::impl_error_sources;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use ibc_primitives::timestamp::TimestampOverflowError;
+
+#[derive(Display, Debug)]
+pub enum InterchainAccountError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid owner: `{0}`
+    InvalidOwner(IdentifierError),
+    /// no active channel for port_id `{port_id}` on connection_id `{connection_id}`
+    ActiveChannelNotFound {
+        connection_id: ConnectionId,
+        port_id: PortId,
+    },
+    /// destination channel not found in the counterparty of port_id `{port_id}` and channel_id `{channel_id}`
+    DestinationChannelNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// invalid packet data: `{reason}`
+    InvalidPacketData { reason: String },
+    /// relative timeout must not be zero
+    ZeroRelativeTimeout,
+    /// timeout timestamp overflow: `{0}`
+    TimestampOverflow(TimestampOverflowError),
+    /// owner `{owner}` is not authorized to send the transaction: `{reason}`
+    UnauthorizedSendTx { owner: Signer, reason: String },
+}
+
+impl_error_sources!(InterchainAccountError {
+    ibc {
+        Self::ContextError(e) => e,
+        Self::InvalidOwner(e) => e,
+        Self::TimestampOverflow(e) => e,
+    }
+});
+
+impl From<ContextError> for InterchainAccountError {
+    fn from(err: ContextError) -> InterchainAccountError {
+        Self::ContextError(err)
+    }
+}
@@ -0,0 +1,27 @@
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+// https://github.com/cosmos/ibc-rs/issues/342
+#![allow(clippy::result_large_err)]
+//! Implementation of the controller side of [interchain accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md) (ICS-27),
+//! through which hosts embedding controller logic, e.g. liquid staking
+//! protocols, submit transactions to be executed by the interchain accounts
+//! they own on counterparty chains.
+
+pub mod controller;
+pub mod error;
+pub mod metadata;
+pub mod msgs;
+pub mod packet;
+
+/// ICS27 application current version.
+pub const VERSION: &str = "ics27-1";
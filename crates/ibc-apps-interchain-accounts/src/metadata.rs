@@ -0,0 +1,101 @@
+//! Defines the [`Metadata`] encoded in the version of the channels of
+//! interchain accounts, e.g.
+//! `{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-0","address":"","encoding":"proto3","tx_type":"sdk_multi_msg"}`.
+
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::version_metadata::VersionMetadata;
+use ibc_core_host_types::identifier::ConnectionId;
+use ibc_primitives::prelude::*;
+
+use super::VERSION;
+
+/// The encoding of the transactions sent to interchain accounts.
+pub const ENCODING_PROTO3: &str = "proto3";
+
+/// The type of the transactions sent to interchain accounts.
+pub const TX_TYPE_SDK_MULTI_MSG: &str = "sdk_multi_msg";
+
+/// The metadata of the channels of interchain accounts.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Metadata {
+    pub version: String,
+    pub controller_connection_id: ConnectionId,
+    pub host_connection_id: ConnectionId,
+    /// the address of the interchain account, set by the host
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+impl Metadata {
+    /// Builds the metadata a controller proposes for the channel to its
+    /// interchain account, whose address is yet to be set by the host.
+    pub fn new(controller_connection_id: ConnectionId, host_connection_id: ConnectionId) -> Self {
+        Self {
+            version: VERSION.to_string(),
+            controller_connection_id,
+            host_connection_id,
+            address: String::new(),
+            encoding: ENCODING_PROTO3.to_string(),
+            tx_type: TX_TYPE_SDK_MULTI_MSG.to_string(),
+        }
+    }
+}
+
+impl VersionMetadata for Metadata {
+    /// Accepts the `proposed` metadata as long as it has the version, encoding
+    /// and transaction type of this one, whatever its address. Its
+    /// connections must be the ones of the channel, which hosts build this
+    /// metadata with, lest the account be opened over other connections.
+    fn merge(&self, proposed: &Self) -> Result<Self, ChannelError> {
+        if self.version != proposed.version
+            || self.controller_connection_id != proposed.controller_connection_id
+            || self.host_connection_id != proposed.host_connection_id
+            || self.encoding != proposed.encoding
+            || self.tx_type != proposed.tx_type
+        {
+            return Err(ChannelError::VersionNotSupported {
+                expected: self.to_version(),
+                actual: proposed.to_version(),
+            });
+        }
+        Ok(proposed.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_channel_types::version::Version;
+    use ibc_core_channel_types::version_metadata::negotiate_on_try;
+
+    use super::*;
+
+    #[test]
+    fn test_metadata_version() {
+        let metadata = Metadata::new(ConnectionId::new(0), ConnectionId::new(1));
+        assert_eq!(
+            metadata.to_version().as_str(),
+            r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-1","address":"","encoding":"proto3","tx_type":"sdk_multi_msg"}"#
+        );
+
+        let mut proposed = metadata.clone();
+        proposed.address = "cosmos1ica".to_string();
+        assert_eq!(
+            negotiate_on_try(&metadata, &proposed.to_version()).unwrap(),
+            proposed.to_version()
+        );
+
+        let other_connections = Metadata::new(ConnectionId::new(2), ConnectionId::new(3));
+        assert!(matches!(
+            negotiate_on_try(&metadata, &other_connections.to_version()),
+            Err(ChannelError::VersionNotSupported { .. })
+        ));
+        let mut other_host_connection = metadata.clone();
+        other_host_connection.host_connection_id = ConnectionId::new(2);
+        assert!(negotiate_on_try(&metadata, &other_host_connection.to_version()).is_err());
+
+        proposed.encoding = "proto3json".to_string();
+        assert!(negotiate_on_try(&metadata, &proposed.to_version()).is_err());
+        assert!(negotiate_on_try(&metadata, &Version::new(VERSION.to_string())).is_err());
+    }
+}
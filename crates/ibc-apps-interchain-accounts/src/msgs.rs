@@ -0,0 +1,3 @@
+//! Defines the interchain accounts controller message type
+
+pub mod send_tx;
@@ -0,0 +1,24 @@
+//! Defines the `MsgSendTx` through which owners submit transactions to their
+//! interchain accounts.
+
+use core::time::Duration;
+
+use ibc_core_host_types::identifier::ConnectionId;
+use ibc_primitives::signer::Signer;
+
+use crate::packet::InterchainAccountPacketData;
+
+/// Message through which `owner` sends `packet_data` to its interchain account
+/// on the counterparty of `connection_id`, over the active channel of its
+/// controller port.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgSendTx {
+    /// the owner of the interchain account
+    pub owner: Signer,
+    /// the connection to the chain hosting the interchain account
+    pub connection_id: ConnectionId,
+    /// the transaction to execute on the interchain account
+    pub packet_data: InterchainAccountPacketData,
+    /// the timeout of the packet, relative to the current host timestamp
+    pub relative_timeout: Duration,
+}
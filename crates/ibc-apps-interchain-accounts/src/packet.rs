@@ -0,0 +1,149 @@
+//! Contains the `InterchainAccountPacketData` type that defines the structure
+//! of the packet bytes of interchain accounts, whose JSON encoding is the one
+//! of ibc-go, e.g. `{"type":"TYPE_EXECUTE_TX","data":"CgA=","memo":""}`.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use prost::encoding;
+use subtle_encoding::base64;
+
+use super::error::InterchainAccountError;
+
+/// The type of the packets sent to interchain accounts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Type {
+    /// Execute a transaction on the interchain account
+    ExecuteTx,
+}
+
+impl Type {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ExecuteTx => "TYPE_EXECUTE_TX",
+        }
+    }
+}
+
+/// Defines the structure of the packet bytes of interchain accounts.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(
+    try_from = "RawInterchainAccountPacketData",
+    into = "RawInterchainAccountPacketData"
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterchainAccountPacketData {
+    pub r#type: Type,
+    /// the encoded `CosmosTx` to execute
+    pub data: Vec<u8>,
+    pub memo: String,
+}
+
+impl InterchainAccountPacketData {
+    /// Builds the packet executing `messages` on the interchain account, in a
+    /// single transaction encoded as
+    ///
+    /// ```proto
+    /// message CosmosTx {
+    ///   repeated google.protobuf.Any messages = 1;
+    /// }
+    /// ```
+    pub fn execute_tx(messages: Vec<Any>, memo: String) -> Self {
+        let mut data = Vec::new();
+        encoding::message::encode_repeated(1, &messages, &mut data);
+
+        Self {
+            r#type: Type::ExecuteTx,
+            data,
+            memo,
+        }
+    }
+
+    pub fn from_json(bytes: &[u8]) -> Result<Self, InterchainAccountError> {
+        serde_json::from_slice(bytes).map_err(|e| InterchainAccountError::InvalidPacketData {
+            reason: e.to_string(),
+        })
+    }
+
+    pub fn to_json(&self) -> Vec<u8> {
+        serde_json::to_vec(self)
+            .expect("InterchainAccountPacketData's infallible Serialize impl failed")
+    }
+}
+
+/// The JSON encoding of [`InterchainAccountPacketData`].
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RawInterchainAccountPacketData {
+    r#type: String,
+    data: String,
+    #[serde(default)]
+    memo: String,
+}
+
+impl TryFrom<RawInterchainAccountPacketData> for InterchainAccountPacketData {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw: RawInterchainAccountPacketData) -> Result<Self, Self::Error> {
+        let r#type = match raw.r#type.as_str() {
+            "TYPE_EXECUTE_TX" => Type::ExecuteTx,
+            other => {
+                return Err(InterchainAccountError::InvalidPacketData {
+                    reason: format!("unsupported packet type `{other}`"),
+                })
+            }
+        };
+
+        let data = base64::decode(raw.data.as_bytes()).map_err(|e| {
+            InterchainAccountError::InvalidPacketData {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self {
+            r#type,
+            data,
+            memo: raw.memo,
+        })
+    }
+}
+
+impl From<InterchainAccountPacketData> for RawInterchainAccountPacketData {
+    fn from(data: InterchainAccountPacketData) -> Self {
+        Self {
+            r#type: data.r#type.as_str().to_string(),
+            data: String::from_utf8(base64::encode(data.data))
+                .expect("base64 encoding is always valid UTF-8"),
+            memo: data.memo,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packet_data_json() {
+        let data = InterchainAccountPacketData::execute_tx(
+            vec![Any {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: vec![],
+            }],
+            "memo".to_string(),
+        );
+
+        let json = data.to_json();
+        assert!(String::from_utf8(json.clone())
+            .unwrap()
+            .starts_with(r#"{"type":"TYPE_EXECUTE_TX","data":"#));
+        assert_eq!(InterchainAccountPacketData::from_json(&json).unwrap(), data);
+    }
+
+    #[test]
+    fn test_packet_data_unsupported_type() {
+        let json = br#"{"type":"TYPE_UNSPECIFIED","data":""}"#;
+        assert!(matches!(
+            InterchainAccountPacketData::from_json(json),
+            Err(InterchainAccountError::InvalidPacketData { .. })
+        ));
+    }
+}
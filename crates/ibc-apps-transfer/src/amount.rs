@@ -4,6 +4,7 @@ use core::ops::Deref;
 use core::str::FromStr;
 
 use derive_more::{Display, From, Into};
+use ibc_primitives::prelude::*;
 use primitive_types::U256;
 
 use super::error::TokenTransferError;
@@ -11,15 +12,14 @@ use super::error::TokenTransferError;
 use crate::alloc::borrow::ToOwned;
 #[cfg(feature = "schema")]
 use crate::alloc::string::String;
-use crate::prelude::*;
 
 /// A type for representing token transfer amounts.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Display, From, Into)]
 pub struct Amount(
     #[cfg_attr(feature = "schema", schemars(with = "String"))]
-    #[serde(serialize_with = "crate::serializers::serde_string::serialize")]
+    #[serde(serialize_with = "ibc_primitives::serializers::serde_string::serialize")]
     #[serde(deserialize_with = "deserialize")]
     U256,
 );
@@ -32,6 +32,13 @@ impl parity_scale_codec::WrapperTypeDecode for Amount {
 #[cfg(feature = "parity-scale-codec")]
 impl parity_scale_codec::WrapperTypeEncode for Amount {}
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Amount {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(U256(u.arbitrary()?)))
+    }
+}
+
 #[cfg(feature = "borsh")]
 impl borsh::BorshSerialize for Amount {
     fn serialize<W: borsh::maybestd::io::Write>(
@@ -125,7 +132,6 @@ impl From<u64> for Amount {
     }
 }
 
-#[cfg(feature = "serde")]
 fn deserialize<'de, D>(deserializer: D) -> Result<U256, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -139,7 +145,6 @@ where
 mod tests {
     use super::Amount;
 
-    #[cfg(feature = "serde")]
     #[test]
     fn serde_amount() {
         let value = Amount::from(42);
@@ -0,0 +1,222 @@
+//! Defines the `TransferAuthorization` through which an account grants another
+//! one the right to initiate token transfers on its behalf, as the
+//! `TransferAuthorization` of ibc-go does for the `authz` module of the Cosmos
+//! SDK.
+
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+
+use super::error::TokenTransferError;
+use super::msgs::transfer::MsgTransfer;
+use super::PrefixedCoin;
+
+/// The transfers a grantee may initiate on behalf of a granter, on each of the
+/// channels of its allocations.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TransferAuthorization {
+    pub allocations: Vec<Allocation>,
+}
+
+/// The transfers allowed on a single channel.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Allocation {
+    /// the port on which the transfers are sent
+    pub port_id: PortId,
+    /// the channel by which the transfers are sent
+    pub chan_id: ChannelId,
+    /// the tokens that may still be transferred, each up to its amount
+    pub spend_limit: Vec<PrefixedCoin>,
+    /// the receivers the transfers may be sent to, or any if empty
+    pub allow_list: Vec<Signer>,
+}
+
+/// What remains of a [`TransferAuthorization`] once it accepted a transfer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Acceptance {
+    /// The authorization with the spend limits reduced by the transfer
+    Updated(TransferAuthorization),
+    /// The transfer used up the authorization, which should be deleted
+    Exhausted,
+}
+
+impl TransferAuthorization {
+    pub fn new(allocations: Vec<Allocation>) -> Self {
+        Self { allocations }
+    }
+
+    /// Checks that the authorization allows `msg`, i.e. that it has an
+    /// allocation for its channel which allows its receiver and whose spend
+    /// limit covers its token, and returns the authorization left after it.
+    pub fn accept(&self, msg: &MsgTransfer) -> Result<Acceptance, TokenTransferError> {
+        let mut allocations = self.allocations.clone();
+
+        let index = allocations
+            .iter()
+            .position(|a| a.port_id == msg.port_id_on_a && a.chan_id == msg.chan_id_on_a)
+            .ok_or_else(|| TokenTransferError::UnauthorizedTransferChannel {
+                port_id: msg.port_id_on_a.clone(),
+                channel_id: msg.chan_id_on_a.clone(),
+            })?;
+        let allocation = &mut allocations[index];
+
+        let receiver = &msg.packet_data.receiver;
+        if !allocation.allow_list.is_empty() && !allocation.allow_list.contains(receiver) {
+            return Err(TokenTransferError::UnauthorizedTransferReceiver {
+                receiver: receiver.clone(),
+            });
+        }
+
+        let token = &msg.packet_data.token;
+        let spend_limit_exceeded = || TokenTransferError::SpendLimitExceeded {
+            coin: token.clone(),
+        };
+        let limit = allocation
+            .spend_limit
+            .iter_mut()
+            .find(|coin| coin.denom == token.denom)
+            .ok_or_else(spend_limit_exceeded)?;
+        limit.amount = limit
+            .amount
+            .checked_sub(token.amount)
+            .ok_or_else(spend_limit_exceeded)?;
+
+        allocation
+            .spend_limit
+            .retain(|coin| !coin.amount.as_ref().is_zero());
+        if allocation.spend_limit.is_empty() {
+            allocations.remove(index);
+        }
+
+        if allocations.is_empty() {
+            Ok(Acceptance::Exhausted)
+        } else {
+            Ok(Acceptance::Updated(Self::new(allocations)))
+        }
+    }
+}
+
+/// Methods required by the transfers initiated by grantees, to be implemented
+/// by the hosts supporting them, through which
+/// [`send_granted_transfer`](crate::send_granted_transfer)
+/// keeps track of the authorizations granted by each account.
+pub trait TransferAuthorizationContext {
+    /// Returns the authorization `granter` granted to `grantee`, if any.
+    fn transfer_authorization(
+        &self,
+        granter: &Signer,
+        grantee: &Signer,
+    ) -> Result<Option<TransferAuthorization>, TokenTransferError>;
+
+    /// Stores the authorization `granter` granted to `grantee`.
+    fn store_transfer_authorization(
+        &mut self,
+        granter: &Signer,
+        grantee: &Signer,
+        authorization: TransferAuthorization,
+    ) -> Result<(), TokenTransferError>;
+
+    /// Deletes the authorization `granter` granted to `grantee`, once used up.
+    fn delete_transfer_authorization(
+        &mut self,
+        granter: &Signer,
+        grantee: &Signer,
+    ) -> Result<(), TokenTransferError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_primitives::timestamp::Timestamp;
+    use primitive_types::U256;
+
+    use super::*;
+    use crate::packet::PacketData;
+    use crate::BaseCoin;
+
+    fn coin(amount: u64) -> PrefixedCoin {
+        BaseCoin {
+            denom: "uatom".parse().unwrap(),
+            amount: U256::from(amount).into(),
+        }
+        .into()
+    }
+
+    fn allocation(spend_limit: u64, allow_list: Vec<Signer>) -> Allocation {
+        Allocation {
+            port_id: PortId::transfer(),
+            chan_id: ChannelId::new(0),
+            spend_limit: vec![coin(spend_limit)],
+            allow_list,
+        }
+    }
+
+    fn msg(amount: u64) -> MsgTransfer {
+        let mut packet_data = PacketData::new_dummy();
+        packet_data.token = coin(amount);
+
+        MsgTransfer {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            packet_data,
+            timeout_height_on_b: Default::default(),
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    #[test]
+    fn test_accept_reduces_spend_limit() {
+        let authorization = TransferAuthorization::new(vec![allocation(10, vec![])]);
+
+        assert_eq!(
+            authorization.accept(&msg(4)).unwrap(),
+            Acceptance::Updated(TransferAuthorization::new(vec![allocation(6, vec![])]))
+        );
+        assert_eq!(
+            authorization.accept(&msg(10)).unwrap(),
+            Acceptance::Exhausted
+        );
+        assert!(matches!(
+            authorization.accept(&msg(11)),
+            Err(TokenTransferError::SpendLimitExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn test_accept_checks_channel_and_receiver() {
+        let mut other_channel = msg(1);
+        other_channel.chan_id_on_a = ChannelId::new(1);
+        assert!(matches!(
+            TransferAuthorization::new(vec![allocation(10, vec![])]).accept(&other_channel),
+            Err(TokenTransferError::UnauthorizedTransferChannel { .. })
+        ));
+
+        let allowed =
+            TransferAuthorization::new(vec![allocation(10, vec![msg(1).packet_data.receiver])]);
+        assert!(allowed.accept(&msg(1)).is_ok());
+
+        let restricted = TransferAuthorization::new(vec![allocation(
+            10,
+            vec!["cosmos1other".to_string().into()],
+        )]);
+        assert!(matches!(
+            restricted.accept(&msg(1)),
+            Err(TokenTransferError::UnauthorizedTransferReceiver { .. })
+        ));
+    }
+}
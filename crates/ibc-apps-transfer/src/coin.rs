@@ -3,12 +3,12 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
+use ibc_primitives::prelude::*;
 use ibc_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
 
 use super::amount::Amount;
 use super::denom::{BaseDenom, PrefixedDenom};
 use super::error::TokenTransferError;
-use crate::prelude::*;
 
 /// A `Coin` type with fully qualified `PrefixedDenom`.
 pub type PrefixedCoin = Coin<PrefixedDenom>;
@@ -22,7 +22,7 @@ pub type RawCoin = Coin<String>;
 const VALID_DENOM_CHARACTERS: &str = "/:._-";
 
 /// Coin defines a token with a denomination and an amount.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -32,6 +32,7 @@ const VALID_DENOM_CHARACTERS: &str = "/:._-";
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Coin<D> {
     /// Denomination
@@ -1,24 +1,23 @@
 //! Defines the main context traits and IBC module callbacks
+use ibc_core_channel_types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core_channel_types::channel::{Counterparty, Order};
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::version::Version;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
+use ibc_core_router::router::ModuleExtras;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::{AccountResolver, Signer};
 use sha2::{Digest, Sha256};
 
 use super::ack_success_b64;
 use super::error::TokenTransferError;
-use crate::applications::transfer::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
-use crate::applications::transfer::packet::PacketData;
-use crate::applications::transfer::relay::on_recv_packet::process_recv_packet_execute;
-use crate::applications::transfer::relay::{
-    refund_packet_token_execute, refund_packet_token_validate,
-};
-use crate::applications::transfer::{PrefixedCoin, PrefixedDenom, VERSION};
-use crate::core::ics04_channel::acknowledgement::{Acknowledgement, AcknowledgementStatus};
-use crate::core::ics04_channel::channel::{Counterparty, Order};
-use crate::core::ics04_channel::packet::Packet;
-use crate::core::ics04_channel::Version;
-use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
-use crate::core::router::ModuleExtras;
-use crate::core::ContextError;
-use crate::prelude::*;
-use crate::signer::Signer;
+use crate::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
+use crate::packet::PacketDataParsing;
+use crate::packet_v2::PacketDataV2;
+use crate::relay::on_recv_packet::process_recv_packet_execute;
+use crate::relay::{refund_packet_token_execute, refund_packet_token_validate};
+use crate::{PrefixedCoin, PrefixedDenom, MAXIMUM_MEMO_LENGTH, VERSION, VERSION_V2};
 
 /// Methods required in token transfer validation, to be implemented by the host
 pub trait TokenTransferValidationContext {
@@ -27,6 +26,25 @@ pub trait TokenTransferValidationContext {
     /// get_port returns the portID for the transfer module.
     fn get_port(&self) -> Result<PortId, TokenTransferError>;
 
+    /// The resolver of the senders and receivers of transfers into accounts.
+    type AccountResolver: AccountResolver<AccountId = Self::AccountId>;
+
+    /// Returns the resolver of the senders and receivers of transfers.
+    ///
+    /// Handlers resolve each signer through it, and pass the account on to
+    /// the coin callbacks. Hosts parsing their accounts with
+    /// `TryFrom<Signer>` use a
+    /// [`TryFromSignerResolver`](ibc_primitives::signer::TryFromSignerResolver), and can wrap
+    /// it in a [`CachingAccountResolver`](ibc_primitives::signer::CachingAccountResolver) to
+    /// parse each signer at most once.
+    ///
+    /// Hosts whose accounts are designated by addresses in a format of their
+    /// own, e.g. EVM hex addresses, use a
+    /// [`CodecAccountResolver`](ibc_primitives::signer::CodecAccountResolver) with the
+    /// [`AddressCodec`](ibc_primitives::signer::AddressCodec) of that format, so that the
+    /// packets with malformed receivers are acknowledged with an error.
+    fn account_resolver(&self) -> &Self::AccountResolver;
+
     /// Returns the escrow account id for a port and channel combination
     fn get_escrow_account(
         &self,
@@ -67,6 +85,40 @@ pub trait TokenTransferValidationContext {
     fn denom_hash_string(&self, _denom: &PrefixedDenom) -> Option<String> {
         None
     }
+
+    /// Returns the maximum length, in bytes, of the memo of the transfers
+    /// initiated on this host. Defaults to [`MAXIMUM_MEMO_LENGTH`].
+    fn max_memo_length(&self) -> usize {
+        MAXIMUM_MEMO_LENGTH
+    }
+
+    /// Returns whether the packets received by this host may carry fields
+    /// unknown to ICS-20. Defaults to [`PacketDataParsing::Lenient`].
+    fn packet_data_parsing(&self) -> PacketDataParsing {
+        PacketDataParsing::Lenient
+    }
+
+    /// Returns the version of ICS-20 negotiated on the given channel, which
+    /// determines how its packets are decoded. Defaults to [`VERSION`]: the
+    /// hosts agreeing on [`VERSION_V2`] return the version of their channel
+    /// ends, lest the packets of those channels be decoded as version 1 ones.
+    fn channel_version(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<Version, TokenTransferError> {
+        Ok(Version::new(VERSION.to_string()))
+    }
+
+    /// Returns whether the given channel may be closed from this host, which
+    /// it may not by default, as ICS-20 prescribes.
+    ///
+    /// The packets still in flight on a closed channel are not refunded when
+    /// it closes, as the counterparty may have received them already, but as
+    /// each of them is timed out on close, which proves it was not received.
+    fn allows_channel_closing(&self, _port_id: &PortId, _channel_id: &ChannelId) -> bool {
+        false
+    }
 }
 
 /// Methods required in token transfer execution, to be implemented by the host
@@ -132,14 +184,13 @@ pub fn on_chan_open_init_validate(
     }
 
     if !version.is_empty() {
-        version
-            .verify_is_expected(Version::new(VERSION.to_string()))
-            .map_err(ContextError::from)?;
+        verify_supported_version(version)?;
     }
 
     Ok(())
 }
 
+/// Proposes the version requested by the relayer, or [`VERSION`] if none.
 pub fn on_chan_open_init_execute(
     _ctx: &mut impl TokenTransferExecutionContext,
     _order: Order,
@@ -147,9 +198,15 @@ pub fn on_chan_open_init_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _version: &Version,
+    version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    let version = if version.is_empty() {
+        Version::new(VERSION.to_string())
+    } else {
+        version.clone()
+    };
+
+    Ok((ModuleExtras::empty(), version))
 }
 
 pub fn on_chan_open_try_validate(
@@ -168,13 +225,13 @@ pub fn on_chan_open_try_validate(
         });
     }
 
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    verify_supported_version(counterparty_version)?;
 
     Ok(())
 }
 
+/// Agrees on the version proposed by the counterparty, which
+/// [`on_chan_open_try_validate`] checked is supported.
 pub fn on_chan_open_try_execute(
     _ctx: &mut impl TokenTransferExecutionContext,
     _order: Order,
@@ -182,9 +239,9 @@ pub fn on_chan_open_try_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _counterparty_version: &Version,
+    counterparty_version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    Ok((ModuleExtras::empty(), counterparty_version.clone()))
 }
 
 pub fn on_chan_open_ack_validate(
@@ -193,9 +250,7 @@ pub fn on_chan_open_ack_validate(
     _channel_id: &ChannelId,
     counterparty_version: &Version,
 ) -> Result<(), TokenTransferError> {
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    verify_supported_version(counterparty_version)?;
 
     Ok(())
 }
@@ -225,20 +280,31 @@ pub fn on_chan_open_confirm_execute(
     Ok(ModuleExtras::empty())
 }
 
+/// Refuses to close the channel, as ICS-20 prescribes, unless the host allows
+/// it from [`TokenTransferValidationContext::allows_channel_closing`].
 pub fn on_chan_close_init_validate(
-    _ctx: &impl TokenTransferValidationContext,
-    _port_id: &PortId,
-    _channel_id: &ChannelId,
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
 ) -> Result<(), TokenTransferError> {
-    Err(TokenTransferError::CantCloseChannel)
+    if !ctx.allows_channel_closing(port_id, channel_id) {
+        return Err(TokenTransferError::CantCloseChannel);
+    }
+
+    Ok(())
 }
 
+/// Leaves the tokens of the packets in flight escrowed, as the counterparty
+/// may still have received them: they are refunded by
+/// [`on_timeout_packet_execute`] once timed out through `MsgTimeoutOnClose`.
 pub fn on_chan_close_init_execute(
-    _ctx: &mut impl TokenTransferExecutionContext,
-    _port_id: &PortId,
-    _channel_id: &ChannelId,
+    ctx: &mut impl TokenTransferExecutionContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
 ) -> Result<ModuleExtras, TokenTransferError> {
-    Err(TokenTransferError::CantCloseChannel)
+    on_chan_close_init_validate(ctx, port_id, channel_id)?;
+
+    Ok(ModuleExtras::empty())
 }
 
 pub fn on_chan_close_confirm_validate(
@@ -261,11 +327,10 @@ pub fn on_recv_packet_execute(
     ctx_b: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
 ) -> (ModuleExtras, Acknowledgement) {
-    let data = match serde_json::from_slice::<PacketData>(&packet.data) {
+    let data = match received_packet_data(ctx_b, packet) {
         Ok(data) => data,
-        Err(_) => {
-            let ack =
-                AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into());
+        Err(err) => {
+            let ack = AcknowledgementStatus::error(err.into());
             return (ModuleExtras::empty(), ack.into());
         }
     };
@@ -275,15 +340,17 @@ pub fn on_recv_packet_execute(
         Err((extras, error)) => (extras, AcknowledgementStatus::error(error.into())),
     };
 
-    let recv_event = RecvEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        success: ack.is_successful(),
-    };
-    extras.events.push(recv_event.into());
+    for token in data.tokens {
+        let recv_event = RecvEvent {
+            sender: data.sender.clone(),
+            receiver: data.receiver.clone(),
+            denom: token.denom,
+            amount: token.amount,
+            memo: data.memo.clone(),
+            success: ack.is_successful(),
+        };
+        extras.events.push(recv_event.into());
+    }
 
     (extras, ack.into())
 }
@@ -297,10 +364,9 @@ pub fn on_acknowledgement_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let data = sent_packet_data(ctx, packet)?;
 
-    let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+    let acknowledgement = AcknowledgementStatus::try_from(acknowledgement)
         .map_err(|_| TokenTransferError::AckDeserialization)?;
 
     if !acknowledgement.is_successful() {
@@ -316,44 +382,46 @@ pub fn on_acknowledgement_packet_execute(
     acknowledgement: &Acknowledgement,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let data = match serde_json::from_slice::<PacketData>(&packet.data) {
+    let data = match sent_packet_data(ctx, packet) {
         Ok(data) => data,
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
+    };
+
+    let acknowledgement = match AcknowledgementStatus::try_from(acknowledgement) {
+        Ok(ack) => ack,
         Err(_) => {
             return (
                 ModuleExtras::empty(),
-                Err(TokenTransferError::PacketDataDeserialization),
+                Err(TokenTransferError::AckDeserialization),
             );
         }
     };
 
-    let acknowledgement =
-        match serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref()) {
-            Ok(ack) => ack,
-            Err(_) => {
-                return (
-                    ModuleExtras::empty(),
-                    Err(TokenTransferError::AckDeserialization),
-                );
-            }
-        };
-
     if !acknowledgement.is_successful() {
         if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
             return (ModuleExtras::empty(), Err(err));
         }
     }
 
-    let ack_event = AckEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        acknowledgement: acknowledgement.clone(),
-    };
+    let mut events: Vec<_> = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            AckEvent {
+                sender: data.sender.clone(),
+                receiver: data.receiver.clone(),
+                denom: token.denom,
+                amount: token.amount,
+                memo: data.memo.clone(),
+                acknowledgement: acknowledgement.clone(),
+            }
+            .into()
+        })
+        .collect();
+    events.push(AckStatusEvent { acknowledgement }.into());
 
     let extras = ModuleExtras {
-        events: vec![ack_event.into(), AckStatusEvent { acknowledgement }.into()],
+        events,
         log: Vec::new(),
     };
 
@@ -368,8 +436,7 @@ pub fn on_timeout_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let data = sent_packet_data(ctx, packet)?;
 
     refund_packet_token_validate(ctx, packet, &data)?;
 
@@ -381,41 +448,83 @@ pub fn on_timeout_packet_execute(
     packet: &Packet,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let data = match serde_json::from_slice::<PacketData>(&packet.data) {
+    let data = match sent_packet_data(ctx, packet) {
         Ok(data) => data,
-        Err(_) => {
-            return (
-                ModuleExtras::empty(),
-                Err(TokenTransferError::PacketDataDeserialization),
-            );
-        }
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
     };
 
     if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
         return (ModuleExtras::empty(), Err(err));
     }
 
-    let timeout_event = TimeoutEvent {
-        refund_receiver: data.sender,
-        refund_denom: data.token.denom,
-        refund_amount: data.token.amount,
-        memo: data.memo,
-    };
+    let events = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            TimeoutEvent {
+                refund_receiver: data.sender.clone(),
+                refund_denom: token.denom,
+                refund_amount: token.amount,
+                memo: data.memo.clone(),
+            }
+            .into()
+        })
+        .collect();
 
     let extras = ModuleExtras {
-        events: vec![timeout_event.into()],
+        events,
         log: Vec::new(),
     };
 
     (extras, Ok(()))
 }
 
+/// Decodes the data of a packet received on the channel of the host, in the
+/// version negotiated on it.
+fn received_packet_data(
+    ctx_b: &impl TokenTransferValidationContext,
+    packet: &Packet,
+) -> Result<PacketDataV2, TokenTransferError> {
+    let version = ctx_b.channel_version(&packet.port_id_on_b, &packet.chan_id_on_b)?;
+
+    // The error acknowledgement is committed to, so it must not carry the
+    // message of the JSON decoder, which may change across versions.
+    PacketDataV2::from_json(&packet.data, &version, ctx_b.packet_data_parsing())
+        .map_err(|_| TokenTransferError::PacketDataDeserialization)
+}
+
+/// Decodes the data of a packet the host sent, in the version negotiated on
+/// its channel.
+fn sent_packet_data(
+    ctx_a: &impl TokenTransferValidationContext,
+    packet: &Packet,
+) -> Result<PacketDataV2, TokenTransferError> {
+    let version = ctx_a.channel_version(&packet.port_id_on_a, &packet.chan_id_on_a)?;
+
+    PacketDataV2::from_json(&packet.data, &version, PacketDataParsing::Lenient)
+        .map_err(|_| TokenTransferError::PacketDataDeserialization)
+}
+
+/// Checks that `version` is one of the versions of ICS20 this module
+/// supports, i.e. [`VERSION`] or [`VERSION_V2`].
+fn verify_supported_version(version: &Version) -> Result<(), TokenTransferError> {
+    if version.as_str() == VERSION_V2 {
+        return Ok(());
+    }
+
+    version
+        .verify_is_expected(Version::new(VERSION.to_string()))
+        .map_err(ContextError::from)?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use subtle_encoding::bech32;
 
     use super::*;
-    use crate::applications::transfer::context::cosmos_adr028_escrow_address;
+    use crate::context::cosmos_adr028_escrow_address;
 
     #[test]
     fn test_cosmos_escrow_address() {
@@ -4,20 +4,19 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
 use derive_more::{Display, From};
+use ibc_core_host_types::identifier::{is_valid_port_identifier, ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::serializers::serde_string;
 use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
 
 use super::error::TokenTransferError;
-use crate::core::ics24_host::identifier::{ChannelId, PortId};
-use crate::prelude::*;
-#[cfg(feature = "serde")]
-use crate::serializers::serde_string;
 
 /// The "base" of a denomination.
 ///
 /// For example, given the token `my_port-1/my_channel-1/my_port-2/my_channel-2/base_denom`,
 /// `base_denom` is the "base" of the denomination
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -40,6 +39,83 @@ impl BaseDenom {
     }
 }
 
+/// The prefix of the denominations of the vouchers minted by ICS-20, which
+/// are followed by the hash of their [`PrefixedDenom`].
+pub const IBC_DENOM_PREFIX: &str = "ibc/";
+
+const MIN_BANK_DENOM_LENGTH: usize = 3;
+const MAX_BANK_DENOM_LENGTH: usize = 128;
+
+impl BaseDenom {
+    /// Checks that this base denomination is accepted by the bank module of
+    /// the Cosmos SDK, see [`validate_bank_denom`].
+    pub fn validate_bank_compatible(&self) -> Result<(), TokenTransferError> {
+        validate_bank_denom(self.as_str())
+    }
+}
+
+/// Generates base denominations the bank module of the Cosmos SDK accepts.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BaseDenom {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use ibc_primitives::utils::arbitrary::string_of;
+
+        const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789/:._-";
+
+        let mut denom = string_of(u, LETTERS, 1, 1)?;
+        denom.push_str(&string_of(
+            u,
+            CHARS,
+            MIN_BANK_DENOM_LENGTH - 1,
+            MAX_BANK_DENOM_LENGTH - 1,
+        )?);
+        Ok(Self(denom))
+    }
+}
+
+/// Validates `denom` against the denominations of the bank module of the
+/// Cosmos SDK, which match `[a-zA-Z][a-zA-Z0-9/:._-]{2,127}`.
+pub fn validate_bank_denom(denom: &str) -> Result<(), TokenTransferError> {
+    let length = denom.len();
+    if !(MIN_BANK_DENOM_LENGTH..=MAX_BANK_DENOM_LENGTH).contains(&length) {
+        return Err(TokenTransferError::InvalidDenomLength {
+            denom: denom.to_string(),
+            length: length as u64,
+            min: MIN_BANK_DENOM_LENGTH as u64,
+            max: MAX_BANK_DENOM_LENGTH as u64,
+        });
+    }
+
+    for (pos, character) in denom.chars().enumerate() {
+        let valid = if pos == 0 {
+            character.is_ascii_alphabetic()
+        } else {
+            character.is_ascii_alphanumeric() || matches!(character, '/' | ':' | '.' | '_' | '-')
+        };
+        if !valid {
+            return Err(TokenTransferError::InvalidDenomCharacter {
+                denom: denom.to_string(),
+                character,
+                pos: pos as u64,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates `denom` as the denomination of an ICS-20 voucher, i.e.
+/// `ibc/{hash}` where `hash` is a hex-encoded SHA-256 hash.
+pub fn validate_ibc_denom(denom: &str) -> Result<(), TokenTransferError> {
+    match denom.strip_prefix(IBC_DENOM_PREFIX) {
+        Some(hash) if hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit()) => Ok(()),
+        _ => Err(TokenTransferError::InvalidIbcDenom {
+            denom: denom.to_string(),
+        }),
+    }
+}
+
 impl FromStr for BaseDenom {
     type Err = TokenTransferError;
 
@@ -69,8 +145,9 @@ impl FromStr for BaseDenom {
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
 pub struct TracePrefix {
     port_id: PortId,
@@ -108,8 +185,9 @@ impl Display for TracePrefix {
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord, From)]
 pub struct TracePath(Vec<TracePrefix>);
 
@@ -201,7 +279,7 @@ impl Display for TracePath {
 }
 
 /// A type that contains the base denomination for ICS20 and the source tracing information path.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -215,10 +293,11 @@ impl Display for TracePath {
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct PrefixedDenom {
     /// A series of `{port-id}/{channel-id}`s for tracing the source of the token.
-    #[cfg_attr(feature = "serde", serde(with = "serde_string"))]
+    #[serde(with = "serde_string")]
     #[cfg_attr(feature = "schema", schemars(with = "String"))]
     pub trace_path: TracePath,
     /// Base denomination of the relayed fungible token.
@@ -235,6 +314,46 @@ impl PrefixedDenom {
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// Parses the full denomination of a token that travelled through any
+    /// number of hops, whose base denomination may itself contain `/`, e.g.
+    /// `transfer/channel-0/transfer/channel-1/gamm/pool/1`.
+    ///
+    /// Like ibc-go, it takes the leading `{port-id}/channel-{n}` pairs as the
+    /// trace path, and the rest as the base denomination. Unlike
+    /// [`PrefixedDenom::from_str`], it therefore accepts base denominations
+    /// such as the ones of liquidity pool shares or bridged ERC-20 tokens.
+    pub fn from_full_denom(s: &str) -> Result<Self, TokenTransferError> {
+        let parts: Vec<&str> = s.split('/').collect();
+
+        let mut trace_len = 0;
+        while let [port_id, channel_id, ..] = &parts[trace_len..] {
+            if !(is_valid_port_identifier(port_id) && is_counter_channel_id(channel_id)) {
+                break;
+            }
+            trace_len += 2;
+        }
+
+        let base_denom = BaseDenom::from_str(&parts[trace_len..].join("/"))?;
+        let trace_path = TracePath::try_from(parts[..trace_len].to_vec())?;
+
+        Ok(Self {
+            trace_path,
+            base_denom,
+        })
+    }
+}
+
+/// Returns whether `channel_id` is formatted as `channel-{n}`, like the
+/// identifiers of the channels in trace paths.
+fn is_counter_channel_id(channel_id: &str) -> bool {
+    let counter = channel_id
+        .strip_prefix(ChannelId::prefix())
+        .and_then(|suffix| suffix.strip_prefix('-'));
+    match counter {
+        Some(counter) => !counter.is_empty() && counter.bytes().all(|b| b.is_ascii_digit()),
+        None => false,
+    }
 }
 
 /// Returns true if the denomination originally came from the sender chain and
@@ -353,8 +472,120 @@ impl Display for PrefixedDenom {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
+    #[rstest]
+    #[case("uatom")]
+    #[case("abc")]
+    #[case("gamm/pool/1")]
+    #[case("erc20/0xdAC17F958D2ee523a2206206994597C13D831ec7")]
+    #[case("factory/osmo1abc/mytoken.v2_a-b:c")]
+    #[case(&"a".repeat(128))]
+    fn test_valid_bank_denom(#[case] denom: &str) {
+        validate_bank_denom(denom).unwrap();
+        BaseDenom::from_str(denom)
+            .unwrap()
+            .validate_bank_compatible()
+            .unwrap();
+    }
+
+    #[rstest]
+    #[case::too_short("ab")]
+    #[case::too_long(&"a".repeat(129))]
+    fn test_bank_denom_invalid_length(#[case] denom: &str) {
+        assert!(matches!(
+            validate_bank_denom(denom),
+            Err(TokenTransferError::InvalidDenomLength { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case::leading_digit("1atom", '1', 0)]
+    #[case::leading_slash("/uatom", '/', 0)]
+    #[case::space("u atom", ' ', 1)]
+    #[case::hash("uatom#1", '#', 5)]
+    fn test_bank_denom_invalid_character(
+        #[case] denom: &str,
+        #[case] expected_character: char,
+        #[case] expected_pos: u64,
+    ) {
+        match validate_bank_denom(denom) {
+            Err(TokenTransferError::InvalidDenomCharacter { character, pos, .. }) => {
+                assert_eq!(character, expected_character);
+                assert_eq!(pos, expected_pos);
+            }
+            res => panic!("unexpected result: {res:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+        true
+    )]
+    #[case(
+        "ibc/27394fb092d2eccd56123c74f36e4c1f926001ceada9ca97ea622b25f41e5eb2",
+        true
+    )]
+    #[case(
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB",
+        false
+    )]
+    #[case(
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EBZ",
+        false
+    )]
+    #[case("ibc/", false)]
+    #[case("uatom", false)]
+    fn test_ibc_denom(#[case] denom: &str, #[case] valid: bool) {
+        assert_eq!(validate_ibc_denom(denom).is_ok(), valid);
+    }
+
+    #[rstest]
+    #[case("uatom", "", "uatom")]
+    #[case("gamm/pool/1", "", "gamm/pool/1")]
+    #[case("transfer/channel-0/uatom", "transfer/channel-0", "uatom")]
+    #[case(
+        "transfer/channel-0/transfer/channel-1/gamm/pool/1",
+        "transfer/channel-0/transfer/channel-1",
+        "gamm/pool/1"
+    )]
+    #[case(
+        "transfer/channel-0/transfer/channel-1/transfer/channel-2/transfer/channel-3/uatom",
+        "transfer/channel-0/transfer/channel-1/transfer/channel-2/transfer/channel-3",
+        "uatom"
+    )]
+    #[case("transfer/atom", "", "transfer/atom")]
+    #[case(
+        "transfer/channel-0/transfer/pool/1",
+        "transfer/channel-0",
+        "transfer/pool/1"
+    )]
+    #[case("transfer/channel-x/uatom", "", "transfer/channel-x/uatom")]
+    fn test_from_full_denom(
+        #[case] full_denom: &str,
+        #[case] trace_path: &str,
+        #[case] base_denom: &str,
+    ) {
+        let denom = PrefixedDenom::from_full_denom(full_denom).unwrap();
+        assert_eq!(denom.trace_path, TracePath::from_str(trace_path).unwrap());
+        assert_eq!(denom.base_denom.as_str(), base_denom);
+        assert_eq!(denom.to_string(), full_denom);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("transfer/channel-0/")]
+    #[case("transfer/channel-0")]
+    fn test_from_full_denom_empty_base(#[case] full_denom: &str) {
+        assert!(matches!(
+            PrefixedDenom::from_full_denom(full_denom),
+            Err(TokenTransferError::EmptyBaseDenom)
+        ));
+    }
+
     #[test]
     fn test_denom_validation() -> Result<(), TokenTransferError> {
         assert!(BaseDenom::from_str("").is_err(), "empty base denom");
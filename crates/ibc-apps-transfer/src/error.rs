@@ -0,0 +1,163 @@
+//! Defines the token transfer error type
+
+use core::convert::Infallible;
+use core::str::Utf8Error;
+
+use displaydoc::Display;
+use ibc_core_channel_types::acknowledgement::StatusValue;
+use ibc_core_channel_types::channel::Order;
+use ibc_core_channel_types::error::{ChannelCloseVeto, ChannelError};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifier::{ChannelId, IdentifierError, PortId};
+use ibc_primitives::impl_error_sources;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use uint::FromDecStrErr;
+
+use super::PrefixedCoin;
+
+#[derive(Display, Debug)]
+pub enum TokenTransferError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// destination channel not found in the counterparty of port_id `{port_id}` and channel_id `{channel_id}`
+    DestinationChannelNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// base denomination is empty
+    EmptyBaseDenom,
+    /// denomination `{denom}` has invalid length `{length}` must be between `{min}`-`{max}` characters
+    InvalidDenomLength {
+        denom: String,
+        length: u64,
+        min: u64,
+        max: u64,
+    },
+    /// denomination `{denom}` must start with a letter and only contain alphanumeric characters or `/`, `:`, `.`, `_`, `-`, but has `{character}` at position `{pos}`
+    InvalidDenomCharacter {
+        denom: String,
+        character: char,
+        pos: u64,
+    },
+    /// IBC denomination `{denom}` must be `ibc/` followed by a hex-encoded SHA-256 hash
+    InvalidIbcDenom { denom: String },
+    /// invalid prot id n trace at position: `{pos}`, validation error: `{validation_error}`
+    InvalidTracePortId {
+        pos: u64,
+        validation_error: IdentifierError,
+    },
+    /// invalid channel id in trace at position: `{pos}`, validation error: `{validation_error}`
+    InvalidTraceChannelId {
+        pos: u64,
+        validation_error: IdentifierError,
+    },
+    /// trace length must be even but got: `{len}`
+    InvalidTraceLength { len: u64 },
+    /// invalid amount error: `{0}`
+    InvalidAmount(FromDecStrErr),
+    /// invalid token
+    InvalidToken,
+    /// expected `{expect_order}` channel, got `{got_order}`
+    ChannelNotUnordered {
+        expect_order: Order,
+        got_order: Order,
+    },
+    /// channel cannot be closed
+    CantCloseChannel,
+    /// failed to deserialize packet data
+    PacketDataDeserialization,
+    /// invalid packet data: `{reason}`
+    InvalidPacketData { reason: String },
+    /// forwarding the tokens of a packet is not supported
+    ForwardingNotSupported,
+    /// failed to deserialize acknowledgement
+    AckDeserialization,
+    /// receive is not enabled
+    ReceiveDisabled { reason: String },
+    /// send is not enabled
+    SendDisabled { reason: String },
+    /// failed to parse as AccountId
+    ParseAccountFailure,
+    /// invalid port: `{port_id}`, expected `{exp_port_id}`
+    InvalidPort {
+        port_id: PortId,
+        exp_port_id: PortId,
+    },
+    /// decoding raw msg error: `{reason}`
+    DecodeRawMsg { reason: String },
+    /// unknown msg type: `{msg_type}`
+    UnknownMsgType { msg_type: String },
+    /// invalid coin string: `{coin}`
+    InvalidCoin { coin: String },
+    /// decoding raw bytes as UTF8 string error: `{0}`
+    Utf8Decode(Utf8Error),
+    /// memo is `{length}` bytes long, exceeding the maximum of `{max_length}` bytes
+    MemoTooLong { length: usize, max_length: usize },
+    /// invalid forward memo: `{reason}`
+    InvalidForwardMemo { reason: String },
+    /// no transfer authorization granted by `{granter}` to `{grantee}`
+    MissingTransferAuthorization { granter: Signer, grantee: Signer },
+    /// transfer authorization has no allocation for port_id `{port_id}` and channel_id `{channel_id}`
+    UnauthorizedTransferChannel {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// receiver `{receiver}` is not allowed by the transfer authorization
+    UnauthorizedTransferReceiver { receiver: Signer },
+    /// transfer of `{coin}` exceeds the spend limit of the transfer authorization
+    SpendLimitExceeded { coin: PrefixedCoin },
+}
+
+impl_error_sources!(TokenTransferError {
+    ibc {
+        Self::ContextError(e) => e,
+        Self::InvalidIdentifier(e) => e,
+        Self::InvalidTracePortId { validation_error: e, .. } => e,
+        Self::InvalidTraceChannelId { validation_error: e, .. } => e,
+    }
+    std {
+        Self::InvalidAmount(e) => e,
+        Self::Utf8Decode(e) => e,
+    }
+});
+
+impl From<Infallible> for TokenTransferError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<ContextError> for TokenTransferError {
+    fn from(err: ContextError) -> TokenTransferError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for TokenTransferError {
+    fn from(err: IdentifierError) -> TokenTransferError {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+/// Reports the refusal to close a channel as a [`ChannelCloseVeto`], for the
+/// channel closing callbacks of the transfer module, and the other errors as
+/// application module errors.
+impl From<TokenTransferError> for ChannelError {
+    fn from(err: TokenTransferError) -> Self {
+        match err {
+            TokenTransferError::CantCloseChannel => ChannelCloseVeto::NotAllowed.into(),
+            err => ChannelError::AppModule {
+                description: err.to_string(),
+            },
+        }
+    }
+}
+
+impl From<TokenTransferError> for StatusValue {
+    fn from(err: TokenTransferError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
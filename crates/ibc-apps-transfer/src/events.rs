@@ -1,11 +1,12 @@
 //! Defines all token transfer event types
 
+use ibc_core_channel_types::acknowledgement::AcknowledgementStatus;
+use ibc_core_handler_types::events::ModuleEvent;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+
 use super::Memo;
-use crate::applications::transfer::{Amount, PrefixedDenom, MODULE_ID_STR};
-use crate::core::events::ModuleEvent;
-use crate::core::ics04_channel::acknowledgement::AcknowledgementStatus;
-use crate::prelude::*;
-use crate::signer::Signer;
+use crate::{Amount, PrefixedDenom, MODULE_ID_STR};
 
 const EVENT_TYPE_PACKET: &str = "fungible_token_packet";
 const EVENT_TYPE_TIMEOUT: &str = "timeout";
@@ -13,6 +14,10 @@ const EVENT_TYPE_DENOM_TRACE: &str = "denomination_trace";
 const EVENT_TYPE_TRANSFER: &str = "ibc_transfer";
 
 /// Contains all events variants that can be emitted from the token transfer application
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub enum Event {
     Recv(RecvEvent),
     Ack(AckEvent),
@@ -24,6 +29,10 @@ pub enum Event {
 
 /// Event emitted in the [`onRecvPacket`][super::context::on_recv_packet_execute]
 /// module callback to indicate the that the `RecvPacket` message was processed
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct RecvEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -60,6 +69,10 @@ impl From<RecvEvent> for ModuleEvent {
 
 /// Event emitted in the [`onAcknowledgePacket`][super::context::on_acknowledgement_packet_execute]
 /// module callback
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct AckEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -96,6 +109,10 @@ impl From<AckEvent> for ModuleEvent {
 
 /// Event emitted in the [`onAcknowledgePacket`][super::context::on_acknowledgement_packet_execute]
 /// module callback to indicate whether the acknowledgement is a success or a failure
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct AckStatusEvent {
     pub acknowledgement: AcknowledgementStatus,
 }
@@ -117,6 +134,10 @@ impl From<AckStatusEvent> for ModuleEvent {
 
 /// Event emitted in the [`onTimeoutPacket`][super::context::on_timeout_packet_execute]
 /// module callback
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct TimeoutEvent {
     pub refund_receiver: Signer,
     pub refund_denom: PrefixedDenom,
@@ -147,6 +168,10 @@ impl From<TimeoutEvent> for ModuleEvent {
 
 /// Event emitted in the [`onRecvPacket`][super::context::on_recv_packet_execute]
 /// module callback when new tokens are minted
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct DenomTraceEvent {
     pub trace_hash: Option<String>,
     pub denom: PrefixedDenom,
@@ -168,6 +193,10 @@ impl From<DenomTraceEvent> for ModuleEvent {
 
 /// Event emitted in [`sendTransfer`][super::send_transfer] after a successful
 /// transfer
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct TransferEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -0,0 +1,74 @@
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+// https://github.com/cosmos/ibc-rs/issues/342
+#![allow(clippy::result_large_err)]
+//! Implementation of the [fungible token transfer module](https://github.com/cosmos/ibc/blob/main/spec/app/ics-020-fungible-token-transfer/README.md) (ICS-20)
+
+extern crate alloc;
+// The tests, and the `Arbitrary` and `JsonSchema` derives, refer to `std`
+#[cfg(any(test, feature = "arbitrary", feature = "schema"))]
+extern crate std;
+
+pub mod amount;
+pub mod authorization;
+pub mod coin;
+pub mod context;
+pub mod denom;
+pub mod error;
+pub mod events;
+pub mod memo;
+pub mod msgs;
+pub mod packet;
+pub mod packet_v2;
+pub mod simulation;
+
+pub use amount::*;
+pub use coin::*;
+pub use denom::*;
+pub use memo::*;
+
+mod relay;
+
+pub use relay::send_transfer::{
+    send_granted_transfer, send_granted_transfer_execute, send_granted_transfer_validate,
+    send_transfer, send_transfer_execute, send_transfer_validate,
+};
+
+/// Module identifier for the ICS20 application.
+pub const MODULE_ID_STR: &str = "transfer";
+
+/// The port identifier that the ICS20 applications
+/// typically bind with.
+pub const PORT_ID_STR: &str = "transfer";
+
+/// ICS20 application current version.
+pub const VERSION: &str = "ics20-1";
+
+/// ICS20 application version whose packets carry several tokens and a
+/// forwarding path (see [`packet_v2`]).
+pub const VERSION_V2: &str = "ics20-2";
+
+/// The default maximum length, in bytes, of the memo of a transfer, as in
+/// ibc-go.
+pub const MAXIMUM_MEMO_LENGTH: usize = 32768;
+
+/// The successful string used for creating an acknowledgement status,
+/// equivalent to `base64::encode(0x01)`.
+pub const ACK_SUCCESS_B64: &str = "AQ==";
+
+use ibc_core_channel_types::acknowledgement::StatusValue;
+
+/// Returns a successful acknowledgement status for the token transfer application.
+pub fn ack_success_b64() -> StatusValue {
+    StatusValue::new(ACK_SUCCESS_B64).expect("ack status value is never supposed to be empty")
+}
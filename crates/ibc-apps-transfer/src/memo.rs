@@ -0,0 +1,226 @@
+//! Defines the memo type, which represents the string that users can include
+//! with a token transfer
+
+use alloc::collections::BTreeMap;
+use core::convert::Infallible;
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use serde::de::IgnoredAny;
+
+use super::error::TokenTransferError;
+
+/// Represents the token transfer memo
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Memo(String);
+
+impl Memo {
+    /// Returns the forwarding instructions of the packet-forward middleware
+    /// carried by this memo, if it is a JSON object with a `forward` key.
+    ///
+    /// Memos which are not JSON objects, such as plain text, carry none.
+    pub fn forward(&self) -> Result<Option<ForwardMetadata>, TokenTransferError> {
+        match serde_json::from_str::<BTreeMap<String, IgnoredAny>>(&self.0) {
+            Ok(keys) if keys.contains_key("forward") => {}
+            _ => return Ok(None),
+        }
+        serde_json::from_str::<ForwardMemo>(&self.0)
+            .map(|memo| Some(memo.forward))
+            .map_err(|e| TokenTransferError::InvalidForwardMemo {
+                reason: e.to_string(),
+            })
+    }
+}
+
+impl AsRef<str> for Memo {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Memo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for Memo {
+    fn from(memo: String) -> Self {
+        Self(memo)
+    }
+}
+
+impl FromStr for Memo {
+    type Err = Infallible;
+
+    fn from_str(memo: &str) -> Result<Self, Infallible> {
+        Ok(Self(memo.to_owned()))
+    }
+}
+
+/// The memo of a transfer to be forwarded by the packet-forward middleware of
+/// the receiving chain, e.g.
+/// `{"forward":{"receiver":"osmo1...","port":"transfer","channel":"channel-0"}}`.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardMemo {
+    pub forward: ForwardMetadata,
+}
+
+/// Where and how the packet-forward middleware forwards a received transfer.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardMetadata {
+    /// The receiver of the forwarded transfer.
+    pub receiver: String,
+    /// The port on which to forward the transfer.
+    pub port: PortId,
+    /// The channel on which to forward the transfer.
+    pub channel: ChannelId,
+    /// The timeout of the forwarded transfer, in nanoseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// The number of times to retry the forwarded transfer on timeout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u8>,
+    /// The memo of the forwarded transfer, e.g. another [`ForwardMemo`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<NextMemo>>,
+}
+
+/// The memo of a forwarded transfer, either another [`ForwardMemo`], a
+/// string holding the encoded memo, or any other JSON value, such as the memo
+/// of another middleware.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NextMemo {
+    Forward(ForwardMemo),
+    Encoded(String),
+    Other(JsonValue),
+}
+
+/// An opaque JSON value, holding the memos of other middlewares.
+///
+/// Numbers are limited to integers, which are all that memos carry in practice.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Unsigned(u64),
+    Signed(i64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(BTreeMap<String, JsonValue>),
+}
+
+impl ForwardMetadata {
+    /// Returns the memo of the forwarded transfer.
+    pub fn next_memo(&self) -> Memo {
+        match self.next.as_deref() {
+            None => Memo::from(String::new()),
+            Some(NextMemo::Forward(next)) => Memo::from(
+                serde_json::to_string(next)
+                    .expect("ForwardMemo's infallible Serialize impl failed"),
+            ),
+            Some(NextMemo::Encoded(next)) => Memo::from(next.clone()),
+            Some(NextMemo::Other(next)) => Memo::from(
+                serde_json::to_string(next).expect("JsonValue's infallible Serialize impl failed"),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forward_memo() {
+        let memo = Memo::from(
+            r#"{"forward":{"receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","port":"transfer","channel":"channel-1","retries":2,"next":{"forward":{"receiver":"osmo1","port":"transfer","channel":"channel-2"}}},"wasm":{}}"#
+                .to_string(),
+        );
+        let forward = memo.forward().unwrap().unwrap();
+        assert_eq!(forward.port, PortId::transfer());
+        assert_eq!(forward.channel, ChannelId::new(1));
+        assert_eq!(forward.timeout, None);
+        assert_eq!(forward.retries, Some(2));
+
+        let next = forward.next_memo().forward().unwrap().unwrap();
+        assert_eq!(next.receiver, "osmo1");
+        assert_eq!(next.channel, ChannelId::new(2));
+        assert_eq!(next.next_memo().as_ref(), "");
+    }
+
+    #[test]
+    fn test_forward_memo_with_other_next() {
+        let next = r#"{"wasm":{"contract":"osmo1c","msg":{"amount":-1,"list":[1,"a"],"none":null,"ok":true}}}"#;
+        let memo = Memo::from(format!(
+            r#"{{"forward":{{"receiver":"osmo1","port":"transfer","channel":"channel-0","next":{next}}}}}"#
+        ));
+        let forward = memo.forward().unwrap().unwrap();
+        assert!(matches!(forward.next.as_deref(), Some(NextMemo::Other(_))));
+        assert_eq!(forward.next_memo().as_ref(), next);
+        assert_eq!(forward.next_memo().forward().unwrap(), None);
+    }
+
+    #[test]
+    fn test_memo_without_forward() {
+        for memo in ["", "a plain memo", "[1,2]", r#"{"wasm":{}}"#] {
+            assert_eq!(Memo::from(memo.to_string()).forward().unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn test_invalid_forward_memo() {
+        let memo = Memo::from(r#"{"forward":{"receiver":"osmo1"}}"#.to_string());
+        assert!(matches!(
+            memo.forward(),
+            Err(TokenTransferError::InvalidForwardMemo { .. })
+        ));
+    }
+
+    #[test]
+    fn test_forward_memo_round_trip() {
+        let memo = ForwardMemo {
+            forward: ForwardMetadata {
+                receiver: "osmo1".to_string(),
+                port: PortId::transfer(),
+                channel: ChannelId::new(0),
+                timeout: Some(600_000_000_000),
+                retries: None,
+                next: None,
+            },
+        };
+        let json = serde_json::to_string(&memo).unwrap();
+        assert_eq!(
+            json,
+            r#"{"forward":{"receiver":"osmo1","port":"transfer","channel":"channel-0","timeout":600000000000}}"#
+        );
+        assert_eq!(Memo::from(json).forward().unwrap(), Some(memo.forward));
+    }
+}
@@ -1,17 +1,18 @@
 //! Defines the token transfer message type
 
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
 use ibc_proto::Protobuf;
 
-use crate::applications::transfer::error::TokenTransferError;
-use crate::applications::transfer::packet::PacketData;
-use crate::core::ics04_channel::error::PacketError;
-use crate::core::ics04_channel::timeout::TimeoutHeight;
-use crate::core::ics24_host::identifier::{ChannelId, PortId};
-use crate::core::timestamp::Timestamp;
-use crate::core::{ContextError, Msg};
-use crate::prelude::*;
+use crate::error::TokenTransferError;
+use crate::packet::PacketData;
 
 pub(crate) const TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
 
@@ -22,8 +23,8 @@ pub(crate) const TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
 /// packet, which might be the user of a command line application, should only
 /// have to specify the information related to the transfer of the token, and
 /// let the library figure out how to build the packet properly.
-#[derive(Clone, Debug, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
@@ -109,6 +110,14 @@ impl From<MsgTransfer> for RawMsgTransfer {
 
 impl Protobuf<RawMsgTransfer> for MsgTransfer {}
 
+impl ValidateBasic for MsgTransfer {
+    type Error = TokenTransferError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<Any> for MsgTransfer {
     type Error = TokenTransferError;
 
@@ -1,24 +1,22 @@
 //! Contains the `PacketData` type that defines the structure of token transfers' packet bytes
 
-use alloc::string::ToString;
 use core::convert::TryFrom;
 use core::str::FromStr;
 
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
 
 use super::error::TokenTransferError;
 use super::{Amount, Memo, PrefixedCoin, PrefixedDenom};
-#[cfg(feature = "schema")]
-use crate::alloc::borrow::ToOwned;
-use crate::signer::Signer;
 
 /// Defines the structure of token transfers' packet bytes
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(
-    feature = "serde",
-    serde(try_from = "RawPacketData", into = "RawPacketData")
-)]
-#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+///
+/// Its JSON encoding is the `FungibleTokenPacketData` of ICS-20, e.g.
+/// `{"denom":"uatom","amount":"10","sender":"...","receiver":"...","memo":""}`,
+/// which is also what its JSON schema describes.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "RawPacketData", into = "RawPacketData")]
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
@@ -27,6 +25,7 @@ use crate::signer::Signer;
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PacketData {
     pub token: PrefixedCoin,
@@ -35,6 +34,78 @@ pub struct PacketData {
     pub memo: Memo,
 }
 
+impl PacketData {
+    /// Decodes the JSON bytes of a packet, accepting or rejecting the fields
+    /// unknown to ICS-20 according to `parsing`.
+    pub fn from_json(bytes: &[u8], parsing: PacketDataParsing) -> Result<Self, TokenTransferError> {
+        match parsing {
+            PacketDataParsing::Strict => {
+                let strict: StrictPacketData = serde_json::from_slice(bytes).map_err(|e| {
+                    TokenTransferError::InvalidPacketData {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Self::try_from(RawPacketData::from(strict))
+            }
+            PacketDataParsing::Lenient => {
+                serde_json::from_slice(bytes).map_err(|e| TokenTransferError::InvalidPacketData {
+                    reason: e.to_string(),
+                })
+            }
+        }
+    }
+}
+
+/// How strictly to decode the JSON bytes of token transfer packets.
+///
+/// Implementations disagree on whether the packet data may carry fields other
+/// than those of ICS-20: ibc-go rejects them, while others add their own.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PacketDataParsing {
+    /// Rejects the packets with fields unknown to ICS-20.
+    Strict,
+    /// Ignores the fields unknown to ICS-20.
+    #[default]
+    Lenient,
+}
+
+/// The JSON encoding of [`PacketData`], without any other field.
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "FungibleTokenPacketData"))]
+#[serde(deny_unknown_fields)]
+struct StrictPacketData {
+    denom: String,
+    amount: String,
+    sender: String,
+    receiver: String,
+    #[serde(default)]
+    memo: String,
+}
+
+impl From<StrictPacketData> for RawPacketData {
+    fn from(strict: StrictPacketData) -> Self {
+        Self {
+            denom: strict.denom,
+            amount: strict.amount,
+            sender: strict.sender,
+            receiver: strict.receiver,
+            memo: strict.memo,
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for PacketData {
+    fn schema_name() -> String {
+        StrictPacketData::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        StrictPacketData::json_schema(gen)
+    }
+}
+
 impl TryFrom<RawPacketData> for PacketData {
     type Error = TokenTransferError;
 
@@ -69,7 +140,7 @@ mod tests {
     use primitive_types::U256;
 
     use super::*;
-    use crate::applications::transfer::BaseCoin;
+    use crate::BaseCoin;
 
     impl PacketData {
         pub fn new_dummy() -> Self {
@@ -120,4 +191,31 @@ mod tests {
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data());
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data_without_memo());
     }
+
+    #[test]
+    fn test_packet_data_parsing() {
+        for json in [
+            dummy_json_packet_data(),
+            dummy_json_packet_data_without_memo(),
+        ] {
+            for parsing in [PacketDataParsing::Strict, PacketDataParsing::Lenient] {
+                assert_eq!(
+                    PacketData::from_json(json.as_bytes(), parsing).unwrap(),
+                    PacketData::new_dummy()
+                );
+            }
+        }
+
+        let with_unknown_field =
+            dummy_json_packet_data().replace(r#""memo":"#, r#""extra":1,"memo":"#);
+        assert_eq!(
+            PacketData::from_json(with_unknown_field.as_bytes(), PacketDataParsing::Lenient)
+                .unwrap(),
+            PacketData::new_dummy()
+        );
+        assert!(matches!(
+            PacketData::from_json(with_unknown_field.as_bytes(), PacketDataParsing::Strict),
+            Err(TokenTransferError::InvalidPacketData { .. })
+        ));
+    }
 }
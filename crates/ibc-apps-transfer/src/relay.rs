@@ -0,0 +1,77 @@
+//! Implements the processing logic for ICS20 (token transfer) message.
+
+use ibc_core_channel_types::packet::Packet;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::{AccountResolver, Signer};
+
+use super::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use crate::error::TokenTransferError;
+use crate::is_sender_chain_source;
+use crate::packet_v2::PacketDataV2;
+
+pub mod on_recv_packet;
+pub mod send_transfer;
+
+/// Resolves the sender or receiver `signer` of a transfer into an account
+/// through the resolver of the host.
+pub(crate) fn resolve_account<Ctx: TokenTransferValidationContext>(
+    ctx: &Ctx,
+    signer: &Signer,
+) -> Result<Ctx::AccountId, TokenTransferError> {
+    ctx.account_resolver()
+        .resolve_account(signer)
+        .map_err(|_| TokenTransferError::ParseAccountFailure)
+}
+
+pub fn refund_packet_token_execute(
+    ctx_a: &mut impl TokenTransferExecutionContext,
+    packet: &Packet,
+    data: &PacketDataV2,
+) -> Result<(), TokenTransferError> {
+    let sender = resolve_account(ctx_a, &data.sender)?;
+
+    for token in &data.aggregated_tokens()? {
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            // unescrow tokens back to sender
+            let escrow_address =
+                ctx_a.get_escrow_account(&packet.port_id_on_a, &packet.chan_id_on_a)?;
+
+            ctx_a.send_coins_execute(&escrow_address, &sender, token)?;
+        }
+        // mint vouchers back to sender
+        else {
+            ctx_a.mint_coins_execute(&sender, token)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn refund_packet_token_validate(
+    ctx_a: &impl TokenTransferValidationContext,
+    packet: &Packet,
+    data: &PacketDataV2,
+) -> Result<(), TokenTransferError> {
+    let sender = resolve_account(ctx_a, &data.sender)?;
+
+    for token in &data.aggregated_tokens()? {
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            let escrow_address =
+                ctx_a.get_escrow_account(&packet.port_id_on_a, &packet.chan_id_on_a)?;
+
+            ctx_a.send_coins_validate(&escrow_address, &sender, token)?;
+        } else {
+            ctx_a.mint_coins_validate(&sender, token)?;
+        }
+    }
+
+    Ok(())
+}
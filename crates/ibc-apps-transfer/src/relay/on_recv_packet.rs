@@ -0,0 +1,120 @@
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_router::router::ModuleExtras;
+use ibc_primitives::prelude::*;
+
+use crate::context::TokenTransferExecutionContext;
+use crate::error::TokenTransferError;
+use crate::events::DenomTraceEvent;
+use crate::packet_v2::PacketDataV2;
+use crate::relay::resolve_account;
+use crate::{is_receiver_chain_source, TracePrefix};
+
+/// This function handles the transfer receiving logic.
+///
+/// Note that `send/mint_coins_validate` steps are performed on the host chain
+/// to validate accounts and token info. But the result is then used for
+/// execution on the IBC side, including storing acknowledgements and emitting
+/// events.
+///
+/// The receipt of every token of the packet is validated before any of them
+/// is executed, so that the packets carrying several tokens are received
+/// entirely or not at all, the amounts of each denomination summed up so
+/// that together they cannot overdraw the escrow account they are released
+/// from. The packets forwarding their tokens further are not received, as
+/// forwarding is not supported.
+pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
+    ctx_b: &mut Ctx,
+    packet: &Packet,
+    data: PacketDataV2,
+) -> Result<ModuleExtras, (ModuleExtras, TokenTransferError)> {
+    ctx_b
+        .can_receive_coins()
+        .map_err(|err| (ModuleExtras::empty(), err))?;
+
+    if !data.forwarding.is_empty() {
+        return Err((
+            ModuleExtras::empty(),
+            TokenTransferError::ForwardingNotSupported,
+        ));
+    }
+
+    let tokens = data
+        .aggregated_tokens()
+        .map_err(|err| (ModuleExtras::empty(), err))?;
+
+    let receiver_account =
+        resolve_account(ctx_b, &data.receiver).map_err(|err| (ModuleExtras::empty(), err))?;
+
+    let mut extras = ModuleExtras::empty();
+    // The coins to credit to the receiver, along with the escrow account to
+    // unescrow them from, or `None` to mint them.
+    let mut receipts = Vec::with_capacity(tokens.len());
+
+    // Note: it is correct to do the validation here because `recv_packet()`
+    // works slightly differently. We do not have a
+    // `on_recv_packet_validate()` callback because regardless of whether or
+    // not the app succeeds to receive the packet, we want to run the
+    // `execute()` phase. And this is because the app failing to receive
+    // does not constitute a failure of the message processing.
+    // Specifically, when the app fails to receive, we need to return
+    // a `TokenTransferAcknowledgement::Error` acknowledgement, which
+    // gets relayed back to the sender so that the escrowed tokens
+    // can be refunded.
+    for token in tokens {
+        if is_receiver_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            // sender chain is not the source, unescrow tokens
+            let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
+            let coin = {
+                let mut c = token;
+                c.denom.remove_trace_prefix(&prefix);
+                c
+            };
+
+            let escrow_address = ctx_b
+                .get_escrow_account(&packet.port_id_on_b, &packet.chan_id_on_b)
+                .map_err(|token_err| (extras.clone(), token_err))?;
+
+            ctx_b
+                .send_coins_validate(&escrow_address, &receiver_account, &coin)
+                .map_err(|token_err| (extras.clone(), token_err))?;
+
+            receipts.push((Some(escrow_address), coin));
+        } else {
+            // sender chain is the source, mint vouchers
+            let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
+            let coin = {
+                let mut c = token;
+                c.denom.add_trace_prefix(prefix);
+                c
+            };
+
+            let denom_trace_event = DenomTraceEvent {
+                trace_hash: ctx_b.denom_hash_string(&coin.denom),
+                denom: coin.denom.clone(),
+            };
+            extras.events.push(denom_trace_event.into());
+
+            ctx_b
+                .mint_coins_validate(&receiver_account, &coin)
+                .map_err(|token_err| (extras.clone(), token_err))?;
+
+            receipts.push((None, coin));
+        }
+    }
+
+    for (escrow_address, coin) in receipts {
+        match escrow_address {
+            Some(escrow_address) => {
+                ctx_b.send_coins_execute(&escrow_address, &receiver_account, &coin)
+            }
+            None => ctx_b.mint_coins_execute(&receiver_account, &coin),
+        }
+        .map_err(|token_err| (extras.clone(), token_err))?;
+    }
+
+    Ok(extras)
+}
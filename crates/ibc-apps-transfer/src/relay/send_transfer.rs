@@ -1,18 +1,21 @@
-use crate::applications::transfer::context::{
-    TokenTransferExecutionContext, TokenTransferValidationContext,
-};
-use crate::applications::transfer::error::TokenTransferError;
-use crate::applications::transfer::events::TransferEvent;
-use crate::applications::transfer::msgs::transfer::MsgTransfer;
-use crate::applications::transfer::{is_sender_chain_source, MODULE_ID_STR};
-use crate::core::events::{MessageEvent, ModuleEvent};
-use crate::core::ics04_channel::context::{
-    SendPacketExecutionContext, SendPacketValidationContext,
-};
-use crate::core::ics04_channel::handler::send_packet::{send_packet_execute, send_packet_validate};
-use crate::core::ics04_channel::packet::Packet;
-use crate::core::ics24_host::path::{ChannelEndPath, SeqSendPath};
-use crate::prelude::*;
+use ibc_core_channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core_channel::handler::send_packet::{send_packet_execute, send_packet_validate};
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_handler_types::events::{MessageEvent, ModuleEvent};
+use ibc_core_handler_types::path::{ChannelEndPath, SeqSendPath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+
+use crate::authorization::{Acceptance, TransferAuthorization, TransferAuthorizationContext};
+use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use crate::error::TokenTransferError;
+use crate::events::TransferEvent;
+use crate::msgs::transfer::MsgTransfer;
+use crate::packet::PacketData;
+use crate::packet_v2::PacketDataV2;
+use crate::relay::resolve_account;
+use crate::{is_sender_chain_source, MODULE_ID_STR, VERSION_V2};
 
 /// Initiate a token transfer. Equivalent to calling [`send_transfer_validate`], followed by [`send_transfer_execute`].
 pub fn send_transfer<SendPacketCtx, TokenCtx>(
@@ -40,6 +43,15 @@ where
 {
     token_ctx_a.can_send_coins()?;
 
+    let memo_length = msg.packet_data.memo.as_ref().len();
+    let max_memo_length = token_ctx_a.max_memo_length();
+    if memo_length > max_memo_length {
+        return Err(TokenTransferError::MemoTooLong {
+            length: memo_length,
+            max_length: max_memo_length,
+        });
+    }
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -58,12 +70,7 @@ where
 
     let token = &msg.packet_data.token;
 
-    let sender: TokenCtx::AccountId = msg
-        .packet_data
-        .sender
-        .clone()
-        .try_into()
-        .map_err(|_| TokenTransferError::ParseAccountFailure)?;
+    let sender = resolve_account(token_ctx_a, &msg.packet_data.sender)?;
 
     if is_sender_chain_source(
         msg.port_id_on_a.clone(),
@@ -78,8 +85,7 @@ where
     }
 
     let packet = {
-        let data = serde_json::to_vec(&msg.packet_data)
-            .expect("PacketData's infallible Serialize impl failed");
+        let data = encode_packet_data(&chan_end_on_a, &msg.packet_data);
 
         Packet {
             seq_on_a: sequence,
@@ -127,12 +133,7 @@ where
 
     let token = &msg.packet_data.token;
 
-    let sender = msg
-        .packet_data
-        .sender
-        .clone()
-        .try_into()
-        .map_err(|_| TokenTransferError::ParseAccountFailure)?;
+    let sender = resolve_account(token_ctx_a, &msg.packet_data.sender)?;
 
     if is_sender_chain_source(
         msg.port_id_on_a.clone(),
@@ -147,10 +148,7 @@ where
     }
 
     let packet = {
-        let data = {
-            serde_json::to_vec(&msg.packet_data)
-                .expect("PacketData's infallible Serialize impl failed")
-        };
+        let data = encode_packet_data(&chan_end_on_a, &msg.packet_data);
 
         Packet {
             seq_on_a: sequence,
@@ -186,3 +184,90 @@ where
 
     Ok(())
 }
+
+/// Initiates a token transfer on behalf of its sender, who granted `grantee` a
+/// [`TransferAuthorization`] allowing it. Equivalent to calling [`send_granted_transfer_validate`],
+/// followed by [`send_granted_transfer_execute`].
+pub fn send_granted_transfer<SendPacketCtx, TokenCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    token_ctx_a: &mut TokenCtx,
+    grantee: &Signer,
+    msg: MsgTransfer,
+) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    TokenCtx: TokenTransferExecutionContext + TransferAuthorizationContext,
+{
+    send_granted_transfer_validate(send_packet_ctx_a, token_ctx_a, grantee, msg.clone())?;
+    send_granted_transfer_execute(send_packet_ctx_a, token_ctx_a, grantee, msg)
+}
+
+/// Validates the token transfer `grantee` initiates on behalf of its sender,
+/// which must be allowed by the authorization the sender granted it.
+pub fn send_granted_transfer_validate<SendPacketCtx, TokenCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    token_ctx_a: &TokenCtx,
+    grantee: &Signer,
+    msg: MsgTransfer,
+) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    TokenCtx: TokenTransferValidationContext + TransferAuthorizationContext,
+{
+    granted_authorization(token_ctx_a, grantee, &msg)?.accept(&msg)?;
+
+    send_transfer_validate(send_packet_ctx_a, token_ctx_a, msg)
+}
+
+/// Executes the token transfer `grantee` initiates on behalf of its sender,
+/// deducting it from the authorization the sender granted it. A prior call to
+/// [`send_granted_transfer_validate`] MUST have succeeded.
+pub fn send_granted_transfer_execute<SendPacketCtx, TokenCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    token_ctx_a: &mut TokenCtx,
+    grantee: &Signer,
+    msg: MsgTransfer,
+) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    TokenCtx: TokenTransferExecutionContext + TransferAuthorizationContext,
+{
+    let granter = &msg.packet_data.sender;
+
+    match granted_authorization(token_ctx_a, grantee, &msg)?.accept(&msg)? {
+        Acceptance::Updated(authorization) => {
+            token_ctx_a.store_transfer_authorization(granter, grantee, authorization)?
+        }
+        Acceptance::Exhausted => token_ctx_a.delete_transfer_authorization(granter, grantee)?,
+    }
+
+    send_transfer_execute(send_packet_ctx_a, token_ctx_a, msg)
+}
+
+/// Encodes the packet data of a transfer in the version of ICS-20 negotiated
+/// on its channel.
+fn encode_packet_data(chan_end_on_a: &ChannelEnd, packet_data: &PacketData) -> Vec<u8> {
+    if chan_end_on_a.version().as_str() == VERSION_V2 {
+        PacketDataV2::from(packet_data.clone()).to_json()
+    } else {
+        serde_json::to_vec(packet_data).expect("PacketData's infallible Serialize impl failed")
+    }
+}
+
+fn granted_authorization<TokenCtx>(
+    token_ctx_a: &TokenCtx,
+    grantee: &Signer,
+    msg: &MsgTransfer,
+) -> Result<TransferAuthorization, TokenTransferError>
+where
+    TokenCtx: TransferAuthorizationContext,
+{
+    let granter = &msg.packet_data.sender;
+
+    token_ctx_a
+        .transfer_authorization(granter, grantee)?
+        .ok_or_else(|| TokenTransferError::MissingTransferAuthorization {
+            granter: granter.clone(),
+            grantee: grantee.clone(),
+        })
+}
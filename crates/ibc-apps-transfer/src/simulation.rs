@@ -0,0 +1,220 @@
+//! Predicts the outcome of a token transfer without touching any state, e.g.
+//! for wallets to show users what they will receive.
+
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+
+use super::error::TokenTransferError;
+use super::msgs::transfer::MsgTransfer;
+use super::{is_sender_chain_source, PrefixedCoin, TracePrefix};
+
+/// The action taken on the sending chain when a transfer is initiated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SendAction {
+    /// The tokens are moved into the escrow account of the given channel, as
+    /// the sending chain is the source of the token.
+    Escrow {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// The vouchers are burnt, as the receiving chain is the source of the
+    /// token.
+    Burn,
+}
+
+/// The action taken on the receiving chain when the transfer packet is
+/// successfully received.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecvAction {
+    /// The tokens are released from the escrow account of the given channel,
+    /// as the receiving chain is the source of the token.
+    Unescrow {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// Vouchers are minted, as the sending chain is the source of the token.
+    Mint,
+}
+
+/// The predicted outcome of a token transfer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferSimulation {
+    /// The coin debited from the sender on the sending chain.
+    pub sent: PrefixedCoin,
+    pub send_action: SendAction,
+    /// The coin credited to the receiver on the receiving chain, whose denom
+    /// is the voucher denom if vouchers are minted.
+    pub received: PrefixedCoin,
+    pub recv_action: RecvAction,
+}
+
+/// Predicts the outcome of `msg` sent over the channel `chan_end_on_a`,
+/// assuming the packet is successfully received.
+///
+/// This follows the same rules as [`send_transfer`](super::send_transfer) on
+/// the sending chain and as the packet reception logic on the receiving chain,
+/// but does not check balances, send or receive permissions, or timeouts.
+pub fn simulate_transfer(
+    msg: &MsgTransfer,
+    chan_end_on_a: &ChannelEnd,
+) -> Result<TransferSimulation, TokenTransferError> {
+    let port_id_on_b = chan_end_on_a.counterparty().port_id().clone();
+    let chan_id_on_b = chan_end_on_a
+        .counterparty()
+        .channel_id()
+        .ok_or_else(|| TokenTransferError::DestinationChannelNotFound {
+            port_id: msg.port_id_on_a.clone(),
+            channel_id: msg.chan_id_on_a.clone(),
+        })?
+        .clone();
+
+    let sent = msg.packet_data.token.clone();
+    let mut received = sent.clone();
+
+    let (send_action, recv_action) = if is_sender_chain_source(
+        msg.port_id_on_a.clone(),
+        msg.chan_id_on_a.clone(),
+        &sent.denom,
+    ) {
+        received
+            .denom
+            .add_trace_prefix(TracePrefix::new(port_id_on_b, chan_id_on_b));
+
+        (
+            SendAction::Escrow {
+                port_id: msg.port_id_on_a.clone(),
+                channel_id: msg.chan_id_on_a.clone(),
+            },
+            RecvAction::Mint,
+        )
+    } else {
+        received.denom.remove_trace_prefix(&TracePrefix::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+        ));
+
+        (
+            SendAction::Burn,
+            RecvAction::Unescrow {
+                port_id: port_id_on_b,
+                channel_id: chan_id_on_b,
+            },
+        )
+    };
+
+    Ok(TransferSimulation {
+        sent,
+        send_action,
+        received,
+        recv_action,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_channel_types::channel::{Counterparty, Order, State};
+    use ibc_core_channel_types::timeout::TimeoutHeight;
+    use ibc_core_channel_types::version::Version;
+    use ibc_core_host_types::identifier::ConnectionId;
+    use ibc_primitives::prelude::*;
+    use ibc_primitives::timestamp::Timestamp;
+
+    use super::*;
+    use crate::packet::PacketData;
+
+    fn chan_end_on_a(chan_id_on_b: Option<ChannelId>) -> ChannelEnd {
+        ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), chan_id_on_b),
+            vec![ConnectionId::default()],
+            Version::new("ics20-1".to_string()),
+        )
+        .unwrap()
+    }
+
+    fn msg_transfer(denom: &str) -> MsgTransfer {
+        MsgTransfer {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            packet_data: PacketData {
+                token: format!("100{denom}").parse().unwrap(),
+                sender: "sender".to_string().into(),
+                receiver: "receiver".to_string().into(),
+                memo: "".to_string().into(),
+            },
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(1).unwrap(),
+        }
+    }
+
+    #[test]
+    fn native_token_is_escrowed_and_minted() {
+        let simulation = simulate_transfer(
+            &msg_transfer("uatom"),
+            &chan_end_on_a(Some(ChannelId::new(7))),
+        )
+        .unwrap();
+
+        assert_eq!(
+            simulation.send_action,
+            SendAction::Escrow {
+                port_id: PortId::transfer(),
+                channel_id: ChannelId::new(0),
+            }
+        );
+        assert_eq!(simulation.recv_action, RecvAction::Mint);
+        assert_eq!(
+            simulation.received,
+            "100transfer/channel-7/uatom".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn returning_voucher_is_burnt_and_unescrowed() {
+        let simulation = simulate_transfer(
+            &msg_transfer("transfer/channel-0/transfer/channel-3/uatom"),
+            &chan_end_on_a(Some(ChannelId::new(7))),
+        )
+        .unwrap();
+
+        assert_eq!(simulation.send_action, SendAction::Burn);
+        assert_eq!(
+            simulation.recv_action,
+            RecvAction::Unescrow {
+                port_id: PortId::transfer(),
+                channel_id: ChannelId::new(7),
+            }
+        );
+        assert_eq!(
+            simulation.received,
+            "100transfer/channel-3/uatom".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn voucher_from_another_chain_is_escrowed_and_minted() {
+        let simulation = simulate_transfer(
+            &msg_transfer("transfer/channel-3/uatom"),
+            &chan_end_on_a(Some(ChannelId::new(7))),
+        )
+        .unwrap();
+
+        assert!(matches!(simulation.send_action, SendAction::Escrow { .. }));
+        assert_eq!(simulation.recv_action, RecvAction::Mint);
+        assert_eq!(
+            simulation.received,
+            "100transfer/channel-7/transfer/channel-3/uatom"
+                .parse()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn missing_counterparty_channel_is_rejected() {
+        assert!(matches!(
+            simulate_transfer(&msg_transfer("uatom"), &chan_end_on_a(None)),
+            Err(TokenTransferError::DestinationChannelNotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,319 @@
+//! Benchmarks of the core handlers, built on the `ibc-testkit` fixtures.
+//!
+//! Each benchmark sets its context up outside of the measured routine, so that
+//! only the handler itself is timed.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use ibc::clients::ics07_tendermint::client_type as tm_client_type;
+use ibc::core::ics02_client::msgs::create_client::MsgCreateClient;
+use ibc::core::ics02_client::msgs::update_client::MsgUpdateClient;
+use ibc::core::ics02_client::msgs::ClientMsg;
+use ibc::core::ics02_client::ClientExecutionContext;
+use ibc::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::ics04_channel::commitment::compute_packet_commitment;
+use ibc::core::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+use ibc::core::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use ibc::core::ics04_channel::msgs::PacketMsg;
+use ibc::core::ics04_channel::Version;
+use ibc::core::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+use ibc::core::ics23_commitment::specs::ProofSpecs;
+use ibc::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
+use ibc::core::{execute, validate, ExecutionContext, MsgEnvelope};
+use ibc::proto::core::commitment::v1::MerkleProof as RawMerkleProof;
+use ibc::Height;
+use ibc_testkit::hosts::block::{HostBlock, HostType};
+use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::core::merkle::{membership_proof, multistore_root, MerkleStore};
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::utils::clients::tendermint::dummy_tm_client_state_from_header;
+use ibc_testkit::utils::core::channel::{dummy_raw_msg_acknowledgement, dummy_raw_msg_recv_packet};
+use ibc_testkit::utils::core::context::MockContextConfig;
+use ibc_testkit::utils::core::signer::dummy_account_id;
+use tendermint_testgen::Validator as TestgenValidator;
+
+/// Sizes of the validator sets signing the headers of `update_client`.
+const VALIDATOR_SET_SIZES: [usize; 4] = [4, 16, 64, 128];
+
+/// Numbers of entries in the stores proven against by `verify_membership`.
+const STORE_SIZES: [usize; 3] = [16, 256, 4096];
+
+fn validators(size: usize) -> Vec<TestgenValidator> {
+    (0..size)
+        .map(|i| TestgenValidator::new(&format!("{i}")).voting_power(50))
+        .collect()
+}
+
+/// Returns a context on chain A with a Tendermint client of chain B, whose
+/// blocks are signed by `validator_set_size` validators, together with the
+/// message updating that client from height 1 to height 2 of chain B.
+fn update_client_fixture(validator_set_size: usize) -> (MockContext, MsgEnvelope) {
+    let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let trusted_height = Height::new(1, 1).unwrap();
+    let update_height = Height::new(1, 2).unwrap();
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaB-1").unwrap())
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(update_height)
+        .max_history_size(2)
+        .validator_set_history(vec![validators(validator_set_size); 3])
+        .build();
+
+    let mut ctx_a: MockContext = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build();
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let trusted_block = ctx_b.host_block(&trusted_height).unwrap().clone();
+    let trusted_next_validators = match &trusted_block {
+        HostBlock::SyntheticTendermint(block) => block.light_block.next_validators.clone(),
+        HostBlock::Mock(_) => unreachable!("chain B is a synthetic Tendermint host"),
+    };
+    let client_state = match &trusted_block {
+        HostBlock::SyntheticTendermint(block) => {
+            dummy_tm_client_state_from_header(block.header().clone())
+        }
+        HostBlock::Mock(_) => unreachable!("chain B is a synthetic Tendermint host"),
+    };
+    let msg_create = MsgCreateClient::new(
+        client_state.into(),
+        AnyConsensusState::from(trusted_block).into(),
+        dummy_account_id(),
+    );
+    execute(
+        &mut ctx_a,
+        &mut router_a,
+        MsgEnvelope::from(ClientMsg::from(msg_create)),
+    )
+    .unwrap();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().clone();
+    block.set_trusted_height(trusted_height);
+    block.set_trusted_next_validators_set(trusted_next_validators);
+
+    let msg_update = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+
+    (ctx_a, MsgEnvelope::from(ClientMsg::from(msg_update)))
+}
+
+fn bench_update_client(c: &mut Criterion) {
+    let router = MockRouter::new_with_transfer();
+    let mut group = c.benchmark_group("update_client");
+
+    for size in VALIDATOR_SET_SIZES {
+        let (ctx, msg) = update_client_fixture(size);
+        validate(&ctx, &router, msg.clone()).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("validate", size), &msg, |b, msg| {
+            b.iter_batched(
+                || msg.clone(),
+                |msg| validate(&ctx, &router, msg).unwrap(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+fn conn_end() -> ConnectionEnd {
+    ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap()
+}
+
+fn chan_end(port_id: PortId, channel_id: ChannelId) -> ChannelEnd {
+    ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(port_id, Some(channel_id)),
+        vec![ConnectionId::default()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap()
+}
+
+/// Records that the mock client was updated to `client_height` in the latest
+/// block, as the packet handlers check the delay period of the connection
+/// against it.
+fn store_client_update(ctx: &mut MockContext, client_height: Height) {
+    let host_height = ctx.latest_height();
+    ctx.get_client_execution_context()
+        .store_update_time(
+            ClientId::default(),
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+        )
+        .unwrap();
+    ctx.get_client_execution_context()
+        .store_update_height(ClientId::default(), client_height, host_height)
+        .unwrap();
+}
+
+/// Returns a context on chain B with an open channel to chain A, together with
+/// the message receiving a packet on it.
+fn recv_packet_fixture() -> (MockContext, MsgEnvelope) {
+    let ctx = MockContext::default();
+    let host_height = ctx.latest_height().increment();
+    let client_height = host_height.increment();
+
+    let msg = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(client_height.revision_height()))
+        .unwrap();
+    let packet = &msg.packet;
+
+    let mut ctx = ctx
+        .with_client(&ClientId::default(), client_height)
+        .with_connection(ConnectionId::default(), conn_end())
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone()),
+        )
+        .with_height(host_height);
+    store_client_update(&mut ctx, client_height);
+
+    (ctx, MsgEnvelope::from(PacketMsg::from(msg)))
+}
+
+/// Returns a context on chain A with an open channel to chain B and the
+/// commitment of a packet sent on it, together with the message acknowledging
+/// that packet.
+fn ack_packet_fixture() -> (MockContext, MsgEnvelope) {
+    let client_height = Height::new(0, 2).unwrap();
+
+    let msg = MsgAcknowledgement::try_from(dummy_raw_msg_acknowledgement(
+        client_height.revision_height(),
+    ))
+    .unwrap();
+    let packet = &msg.packet;
+    let packet_commitment = compute_packet_commitment(
+        &packet.data,
+        &packet.timeout_height_on_b,
+        &packet.timeout_timestamp_on_b,
+    );
+
+    let mut ctx = MockContext::default()
+        .with_client(&ClientId::default(), client_height)
+        .with_connection(ConnectionId::default(), conn_end())
+        .with_channel(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            chan_end(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone()),
+        )
+        .with_packet_commitment(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+            packet_commitment,
+        );
+    store_client_update(&mut ctx, client_height);
+
+    (ctx, MsgEnvelope::from(PacketMsg::from(msg)))
+}
+
+/// Measures the validation and the execution of the message built by
+/// `fixture`. Execution runs on a fresh context each time, since it consumes
+/// the state the message acts upon.
+fn bench_packet_handler(
+    c: &mut Criterion,
+    name: &str,
+    fixture: fn() -> (MockContext, MsgEnvelope),
+) {
+    let mut group = c.benchmark_group(name);
+
+    let (ctx, msg) = fixture();
+    let router = MockRouter::new_with_transfer();
+    validate(&ctx, &router, msg.clone()).unwrap();
+    group.bench_function("validate", |b| {
+        b.iter_batched(
+            || msg.clone(),
+            |msg| validate(&ctx, &router, msg).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("execute", |b| {
+        b.iter_batched(
+            || (fixture(), MockRouter::new_with_transfer()),
+            |((mut ctx, msg), mut router)| execute(&mut ctx, &mut router, msg).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn bench_recv_packet(c: &mut Criterion) {
+    bench_packet_handler(c, "recv_packet", recv_packet_fixture);
+}
+
+fn bench_ack_packet(c: &mut Criterion) {
+    bench_packet_handler(c, "ack_packet", ack_packet_fixture);
+}
+
+/// Measures the verification of the ICS-23 proof that a key is in a store of
+/// increasing size, laid out as the one of a Cosmos-SDK chain.
+fn bench_verify_membership(c: &mut Criterion) {
+    let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+    let specs = ProofSpecs::cosmos();
+    let mut group = c.benchmark_group("verify_membership");
+
+    for size in STORE_SIZES {
+        let mut store = MerkleStore::new();
+        for i in 0..size {
+            store.set(format!("key-{i:08}"), format!("value-{i}").into_bytes());
+        }
+
+        let key = format!("key-{:08}", size / 2);
+        let value = store.get(key.as_bytes()).unwrap().to_vec();
+        let root = multistore_root(prefix.as_bytes(), &store.root());
+        let proof: CommitmentProofBytes =
+            membership_proof(&store, prefix.as_bytes(), key.as_bytes()).unwrap();
+        let proof = MerkleProof::from(RawMerkleProof::try_from(proof).unwrap());
+        let path = apply_prefix(&prefix, vec![key]);
+
+        proof
+            .verify_membership(&specs, root.clone().into(), path.clone(), value.clone(), 0)
+            .unwrap();
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| {
+                proof
+                    .verify_membership(&specs, root.clone().into(), path.clone(), value.clone(), 0)
+                    .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    handlers,
+    bench_update_client,
+    bench_recv_packet,
+    bench_ack_packet,
+    bench_verify_membership
+);
+criterion_main!(handlers);
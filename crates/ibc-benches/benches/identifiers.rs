@@ -0,0 +1,78 @@
+//! Benchmarks of the parsing of identifiers and of the indexing of packets by
+//! them, which the `inline-identifiers` feature stores without allocating.
+//!
+//! Running them with and without the feature, saving a baseline in between,
+//! compares the inline identifiers against the `String`-backed ones.
+
+use core::str::FromStr;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::ics04_channel::timeout_queue::{PendingTimeout, TimeoutQueue, TimeoutQueueContext};
+#[cfg(feature = "inline-identifiers")]
+use ibc::core::ics24_host::identifier::inline::{InlineChannelId, InlinePortId};
+use ibc::core::ics24_host::identifier::{ChannelId, PortId};
+use ibc::core::timestamp::Timestamp;
+use ibc::Height;
+
+/// Numbers of packets recorded in the timeout queue.
+const QUEUE_SIZES: [u64; 3] = [16, 256, 4096];
+
+fn bench_parse_identifiers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_identifiers");
+
+    group.bench_function("string", |b| {
+        b.iter(|| {
+            (
+                PortId::from_str(black_box("transfer")).unwrap(),
+                ChannelId::from_str(black_box("channel-7")).unwrap(),
+            )
+        })
+    });
+    #[cfg(feature = "inline-identifiers")]
+    group.bench_function("inline", |b| {
+        b.iter(|| {
+            (
+                InlinePortId::from_str(black_box("transfer")).unwrap(),
+                InlineChannelId::from_str(black_box("channel-7")).unwrap(),
+            )
+        })
+    });
+
+    group.finish();
+}
+
+/// Enqueues `size` packets sent on a few channels, then pops them all as
+/// expired, as a host tracking the timeouts of the packets it sends does.
+fn bench_timeout_queue(c: &mut Criterion) {
+    let mut group = c.benchmark_group("timeout_queue");
+
+    for size in QUEUE_SIZES {
+        let timeouts: Vec<_> = (1..=size)
+            .map(|seq| PendingTimeout {
+                port_id_on_a: PortId::transfer(),
+                chan_id_on_a: ChannelId::new(seq % 4),
+                seq_on_a: seq.into(),
+                timeout_height_on_b: TimeoutHeight::At(Height::new(0, seq).unwrap()),
+                timeout_timestamp_on_b: Timestamp::none(),
+            })
+            .collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            b.iter(|| {
+                let mut queue = TimeoutQueue::new();
+                for timeout in &timeouts {
+                    queue.enqueue_timeout(timeout.clone()).unwrap();
+                }
+                let expired =
+                    queue.pop_expired(Height::new(0, size + 1).unwrap(), &Timestamp::none());
+                assert_eq!(expired.len() as u64, size);
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(identifiers, bench_parse_identifiers, bench_timeout_queue);
+criterion_main!(identifiers);
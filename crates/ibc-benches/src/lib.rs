@@ -0,0 +1,3 @@
+//! Benchmarks of the IBC core handlers, run with `cargo bench -p ibc-benches`.
+//!
+//! The benchmarks live under `benches/`; this crate exposes nothing.
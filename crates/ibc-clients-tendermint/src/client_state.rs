@@ -1,4 +1,4 @@
-//! Implements the core [`ClientState`](crate::core::ics02_client::client_state::ClientState) trait
+//! Implements the core [`ClientState`](ibc_core_client_context::client_state::ClientState) trait
 //! for the Tendermint light client.
 
 mod misbehaviour;
@@ -9,9 +9,31 @@ use core::convert::{TryFrom, TryInto};
 use core::str::FromStr;
 use core::time::Duration;
 
+use ibc_core_client_context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc_core_client_context::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::{ClientError, UpgradeClientError};
+use ibc_core_client_types::height::Height;
+use ibc_core_client_types::msgs::UpdateKind;
+use ibc_core_client_types::status::Status;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_commitment_types::merkle::{apply_prefix, MerkleProof, ProofLimits};
+use ibc_core_commitment_types::specs::ProofSpecs;
+use ibc_core_handler_types::path::{
+    ClientConsensusStatePath, ClientStatePath, Path, UpgradeClientPath,
+};
+use ibc_core_host::context::ExecutionContext;
+use ibc_core_host_types::client_type::ClientType;
+use ibc_core_host_types::identifier::{ChainId, ClientId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::ZERO_DURATION;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
-use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use ibc_proto::ibc::core::commitment::v1::MerklePath;
 use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
 use ibc_proto::Protobuf;
 use prost::Message;
@@ -25,31 +47,12 @@ use super::{
     client_type as tm_client_type, ExecutionContext as TmExecutionContext,
     ValidationContext as TmValidationContext,
 };
-use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
-use crate::clients::ics07_tendermint::error::Error;
-use crate::clients::ics07_tendermint::header::Header as TmHeader;
-use crate::clients::ics07_tendermint::misbehaviour::Misbehaviour as TmMisbehaviour;
-use crate::clients::ics07_tendermint::CommonContext;
-use crate::core::ics02_client::client_state::{
-    ClientStateCommon, ClientStateExecution, ClientStateValidation, Status, UpdateKind,
-};
-use crate::core::ics02_client::client_type::ClientType;
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::{ClientError, UpgradeClientError};
-use crate::core::ics02_client::{ClientExecutionContext, ClientValidationContext};
-use crate::core::ics23_commitment::commitment::{
-    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
-};
-use crate::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
-use crate::core::ics23_commitment::specs::ProofSpecs;
-use crate::core::ics24_host::identifier::{ChainId, ClientId};
-use crate::core::ics24_host::path::{
-    ClientConsensusStatePath, ClientStatePath, Path, UpgradeClientPath,
-};
-use crate::core::timestamp::ZERO_DURATION;
-use crate::core::ExecutionContext;
-use crate::prelude::*;
-use crate::Height;
+use crate::consensus_state::ConsensusState as TmConsensusState;
+use crate::error::Error;
+use crate::header::Header as TmHeader;
+use crate::header_batch::{HeaderBatch, TENDERMINT_HEADER_BATCH_TYPE_URL};
+use crate::misbehaviour::{Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOUR_TYPE_URL};
+use crate::CommonContext;
 
 pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
 
@@ -76,6 +79,8 @@ pub struct ClientState {
     frozen_height: Option<Height>,
     #[cfg_attr(feature = "serde", serde(skip))]
     verifier: ProdVerifier,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    proof_limits: ProofLimits,
 }
 
 impl ClientState {
@@ -103,6 +108,7 @@ impl ClientState {
             allow_update,
             frozen_height: None,
             verifier: ProdVerifier::default(),
+            proof_limits: ProofLimits::default(),
         }
     }
 
@@ -147,6 +153,24 @@ impl ClientState {
         }
     }
 
+    /// Sets the limits on the size and number of operations of the proofs
+    /// verified by the client, which are not part of its encoding.
+    ///
+    /// Client states are decoded with [`ProofLimits::default`], so hosts
+    /// accepting other proofs set their own limits wherever they decode the
+    /// client states, i.e. in `ValidationContext::client_state` and
+    /// `ValidationContext::decode_client_state`.
+    pub fn with_proof_limits(self, proof_limits: ProofLimits) -> Self {
+        Self {
+            proof_limits,
+            ..self
+        }
+    }
+
+    pub fn proof_limits(&self) -> &ProofLimits {
+        &self.proof_limits
+    }
+
     pub fn validate(&self) -> Result<(), Error> {
         self.chain_id.validate_length(3, MaxChainIdLen as u64)?;
 
@@ -211,18 +235,7 @@ impl ClientState {
             });
         }
 
-        // `upgrade_path` itself may be empty, but if not then each key must be non-empty
-        for (idx, key) in self.upgrade_path.iter().enumerate() {
-            if key.trim().is_empty() {
-                return Err(Error::Validation {
-                    reason: format!(
-                        "ClientState upgrade-path key at index {idx:?} cannot be empty"
-                    ),
-                });
-            }
-        }
-
-        Ok(())
+        validate_upgrade_path(&self.upgrade_path)
     }
 
     /// Get the refresh time to ensure the state does not expire
@@ -261,6 +274,65 @@ impl ClientState {
         self.frozen_height = None;
         self.max_clock_drift = ZERO_DURATION;
     }
+
+    /// Returns the client state a chain commits under its upgrade path ahead
+    /// of a planned upgrade, for the upgraded chain to start at
+    /// `upgraded_height`.
+    ///
+    /// If the upgrade moves the chain to a new revision, the chain identifier
+    /// takes on the revision number of `upgraded_height` while keeping its
+    /// chain name. The custom fields are zeroed, as they are carried over from
+    /// the upgrading client.
+    pub fn upgraded_client_state(&self, upgraded_height: Height) -> Result<Self, ClientError> {
+        if upgraded_height <= self.latest_height {
+            return Err(UpgradeClientError::LowUpgradeHeight {
+                upgraded_height,
+                client_height: self.latest_height,
+            })?;
+        }
+
+        let chain_id = if upgraded_height.revision_number() == self.chain_id.revision_number() {
+            self.chain_id.clone()
+        } else {
+            self.chain_id
+                .with_revision_number(upgraded_height.revision_number())
+                .map_err(|e| UpgradeClientError::Other {
+                    reason: e.to_string(),
+                })?
+        };
+
+        let mut upgraded_client_state = Self {
+            chain_id,
+            latest_height: upgraded_height,
+            ..self.clone()
+        };
+        upgraded_client_state.zero_custom_fields();
+
+        Ok(upgraded_client_state)
+    }
+
+    /// Verifies that `value` is committed at `merkle_path` in the store of
+    /// root `root`.
+    fn verify_merkle_membership(
+        &self,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        merkle_path: MerklePath,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let merkle_proof = MerkleProof::decode(proof, &self.proof_limits)
+            .map_err(ClientError::InvalidCommitmentProof)?;
+
+        merkle_proof
+            .verify_membership(
+                &self.proof_specs,
+                root.clone().into(),
+                merkle_path,
+                value,
+                0,
+            )
+            .map_err(ClientError::Ics23Verification)
+    }
 }
 
 impl ClientStateCommon for ClientState {
@@ -314,6 +386,26 @@ impl ClientStateCommon for ClientState {
         // Make sure that the consensus type is of Tendermint type `ConsensusState`
         TmConsensusState::try_from(upgraded_consensus_state.clone())?;
 
+        // The upgraded chain may move to a new revision, but must keep its
+        // chain name, and its latest height must be at the revision of its new
+        // chain identifier, as the upgraded client state is stored without
+        // being validated
+        if upgraded_tm_client_state.chain_id.chain_name() != self.chain_id.chain_name() {
+            return Err(UpgradeClientError::ChainNameMismatch {
+                chain_id: self.chain_id.clone(),
+                upgraded_chain_id: upgraded_tm_client_state.chain_id,
+            })?;
+        }
+
+        if upgraded_tm_client_state.latest_height.revision_number()
+            != upgraded_tm_client_state.chain_id.revision_number()
+        {
+            return Err(UpgradeClientError::RevisionMismatch {
+                upgraded_chain_id: upgraded_tm_client_state.chain_id,
+                upgraded_height: upgraded_tm_client_state.latest_height,
+            })?;
+        }
+
         // Make sure the latest height of the current client is not greater then
         // the upgrade height This condition checks both the revision number and
         // the height
@@ -324,30 +416,29 @@ impl ClientStateCommon for ClientState {
             })?;
         }
 
-        // Check to see if the upgrade path is set
-        let mut upgrade_path = self.upgrade_path.clone();
-        if upgrade_path.pop().is_none() {
-            return Err(ClientError::ClientSpecific {
-                description: "cannot upgrade client as no upgrade path has been set".to_string(),
-            });
-        };
-
-        let upgrade_path_prefix = CommitmentPrefix::try_from(upgrade_path[0].clone().into_bytes())
-            .map_err(ClientError::InvalidCommitmentProof)?;
-
         let last_height = self.latest_height().revision_height();
 
+        // Check to see if the upgrade path is set, and build the keys of the
+        // upgraded states under it
+        let upgraded_client_state_path = upgrade_client_merkle_path(
+            &self.upgrade_path,
+            &UpgradeClientPath::UpgradedClientState(last_height),
+        )?;
+        let upgraded_consensus_state_path = upgrade_client_merkle_path(
+            &self.upgrade_path,
+            &UpgradeClientPath::UpgradedClientConsensusState(last_height),
+        )?;
+
         let mut client_state_value = Vec::new();
         upgraded_client_state
             .encode(&mut client_state_value)
             .map_err(ClientError::Encode)?;
 
         // Verify the proof of the upgraded client state
-        self.verify_membership(
-            &upgrade_path_prefix,
+        self.verify_merkle_membership(
             &proof_upgrade_client,
             root,
-            Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(last_height)),
+            upgraded_client_state_path,
             client_state_value,
         )?;
 
@@ -357,11 +448,10 @@ impl ClientStateCommon for ClientState {
             .map_err(ClientError::Encode)?;
 
         // Verify the proof of the upgraded consensus state
-        self.verify_membership(
-            &upgrade_path_prefix,
+        self.verify_merkle_membership(
             &proof_upgrade_consensus_state,
             root,
-            Path::UpgradeClient(UpgradeClientPath::UpgradedClientConsensusState(last_height)),
+            upgraded_consensus_state_path,
             cons_state_value,
         )?;
 
@@ -377,19 +467,8 @@ impl ClientStateCommon for ClientState {
         value: Vec<u8>,
     ) -> Result<(), ClientError> {
         let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
-        let merkle_proof: MerkleProof = RawMerkleProof::try_from(proof.clone())
-            .map_err(ClientError::InvalidCommitmentProof)?
-            .into();
 
-        merkle_proof
-            .verify_membership(
-                &self.proof_specs,
-                root.clone().into(),
-                merkle_path,
-                value,
-                0,
-            )
-            .map_err(ClientError::Ics23Verification)
+        self.verify_merkle_membership(proof, root, merkle_path, value)
     }
 
     fn verify_non_membership(
@@ -400,14 +479,39 @@ impl ClientStateCommon for ClientState {
         path: Path,
     ) -> Result<(), ClientError> {
         let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
-        let merkle_proof: MerkleProof = RawMerkleProof::try_from(proof.clone())
-            .map_err(ClientError::InvalidCommitmentProof)?
-            .into();
+        let merkle_proof = MerkleProof::decode(proof, &self.proof_limits)
+            .map_err(ClientError::InvalidCommitmentProof)?;
 
         merkle_proof
             .verify_non_membership(&self.proof_specs, root.clone().into(), merkle_path)
             .map_err(ClientError::Ics23Verification)
     }
+
+    fn evidence_heights(&self, client_message: &Any) -> Vec<Height> {
+        if let Ok(misbehaviour) = TmMisbehaviour::try_from(client_message.clone()) {
+            vec![
+                misbehaviour.header1().height(),
+                misbehaviour.header2().height(),
+            ]
+        } else if let Ok(batch) = HeaderBatch::try_from(client_message.clone()) {
+            batch.headers().iter().map(TmHeader::height).collect()
+        } else if let Ok(header) = TmHeader::try_from(client_message.clone()) {
+            vec![header.height()]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn update_kind(&self, client_message: &Any) -> UpdateKind {
+        match client_message.type_url.as_str() {
+            TENDERMINT_MISBEHAVIOUR_TYPE_URL => UpdateKind::SubmitMisbehaviour,
+            _ => UpdateKind::UpdateClient,
+        }
+    }
+
+    fn counterparty_chain_id(&self) -> Option<ChainId> {
+        Some(self.chain_id())
+    }
 }
 
 impl<V> ClientStateValidation<V> for ClientState
@@ -424,10 +528,16 @@ where
         update_kind: &UpdateKind,
     ) -> Result<(), ClientError> {
         match update_kind {
-            UpdateKind::UpdateClient => {
-                let header = TmHeader::try_from(client_message)?;
-                self.verify_header(ctx, client_id, header)
-            }
+            UpdateKind::UpdateClient => match client_message.type_url.as_str() {
+                TENDERMINT_HEADER_BATCH_TYPE_URL => {
+                    let batch = HeaderBatch::try_from(client_message)?;
+                    self.verify_header_batch(ctx, client_id, batch)
+                }
+                _ => {
+                    let header = TmHeader::try_from(client_message)?;
+                    self.verify_header(ctx, client_id, header)
+                }
+            },
             UpdateKind::SubmitMisbehaviour => {
                 let misbehaviour = TmMisbehaviour::try_from(client_message)?;
                 self.verify_misbehaviour(ctx, client_id, misbehaviour)
@@ -443,10 +553,16 @@ where
         update_kind: &UpdateKind,
     ) -> Result<bool, ClientError> {
         match update_kind {
-            UpdateKind::UpdateClient => {
-                let header = TmHeader::try_from(client_message)?;
-                self.check_for_misbehaviour_update_client(ctx, client_id, header)
-            }
+            UpdateKind::UpdateClient => match client_message.type_url.as_str() {
+                TENDERMINT_HEADER_BATCH_TYPE_URL => {
+                    let batch = HeaderBatch::try_from(client_message)?;
+                    self.check_for_misbehaviour_header_batch(ctx, client_id, batch)
+                }
+                _ => {
+                    let header = TmHeader::try_from(client_message)?;
+                    self.check_for_misbehaviour_update_client(ctx, client_id, header)
+                }
+            },
             UpdateKind::SubmitMisbehaviour => {
                 let misbehaviour = TmMisbehaviour::try_from(client_message)?;
                 self.check_for_misbehaviour_misbehavior(&misbehaviour)
@@ -486,6 +602,36 @@ where
 
         Ok(Status::Active)
     }
+
+    /// Returns the time left until the consensus state at the latest height
+    /// of the client falls out of its trusting period, as seen from the host.
+    fn remaining_trusting_period(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+    ) -> Result<Option<Duration>, ClientError> {
+        let latest_consensus_state: TmConsensusState = {
+            let any_latest_consensus_state = match ctx.consensus_state(
+                &ClientConsensusStatePath::new(client_id, &self.latest_height),
+            ) {
+                Ok(cs) => cs,
+                // as in `status()`, a client without a consensus state for its
+                // latest height has expired
+                Err(_) => return Ok(Some(ZERO_DURATION)),
+            };
+
+            any_latest_consensus_state.try_into()?
+        };
+
+        // Note: a latest consensus state in the future leaves the whole
+        // trusting period.
+        let elapsed = ctx
+            .host_timestamp()?
+            .duration_since(&latest_consensus_state.timestamp())
+            .unwrap_or(ZERO_DURATION);
+
+        Ok(Some(self.trusting_period.saturating_sub(elapsed)))
+    }
 }
 
 impl<E> ClientStateExecution<E> for ClientState
@@ -522,6 +668,11 @@ where
         client_id: &ClientId,
         header: Any,
     ) -> Result<Vec<Height>, ClientError> {
+        if header.type_url == TENDERMINT_HEADER_BATCH_TYPE_URL {
+            let batch = HeaderBatch::try_from(header)?;
+            return self.update_state_with_header_batch(ctx, client_id, batch);
+        }
+
         let header = TmHeader::try_from(header)?;
         let header_height = header.height();
 
@@ -775,6 +926,53 @@ impl From<ClientState> for Any {
     }
 }
 
+/// Checks the upgrade path of a client, i.e. the keys under which the chain
+/// commits its upgraded client and consensus states, e.g. `["upgrade",
+/// "upgradedIBCState"]` on Cosmos SDK chains.
+///
+/// The upgrade path may be empty, if the chain never upgrades, but if not then
+/// each key must be non-empty.
+pub fn validate_upgrade_path(upgrade_path: &[String]) -> Result<(), Error> {
+    for (idx, key) in upgrade_path.iter().enumerate() {
+        if key.trim().is_empty() {
+            return Err(Error::Validation {
+                reason: format!("ClientState upgrade-path key at index {idx:?} cannot be empty"),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the Merkle path of `path` under the given upgrade path: all of its
+/// keys but the last one are those of the stores the upgraded states are
+/// committed in, while the last one stands for [`UPGRADED_IBC_STATE`] in
+/// the key of `path`.
+///
+/// For instance, the upgraded client state at height 5 is committed at
+/// `["upgrade", "upgradedIBCState/5/upgradedClient"]` on Cosmos SDK chains,
+/// whose upgrade path is `["upgrade", "upgradedIBCState"]`.
+///
+/// [`UPGRADED_IBC_STATE`]: ibc_core_handler_types::storage::UPGRADED_IBC_STATE
+pub fn upgrade_client_merkle_path(
+    upgrade_path: &[String],
+    path: &UpgradeClientPath,
+) -> Result<MerklePath, ClientError> {
+    validate_upgrade_path(upgrade_path)?;
+
+    let (upgrade_key, store_keys) =
+        upgrade_path
+            .split_last()
+            .ok_or_else(|| ClientError::ClientSpecific {
+                description: "cannot upgrade client as no upgrade path has been set".to_string(),
+            })?;
+
+    let mut key_path = store_keys.to_vec();
+    key_path.push(path.key_under(upgrade_key));
+
+    Ok(MerklePath { key_path })
+}
+
 // `header.trusted_validator_set` was given to us by the relayer. Thus, we
 // need to ensure that the relayer gave us the right set, i.e. by ensuring
 // that it matches the hash we have stored on chain.
@@ -796,7 +994,7 @@ fn check_header_trusted_next_validator_set(
 mod serde_tests {
     use tendermint_rpc::endpoint::abci_query::AbciQuery;
 
-    use crate::serializers::tests::test_serialization_roundtrip;
+    use crate::tests::test_serialization_roundtrip;
     #[test]
     fn serialization_roundtrip_no_proof() {
         let json_data = include_str!(concat!(
@@ -821,6 +1019,10 @@ mod tests {
     use core::str::FromStr;
     use core::time::Duration;
 
+    use ibc_core_client_types::height::Height;
+    use ibc_core_commitment_types::specs::ProofSpecs;
+    use ibc_core_host_types::identifier::ChainId;
+    use ibc_primitives::timestamp::ZERO_DURATION;
     use ibc_proto::google::protobuf::Any;
     use ibc_proto::ibc::core::client::v1::Height as RawHeight;
     use ibc_proto::ibc::lightclients::tendermint::v1::{ClientState as RawTmClientState, Fraction};
@@ -830,12 +1032,8 @@ mod tests {
     use test_log::test;
 
     use super::*;
-    use crate::clients::ics07_tendermint::client_state::{AllowUpdate, ClientState};
-    use crate::clients::ics07_tendermint::error::Error;
-    use crate::core::ics02_client::height::Height;
-    use crate::core::ics23_commitment::specs::ProofSpecs;
-    use crate::core::ics24_host::identifier::ChainId;
-    use crate::core::timestamp::ZERO_DURATION;
+    use crate::client_state::{AllowUpdate, ClientState};
+    use crate::error::Error;
 
     impl ClientState {
         pub fn new_dummy_from_raw(frozen_height: RawHeight) -> Result<Self, Error> {
@@ -1167,6 +1365,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_upgrade_client_merkle_path() {
+        let path = UpgradeClientPath::UpgradedClientState(5);
+
+        let merkle_path = upgrade_client_merkle_path(
+            &["upgrade".to_string(), "upgradedIBCState".to_string()],
+            &path,
+        )
+        .unwrap();
+        assert_eq!(
+            merkle_path.key_path,
+            ["upgrade", "upgradedIBCState/5/upgradedClient"]
+        );
+
+        // custom upgrade store keys
+        let merkle_path = upgrade_client_merkle_path(
+            &[
+                "chain".to_string(),
+                "upgrades".to_string(),
+                "ibc".to_string(),
+            ],
+            &UpgradeClientPath::UpgradedClientConsensusState(5),
+        )
+        .unwrap();
+        assert_eq!(
+            merkle_path.key_path,
+            ["chain", "upgrades", "ibc/5/upgradedConsState"]
+        );
+
+        assert!(upgrade_client_merkle_path(&[], &path).is_err());
+        assert!(
+            upgrade_client_merkle_path(&["upgrade".to_string(), " ".to_string()], &path).is_err()
+        );
+    }
+
     #[test]
     fn tm_client_state_malformed_with_frozen_height() {
         let tm_client_state_from_raw = ClientState::new_dummy_from_raw(RawHeight {
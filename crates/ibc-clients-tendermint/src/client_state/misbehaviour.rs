@@ -1,17 +1,17 @@
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_handler_types::path::ClientConsensusStatePath;
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
 use tendermint_light_client_verifier::Verifier;
 
 use super::{check_header_trusted_next_validator_set, ClientState};
-use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
-use crate::clients::ics07_tendermint::error::{Error, IntoResult};
-use crate::clients::ics07_tendermint::header::Header as TmHeader;
-use crate::clients::ics07_tendermint::misbehaviour::Misbehaviour as TmMisbehaviour;
-use crate::clients::ics07_tendermint::ValidationContext as TmValidationContext;
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics24_host::identifier::ClientId;
-use crate::core::ics24_host::path::ClientConsensusStatePath;
-use crate::core::timestamp::Timestamp;
-use crate::prelude::*;
+use crate::consensus_state::ConsensusState as TmConsensusState;
+use crate::error::{Error, IntoResult};
+use crate::header::Header as TmHeader;
+use crate::misbehaviour::Misbehaviour as TmMisbehaviour;
+use crate::ValidationContext as TmValidationContext;
 
 impl ClientState {
     // verify_misbehaviour determines whether or not two conflicting headers at
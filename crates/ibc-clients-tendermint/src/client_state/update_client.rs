@@ -0,0 +1,390 @@
+use ibc_core_client_context::context::ClientExecutionContext;
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::prelude::*;
+use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
+use tendermint_light_client_verifier::Verifier;
+
+use super::{check_header_trusted_next_validator_set, ClientState};
+use crate::consensus_state::ConsensusState as TmConsensusState;
+use crate::error::{Error, IntoResult};
+use crate::header::Header as TmHeader;
+use crate::header_batch::HeaderBatch;
+use crate::{
+    CommonContext, ExecutionContext as TmExecutionContext, ValidationContext as TmValidationContext,
+};
+
+impl ClientState {
+    pub fn verify_header<ClientValidationContext>(
+        &self,
+        ctx: &ClientValidationContext,
+        client_id: &ClientId,
+        header: TmHeader,
+    ) -> Result<(), ClientError>
+    where
+        ClientValidationContext: TmValidationContext,
+    {
+        let trusted_client_cons_state_path =
+            ClientConsensusStatePath::new(client_id, &header.trusted_height);
+        let (trusted_height, trusted_consensus_state) =
+            match ctx.consensus_state(&trusted_client_cons_state_path) {
+                Ok(cs) => (
+                    header.trusted_height,
+                    cs.try_into().map_err(|err| ClientError::Other {
+                        description: err.to_string(),
+                    })?,
+                ),
+                // Falls back to the consensus state the header can best be
+                // verified against, which holds as long as the trusted
+                // validator set of the header is the one it commits to.
+                Err(ContextError::ClientError(ClientError::ConsensusStateNotFound { .. })) => self
+                    .select_trusted_consensus_state(ctx, client_id, header.height())?
+                    .ok_or(Error::MissingTrustedConsensusState {
+                        trusted_height: header.trusted_height,
+                        height: header.height(),
+                    })?,
+                Err(err) => return Err(err.into()),
+            };
+
+        self.verify_header_against(ctx, &header, trusted_height, &trusted_consensus_state)
+    }
+
+    /// Selects the consensus state to trust when verifying a header at
+    /// `target_height`: the one stored at the highest height below
+    /// `target_height`, within the same revision, whose timestamp is still
+    /// within the trusting period as seen from the host.
+    ///
+    /// Returns the height and consensus state selected, or `None` if no
+    /// consensus state qualifies, in which case no header at `target_height`
+    /// can update the client.
+    pub fn select_trusted_consensus_state<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        target_height: Height,
+    ) -> Result<Option<(Height, TmConsensusState)>, ClientError>
+    where
+        V: CommonContext,
+    {
+        let mut heights: Vec<Height> = ctx
+            .consensus_state_heights(client_id)?
+            .into_iter()
+            .filter(|height| {
+                height.revision_number() == target_height.revision_number()
+                    && *height < target_height
+            })
+            .collect();
+        heights.sort_unstable_by(|lhs, rhs| rhs.cmp(lhs));
+
+        let now = ctx.host_timestamp()?;
+        for height in heights {
+            let consensus_state: TmConsensusState = ctx
+                .consensus_state(&ClientConsensusStatePath::new(client_id, &height))?
+                .try_into()
+                .map_err(|err| ClientError::Other {
+                    description: err.to_string(),
+                })?;
+
+            // As in `status()`, a consensus state in the future is trusted.
+            let within_trusting_period = now
+                .duration_since(&consensus_state.timestamp())
+                .map_or(true, |elapsed| elapsed < self.trusting_period);
+            if within_trusting_period {
+                return Ok(Some((height, consensus_state)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Verifies a batch of sequential headers as a chain: the first header
+    /// against the consensus state stored at its trusted height, and every
+    /// other header against the consensus state of the previous one.
+    pub fn verify_header_batch<ClientValidationContext>(
+        &self,
+        ctx: &ClientValidationContext,
+        client_id: &ClientId,
+        batch: HeaderBatch,
+    ) -> Result<(), ClientError>
+    where
+        ClientValidationContext: TmValidationContext,
+    {
+        batch.validate_basic()?;
+
+        let mut trusted_header: Option<&TmHeader> = None;
+        for header in batch.headers() {
+            match trusted_header {
+                None => self.verify_header(ctx, client_id, header.clone())?,
+                Some(prev_header) => {
+                    let trusted_consensus_state = TmConsensusState::from(prev_header.clone());
+                    self.verify_header_against(
+                        ctx,
+                        header,
+                        prev_header.height(),
+                        &trusted_consensus_state,
+                    )?
+                }
+            }
+            trusted_header = Some(header);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies `header` against the consensus state at `trusted_height`.
+    fn verify_header_against<ClientValidationContext>(
+        &self,
+        ctx: &ClientValidationContext,
+        header: &TmHeader,
+        trusted_height: Height,
+        trusted_consensus_state: &TmConsensusState,
+    ) -> Result<(), ClientError>
+    where
+        ClientValidationContext: TmValidationContext,
+    {
+        // Checks that the header fields are valid.
+        header.validate_basic()?;
+
+        // The tendermint-light-client crate though works on heights that are assumed
+        // to have the same revision number. We ensure this here.
+        header.verify_chain_id_version_matches_height(&self.chain_id())?;
+
+        // Delegate to tendermint-light-client, which contains the required checks
+        // of the new header against the trusted consensus state.
+        {
+            let trusted_state = {
+                check_header_trusted_next_validator_set(header, trusted_consensus_state)?;
+
+                TrustedBlockState {
+                    chain_id: &self.chain_id.to_string().try_into().map_err(|e| {
+                        ClientError::Other {
+                            description: format!("failed to parse chain id: {}", e),
+                        }
+                    })?,
+                    header_time: trusted_consensus_state.timestamp,
+                    height: trusted_height.revision_height().try_into().map_err(|_| {
+                        ClientError::ClientSpecific {
+                            description: Error::InvalidHeaderHeight {
+                                height: trusted_height.revision_height(),
+                            }
+                            .to_string(),
+                        }
+                    })?,
+                    next_validators: &header.trusted_next_validator_set,
+                    next_validators_hash: trusted_consensus_state.next_validators_hash,
+                }
+            };
+
+            let untrusted_state = UntrustedBlockState {
+                signed_header: &header.signed_header,
+                validators: &header.validator_set,
+                // NB: This will skip the
+                // VerificationPredicates::next_validators_match check for the
+                // untrusted state.
+                next_validators: None,
+            };
+
+            let options = self.as_light_client_options()?;
+            let now = ctx.host_timestamp()?.into_tm_time().ok_or_else(|| {
+                ClientError::ClientSpecific {
+                    description: "host timestamp is not a valid TM timestamp".to_string(),
+                }
+            })?;
+
+            // main header verification, delegated to the tendermint-light-client crate.
+            self.verifier
+                .verify_update_header(untrusted_state, trusted_state, &options, now)
+                .into_result()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn check_for_misbehaviour_update_client<ClientValidationContext>(
+        &self,
+        ctx: &ClientValidationContext,
+        client_id: &ClientId,
+        header: TmHeader,
+    ) -> Result<bool, ClientError>
+    where
+        ClientValidationContext: TmValidationContext,
+    {
+        let header_consensus_state = TmConsensusState::from(header.clone());
+
+        let maybe_existing_consensus_state = {
+            let path_at_header_height = ClientConsensusStatePath::new(client_id, &header.height());
+
+            ctx.consensus_state(&path_at_header_height).ok()
+        };
+
+        match maybe_existing_consensus_state {
+            Some(existing_consensus_state) => {
+                let existing_consensus_state: TmConsensusState = existing_consensus_state
+                    .try_into()
+                    .map_err(|err| ClientError::Other {
+                        description: err.to_string(),
+                    })?;
+
+                // There is evidence of misbehaviour if the stored consensus state
+                // is different from the new one we received.
+                Ok(existing_consensus_state != header_consensus_state)
+            }
+            None => {
+                // If no header was previously installed, we ensure the monotonicity of timestamps.
+
+                // 1. for all headers, the new header needs to have a larger timestamp than
+                //    the “previous header”
+                {
+                    let maybe_prev_cs = ctx.prev_consensus_state(client_id, &header.height())?;
+
+                    if let Some(prev_cs) = maybe_prev_cs {
+                        // New header timestamp cannot occur *before* the
+                        // previous consensus state's height
+                        let prev_cs: TmConsensusState =
+                            prev_cs.try_into().map_err(|err| ClientError::Other {
+                                description: err.to_string(),
+                            })?;
+
+                        if header.signed_header.header().time <= prev_cs.timestamp {
+                            return Ok(true);
+                        }
+                    }
+                }
+
+                // 2. if a header comes in and is not the “last” header, then we also ensure
+                //    that its timestamp is less than the “next header”
+                if header.height() < self.latest_height {
+                    let maybe_next_cs = ctx.next_consensus_state(client_id, &header.height())?;
+
+                    if let Some(next_cs) = maybe_next_cs {
+                        // New (untrusted) header timestamp cannot occur *after* next
+                        // consensus state's height
+                        let next_cs: TmConsensusState =
+                            next_cs.try_into().map_err(|err| ClientError::Other {
+                                description: err.to_string(),
+                            })?;
+
+                        if header.signed_header.header().time >= next_cs.timestamp {
+                            return Ok(true);
+                        }
+                    }
+                }
+
+                Ok(false)
+            }
+        }
+    }
+
+    /// Checks each header of a batch for misbehaviour, as if it were submitted
+    /// on its own.
+    pub fn check_for_misbehaviour_header_batch<ClientValidationContext>(
+        &self,
+        ctx: &ClientValidationContext,
+        client_id: &ClientId,
+        batch: HeaderBatch,
+    ) -> Result<bool, ClientError>
+    where
+        ClientValidationContext: TmValidationContext,
+    {
+        for header in batch.headers() {
+            if self.check_for_misbehaviour_update_client(ctx, client_id, header.clone())? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Stores the consensus states of the last header of a verified batch and
+    /// of its selected intermediate headers, skipping the ones already stored,
+    /// and returns their heights.
+    pub fn update_state_with_header_batch<E>(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        batch: HeaderBatch,
+    ) -> Result<Vec<Height>, ClientError>
+    where
+        E: TmExecutionContext,
+        <E as ClientExecutionContext>::AnyClientState: From<ClientState>,
+        <E as ClientExecutionContext>::AnyConsensusState: From<TmConsensusState>,
+    {
+        self.prune_oldest_consensus_state(ctx, client_id)?;
+
+        let host_timestamp = CommonContext::host_timestamp(ctx)?;
+        let host_height = CommonContext::host_height(ctx)?;
+
+        let mut new_client_state = self.clone();
+        let mut heights = Vec::new();
+        for header in batch.into_headers_to_store() {
+            let header_height = header.height();
+            let path_at_header_height = ClientConsensusStatePath::new(client_id, &header_height);
+
+            // Consensus states already installed, e.g. by another relayer, are
+            // left as they are.
+            if CommonContext::consensus_state(ctx, &path_at_header_height).is_err() {
+                let new_consensus_state = TmConsensusState::from(header.clone());
+                new_client_state = new_client_state.with_header(header)?;
+
+                ctx.store_consensus_state(path_at_header_height, new_consensus_state.into())?;
+                ctx.store_update_time(client_id.clone(), header_height, host_timestamp)?;
+                ctx.store_update_height(client_id.clone(), header_height, host_height)?;
+            }
+
+            heights.push(header_height);
+        }
+
+        if new_client_state != *self {
+            ctx.store_client_state(ClientStatePath::new(client_id), new_client_state.into())?;
+        }
+
+        Ok(heights)
+    }
+
+    pub fn prune_oldest_consensus_state<E>(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+    ) -> Result<(), ClientError>
+    where
+        E: ClientExecutionContext + CommonContext,
+    {
+        let mut heights = ctx.consensus_state_heights(client_id)?;
+
+        heights.sort();
+
+        for height in heights {
+            let client_consensus_state_path = ClientConsensusStatePath::new(client_id, &height);
+            let consensus_state =
+                CommonContext::consensus_state(ctx, &client_consensus_state_path)?;
+            let tm_consensus_state: TmConsensusState =
+                consensus_state
+                    .try_into()
+                    .map_err(|err| ClientError::Other {
+                        description: err.to_string(),
+                    })?;
+
+            let host_timestamp = ctx.host_timestamp()?;
+            let tm_consensus_state_expiry = (tm_consensus_state.timestamp() + self.trusting_period)
+                .map_err(|_| ClientError::Other {
+                    description: String::from("Timestamp overflow error occurred while attempting to parse TmConsensusState")
+                })?;
+
+            if tm_consensus_state_expiry > host_timestamp {
+                break;
+            } else {
+                let client_id = client_id.clone();
+
+                ctx.delete_consensus_state(client_consensus_state_path)?;
+                ctx.delete_update_time(client_id.clone(), height)?;
+                ctx.delete_update_height(client_id, height)?;
+            }
+        }
+
+        Ok(())
+    }
+}
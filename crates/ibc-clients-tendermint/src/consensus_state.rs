@@ -1,5 +1,10 @@
 //! Defines Tendermint's `ConsensusState` type
 
+use ibc_core_client_types::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawConsensusState;
 use ibc_proto::Protobuf;
@@ -8,13 +13,8 @@ use tendermint::time::Time;
 use tendermint::Hash;
 use tendermint_proto::google::protobuf as tpb;
 
-use crate::clients::ics07_tendermint::error::Error;
-use crate::clients::ics07_tendermint::header::Header;
-use crate::core::ics02_client::consensus_state::ConsensusState as ConsensusStateTrait;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics23_commitment::commitment::CommitmentRoot;
-use crate::core::timestamp::Timestamp;
-use crate::prelude::*;
+use crate::error::Error;
+use crate::header::Header;
 
 pub const TENDERMINT_CONSENSUS_STATE_TYPE_URL: &str =
     "/ibc.lightclients.tendermint.v1.ConsensusState";
@@ -166,7 +166,7 @@ mod tests {
     use tendermint_rpc::endpoint::abci_query::AbciQuery;
     use test_log::test;
 
-    use crate::serializers::tests::test_serialization_roundtrip;
+    use crate::tests::test_serialization_roundtrip;
 
     #[test]
     fn serialization_roundtrip_no_proof() {
@@ -1,13 +1,14 @@
 use alloc::string::ToString;
 
+use ibc_core_client_context::context::ClientExecutionContext;
+use ibc_core_client_types::height::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::path::ClientConsensusStatePath;
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
+
 use super::consensus_state::ConsensusState as TmConsensusState;
-use crate::core::ics02_client::ClientExecutionContext;
-use crate::core::ics24_host::identifier::ClientId;
-use crate::core::ics24_host::path::ClientConsensusStatePath;
-use crate::core::timestamp::Timestamp;
-use crate::core::ContextError;
-use crate::prelude::*;
-use crate::Height;
 
 /// Client's context required during both validation and execution
 pub trait CommonContext {
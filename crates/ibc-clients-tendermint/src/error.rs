@@ -3,16 +3,16 @@
 use core::time::Duration;
 
 use displaydoc::Display;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::Height;
+use ibc_core_host_types::identifier::{ClientId, IdentifierError};
+use ibc_primitives::impl_error_sources;
+use ibc_primitives::prelude::*;
 use tendermint::{Error as TendermintError, Hash};
 use tendermint_light_client_verifier::errors::VerificationErrorDetail as LightClientErrorDetail;
 use tendermint_light_client_verifier::operations::VotingPowerTally;
 use tendermint_light_client_verifier::Verdict;
 
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics24_host::identifier::{ClientId, IdentifierError};
-use crate::prelude::*;
-use crate::Height;
-
 /// The main error type
 #[derive(Debug, Display)]
 pub enum Error {
@@ -94,21 +94,36 @@ pub enum Error {
     MisbehaviourHeadersBlockHashesEqual,
     /// headers are not at same height and are monotonically increasing
     MisbehaviourHeadersNotAtSameHeight,
+    /// header batch is empty
+    EmptyHeaderBatch,
+    /// header at height `{height}` of the batch is trusted at `{trusted_height}` instead of at the height `{expected}` of the previous header
+    NonSequentialHeaderBatch {
+        height: Height,
+        trusted_height: Height,
+        expected: Height,
+    },
+    /// intermediate height `{height}` is not the height of a header of the batch other than the last one
+    InvalidIntermediateHeight { height: Height },
+    /// invalid raw header batch: `{reason}`
+    InvalidRawHeaderBatch { reason: String },
+    /// no consensus state at the trusted height `{trusted_height}` of the header at `{height}`, nor any within the trusting period below it
+    MissingTrustedConsensusState {
+        trusted_height: Height,
+        height: Height,
+    },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self {
-            Self::InvalidIdentifier(e) => Some(e),
-            Self::InvalidHeader { error: e, .. } => Some(e),
-            Self::InvalidTendermintTrustThreshold(e) => Some(e),
-            Self::InvalidRawHeader(e) => Some(e),
-            Self::Decode(e) => Some(e),
-            _ => None,
-        }
+impl_error_sources!(Error {
+    ibc {
+        Self::InvalidIdentifier(e) => e,
     }
-}
+    std {
+        Self::InvalidHeader { error: e, .. } => e,
+        Self::InvalidTendermintTrustThreshold(e) => e,
+        Self::InvalidRawHeader(e) => e,
+        Self::Decode(e) => e,
+    }
+});
 
 impl From<Error> for ClientError {
     fn from(e: Error) -> Self {
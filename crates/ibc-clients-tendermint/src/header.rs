@@ -5,6 +5,11 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
 use bytes::Buf;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::Height;
+use ibc_core_host_types::identifier::ChainId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::Header as RawHeader;
 use ibc_proto::Protobuf;
@@ -15,13 +20,8 @@ use tendermint::chain::Id as TmChainId;
 use tendermint::validator::Set as ValidatorSet;
 use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
 
-use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
-use crate::clients::ics07_tendermint::error::Error;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics24_host::identifier::ChainId;
-use crate::core::timestamp::Timestamp;
-use crate::prelude::*;
-use crate::Height;
+use crate::consensus_state::ConsensusState as TmConsensusState;
+use crate::error::Error;
 
 pub const TENDERMINT_HEADER_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Header";
 
@@ -62,6 +62,12 @@ impl Header {
         .expect("malformed tendermint header domain type has an illegal height of 0")
     }
 
+    /// Returns whether the header directly follows its trusted height, in
+    /// which case it is verified sequentially rather than by skipping.
+    pub fn is_adjacent(&self) -> bool {
+        self.trusted_height.increment() == self.height()
+    }
+
     pub(crate) fn as_untrusted_block_state(&self) -> UntrustedBlockState<'_> {
         UntrustedBlockState {
             signed_header: &self.signed_header,
@@ -207,8 +213,9 @@ impl From<Header> for RawHeader {
 }
 
 mod pretty {
+    use ibc_primitives::utils::pretty::PrettySlice;
+
     pub use super::*;
-    use crate::utils::pretty::PrettySlice;
 
     pub struct PrettySignedHeader<'a>(pub &'a SignedHeader);
 
@@ -0,0 +1,268 @@
+//! Defines the domain type for batches of sequential tendermint headers, with
+//! which a lagging client catches up with its counterparty in a single update.
+
+use bytes::{Buf, BufMut};
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::Height;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+use ibc_proto::ibc::lightclients::tendermint::v1::Header as RawHeader;
+use ibc_proto::Protobuf;
+use prost::encoding::{self, DecodeContext, WireType};
+use prost::{DecodeError, Message};
+
+use crate::error::Error;
+use crate::header::Header;
+
+/// The type URL of [`HeaderBatch`]es. The message is specific to ibc-rs, so it
+/// is declared in a package of its own rather than in the `ibc` one of
+/// ibc-go, whose clients do not accept it.
+pub const TENDERMINT_HEADER_BATCH_TYPE_URL: &str = "/ibc_rs.lightclients.tendermint.v1.HeaderBatch";
+
+/// Sequential tendermint headers, submitted as the client message of a single
+/// `MsgUpdateClient`.
+///
+/// The first header is verified against a consensus state stored by the
+/// client, and every other header against the previous one, at whose height it
+/// must be trusted. Only the consensus states of the last header and of the
+/// headers at the `intermediate_heights` are stored.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedHeaderBatch"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeaderBatch {
+    headers: Vec<Header>,
+    intermediate_heights: Vec<Height>,
+}
+
+impl HeaderBatch {
+    pub fn new(headers: Vec<Header>, intermediate_heights: Vec<Height>) -> Result<Self, Error> {
+        let batch = Self {
+            headers,
+            intermediate_heights,
+        };
+        batch.validate_basic()?;
+        Ok(batch)
+    }
+
+    /// The headers of the batch, in ascending order of height.
+    pub fn headers(&self) -> &[Header] {
+        &self.headers
+    }
+
+    /// The heights of the intermediate headers whose consensus states are
+    /// stored along with the one of the last header.
+    pub fn intermediate_heights(&self) -> &[Height] {
+        &self.intermediate_heights
+    }
+
+    /// Returns the headers whose consensus states are stored by an update with
+    /// this batch: the selected intermediate headers, then the last one.
+    pub fn into_headers_to_store(self) -> Vec<Header> {
+        let last_height = match self.headers.split_last() {
+            Some((last, _)) => last.height(),
+            None => return Vec::new(),
+        };
+        let intermediate_heights = self.intermediate_heights;
+
+        self.headers
+            .into_iter()
+            .filter(|header| {
+                header.height() == last_height || intermediate_heights.contains(&header.height())
+            })
+            .collect()
+    }
+
+    /// Checks each header of the batch, that every header is trusted at the
+    /// height of the previous one, and that every intermediate height is the
+    /// height of a header that is not the last one.
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        let (last, intermediates) = self.headers.split_last().ok_or(Error::EmptyHeaderBatch)?;
+
+        for header in &self.headers {
+            header.validate_basic()?;
+        }
+
+        for (prev, header) in self.headers.iter().zip(self.headers.iter().skip(1)) {
+            if header.trusted_height != prev.height() {
+                return Err(Error::NonSequentialHeaderBatch {
+                    height: header.height(),
+                    trusted_height: header.trusted_height,
+                    expected: prev.height(),
+                });
+            }
+        }
+
+        for height in &self.intermediate_heights {
+            if *height == last.height()
+                || !intermediates
+                    .iter()
+                    .any(|header| header.height() == *height)
+            {
+                return Err(Error::InvalidIntermediateHeight { height: *height });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The fields of a deserialized [`HeaderBatch`], before [`HeaderBatch::new`]
+/// validates them.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedHeaderBatch {
+    headers: Vec<Header>,
+    intermediate_heights: Vec<Height>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedHeaderBatch> for HeaderBatch {
+    type Error = Error;
+
+    fn try_from(unchecked: UncheckedHeaderBatch) -> Result<Self, Self::Error> {
+        Self::new(unchecked.headers, unchecked.intermediate_heights)
+    }
+}
+
+/// The protobuf encoding of a [`HeaderBatch`]:
+///
+/// ```proto
+/// package ibc_rs.lightclients.tendermint.v1;
+///
+/// message HeaderBatch {
+///   repeated ibc.lightclients.tendermint.v1.Header headers = 1;
+///   repeated ibc.core.client.v1.Height intermediate_heights = 2;
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RawHeaderBatch {
+    pub headers: Vec<RawHeader>,
+    pub intermediate_heights: Vec<RawHeight>,
+}
+
+impl Message for RawHeaderBatch {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        for header in &self.headers {
+            encoding::message::encode(1, header, buf);
+        }
+        for height in &self.intermediate_heights {
+            encoding::message::encode(2, height, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => encoding::message::merge_repeated(wire_type, &mut self.headers, buf, ctx),
+            2 => encoding::message::merge_repeated(
+                wire_type,
+                &mut self.intermediate_heights,
+                buf,
+                ctx,
+            ),
+            _ => encoding::skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        encoding::message::encoded_len_repeated(1, &self.headers)
+            + encoding::message::encoded_len_repeated(2, &self.intermediate_heights)
+    }
+
+    fn clear(&mut self) {
+        self.headers.clear();
+        self.intermediate_heights.clear();
+    }
+}
+
+impl Protobuf<RawHeaderBatch> for HeaderBatch {}
+
+impl TryFrom<RawHeaderBatch> for HeaderBatch {
+    type Error = Error;
+
+    fn try_from(raw: RawHeaderBatch) -> Result<Self, Self::Error> {
+        let headers = raw
+            .headers
+            .into_iter()
+            .map(Header::try_from)
+            .collect::<Result<_, _>>()?;
+        let intermediate_heights = raw
+            .intermediate_heights
+            .into_iter()
+            .map(|raw_height| {
+                Height::try_from(raw_height).map_err(|e| Error::InvalidRawHeaderBatch {
+                    reason: e.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Self::new(headers, intermediate_heights)
+    }
+}
+
+impl From<HeaderBatch> for RawHeaderBatch {
+    fn from(value: HeaderBatch) -> Self {
+        Self {
+            headers: value.headers.into_iter().map(Into::into).collect(),
+            intermediate_heights: value
+                .intermediate_heights
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for HeaderBatch {}
+
+impl TryFrom<Any> for HeaderBatch {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TENDERMINT_HEADER_BATCH_TYPE_URL => RawHeaderBatch::decode(raw.value.as_slice())
+                .map_err(Error::Decode)?
+                .try_into()
+                .map_err(Into::into),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<HeaderBatch> for Any {
+    fn from(batch: HeaderBatch) -> Self {
+        Any {
+            type_url: TENDERMINT_HEADER_BATCH_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeaderBatch>::encode_vec(batch),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialization_validates_the_batch() {
+        let empty = r#"{"headers":[],"intermediate_heights":[]}"#;
+
+        let err = serde_json::from_str::<HeaderBatch>(empty).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(&Error::EmptyHeaderBatch.to_string()));
+    }
+}
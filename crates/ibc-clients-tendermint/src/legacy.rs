@@ -0,0 +1,200 @@
+//! Conversions from the Tendermint client states stored by older versions of
+//! ibc-go, for chains migrating their historical state to this crate.
+//!
+//! Such client states may still set the deprecated `allow_update_after_expiry`
+//! and `allow_update_after_misbehaviour` fields, which ibc-go no longer reads
+//! and now always encodes as `false`, or be frozen, which a
+//! [`ClientState`] decoded from its protobuf encoding may not be. The
+//! [`LegacyClientState`] holds any of them as is, so that it re-encodes to the
+//! exact same bytes, and converts them into their canonical [`ClientState`].
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::Height;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
+use ibc_proto::Protobuf;
+use prost::Message;
+
+use crate::client_state::{ClientState, TENDERMINT_CLIENT_STATE_TYPE_URL};
+use crate::error::Error;
+
+/// A Tendermint client state as encoded by any version of ibc-go, with its
+/// deprecated fields and frozen height, if any, left untouched.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyClientState(RawTmClientState);
+
+impl LegacyClientState {
+    pub fn new(raw: RawTmClientState) -> Self {
+        Self(raw)
+    }
+
+    pub fn as_raw(&self) -> &RawTmClientState {
+        &self.0
+    }
+
+    pub fn into_raw(self) -> RawTmClientState {
+        self.0
+    }
+
+    /// Returns whether any of the deprecated `allow_update_*` fields is set.
+    #[allow(deprecated)]
+    pub fn has_deprecated_fields(&self) -> bool {
+        self.0.allow_update_after_expiry || self.0.allow_update_after_misbehaviour
+    }
+
+    /// Returns the height at which the client was frozen, if any.
+    pub fn frozen_height(&self) -> Option<Height> {
+        self.0
+            .frozen_height
+            .as_ref()
+            .and_then(|height| Height::new(height.revision_number, height.revision_height).ok())
+    }
+
+    /// Converts into the canonical client state, which ibc-go would encode
+    /// today: the deprecated fields are cleared, while a frozen client is
+    /// carried over as frozen.
+    ///
+    /// The client state is not validated, as its parameters may predate the
+    /// current validation rules.
+    #[allow(deprecated)]
+    pub fn into_canonical(self) -> Result<ClientState, Error> {
+        let frozen_height = self.frozen_height();
+
+        let raw = RawTmClientState {
+            frozen_height: Some(RawHeight {
+                revision_number: 0,
+                revision_height: 0,
+            }),
+            allow_update_after_expiry: false,
+            allow_update_after_misbehaviour: false,
+            ..self.0
+        };
+
+        let client_state = ClientState::try_from(raw)?;
+
+        Ok(match frozen_height {
+            Some(frozen_height) => client_state.with_frozen_height(frozen_height),
+            None => client_state,
+        })
+    }
+}
+
+impl From<RawTmClientState> for LegacyClientState {
+    fn from(raw: RawTmClientState) -> Self {
+        Self::new(raw)
+    }
+}
+
+impl From<ClientState> for LegacyClientState {
+    fn from(client_state: ClientState) -> Self {
+        Self::new(client_state.into())
+    }
+}
+
+impl Protobuf<Any> for LegacyClientState {}
+
+impl TryFrom<Any> for LegacyClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TENDERMINT_CLIENT_STATE_TYPE_URL => RawTmClientState::decode(raw.value.as_slice())
+                .map(Self::new)
+                .map_err(|e| Error::Decode(e).into()),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<LegacyClientState> for Any {
+    fn from(client_state: LegacyClientState) -> Self {
+        Any {
+            type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
+            value: client_state.0.encode_to_vec(),
+        }
+    }
+}
+
+/// Decodes a Tendermint client state encoded by any version of ibc-go into its
+/// canonical form, as described in [`LegacyClientState::into_canonical`].
+pub fn decode_legacy_client_state(any: Any) -> Result<ClientState, ClientError> {
+    Ok(LegacyClientState::try_from(any)?.into_canonical()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use ibc_core_commitment_types::specs::ProofSpecs;
+    use ibc_proto::ibc::lightclients::tendermint::v1::Fraction;
+
+    use super::*;
+
+    #[allow(deprecated)]
+    fn legacy_raw_client_state(frozen_height: RawHeight) -> RawTmClientState {
+        RawTmClientState {
+            chain_id: "ibc-0".to_string(),
+            trust_level: Some(Fraction {
+                numerator: 1,
+                denominator: 3,
+            }),
+            trusting_period: Some(Duration::from_secs(64000).into()),
+            unbonding_period: Some(Duration::from_secs(128000).into()),
+            max_clock_drift: Some(Duration::from_millis(3000).into()),
+            latest_height: Some(Height::new(0, 10).unwrap().into()),
+            proof_specs: ProofSpecs::default().into(),
+            upgrade_path: vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            frozen_height: Some(frozen_height),
+            allow_update_after_expiry: true,
+            allow_update_after_misbehaviour: true,
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn legacy_client_state_roundtrip() {
+        let raw = legacy_raw_client_state(RawHeight {
+            revision_number: 0,
+            revision_height: 0,
+        });
+        let any = Any {
+            type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
+            value: raw.encode_to_vec(),
+        };
+
+        let legacy = LegacyClientState::try_from(any.clone()).unwrap();
+        assert!(legacy.has_deprecated_fields());
+        assert_eq!(legacy.frozen_height(), None);
+        assert_eq!(Any::from(legacy.clone()), any, "lossless re-encoding");
+
+        let canonical = legacy.into_canonical().unwrap();
+        let canonical_raw = RawTmClientState::from(canonical.clone());
+        assert!(!canonical_raw.allow_update_after_expiry);
+        assert!(!canonical_raw.allow_update_after_misbehaviour);
+        assert!(!LegacyClientState::from(canonical.clone()).has_deprecated_fields());
+        assert_eq!(decode_legacy_client_state(any).unwrap(), canonical);
+    }
+
+    #[test]
+    fn frozen_legacy_client_state() {
+        let frozen_height = Height::new(0, 5).unwrap();
+        let raw = legacy_raw_client_state(frozen_height.into());
+
+        // rejected when decoded strictly, but carried over as frozen
+        assert!(matches!(
+            ClientState::try_from(raw.clone()),
+            Err(Error::FrozenHeightNotAllowed)
+        ));
+
+        let canonical = LegacyClientState::from(raw).into_canonical().unwrap();
+        assert!(canonical.is_frozen());
+        assert_eq!(
+            LegacyClientState::from(canonical).frozen_height(),
+            Some(frozen_height)
+        );
+    }
+}
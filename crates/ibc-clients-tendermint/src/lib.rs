@@ -0,0 +1,80 @@
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+// https://github.com/cosmos/ibc-rs/issues/342
+#![allow(clippy::result_large_err)]
+//! ICS 07: Tendermint Client implements a client verification algorithm for blockchains which use
+//! the Tendermint consensus algorithm.
+
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
+
+use ibc_core_host_types::client_type::ClientType;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod header;
+pub mod header_batch;
+pub mod legacy;
+pub mod misbehaviour;
+pub mod trust_threshold;
+
+mod context;
+pub use context::*;
+
+pub const TENDERMINT_CLIENT_TYPE: &str = "07-tendermint";
+
+/// Returns the tendermint `ClientType`
+pub fn client_type() -> ClientType {
+    ibc_core_host_types::client_type!(TENDERMINT_CLIENT_TYPE)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use super::*;
+
+    // Ensures that the validation in `ClientType::from_str` doesn't fail for the tendermint client type
+    #[test]
+    pub fn test_tm_client_type() {
+        let _ = ClientType::from_str(TENDERMINT_CLIENT_TYPE).unwrap();
+    }
+
+    /// Test that a struct `T` can be:
+    ///
+    /// - parsed out of the provided JSON data
+    /// - serialized back to JSON
+    /// - parsed back from the serialized JSON of the previous step
+    /// - that the two parsed structs are equal according to their `PartialEq` impl
+    #[cfg(feature = "serde")]
+    pub(crate) fn test_serialization_roundtrip<T>(json_data: &str)
+    where
+        T: core::fmt::Debug + PartialEq + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let parsed0 = serde_json::from_str::<T>(json_data);
+        assert!(parsed0.is_ok());
+        let parsed0 = parsed0.unwrap();
+
+        let serialized = serde_json::to_string(&parsed0);
+        assert!(serialized.is_ok());
+        let serialized = serialized.unwrap();
+
+        let parsed1 = serde_json::from_str::<T>(&serialized);
+        assert!(parsed1.is_ok());
+        let parsed1 = parsed1.unwrap();
+
+        assert_eq!(parsed0, parsed1);
+    }
+}
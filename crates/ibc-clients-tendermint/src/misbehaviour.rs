@@ -1,18 +1,18 @@
 //! Defines the misbehaviour type for the tendermint light client
 
 use bytes::Buf;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawMisbehaviour;
 use ibc_proto::Protobuf;
 use prost::Message;
 
-use crate::clients::ics07_tendermint::error::Error;
-use crate::clients::ics07_tendermint::header::Header;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics24_host::identifier::ClientId;
-use crate::prelude::*;
+use crate::error::Error;
+use crate::header::Header;
 
-const TENDERMINT_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Misbehaviour";
+pub const TENDERMINT_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Misbehaviour";
 
 /// Tendermint light client's misbehaviour type
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5,12 +5,11 @@
 use core::convert::TryFrom;
 use core::fmt::{Display, Error as FmtError, Formatter};
 
+use ibc_core_client_types::error::ClientError;
 use ibc_proto::ibc::lightclients::tendermint::v1::Fraction;
 use ibc_proto::Protobuf;
 use tendermint::trust_threshold::TrustThresholdFraction;
 
-use crate::core::ics02_client::error::ClientError;
-
 /// [`TrustThreshold`] defines the level of trust that a client has
 /// towards a set of validators of a chain.
 ///
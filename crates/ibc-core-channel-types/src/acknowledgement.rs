@@ -3,9 +3,10 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 
 use derive_more::Into;
+use ibc_primitives::prelude::*;
+use subtle_encoding::base64;
 
 use super::error::PacketError;
-use crate::prelude::*;
 
 /// A generic Acknowledgement type that modules may interpret as they like.
 ///
@@ -52,9 +53,28 @@ impl TryFrom<Vec<u8>> for Acknowledgement {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Acknowledgement {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(ibc_primitives::utils::arbitrary::non_empty_bytes(u)?))
+    }
+}
+
 /// Defines a convenience type for IBC applications to construct an
 /// [`Acknowledgement`] based on the
 /// success or failure of processing a received packet.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AcknowledgementStatus {
@@ -71,6 +91,18 @@ pub enum AcknowledgementStatus {
 /// A wrapper type that guards variants of
 /// [`AcknowledgementStatus`]
 /// against being constructed with an empty value.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StatusValue(String);
@@ -105,6 +137,23 @@ impl AcknowledgementStatus {
         Self::Error(value)
     }
 
+    /// Creates a success acknowledgement status carrying the base64 encoding
+    /// of `result`, like ibc-go's `NewResultAcknowledgement`. For instance,
+    /// `from_result(b"\x01")` gives the `{"result":"AQ=="}` of ICS-20.
+    pub fn from_result(result: &[u8]) -> Result<Self, PacketError> {
+        let encoded = String::from_utf8(base64::encode(result)).map_err(|e| {
+            PacketError::InvalidAcknowledgementStatus {
+                reason: e.to_string(),
+            }
+        })?;
+        StatusValue::new(encoded).map(Self::Success)
+    }
+
+    /// Creates an error acknowledgement status carrying the message of `err`.
+    pub fn from_error(err: impl Display) -> Result<Self, PacketError> {
+        StatusValue::new(err).map(Self::Error)
+    }
+
     /// Returns true if the acknowledgement status is successful.
     pub fn is_successful(&self) -> bool {
         matches!(self, AcknowledgementStatus::Success(_))
@@ -125,11 +174,30 @@ impl From<AcknowledgementStatus> for Vec<u8> {
     fn from(ack: AcknowledgementStatus) -> Self {
         // WARNING: Make sure all branches always return a non-empty vector.
         // Otherwise, the conversion to `Acknowledgement` will panic.
-        match ack {
-            AcknowledgementStatus::Success(v) => alloc::format!(r#"{{"result":"{v}"}}"#).into(),
-            AcknowledgementStatus::Error(v) => alloc::format!(r#"{{"error":"{v}"}}"#).into(),
+        let (key, value) = match ack {
+            AcknowledgementStatus::Success(v) => ("result", v),
+            AcknowledgementStatus::Error(v) => ("error", v),
+        };
+        format!(r#"{{"{key}":"{}"}}"#, escape_json(&value.0)).into()
+    }
+}
+
+/// Escapes `value` for use within a JSON string, so that error messages with
+/// quotes or line breaks still yield valid acknowledgements.
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
         }
     }
+    escaped
 }
 
 impl From<AcknowledgementStatus> for Acknowledgement {
@@ -141,11 +209,33 @@ impl From<AcknowledgementStatus> for Acknowledgement {
     }
 }
 
+/// Decodes the standard `{"result": ...}` or `{"error": ...}` envelope of an
+/// acknowledgement, as written by ICS-20 and ICS-27 and their ibc-go
+/// counterparts.
+#[cfg(feature = "serde")]
+impl TryFrom<&Acknowledgement> for AcknowledgementStatus {
+    type Error = PacketError;
+
+    fn try_from(ack: &Acknowledgement) -> Result<Self, PacketError> {
+        serde_json::from_slice(ack.as_bytes()).map_err(|e| {
+            PacketError::InvalidAcknowledgementStatus {
+                reason: e.to_string(),
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::applications::transfer::ack_success_b64;
-    use crate::applications::transfer::error::TokenTransferError;
+
+    /// The success status of the acknowledgements of ICS-20.
+    fn ack_success_b64() -> StatusValue {
+        StatusValue::new("AQ==").unwrap()
+    }
+
+    /// The message of an error decoding the data of an ICS-20 packet.
+    const PACKET_DATA_DESERIALIZATION: &str = "failed to deserialize packet data";
 
     #[test]
     fn test_ack_ser() {
@@ -159,7 +249,7 @@ mod test {
             r#"{"result":"AQ=="}"#,
         );
         ser_json_assert_eq(
-            AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into()),
+            AcknowledgementStatus::error(StatusValue::new(PACKET_DATA_DESERIALIZATION).unwrap()),
             r#"{"error":"failed to deserialize packet data"}"#,
         );
     }
@@ -177,7 +267,7 @@ mod test {
     #[test]
     fn test_ack_error_to_vec() {
         let ack_error: Vec<u8> =
-            AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into())
+            AcknowledgementStatus::error(StatusValue::new(PACKET_DATA_DESERIALIZATION).unwrap())
                 .into();
 
         // Check that it's the same output as ibc-go
@@ -202,9 +292,41 @@ mod test {
         );
         de_json_assert_eq(
             r#"{"error":"failed to deserialize packet data"}"#,
-            AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into()),
+            AcknowledgementStatus::error(StatusValue::new(PACKET_DATA_DESERIALIZATION).unwrap()),
         );
 
         assert!(serde_json::from_str::<AcknowledgementStatus>(r#"{"success":"AQ=="}"#).is_err());
     }
+
+    #[test]
+    fn test_ack_helpers() {
+        let success = AcknowledgementStatus::from_result(b"\x01").unwrap();
+        assert_eq!(success, AcknowledgementStatus::success(ack_success_b64()));
+        assert!(AcknowledgementStatus::from_result(b"").is_err());
+
+        let error = AcknowledgementStatus::from_error(PACKET_DATA_DESERIALIZATION).unwrap();
+        assert_eq!(
+            error,
+            AcknowledgementStatus::error(StatusValue::new(PACKET_DATA_DESERIALIZATION).unwrap())
+        );
+        assert!(AcknowledgementStatus::from_error("").is_err());
+    }
+
+    #[test]
+    fn test_ack_round_trip() {
+        for status in [
+            AcknowledgementStatus::from_result(b"\x01").unwrap(),
+            AcknowledgementStatus::from_result(b"ica tx response").unwrap(),
+            AcknowledgementStatus::from_error("denom \"uatom\" not found\n\tat C:\\").unwrap(),
+        ] {
+            let ack = Acknowledgement::from(status.clone());
+            assert_eq!(AcknowledgementStatus::try_from(&ack).unwrap(), status);
+        }
+
+        let ack = Acknowledgement::try_from(b"\x01".to_vec()).unwrap();
+        assert!(matches!(
+            AcknowledgementStatus::try_from(&ack),
+            Err(PacketError::InvalidAcknowledgementStatus { .. })
+        ));
+    }
 }
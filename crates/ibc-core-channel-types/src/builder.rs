@@ -0,0 +1,261 @@
+//! Builders of the messages of the channel handshake, e.g.
+//! `MsgChannelOpenTry::builder().port_id_on_b(..)...build()`.
+//!
+//! Unlike the struct literals of the messages, the builders leave out their
+//! deprecated fields, and `build` checks the invariants that the decoding of
+//! the messages enforces, such that relayers cannot submit messages which the
+//! counterparty would reject.
+
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::msgs::ValidateBasic;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use typed_builder::TypedBuilder;
+
+use crate::channel::Order;
+use crate::error::ChannelError;
+use crate::msgs::chan_close_confirm::MsgChannelCloseConfirm;
+use crate::msgs::chan_close_init::MsgChannelCloseInit;
+use crate::msgs::chan_open_ack::MsgChannelOpenAck;
+use crate::msgs::chan_open_confirm::MsgChannelOpenConfirm;
+use crate::msgs::chan_open_init::MsgChannelOpenInit;
+use crate::msgs::chan_open_try::MsgChannelOpenTry;
+use crate::version::Version as ChannelVersion;
+
+/// Returns `msg` once it passes its [`ValidateBasic`] checks.
+fn validated<M>(msg: M) -> Result<M, M::Error>
+where
+    M: ValidateBasic,
+{
+    msg.validate_basic()?;
+    Ok(msg)
+}
+
+/// The fields of a [`MsgChannelOpenInit`], set through
+/// [`MsgChannelOpenInit::builder`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(
+    field_defaults(setter(into)),
+    builder_method(vis = ""),
+    build_method(into = Result<MsgChannelOpenInit, ChannelError>)
+)]
+pub struct ChanOpenInitFields {
+    pub port_id_on_a: PortId,
+    pub connection_hops_on_a: Vec<ConnectionId>,
+    pub port_id_on_b: PortId,
+    pub ordering: Order,
+    pub signer: Signer,
+    #[builder(default)]
+    pub version_proposal: ChannelVersion,
+}
+
+impl From<ChanOpenInitFields> for Result<MsgChannelOpenInit, ChannelError> {
+    fn from(fields: ChanOpenInitFields) -> Self {
+        let msg = MsgChannelOpenInit {
+            port_id_on_a: fields.port_id_on_a,
+            connection_hops_on_a: fields.connection_hops_on_a,
+            port_id_on_b: fields.port_id_on_b,
+            ordering: fields.ordering,
+            signer: fields.signer,
+            version_proposal: fields.version_proposal,
+        };
+        validated(msg)
+    }
+}
+
+impl MsgChannelOpenInit {
+    /// Returns a builder of the message, whose channel must go through a
+    /// single connection.
+    pub fn builder() -> ChanOpenInitFieldsBuilder {
+        ChanOpenInitFields::builder()
+    }
+}
+
+/// The fields of a [`MsgChannelOpenTry`], set through
+/// [`MsgChannelOpenTry::builder`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(
+    field_defaults(setter(into)),
+    builder_method(vis = ""),
+    build_method(into = Result<MsgChannelOpenTry, ChannelError>)
+)]
+pub struct ChanOpenTryFields {
+    pub port_id_on_b: PortId,
+    pub connection_hops_on_b: Vec<ConnectionId>,
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub version_supported_on_a: ChannelVersion,
+    pub proof_chan_end_on_a: CommitmentProofBytes,
+    pub proof_height_on_a: Height,
+    pub ordering: Order,
+    pub signer: Signer,
+}
+
+impl From<ChanOpenTryFields> for Result<MsgChannelOpenTry, ChannelError> {
+    fn from(fields: ChanOpenTryFields) -> Self {
+        #[allow(deprecated)]
+        let msg = MsgChannelOpenTry {
+            port_id_on_b: fields.port_id_on_b,
+            connection_hops_on_b: fields.connection_hops_on_b,
+            port_id_on_a: fields.port_id_on_a,
+            chan_id_on_a: fields.chan_id_on_a,
+            version_supported_on_a: fields.version_supported_on_a,
+            proof_chan_end_on_a: fields.proof_chan_end_on_a,
+            proof_height_on_a: fields.proof_height_on_a,
+            ordering: fields.ordering,
+            signer: fields.signer,
+            version_proposal: ChannelVersion::empty(),
+        };
+        validated(msg)
+    }
+}
+
+impl MsgChannelOpenTry {
+    /// Returns a builder of the message, whose channel must go through a
+    /// single connection.
+    pub fn builder() -> ChanOpenTryFieldsBuilder {
+        ChanOpenTryFields::builder()
+    }
+}
+
+/// The fields of a [`MsgChannelOpenAck`], set through
+/// [`MsgChannelOpenAck::builder`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(
+    field_defaults(setter(into)),
+    builder_method(vis = ""),
+    build_method(into = Result<MsgChannelOpenAck, ChannelError>)
+)]
+pub struct ChanOpenAckFields {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub chan_id_on_b: ChannelId,
+    pub version_on_b: ChannelVersion,
+    pub proof_chan_end_on_b: CommitmentProofBytes,
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl From<ChanOpenAckFields> for Result<MsgChannelOpenAck, ChannelError> {
+    fn from(fields: ChanOpenAckFields) -> Self {
+        validated(MsgChannelOpenAck {
+            port_id_on_a: fields.port_id_on_a,
+            chan_id_on_a: fields.chan_id_on_a,
+            chan_id_on_b: fields.chan_id_on_b,
+            version_on_b: fields.version_on_b,
+            proof_chan_end_on_b: fields.proof_chan_end_on_b,
+            proof_height_on_b: fields.proof_height_on_b,
+            signer: fields.signer,
+        })
+    }
+}
+
+impl MsgChannelOpenAck {
+    /// Returns a builder of the message.
+    pub fn builder() -> ChanOpenAckFieldsBuilder {
+        ChanOpenAckFields::builder()
+    }
+}
+
+/// The fields of a [`MsgChannelOpenConfirm`], set through
+/// [`MsgChannelOpenConfirm::builder`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(
+    field_defaults(setter(into)),
+    builder_method(vis = ""),
+    build_method(into = Result<MsgChannelOpenConfirm, ChannelError>)
+)]
+pub struct ChanOpenConfirmFields {
+    pub port_id_on_b: PortId,
+    pub chan_id_on_b: ChannelId,
+    pub proof_chan_end_on_a: CommitmentProofBytes,
+    pub proof_height_on_a: Height,
+    pub signer: Signer,
+}
+
+impl From<ChanOpenConfirmFields> for Result<MsgChannelOpenConfirm, ChannelError> {
+    fn from(fields: ChanOpenConfirmFields) -> Self {
+        validated(MsgChannelOpenConfirm {
+            port_id_on_b: fields.port_id_on_b,
+            chan_id_on_b: fields.chan_id_on_b,
+            proof_chan_end_on_a: fields.proof_chan_end_on_a,
+            proof_height_on_a: fields.proof_height_on_a,
+            signer: fields.signer,
+        })
+    }
+}
+
+impl MsgChannelOpenConfirm {
+    /// Returns a builder of the message.
+    pub fn builder() -> ChanOpenConfirmFieldsBuilder {
+        ChanOpenConfirmFields::builder()
+    }
+}
+
+/// The fields of a [`MsgChannelCloseInit`], set through
+/// [`MsgChannelCloseInit::builder`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(
+    field_defaults(setter(into)),
+    builder_method(vis = ""),
+    build_method(into = Result<MsgChannelCloseInit, ChannelError>)
+)]
+pub struct ChanCloseInitFields {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub signer: Signer,
+}
+
+impl From<ChanCloseInitFields> for Result<MsgChannelCloseInit, ChannelError> {
+    fn from(fields: ChanCloseInitFields) -> Self {
+        validated(MsgChannelCloseInit {
+            port_id_on_a: fields.port_id_on_a,
+            chan_id_on_a: fields.chan_id_on_a,
+            signer: fields.signer,
+        })
+    }
+}
+
+impl MsgChannelCloseInit {
+    /// Returns a builder of the message.
+    pub fn builder() -> ChanCloseInitFieldsBuilder {
+        ChanCloseInitFields::builder()
+    }
+}
+
+/// The fields of a [`MsgChannelCloseConfirm`], set through
+/// [`MsgChannelCloseConfirm::builder`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(
+    field_defaults(setter(into)),
+    builder_method(vis = ""),
+    build_method(into = Result<MsgChannelCloseConfirm, ChannelError>)
+)]
+pub struct ChanCloseConfirmFields {
+    pub port_id_on_b: PortId,
+    pub chan_id_on_b: ChannelId,
+    pub proof_chan_end_on_a: CommitmentProofBytes,
+    pub proof_height_on_a: Height,
+    pub signer: Signer,
+}
+
+impl From<ChanCloseConfirmFields> for Result<MsgChannelCloseConfirm, ChannelError> {
+    fn from(fields: ChanCloseConfirmFields) -> Self {
+        validated(MsgChannelCloseConfirm {
+            port_id_on_b: fields.port_id_on_b,
+            chan_id_on_b: fields.chan_id_on_b,
+            proof_chan_end_on_a: fields.proof_chan_end_on_a,
+            proof_height_on_a: fields.proof_height_on_a,
+            signer: fields.signer,
+        })
+    }
+}
+
+impl MsgChannelCloseConfirm {
+    /// Returns a builder of the message.
+    pub fn builder() -> ChanCloseConfirmFieldsBuilder {
+        ChanCloseConfirmFields::builder()
+    }
+}
@@ -3,17 +3,17 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::utils::pretty::PrettySlice;
 use ibc_proto::ibc::core::channel::v1::{
     Channel as RawChannel, Counterparty as RawCounterparty,
     IdentifiedChannel as RawIdentifiedChannel,
 };
 use ibc_proto::Protobuf;
 
-use crate::core::ics04_channel::error::ChannelError;
-use crate::core::ics04_channel::Version;
-use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
-use crate::prelude::*;
-use crate::utils::pretty::PrettySlice;
+use crate::error::ChannelError;
+use crate::version::Version;
 
 /// A [`ChannelEnd`] along with its ID and the port it is bound to
 #[cfg_attr(
@@ -435,6 +435,7 @@ impl From<Counterparty> for RawCounterparty {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum Order {
     None = 0isize,
@@ -570,12 +571,12 @@ impl Display for State {
 mod tests {
     use core::str::FromStr;
 
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::channel::v1::Channel as RawChannel;
     use ibc_testkit::utils::core::channel::dummy_raw_channel_end;
     use test_log::test;
 
-    use crate::core::ics04_channel::channel::ChannelEnd;
-    use crate::prelude::*;
+    use crate::channel::ChannelEnd;
 
     #[test]
     fn channel_end_try_from_raw() {
@@ -0,0 +1,270 @@
+//! Types and utilities related to packet commitments.
+//!
+//! The commitments are stored by the chains under the packet commitment and
+//! acknowledgement commitment paths, and proven to their counterparties.
+//! Relayers and light clients therefore recompute them off-chain to check the
+//! values they query, which they do with the functions below, whose byte
+//! layouts are documented on each of them.
+
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
+
+use super::acknowledgement::Acknowledgement;
+use crate::timeout::TimeoutHeight;
+
+/// Packet commitment
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketCommitment(Vec<u8>);
+
+impl PacketCommitment {
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for PacketCommitment {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for PacketCommitment {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+/// Acknowledgement commitment to be stored
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AcknowledgementCommitment(Vec<u8>);
+
+impl AcknowledgementCommitment {
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for AcknowledgementCommitment {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for AcknowledgementCommitment {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+/// Compute the commitment for a packet.
+///
+/// The commitment is the SHA256 hash of the following 56 bytes:
+///
+/// | bytes     | content                                          |
+/// |-----------|--------------------------------------------------|
+/// | `0..8`    | `timeout_timestamp`, in nanoseconds              |
+/// | `8..16`   | revision number of `timeout_height`              |
+/// | `16..24`  | revision height of `timeout_height`              |
+/// | `24..56`  | SHA256 hash of `packet_data`                     |
+///
+/// where the integers are encoded as big-endian `u64`s, and an unset timeout
+/// timestamp as `0`.
+///
+/// Note that the absence of `timeout_height` is treated as
+/// `{revision_number: 0, revision_height: 0}` to be consistent with ibc-go,
+/// where this value is used to mean "no timeout height":
+/// <https://github.com/cosmos/ibc-go/blob/04791984b3d6c83f704c4f058e6ca0038d155d91/modules/core/04-channel/keeper/packet.go#L206>
+///
+/// See [`Packet::commitment`](crate::packet::Packet::commitment)
+/// to compute the commitment of a whole packet.
+pub fn compute_packet_commitment(
+    packet_data: &[u8],
+    timeout_height: &TimeoutHeight,
+    timeout_timestamp: &Timestamp,
+) -> PacketCommitment {
+    let mut hash_input = [0; 8 * 3 + 32];
+
+    hash_input[..8].copy_from_slice(&timeout_timestamp.nanoseconds().to_be_bytes());
+    hash_input[8..16].copy_from_slice(&timeout_height.commitment_revision_number().to_be_bytes());
+    hash_input[16..24].copy_from_slice(&timeout_height.commitment_revision_height().to_be_bytes());
+    hash_input[24..].copy_from_slice(&hash(packet_data));
+
+    hash(&hash_input).to_vec().into()
+}
+
+/// Compute the commitment for an acknowledgement.
+///
+/// The commitment is the SHA256 hash of the acknowledgement bytes, as written
+/// by the receiving application, e.g. the JSON encoding of an ICS-20
+/// acknowledgement.
+pub fn compute_ack_commitment(ack: &Acknowledgement) -> AcknowledgementCommitment {
+    hash(ack.as_ref()).to_vec().into()
+}
+
+/// Helper function to hash a byte slice using SHA256.
+///
+/// Note that computing commitments with anything other than SHA256 will
+/// break the Merkle proofs of the IBC provable store.
+fn hash(data: &[u8]) -> [u8; 32] {
+    use sha2::Digest;
+
+    sha2::Sha256::digest(data).into()
+}
+
+#[cfg(test)]
+mod test {
+    use subtle_encoding::hex;
+
+    use super::*;
+
+    #[test]
+    fn test_compute_packet_commitment() {
+        let expected: [u8; 32] = [
+            0xa9, 0x28, 0xb5, 0x1f, 0x62, 0xbd, 0x54, 0x00, 0x91, 0xec, 0x45, 0x1f, 0x4e, 0xf3,
+            0x45, 0x79, 0x4f, 0x05, 0x9e, 0x65, 0x91, 0x08, 0x16, 0x86, 0x61, 0x26, 0xdc, 0x36,
+            0x4f, 0x84, 0xcc, 0x15,
+        ];
+        let actual = compute_packet_commitment(
+            "packet data".as_bytes(),
+            &TimeoutHeight::At(ibc_core_client_types::height::Height::new(42, 24).unwrap()),
+            &Timestamp::from_nanoseconds(0x42).unwrap(),
+        );
+        assert_eq!(&expected[..], actual.as_ref());
+    }
+
+    #[test]
+    fn test_compute_packet_commitment_no_timeout_height() {
+        let actual = compute_packet_commitment(
+            "packet data".as_bytes(),
+            &TimeoutHeight::Never,
+            &Timestamp::from_nanoseconds(0x42).unwrap(),
+        );
+
+        // The byte layout documented on `compute_packet_commitment`
+        let mut hash_input = Vec::new();
+        hash_input.extend(0x42u64.to_be_bytes());
+        hash_input.extend(0u64.to_be_bytes());
+        hash_input.extend(0u64.to_be_bytes());
+        hash_input.extend(hash("packet data".as_bytes()));
+        assert_eq!(&hash(&hash_input)[..], actual.as_ref());
+    }
+
+    /// Known answers of ibc-go's `CommitPacket`, as (packet data, timeout
+    /// timestamp, timeout revision number, timeout revision height,
+    /// commitment), checked against ibc-go by the same table in
+    /// `ci/ibc-go-vectors/commitments_test.go`.
+    const IBC_GO_PACKET_COMMITMENTS: &[(&str, u64, u64, u64, &str)] = &[
+        (
+            "7b22616d6f756e74223a22313030222c2264656e6f6d223a227561746f6d222c227265636569766572223a22636f736d6f73317265636569766572222c2273656e646572223a22636f736d6f733173656e646572227d",
+            1_700_000_000_000_000_000,
+            1,
+            10,
+            "e19ef7663c54ba3d52d1315860438cba96ab800c3d2b60dbfdc1e6b9ed4d2856",
+        ),
+        (
+            "7b22616d6f756e74223a22313030222c2264656e6f6d223a227561746f6d222c227265636569766572223a22636f736d6f73317265636569766572222c2273656e646572223a22636f736d6f733173656e646572227d",
+            1_700_000_000_000_000_000,
+            0,
+            0,
+            "cd137d5c1140262390f31e092f5e3ae6ed83e8e67c346a9b5bcba735f5d8db3f",
+        ),
+        (
+            "7061636b65742064617461",
+            0,
+            0,
+            100,
+            "ebd3443c54f9beade96e2b663a6e1ff85dfc2f4a9dff238c896543e9b366360c",
+        ),
+        (
+            "00",
+            0x42,
+            u64::MAX,
+            u64::MAX,
+            "876d425fbee421a95a21edb3dc811f163e8a942daa6cc86e7afcbb51ed12edf1",
+        ),
+    ];
+
+    /// Known answers of ibc-go's `CommitAcknowledgement`, as
+    /// (acknowledgement, commitment), checked against ibc-go by the same
+    /// table in `ci/ibc-go-vectors/commitments_test.go`.
+    const IBC_GO_ACK_COMMITMENTS: &[(&str, &str)] = &[
+        (
+            "7b22726573756c74223a2241513d3d227d",
+            "08f7557ed51826fe18d84512bf24ec75001edbaf2123a477df72a0a9f3640a7c",
+        ),
+        (
+            "7b226572726f72223a224142434920636f64653a20313a206572726f722068616e646c696e67207061636b65743a20736565206576656e747320666f722064657461696c73227d",
+            "439dd0ea54f168850977fce615993ac34f8e2d238c76c0c9328a744e89a2230d",
+        ),
+        (
+            "00010203",
+            "054edec1d0211f624fed0cbca9d4f9400b0e491c43742af2c5b0abebf0c990d8",
+        ),
+    ];
+
+    #[test]
+    fn test_ibc_go_commitment_vectors() {
+        for &(data, timestamp, revision_number, revision_height, expected) in
+            IBC_GO_PACKET_COMMITMENTS
+        {
+            // ibc-go encodes no timeout height as the zero height.
+            let timeout_height = match (revision_number, revision_height) {
+                (0, 0) => TimeoutHeight::Never,
+                _ => TimeoutHeight::At(
+                    ibc_core_client_types::height::Height::new(revision_number, revision_height)
+                        .unwrap(),
+                ),
+            };
+            let actual = compute_packet_commitment(
+                &hex::decode(data).unwrap(),
+                &timeout_height,
+                &Timestamp::from_nanoseconds(timestamp).unwrap(),
+            );
+            assert_eq!(hex::decode(expected).unwrap(), actual.into_vec(), "{data}");
+        }
+
+        for &(ack, expected) in IBC_GO_ACK_COMMITMENTS {
+            let ack = Acknowledgement::try_from(hex::decode(ack).unwrap()).unwrap();
+            let actual = compute_ack_commitment(&ack);
+            assert_eq!(hex::decode(expected).unwrap(), actual.into_vec());
+        }
+    }
+
+    #[test]
+    fn test_compute_ack_commitment() {
+        let expected: [u8; 32] = [
+            0x05, 0x4e, 0xde, 0xc1, 0xd0, 0x21, 0x1f, 0x62, 0x4f, 0xed, 0x0c, 0xbc, 0xa9, 0xd4,
+            0xf9, 0x40, 0x0b, 0x0e, 0x49, 0x1c, 0x43, 0x74, 0x2a, 0xf2, 0xc5, 0xb0, 0xab, 0xeb,
+            0xf0, 0xc9, 0x90, 0xd8,
+        ];
+        let ack = Acknowledgement::try_from(vec![0, 1, 2, 3]).unwrap();
+        let actual = compute_ack_commitment(&ack);
+        assert_eq!(&expected[..], actual.as_ref())
+    }
+}
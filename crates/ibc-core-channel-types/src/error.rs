@@ -1,18 +1,19 @@
 //! Defines the main channel, port and packet error types
 
 use displaydoc::Display;
+use ibc_core_client_types::error as client_error;
+use ibc_core_client_types::height::Height;
+use ibc_core_connection_types::error as connection_error;
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, IdentifierError, PortId};
+use ibc_primitives::impl_error_sources;
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::{ParseTimestampError, Timestamp};
 
 use super::channel::Counterparty;
-use super::packet::Sequence;
 use super::timeout::TimeoutHeight;
-use crate::core::ics02_client::error as client_error;
-use crate::core::ics03_connection::error as connection_error;
-use crate::core::ics04_channel::channel::State;
-use crate::core::ics04_channel::Version;
-use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, IdentifierError, PortId};
-use crate::core::timestamp::{ParseTimestampError, Timestamp};
-use crate::prelude::*;
-use crate::Height;
+use crate::channel::State;
+use crate::packet::Sequence;
+use crate::version::Version;
 
 #[derive(Debug, Display)]
 pub enum ChannelError {
@@ -36,6 +37,8 @@ pub enum ChannelError {
     MissingCounterparty,
     /// version not supported: expected `{expected}`, actual `{actual}`
     VersionNotSupported { expected: Version, actual: Version },
+    /// invalid version metadata `{version}`: `{reason}`
+    InvalidVersionMetadata { version: Version, reason: String },
     /// missing channel end
     MissingChannel,
     /// the channel end (`{port_id}`, `{channel_id}`) does not exist
@@ -62,12 +65,16 @@ pub enum ChannelError {
     },
     /// application module error: `{description}`
     AppModule { description: String },
+    /// the application vetoed the closing of the channel: `{0}`
+    CloseVetoed(ChannelCloseVeto),
     /// Undefined counterparty connection for `{connection_id}`
     UndefinedConnectionCounterparty { connection_id: ConnectionId },
     /// invalid proof: empty proof
     InvalidProof,
     /// identifier error: `{0}`
     InvalidIdentifier(IdentifierError),
+    /// invalid channel genesis: `{reason}`
+    InvalidGenesis { reason: String },
     /// channel counter overflow error
     CounterOverflow,
     /// other error: `{description}`
@@ -119,6 +126,8 @@ pub enum PacketError {
     InvalidAcknowledgement,
     /// Acknowledgment status cannot be empty
     EmptyAcknowledgementStatus,
+    /// invalid acknowledgement status: `{reason}`
+    InvalidAcknowledgementStatus { reason: String },
     /// Acknowledgment for the packet `{sequence}` not found
     PacketAcknowledgementNotFound { sequence: Sequence },
     /// invalid proof: missing height
@@ -133,6 +142,8 @@ pub enum PacketError {
     RouteNotFound,
     /// packet sequence cannot be 0
     ZeroPacketSequence,
+    /// packet data is `{size}` bytes long, exceeding the maximum of `{max_size}` bytes
+    PacketDataTooLarge { size: usize, max_size: usize },
     /// packet data bytes cannot be empty
     ZeroPacketData,
     /// invalid timeout height for the packet
@@ -153,6 +164,11 @@ pub enum PacketError {
         port_id: PortId,
         channel_id: ChannelId,
     },
+    /// the channel end (`{port_id}`, `{channel_id}`) is not a localhost channel end
+    NotLocalhostChannel {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
     /// Commitment for the packet `{sequence}` not found
     PacketCommitmentNotFound { sequence: Sequence },
     /// Missing sequence number for receiving packets on port `{port_id}` and channel `{channel_id}`
@@ -167,10 +183,32 @@ pub enum PacketError {
     },
     /// Cannot encode sequence `{sequence}`
     CannotEncodeSequence { sequence: Sequence },
+    /// the proof that the packet `{sequence}` was not received is not a receipt bitmap proof
+    InvalidReceiptBitmapProof { sequence: Sequence },
+    /// the receipt bitmap of the packet `{sequence}` records it as received
+    PacketReceived { sequence: Sequence },
     /// other error: `{description}`
     Other { description: String },
 }
 
+/// Why an application refuses to close one of its channels, as reported by
+/// its `Module` channel closing callbacks.
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum ChannelCloseVeto {
+    /// the application does not allow its channels to be closed
+    NotAllowed,
+    /// `{count}` packets sent on the channel are still in flight
+    PacketsInFlight { count: u64 },
+    /// `{reason}`
+    Other { reason: String },
+}
+
+impl From<ChannelCloseVeto> for ChannelError {
+    fn from(veto: ChannelCloseVeto) -> Self {
+        Self::CloseVetoed(veto)
+    }
+}
+
 impl From<IdentifierError> for ChannelError {
     fn from(err: IdentifierError) -> Self {
         Self::InvalidIdentifier(err)
@@ -183,28 +221,22 @@ impl From<IdentifierError> for PacketError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for PacketError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self {
-            Self::Connection(e) => Some(e),
-            Self::Channel(e) => Some(e),
-            Self::InvalidIdentifier(e) => Some(e),
-            _ => None,
-        }
+impl_error_sources!(PacketError {
+    ibc {
+        Self::Connection(e) => e,
+        Self::Channel(e) => e,
+        Self::InvalidPacketTimestamp(e) => e,
+        Self::InvalidIdentifier(e) => e,
     }
-}
+});
 
-#[cfg(feature = "std")]
-impl std::error::Error for ChannelError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self {
-            Self::InvalidIdentifier(e) => Some(e),
-            Self::PacketVerificationFailed {
-                client_error: e, ..
-            } => Some(e),
-            Self::InvalidStringAsSequence { error: e, .. } => Some(e),
-            _ => None,
-        }
+impl_error_sources!(ChannelError {
+    ibc {
+        Self::InvalidIdentifier(e) => e,
+        Self::PacketVerificationFailed { client_error: e, .. } => e,
+        Self::VerifyChannelFailed(e) => e,
     }
-}
+    std {
+        Self::InvalidStringAsSequence { error: e, .. } => e,
+    }
+});
@@ -3,6 +3,10 @@
 mod channel_attributes;
 mod packet_attributes;
 
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use ibc_primitives::timestamp::Timestamp;
 use tendermint::abci;
 
 use self::channel_attributes::{
@@ -10,21 +14,21 @@ use self::channel_attributes::{
     CounterpartyPortIdAttribute, PortIdAttribute, VersionAttribute,
     COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY,
 };
+pub use self::packet_attributes::AttributeRef;
 use self::packet_attributes::{
-    AcknowledgementAttribute, ChannelOrderingAttribute, DstChannelIdAttribute, DstPortIdAttribute,
-    PacketConnectionIdAttribute, PacketDataAttribute, SequenceAttribute, SrcChannelIdAttribute,
-    SrcPortIdAttribute, TimeoutHeightAttribute, TimeoutTimestampAttribute,
+    AckCommitmentAttribute, AcknowledgementAttribute, ChannelOrderingAttribute,
+    DstChannelIdAttribute, DstPortIdAttribute, PacketConnectionIdAttribute, PacketDataAttribute,
+    RecvTimestampAttribute, RelayerAttribute, SendTimestampAttribute, SequenceAttribute,
+    SrcChannelIdAttribute, SrcPortIdAttribute, TimeoutHeightAttribute, TimeoutOnCloseAttribute,
+    TimeoutTimestampAttribute,
 };
 use super::acknowledgement::Acknowledgement;
 use super::channel::Order;
-use super::packet::Sequence;
+use super::commitment::{compute_ack_commitment, AcknowledgementCommitment};
 use super::timeout::TimeoutHeight;
-use super::Version;
-use crate::core::ics04_channel::error::ChannelError;
-use crate::core::ics04_channel::packet::Packet;
-use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
-use crate::core::timestamp::Timestamp;
-use crate::prelude::*;
+use crate::error::ChannelError;
+use crate::packet::{Packet, Sequence};
+use crate::version::Version;
 
 /// Channel event types
 const CHANNEL_OPEN_INIT_EVENT: &str = "channel_open_init";
@@ -606,6 +610,7 @@ pub struct SendPacket {
     chan_id_attr_on_b: DstChannelIdAttribute,
     channel_ordering_attr: ChannelOrderingAttribute,
     conn_id_attr_on_a: PacketConnectionIdAttribute,
+    send_timestamp_attr: Option<SendTimestampAttribute>,
 }
 
 impl SendPacket {
@@ -621,9 +626,16 @@ impl SendPacket {
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             channel_ordering_attr: channel_ordering.into(),
             conn_id_attr_on_a: src_connection_id.into(),
+            send_timestamp_attr: None,
         }
     }
 
+    /// Records the host timestamp at which the packet was sent.
+    pub fn with_send_timestamp(mut self, send_timestamp: Timestamp) -> Self {
+        self.send_timestamp_attr = Some(send_timestamp.into());
+        self
+    }
+
     pub fn packet_data(&self) -> &[u8] {
         &self.packet_data_attr.packet_data
     }
@@ -660,34 +672,63 @@ impl SendPacket {
         &self.channel_ordering_attr.order
     }
 
+    /// Returns the packet that was sent.
+    pub fn packet(&self) -> Packet {
+        Packet {
+            seq_on_a: *self.seq_on_a(),
+            port_id_on_a: self.port_id_on_a().clone(),
+            chan_id_on_a: self.chan_id_on_a().clone(),
+            port_id_on_b: self.port_id_on_b().clone(),
+            chan_id_on_b: self.chan_id_on_b().clone(),
+            data: self.packet_data().to_vec(),
+            timeout_height_on_b: *self.timeout_height_on_b(),
+            timeout_timestamp_on_b: *self.timeout_timestamp_on_b(),
+        }
+    }
+
     pub fn conn_id_on_a(&self) -> &ConnectionId {
         &self.conn_id_attr_on_a.connection_id
     }
 
+    pub fn send_timestamp(&self) -> Option<&Timestamp> {
+        self.send_timestamp_attr
+            .as_ref()
+            .map(|attr| &attr.send_timestamp)
+    }
+
     pub fn event_type(&self) -> &str {
         SEND_PACKET_EVENT
     }
+
+    /// Returns the attributes of the event, borrowing its identifiers.
+    pub fn attributes(&self) -> Result<Vec<AttributeRef<'_>>, ChannelError> {
+        let mut attributes = Vec::with_capacity(12);
+        attributes.extend(self.packet_data_attr.attributes()?);
+        attributes.push(self.timeout_height_attr_on_b.attribute());
+        attributes.push(self.timeout_timestamp_attr_on_b.attribute());
+        attributes.push(self.seq_attr_on_a.attribute());
+        attributes.push(self.port_id_attr_on_a.attribute());
+        attributes.push(self.chan_id_attr_on_a.attribute());
+        attributes.push(self.port_id_attr_on_b.attribute());
+        attributes.push(self.chan_id_attr_on_b.attribute());
+        attributes.push(self.channel_ordering_attr.attribute());
+        attributes.push(self.conn_id_attr_on_a.attribute());
+        attributes.extend(
+            self.send_timestamp_attr
+                .as_ref()
+                .map(|attr| attr.attribute()),
+        );
+        Ok(attributes)
+    }
 }
 
 impl TryFrom<SendPacket> for abci::Event {
     type Error = ChannelError;
 
     fn try_from(v: SendPacket) -> Result<Self, Self::Error> {
-        let mut attributes = Vec::with_capacity(11);
-        attributes.append(&mut v.packet_data_attr.try_into()?);
-        attributes.push(v.timeout_height_attr_on_b.into());
-        attributes.push(v.timeout_timestamp_attr_on_b.into());
-        attributes.push(v.seq_attr_on_a.into());
-        attributes.push(v.port_id_attr_on_a.into());
-        attributes.push(v.chan_id_attr_on_a.into());
-        attributes.push(v.port_id_attr_on_b.into());
-        attributes.push(v.chan_id_attr_on_b.into());
-        attributes.push(v.channel_ordering_attr.into());
-        attributes.push(v.conn_id_attr_on_a.into());
-
         Ok(abci::Event {
             kind: SEND_PACKET_EVENT.to_string(),
-            attributes,
+            attributes: v.attributes()?.into_iter().map(Into::into).collect(),
         })
     }
 }
@@ -717,6 +758,8 @@ pub struct ReceivePacket {
     chan_id_attr_on_b: DstChannelIdAttribute,
     channel_ordering_attr: ChannelOrderingAttribute,
     conn_id_attr_on_b: PacketConnectionIdAttribute,
+    recv_timestamp_attr: Option<RecvTimestampAttribute>,
+    relayer_attr: Option<RelayerAttribute>,
 }
 
 impl ReceivePacket {
@@ -732,9 +775,24 @@ impl ReceivePacket {
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             channel_ordering_attr: channel_ordering.into(),
             conn_id_attr_on_b: dst_connection_id.into(),
+            recv_timestamp_attr: None,
+            relayer_attr: None,
         }
     }
 
+    /// Records the host timestamp at which the packet was received.
+    pub fn with_recv_timestamp(mut self, recv_timestamp: Timestamp) -> Self {
+        self.recv_timestamp_attr = Some(recv_timestamp.into());
+        self
+    }
+
+    /// Records the relayer which delivered the packet, i.e. the signer of the
+    /// message.
+    pub fn with_relayer(mut self, relayer: Signer) -> Self {
+        self.relayer_attr = Some(relayer.into());
+        self
+    }
+
     pub fn packet_data(&self) -> &[u8] {
         &self.packet_data_attr.packet_data
     }
@@ -775,30 +833,50 @@ impl ReceivePacket {
         &self.conn_id_attr_on_b.connection_id
     }
 
+    pub fn recv_timestamp(&self) -> Option<&Timestamp> {
+        self.recv_timestamp_attr
+            .as_ref()
+            .map(|attr| &attr.recv_timestamp)
+    }
+
+    pub fn relayer(&self) -> Option<&Signer> {
+        self.relayer_attr.as_ref().map(|attr| &attr.relayer)
+    }
+
     pub fn event_type(&self) -> &str {
         RECEIVE_PACKET_EVENT
     }
+
+    /// Returns the attributes of the event, borrowing its identifiers.
+    pub fn attributes(&self) -> Result<Vec<AttributeRef<'_>>, ChannelError> {
+        let mut attributes = Vec::with_capacity(13);
+        attributes.extend(self.packet_data_attr.attributes()?);
+        attributes.push(self.timeout_height_attr_on_b.attribute());
+        attributes.push(self.timeout_timestamp_attr_on_b.attribute());
+        attributes.push(self.seq_attr_on_a.attribute());
+        attributes.push(self.port_id_attr_on_a.attribute());
+        attributes.push(self.chan_id_attr_on_a.attribute());
+        attributes.push(self.port_id_attr_on_b.attribute());
+        attributes.push(self.chan_id_attr_on_b.attribute());
+        attributes.push(self.channel_ordering_attr.attribute());
+        attributes.push(self.conn_id_attr_on_b.attribute());
+        attributes.extend(
+            self.recv_timestamp_attr
+                .as_ref()
+                .map(|attr| attr.attribute()),
+        );
+        attributes.extend(self.relayer_attr.as_ref().map(|attr| attr.attribute()));
+        Ok(attributes)
+    }
 }
 
 impl TryFrom<ReceivePacket> for abci::Event {
     type Error = ChannelError;
 
     fn try_from(v: ReceivePacket) -> Result<Self, Self::Error> {
-        let mut attributes = Vec::with_capacity(11);
-        attributes.append(&mut v.packet_data_attr.try_into()?);
-        attributes.push(v.timeout_height_attr_on_b.into());
-        attributes.push(v.timeout_timestamp_attr_on_b.into());
-        attributes.push(v.seq_attr_on_a.into());
-        attributes.push(v.port_id_attr_on_a.into());
-        attributes.push(v.chan_id_attr_on_a.into());
-        attributes.push(v.port_id_attr_on_b.into());
-        attributes.push(v.chan_id_attr_on_b.into());
-        attributes.push(v.channel_ordering_attr.into());
-        attributes.push(v.conn_id_attr_on_b.into());
-
         Ok(abci::Event {
             kind: RECEIVE_PACKET_EVENT.to_string(),
-            attributes,
+            attributes: v.attributes()?.into_iter().map(Into::into).collect(),
         })
     }
 }
@@ -828,6 +906,7 @@ pub struct WriteAcknowledgement {
     chan_id_attr_on_b: DstChannelIdAttribute,
     acknowledgement: AcknowledgementAttribute,
     conn_id_attr_on_b: PacketConnectionIdAttribute,
+    ack_commitment_attr: AckCommitmentAttribute,
 }
 
 impl WriteAcknowledgement {
@@ -845,6 +924,7 @@ impl WriteAcknowledgement {
             chan_id_attr_on_a: packet.chan_id_on_a.into(),
             port_id_attr_on_b: packet.port_id_on_b.into(),
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
+            ack_commitment_attr: compute_ack_commitment(&acknowledgement).into(),
             acknowledgement: acknowledgement.into(),
             conn_id_attr_on_b: conn_id_on_b.into(),
         }
@@ -886,34 +966,57 @@ impl WriteAcknowledgement {
         &self.acknowledgement.acknowledgement
     }
 
+    /// Returns the packet that was acknowledged.
+    pub fn packet(&self) -> Packet {
+        Packet {
+            seq_on_a: *self.seq_on_a(),
+            port_id_on_a: self.port_id_on_a().clone(),
+            chan_id_on_a: self.chan_id_on_a().clone(),
+            port_id_on_b: self.port_id_on_b().clone(),
+            chan_id_on_b: self.chan_id_on_b().clone(),
+            data: self.packet_data().to_vec(),
+            timeout_height_on_b: *self.timeout_height_on_b(),
+            timeout_timestamp_on_b: *self.timeout_timestamp_on_b(),
+        }
+    }
+
     pub fn conn_id_on_b(&self) -> &ConnectionId {
         &self.conn_id_attr_on_b.connection_id
     }
 
+    pub fn ack_commitment(&self) -> &AcknowledgementCommitment {
+        &self.ack_commitment_attr.ack_commitment
+    }
+
     pub fn event_type(&self) -> &str {
         WRITE_ACK_EVENT
     }
+
+    /// Returns the attributes of the event, borrowing its identifiers.
+    pub fn attributes(&self) -> Result<Vec<AttributeRef<'_>>, ChannelError> {
+        let mut attributes = Vec::with_capacity(12);
+        attributes.extend(self.packet_data.attributes()?);
+        attributes.push(self.timeout_height_attr_on_b.attribute());
+        attributes.push(self.timeout_timestamp_attr_on_b.attribute());
+        attributes.push(self.seq_attr_on_a.attribute());
+        attributes.push(self.port_id_attr_on_a.attribute());
+        attributes.push(self.chan_id_attr_on_a.attribute());
+        attributes.push(self.port_id_attr_on_b.attribute());
+        attributes.push(self.chan_id_attr_on_b.attribute());
+        attributes.extend(self.acknowledgement.attributes()?);
+        attributes.push(self.conn_id_attr_on_b.attribute());
+        attributes.push(self.ack_commitment_attr.attribute());
+        Ok(attributes)
+    }
 }
 
 impl TryFrom<WriteAcknowledgement> for abci::Event {
     type Error = ChannelError;
 
     fn try_from(v: WriteAcknowledgement) -> Result<Self, Self::Error> {
-        let mut attributes = Vec::with_capacity(11);
-        attributes.append(&mut v.packet_data.try_into()?);
-        attributes.push(v.timeout_height_attr_on_b.into());
-        attributes.push(v.timeout_timestamp_attr_on_b.into());
-        attributes.push(v.seq_attr_on_a.into());
-        attributes.push(v.port_id_attr_on_a.into());
-        attributes.push(v.chan_id_attr_on_a.into());
-        attributes.push(v.port_id_attr_on_b.into());
-        attributes.push(v.chan_id_attr_on_b.into());
-        attributes.append(&mut v.acknowledgement.try_into()?);
-        attributes.push(v.conn_id_attr_on_b.into());
-
         Ok(abci::Event {
             kind: WRITE_ACK_EVENT.to_string(),
-            attributes,
+            attributes: v.attributes()?.into_iter().map(Into::into).collect(),
         })
     }
 }
@@ -942,10 +1045,17 @@ pub struct AcknowledgePacket {
     chan_id_attr_on_b: DstChannelIdAttribute,
     channel_ordering_attr: ChannelOrderingAttribute,
     conn_id_attr_on_a: PacketConnectionIdAttribute,
+    ack_commitment_attr: AckCommitmentAttribute,
+    relayer_attr: Option<RelayerAttribute>,
 }
 
 impl AcknowledgePacket {
-    pub fn new(packet: Packet, channel_ordering: Order, src_connection_id: ConnectionId) -> Self {
+    pub fn new(
+        packet: Packet,
+        channel_ordering: Order,
+        src_connection_id: ConnectionId,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Self {
         Self {
             timeout_height_attr_on_b: packet.timeout_height_on_b.into(),
             timeout_timestamp_attr_on_b: packet.timeout_timestamp_on_b.into(),
@@ -956,9 +1066,18 @@ impl AcknowledgePacket {
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             channel_ordering_attr: channel_ordering.into(),
             conn_id_attr_on_a: src_connection_id.into(),
+            ack_commitment_attr: ack_commitment.into(),
+            relayer_attr: None,
         }
     }
 
+    /// Records the relayer which delivered the acknowledgement, i.e. the signer of
+    /// the message.
+    pub fn with_relayer(mut self, relayer: Signer) -> Self {
+        self.relayer_attr = Some(relayer.into());
+        self
+    }
+
     pub fn timeout_height_on_b(&self) -> &TimeoutHeight {
         &self.timeout_height_attr_on_b.timeout_height
     }
@@ -995,9 +1114,35 @@ impl AcknowledgePacket {
         &self.conn_id_attr_on_a.connection_id
     }
 
+    pub fn ack_commitment(&self) -> &AcknowledgementCommitment {
+        &self.ack_commitment_attr.ack_commitment
+    }
+
+    pub fn relayer(&self) -> Option<&Signer> {
+        self.relayer_attr.as_ref().map(|attr| &attr.relayer)
+    }
+
     pub fn event_type(&self) -> &str {
         ACK_PACKET_EVENT
     }
+
+    /// Returns the attributes of the event, borrowing its identifiers.
+    pub fn attributes(&self) -> Result<Vec<AttributeRef<'_>>, ChannelError> {
+        let mut attributes = vec![
+            self.timeout_height_attr_on_b.attribute(),
+            self.timeout_timestamp_attr_on_b.attribute(),
+            self.seq_on_a.attribute(),
+            self.port_id_attr_on_a.attribute(),
+            self.chan_id_attr_on_a.attribute(),
+            self.port_id_attr_on_b.attribute(),
+            self.chan_id_attr_on_b.attribute(),
+            self.channel_ordering_attr.attribute(),
+            self.conn_id_attr_on_a.attribute(),
+            self.ack_commitment_attr.attribute(),
+        ];
+        attributes.extend(self.relayer_attr.as_ref().map(|attr| attr.attribute()));
+        Ok(attributes)
+    }
 }
 
 impl TryFrom<AcknowledgePacket> for abci::Event {
@@ -1006,17 +1151,7 @@ impl TryFrom<AcknowledgePacket> for abci::Event {
     fn try_from(v: AcknowledgePacket) -> Result<Self, Self::Error> {
         Ok(abci::Event {
             kind: ACK_PACKET_EVENT.to_string(),
-            attributes: vec![
-                v.timeout_height_attr_on_b.into(),
-                v.timeout_timestamp_attr_on_b.into(),
-                v.seq_on_a.into(),
-                v.port_id_attr_on_a.into(),
-                v.chan_id_attr_on_a.into(),
-                v.port_id_attr_on_b.into(),
-                v.chan_id_attr_on_b.into(),
-                v.channel_ordering_attr.into(),
-                v.conn_id_attr_on_a.into(),
-            ],
+            attributes: v.attributes()?.into_iter().map(Into::into).collect(),
         })
     }
 }
@@ -1044,6 +1179,8 @@ pub struct TimeoutPacket {
     port_id_attr_on_b: DstPortIdAttribute,
     chan_id_attr_on_b: DstChannelIdAttribute,
     channel_ordering_attr: ChannelOrderingAttribute,
+    on_close_attr: Option<TimeoutOnCloseAttribute>,
+    relayer_attr: Option<RelayerAttribute>,
 }
 
 impl TimeoutPacket {
@@ -1057,9 +1194,25 @@ impl TimeoutPacket {
             port_id_attr_on_b: packet.port_id_on_b.into(),
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             channel_ordering_attr: channel_ordering.into(),
+            on_close_attr: None,
+            relayer_attr: None,
         }
     }
 
+    /// Marks the timeout as caused by the closing of the counterparty channel
+    /// end, so that relayers can tell it apart from an ordinary timeout.
+    pub fn with_on_close(mut self) -> Self {
+        self.on_close_attr = Some(TimeoutOnCloseAttribute);
+        self
+    }
+
+    /// Records the relayer which delivered the timeout, i.e. the signer of
+    /// the message.
+    pub fn with_relayer(mut self, relayer: Signer) -> Self {
+        self.relayer_attr = Some(relayer.into());
+        self
+    }
+
     pub fn timeout_height_on_b(&self) -> &TimeoutHeight {
         &self.timeout_height_attr_on_b.timeout_height
     }
@@ -1092,9 +1245,35 @@ impl TimeoutPacket {
         &self.channel_ordering_attr.order
     }
 
+    /// Whether the timeout was delivered by a `MsgTimeoutOnClose`.
+    pub fn is_on_close(&self) -> bool {
+        self.on_close_attr.is_some()
+    }
+
+    pub fn relayer(&self) -> Option<&Signer> {
+        self.relayer_attr.as_ref().map(|attr| &attr.relayer)
+    }
+
     pub fn event_type(&self) -> &str {
         TIMEOUT_EVENT
     }
+
+    /// Returns the attributes of the event, borrowing its identifiers.
+    pub fn attributes(&self) -> Result<Vec<AttributeRef<'_>>, ChannelError> {
+        let mut attributes = vec![
+            self.timeout_height_attr_on_b.attribute(),
+            self.timeout_timestamp_attr_on_b.attribute(),
+            self.seq_attr_on_a.attribute(),
+            self.port_id_attr_on_a.attribute(),
+            self.chan_id_attr_on_a.attribute(),
+            self.port_id_attr_on_b.attribute(),
+            self.chan_id_attr_on_b.attribute(),
+            self.channel_ordering_attr.attribute(),
+        ];
+        attributes.extend(self.on_close_attr.as_ref().map(|attr| attr.attribute()));
+        attributes.extend(self.relayer_attr.as_ref().map(|attr| attr.attribute()));
+        Ok(attributes)
+    }
 }
 
 impl TryFrom<TimeoutPacket> for abci::Event {
@@ -1103,22 +1282,15 @@ impl TryFrom<TimeoutPacket> for abci::Event {
     fn try_from(v: TimeoutPacket) -> Result<Self, Self::Error> {
         Ok(abci::Event {
             kind: TIMEOUT_EVENT.to_string(),
-            attributes: vec![
-                v.timeout_height_attr_on_b.into(),
-                v.timeout_timestamp_attr_on_b.into(),
-                v.seq_attr_on_a.into(),
-                v.port_id_attr_on_a.into(),
-                v.chan_id_attr_on_a.into(),
-                v.port_id_attr_on_b.into(),
-                v.chan_id_attr_on_b.into(),
-                v.channel_ordering_attr.into(),
-            ],
+            attributes: v.attributes()?.into_iter().map(Into::into).collect(),
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::borrow::Cow;
+
     use tendermint::abci::Event as AbciEvent;
 
     use super::*;
@@ -1257,4 +1429,102 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn packet_event_attributes_borrow_identifiers() {
+        let packet = Packet {
+            seq_on_a: 1.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: b"{}".to_vec(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+        let event = SendPacket::new(packet, Order::Unordered, ConnectionId::new(0));
+
+        let attributes = event.attributes().unwrap();
+        assert!(attributes
+            .iter()
+            .any(|attr| attr.key == "packet_src_channel"
+                && matches!(attr.value, Cow::Borrowed("channel-0"))));
+
+        let abci_event = AbciEvent::try_from(event.clone()).unwrap();
+        assert_eq!(
+            abci_event.attributes,
+            attributes
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<abci::EventAttribute>>()
+        );
+    }
+
+    #[test]
+    fn packet_events_carry_optional_metadata() {
+        let packet = Packet {
+            seq_on_a: 1.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: b"{}".to_vec(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+        let send_timestamp = Timestamp::from_nanoseconds(1).unwrap();
+        let recv_timestamp = Timestamp::from_nanoseconds(2).unwrap();
+        let relayer = Signer::from("relayer".to_string());
+
+        let event = SendPacket::new(packet.clone(), Order::Unordered, ConnectionId::new(0));
+        assert_eq!(event.send_timestamp(), None);
+        let attributes_len = event.attributes().unwrap().len();
+        let event = event.with_send_timestamp(send_timestamp);
+        assert_eq!(event.send_timestamp(), Some(&send_timestamp));
+        assert_eq!(
+            event.attributes().unwrap().last(),
+            Some(&AttributeRef {
+                key: "packet_send_timestamp",
+                value: Cow::Owned("1".to_string()),
+            })
+        );
+        assert_eq!(event.attributes().unwrap().len(), attributes_len + 1);
+
+        let event = ReceivePacket::new(packet.clone(), Order::Unordered, ConnectionId::new(1))
+            .with_recv_timestamp(recv_timestamp)
+            .with_relayer(relayer.clone());
+        assert_eq!(event.recv_timestamp(), Some(&recv_timestamp));
+        assert_eq!(event.relayer(), Some(&relayer));
+        let attributes = event.attributes().unwrap();
+        assert_eq!(
+            attributes[attributes.len() - 2..],
+            [
+                AttributeRef {
+                    key: "packet_recv_timestamp",
+                    value: Cow::Owned("2".to_string()),
+                },
+                AttributeRef {
+                    key: "packet_relayer",
+                    value: Cow::Borrowed("relayer"),
+                },
+            ]
+        );
+
+        let event = TimeoutPacket::new(packet, Order::Unordered);
+        assert_eq!(event.relayer(), None);
+        assert!(!event.is_on_close());
+        let attributes_len = event.attributes().unwrap().len();
+        let event = event.with_on_close().with_relayer(relayer.clone());
+        assert!(event.is_on_close());
+        assert_eq!(event.relayer(), Some(&relayer));
+        let attributes = event.attributes().unwrap();
+        assert_eq!(attributes.len(), attributes_len + 2);
+        assert_eq!(
+            attributes[attributes_len],
+            AttributeRef {
+                key: "packet_timeout_on_close",
+                value: Cow::Borrowed("true"),
+            }
+        );
+    }
 }
@@ -1,10 +1,10 @@
 //! This module holds all the abci event attributes for IBC events emitted
 //! during the channel handshake.
 use derive_more::From;
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
 use tendermint::abci;
 
-use crate::core::ics04_channel::Version;
-use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+use crate::version::Version;
 
 const CONNECTION_ID_ATTRIBUTE_KEY: &str = "connection_id";
 const CHANNEL_ID_ATTRIBUTE_KEY: &str = "channel_id";
@@ -0,0 +1,597 @@
+//! This module holds all the abci event attributes for IBC events emitted
+//! during packet-related datagrams.
+//!
+use alloc::borrow::Cow;
+use core::str;
+
+use derive_more::From;
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use ibc_primitives::timestamp::Timestamp;
+use subtle_encoding::hex;
+use tendermint::abci;
+
+use crate::acknowledgement::Acknowledgement;
+use crate::channel::Order;
+use crate::commitment::AcknowledgementCommitment;
+use crate::error::ChannelError;
+use crate::packet::Sequence;
+use crate::timeout::TimeoutHeight;
+
+const PKT_SEQ_ATTRIBUTE_KEY: &str = "packet_sequence";
+const PKT_DATA_ATTRIBUTE_KEY: &str = "packet_data";
+const PKT_DATA_HEX_ATTRIBUTE_KEY: &str = "packet_data_hex";
+const PKT_SRC_PORT_ATTRIBUTE_KEY: &str = "packet_src_port";
+const PKT_SRC_CHANNEL_ATTRIBUTE_KEY: &str = "packet_src_channel";
+const PKT_DST_PORT_ATTRIBUTE_KEY: &str = "packet_dst_port";
+const PKT_DST_CHANNEL_ATTRIBUTE_KEY: &str = "packet_dst_channel";
+const PKT_CHANNEL_ORDERING_ATTRIBUTE_KEY: &str = "packet_channel_ordering";
+const PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY: &str = "packet_timeout_height";
+const PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_timeout_timestamp";
+const PKT_ACK_ATTRIBUTE_KEY: &str = "packet_ack";
+const PKT_ACK_HEX_ATTRIBUTE_KEY: &str = "packet_ack_hex";
+const PKT_CONNECTION_ID_ATTRIBUTE_KEY: &str = "packet_connection";
+const PKT_ACK_COMMITMENT_ATTRIBUTE_KEY: &str = "packet_ack_commitment";
+const PKT_SEND_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_send_timestamp";
+const PKT_RECV_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_recv_timestamp";
+const PKT_RELAYER_ATTRIBUTE_KEY: &str = "packet_relayer";
+const PKT_TIMEOUT_ON_CLOSE_ATTRIBUTE_KEY: &str = "packet_timeout_on_close";
+
+/// An attribute of a packet event, borrowing its value from the event where
+/// possible, through which hosts emitting the events of large batches of
+/// packets write them to their sink without allocating for each identifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttributeRef<'a> {
+    pub key: &'static str,
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> AttributeRef<'a> {
+    fn borrowed(key: &'static str, value: &'a str) -> Self {
+        Self {
+            key,
+            value: Cow::Borrowed(value),
+        }
+    }
+
+    fn owned(key: &'static str, value: String) -> Self {
+        Self {
+            key,
+            value: Cow::Owned(value),
+        }
+    }
+}
+
+impl From<AttributeRef<'_>> for abci::EventAttribute {
+    fn from(attr: AttributeRef<'_>) -> Self {
+        (attr.key, attr.value.into_owned()).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct PacketDataAttribute {
+    pub packet_data: Vec<u8>,
+}
+
+impl PacketDataAttribute {
+    pub fn attributes(&self) -> Result<[AttributeRef<'_>; 2], ChannelError> {
+        Ok([
+            AttributeRef::borrowed(
+                PKT_DATA_ATTRIBUTE_KEY,
+                str::from_utf8(&self.packet_data).map_err(|_| ChannelError::NonUtf8PacketData)?,
+            ),
+            AttributeRef::owned(
+                PKT_DATA_HEX_ATTRIBUTE_KEY,
+                String::from_utf8(hex::encode(&self.packet_data))
+                    .expect("Never fails because hexadecimal is valid UTF8"),
+            ),
+        ])
+    }
+}
+
+impl TryFrom<PacketDataAttribute> for Vec<abci::EventAttribute> {
+    type Error = ChannelError;
+
+    fn try_from(attr: PacketDataAttribute) -> Result<Self, Self::Error> {
+        Ok(attr.attributes()?.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct TimeoutHeightAttribute {
+    pub timeout_height: TimeoutHeight,
+}
+
+impl TimeoutHeightAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        match self.timeout_height {
+            TimeoutHeight::Never => AttributeRef::borrowed(PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY, "0-0"),
+            TimeoutHeight::At(height) => {
+                AttributeRef::owned(PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY, height.to_string())
+            }
+        }
+    }
+}
+
+impl From<TimeoutHeightAttribute> for abci::EventAttribute {
+    fn from(attr: TimeoutHeightAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct TimeoutTimestampAttribute {
+    pub timeout_timestamp: Timestamp,
+}
+
+impl TimeoutTimestampAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::owned(
+            PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY,
+            self.timeout_timestamp.nanoseconds().to_string(),
+        )
+    }
+}
+
+impl From<TimeoutTimestampAttribute> for abci::EventAttribute {
+    fn from(attr: TimeoutTimestampAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct SequenceAttribute {
+    pub sequence: Sequence,
+}
+
+impl SequenceAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::owned(PKT_SEQ_ATTRIBUTE_KEY, self.sequence.to_string())
+    }
+}
+
+impl From<SequenceAttribute> for abci::EventAttribute {
+    fn from(attr: SequenceAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct SrcPortIdAttribute {
+    pub src_port_id: PortId,
+}
+
+impl SrcPortIdAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::borrowed(PKT_SRC_PORT_ATTRIBUTE_KEY, self.src_port_id.as_str())
+    }
+}
+
+impl From<SrcPortIdAttribute> for abci::EventAttribute {
+    fn from(attr: SrcPortIdAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct SrcChannelIdAttribute {
+    pub src_channel_id: ChannelId,
+}
+
+impl SrcChannelIdAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::borrowed(PKT_SRC_CHANNEL_ATTRIBUTE_KEY, self.src_channel_id.as_str())
+    }
+}
+
+impl From<SrcChannelIdAttribute> for abci::EventAttribute {
+    fn from(attr: SrcChannelIdAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct DstPortIdAttribute {
+    pub dst_port_id: PortId,
+}
+
+impl DstPortIdAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::borrowed(PKT_DST_PORT_ATTRIBUTE_KEY, self.dst_port_id.as_str())
+    }
+}
+
+impl From<DstPortIdAttribute> for abci::EventAttribute {
+    fn from(attr: DstPortIdAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct DstChannelIdAttribute {
+    pub dst_channel_id: ChannelId,
+}
+
+impl DstChannelIdAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::borrowed(PKT_DST_CHANNEL_ATTRIBUTE_KEY, self.dst_channel_id.as_str())
+    }
+}
+
+impl From<DstChannelIdAttribute> for abci::EventAttribute {
+    fn from(attr: DstChannelIdAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct ChannelOrderingAttribute {
+    pub order: Order,
+}
+
+impl ChannelOrderingAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::borrowed(PKT_CHANNEL_ORDERING_ATTRIBUTE_KEY, self.order.as_str())
+    }
+}
+
+impl From<ChannelOrderingAttribute> for abci::EventAttribute {
+    fn from(attr: ChannelOrderingAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct PacketConnectionIdAttribute {
+    pub connection_id: ConnectionId,
+}
+
+impl PacketConnectionIdAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::borrowed(PKT_CONNECTION_ID_ATTRIBUTE_KEY, self.connection_id.as_str())
+    }
+}
+
+impl From<PacketConnectionIdAttribute> for abci::EventAttribute {
+    fn from(attr: PacketConnectionIdAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct AcknowledgementAttribute {
+    pub acknowledgement: Acknowledgement,
+}
+
+impl AcknowledgementAttribute {
+    pub fn attributes(&self) -> Result<[AttributeRef<'_>; 2], ChannelError> {
+        Ok([
+            AttributeRef::borrowed(
+                PKT_ACK_ATTRIBUTE_KEY,
+                // Note: this attribute forces us to assume that Packet data
+                // is valid UTF-8, even though the standard doesn't require
+                // it. It has been deprecated in ibc-go. It will be removed
+                // in the future.
+                str::from_utf8(self.acknowledgement.as_bytes())
+                    .map_err(|_| ChannelError::NonUtf8PacketData)?,
+            ),
+            AttributeRef::owned(
+                PKT_ACK_HEX_ATTRIBUTE_KEY,
+                String::from_utf8(hex::encode(self.acknowledgement.as_bytes()))
+                    .expect("Never fails because hexadecimal is always valid UTF-8"),
+            ),
+        ])
+    }
+}
+
+impl TryFrom<AcknowledgementAttribute> for Vec<abci::EventAttribute> {
+    type Error = ChannelError;
+
+    fn try_from(attr: AcknowledgementAttribute) -> Result<Self, Self::Error> {
+        Ok(attr.attributes()?.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct AckCommitmentAttribute {
+    pub ack_commitment: AcknowledgementCommitment,
+}
+
+impl AckCommitmentAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::owned(
+            PKT_ACK_COMMITMENT_ATTRIBUTE_KEY,
+            String::from_utf8(hex::encode(self.ack_commitment.as_ref()))
+                .expect("Never fails because hexadecimal is always valid UTF-8"),
+        )
+    }
+}
+
+impl From<AckCommitmentAttribute> for abci::EventAttribute {
+    fn from(attr: AckCommitmentAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct SendTimestampAttribute {
+    pub send_timestamp: Timestamp,
+}
+
+impl SendTimestampAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::owned(
+            PKT_SEND_TIMESTAMP_ATTRIBUTE_KEY,
+            self.send_timestamp.nanoseconds().to_string(),
+        )
+    }
+}
+
+impl From<SendTimestampAttribute> for abci::EventAttribute {
+    fn from(attr: SendTimestampAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct RecvTimestampAttribute {
+    pub recv_timestamp: Timestamp,
+}
+
+impl RecvTimestampAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::owned(
+            PKT_RECV_TIMESTAMP_ATTRIBUTE_KEY,
+            self.recv_timestamp.nanoseconds().to_string(),
+        )
+    }
+}
+
+impl From<RecvTimestampAttribute> for abci::EventAttribute {
+    fn from(attr: RecvTimestampAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct RelayerAttribute {
+    pub relayer: Signer,
+}
+
+impl RelayerAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::borrowed(PKT_RELAYER_ATTRIBUTE_KEY, self.relayer.as_ref())
+    }
+}
+
+impl From<RelayerAttribute> for abci::EventAttribute {
+    fn from(attr: RelayerAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
+
+/// Marks a packet timeout as caused by the closing of the counterparty
+/// channel end, i.e. as delivered by a `MsgTimeoutOnClose`.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimeoutOnCloseAttribute;
+
+impl TimeoutOnCloseAttribute {
+    pub fn attribute(&self) -> AttributeRef<'_> {
+        AttributeRef::borrowed(PKT_TIMEOUT_ON_CLOSE_ATTRIBUTE_KEY, "true")
+    }
+}
+
+impl From<TimeoutOnCloseAttribute> for abci::EventAttribute {
+    fn from(attr: TimeoutOnCloseAttribute) -> Self {
+        attr.attribute().into()
+    }
+}
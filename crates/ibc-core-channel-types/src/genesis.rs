@@ -0,0 +1,120 @@
+//! Defines the genesis state of the channel module.
+
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::channel::v1::{
+    GenesisState as RawChannelGenesisState, PacketSequence as RawPacketSequence,
+    PacketState as RawPacketState,
+};
+use ibc_proto::Protobuf;
+
+use crate::channel::IdentifiedChannelEnd;
+use crate::error::ChannelError;
+use crate::packet::{PacketState, Sequence};
+
+/// A sequence stored for a channel end, such as its next sequence to send.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketSequence {
+    pub port_id: PortId,
+    pub chan_id: ChannelId,
+    pub seq: Sequence,
+}
+
+impl Protobuf<RawPacketSequence> for PacketSequence {}
+
+impl TryFrom<RawPacketSequence> for PacketSequence {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawPacketSequence) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: raw.port_id.parse()?,
+            chan_id: raw.channel_id.parse()?,
+            seq: raw.sequence.into(),
+        })
+    }
+}
+
+impl From<PacketSequence> for RawPacketSequence {
+    fn from(sequence: PacketSequence) -> Self {
+        Self {
+            port_id: sequence.port_id.to_string(),
+            channel_id: sequence.chan_id.to_string(),
+            sequence: sequence.seq.into(),
+        }
+    }
+}
+
+/// The state of the channel module, imported at genesis or exported for an
+/// upgrade.
+///
+/// The `data` of the packet states holds the packet commitments and the
+/// acknowledgement commitments, while that of the receipts is ignored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelGenesisState {
+    pub channels: Vec<IdentifiedChannelEnd>,
+    pub acknowledgements: Vec<PacketState>,
+    pub commitments: Vec<PacketState>,
+    pub receipts: Vec<PacketState>,
+    pub send_sequences: Vec<PacketSequence>,
+    pub recv_sequences: Vec<PacketSequence>,
+    pub ack_sequences: Vec<PacketSequence>,
+    pub next_channel_sequence: u64,
+}
+
+impl Protobuf<RawChannelGenesisState> for ChannelGenesisState {}
+
+impl TryFrom<RawChannelGenesisState> for ChannelGenesisState {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawChannelGenesisState) -> Result<Self, Self::Error> {
+        let packet_states = |states: Vec<RawPacketState>| {
+            states
+                .into_iter()
+                .map(PacketState::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| ChannelError::InvalidGenesis {
+                    reason: e.to_string(),
+                })
+        };
+        let sequences = |sequences: Vec<_>| {
+            sequences
+                .into_iter()
+                .map(PacketSequence::try_from)
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        Ok(Self {
+            channels: raw
+                .channels
+                .into_iter()
+                .map(IdentifiedChannelEnd::try_from)
+                .collect::<Result<_, _>>()?,
+            acknowledgements: packet_states(raw.acknowledgements)?,
+            commitments: packet_states(raw.commitments)?,
+            receipts: packet_states(raw.receipts)?,
+            send_sequences: sequences(raw.send_sequences)?,
+            recv_sequences: sequences(raw.recv_sequences)?,
+            ack_sequences: sequences(raw.ack_sequences)?,
+            next_channel_sequence: raw.next_channel_sequence,
+        })
+    }
+}
+
+impl From<ChannelGenesisState> for RawChannelGenesisState {
+    fn from(genesis: ChannelGenesisState) -> Self {
+        fn raw<T, R: From<T>>(items: Vec<T>) -> Vec<R> {
+            items.into_iter().map(Into::into).collect()
+        }
+
+        Self {
+            channels: raw(genesis.channels),
+            acknowledgements: raw(genesis.acknowledgements),
+            commitments: raw(genesis.commitments),
+            receipts: raw(genesis.receipts),
+            send_sequences: raw(genesis.send_sequences),
+            recv_sequences: raw(genesis.recv_sequences),
+            ack_sequences: raw(genesis.ack_sequences),
+            next_channel_sequence: genesis.next_channel_sequence,
+        }
+    }
+}
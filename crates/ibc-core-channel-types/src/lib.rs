@@ -0,0 +1,36 @@
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+// https://github.com/cosmos/ibc-rs/issues/342
+#![allow(clippy::result_large_err)]
+//! ICS 04: Data structures of the channels: channel ends, packets,
+//! acknowledgements, commitments, messages, events and errors.
+
+extern crate alloc;
+// The tests, and the `Arbitrary` and `JsonSchema` derives, refer to `std`
+#[cfg(any(test, feature = "arbitrary", feature = "schema"))]
+extern crate std;
+
+pub mod acknowledgement;
+#[cfg(feature = "builder")]
+pub mod builder;
+pub mod channel;
+pub mod commitment;
+pub mod error;
+pub mod events;
+pub mod genesis;
+pub mod msgs;
+pub mod packet;
+pub mod timeout;
+pub mod version;
+#[cfg(feature = "serde")]
+pub mod version_metadata;
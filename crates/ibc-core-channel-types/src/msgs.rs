@@ -1,7 +1,7 @@
 //! Message definitions for all ICS4 domain types: channel open & close handshake datagrams, as well
 //! as packets.
 
-use crate::prelude::*;
+use ibc_primitives::prelude::*;
 
 pub mod acknowledgement;
 pub mod chan_close_confirm;
@@ -24,18 +24,29 @@ pub use chan_open_ack::MsgChannelOpenAck;
 pub use chan_open_confirm::MsgChannelOpenConfirm;
 pub use chan_open_init::MsgChannelOpenInit;
 pub use chan_open_try::MsgChannelOpenTry;
+use ibc_core_host_types::identifier::PortId;
+use ibc_primitives::msgs::ValidateBasic;
 pub use recv_packet::MsgRecvPacket;
 pub use timeout::MsgTimeout;
 pub use timeout_on_close::MsgTimeoutOnClose;
 
-use crate::core::ics24_host::identifier::PortId;
+use crate::error::{ChannelError, PacketError};
 
 /// All channel messages
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
 pub enum ChannelMsg {
     OpenInit(MsgChannelOpenInit),
@@ -46,12 +57,36 @@ pub enum ChannelMsg {
     CloseConfirm(MsgChannelCloseConfirm),
 }
 
+impl ValidateBasic for ChannelMsg {
+    type Error = ChannelError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        match self {
+            Self::OpenInit(msg) => msg.validate_basic(),
+            Self::OpenTry(msg) => msg.validate_basic(),
+            Self::OpenAck(msg) => msg.validate_basic(),
+            Self::OpenConfirm(msg) => msg.validate_basic(),
+            Self::CloseInit(msg) => msg.validate_basic(),
+            Self::CloseConfirm(msg) => msg.validate_basic(),
+        }
+    }
+}
+
 /// All packet messages
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
 pub enum PacketMsg {
     Recv(MsgRecvPacket),
@@ -60,7 +95,20 @@ pub enum PacketMsg {
     TimeoutOnClose(MsgTimeoutOnClose),
 }
 
-pub(crate) fn channel_msg_to_port_id(msg: &ChannelMsg) -> &PortId {
+impl ValidateBasic for PacketMsg {
+    type Error = PacketError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        match self {
+            Self::Recv(msg) => msg.validate_basic(),
+            Self::Ack(msg) => msg.validate_basic(),
+            Self::Timeout(msg) => msg.validate_basic(),
+            Self::TimeoutOnClose(msg) => msg.validate_basic(),
+        }
+    }
+}
+
+pub fn channel_msg_to_port_id(msg: &ChannelMsg) -> &PortId {
     match msg {
         ChannelMsg::OpenInit(msg) => &msg.port_id_on_a,
         ChannelMsg::OpenTry(msg) => &msg.port_id_on_b,
@@ -71,7 +119,7 @@ pub(crate) fn channel_msg_to_port_id(msg: &ChannelMsg) -> &PortId {
     }
 }
 
-pub(crate) fn packet_msg_to_port_id(msg: &PacketMsg) -> &PortId {
+pub fn packet_msg_to_port_id(msg: &PacketMsg) -> &PortId {
     match msg {
         PacketMsg::Recv(msg) => &msg.packet.port_id_on_b,
         PacketMsg::Ack(msg) => &msg.packet.port_id_on_a,
@@ -1,25 +1,34 @@
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgAcknowledgement as RawMsgAcknowledgement;
 use ibc_proto::Protobuf;
 
-use crate::core::ics04_channel::acknowledgement::Acknowledgement;
-use crate::core::ics04_channel::error::PacketError;
-use crate::core::ics04_channel::packet::Packet;
-use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
-use crate::Height;
+use crate::acknowledgement::Acknowledgement;
+use crate::error::PacketError;
+use crate::packet::Packet;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.channel.v1.MsgAcknowledgement";
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgAcknowledgement";
 
 ///
 /// Message definition for packet acknowledgements.
 ///
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgAcknowledgement {
     pub packet: Packet,
@@ -41,6 +50,14 @@ impl Msg for MsgAcknowledgement {
 
 impl Protobuf<RawMsgAcknowledgement> for MsgAcknowledgement {}
 
+impl ValidateBasic for MsgAcknowledgement {
+    type Error = PacketError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgAcknowledgement> for MsgAcknowledgement {
     type Error = PacketError;
 
@@ -78,14 +95,14 @@ impl From<MsgAcknowledgement> for RawMsgAcknowledgement {
 
 #[cfg(test)]
 mod test {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::channel::v1::MsgAcknowledgement as RawMsgAcknowledgement;
     use ibc_testkit::utils::core::channel::dummy_raw_msg_acknowledgement;
     use ibc_testkit::utils::core::signer::dummy_bech32_account;
     use test_log::test;
 
-    use crate::core::ics04_channel::error::PacketError;
-    use crate::core::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
-    use crate::prelude::*;
+    use crate::error::PacketError;
+    use crate::msgs::acknowledgement::MsgAcknowledgement;
 
     #[test]
     fn msg_acknowledgment_try_from_raw() {
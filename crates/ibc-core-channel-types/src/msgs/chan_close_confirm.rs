@@ -1,26 +1,35 @@
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgChannelCloseConfirm as RawMsgChannelCloseConfirm;
 use ibc_proto::Protobuf;
 
-use crate::core::ics04_channel::error::ChannelError;
-use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
-use crate::core::ics24_host::identifier::{ChannelId, PortId};
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
-use crate::Height;
+use crate::error::ChannelError;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelCloseConfirm";
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelCloseConfirm";
 
 ///
 /// Message definition for the second step in the channel close handshake (the `ChanCloseConfirm`
 /// datagram).
 /// Per our convention, this message is sent to chain B.
 ///
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgChannelCloseConfirm {
     pub port_id_on_b: PortId,
@@ -40,6 +49,14 @@ impl Msg for MsgChannelCloseConfirm {
 
 impl Protobuf<RawMsgChannelCloseConfirm> for MsgChannelCloseConfirm {}
 
+impl ValidateBasic for MsgChannelCloseConfirm {
+    type Error = ChannelError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgChannelCloseConfirm> for MsgChannelCloseConfirm {
     type Error = ChannelError;
 
@@ -74,12 +91,12 @@ impl From<MsgChannelCloseConfirm> for RawMsgChannelCloseConfirm {
 
 #[cfg(test)]
 mod tests {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::channel::v1::MsgChannelCloseConfirm as RawMsgChannelCloseConfirm;
     use ibc_proto::ibc::core::client::v1::Height;
     use ibc_testkit::utils::core::channel::dummy_raw_msg_chan_close_confirm;
 
-    use crate::core::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
-    use crate::prelude::*;
+    use crate::msgs::chan_close_confirm::MsgChannelCloseConfirm;
 
     #[test]
     fn parse_channel_close_confirm_msg() {
@@ -1,23 +1,32 @@
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgChannelCloseInit as RawMsgChannelCloseInit;
 use ibc_proto::Protobuf;
 
-use crate::core::ics04_channel::error::ChannelError;
-use crate::core::ics24_host::identifier::{ChannelId, PortId};
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
+use crate::error::ChannelError;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelCloseInit";
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelCloseInit";
 
 ///
 /// Message definition for the first step in the channel close handshake (`ChanCloseInit` datagram).
 /// Per our convention, this message is sent to chain A.
 ///
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgChannelCloseInit {
     pub port_id_on_a: PortId,
@@ -35,6 +44,14 @@ impl Msg for MsgChannelCloseInit {
 
 impl Protobuf<RawMsgChannelCloseInit> for MsgChannelCloseInit {}
 
+impl ValidateBasic for MsgChannelCloseInit {
+    type Error = ChannelError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgChannelCloseInit> for MsgChannelCloseInit {
     type Error = ChannelError;
 
@@ -59,12 +76,12 @@ impl From<MsgChannelCloseInit> for RawMsgChannelCloseInit {
 
 #[cfg(test)]
 mod tests {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::channel::v1::MsgChannelCloseInit as RawMsgChannelCloseInit;
     use ibc_testkit::utils::core::channel::dummy_raw_msg_chan_close_init;
     use test_log::test;
 
-    use crate::core::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
-    use crate::prelude::*;
+    use crate::msgs::chan_close_init::MsgChannelCloseInit;
 
     #[test]
     fn parse_channel_close_init_msg() {
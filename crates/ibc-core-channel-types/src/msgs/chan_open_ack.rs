@@ -1,25 +1,34 @@
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenAck as RawMsgChannelOpenAck;
 use ibc_proto::Protobuf;
 
-use crate::core::ics04_channel::error::ChannelError;
-use crate::core::ics04_channel::Version;
-use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
-use crate::core::ics24_host::identifier::{ChannelId, PortId};
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
-use crate::Height;
+use crate::error::ChannelError;
+use crate::version::Version;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenAck";
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenAck";
 
 /// Message definition for the third step in the channel open handshake (`ChanOpenAck` datagram).
 ///
 /// Per our convention, this message is sent to chain A.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgChannelOpenAck {
     pub port_id_on_a: PortId,
@@ -41,6 +50,14 @@ impl Msg for MsgChannelOpenAck {
 
 impl Protobuf<RawMsgChannelOpenAck> for MsgChannelOpenAck {}
 
+impl ValidateBasic for MsgChannelOpenAck {
+    type Error = ChannelError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgChannelOpenAck> for MsgChannelOpenAck {
     type Error = ChannelError;
 
@@ -79,13 +96,13 @@ impl From<MsgChannelOpenAck> for RawMsgChannelOpenAck {
 
 #[cfg(test)]
 mod tests {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::channel::v1::MsgChannelOpenAck as RawMsgChannelOpenAck;
     use ibc_proto::ibc::core::client::v1::Height;
     use ibc_testkit::utils::core::channel::dummy_raw_msg_chan_open_ack;
     use test_log::test;
 
-    use crate::core::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
-    use crate::prelude::*;
+    use crate::msgs::chan_open_ack::MsgChannelOpenAck;
 
     #[test]
     fn parse_channel_open_ack_msg() {
@@ -1,27 +1,34 @@
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenInit as RawMsgChannelOpenInit;
 use ibc_proto::Protobuf;
 
-use crate::core::ics04_channel::channel::{
-    verify_connection_hops_length, ChannelEnd, Counterparty, Order, State,
-};
-use crate::core::ics04_channel::error::ChannelError;
-use crate::core::ics04_channel::Version;
-use crate::core::ics24_host::identifier::{ConnectionId, PortId};
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
+use crate::channel::{verify_connection_hops_length, ChannelEnd, Counterparty, Order, State};
+use crate::error::ChannelError;
+use crate::version::Version;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenInit";
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenInit";
 
 ///
 /// Message definition for the first step in the channel open handshake (`ChanOpenInit` datagram).
 /// Per our convention, this message is sent to chain A.
 ///
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgChannelOpenInit {
     pub port_id_on_a: PortId,
@@ -37,7 +44,7 @@ impl MsgChannelOpenInit {
     /// Checks if the `connection_hops` has a length of `expected`.
     ///
     /// Note: Current IBC version only supports one connection hop.
-    pub(crate) fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
+    pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
         verify_connection_hops_length(&self.connection_hops_on_a, 1)
     }
 }
@@ -52,6 +59,15 @@ impl Msg for MsgChannelOpenInit {
 
 impl Protobuf<RawMsgChannelOpenInit> for MsgChannelOpenInit {}
 
+impl ValidateBasic for MsgChannelOpenInit {
+    type Error = ChannelError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)?;
+        self.verify_connection_hops_length()
+    }
+}
+
 impl TryFrom<RawMsgChannelOpenInit> for MsgChannelOpenInit {
     type Error = ChannelError;
 
@@ -91,14 +107,38 @@ impl From<MsgChannelOpenInit> for RawMsgChannelOpenInit {
     }
 }
 
+/// The outcome of executing a [`MsgChannelOpenInit`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChanOpenInitResult {
+    /// The identifier generated for the new channel.
+    pub chan_id_on_a: ChannelId,
+    /// The version the module chose for the channel.
+    pub version: Version,
+}
+
 #[cfg(test)]
 mod tests {
+    use ibc_core_host_types::identifier::ConnectionId;
+    use ibc_primitives::msgs::ValidateBasic;
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::channel::v1::MsgChannelOpenInit as RawMsgChannelOpenInit;
     use ibc_testkit::utils::core::channel::dummy_raw_msg_chan_open_init;
     use test_log::test;
 
-    use crate::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
-    use crate::prelude::*;
+    use crate::error::ChannelError;
+    use crate::msgs::chan_open_init::MsgChannelOpenInit;
 
     #[test]
     fn channel_open_init_from_raw() {
@@ -183,4 +223,21 @@ mod tests {
             msg_with_counterparty_chan_id_some_back
         );
     }
+    #[test]
+    fn channel_open_init_validate_basic() {
+        let msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+        assert!(msg.validate_basic().is_ok());
+
+        let two_hops = MsgChannelOpenInit {
+            connection_hops_on_a: vec![ConnectionId::new(0), ConnectionId::new(1)],
+            ..msg
+        };
+        assert!(matches!(
+            two_hops.validate_basic(),
+            Err(ChannelError::InvalidConnectionHopsLength {
+                expected: 1,
+                actual: 2
+            })
+        ));
+    }
 }
@@ -1,29 +1,36 @@
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenTry as RawMsgChannelOpenTry;
 use ibc_proto::Protobuf;
 
-use crate::core::ics04_channel::channel::{
-    verify_connection_hops_length, ChannelEnd, Counterparty, Order, State,
-};
-use crate::core::ics04_channel::error::ChannelError;
-use crate::core::ics04_channel::Version;
-use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
-use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
-use crate::Height;
+use crate::channel::{verify_connection_hops_length, ChannelEnd, Counterparty, Order, State};
+use crate::error::ChannelError;
+use crate::version::Version;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenTry";
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenTry";
 
 ///
 /// Message definition for the second step in the channel open handshake (`ChanOpenTry` datagram).
 /// Per our convention, this message is sent to chain B.
 ///
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgChannelOpenTry {
     pub port_id_on_b: PortId,
@@ -45,7 +52,7 @@ impl MsgChannelOpenTry {
     /// Checks if the `connection_hops` has a length of `expected`.
     ///
     /// Note: Current IBC version only supports one connection hop.
-    pub(crate) fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
+    pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
         verify_connection_hops_length(&self.connection_hops_on_b, 1)
     }
 }
@@ -60,6 +67,15 @@ impl Msg for MsgChannelOpenTry {
 
 impl Protobuf<RawMsgChannelOpenTry> for MsgChannelOpenTry {}
 
+impl ValidateBasic for MsgChannelOpenTry {
+    type Error = ChannelError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)?;
+        self.verify_connection_hops_length()
+    }
+}
+
 impl TryFrom<RawMsgChannelOpenTry> for MsgChannelOpenTry {
     type Error = ChannelError;
 
@@ -128,15 +144,36 @@ impl From<MsgChannelOpenTry> for RawMsgChannelOpenTry {
     }
 }
 
+/// The outcome of executing a [`MsgChannelOpenTry`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChanOpenTryResult {
+    /// The identifier generated for the new channel.
+    pub chan_id_on_b: ChannelId,
+    /// The version the module chose for the channel.
+    pub version: Version,
+}
+
 #[cfg(test)]
 mod tests {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::channel::v1::MsgChannelOpenTry as RawMsgChannelOpenTry;
     use ibc_proto::ibc::core::client::v1::Height;
     use ibc_testkit::utils::core::channel::dummy_raw_msg_chan_open_try;
     use test_log::test;
 
-    use crate::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
-    use crate::prelude::*;
+    use crate::msgs::chan_open_try::MsgChannelOpenTry;
 
     #[test]
     fn channel_open_try_from_raw() {
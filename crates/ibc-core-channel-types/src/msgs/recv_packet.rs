@@ -1,24 +1,33 @@
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgRecvPacket as RawMsgRecvPacket;
 use ibc_proto::Protobuf;
 
-use crate::core::ics04_channel::error::PacketError;
-use crate::core::ics04_channel::packet::Packet;
-use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
-use crate::Height;
+use crate::error::PacketError;
+use crate::packet::Packet;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.channel.v1.MsgRecvPacket";
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgRecvPacket";
 
 ///
 /// Message definition for the "packet receiving" datagram.
 ///
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgRecvPacket {
     /// The packet to be received
@@ -41,6 +50,14 @@ impl Msg for MsgRecvPacket {
 
 impl Protobuf<RawMsgRecvPacket> for MsgRecvPacket {}
 
+impl ValidateBasic for MsgRecvPacket {
+    type Error = PacketError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgRecvPacket> for MsgRecvPacket {
     type Error = PacketError;
 
@@ -74,16 +91,38 @@ impl From<MsgRecvPacket> for RawMsgRecvPacket {
     }
 }
 
+/// The outcome of executing a [`MsgRecvPacket`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecvOutcome {
+    /// The packet was delivered to its module and the acknowledgement written.
+    Received,
+    /// The packet had already been received, e.g. relayed by another relayer.
+    /// The message was a no-op: no state was written and no event emitted.
+    AlreadyReceived,
+}
+
 #[cfg(test)]
 mod test {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::channel::v1::MsgRecvPacket as RawMsgRecvPacket;
     use ibc_testkit::utils::core::channel::dummy_raw_msg_recv_packet;
     use ibc_testkit::utils::core::signer::dummy_bech32_account;
     use test_log::test;
 
-    use crate::core::ics04_channel::error::PacketError;
-    use crate::core::ics04_channel::msgs::recv_packet::MsgRecvPacket;
-    use crate::prelude::*;
+    use crate::error::PacketError;
+    use crate::msgs::recv_packet::MsgRecvPacket;
 
     #[test]
     fn msg_recv_packet_try_from_raw() {
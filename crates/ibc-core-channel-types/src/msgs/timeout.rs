@@ -1,25 +1,34 @@
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgTimeout as RawMsgTimeout;
 use ibc_proto::Protobuf;
 
-use crate::core::ics04_channel::error::PacketError;
-use crate::core::ics04_channel::packet::{Packet, Sequence};
-use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
-use crate::Height;
+use crate::error::PacketError;
+use crate::packet::{Packet, Sequence};
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeout";
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeout";
 
 ///
 /// Message definition for packet timeout domain type,
 /// which is sent on chain A and needs to prove that a previously sent packet was not received on chain B
 ///
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgTimeout {
     pub packet: Packet,
@@ -39,6 +48,14 @@ impl Msg for MsgTimeout {
 
 impl Protobuf<RawMsgTimeout> for MsgTimeout {}
 
+impl ValidateBasic for MsgTimeout {
+    type Error = PacketError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgTimeout> for MsgTimeout {
     type Error = PacketError;
 
@@ -79,14 +96,15 @@ impl From<MsgTimeout> for RawMsgTimeout {
 
 #[cfg(test)]
 mod test {
+    use ibc_primitives::msgs::ValidateBasic;
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::channel::v1::MsgTimeout as RawMsgTimeout;
     use ibc_testkit::utils::core::channel::dummy_raw_msg_timeout;
     use ibc_testkit::utils::core::signer::dummy_bech32_account;
     use test_log::test;
 
-    use crate::core::ics04_channel::error::PacketError;
-    use crate::core::ics04_channel::msgs::timeout::MsgTimeout;
-    use crate::prelude::*;
+    use crate::error::PacketError;
+    use crate::msgs::timeout::MsgTimeout;
 
     #[test]
     fn msg_timeout_try_from_raw() {
@@ -166,4 +184,18 @@ mod test {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+    #[test]
+    fn msg_timeout_validate_basic() {
+        let msg = MsgTimeout::try_from(dummy_raw_msg_timeout(15, 20, 0)).unwrap();
+        assert!(msg.validate_basic().is_ok());
+
+        let zero_sequence = MsgTimeout {
+            next_seq_recv_on_b: 0.into(),
+            ..msg
+        };
+        assert!(matches!(
+            zero_sequence.validate_basic(),
+            Err(PacketError::ZeroPacketSequence)
+        ));
+    }
 }
@@ -1,29 +1,43 @@
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
 use ibc_proto::Protobuf;
 
-use crate::core::ics04_channel::error::PacketError;
-use crate::core::ics04_channel::packet::{Packet, Sequence};
-use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
-use crate::Height;
+use crate::error::PacketError;
+use crate::packet::{Packet, Sequence};
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeoutOnClose";
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeoutOnClose";
 
 ///
 /// Message definition for packet timeout domain type.
 ///
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgTimeoutOnClose {
     pub packet: Packet,
+    /// The next sequence that chain B expects to receive on the channel,
+    /// which is only verified on ordered channels
     pub next_seq_recv_on_b: Sequence,
+    /// Proof of `next_seq_recv_on_b` on ordered channels, or of the absence
+    /// of the receipt of the packet on unordered channels
     pub proof_unreceived_on_b: CommitmentProofBytes,
+    /// Proof that the channel end on chain B is closed
     pub proof_close_on_b: CommitmentProofBytes,
     pub proof_height_on_b: Height,
     pub signer: Signer,
@@ -39,6 +53,14 @@ impl Msg for MsgTimeoutOnClose {
 
 impl Protobuf<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {}
 
+impl ValidateBasic for MsgTimeoutOnClose {
+    type Error = PacketError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {
     type Error = PacketError;
 
@@ -85,12 +107,12 @@ impl From<MsgTimeoutOnClose> for RawMsgTimeoutOnClose {
 
 #[cfg(test)]
 mod tests {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
     use ibc_testkit::utils::core::channel::dummy_raw_msg_timeout_on_close;
     use test_log::test;
 
-    use crate::core::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
-    use crate::prelude::*;
+    use crate::msgs::timeout_on_close::MsgTimeoutOnClose;
 
     #[test]
     fn msg_timeout_on_close_try_from_raw() {
@@ -2,17 +2,26 @@
 
 use core::str::FromStr;
 
+use ibc_core_client_types::height::Height;
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Expiry::Expired;
+use ibc_primitives::timestamp::Timestamp;
 use ibc_proto::ibc::core::channel::v1::{Packet as RawPacket, PacketState as RawPacketState};
 
 use super::timeout::TimeoutHeight;
-use crate::core::ics04_channel::error::{ChannelError, PacketError};
-use crate::core::ics24_host::identifier::{ChannelId, PortId};
-use crate::core::timestamp::Expiry::Expired;
-use crate::core::timestamp::Timestamp;
-use crate::prelude::*;
-use crate::Height;
+use crate::commitment::{compute_packet_commitment, PacketCommitment};
+use crate::error::{ChannelError, PacketError};
 
 /// Enumeration of proof carrying ICS4 message, helper for relayer.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum PacketMsgType {
     Recv,
@@ -68,6 +77,7 @@ impl core::fmt::Display for PacketMsgType {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 /// The sequence number of a packet enforces ordering among packets from the same source.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Sequence(u64);
 
@@ -129,6 +139,7 @@ impl core::fmt::Display for Sequence {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct Packet {
     pub seq_on_a: Sequence,
@@ -138,7 +149,7 @@ pub struct Packet {
     pub chan_id_on_b: ChannelId,
     #[cfg_attr(
         feature = "serde",
-        serde(serialize_with = "crate::serializers::ser_hex_upper")
+        serde(serialize_with = "ibc_primitives::serializers::ser_hex_upper")
     )]
     pub data: Vec<u8>,
     pub timeout_height_on_b: TimeoutHeight,
@@ -187,7 +198,7 @@ impl core::fmt::Debug for Packet {
 
 impl Packet {
     /// Checks whether a packet from a
-    /// [`SendPacket`](crate::core::ics04_channel::events::SendPacket)
+    /// [`SendPacket`](crate::events::SendPacket)
     /// event is timed-out relative to the current state of the
     /// destination chain.
     ///
@@ -196,9 +207,9 @@ impl Packet {
     /// the height `dst_chain_height`.
     ///
     /// Note: a timed-out packet should result in a
-    /// [`MsgTimeout`](crate::core::ics04_channel::msgs::timeout::MsgTimeout),
+    /// [`MsgTimeout`](crate::msgs::timeout::MsgTimeout),
     /// instead of the common-case where it results in
-    /// [`MsgRecvPacket`](crate::core::ics04_channel::msgs::recv_packet::MsgRecvPacket).
+    /// [`MsgRecvPacket`](crate::msgs::recv_packet::MsgRecvPacket).
     pub fn timed_out(&self, dst_chain_ts: &Timestamp, dst_chain_height: Height) -> bool {
         let height_timed_out = self.timeout_height_on_b.has_expired(dst_chain_height);
 
@@ -207,6 +218,16 @@ impl Packet {
 
         height_timed_out || timestamp_timed_out
     }
+
+    /// Computes the commitment the sending chain stores for this packet, with
+    /// [`compute_packet_commitment`].
+    pub fn commitment(&self) -> PacketCommitment {
+        compute_packet_commitment(
+            &self.data,
+            &self.timeout_height_on_b,
+            &self.timeout_timestamp_on_b,
+        )
+    }
 }
 
 /// Custom debug output to omit the packet data
@@ -275,7 +296,7 @@ impl TryFrom<RawPacket> for Packet {
 impl From<Packet> for RawPacket {
     fn from(packet: Packet) -> Self {
         RawPacket {
-            sequence: packet.seq_on_a.0,
+            sequence: packet.seq_on_a.into(),
             source_port: packet.port_id_on_a.to_string(),
             source_channel: packet.chan_id_on_a.to_string(),
             destination_port: packet.port_id_on_b.to_string(),
@@ -311,7 +332,7 @@ pub struct PacketState {
     pub seq: Sequence,
     #[cfg_attr(
         feature = "serde",
-        serde(serialize_with = "crate::serializers::ser_hex_upper")
+        serde(serialize_with = "ibc_primitives::serializers::ser_hex_upper")
     )]
     pub data: Vec<u8>,
 }
@@ -364,7 +385,7 @@ impl TryFrom<RawPacketState> for PacketState {
 impl From<PacketState> for RawPacketState {
     fn from(packet: PacketState) -> Self {
         Self {
-            sequence: packet.seq.0,
+            sequence: packet.seq.into(),
             port_id: packet.port_id.to_string(),
             channel_id: packet.chan_id.to_string(),
             data: packet.data,
@@ -374,13 +395,13 @@ impl From<PacketState> for RawPacketState {
 
 #[cfg(test)]
 mod tests {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
     use ibc_proto::ibc::core::client::v1::Height as RawHeight;
     use ibc_testkit::utils::core::channel::dummy_raw_packet;
     use test_log::test;
 
-    use crate::core::ics04_channel::packet::Packet;
-    use crate::prelude::*;
+    use crate::packet::Packet;
 
     #[test]
     fn packet_try_from_raw() {
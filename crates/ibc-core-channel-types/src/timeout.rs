@@ -1,13 +1,13 @@
 //! Types and utilities pertaining to packet timeouts.
 
 use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
 
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::{Height, HeightError};
+use ibc_primitives::prelude::*;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics02_client::height::Height;
-use crate::prelude::*;
-
 /// Indicates a consensus height on the destination chain after which the packet
 /// will no longer be processed, and will instead count as having timed-out.
 ///
@@ -31,6 +31,7 @@ use crate::prelude::*;
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
 pub enum TimeoutHeight {
     Never,
@@ -83,6 +84,33 @@ impl TimeoutHeight {
             TimeoutHeight::Never => "0-0".into(),
         }
     }
+
+    /// Parses a timeout height like [`Height::parse_with_default_revision`],
+    /// from either its `{revision_number}-{revision_height}` or bare
+    /// `{revision_height}` form, where `0-0` and `0` mean no timeout.
+    pub fn parse_with_default_revision(
+        value: &str,
+        default_revision_number: u64,
+    ) -> Result<Self, HeightError> {
+        match value {
+            "0-0" | "0" => Ok(TimeoutHeight::Never),
+            _ => Height::parse_with_default_revision(value, default_revision_number)
+                .map(TimeoutHeight::At),
+        }
+    }
+}
+
+/// Parses the `{revision_number}-{revision_height}` form of a timeout height,
+/// as formatted by [`TimeoutHeight::to_event_attribute_value`].
+impl FromStr for TimeoutHeight {
+    type Err = HeightError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "0-0" => Ok(TimeoutHeight::Never),
+            _ => s.parse().map(TimeoutHeight::At),
+        }
+    }
 }
 
 impl Default for TimeoutHeight {
@@ -190,7 +218,7 @@ mod tests {
         where
             D: serde::Deserializer<'de>,
         {
-            use crate::core::ics02_client::height::Height as Ics02Height;
+            use ibc_core_client_types::height::Height as Ics02Height;
 
             // Here we have to use a bespoke struct as well in order to deserialize
             // a height which may have a revision height equal to zero.
@@ -209,4 +237,31 @@ mod tests {
             })
         }
     }
+
+    #[test]
+    fn test_parse_timeout_height() {
+        use ibc_core_client_types::height::Height;
+
+        let height = Height::new(1, 42).unwrap();
+
+        assert_eq!("0-0".parse::<TimeoutHeight>(), Ok(TimeoutHeight::Never));
+        assert_eq!(
+            "1-42".parse::<TimeoutHeight>(),
+            Ok(TimeoutHeight::At(height))
+        );
+        assert!("42".parse::<TimeoutHeight>().is_err());
+
+        for value in ["0-0", "0"] {
+            assert_eq!(
+                TimeoutHeight::parse_with_default_revision(value, 1),
+                Ok(TimeoutHeight::Never)
+            );
+        }
+        for value in ["1-42", "42"] {
+            assert_eq!(
+                TimeoutHeight::parse_with_default_revision(value, 1),
+                Ok(TimeoutHeight::At(height))
+            );
+        }
+    }
 }
@@ -0,0 +1,136 @@
+//! Defines the [`VersionMetadata`] of the applications whose channel versions
+//! are JSON blobs, e.g. the fee middleware or interchain accounts, so that
+//! they negotiate their versions without handling JSON strings themselves.
+
+use alloc::collections::BTreeMap;
+
+use ibc_primitives::prelude::*;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::Serialize;
+
+use super::error::ChannelError;
+use crate::version::Version;
+
+/// The metadata encoded as JSON in the version of a channel.
+///
+/// Implementors must serialize all their fields, since any field of a version
+/// that is not serialized back is rejected as unknown by [`Self::parse`].
+pub trait VersionMetadata: Clone + PartialEq + Serialize + DeserializeOwned {
+    /// Parses the metadata of `version`, rejecting unknown fields.
+    fn parse(version: &Version) -> Result<Self, ChannelError> {
+        let invalid = |reason: String| ChannelError::InvalidVersionMetadata {
+            version: version.clone(),
+            reason,
+        };
+
+        let fields: BTreeMap<String, IgnoredAny> =
+            serde_json::from_str(version.as_str()).map_err(|e| invalid(e.to_string()))?;
+        let metadata: Self =
+            serde_json::from_str(version.as_str()).map_err(|e| invalid(e.to_string()))?;
+
+        let known: BTreeMap<String, IgnoredAny> =
+            serde_json::from_str(metadata.to_version().as_str())
+                .expect("VersionMetadata is encoded as a JSON object");
+        if let Some(field) = fields.keys().find(|field| !known.contains_key(*field)) {
+            return Err(invalid(format!("unknown field `{field}`")));
+        }
+
+        Ok(metadata)
+    }
+
+    /// Encodes the metadata as a channel version.
+    fn to_version(&self) -> Version {
+        Version::new(serde_json::to_string(self).expect("VersionMetadata's Serialize impl failed"))
+    }
+
+    /// Merges the metadata `proposed` by the counterparty into the one
+    /// supported by the host, returning the metadata both ends agree on.
+    ///
+    /// Defaults to requiring both to be equal.
+    fn merge(&self, proposed: &Self) -> Result<Self, ChannelError> {
+        if self != proposed {
+            return Err(ChannelError::VersionNotSupported {
+                expected: self.to_version(),
+                actual: proposed.to_version(),
+            });
+        }
+        Ok(self.clone())
+    }
+}
+
+/// Negotiates the version an application returns from `on_chan_open_try`,
+/// merging the `counterparty_version` into the metadata it `supported`s.
+pub fn negotiate_on_try<M: VersionMetadata>(
+    supported: &M,
+    counterparty_version: &Version,
+) -> Result<Version, ChannelError> {
+    let proposed = M::parse(counterparty_version)?;
+    supported.merge(&proposed).map(|m| m.to_version())
+}
+
+/// Checks in `on_chan_open_ack` that the `counterparty_version` agreed on is
+/// the one the host proposed in `on_chan_open_init`.
+pub fn verify_on_ack<M: VersionMetadata>(
+    proposed: &M,
+    counterparty_version: &Version,
+) -> Result<M, ChannelError> {
+    let agreed = M::parse(counterparty_version)?;
+    proposed.merge(&agreed)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct FeeMetadata {
+        fee_version: String,
+        app_version: String,
+    }
+
+    impl VersionMetadata for FeeMetadata {}
+
+    fn metadata() -> FeeMetadata {
+        FeeMetadata {
+            fee_version: "ics29-1".to_string(),
+            app_version: "ics20-1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_version_metadata() {
+        let version = metadata().to_version();
+        assert_eq!(
+            version.as_str(),
+            r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#
+        );
+        assert_eq!(FeeMetadata::parse(&version).unwrap(), metadata());
+
+        for invalid in [
+            "ics20-1",
+            r#"{"fee_version":"ics29-1"}"#,
+            r#"{"fee_version":"ics29-1","app_version":"ics20-1","extra":""}"#,
+        ] {
+            assert!(matches!(
+                FeeMetadata::parse(&Version::new(invalid.to_string())),
+                Err(ChannelError::InvalidVersionMetadata { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_negotiate_version_metadata() {
+        let version = metadata().to_version();
+        assert_eq!(negotiate_on_try(&metadata(), &version).unwrap(), version);
+        assert_eq!(verify_on_ack(&metadata(), &version).unwrap(), metadata());
+
+        let mut other = metadata();
+        other.app_version = "ics20-2".to_string();
+        assert!(matches!(
+            negotiate_on_try(&metadata(), &other.to_version()),
+            Err(ChannelError::VersionNotSupported { .. })
+        ));
+    }
+}
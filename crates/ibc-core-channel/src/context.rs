@@ -1,21 +1,22 @@
 //! ICS4 (channel) context.
 
-use core::time::Duration;
-
-use super::packet::Sequence;
-use crate::core::events::IbcEvent;
-use crate::core::ics02_client::client_state::ClientState;
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::{ClientExecutionContext, ClientValidationContext};
-use crate::core::ics03_connection::connection::ConnectionEnd;
-use crate::core::ics04_channel::channel::ChannelEnd;
-use crate::core::ics04_channel::commitment::PacketCommitment;
-use crate::core::ics24_host::identifier::{ClientId, ConnectionId};
-use crate::core::ics24_host::path::{
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_channel_types::commitment::PacketCommitment;
+use ibc_core_channel_types::packet::Sequence;
+use ibc_core_client_context::client_state::ClientState;
+use ibc_core_client_context::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_connection_types::connection::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_handler_types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, SeqSendPath,
 };
-use crate::core::{ContextError, ExecutionContext, ValidationContext};
-use crate::prelude::*;
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host::timeout_queue::TimeoutQueueContext;
+use ibc_core_host_types::identifier::{ClientId, ConnectionId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
 
 /// Methods required in send packet validation, to be implemented by the host
 pub trait SendPacketValidationContext {
@@ -44,6 +45,15 @@ pub trait SendPacketValidationContext {
 
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
+
+    /// Returns the current timestamp of the local chain.
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
+    /// Returns the maximum size, in bytes, of the data of sent packets, or
+    /// `None` if it is unbounded.
+    fn max_packet_data_size(&self) -> Option<usize> {
+        None
+    }
 }
 
 impl<T> SendPacketValidationContext for T
@@ -84,6 +94,14 @@ where
     ) -> Result<Sequence, ContextError> {
         self.get_next_sequence_send(seq_send_path)
     }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        ValidationContext::host_timestamp(self)
+    }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        ValidationContext::max_packet_data_size(self)
+    }
 }
 
 /// Methods required in send packet execution, to be implemented by the host
@@ -105,6 +123,11 @@ pub trait SendPacketExecutionContext: SendPacketValidationContext {
 
     /// Logging facility
     fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+
+    /// Queue recording the timeouts of the packets sent, if any
+    fn timeout_queue(&mut self) -> Option<&mut dyn TimeoutQueueContext> {
+        None
+    }
 }
 
 impl<T> SendPacketExecutionContext for T
@@ -134,48 +157,8 @@ where
     fn log_message(&mut self, message: String) -> Result<(), ContextError> {
         self.log_message(message)
     }
-}
-
-pub(crate) fn calculate_block_delay(
-    delay_period_time: &Duration,
-    max_expected_time_per_block: &Duration,
-) -> u64 {
-    let delay_period_time = delay_period_time.as_secs();
-    let max_expected_time_per_block = max_expected_time_per_block.as_secs();
-    if max_expected_time_per_block == 0 {
-        return 0;
-    }
-    if delay_period_time % max_expected_time_per_block == 0 {
-        return delay_period_time / max_expected_time_per_block;
-    }
-    (delay_period_time / max_expected_time_per_block) + 1
-}
 
-#[cfg(test)]
-mod tests {
-
-    use rstest::rstest;
-
-    use super::*;
-
-    #[rstest]
-    #[case::remainder_zero(10, 2, 5)]
-    #[case::remainder_not_zero(10, 3, 4)]
-    #[case::max_expected_zero(10, 0, 0)]
-    #[case::delay_period_zero(0, 2, 0)]
-    #[case::both_zero(0, 0, 0)]
-    #[case::delay_less_than_max(10, 11, 1)]
-    fn test_calculate_block_delay_zero(
-        #[case] delay_period_time: u64,
-        #[case] max_expected_time_per_block: u64,
-        #[case] expected: u64,
-    ) {
-        assert_eq!(
-            calculate_block_delay(
-                &Duration::from_secs(delay_period_time),
-                &Duration::from_secs(max_expected_time_per_block)
-            ),
-            expected
-        );
+    fn timeout_queue(&mut self) -> Option<&mut dyn TimeoutQueueContext> {
+        self.timeout_queue()
     }
 }
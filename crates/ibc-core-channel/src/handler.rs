@@ -0,0 +1,15 @@
+//! This module implements the processing logic for ICS4 (channel) messages.
+
+pub mod acknowledgement;
+pub mod chan_close_confirm;
+pub mod chan_close_init;
+pub mod chan_open_ack;
+pub mod chan_open_confirm;
+pub mod chan_open_init;
+pub mod chan_open_try;
+#[cfg(feature = "localhost")]
+pub mod loopback;
+pub mod recv_packet;
+pub mod send_packet;
+pub mod timeout;
+pub mod timeout_on_close;
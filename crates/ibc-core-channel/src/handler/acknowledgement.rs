@@ -1,22 +1,26 @@
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::connection::State as ConnectionState;
-use crate::core::ics03_connection::delay::verify_conn_delay_passed;
-use crate::core::ics04_channel::channel::{Counterparty, Order, State as ChannelState};
-use crate::core::ics04_channel::commitment::{compute_ack_commitment, compute_packet_commitment};
-use crate::core::ics04_channel::error::{ChannelError, PacketError};
-use crate::core::ics04_channel::events::AcknowledgePacket;
-use crate::core::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
-use crate::core::ics24_host::path::{
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
+use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::events::AcknowledgePacket;
+use ibc_core_channel_types::msgs::acknowledgement::MsgAcknowledgement;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_connection_types::connection::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, SeqAckPath,
 };
-use crate::core::router::Module;
-use crate::core::{ContextError, ExecutionContext, ValidationContext};
-use crate::prelude::*;
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 
-pub(crate) fn acknowledgement_packet_validate<ValCtx>(
+pub fn acknowledgement_packet_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
     msg: MsgAcknowledgement,
@@ -31,7 +35,7 @@ where
         .map_err(ContextError::PacketError)
 }
 
-pub(crate) fn acknowledgement_packet_execute<ExecCtx>(
+pub fn acknowledgement_packet_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
     msg: MsgAcknowledgement,
@@ -39,25 +43,46 @@ pub(crate) fn acknowledgement_packet_execute<ExecCtx>(
 where
     ExecCtx: ExecutionContext,
 {
-    let chan_end_path_on_a =
-        ChannelEndPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+    acknowledge_packet(
+        ctx_a,
+        module,
+        &msg.packet,
+        &msg.acknowledgement,
+        &msg.signer,
+    )
+}
+
+/// Delivers the `acknowledgement` of `packet` to `module`, and deletes the
+/// commitment of the packet, unless it was already acknowledged.
+pub fn acknowledge_packet<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    signer: &Signer,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
     let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
 
     // In all cases, this event is emitted
-    let event = IbcEvent::AcknowledgePacket(AcknowledgePacket::new(
-        msg.packet.clone(),
-        chan_end_on_a.ordering,
-        conn_id_on_a.clone(),
-    ));
+    let event = IbcEvent::AcknowledgePacket(
+        AcknowledgePacket::new(
+            packet.clone(),
+            chan_end_on_a.ordering,
+            conn_id_on_a.clone(),
+            compute_ack_commitment(acknowledgement),
+        )
+        .with_relayer(signer.clone()),
+    );
     ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
     ctx_a.emit_ibc_event(event)?;
 
-    let commitment_path_on_a = CommitmentPath::new(
-        &msg.packet.port_id_on_a,
-        &msg.packet.chan_id_on_a,
-        msg.packet.seq_on_a,
-    );
+    let commitment_path_on_a =
+        CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
 
     // check if we're in the NO-OP case
     if ctx_a.get_packet_commitment(&commitment_path_on_a).is_err() {
@@ -69,25 +94,31 @@ where
     };
 
     let (extras, cb_result) =
-        module.on_acknowledgement_packet_execute(&msg.packet, &msg.acknowledgement, &msg.signer);
+        module.on_acknowledgement_packet_execute(packet, acknowledgement, signer);
 
     cb_result?;
 
     // apply state changes
     {
         let commitment_path_on_a = CommitmentPath {
-            port_id: msg.packet.port_id_on_a.clone(),
-            channel_id: msg.packet.chan_id_on_a.clone(),
-            sequence: msg.packet.seq_on_a,
+            port_id: packet.port_id_on_a.clone(),
+            channel_id: packet.chan_id_on_a.clone(),
+            sequence: packet.seq_on_a,
         };
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
+        if let Some(timeout_queue) = ctx_a.timeout_queue() {
+            timeout_queue.dequeue_timeout(
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                packet.seq_on_a,
+            )?;
+        }
 
         if let Order::Ordered = chan_end_on_a.ordering {
-            // Note: in validation, we verified that `msg.packet.sequence == nextSeqRecv`
+            // Note: in validation, we verified that `packet.sequence == nextSeqRecv`
             // (where `nextSeqRecv` is the value in the store)
-            let seq_ack_path_on_a =
-                SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
-            ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, msg.packet.seq_on_a.increment())?;
+            let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+            ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, packet.seq_on_a.increment())?;
         }
     }
 
@@ -113,8 +144,6 @@ fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgAcknowledgement) -> Result<(), ContextErr
 where
     Ctx: ValidationContext,
 {
-    ctx_a.validate_message_signer(&msg.signer)?;
-
     let packet = &msg.packet;
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
@@ -199,7 +228,7 @@ where
         client_state_of_b_on_a
             .verify_membership(
                 conn_end_on_a.counterparty().prefix(),
-                &msg.proof_acked_on_b,
+                &*ctx_a.resolve_proof(&msg.proof_acked_on_b)?,
                 consensus_state_of_b_on_a.root(),
                 Path::Ack(ack_path_on_b),
                 ack_commitment.into_vec(),
@@ -1,22 +1,22 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelCloseConfirm`.
 
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::CloseConfirm;
+use ibc_core_channel_types::msgs::chan_close_confirm::MsgChannelCloseConfirm;
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection_types::connection::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Module;
+use ibc_primitives::prelude::*;
 use ibc_proto::Protobuf;
 
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::connection::State as ConnectionState;
-use crate::core::ics04_channel::channel::{ChannelEnd, Counterparty, State, State as ChannelState};
-use crate::core::ics04_channel::error::ChannelError;
-use crate::core::ics04_channel::events::CloseConfirm;
-use crate::core::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
-use crate::core::ics24_host::path::{ChannelEndPath, ClientConsensusStatePath, Path};
-use crate::core::router::Module;
-use crate::core::{ContextError, ExecutionContext, ValidationContext};
-use crate::prelude::*;
-
-pub(crate) fn chan_close_confirm_validate<ValCtx>(
+pub fn chan_close_confirm_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
     msg: MsgChannelCloseConfirm,
@@ -31,7 +31,7 @@ where
     Ok(())
 }
 
-pub(crate) fn chan_close_confirm_execute<ExecCtx>(
+pub fn chan_close_confirm_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module: &mut dyn Module,
     msg: MsgChannelCloseConfirm,
@@ -97,8 +97,6 @@ fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelCloseConfirm) -> Result<(), Contex
 where
     Ctx: ValidationContext,
 {
-    ctx_b.validate_message_signer(&msg.signer)?;
-
     // Retrieve the old channel end and validate it against the message.
     let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
@@ -153,7 +151,7 @@ where
         client_state_of_a_on_b
             .verify_membership(
                 prefix_on_a,
-                &msg.proof_chan_end_on_a,
+                &*ctx_b.resolve_proof(&msg.proof_chan_end_on_a)?,
                 consensus_state_of_a_on_b.root(),
                 Path::ChannelEnd(chan_end_path_on_a),
                 expected_chan_end_on_a.encode_vec(),
@@ -1,18 +1,19 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelCloseInit`.
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::ClientStateValidation;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::connection::State as ConnectionState;
-use crate::core::ics04_channel::channel::State;
-use crate::core::ics04_channel::error::ChannelError;
-use crate::core::ics04_channel::events::CloseInit;
-use crate::core::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
-use crate::core::ics24_host::path::ChannelEndPath;
-use crate::core::router::Module;
-use crate::core::{ContextError, ExecutionContext, ValidationContext};
-use crate::prelude::*;
-
-pub(crate) fn chan_close_init_validate<ValCtx>(
+use ibc_core_channel_types::channel::State;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::CloseInit;
+use ibc_core_channel_types::msgs::chan_close_init::MsgChannelCloseInit;
+use ibc_core_client_context::client_state::ClientStateValidation;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection_types::connection::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::ChannelEndPath;
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Module;
+use ibc_primitives::prelude::*;
+
+pub fn chan_close_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
     msg: MsgChannelCloseInit,
@@ -27,7 +28,7 @@ where
     Ok(())
 }
 
-pub(crate) fn chan_close_init_execute<ExecCtx>(
+pub fn chan_close_init_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
     msg: MsgChannelCloseInit,
@@ -94,8 +95,6 @@ fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelCloseInit) -> Result<(), ContextEr
 where
     Ctx: ValidationContext,
 {
-    ctx_a.validate_message_signer(&msg.signer)?;
-
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -1,22 +1,22 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelOpenAck`.
 
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::OpenAck;
+use ibc_core_channel_types::msgs::chan_open_ack::MsgChannelOpenAck;
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection_types::connection::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Module;
+use ibc_primitives::prelude::*;
 use ibc_proto::Protobuf;
 
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::connection::State as ConnectionState;
-use crate::core::ics04_channel::channel::{ChannelEnd, Counterparty, State, State as ChannelState};
-use crate::core::ics04_channel::error::ChannelError;
-use crate::core::ics04_channel::events::OpenAck;
-use crate::core::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
-use crate::core::ics24_host::path::{ChannelEndPath, ClientConsensusStatePath, Path};
-use crate::core::router::Module;
-use crate::core::{ContextError, ExecutionContext, ValidationContext};
-use crate::prelude::*;
-
-pub(crate) fn chan_open_ack_validate<ValCtx>(
+pub fn chan_open_ack_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
     msg: MsgChannelOpenAck,
@@ -31,7 +31,7 @@ where
     Ok(())
 }
 
-pub(crate) fn chan_open_ack_execute<ExecCtx>(
+pub fn chan_open_ack_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
     msg: MsgChannelOpenAck,
@@ -93,8 +93,6 @@ fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelOpenAck) -> Result<(), ContextErro
 where
     Ctx: ValidationContext,
 {
-    ctx_a.validate_message_signer(&msg.signer)?;
-
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -149,7 +147,7 @@ where
         client_state_of_b_on_a
             .verify_membership(
                 prefix_on_b,
-                &msg.proof_chan_end_on_b,
+                &*ctx_a.resolve_proof(&msg.proof_chan_end_on_b)?,
                 consensus_state_of_b_on_a.root(),
                 Path::ChannelEnd(chan_end_path_on_b),
                 expected_chan_end_on_b.encode_vec(),
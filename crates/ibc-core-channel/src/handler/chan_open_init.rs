@@ -1,18 +1,19 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelOpenInit`.
 
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::ClientStateValidation;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics04_channel::channel::{ChannelEnd, Counterparty, State};
-use crate::core::ics04_channel::events::OpenInit;
-use crate::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
-use crate::core::ics24_host::identifier::ChannelId;
-use crate::core::ics24_host::path::{ChannelEndPath, SeqAckPath, SeqRecvPath, SeqSendPath};
-use crate::core::router::Module;
-use crate::core::{ContextError, ExecutionContext, ValidationContext};
-use crate::prelude::*;
-
-pub(crate) fn chan_open_init_validate<ValCtx>(
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::OpenInit;
+use ibc_core_channel_types::msgs::chan_open_init::{ChanOpenInitResult, MsgChannelOpenInit};
+use ibc_core_client_context::client_state::ClientStateValidation;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{ChannelEndPath, SeqAckPath, SeqRecvPath, SeqSendPath};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Module;
+use ibc_primitives::prelude::*;
+
+pub fn chan_open_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
     msg: MsgChannelOpenInit,
@@ -21,7 +22,10 @@ where
     ValCtx: ValidationContext,
 {
     validate(ctx_a, &msg)?;
-    let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
+    let chan_id_on_a = ctx_a
+        .identifier_allocator()
+        .allocate_channel_id(ctx_a.channel_counter()?)
+        .map_err(ChannelError::InvalidIdentifier)?;
 
     module.on_chan_open_init_validate(
         msg.ordering,
@@ -35,15 +39,18 @@ where
     Ok(())
 }
 
-pub(crate) fn chan_open_init_execute<ExecCtx>(
+pub fn chan_open_init_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
     msg: MsgChannelOpenInit,
-) -> Result<(), ContextError>
+) -> Result<ChanOpenInitResult, ContextError>
 where
     ExecCtx: ExecutionContext,
 {
-    let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
+    let chan_id_on_a = ctx_a
+        .identifier_allocator()
+        .allocate_channel_id(ctx_a.channel_counter()?)
+        .map_err(ChannelError::InvalidIdentifier)?;
     let (extras, version) = module.on_chan_open_init_execute(
         msg.ordering,
         &msg.connection_hops_on_a,
@@ -90,7 +97,7 @@ where
             chan_id_on_a.clone(),
             msg.port_id_on_b,
             conn_id_on_a,
-            version,
+            version.clone(),
         ));
         ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_a.emit_ibc_event(core_event)?;
@@ -104,15 +111,16 @@ where
         }
     }
 
-    Ok(())
+    Ok(ChanOpenInitResult {
+        chan_id_on_a,
+        version,
+    })
 }
 
 fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelOpenInit) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
-    ctx_a.validate_message_signer(&msg.signer)?;
-
     msg.verify_connection_hops_length()?;
     // An IBC connection running on the local (host) chain should exist.
     let conn_end_on_a = ctx_a.connection_end(&msg.connection_hops_on_a[0])?;
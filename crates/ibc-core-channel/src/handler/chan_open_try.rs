@@ -1,25 +1,24 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelOpenTry`.
 
-use ibc_proto::Protobuf;
-
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::connection::State as ConnectionState;
-use crate::core::ics04_channel::channel::{ChannelEnd, Counterparty, State, State as ChannelState};
-use crate::core::ics04_channel::error::ChannelError;
-use crate::core::ics04_channel::events::OpenTry;
-use crate::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
-use crate::core::ics24_host::identifier::ChannelId;
-use crate::core::ics24_host::path::{
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::OpenTry;
+use ibc_core_channel_types::msgs::chan_open_try::{ChanOpenTryResult, MsgChannelOpenTry};
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection_types::connection::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{
     ChannelEndPath, ClientConsensusStatePath, Path, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
-use crate::core::router::Module;
-use crate::core::{ContextError, ExecutionContext, ValidationContext};
-use crate::prelude::*;
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Module;
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
 
-pub(crate) fn chan_open_try_validate<ValCtx>(
+pub fn chan_open_try_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
     msg: MsgChannelOpenTry,
@@ -29,7 +28,10 @@ where
 {
     validate(ctx_b, &msg)?;
 
-    let chan_id_on_b = ChannelId::new(ctx_b.channel_counter()?);
+    let chan_id_on_b = ctx_b
+        .identifier_allocator()
+        .allocate_channel_id(ctx_b.channel_counter()?)
+        .map_err(ChannelError::InvalidIdentifier)?;
 
     module.on_chan_open_try_validate(
         msg.ordering,
@@ -43,15 +45,18 @@ where
     Ok(())
 }
 
-pub(crate) fn chan_open_try_execute<ExecCtx>(
+pub fn chan_open_try_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module: &mut dyn Module,
     msg: MsgChannelOpenTry,
-) -> Result<(), ContextError>
+) -> Result<ChanOpenTryResult, ContextError>
 where
     ExecCtx: ExecutionContext,
 {
-    let chan_id_on_b = ChannelId::new(ctx_b.channel_counter()?);
+    let chan_id_on_b = ctx_b
+        .identifier_allocator()
+        .allocate_channel_id(ctx_b.channel_counter()?)
+        .map_err(ChannelError::InvalidIdentifier)?;
     let (extras, version) = module.on_chan_open_try_execute(
         msg.ordering,
         &msg.connection_hops_on_b,
@@ -100,7 +105,7 @@ where
             msg.port_id_on_a.clone(),
             msg.chan_id_on_a.clone(),
             conn_id_on_b,
-            version,
+            version.clone(),
         ));
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(core_event)?;
@@ -114,15 +119,16 @@ where
         }
     }
 
-    Ok(())
+    Ok(ChanOpenTryResult {
+        chan_id_on_b,
+        version,
+    })
 }
 
 fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelOpenTry) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
-    ctx_b.validate_message_signer(&msg.signer)?;
-
     msg.verify_connection_hops_length()?;
 
     let conn_end_on_b = ctx_b.connection_end(&msg.connection_hops_on_b[0])?;
@@ -173,7 +179,7 @@ where
         client_state_of_a_on_b
             .verify_membership(
                 prefix_on_a,
-                &msg.proof_chan_end_on_a,
+                &*ctx_b.resolve_proof(&msg.proof_chan_end_on_a)?,
                 consensus_state_of_a_on_b.root(),
                 Path::ChannelEnd(chan_end_path_on_a),
                 expected_chan_end_on_a.encode_vec(),
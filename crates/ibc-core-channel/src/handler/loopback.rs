@@ -0,0 +1,222 @@
+//! Implements the loopback of packets sent on localhost channels, i.e. on
+//! channels whose both ends are on the host, over connections of the
+//! `09-localhost` client.
+//!
+//! Such packets need no relayer: right after being sent, a packet is received
+//! by the module bound to its destination port, and the acknowledgement it
+//! writes is delivered back to the sending module, all within the same block.
+//! No proof is verified along the way, since both channel ends, and the
+//! commitment of the packet, are read from the host store itself.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::msgs::recv_packet::RecvOutcome;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_handler_types::error::{ContextError, RouterError};
+use ibc_core_handler_types::module::ModuleId;
+use ibc_core_handler_types::path::{ChannelEndPath, CommitmentPath, SeqAckPath};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+
+use crate::handler::acknowledgement::acknowledge_packet;
+use crate::handler::recv_packet::{receive_packet, validate_destination, validate_receipt};
+
+/// Returns whether `chan_end` is a localhost channel end, i.e. whether its
+/// connection is over the
+/// [`LOCALHOST_CLIENT_ID`](ibc_core_host_types::identifier::LOCALHOST_CLIENT_ID)
+/// client.
+pub fn is_localhost_channel<Ctx>(ctx: &Ctx, chan_end: &ChannelEnd) -> Result<bool, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let conn_end = ctx.connection_end(&chan_end.connection_hops()[0])?;
+
+    Ok(conn_end.client_id().is_localhost())
+}
+
+/// Loops back the given packet, sent on a localhost channel, including all
+/// necessary validation.
+///
+/// Equivalent to calling [`loopback_packet_validate`], followed by
+/// [`loopback_packet_execute`]
+pub fn loopback_packet<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    packet: Packet,
+    signer: &Signer,
+) -> Result<RecvOutcome, RouterError>
+where
+    Ctx: ExecutionContext,
+{
+    loopback_packet_validate(ctx, router, &packet, signer)?;
+    loopback_packet_execute(ctx, router, packet, signer)
+}
+
+/// Validate that looping back the given packet would succeed.
+///
+/// The packet must have been sent on a localhost channel, whose counterparty
+/// is also a localhost channel end on the host, and would be received on it as
+/// if relayed with valid proofs. As the acknowledgement is only known once the
+/// packet is received, the sending module validates it on execution.
+///
+/// Looped back packets bypass `validate`, so the
+/// signer is checked here instead.
+pub fn loopback_packet_validate<Ctx>(
+    ctx: &Ctx,
+    router: &impl Router,
+    packet: &Packet,
+    signer: &Signer,
+) -> Result<(), RouterError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.validate_message_signer(signer)?;
+
+    validate_channels(ctx, packet)?;
+
+    let module_id_on_a = lookup_module(router, &packet.port_id_on_a)?;
+    router
+        .get_route(&module_id_on_a)
+        .ok_or(RouterError::ModuleNotFound)?;
+
+    let module_id_on_b = lookup_module(router, &packet.port_id_on_b)?;
+    router
+        .get_route(&module_id_on_b)
+        .ok_or(RouterError::ModuleNotFound)?;
+
+    Ok(())
+}
+
+/// Loop back the packet without any validation: deliver it to the module of
+/// its destination port, then deliver the acknowledgement it wrote to the
+/// module of its source port, and returns whether the packet was received for
+/// the first time.
+///
+/// A prior call to [`loopback_packet_validate`] MUST have succeeded.
+pub fn loopback_packet_execute<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    packet: Packet,
+    signer: &Signer,
+) -> Result<RecvOutcome, RouterError>
+where
+    Ctx: ExecutionContext,
+{
+    let acknowledgement = {
+        let module_id_on_b = lookup_module(router, &packet.port_id_on_b)?;
+        let module_on_b = router
+            .get_route_mut(&module_id_on_b)
+            .ok_or(RouterError::ModuleNotFound)?;
+
+        match receive_packet(ctx, module_on_b, packet.clone(), signer)? {
+            Some(acknowledgement) => acknowledgement,
+            None => return Ok(RecvOutcome::AlreadyReceived),
+        }
+    };
+
+    let module_id_on_a = lookup_module(router, &packet.port_id_on_a)?;
+    let module_on_a = router
+        .get_route_mut(&module_id_on_a)
+        .ok_or(RouterError::ModuleNotFound)?;
+
+    acknowledge_packet(ctx, module_on_a, &packet, &acknowledgement, signer)?;
+
+    Ok(RecvOutcome::Received)
+}
+
+/// Validates both localhost channel ends the packet goes through, as if it
+/// were relayed with valid proofs.
+fn validate_channels<Ctx>(ctx: &Ctx, packet: &Packet) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    // the sending end
+    {
+        let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+        let chan_end_on_a = ctx.channel_end(&chan_end_path_on_a)?;
+
+        chan_end_on_a.verify_state_matches(&ChannelState::Open)?;
+
+        let counterparty = Counterparty::new(
+            packet.port_id_on_b.clone(),
+            Some(packet.chan_id_on_b.clone()),
+        );
+
+        chan_end_on_a.verify_counterparty_matches(&counterparty)?;
+
+        verify_localhost_channel(
+            ctx,
+            &chan_end_on_a,
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+        )?;
+
+        let commitment_path_on_a =
+            CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+        if ctx.get_packet_commitment(&commitment_path_on_a)? != packet.commitment() {
+            return Err(PacketError::IncorrectPacketCommitment {
+                sequence: packet.seq_on_a,
+            }
+            .into());
+        }
+
+        if let Order::Ordered = chan_end_on_a.ordering {
+            let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+            let next_seq_ack = ctx.get_next_sequence_ack(&seq_ack_path_on_a)?;
+            if packet.seq_on_a != next_seq_ack {
+                return Err(PacketError::InvalidPacketSequence {
+                    given_sequence: packet.seq_on_a,
+                    next_sequence: next_seq_ack,
+                }
+                .into());
+            }
+        }
+    }
+
+    // the receiving end
+    {
+        let (chan_end_on_b, _) = validate_destination(ctx, packet)?;
+
+        verify_localhost_channel(
+            ctx,
+            &chan_end_on_b,
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+        )?;
+
+        validate_receipt(ctx, &chan_end_on_b, packet)?;
+    }
+
+    Ok(())
+}
+
+fn verify_localhost_channel<Ctx>(
+    ctx: &Ctx,
+    chan_end: &ChannelEnd,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if !is_localhost_channel(ctx, chan_end)? {
+        return Err(PacketError::NotLocalhostChannel {
+            port_id: port_id.clone(),
+            channel_id: chan_id.clone(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+fn lookup_module(router: &impl Router, port_id: &PortId) -> Result<ModuleId, RouterError> {
+    router
+        .lookup_module(port_id)
+        .ok_or(RouterError::UnknownPort {
+            port_id: port_id.clone(),
+        })
+}
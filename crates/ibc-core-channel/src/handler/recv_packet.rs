@@ -1,28 +1,32 @@
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::connection::State as ConnectionState;
-use crate::core::ics03_connection::delay::verify_conn_delay_passed;
-use crate::core::ics04_channel::channel::{Counterparty, Order, State as ChannelState};
-use crate::core::ics04_channel::commitment::{compute_ack_commitment, compute_packet_commitment};
-use crate::core::ics04_channel::error::{ChannelError, PacketError};
-use crate::core::ics04_channel::events::{ReceivePacket, WriteAcknowledgement};
-use crate::core::ics04_channel::msgs::recv_packet::MsgRecvPacket;
-use crate::core::ics04_channel::packet::Receipt;
-use crate::core::ics24_host::path::{
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::events::{ReceivePacket, WriteAcknowledgement};
+use ibc_core_channel_types::msgs::recv_packet::{MsgRecvPacket, RecvOutcome};
+use ibc_core_channel_types::packet::{Packet, Receipt};
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_connection_types::connection::{ConnectionEnd, State as ConnectionState};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
     SeqRecvPath,
 };
-use crate::core::router::Module;
-use crate::core::timestamp::Expiry;
-use crate::core::{ContextError, ExecutionContext, ValidationContext};
-use crate::prelude::*;
-
-pub(crate) fn recv_packet_validate<ValCtx>(
-    ctx_b: &ValCtx,
-    msg: MsgRecvPacket,
-) -> Result<(), ContextError>
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use ibc_primitives::timestamp::Expiry;
+
+/// Validate that receiving the packet of `msg` would succeed.
+///
+/// Redundant relays of an already received packet pass validation, as they
+/// are executed as no-ops.
+pub fn recv_packet_validate<ValCtx>(ctx_b: &ValCtx, msg: MsgRecvPacket) -> Result<(), ContextError>
 where
     ValCtx: ValidationContext,
 {
@@ -33,16 +37,38 @@ where
     // If any error occurs, then an "error acknowledgement" must be returned.
 }
 
-pub(crate) fn recv_packet_execute<ExecCtx>(
+/// Receive the packet of `msg`, delivering it to `module`, and returns whether
+/// it was received for the first time.
+///
+/// Must be called after [`recv_packet_validate`].
+pub fn recv_packet_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module: &mut dyn Module,
     msg: MsgRecvPacket,
-) -> Result<(), ContextError>
+) -> Result<RecvOutcome, ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let outcome = match receive_packet(ctx_b, module, msg.packet, &msg.signer)? {
+        Some(_) => RecvOutcome::Received,
+        None => RecvOutcome::AlreadyReceived,
+    };
+
+    Ok(outcome)
+}
+
+/// Delivers `packet` to `module` and writes its acknowledgement, which is
+/// returned, or `None` if the packet was already received.
+pub fn receive_packet<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    module: &mut dyn Module,
+    packet: Packet,
+    signer: &Signer,
+) -> Result<Option<Acknowledgement>, ContextError>
 where
     ExecCtx: ExecutionContext,
 {
-    let chan_end_path_on_b =
-        ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
 
     // Check if another relayer already relayed the packet.
@@ -52,28 +78,28 @@ where
             // Note: ibc-go doesn't make the check for `Order::None` channels
             Order::None => false,
             Order::Unordered => {
-                let packet = &msg.packet;
                 let receipt_path_on_b =
                     ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
                 ctx_b.get_packet_receipt(&receipt_path_on_b).is_ok()
             }
             Order::Ordered => {
                 let seq_recv_path_on_b =
-                    SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+                    SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
                 let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
 
                 // the sequence number has already been incremented, so
                 // another relayer already relayed the packet
-                msg.packet.seq_on_a < next_seq_recv
+                packet.seq_on_a < next_seq_recv
             }
         };
 
         if packet_already_received {
-            return Ok(());
+            ctx_b.log_message("no-op: packet already received".to_string())?;
+            return Ok(None);
         }
     }
 
-    let (extras, acknowledgement) = module.on_recv_packet_execute(&msg.packet, &msg.signer);
+    let (extras, acknowledgement) = module.on_recv_packet_execute(&packet, signer);
 
     // state changes
     {
@@ -81,26 +107,23 @@ where
         match chan_end_on_b.ordering {
             Order::Unordered => {
                 let receipt_path_on_b = ReceiptPath {
-                    port_id: msg.packet.port_id_on_b.clone(),
-                    channel_id: msg.packet.chan_id_on_b.clone(),
-                    sequence: msg.packet.seq_on_a,
+                    port_id: packet.port_id_on_b.clone(),
+                    channel_id: packet.chan_id_on_b.clone(),
+                    sequence: packet.seq_on_a,
                 };
 
                 ctx_b.store_packet_receipt(&receipt_path_on_b, Receipt::Ok)?;
             }
             Order::Ordered => {
                 let seq_recv_path_on_b =
-                    SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+                    SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
                 let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
                 ctx_b.store_next_sequence_recv(&seq_recv_path_on_b, next_seq_recv.increment())?;
             }
             _ => {}
         }
-        let ack_path_on_b = AckPath::new(
-            &msg.packet.port_id_on_b,
-            &msg.packet.chan_id_on_b,
-            msg.packet.seq_on_a,
-        );
+        let ack_path_on_b =
+            AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
         // `writeAcknowledgement` handler state changes
         ctx_b.store_packet_acknowledgement(
             &ack_path_on_b,
@@ -114,16 +137,16 @@ where
         ctx_b.log_message("success: packet write acknowledgement".to_string())?;
 
         let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
-        let event = IbcEvent::ReceivePacket(ReceivePacket::new(
-            msg.packet.clone(),
-            chan_end_on_b.ordering,
-            conn_id_on_b.clone(),
-        ));
+        let event = IbcEvent::ReceivePacket(
+            ReceivePacket::new(packet.clone(), chan_end_on_b.ordering, conn_id_on_b.clone())
+                .with_recv_timestamp(ctx_b.host_timestamp()?)
+                .with_relayer(signer.clone()),
+        );
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(event)?;
         let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
-            msg.packet,
-            acknowledgement,
+            packet,
+            acknowledgement.clone(),
             conn_id_on_b.clone(),
         ));
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
@@ -138,46 +161,14 @@ where
         }
     }
 
-    Ok(())
+    Ok(Some(acknowledgement))
 }
 
 fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgRecvPacket) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
-    ctx_b.validate_message_signer(&msg.signer)?;
-
-    let chan_end_path_on_b =
-        ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
-    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
-
-    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
-
-    let counterparty = Counterparty::new(
-        msg.packet.port_id_on_a.clone(),
-        Some(msg.packet.chan_id_on_a.clone()),
-    );
-
-    chan_end_on_b.verify_counterparty_matches(&counterparty)?;
-
-    let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
-    let conn_end_on_b = ctx_b.connection_end(conn_id_on_b)?;
-
-    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
-
-    let latest_height = ctx_b.host_height()?;
-    if msg.packet.timeout_height_on_b.has_expired(latest_height) {
-        return Err(PacketError::LowPacketHeight {
-            chain_height: latest_height,
-            timeout_height: msg.packet.timeout_height_on_b,
-        }
-        .into());
-    }
-
-    let latest_timestamp = ctx_b.host_timestamp()?;
-    if let Expiry::Expired = latest_timestamp.check_expiry(&msg.packet.timeout_timestamp_on_b) {
-        return Err(PacketError::LowPacketTimestamp.into());
-    }
+    let (chan_end_on_b, conn_end_on_b) = validate_destination(ctx_b, &msg.packet)?;
 
     // Verify proofs
     {
@@ -214,7 +205,7 @@ where
         client_state_of_a_on_b
             .verify_membership(
                 conn_end_on_b.counterparty().prefix(),
-                &msg.proof_commitment_on_a,
+                &*ctx_b.resolve_proof(&msg.proof_commitment_on_a)?,
                 consensus_state_of_a_on_b.root(),
                 Path::Commitment(commitment_path_on_a),
                 expected_commitment_on_a.into_vec(),
@@ -226,53 +217,108 @@ where
             .map_err(PacketError::Channel)?;
     }
 
+    validate_receipt(ctx_b, &chan_end_on_b, &msg.packet)
+}
+
+/// Validates that `packet` can be received on its destination channel end,
+/// which is returned along with its connection end, before any proof is
+/// verified.
+pub fn validate_destination<Ctx>(
+    ctx_b: &Ctx,
+    packet: &Packet,
+) -> Result<(ChannelEnd, ConnectionEnd), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
+
+    let counterparty = Counterparty::new(
+        packet.port_id_on_a.clone(),
+        Some(packet.chan_id_on_a.clone()),
+    );
+
+    chan_end_on_b.verify_counterparty_matches(&counterparty)?;
+
+    let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
+    let conn_end_on_b = ctx_b.connection_end(conn_id_on_b)?;
+
+    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+
+    let latest_height = ctx_b.host_height()?;
+    if packet.timeout_height_on_b.has_expired(latest_height) {
+        return Err(PacketError::LowPacketHeight {
+            chain_height: latest_height,
+            timeout_height: packet.timeout_height_on_b,
+        }
+        .into());
+    }
+
+    let latest_timestamp = ctx_b.host_timestamp()?;
+    if let Expiry::Expired = latest_timestamp.check_expiry(&packet.timeout_timestamp_on_b) {
+        return Err(PacketError::LowPacketTimestamp.into());
+    }
+
+    Ok((chan_end_on_b, conn_end_on_b))
+}
+
+/// Validates that `packet` is either received for the first time, with its
+/// acknowledgement yet to be written, or already received, as a no-op.
+pub fn validate_receipt<Ctx>(
+    ctx_b: &Ctx,
+    chan_end_on_b: &ChannelEnd,
+    packet: &Packet,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
     if chan_end_on_b.order_matches(&Order::Ordered) {
-        let seq_recv_path_on_b =
-            SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+        let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
         let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
-        if msg.packet.seq_on_a > next_seq_recv {
+        if packet.seq_on_a > next_seq_recv {
             return Err(PacketError::InvalidPacketSequence {
-                given_sequence: msg.packet.seq_on_a,
+                given_sequence: packet.seq_on_a,
                 next_sequence: next_seq_recv,
             }
             .into());
         }
 
-        if msg.packet.seq_on_a == next_seq_recv {
+        if packet.seq_on_a == next_seq_recv {
             // Case where the recvPacket is successful and an
             // acknowledgement will be written (not a no-op)
-            validate_write_acknowledgement(ctx_b, msg)?;
+            validate_write_acknowledgement(ctx_b, packet)?;
         }
     } else {
-        let receipt_path_on_b = ReceiptPath::new(
-            &msg.packet.port_id_on_a,
-            &msg.packet.chan_id_on_a,
-            msg.packet.seq_on_a,
-        );
+        let receipt_path_on_b =
+            ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
         let packet_rec = ctx_b.get_packet_receipt(&receipt_path_on_b);
         match packet_rec {
+            // Redundant relay, executed as a no-op
             Ok(_receipt) => {}
             Err(ContextError::PacketError(PacketError::PacketReceiptNotFound { sequence }))
-                if sequence == msg.packet.seq_on_a => {}
+                if sequence == packet.seq_on_a =>
+            {
+                // Case where the recvPacket is successful and an
+                // acknowledgement will be written (not a no-op)
+                validate_write_acknowledgement(ctx_b, packet)?;
+            }
             Err(e) => return Err(e),
         }
-        // Case where the recvPacket is successful and an
-        // acknowledgement will be written (not a no-op)
-        validate_write_acknowledgement(ctx_b, msg)?;
     };
 
     Ok(())
 }
 
-fn validate_write_acknowledgement<Ctx>(ctx_b: &Ctx, msg: &MsgRecvPacket) -> Result<(), ContextError>
+fn validate_write_acknowledgement<Ctx>(ctx_b: &Ctx, packet: &Packet) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
-    let packet = msg.packet.clone();
     let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
     if ctx_b.get_packet_acknowledgement(&ack_path_on_b).is_ok() {
         return Err(PacketError::AcknowledgementExists {
-            sequence: msg.packet.seq_on_a,
+            sequence: packet.seq_on_a,
         }
         .into());
     }
@@ -1,21 +1,21 @@
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics04_channel::channel::Counterparty;
-use crate::core::ics04_channel::commitment::compute_packet_commitment;
-use crate::core::ics04_channel::context::{
-    SendPacketExecutionContext, SendPacketValidationContext,
-};
-use crate::core::ics04_channel::error::PacketError;
-use crate::core::ics04_channel::events::SendPacket;
-use crate::core::ics04_channel::packet::Packet;
-use crate::core::ics24_host::path::{
+use ibc_core_channel_types::channel::Counterparty;
+use ibc_core_channel_types::commitment::compute_packet_commitment;
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::events::SendPacket;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, SeqSendPath,
 };
-use crate::core::timestamp::Expiry;
-use crate::core::ContextError;
-use crate::prelude::*;
+use ibc_core_host::timeout_queue::PendingTimeout;
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Expiry;
+
+use crate::context::{SendPacketExecutionContext, SendPacketValidationContext};
 
 /// Send the given packet, including all necessary validation.
 ///
@@ -33,6 +33,16 @@ pub fn send_packet_validate(
     ctx_a: &impl SendPacketValidationContext,
     packet: &Packet,
 ) -> Result<(), ContextError> {
+    if let Some(max_size) = ctx_a.max_packet_data_size() {
+        if packet.data.len() > max_size {
+            return Err(PacketError::PacketDataTooLarge {
+                size: packet.data.len(),
+                max_size,
+            }
+            .into());
+        }
+    }
+
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -119,6 +129,10 @@ pub fn send_packet_execute(
         ),
     )?;
 
+    if let Some(timeout_queue) = ctx_a.timeout_queue() {
+        timeout_queue.enqueue_timeout(PendingTimeout::from(&packet))?;
+    }
+
     // emit events and logs
     {
         let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
@@ -126,11 +140,10 @@ pub fn send_packet_execute(
         let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
 
         ctx_a.log_message("success: packet send".to_string())?;
-        let event = IbcEvent::SendPacket(SendPacket::new(
-            packet,
-            chan_end_on_a.ordering,
-            conn_id_on_a.clone(),
-        ));
+        let event = IbcEvent::SendPacket(
+            SendPacket::new(packet, chan_end_on_a.ordering, conn_id_on_a.clone())
+                .with_send_timestamp(ctx_a.host_timestamp()?),
+        );
         ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_a.emit_ibc_event(event)?;
     }
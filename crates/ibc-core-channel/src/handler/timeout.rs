@@ -1,30 +1,32 @@
+use ibc_core_channel_types::channel::{Counterparty, Order, State};
+use ibc_core_channel_types::commitment::compute_packet_commitment;
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::events::{ChannelClosed, TimeoutPacket};
+use ibc_core_channel_types::msgs::timeout::MsgTimeout;
+use ibc_core_channel_types::msgs::timeout_on_close::MsgTimeoutOnClose;
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{
+    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, SeqRecvPath,
+};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host::receipt_bitmap::verify_packet_unreceived;
+use ibc_core_router::router::Module;
+use ibc_primitives::prelude::*;
 use prost::Message;
 
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::delay::verify_conn_delay_passed;
-use crate::core::ics04_channel::channel::{Counterparty, Order, State};
-use crate::core::ics04_channel::commitment::compute_packet_commitment;
-use crate::core::ics04_channel::error::{ChannelError, PacketError};
-use crate::core::ics04_channel::events::{ChannelClosed, TimeoutPacket};
-use crate::core::ics04_channel::handler::timeout_on_close;
-use crate::core::ics04_channel::msgs::timeout::MsgTimeout;
-use crate::core::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
-use crate::core::ics24_host::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
-};
-use crate::core::router::Module;
-use crate::core::{ContextError, ExecutionContext, ValidationContext};
-use crate::prelude::*;
+use crate::handler::timeout_on_close;
 
-pub(crate) enum TimeoutMsgType {
+pub enum TimeoutMsgType {
     Timeout(MsgTimeout),
     TimeoutOnClose(MsgTimeoutOnClose),
 }
 
-pub(crate) fn timeout_packet_validate<ValCtx>(
+pub fn timeout_packet_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
     timeout_msg_type: TimeoutMsgType,
@@ -47,7 +49,7 @@ where
         .map_err(ContextError::PacketError)
 }
 
-pub(crate) fn timeout_packet_execute<ExecCtx>(
+pub fn timeout_packet_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
     timeout_msg_type: TimeoutMsgType,
@@ -55,15 +57,20 @@ pub(crate) fn timeout_packet_execute<ExecCtx>(
 where
     ExecCtx: ExecutionContext,
 {
-    let (packet, signer) = match timeout_msg_type {
-        TimeoutMsgType::Timeout(msg) => (msg.packet, msg.signer),
-        TimeoutMsgType::TimeoutOnClose(msg) => (msg.packet, msg.signer),
+    let (packet, signer, on_close) = match timeout_msg_type {
+        TimeoutMsgType::Timeout(msg) => (msg.packet, msg.signer, false),
+        TimeoutMsgType::TimeoutOnClose(msg) => (msg.packet, msg.signer, true),
     };
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
-    // In all cases, this event is emitted
-    let event = IbcEvent::TimeoutPacket(TimeoutPacket::new(packet.clone(), chan_end_on_a.ordering));
+    // In all cases, this event is emitted, marked as such when the timeout is
+    // caused by the closing of the counterparty channel end
+    let mut event = TimeoutPacket::new(packet.clone(), chan_end_on_a.ordering);
+    if on_close {
+        event = event.with_on_close();
+    }
+    let event = IbcEvent::TimeoutPacket(event.with_relayer(signer.clone()));
     ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
     ctx_a.emit_ibc_event(event)?;
 
@@ -91,6 +98,13 @@ where
             sequence: packet.seq_on_a,
         };
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
+        if let Some(timeout_queue) = ctx_a.timeout_queue() {
+            timeout_queue.dequeue_timeout(
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                packet.seq_on_a,
+            )?;
+        }
 
         if let Order::Ordered = chan_end_on_a.ordering {
             let mut chan_end_on_a = chan_end_on_a;
@@ -138,8 +152,6 @@ fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgTimeout) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
-    ctx_a.validate_message_signer(&msg.signer)?;
-
     let chan_end_on_a = ctx_a.channel_end(&ChannelEndPath::new(
         &msg.packet.port_id_on_a,
         &msg.packet.chan_id_on_a,
@@ -217,7 +229,7 @@ where
 
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
-        let next_seq_recv_verification_result = if chan_end_on_a.order_matches(&Order::Ordered) {
+        if chan_end_on_a.order_matches(&Order::Ordered) {
             if msg.packet.seq_on_a < msg.next_seq_recv_on_b {
                 return Err(PacketError::InvalidPacketSequence {
                     given_sequence: msg.packet.seq_on_a,
@@ -235,33 +247,29 @@ where
                     sequence: msg.packet.seq_on_a,
                 })?;
 
-            client_state_of_b_on_a.verify_membership(
-                conn_end_on_a.counterparty().prefix(),
-                &msg.proof_unreceived_on_b,
-                consensus_state_of_b_on_a.root(),
-                Path::SeqRecv(seq_recv_path_on_b),
-                value,
-            )
+            client_state_of_b_on_a
+                .verify_membership(
+                    conn_end_on_a.counterparty().prefix(),
+                    &*ctx_a.resolve_proof(&msg.proof_unreceived_on_b)?,
+                    consensus_state_of_b_on_a.root(),
+                    Path::SeqRecv(seq_recv_path_on_b),
+                    value,
+                )
+                .map_err(|e| ChannelError::PacketVerificationFailed {
+                    sequence: msg.next_seq_recv_on_b,
+                    client_error: e,
+                })
+                .map_err(PacketError::Channel)?;
         } else {
-            let receipt_path_on_b = ReceiptPath::new(
-                &msg.packet.port_id_on_b,
-                &msg.packet.chan_id_on_b,
-                msg.packet.seq_on_a,
-            );
-
-            client_state_of_b_on_a.verify_non_membership(
+            verify_packet_unreceived(
+                ctx_a,
+                &client_state_of_b_on_a,
                 conn_end_on_a.counterparty().prefix(),
-                &msg.proof_unreceived_on_b,
                 consensus_state_of_b_on_a.root(),
-                Path::Receipt(receipt_path_on_b),
-            )
-        };
-        next_seq_recv_verification_result
-            .map_err(|e| ChannelError::PacketVerificationFailed {
-                sequence: msg.next_seq_recv_on_b,
-                client_error: e,
-            })
-            .map_err(PacketError::Channel)?;
+                &*ctx_a.resolve_proof(&msg.proof_unreceived_on_b)?,
+                &msg.packet,
+            )?;
+        }
     }
 
     Ok(())
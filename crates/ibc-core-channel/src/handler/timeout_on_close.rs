@@ -1,26 +1,25 @@
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc_core_channel_types::commitment::compute_packet_commitment;
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::msgs::timeout_on_close::MsgTimeoutOnClose;
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::path::{
+    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, SeqRecvPath,
+};
+use ibc_core_host::context::ValidationContext;
+use ibc_core_host::receipt_bitmap::verify_packet_unreceived;
+use ibc_primitives::prelude::*;
 use ibc_proto::Protobuf;
 use prost::Message;
 
-use crate::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::delay::verify_conn_delay_passed;
-use crate::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
-use crate::core::ics04_channel::commitment::compute_packet_commitment;
-use crate::core::ics04_channel::error::{ChannelError, PacketError};
-use crate::core::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
-use crate::core::ics24_host::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
-};
-use crate::core::{ContextError, ValidationContext};
-use crate::prelude::*;
-
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgTimeoutOnClose) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
-    ctx_a.validate_message_signer(&msg.signer)?;
-
     let packet = &msg.packet;
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
@@ -107,12 +106,12 @@ where
 
         let chan_end_path_on_b = ChannelEndPath(port_id_on_b, chan_id_on_b.clone());
 
-        // Verify the proof for the channel state against the expected channel end.
+        // Verify the proof that the channel end on B is closed.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
         client_state_of_b_on_a
             .verify_membership(
                 prefix_on_b,
-                &msg.proof_unreceived_on_b,
+                &*ctx_a.resolve_proof(&msg.proof_close_on_b)?,
                 consensus_state_of_b_on_a.root(),
                 Path::ChannelEnd(chan_end_path_on_b),
                 expected_chan_end_on_b.encode_vec(),
@@ -122,7 +121,10 @@ where
 
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
-        let next_seq_recv_verification_result = if chan_end_on_a.order_matches(&Order::Ordered) {
+        // On ordered channels, the packet was not received if the next
+        // sequence B expects, as proven by `proof_unreceived_on_b`, is not
+        // past it; on unordered channels, if B holds no receipt for it.
+        if chan_end_on_a.order_matches(&Order::Ordered) {
             if packet.seq_on_a < msg.next_seq_recv_on_b {
                 return Err(PacketError::InvalidPacketSequence {
                     given_sequence: packet.seq_on_a,
@@ -133,39 +135,35 @@ where
             let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
 
             let mut value = Vec::new();
-            u64::from(packet.seq_on_a).encode(&mut value).map_err(|_| {
-                PacketError::CannotEncodeSequence {
-                    sequence: packet.seq_on_a,
-                }
-            })?;
-
-            client_state_of_b_on_a.verify_membership(
-                conn_end_on_a.counterparty().prefix(),
-                &msg.proof_unreceived_on_b,
-                consensus_state_of_b_on_a.root(),
-                Path::SeqRecv(seq_recv_path_on_b),
-                value,
-            )
+            u64::from(msg.next_seq_recv_on_b)
+                .encode(&mut value)
+                .map_err(|_| PacketError::CannotEncodeSequence {
+                    sequence: msg.next_seq_recv_on_b,
+                })?;
+
+            client_state_of_b_on_a
+                .verify_membership(
+                    conn_end_on_a.counterparty().prefix(),
+                    &*ctx_a.resolve_proof(&msg.proof_unreceived_on_b)?,
+                    consensus_state_of_b_on_a.root(),
+                    Path::SeqRecv(seq_recv_path_on_b),
+                    value,
+                )
+                .map_err(|e| ChannelError::PacketVerificationFailed {
+                    sequence: msg.next_seq_recv_on_b,
+                    client_error: e,
+                })
+                .map_err(PacketError::Channel)?;
         } else {
-            let receipt_path_on_b = ReceiptPath::new(
-                &msg.packet.port_id_on_b,
-                &msg.packet.chan_id_on_b,
-                msg.packet.seq_on_a,
-            );
-
-            client_state_of_b_on_a.verify_non_membership(
+            verify_packet_unreceived(
+                ctx_a,
+                &client_state_of_b_on_a,
                 conn_end_on_a.counterparty().prefix(),
-                &msg.proof_unreceived_on_b,
                 consensus_state_of_b_on_a.root(),
-                Path::Receipt(receipt_path_on_b),
-            )
-        };
-        next_seq_recv_verification_result
-            .map_err(|e| ChannelError::PacketVerificationFailed {
-                sequence: msg.next_seq_recv_on_b,
-                client_error: e,
-            })
-            .map_err(PacketError::Channel)?;
+                &*ctx_a.resolve_proof(&msg.proof_unreceived_on_b)?,
+                &msg.packet,
+            )?;
+        }
     };
 
     Ok(())
@@ -0,0 +1,107 @@
+//! Computation of the unreceived packets and acknowledgements of a channel, as
+//! done by the `UnreceivedPackets` and `UnreceivedAcks` gRPC queries of ibc-go.
+//!
+//! Relayers embedding this crate use them to find out which packets still
+//! need to be relayed, so they must agree with ibc-go on every channel
+//! ordering.
+
+use ibc_core_channel_types::channel::Order;
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Sequence;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::path::{ChannelEndPath, CommitmentPath, ReceiptPath, SeqRecvPath};
+use ibc_core_host::context::ValidationContext;
+use ibc_primitives::prelude::*;
+
+/// Filters `sequences`, of packets sent by the counterparty to the channel end
+/// at `channel_end_path`, down to those of the packets that were not received
+/// yet. The order of `sequences` is preserved.
+///
+/// On an unordered channel, a packet is unreceived if no receipt is stored for
+/// it. On an ordered channel, it is unreceived if its sequence is not below the
+/// next sequence to receive.
+pub fn unreceived_packets<Ctx>(
+    ctx: &Ctx,
+    channel_end_path: &ChannelEndPath,
+    sequences: impl IntoIterator<Item = Sequence>,
+) -> Result<Vec<Sequence>, ContextError>
+where
+    Ctx: ValidationContext + ?Sized,
+{
+    let chan_end = ctx.channel_end(channel_end_path)?;
+    let ChannelEndPath(port_id, chan_id) = channel_end_path;
+
+    match chan_end.ordering() {
+        Order::Unordered => {
+            let mut unreceived = Vec::new();
+            for seq in sequences {
+                ensure_non_zero(seq)?;
+
+                let receipt_path = ReceiptPath::new(port_id, chan_id, seq);
+                match ctx.get_packet_receipt(&receipt_path) {
+                    Ok(_) => {}
+                    Err(ContextError::PacketError(PacketError::PacketReceiptNotFound {
+                        sequence,
+                    })) if sequence == seq => unreceived.push(seq),
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(unreceived)
+        }
+        Order::Ordered => {
+            let next_seq_recv = ctx.get_next_sequence_recv(&SeqRecvPath::new(port_id, chan_id))?;
+
+            let mut unreceived = Vec::new();
+            for seq in sequences {
+                ensure_non_zero(seq)?;
+
+                if seq >= next_seq_recv {
+                    unreceived.push(seq);
+                }
+            }
+            Ok(unreceived)
+        }
+        Order::None => Err(ChannelError::InvalidOrderType {
+            expected: "Channel ordering cannot be None".to_string(),
+            actual: chan_end.ordering().to_string(),
+        }
+        .into()),
+    }
+}
+
+/// Filters `sequences`, of packets sent on the channel end at
+/// `channel_end_path`, down to those whose acknowledgement was not received
+/// yet, i.e. whose commitment is still stored. The order of `sequences` is
+/// preserved.
+pub fn unreceived_acks<Ctx>(
+    ctx: &Ctx,
+    channel_end_path: &ChannelEndPath,
+    sequences: impl IntoIterator<Item = Sequence>,
+) -> Result<Vec<Sequence>, ContextError>
+where
+    Ctx: ValidationContext + ?Sized,
+{
+    ctx.channel_end(channel_end_path)?;
+    let ChannelEndPath(port_id, chan_id) = channel_end_path;
+
+    let mut unreceived = Vec::new();
+    for seq in sequences {
+        ensure_non_zero(seq)?;
+
+        let commitment_path = CommitmentPath::new(port_id, chan_id, seq);
+        match ctx.get_packet_commitment(&commitment_path) {
+            Ok(_) => unreceived.push(seq),
+            Err(ContextError::PacketError(PacketError::PacketCommitmentNotFound { sequence }))
+                if sequence == seq => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(unreceived)
+}
+
+fn ensure_non_zero(seq: Sequence) -> Result<(), PacketError> {
+    if seq.is_zero() {
+        return Err(PacketError::ZeroPacketSequence);
+    }
+    Ok(())
+}
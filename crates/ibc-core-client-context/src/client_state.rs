@@ -1,66 +1,22 @@
 //! Defines `ClientState`, the core type to be implemented by light clients
 
-use core::fmt::{Debug, Display, Formatter};
 use core::marker::{Send, Sync};
+use core::time::Duration;
 
-use ibc_proto::google::protobuf::Any;
-
-use crate::core::ics02_client::client_type::ClientType;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics02_client::ClientExecutionContext;
-use crate::core::ics23_commitment::commitment::{
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::Height;
+pub use ibc_core_client_types::msgs::UpdateKind;
+pub use ibc_core_client_types::status::Status;
+use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
-use crate::core::ics24_host::identifier::ClientId;
-use crate::core::ics24_host::path::Path;
-use crate::prelude::*;
-use crate::Height;
-
-/// `UpdateKind` represents the 2 ways that a client can be updated
-/// in IBC: either through a `MsgUpdateClient`, or a `MsgSubmitMisbehaviour`.
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub enum UpdateKind {
-    /// this is the typical scenario where a new header is submitted to the client
-    /// to update the client. Note that light clients are free to define the type
-    /// of the object used to update them (e.g. could be a list of headers).
-    UpdateClient,
-    /// this is the scenario where misbehaviour is submitted to the client
-    /// (e.g 2 headers with the same height in Tendermint)
-    SubmitMisbehaviour,
-}
-
-/// Represents the status of a client
-#[derive(Debug, PartialEq, Eq)]
-pub enum Status {
-    /// The client is active and allowed to be used
-    Active,
-    /// The client is frozen and not allowed to be used
-    Frozen,
-    /// The client is expired and not allowed to be used
-    Expired,
-    /// Unauthorized indicates that the client type is not registered as an allowed client type.
-    Unauthorized,
-}
-
-impl Status {
-    pub fn is_active(&self) -> bool {
-        *self == Status::Active
-    }
-
-    pub fn is_frozen(&self) -> bool {
-        *self == Status::Frozen
-    }
-
-    pub fn is_expired(&self) -> bool {
-        *self == Status::Expired
-    }
-}
+use ibc_core_handler_types::path::Path;
+use ibc_core_host_types::client_type::ClientType;
+use ibc_core_host_types::identifier::{ChainId, ClientId};
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
 
-impl Display for Status {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
-    }
-}
+use crate::context::ClientExecutionContext;
 
 /// `ClientState` methods needed in both validation and execution.
 ///
@@ -121,6 +77,33 @@ pub trait ClientStateCommon {
         root: &CommitmentRoot,
         path: Path,
     ) -> Result<(), ClientError>;
+
+    /// Returns the heights of the headers in `client_message`, reported as the
+    /// evidence heights of the `ClientMisbehaviour` event when the message
+    /// freezes the client.
+    ///
+    /// Defaults to no heights, for clients whose messages carry no headers.
+    fn evidence_heights(&self, _client_message: &Any) -> Vec<Height> {
+        Vec::new()
+    }
+
+    /// Returns whether `client_message`, submitted through a
+    /// `MsgUpdateClient`, holds a header to update the client with or
+    /// evidence of misbehaviour, as told by its type URL.
+    ///
+    /// Defaults to `UpdateKind::UpdateClient`, for clients whose misbehaviour
+    /// can only be submitted through a `MsgSubmitMisbehaviour`.
+    fn update_kind(&self, _client_message: &Any) -> UpdateKind {
+        UpdateKind::UpdateClient
+    }
+
+    /// Returns the identifier of the chain the client tracks, i.e. of the
+    /// counterparty of the host.
+    ///
+    /// Defaults to `None`, for clients of chains without a chain identifier.
+    fn counterparty_chain_id(&self) -> Option<ChainId> {
+        None
+    }
 }
 
 /// `ClientState` methods which require access to the client's validation
@@ -172,6 +155,16 @@ where
 
     /// Returns the status of the client. Only Active clients are allowed to process packets.
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError>;
+
+    /// Returns the time left before the client expires, zero if it already
+    /// has, or `None` if the client never expires, which is the default.
+    fn remaining_trusting_period(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+    ) -> Result<Option<Duration>, ClientError> {
+        Ok(None)
+    }
 }
 
 /// `ClientState` methods which require access to the client's
@@ -242,7 +235,7 @@ where
 /// `ClientExecutionContext` (e.g. `MyType<T>` would not be supported).
 pub use ibc_derive::ClientState;
 
-use super::ClientValidationContext;
+use crate::context::ClientValidationContext;
 
 /// Primary client trait. Defines all the methods that clients must implement.
 ///
@@ -1,14 +1,16 @@
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::events::ClientMisbehaviour;
+use ibc_core_client_types::height::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::timestamp::Timestamp;
+
 use super::client_state::ClientState;
-use super::consensus_state::ConsensusState;
-use crate::core::ics24_host::identifier::ClientId;
-use crate::core::ics24_host::path::{ClientConsensusStatePath, ClientStatePath};
-use crate::core::timestamp::Timestamp;
-use crate::core::ContextError;
-use crate::Height;
 
 /// Defines the methods available to clients for validating client state
 /// transitions. The generic `V` parameter in
-/// [crate::core::ics02_client::client_state::ClientStateValidation] must
+/// [crate::client_state::ClientStateValidation] must
 /// inherit from this trait.
 pub trait ClientValidationContext {
     /// Returns the time when the client state for the given [`ClientId`] was updated with a header for the given [`Height`]
@@ -28,7 +30,7 @@ pub trait ClientValidationContext {
 
 /// Defines the methods that all client `ExecutionContext`s (precisely the
 /// generic parameter of
-/// [`crate::core::ics02_client::client_state::ClientStateExecution`] ) must
+/// [`crate::client_state::ClientStateExecution`] ) must
 /// implement.
 ///
 /// Specifically, clients have the responsibility to store their client state
@@ -96,4 +98,13 @@ pub trait ClientExecutionContext: Sized {
         client_id: ClientId,
         height: Height,
     ) -> Result<(), ContextError>;
+
+    /// Called once misbehaviour has frozen a client, with the event reporting
+    /// it, so that hosts can e.g. raise alerts or halt the channels built on
+    /// the client. An error aborts the handling of the message.
+    ///
+    /// Does nothing by default.
+    fn on_client_frozen(&mut self, _misbehaviour: &ClientMisbehaviour) -> Result<(), ContextError> {
+        Ok(())
+    }
 }
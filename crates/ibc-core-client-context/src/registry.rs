@@ -0,0 +1,205 @@
+//! Defines the registry mapping the type URLs of client and consensus states to
+//! their decoders.
+
+use alloc::collections::btree_map::BTreeMap;
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_host_types::identifier::ChainId;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+
+use crate::client_state::ClientStateCommon;
+
+/// The client state types known to a host, which
+/// `validate` consults to reject the creation of
+/// clients of any other type with a
+/// [`RouterError::UnknownClientStateType`](ibc_core_handler_types::error::RouterError::UnknownClientStateType)
+/// listing them.
+///
+/// Implemented by [`ClientTypeRegistry`], so that hosts registering their
+/// client types at runtime expose them through
+/// `ValidationContext::known_client_types`.
+pub trait KnownClientTypes {
+    /// Returns whether client states of type `type_url` are known.
+    fn is_known_client_state(&self, type_url: &str) -> bool;
+
+    /// Returns the type URLs of the known client states.
+    fn known_client_state_types(&self) -> Vec<String>;
+}
+
+/// The client types of hosts that do not list them, for which all client
+/// state types are left to
+/// `ValidationContext::decode_client_state`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnlistedClientTypes;
+
+impl KnownClientTypes for UnlistedClientTypes {
+    fn is_known_client_state(&self, _type_url: &str) -> bool {
+        true
+    }
+
+    fn known_client_state_types(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Decodes a client or consensus state of a given type from its `Any`
+/// encoding.
+pub type AnyDecoder<T> = fn(Any) -> Result<T, ClientError>;
+
+/// Registry of the client and consensus state types known to a host, from
+/// which it decodes the `Any` encodings of its `AnyClientState` (`C`) and
+/// `AnyConsensusState` (`S`) types.
+///
+/// Hosts typically register each supported light client once, e.g.
+///
+/// ```ignore
+/// let registry = ClientTypeRegistry::<AnyClientState, AnyConsensusState>::new()
+///     .with_client::<TmClientState, TmConsensusState>(
+///         TENDERMINT_CLIENT_STATE_TYPE_URL,
+///         TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+///     );
+/// ```
+///
+/// and then implement `ValidationContext::decode_client_state` with
+/// [`ClientTypeRegistry::decode_client_state`].
+pub struct ClientTypeRegistry<C, S> {
+    client_states: BTreeMap<String, AnyDecoder<C>>,
+    consensus_states: BTreeMap<String, AnyDecoder<S>>,
+}
+
+impl<C, S> ClientTypeRegistry<C, S> {
+    /// Creates a registry with no known types.
+    pub fn new() -> Self {
+        Self {
+            client_states: BTreeMap::new(),
+            consensus_states: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the client and consensus state types `CS` and `CO` of a light
+    /// client under their type URLs, decoding them with their `TryFrom<Any>`
+    /// implementations.
+    pub fn with_client<CS, CO>(
+        self,
+        client_state_type_url: &str,
+        consensus_state_type_url: &str,
+    ) -> Self
+    where
+        CS: TryFrom<Any, Error = ClientError> + Into<C>,
+        CO: TryFrom<Any, Error = ClientError> + Into<S>,
+    {
+        self.with_client_state_decoder(client_state_type_url, decode_into::<CS, C>)
+            .with_consensus_state_decoder(consensus_state_type_url, decode_into::<CO, S>)
+    }
+
+    /// Registers `decoder` for the client states of type `type_url`, replacing
+    /// any decoder registered for it before.
+    pub fn with_client_state_decoder(mut self, type_url: &str, decoder: AnyDecoder<C>) -> Self {
+        self.client_states.insert(type_url.to_string(), decoder);
+        self
+    }
+
+    /// Registers `decoder` for the consensus states of type `type_url`,
+    /// replacing any decoder registered for it before.
+    pub fn with_consensus_state_decoder(mut self, type_url: &str, decoder: AnyDecoder<S>) -> Self {
+        self.consensus_states.insert(type_url.to_string(), decoder);
+        self
+    }
+
+    /// Decodes a client state with the decoder registered for its type URL.
+    pub fn decode_client_state(&self, client_state: Any) -> Result<C, ClientError> {
+        match self.client_states.get(&client_state.type_url) {
+            Some(decode) => decode(client_state),
+            None => Err(ClientError::UnknownClientStateType {
+                client_state_type: client_state.type_url,
+            }),
+        }
+    }
+
+    /// Decodes a consensus state with the decoder registered for its type URL.
+    pub fn decode_consensus_state(&self, consensus_state: Any) -> Result<S, ClientError> {
+        match self.consensus_states.get(&consensus_state.type_url) {
+            Some(decode) => decode(consensus_state),
+            None => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: consensus_state.type_url,
+            }),
+        }
+    }
+
+    /// Returns whether client states of type `type_url` can be decoded.
+    pub fn is_known_client_state(&self, type_url: &str) -> bool {
+        self.client_states.contains_key(type_url)
+    }
+
+    /// Returns whether consensus states of type `type_url` can be decoded.
+    pub fn is_known_consensus_state(&self, type_url: &str) -> bool {
+        self.consensus_states.contains_key(type_url)
+    }
+
+    /// Returns the type URLs of the registered client states, in lexicographic
+    /// order.
+    pub fn client_state_type_urls(&self) -> impl Iterator<Item = &str> {
+        self.client_states.keys().map(String::as_str)
+    }
+
+    /// Returns the type URLs of the registered consensus states, in
+    /// lexicographic order.
+    pub fn consensus_state_type_urls(&self) -> impl Iterator<Item = &str> {
+        self.consensus_states.keys().map(String::as_str)
+    }
+}
+
+impl<C, S> ClientTypeRegistry<C, S>
+where
+    C: ClientStateCommon,
+{
+    /// Returns the identifier of the chain tracked by a client, as told by its
+    /// client state decoded with the decoder registered for its type URL, or
+    /// `None` if its client type has no notion of chain identifier.
+    pub fn counterparty_chain_id(&self, client_state: Any) -> Result<Option<ChainId>, ClientError> {
+        self.decode_client_state(client_state)
+            .map(|client_state| client_state.counterparty_chain_id())
+    }
+}
+
+impl<C, S> KnownClientTypes for ClientTypeRegistry<C, S> {
+    fn is_known_client_state(&self, type_url: &str) -> bool {
+        self.client_states.contains_key(type_url)
+    }
+
+    fn known_client_state_types(&self) -> Vec<String> {
+        self.client_states.keys().cloned().collect()
+    }
+}
+
+impl<C, S> Default for ClientTypeRegistry<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C, S> Clone for ClientTypeRegistry<C, S> {
+    fn clone(&self) -> Self {
+        Self {
+            client_states: self.client_states.clone(),
+            consensus_states: self.consensus_states.clone(),
+        }
+    }
+}
+
+impl<C, S> core::fmt::Debug for ClientTypeRegistry<C, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ClientTypeRegistry")
+            .field("client_states", &self.client_states.keys())
+            .field("consensus_states", &self.consensus_states.keys())
+            .finish()
+    }
+}
+
+fn decode_into<T, U>(value: Any) -> Result<U, ClientError>
+where
+    T: TryFrom<Any, Error = ClientError> + Into<U>,
+{
+    T::try_from(value).map(Into::into)
+}
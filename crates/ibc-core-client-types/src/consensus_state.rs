@@ -2,13 +2,12 @@
 
 use core::marker::{Send, Sync};
 
+use ibc_core_commitment_types::commitment::CommitmentRoot;
 /// Derive macro that implements [`ConsensusState`] for enums containing
 /// variants that implement [`ConsensusState`]
 pub use ibc_derive::ConsensusState;
-
-use crate::core::ics23_commitment::commitment::CommitmentRoot;
-use crate::core::timestamp::Timestamp;
-use crate::prelude::*;
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
 
 /// Defines methods that all `ConsensusState`s should provide.
 ///
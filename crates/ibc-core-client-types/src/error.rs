@@ -1,15 +1,17 @@
 //! Defines the client error type
 
+use core::convert::Infallible;
+
 use displaydoc::Display;
+use ibc_core_commitment_types::error::CommitmentError;
+use ibc_core_host_types::client_type::ClientType;
+use ibc_core_host_types::identifier::{ChainId, ClientId, IdentifierError};
+use ibc_primitives::impl_error_sources;
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
 
-use super::client_state::Status;
-use crate::core::ics02_client::client_type::ClientType;
-use crate::core::ics23_commitment::error::CommitmentError;
-use crate::core::ics24_host::identifier::{ClientId, IdentifierError};
-use crate::core::timestamp::Timestamp;
-use crate::core::ContextError;
-use crate::prelude::*;
-use crate::Height;
+use super::status::Status;
+use crate::height::Height;
 
 /// Encodes all the possible client errors
 #[derive(Debug, Display)]
@@ -26,10 +28,27 @@ pub enum ClientError {
     ClientFrozen { description: String },
     /// client is not active. Status=`{status}`
     ClientNotActive { status: Status },
+    /// unknown client status: `{status}`
+    UnknownClientStatus { status: String },
     /// client state not found: `{client_id}`
     ClientStateNotFound { client_id: ClientId },
     /// client state already exists: `{client_id}`
     ClientStateAlreadyExists { client_id: ClientId },
+    /// client is identical to the existing client `{client_id}`
+    DuplicateClient { client_id: ClientId },
+    /// client type `{client_type}` is not in the allowed clients
+    ClientTypeNotAllowed { client_type: ClientType },
+    /// invalid client params: `{reason}`
+    InvalidClientParams { reason: String },
+    /// invalid client genesis: `{reason}`
+    InvalidGenesis { reason: String },
+    /// cannot migrate the store of client `{client_id}` from version `{stored}` to `{target}`, the latest version being `{latest}`
+    InvalidStoreVersion {
+        client_id: ClientId,
+        stored: u32,
+        target: u32,
+        latest: u32,
+    },
     /// consensus state not found at: `{client_id}` at height `{height}`
     ConsensusStateNotFound { client_id: ClientId, height: Height },
     /// Processed time for the client `{client_id}` at height `{height}` not found
@@ -84,7 +103,7 @@ pub enum ClientError {
     /// invalid commitment proof bytes error: `{0}`
     InvalidCommitmentProof(CommitmentError),
     /// invalid packet timeout timestamp value error: `{0}`
-    InvalidPacketTimestamp(crate::core::timestamp::ParseTimestampError),
+    InvalidPacketTimestamp(ibc_primitives::timestamp::ParseTimestampError),
     /// mismatch between client and arguments types
     ClientArgsTypeMismatch { client_type: ClientType },
     /// received header height (`{header_height}`) is lower than (or equal to) client latest height (`{latest_height}`)
@@ -110,17 +129,6 @@ pub enum ClientError {
     Other { description: String },
 }
 
-impl From<ContextError> for ClientError {
-    fn from(context_error: ContextError) -> Self {
-        match context_error {
-            ContextError::ClientError(e) => e,
-            _ => ClientError::Other {
-                description: context_error.to_string(),
-            },
-        }
-    }
-}
-
 impl From<&'static str> for ClientError {
     fn from(s: &'static str) -> Self {
         Self::Other {
@@ -129,25 +137,28 @@ impl From<&'static str> for ClientError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ClientError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self {
-            Self::ClientIdentifierConstructor {
-                validation_error: e,
-                ..
-            } => Some(e),
-            Self::InvalidMsgUpdateClientId(e) => Some(e),
-            Self::InvalidClientIdentifier(e) => Some(e),
-            Self::InvalidRawMisbehaviour(e) => Some(e),
-            Self::InvalidCommitmentProof(e) => Some(e),
-            Self::InvalidPacketTimestamp(e) => Some(e),
-            Self::Ics23Verification(e) => Some(e),
-            _ => None,
-        }
+/// Lets hosts supporting a single client type use its states as their
+/// `AnyClientState` and `AnyConsensusState`, whose identity conversions cannot
+/// fail.
+impl From<Infallible> for ClientError {
+    fn from(e: Infallible) -> Self {
+        match e {}
     }
 }
 
+impl_error_sources!(ClientError {
+    ibc {
+        Self::Upgrade(e) => e,
+        Self::ClientIdentifierConstructor { validation_error: e, .. } => e,
+        Self::InvalidMsgUpdateClientId(e) => e,
+        Self::InvalidClientIdentifier(e) => e,
+        Self::InvalidRawMisbehaviour(e) => e,
+        Self::InvalidCommitmentProof(e) => e,
+        Self::InvalidPacketTimestamp(e) => e,
+        Self::Ics23Verification(e) => e,
+    }
+});
+
 /// Encodes all the possible upgrade client errors
 #[derive(Debug, Display)]
 pub enum UpgradeClientError {
@@ -160,6 +171,16 @@ pub enum UpgradeClientError {
         upgraded_height: Height,
         client_height: Height,
     },
+    /// upgraded chain identifier `{upgraded_chain_id}` does not keep the chain name of `{chain_id}`
+    ChainNameMismatch {
+        chain_id: ChainId,
+        upgraded_chain_id: ChainId,
+    },
+    /// upgraded client height `{upgraded_height}` is not at the revision of upgraded chain identifier `{upgraded_chain_id}`
+    RevisionMismatch {
+        upgraded_chain_id: ChainId,
+        upgraded_height: Height,
+    },
     /// invalid upgrade proposal: `{reason}`
     InvalidUpgradeProposal { reason: String },
     /// invalid upgrade plan: `{reason}`
@@ -174,13 +195,9 @@ impl From<UpgradeClientError> for ClientError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for UpgradeClientError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self {
-            Self::InvalidUpgradeClientProof(e) => Some(e),
-            Self::InvalidUpgradeConsensusStateProof(e) => Some(e),
-            _ => None,
-        }
+impl_error_sources!(UpgradeClientError {
+    ibc {
+        Self::InvalidUpgradeClientProof(e) => e,
+        Self::InvalidUpgradeConsensusStateProof(e) => e,
     }
-}
+});
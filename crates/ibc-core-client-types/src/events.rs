@@ -0,0 +1,967 @@
+//! Types for the IBC events emitted from Tendermint Websocket by the client module.
+use core::time::Duration;
+
+use derive_more::From;
+use displaydoc::Display;
+use ibc_core_host_types::client_type::ClientType;
+use ibc_core_host_types::identifier::{ClientId, IdentifierError};
+use ibc_primitives::impl_error_sources;
+use ibc_primitives::prelude::*;
+use subtle_encoding::hex;
+use tendermint::abci;
+
+use crate::height::Height;
+
+/// Errors of decoding the client events
+#[derive(Debug, Display)]
+pub enum EventError {
+    /// error parsing height
+    Height,
+    /// parse error: `{0}`
+    Parse(IdentifierError),
+    /// incorrect event type: `{event}`
+    IncorrectEventType { event: String },
+    /// missing event attribute: `{key}`
+    MissingAttribute { key: String },
+    /// invalid value `{value}` of event attribute `{key}`
+    InvalidAttribute { key: String, value: String },
+}
+
+impl_error_sources!(EventError {
+    ibc {
+        Self::Parse(e) => e,
+    }
+});
+
+/// Client event types
+const CREATE_CLIENT_EVENT: &str = "create_client";
+const UPDATE_CLIENT_EVENT: &str = "update_client";
+const CLIENT_MISBEHAVIOUR_EVENT: &str = "client_misbehaviour";
+const UPGRADE_CLIENT_EVENT: &str = "upgrade_client";
+const CLIENT_NEAR_EXPIRY_EVENT: &str = "client_near_expiry";
+const CONFLICTING_HEADER_EVENT: &str = "client_conflicting_header";
+
+/// The content of the `key` field for the attribute containing the client identifier.
+pub const CLIENT_ID_ATTRIBUTE_KEY: &str = "client_id";
+
+/// The content of the `key` field for the attribute containing the client type.
+pub const CLIENT_TYPE_ATTRIBUTE_KEY: &str = "client_type";
+
+/// The content of the `key` field for the attribute containing the height.
+pub const CONSENSUS_HEIGHT_ATTRIBUTE_KEY: &str = "consensus_height";
+
+/// The content of the `key` field for the attribute containing the heights of consensus states that were processed.
+pub const CONSENSUS_HEIGHTS_ATTRIBUTE_KEY: &str = "consensus_heights";
+
+/// The content of the `key` field for the header in update client event.
+pub const HEADER_ATTRIBUTE_KEY: &str = "header";
+
+/// The content of the `key` field for the attribute containing the type URL of the evidence of misbehaviour.
+pub const EVIDENCE_TYPE_ATTRIBUTE_KEY: &str = "evidence_type";
+
+/// The content of the `key` field for the attribute containing the heights of the headers in the evidence of misbehaviour.
+pub const EVIDENCE_HEIGHTS_ATTRIBUTE_KEY: &str = "evidence_heights";
+
+/// The content of the `key` field for the attribute containing the seconds left before the client expires.
+pub const REMAINING_TRUSTING_PERIOD_ATTRIBUTE_KEY: &str = "remaining_trusting_period";
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct ClientIdAttribute {
+    client_id: ClientId,
+}
+
+impl From<ClientIdAttribute> for abci::EventAttribute {
+    fn from(attr: ClientIdAttribute) -> Self {
+        (CLIENT_ID_ATTRIBUTE_KEY, attr.client_id.as_str()).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct ClientTypeAttribute {
+    client_type: ClientType,
+}
+
+impl From<ClientTypeAttribute> for abci::EventAttribute {
+    fn from(attr: ClientTypeAttribute) -> Self {
+        (CLIENT_TYPE_ATTRIBUTE_KEY, attr.client_type.as_str()).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct ConsensusHeightAttribute {
+    consensus_height: Height,
+}
+
+impl From<ConsensusHeightAttribute> for abci::EventAttribute {
+    fn from(attr: ConsensusHeightAttribute) -> Self {
+        (CONSENSUS_HEIGHT_ATTRIBUTE_KEY, attr.consensus_height).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct ConsensusHeightsAttribute {
+    consensus_heights: Vec<Height>,
+}
+
+impl From<ConsensusHeightsAttribute> for abci::EventAttribute {
+    fn from(attr: ConsensusHeightsAttribute) -> Self {
+        let consensus_heights: Vec<String> = attr
+            .consensus_heights
+            .into_iter()
+            .map(|consensus_height| consensus_height.to_string())
+            .collect();
+        (CONSENSUS_HEIGHTS_ATTRIBUTE_KEY, consensus_heights.join(",")).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct HeaderAttribute {
+    /// NOTE: The header is encoded as bytes of the
+    /// [`Any`](ibc_proto::google::protobuf::Any) type.
+    header: Vec<u8>,
+}
+
+impl From<HeaderAttribute> for abci::EventAttribute {
+    fn from(attr: HeaderAttribute) -> Self {
+        (
+            HEADER_ATTRIBUTE_KEY,
+            String::from_utf8(hex::encode(attr.header))
+                .expect("Never fails because hexadecimal is valid UTF-8"),
+        )
+            .into()
+    }
+}
+
+/// CreateClient event signals the creation of a new on-chain client (IBC client).
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateClient {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+    consensus_height: ConsensusHeightAttribute,
+}
+
+impl CreateClient {
+    pub fn new(client_id: ClientId, client_type: ClientType, consensus_height: Height) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+            consensus_height: ConsensusHeightAttribute::from(consensus_height),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn consensus_height(&self) -> &Height {
+        &self.consensus_height.consensus_height
+    }
+
+    pub fn event_type(&self) -> &str {
+        CREATE_CLIENT_EVENT
+    }
+}
+
+impl From<CreateClient> for abci::Event {
+    fn from(c: CreateClient) -> Self {
+        Self {
+            kind: CREATE_CLIENT_EVENT.to_owned(),
+            attributes: vec![
+                c.client_id.into(),
+                c.client_type.into(),
+                c.consensus_height.into(),
+            ],
+        }
+    }
+}
+
+/// UpdateClient event signals a recent update of an on-chain client (IBC Client).
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateClient {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+    // Deprecated: consensus_height is deprecated and will be removed in a future release.
+    // Please use consensus_heights instead.
+    consensus_height: ConsensusHeightAttribute,
+    consensus_heights: ConsensusHeightsAttribute,
+    header: HeaderAttribute,
+}
+
+impl UpdateClient {
+    /// Constructs a new UpdateClient event.
+    ///
+    /// NOTE: the `header` is the encoded bytes of the
+    /// [`Any`](ibc_proto::google::protobuf::Any) type.
+    pub fn new(
+        client_id: ClientId,
+        client_type: ClientType,
+        consensus_height: Height,
+        consensus_heights: Vec<Height>,
+        header: Vec<u8>,
+    ) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+            consensus_height: ConsensusHeightAttribute::from(consensus_height),
+            consensus_heights: ConsensusHeightsAttribute::from(consensus_heights),
+            header: HeaderAttribute::from(header),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn consensus_height(&self) -> &Height {
+        &self.consensus_height.consensus_height
+    }
+
+    pub fn consensus_heights(&self) -> &[Height] {
+        self.consensus_heights.consensus_heights.as_ref()
+    }
+
+    pub fn header(&self) -> &Vec<u8> {
+        &self.header.header
+    }
+
+    pub fn event_type(&self) -> &str {
+        UPDATE_CLIENT_EVENT
+    }
+}
+
+impl From<UpdateClient> for abci::Event {
+    fn from(u: UpdateClient) -> Self {
+        Self {
+            kind: UPDATE_CLIENT_EVENT.to_owned(),
+            attributes: vec![
+                u.client_id.into(),
+                u.client_type.into(),
+                u.consensus_height.into(),
+                u.consensus_heights.into(),
+                u.header.into(),
+            ],
+        }
+    }
+}
+
+/// Decodes an `update_client` event, e.g. from a transaction result, for
+/// relayers to learn every consensus height a batched update wrote.
+///
+/// Events of chains that do not emit the `consensus_heights` attribute report
+/// the single height of their `consensus_height` attribute instead, and those
+/// without the `header` attribute an empty header.
+impl TryFrom<abci::Event> for UpdateClient {
+    type Error = EventError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        if event.kind != UPDATE_CLIENT_EVENT {
+            return Err(EventError::IncorrectEventType { event: event.kind });
+        }
+
+        let attribute = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.as_str())
+        };
+        let required_attribute = |key: &str| {
+            attribute(key).ok_or_else(|| EventError::MissingAttribute {
+                key: key.to_string(),
+            })
+        };
+        let parse_height = |value: &str| value.parse::<Height>().map_err(|_| EventError::Height);
+
+        let client_id = required_attribute(CLIENT_ID_ATTRIBUTE_KEY)?
+            .parse()
+            .map_err(EventError::Parse)?;
+        let client_type = required_attribute(CLIENT_TYPE_ATTRIBUTE_KEY)?
+            .parse()
+            .map_err(EventError::Parse)?;
+        let consensus_height = parse_height(required_attribute(CONSENSUS_HEIGHT_ATTRIBUTE_KEY)?)?;
+        let consensus_heights = match attribute(CONSENSUS_HEIGHTS_ATTRIBUTE_KEY) {
+            Some(value) => value
+                .split(',')
+                .map(parse_height)
+                .collect::<Result<_, _>>()?,
+            None => vec![consensus_height],
+        };
+        let header = match attribute(HEADER_ATTRIBUTE_KEY) {
+            Some(value) => hex::decode(value).map_err(|_| EventError::InvalidAttribute {
+                key: HEADER_ATTRIBUTE_KEY.to_string(),
+                value: value.to_string(),
+            })?,
+            None => Vec::new(),
+        };
+
+        Ok(Self::new(
+            client_id,
+            client_type,
+            consensus_height,
+            consensus_heights,
+            header,
+        ))
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct EvidenceTypeAttribute {
+    evidence_type: String,
+}
+
+impl From<EvidenceTypeAttribute> for abci::EventAttribute {
+    fn from(attr: EvidenceTypeAttribute) -> Self {
+        (EVIDENCE_TYPE_ATTRIBUTE_KEY, attr.evidence_type).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct EvidenceHeightsAttribute {
+    evidence_heights: Vec<Height>,
+}
+
+impl From<EvidenceHeightsAttribute> for abci::EventAttribute {
+    fn from(attr: EvidenceHeightsAttribute) -> Self {
+        let evidence_heights: Vec<String> = attr
+            .evidence_heights
+            .into_iter()
+            .map(|evidence_height| evidence_height.to_string())
+            .collect();
+        (EVIDENCE_HEIGHTS_ATTRIBUTE_KEY, evidence_heights.join(",")).into()
+    }
+}
+
+/// ClientMisbehaviour event signals the update of an on-chain client (IBC Client) with evidence of
+/// misbehaviour.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientMisbehaviour {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+    evidence_type: EvidenceTypeAttribute,
+    evidence_heights: EvidenceHeightsAttribute,
+}
+
+impl ClientMisbehaviour {
+    /// Constructs a new ClientMisbehaviour event.
+    ///
+    /// NOTE: the `evidence_type` is the type URL of the client message that
+    /// froze the client, and the `evidence_heights` are the heights of the
+    /// headers in it.
+    pub fn new(
+        client_id: ClientId,
+        client_type: ClientType,
+        evidence_type: String,
+        evidence_heights: Vec<Height>,
+    ) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+            evidence_type: EvidenceTypeAttribute::from(evidence_type),
+            evidence_heights: EvidenceHeightsAttribute::from(evidence_heights),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn evidence_type(&self) -> &str {
+        &self.evidence_type.evidence_type
+    }
+
+    pub fn evidence_heights(&self) -> &[Height] {
+        &self.evidence_heights.evidence_heights
+    }
+
+    pub fn event_type(&self) -> &str {
+        CLIENT_MISBEHAVIOUR_EVENT
+    }
+}
+
+impl From<ClientMisbehaviour> for abci::Event {
+    fn from(c: ClientMisbehaviour) -> Self {
+        Self {
+            kind: CLIENT_MISBEHAVIOUR_EVENT.to_owned(),
+            attributes: vec![
+                c.client_id.into(),
+                c.client_type.into(),
+                c.evidence_type.into(),
+                c.evidence_heights.into(),
+            ],
+        }
+    }
+}
+
+/// Signals a recent upgrade of an on-chain client (IBC Client).
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeClient {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+    consensus_height: ConsensusHeightAttribute,
+}
+
+impl UpgradeClient {
+    pub fn new(client_id: ClientId, client_type: ClientType, consensus_height: Height) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+            consensus_height: ConsensusHeightAttribute::from(consensus_height),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn consensus_height(&self) -> &Height {
+        &self.consensus_height.consensus_height
+    }
+
+    pub fn event_type(&self) -> &str {
+        UPGRADE_CLIENT_EVENT
+    }
+}
+
+impl From<UpgradeClient> for abci::Event {
+    fn from(u: UpgradeClient) -> Self {
+        Self {
+            kind: UPGRADE_CLIENT_EVENT.to_owned(),
+            attributes: vec![
+                u.client_id.into(),
+                u.client_type.into(),
+                u.consensus_height.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct RemainingTrustingPeriodAttribute {
+    /// The remaining trusting period, in whole seconds.
+    remaining_trusting_period: u64,
+}
+
+impl From<RemainingTrustingPeriodAttribute> for abci::EventAttribute {
+    fn from(attr: RemainingTrustingPeriodAttribute) -> Self {
+        (
+            REMAINING_TRUSTING_PERIOD_ATTRIBUTE_KEY,
+            attr.remaining_trusting_period.to_string(),
+        )
+            .into()
+    }
+}
+
+/// ClientNearExpiry event signals that an on-chain client (IBC Client) was
+/// updated, but expires within the
+/// margin set by
+/// the host unless it is updated again.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientNearExpiry {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+    consensus_height: ConsensusHeightAttribute,
+    remaining_trusting_period: RemainingTrustingPeriodAttribute,
+}
+
+impl ClientNearExpiry {
+    /// Constructs a new ClientNearExpiry event.
+    ///
+    /// NOTE: the `consensus_height` is the latest height of the client, and
+    /// the `remaining_trusting_period` is truncated to whole seconds.
+    pub fn new(
+        client_id: ClientId,
+        client_type: ClientType,
+        consensus_height: Height,
+        remaining_trusting_period: Duration,
+    ) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+            consensus_height: ConsensusHeightAttribute::from(consensus_height),
+            remaining_trusting_period: RemainingTrustingPeriodAttribute::from(
+                remaining_trusting_period.as_secs(),
+            ),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn consensus_height(&self) -> &Height {
+        &self.consensus_height.consensus_height
+    }
+
+    pub fn remaining_trusting_period(&self) -> Duration {
+        Duration::from_secs(self.remaining_trusting_period.remaining_trusting_period)
+    }
+
+    pub fn event_type(&self) -> &str {
+        CLIENT_NEAR_EXPIRY_EVENT
+    }
+}
+
+impl From<ClientNearExpiry> for abci::Event {
+    fn from(c: ClientNearExpiry) -> Self {
+        Self {
+            kind: CLIENT_NEAR_EXPIRY_EVENT.to_owned(),
+            attributes: vec![
+                c.client_id.into(),
+                c.client_type.into(),
+                c.consensus_height.into(),
+                c.remaining_trusting_period.into(),
+            ],
+        }
+    }
+}
+
+/// ConflictingHeader event signals that the header of an update of an
+/// on-chain client (IBC Client) conflicts with the consensus states the client
+/// stores, such as a header at an existing height with a different commitment
+/// root, which froze the client.
+///
+/// It is emitted along with the [`ClientMisbehaviour`] event, so that hosts
+/// tell such implicit misbehaviour from submitted evidence.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConflictingHeader {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+    consensus_heights: ConsensusHeightsAttribute,
+    header: HeaderAttribute,
+}
+
+impl ConflictingHeader {
+    /// Constructs a new ConflictingHeader event.
+    ///
+    /// NOTE: the `consensus_heights` are the heights of the headers of the
+    /// client message, and the `header` is the encoded bytes of its
+    /// [`Any`](ibc_proto::google::protobuf::Any) type.
+    pub fn new(
+        client_id: ClientId,
+        client_type: ClientType,
+        consensus_heights: Vec<Height>,
+        header: Vec<u8>,
+    ) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+            consensus_heights: ConsensusHeightsAttribute::from(consensus_heights),
+            header: HeaderAttribute::from(header),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn consensus_heights(&self) -> &[Height] {
+        self.consensus_heights.consensus_heights.as_ref()
+    }
+
+    pub fn header(&self) -> &Vec<u8> {
+        &self.header.header
+    }
+
+    pub fn event_type(&self) -> &str {
+        CONFLICTING_HEADER_EVENT
+    }
+}
+
+impl From<ConflictingHeader> for abci::Event {
+    fn from(c: ConflictingHeader) -> Self {
+        Self {
+            kind: CONFLICTING_HEADER_EVENT.to_owned(),
+            attributes: vec![
+                c.client_id.into(),
+                c.client_type.into(),
+                c.consensus_heights.into(),
+                c.header.into(),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use ibc_proto::google::protobuf::Any;
+    use ibc_testkit::utils::clients::mock::dummy_new_mock_header;
+    use prost::Message;
+    use tendermint::abci::Event as AbciEvent;
+
+    use super::*;
+
+    #[test]
+    fn ibc_to_abci_client_events() {
+        struct Test {
+            event_kind: &'static str,
+            event: AbciEvent,
+            expected_keys: Vec<&'static str>,
+            expected_values: Vec<&'static str>,
+        }
+
+        let client_type = ClientType::from_str("07-tendermint")
+            .expect("never fails because it's a valid client type");
+        let client_id = ClientId::new(client_type.clone(), 0).unwrap();
+        let consensus_height = Height::new(0, 5).unwrap();
+        let consensus_heights = vec![Height::new(0, 5).unwrap(), Height::new(0, 7).unwrap()];
+        let header: Any = dummy_new_mock_header(5).into();
+        let expected_keys = vec![
+            "client_id",
+            "client_type",
+            "consensus_height",
+            "consensus_heights",
+            "header",
+        ];
+
+        let expected_values = vec![
+            "07-tendermint-0",
+            "07-tendermint",
+            "0-5",
+            "0-5,0-7",
+            "0a102f6962632e6d6f636b2e48656164657212040a021005",
+        ];
+
+        let tests: Vec<Test> = vec![
+            Test {
+                event_kind: CREATE_CLIENT_EVENT,
+                event: CreateClient::new(client_id.clone(), client_type.clone(), consensus_height)
+                    .into(),
+                expected_keys: expected_keys[0..3].to_vec(),
+                expected_values: expected_values[0..3].to_vec(),
+            },
+            Test {
+                event_kind: UPDATE_CLIENT_EVENT,
+                event: UpdateClient::new(
+                    client_id.clone(),
+                    client_type.clone(),
+                    consensus_height,
+                    consensus_heights.clone(),
+                    header.encode_to_vec(),
+                )
+                .into(),
+                expected_keys: expected_keys.clone(),
+                expected_values: expected_values.clone(),
+            },
+            Test {
+                event_kind: UPGRADE_CLIENT_EVENT,
+                event: UpgradeClient::new(client_id.clone(), client_type.clone(), consensus_height)
+                    .into(),
+                expected_keys: expected_keys[0..3].to_vec(),
+                expected_values: expected_values[0..3].to_vec(),
+            },
+            Test {
+                event_kind: CLIENT_MISBEHAVIOUR_EVENT,
+                event: ClientMisbehaviour::new(
+                    client_id.clone(),
+                    client_type.clone(),
+                    "/ibc.mock.Misbehavior".to_string(),
+                    consensus_heights.clone(),
+                )
+                .into(),
+                expected_keys: [&expected_keys[0..2], &["evidence_type", "evidence_heights"]]
+                    .concat(),
+                expected_values: [
+                    &expected_values[0..2],
+                    &["/ibc.mock.Misbehavior", "0-5,0-7"],
+                ]
+                .concat(),
+            },
+            Test {
+                event_kind: CONFLICTING_HEADER_EVENT,
+                event: ConflictingHeader::new(
+                    client_id.clone(),
+                    client_type.clone(),
+                    consensus_heights.clone(),
+                    header.encode_to_vec(),
+                )
+                .into(),
+                expected_keys: [&expected_keys[0..2], &expected_keys[3..5]].concat(),
+                expected_values: [&expected_values[0..2], &expected_values[3..5]].concat(),
+            },
+            Test {
+                event_kind: CLIENT_NEAR_EXPIRY_EVENT,
+                event: ClientNearExpiry::new(
+                    client_id,
+                    client_type,
+                    consensus_height,
+                    Duration::from_millis(3_600_500),
+                )
+                .into(),
+                expected_keys: [&expected_keys[0..3], &["remaining_trusting_period"]].concat(),
+                expected_values: [&expected_values[0..3], &["3600"]].concat(),
+            },
+        ];
+
+        for t in tests {
+            assert_eq!(t.event.kind, t.event_kind);
+            assert_eq!(t.expected_keys.len(), t.event.attributes.len());
+            for (i, e) in t.event.attributes.iter().enumerate() {
+                assert_eq!(
+                    e.key, t.expected_keys[i],
+                    "key mismatch for {:?}",
+                    t.event_kind
+                );
+            }
+            for (i, e) in t.event.attributes.iter().enumerate() {
+                assert_eq!(
+                    e.value, t.expected_values[i],
+                    "value mismatch for {:?}",
+                    t.event_kind
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn abci_to_update_client_event() {
+        let client_type = ClientType::from_str("07-tendermint").unwrap();
+        let client_id = ClientId::new(client_type.clone(), 0).unwrap();
+        let consensus_heights = vec![Height::new(0, 5).unwrap(), Height::new(0, 7).unwrap()];
+        let header: Any = dummy_new_mock_header(7).into();
+
+        let update_client = UpdateClient::new(
+            client_id.clone(),
+            client_type.clone(),
+            consensus_heights[0],
+            consensus_heights.clone(),
+            header.encode_to_vec(),
+        );
+        let event = AbciEvent::from(update_client.clone());
+        let decoded = UpdateClient::try_from(event.clone()).unwrap();
+        assert_eq!(decoded, update_client);
+        assert_eq!(decoded.consensus_heights(), consensus_heights.as_slice());
+
+        // without the `consensus_heights` and `header` attributes
+        let legacy_event = AbciEvent {
+            kind: event.kind.clone(),
+            attributes: event.attributes[0..3].to_vec(),
+        };
+        let decoded = UpdateClient::try_from(legacy_event).unwrap();
+        assert_eq!(decoded.consensus_heights(), &consensus_heights[0..1]);
+        assert!(decoded.header().is_empty());
+
+        let missing_client_id = AbciEvent {
+            kind: event.kind.clone(),
+            attributes: event.attributes[1..].to_vec(),
+        };
+        assert!(matches!(
+            UpdateClient::try_from(missing_client_id),
+            Err(EventError::MissingAttribute { key }) if key == CLIENT_ID_ATTRIBUTE_KEY
+        ));
+
+        let create_client: AbciEvent =
+            CreateClient::new(client_id, client_type, consensus_heights[0]).into();
+        assert!(matches!(
+            UpdateClient::try_from(create_client),
+            Err(EventError::IncorrectEventType { .. })
+        ));
+    }
+}
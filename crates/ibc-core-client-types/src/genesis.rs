@@ -0,0 +1,274 @@
+//! Defines the genesis state of the client module.
+
+use alloc::collections::btree_map::BTreeMap;
+
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::{
+    ClientConsensusStates as RawClientConsensusStates,
+    ConsensusStateWithHeight as RawConsensusStateWithHeight, GenesisMetadata as RawGenesisMetadata,
+    GenesisState as RawClientGenesisState, IdentifiedClientState as RawIdentifiedClientState,
+    IdentifiedGenesisMetadata as RawIdentifiedGenesisMetadata,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+use crate::height::Height;
+use crate::params::ClientParams;
+
+/// A client in the genesis state, along with its consensus states.
+///
+/// The states are kept encoded, as they are only decoded by the host when the
+/// genesis is imported.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenesisClient {
+    pub client_id: ClientId,
+    pub client_state: Any,
+    pub consensus_states: Vec<(Height, Any)>,
+    pub processed_consensus_states: Vec<ProcessedConsensusState>,
+}
+
+/// The host time and height at which a consensus state of a client was
+/// processed, against which connection delays are checked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProcessedConsensusState {
+    pub height: Height,
+    pub processed_time: Timestamp,
+    pub processed_height: Height,
+}
+
+/// The prefix of the client metadata keys of a consensus state, followed by
+/// its height and then by one of the suffixes below, as in ibc-go.
+const CONSENSUS_STATES_KEY: &str = "consensusStates";
+const PROCESSED_TIME_KEY: &str = "processedTime";
+const PROCESSED_HEIGHT_KEY: &str = "processedHeight";
+
+impl ProcessedConsensusState {
+    fn key(height: &Height, suffix: &str) -> Vec<u8> {
+        format!("{CONSENSUS_STATES_KEY}/{height}/{suffix}").into_bytes()
+    }
+
+    /// Encodes the processed time and height as client metadata, the former
+    /// as big-endian nanoseconds and the latter as a height string.
+    fn into_raw(self) -> [RawGenesisMetadata; 2] {
+        [
+            RawGenesisMetadata {
+                key: Self::key(&self.height, PROCESSED_TIME_KEY),
+                value: self.processed_time.nanoseconds().to_be_bytes().to_vec(),
+            },
+            RawGenesisMetadata {
+                key: Self::key(&self.height, PROCESSED_HEIGHT_KEY),
+                value: self.processed_height.to_string().into_bytes(),
+            },
+        ]
+    }
+
+    /// Decodes the processed times and heights from the metadata of client
+    /// `client_id`, ignoring the metadata of other kinds.
+    fn from_raw(
+        client_id: &ClientId,
+        metadata: Vec<RawGenesisMetadata>,
+    ) -> Result<Vec<Self>, ClientError> {
+        let invalid = |reason: String| ClientError::InvalidGenesis {
+            reason: format!("metadata of client `{client_id}`: {reason}"),
+        };
+
+        let mut processed_times = BTreeMap::new();
+        let mut processed_heights = BTreeMap::new();
+        for entry in metadata {
+            let key = match core::str::from_utf8(&entry.key) {
+                Ok(key) => key,
+                Err(_) => continue,
+            };
+            let mut segments = key.split('/');
+            let (height, suffix) = match (segments.next(), segments.next(), segments.next()) {
+                (Some(CONSENSUS_STATES_KEY), Some(height), Some(suffix))
+                    if segments.next().is_none() =>
+                {
+                    (height, suffix)
+                }
+                _ => continue,
+            };
+            let height: Height = match suffix {
+                PROCESSED_TIME_KEY | PROCESSED_HEIGHT_KEY => height
+                    .parse()
+                    .map_err(|e| invalid(format!("key `{key}`: {e}")))?,
+                _ => continue,
+            };
+
+            if suffix == PROCESSED_TIME_KEY {
+                let nanos = <[u8; 8]>::try_from(entry.value.as_slice())
+                    .map(u64::from_be_bytes)
+                    .map_err(|_| invalid(format!("`{key}` is not a big-endian u64")))?;
+                let processed_time = Timestamp::from_nanoseconds(nanos)
+                    .map_err(|e| invalid(format!("`{key}`: {e}")))?;
+                processed_times.insert(height, processed_time);
+            } else {
+                let processed_height = core::str::from_utf8(&entry.value)
+                    .map_err(|_| invalid(format!("`{key}` is not a height")))?
+                    .parse()
+                    .map_err(|e| invalid(format!("`{key}`: {e}")))?;
+                processed_heights.insert(height, processed_height);
+            }
+        }
+
+        let mut processed_consensus_states = Vec::with_capacity(processed_times.len());
+        for (height, processed_time) in processed_times {
+            let processed_height = processed_heights.remove(&height).ok_or_else(|| {
+                invalid(format!(
+                    "processed time at height {height} without a processed height"
+                ))
+            })?;
+            processed_consensus_states.push(Self {
+                height,
+                processed_time,
+                processed_height,
+            });
+        }
+        if let Some(height) = processed_heights.into_keys().next() {
+            return Err(invalid(format!(
+                "processed height at height {height} without a processed time"
+            )));
+        }
+
+        Ok(processed_consensus_states)
+    }
+}
+
+/// The state of the client module, imported at genesis or exported for an
+/// upgrade.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientGenesisState {
+    pub clients: Vec<GenesisClient>,
+    pub params: ClientParams,
+    pub next_client_sequence: u64,
+}
+
+impl Protobuf<RawClientGenesisState> for ClientGenesisState {}
+
+impl TryFrom<RawClientGenesisState> for ClientGenesisState {
+    type Error = ClientError;
+
+    fn try_from(raw: RawClientGenesisState) -> Result<Self, Self::Error> {
+        let mut clients = raw
+            .clients
+            .into_iter()
+            .map(|client| {
+                Ok(GenesisClient {
+                    client_id: client
+                        .client_id
+                        .parse()
+                        .map_err(ClientError::InvalidClientIdentifier)?,
+                    client_state: client
+                        .client_state
+                        .ok_or(ClientError::MissingRawClientState)?,
+                    consensus_states: Vec::new(),
+                    processed_consensus_states: Vec::new(),
+                })
+            })
+            .collect::<Result<Vec<_>, ClientError>>()?;
+
+        for client_consensus in raw.clients_consensus {
+            let client_id: ClientId = client_consensus
+                .client_id
+                .parse()
+                .map_err(ClientError::InvalidClientIdentifier)?;
+            let client = clients
+                .iter_mut()
+                .find(|client| client.client_id == client_id)
+                .ok_or_else(|| ClientError::InvalidGenesis {
+                    reason: format!("consensus states of unknown client `{client_id}`"),
+                })?;
+
+            for consensus in client_consensus.consensus_states {
+                let height = consensus
+                    .height
+                    .ok_or_else(|| ClientError::InvalidGenesis {
+                        reason: format!("consensus state of client `{client_id}` without height"),
+                    })?
+                    .try_into()?;
+                let consensus_state = consensus
+                    .consensus_state
+                    .ok_or(ClientError::MissingRawConsensusState)?;
+                client.consensus_states.push((height, consensus_state));
+            }
+        }
+
+        for client_metadata in raw.clients_metadata {
+            let client_id: ClientId = client_metadata
+                .client_id
+                .parse()
+                .map_err(ClientError::InvalidClientIdentifier)?;
+            let client = clients
+                .iter_mut()
+                .find(|client| client.client_id == client_id)
+                .ok_or_else(|| ClientError::InvalidGenesis {
+                    reason: format!("metadata of unknown client `{client_id}`"),
+                })?;
+
+            client
+                .processed_consensus_states
+                .extend(ProcessedConsensusState::from_raw(
+                    &client_id,
+                    client_metadata.client_metadata,
+                )?);
+        }
+
+        Ok(Self {
+            clients,
+            params: raw
+                .params
+                .map(ClientParams::try_from)
+                .transpose()?
+                .unwrap_or_default(),
+            next_client_sequence: raw.next_client_sequence,
+        })
+    }
+}
+
+impl From<ClientGenesisState> for RawClientGenesisState {
+    fn from(genesis: ClientGenesisState) -> Self {
+        let mut clients = Vec::with_capacity(genesis.clients.len());
+        let mut clients_consensus = Vec::with_capacity(genesis.clients.len());
+        let mut clients_metadata = Vec::new();
+
+        for client in genesis.clients {
+            if !client.processed_consensus_states.is_empty() {
+                clients_metadata.push(RawIdentifiedGenesisMetadata {
+                    client_id: client.client_id.to_string(),
+                    client_metadata: client
+                        .processed_consensus_states
+                        .into_iter()
+                        .flat_map(ProcessedConsensusState::into_raw)
+                        .collect(),
+                });
+            }
+            clients_consensus.push(RawClientConsensusStates {
+                client_id: client.client_id.to_string(),
+                consensus_states: client
+                    .consensus_states
+                    .into_iter()
+                    .map(|(height, consensus_state)| RawConsensusStateWithHeight {
+                        height: Some(height.into()),
+                        consensus_state: Some(consensus_state),
+                    })
+                    .collect(),
+            });
+            clients.push(RawIdentifiedClientState {
+                client_id: client.client_id.to_string(),
+                client_state: Some(client.client_state),
+            });
+        }
+
+        Self {
+            clients,
+            clients_consensus,
+            clients_metadata,
+            params: Some(genesis.params.into()),
+            create_localhost: false,
+            next_client_sequence: genesis.next_client_sequence,
+        }
+    }
+}
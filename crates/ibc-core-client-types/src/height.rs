@@ -5,11 +5,12 @@ use core::num::ParseIntError;
 use core::str::FromStr;
 
 use displaydoc::Display;
+use ibc_primitives::impl_error_sources;
+use ibc_primitives::prelude::*;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 use ibc_proto::Protobuf;
 
-use crate::core::ics02_client::error::ClientError;
-use crate::prelude::*;
+use crate::error::ClientError;
 
 /// The core IBC height type, which represents the height of a chain,
 /// which typically is the number of blocks since genesis
@@ -89,6 +90,36 @@ impl Height {
     pub fn decrement(&self) -> Result<Height, ClientError> {
         self.sub(1)
     }
+
+    /// Parses a height from either its `{revision_number}-{revision_height}`
+    /// form, or the bare `{revision_height}` form some chains serialize their
+    /// heights in, in which case the height is of the given revision.
+    ///
+    /// Parsing a height with [`FromStr`] only accepts the former.
+    pub fn parse_with_default_revision(
+        value: &str,
+        default_revision_number: u64,
+    ) -> Result<Self, HeightError> {
+        if value.contains('-') {
+            return value.parse();
+        }
+
+        let revision_height = parse_height_component(value, value)?;
+
+        Height::new(default_revision_number, revision_height).map_err(|_| HeightError::ZeroHeight)
+    }
+
+    /// Formats the height in its bare `{revision_height}` form if it is of the
+    /// given revision, and in its `{revision_number}-{revision_height}` form
+    /// otherwise, so that [`Height::parse_with_default_revision`] parses it
+    /// back with the same revision.
+    pub fn to_string_with_default_revision(&self, default_revision_number: u64) -> String {
+        if self.revision_number == default_revision_number {
+            self.revision_height.to_string()
+        } else {
+            self.to_string()
+        }
+    }
 }
 
 impl PartialOrd for Height {
@@ -132,6 +163,16 @@ impl From<Height> for RawHeight {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Height {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            revision_number: u.arbitrary()?,
+            revision_height: u.int_in_range(1..=u64::MAX)?,
+        })
+    }
+}
+
 impl core::fmt::Debug for Height {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
         f.debug_struct("Height")
@@ -162,16 +203,12 @@ pub enum HeightError {
     InvalidFormat { raw_height: String },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for HeightError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self {
-            HeightError::HeightConversion { error: e, .. } => Some(e),
-            HeightError::ZeroHeight => None,
-            HeightError::InvalidFormat { .. } => None,
-        }
+impl_error_sources!(HeightError {
+    ibc {}
+    std {
+        HeightError::HeightConversion { error: e, .. } => e,
     }
-}
+});
 
 impl TryFrom<&str> for Height {
     type Error = HeightError;
@@ -186,26 +223,23 @@ impl TryFrom<&str> for Height {
             }
         };
 
-        let revision_number =
-            rev_number_str
-                .parse::<u64>()
-                .map_err(|e| HeightError::HeightConversion {
-                    height: value.to_owned(),
-                    error: e,
-                })?;
-
-        let revision_height =
-            rev_height_str
-                .parse::<u64>()
-                .map_err(|e| HeightError::HeightConversion {
-                    height: value.to_owned(),
-                    error: e,
-                })?;
+        let revision_number = parse_height_component(value, rev_number_str)?;
+        let revision_height = parse_height_component(value, rev_height_str)?;
 
         Height::new(revision_number, revision_height).map_err(|_| HeightError::ZeroHeight)
     }
 }
 
+/// Parses the revision number or height `component` of the string `height`.
+fn parse_height_component(height: &str, component: &str) -> Result<u64, HeightError> {
+    component
+        .parse::<u64>()
+        .map_err(|e| HeightError::HeightConversion {
+            height: height.to_owned(),
+            error: e,
+        })
+}
+
 impl From<Height> for String {
     fn from(height: Height) -> Self {
         format!("{}-{}", height.revision_number, height.revision_height)
@@ -261,3 +295,46 @@ fn test_invalid_height() {
         })
     );
 }
+
+#[test]
+fn test_parse_with_default_revision() {
+    assert_eq!(
+        Height::parse_with_default_revision("42", 3),
+        Ok(Height {
+            revision_number: 3,
+            revision_height: 42
+        })
+    );
+    assert_eq!(
+        Height::parse_with_default_revision("1-42", 3),
+        Ok(Height {
+            revision_number: 1,
+            revision_height: 42
+        })
+    );
+    assert_eq!(
+        Height::parse_with_default_revision("0", 3),
+        Err(HeightError::ZeroHeight)
+    );
+    assert!(Height::parse_with_default_revision("", 3).is_err());
+    assert!(Height::parse_with_default_revision("-42", 3).is_err());
+    assert!(Height::parse_with_default_revision("4a", 3).is_err());
+}
+
+#[test]
+fn test_to_string_with_default_revision() {
+    let height = Height::new(3, 42).unwrap();
+
+    assert_eq!(height.to_string_with_default_revision(3), "42");
+    assert_eq!(height.to_string_with_default_revision(0), "3-42");
+
+    for default_revision_number in [0, 3] {
+        assert_eq!(
+            Height::parse_with_default_revision(
+                &height.to_string_with_default_revision(default_revision_number),
+                default_revision_number
+            ),
+            Ok(height)
+        );
+    }
+}
@@ -0,0 +1,29 @@
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+// https://github.com/cosmos/ibc-rs/issues/342
+#![allow(clippy::result_large_err)]
+//! ICS 02: Data structures of the clients, shared by the hosts, the light
+//! clients and the handlers: heights, messages, events and errors.
+
+extern crate alloc;
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod consensus_state;
+pub mod error;
+pub mod events;
+pub mod genesis;
+pub mod height;
+pub mod msgs;
+pub mod params;
+pub mod status;
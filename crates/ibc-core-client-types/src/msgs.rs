@@ -0,0 +1,100 @@
+//! Defines the client message types that are sent to the chain by the relayer.
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::msgs::ValidateBasic;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+
+use crate::error::ClientError;
+use crate::msgs::create_client::MsgCreateClient;
+use crate::msgs::misbehaviour::MsgSubmitMisbehaviour;
+use crate::msgs::update_client::MsgUpdateClient;
+use crate::msgs::upgrade_client::MsgUpgradeClient;
+
+pub mod create_client;
+pub mod migrate_client_store;
+pub mod misbehaviour;
+pub mod update_client;
+pub mod upgrade_client;
+
+/// Encodes all the different client messages
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[allow(dead_code)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
+pub enum ClientMsg {
+    CreateClient(MsgCreateClient),
+    UpdateClient(MsgUpdateClient),
+    Misbehaviour(MsgSubmitMisbehaviour),
+    UpgradeClient(MsgUpgradeClient),
+}
+
+impl ValidateBasic for ClientMsg {
+    type Error = ClientError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        match self {
+            Self::CreateClient(msg) => msg.validate_basic(),
+            Self::UpdateClient(msg) => msg.validate_basic(),
+            Self::Misbehaviour(msg) => msg.validate_basic(),
+            Self::UpgradeClient(msg) => msg.validate_basic(),
+        }
+    }
+}
+
+pub enum MsgUpdateOrMisbehaviour {
+    UpdateClient(MsgUpdateClient),
+    Misbehaviour(MsgSubmitMisbehaviour),
+}
+
+impl MsgUpdateOrMisbehaviour {
+    pub fn client_id(&self) -> &ClientId {
+        match self {
+            MsgUpdateOrMisbehaviour::UpdateClient(msg) => &msg.client_id,
+            MsgUpdateOrMisbehaviour::Misbehaviour(msg) => &msg.client_id,
+        }
+    }
+
+    pub fn client_message(self) -> Any {
+        match self {
+            MsgUpdateOrMisbehaviour::UpdateClient(msg) => msg.client_message,
+            MsgUpdateOrMisbehaviour::Misbehaviour(msg) => msg.misbehaviour,
+        }
+    }
+}
+
+/// `UpdateKind` represents the 2 ways that a client can be updated
+/// in IBC: either through a `MsgUpdateClient`, or a `MsgSubmitMisbehaviour`.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UpdateKind {
+    /// this is the typical scenario where a new header is submitted to the client
+    /// to update the client. Note that light clients are free to define the type
+    /// of the object used to update them (e.g. could be a list of headers).
+    UpdateClient,
+    /// this is the scenario where misbehaviour is submitted to the client
+    /// (e.g 2 headers with the same height in Tendermint)
+    SubmitMisbehaviour,
+}
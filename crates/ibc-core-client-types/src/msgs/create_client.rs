@@ -1,25 +1,37 @@
 //! Definition of domain type message `MsgCreateClient`.
 
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::MsgCreateClient as RawMsgCreateClient;
 use ibc_proto::Protobuf;
 
-use crate::core::ics02_client::error::ClientError;
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
+use crate::error::ClientError;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.client.v1.MsgCreateClient";
+pub const TYPE_URL: &str = "/ibc.core.client.v1.MsgCreateClient";
 
 /// A type of message that triggers the creation of a new on-chain (IBC) client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgCreateClient {
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::utils::arbitrary::any))]
     pub client_state: Any,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::utils::arbitrary::any))]
     pub consensus_state: Any,
     pub signer: Signer,
 }
@@ -44,6 +56,14 @@ impl Msg for MsgCreateClient {
 
 impl Protobuf<RawMsgCreateClient> for MsgCreateClient {}
 
+impl ValidateBasic for MsgCreateClient {
+    type Error = ClientError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgCreateClient> for MsgCreateClient {
     type Error = ClientError;
 
@@ -72,6 +92,25 @@ impl From<MsgCreateClient> for RawMsgCreateClient {
     }
 }
 
+/// The outcome of executing a [`MsgCreateClient`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateClientResult {
+    /// The identifier generated for the new client.
+    pub client_id: ClientId,
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -79,7 +118,7 @@ mod tests {
     use ibc_testkit::utils::core::client::dummy_raw_msg_create_client;
     use test_log::test;
 
-    use crate::core::ics02_client::msgs::create_client::MsgCreateClient;
+    use crate::msgs::create_client::MsgCreateClient;
 
     #[test]
     fn msg_create_client_serialization() {
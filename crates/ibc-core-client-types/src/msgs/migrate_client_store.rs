@@ -0,0 +1,44 @@
+//! Definition of domain type message `MsgMigrateClientStore`.
+
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+
+/// An administrative message that migrates the stored states of a client to a
+/// newer version of their schema (see
+/// `MigrateClientStore`).
+///
+/// Unlike the messages submitted by relayers, it is not routed through
+/// `dispatch`: hosts handle it with the
+/// `migrate_client_store`
+/// handler, typically on behalf of their governance.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgMigrateClientStore {
+    pub client_id: ClientId,
+    pub target_version: u32,
+    pub signer: Signer,
+}
+
+impl MsgMigrateClientStore {
+    pub fn new(client_id: ClientId, target_version: u32, signer: Signer) -> Self {
+        MsgMigrateClientStore {
+            client_id,
+            target_version,
+            signer,
+        }
+    }
+}
@@ -1,28 +1,38 @@
 //! Definition of domain type message `MsgSubmitMisbehaviour`.
 
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::google::protobuf::Any as ProtoAny;
 use ibc_proto::ibc::core::client::v1::MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour;
 use ibc_proto::Protobuf;
 
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics24_host::identifier::ClientId;
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
+use crate::error::ClientError;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.client.v1.MsgSubmitMisbehaviour";
+pub const TYPE_URL: &str = "/ibc.core.client.v1.MsgSubmitMisbehaviour";
 
 /// A type of message that submits client misbehaviour proof.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgSubmitMisbehaviour {
     /// client unique identifier
     pub client_id: ClientId,
     /// misbehaviour used for freezing the light client
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::utils::arbitrary::any))]
     pub misbehaviour: ProtoAny,
     /// signer address
     pub signer: Signer,
@@ -38,6 +48,14 @@ impl Msg for MsgSubmitMisbehaviour {
 
 impl Protobuf<RawMsgSubmitMisbehaviour> for MsgSubmitMisbehaviour {}
 
+impl ValidateBasic for MsgSubmitMisbehaviour {
+    type Error = ClientError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgSubmitMisbehaviour> for MsgSubmitMisbehaviour {
     type Error = ClientError;
 
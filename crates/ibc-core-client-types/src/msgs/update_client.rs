@@ -1,29 +1,41 @@
 //! Definition of domain type message `MsgUpdateClient`.
 
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::MsgUpdateClient as RawMsgUpdateClient;
 use ibc_proto::Protobuf;
 
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics24_host::identifier::ClientId;
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
+use crate::error::ClientError;
+use crate::height::Height;
+use crate::msgs::UpdateKind;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.client.v1.MsgUpdateClient";
+pub const TYPE_URL: &str = "/ibc.core.client.v1.MsgUpdateClient";
 
 /// Represents the message that triggers the update of an on-chain (IBC) client
 /// either with new headers, or evidence of misbehaviour.
 /// Note that some types of misbehaviour can be detected when a headers
 /// are updated (`UpdateKind::UpdateClient`).
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgUpdateClient {
     pub client_id: ClientId,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::utils::arbitrary::any))]
     pub client_message: Any,
     pub signer: Signer,
 }
@@ -38,6 +50,14 @@ impl Msg for MsgUpdateClient {
 
 impl Protobuf<RawMsgUpdateClient> for MsgUpdateClient {}
 
+impl ValidateBasic for MsgUpdateClient {
+    type Error = ClientError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgUpdateClient> for MsgUpdateClient {
     type Error = ClientError;
 
@@ -65,6 +85,33 @@ impl From<MsgUpdateClient> for RawMsgUpdateClient {
     }
 }
 
+/// The outcome of executing a [`MsgUpdateClient`] or a
+/// [`MsgSubmitMisbehaviour`](crate::msgs::misbehaviour::MsgSubmitMisbehaviour).
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateClientResult {
+    /// Whether the client message was handled as a header or as evidence of
+    /// misbehaviour.
+    pub update_kind: UpdateKind,
+    /// Whether the client was frozen, which a header does when it conflicts
+    /// with the consensus states of the client.
+    pub frozen: bool,
+    /// The heights of the consensus states added to the client, empty if it
+    /// was frozen.
+    pub consensus_heights: Vec<Height>,
+}
+
 #[cfg(test)]
 mod tests {
     use ibc_proto::ibc::core::client::v1::MsgUpdateClient as RawMsgUpdateClient;
@@ -72,7 +119,7 @@ mod tests {
     use test_log::test;
 
     use super::*;
-    use crate::core::ics02_client::msgs::MsgUpdateClient;
+    use crate::msgs::MsgUpdateClient;
 
     #[test]
     fn msg_update_client_serialization() {
@@ -2,34 +2,45 @@
 
 use core::str::FromStr;
 
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_commitment_types::error::CommitmentError;
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::MsgUpgradeClient as RawMsgUpgradeClient;
 use ibc_proto::Protobuf;
 
-use crate::core::ics02_client::error::{ClientError, UpgradeClientError};
-use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
-use crate::core::ics23_commitment::error::CommitmentError;
-use crate::core::ics24_host::identifier::ClientId;
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
+use crate::error::{ClientError, UpgradeClientError};
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.client.v1.MsgUpgradeClient";
+pub const TYPE_URL: &str = "/ibc.core.client.v1.MsgUpgradeClient";
 
 /// A type of message that triggers the upgrade of an on-chain (IBC) client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgUpgradeClient {
     // client unique identifier
     pub client_id: ClientId,
     // Upgraded client state
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::utils::arbitrary::any))]
     pub upgraded_client_state: Any,
     // Upgraded consensus state, only contains enough information
     // to serve as a basis of trust in update logic
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::utils::arbitrary::any))]
     pub upgraded_consensus_state: Any,
     // proof that old chain committed to new client
     pub proof_upgrade_client: CommitmentProofBytes,
@@ -49,6 +60,14 @@ impl Msg for MsgUpgradeClient {
 
 impl Protobuf<RawMsgUpgradeClient> for MsgUpgradeClient {}
 
+impl ValidateBasic for MsgUpgradeClient {
+    type Error = ClientError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl From<MsgUpgradeClient> for RawMsgUpgradeClient {
     fn from(dm_msg: MsgUpgradeClient) -> RawMsgUpgradeClient {
         RawMsgUpgradeClient {
@@ -102,7 +121,7 @@ mod tests {
     use ibc_proto::ibc::core::client::v1::MsgUpgradeClient as RawMsgUpgradeClient;
     use ibc_testkit::utils::core::client::dummy_raw_msg_upgrade_client;
 
-    use crate::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
+    use crate::msgs::upgrade_client::MsgUpgradeClient;
 
     #[test]
     fn msg_upgrade_client_serialization() {
@@ -0,0 +1,150 @@
+//! Defines the parameters of the client module.
+
+use ibc_core_host_types::client_type::ClientType;
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Params as RawParams;
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+
+/// The wildcard entry of [`ClientParams::allowed_clients`] allowing clients
+/// of any type.
+pub const ALLOW_ALL_CLIENTS: &str = "*";
+
+/// Parameters of the client module, set at genesis and updatable by the host
+/// afterwards.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientParams {
+    /// The types of the clients that may be created on the host. A single
+    /// [`ALLOW_ALL_CLIENTS`] entry allows any type, and an empty list none.
+    pub allowed_clients: Vec<String>,
+}
+
+impl ClientParams {
+    pub fn new(allowed_clients: Vec<String>) -> Result<Self, ClientError> {
+        let params = Self { allowed_clients };
+        params.validate_basic()?;
+        Ok(params)
+    }
+
+    /// Checks that the allowed clients are neither blank nor duplicated, and
+    /// that the [`ALLOW_ALL_CLIENTS`] wildcard, if present, is the only one.
+    pub fn validate_basic(&self) -> Result<(), ClientError> {
+        if self.allowed_clients.len() > 1
+            && self
+                .allowed_clients
+                .iter()
+                .any(|client| client == ALLOW_ALL_CLIENTS)
+        {
+            return Err(ClientError::InvalidClientParams {
+                reason: format!("wildcard `{ALLOW_ALL_CLIENTS}` must be the only allowed client"),
+            });
+        }
+
+        for (i, client) in self.allowed_clients.iter().enumerate() {
+            if client.trim().is_empty() {
+                return Err(ClientError::InvalidClientParams {
+                    reason: format!("allowed client {i} is blank"),
+                });
+            }
+            if self.allowed_clients[..i].contains(client) {
+                return Err(ClientError::InvalidClientParams {
+                    reason: format!("allowed client `{client}` is duplicated"),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether clients of the given type may be created.
+    pub fn is_allowed_client(&self, client_type: &ClientType) -> bool {
+        self.allowed_clients
+            .iter()
+            .any(|client| client == ALLOW_ALL_CLIENTS || client == client_type.as_str())
+    }
+}
+
+/// The parameters of a chain at genesis, allowing clients of any type.
+impl Default for ClientParams {
+    fn default() -> Self {
+        Self {
+            allowed_clients: vec![ALLOW_ALL_CLIENTS.to_string()],
+        }
+    }
+}
+
+impl Protobuf<RawParams> for ClientParams {}
+
+impl TryFrom<RawParams> for ClientParams {
+    type Error = ClientError;
+
+    fn try_from(raw: RawParams) -> Result<Self, Self::Error> {
+        Self::new(raw.allowed_clients)
+    }
+}
+
+impl From<ClientParams> for RawParams {
+    fn from(params: ClientParams) -> Self {
+        Self {
+            allowed_clients: params.allowed_clients,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn params(allowed_clients: &[&str]) -> Result<ClientParams, ClientError> {
+        ClientParams::new(allowed_clients.iter().map(ToString::to_string).collect())
+    }
+
+    #[rstest]
+    #[case::wildcard(&["*"], true)]
+    #[case::listed(&["06-solomachine", "07-tendermint"], true)]
+    #[case::unlisted(&["06-solomachine"], false)]
+    #[case::empty(&[], false)]
+    fn test_is_allowed_client(#[case] allowed_clients: &[&str], #[case] allowed: bool) {
+        let client_type = ClientType::new("07-tendermint").unwrap();
+        assert_eq!(
+            params(allowed_clients)
+                .unwrap()
+                .is_allowed_client(&client_type),
+            allowed
+        );
+    }
+
+    #[rstest]
+    #[case::wildcard_with_others(&["*", "07-tendermint"])]
+    #[case::blank(&["07-tendermint", " "])]
+    #[case::duplicated(&["07-tendermint", "07-tendermint"])]
+    fn test_invalid_params(#[case] allowed_clients: &[&str]) {
+        assert!(matches!(
+            params(allowed_clients),
+            Err(ClientError::InvalidClientParams { .. })
+        ));
+    }
+
+    #[test]
+    fn test_default_allows_all_clients() {
+        let params = ClientParams::default();
+        params.validate_basic().unwrap();
+        assert_eq!(params.allowed_clients, vec![ALLOW_ALL_CLIENTS.to_string()]);
+    }
+}
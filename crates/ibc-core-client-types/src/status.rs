@@ -0,0 +1,85 @@
+//! Defines the status of clients
+
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use ibc_primitives::prelude::*;
+
+use crate::error::ClientError;
+
+/// Represents the status of a client
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The client is active and allowed to be used
+    Active,
+    /// The client is frozen and not allowed to be used
+    Frozen,
+    /// The client is expired and not allowed to be used
+    Expired,
+    /// Unauthorized indicates that the client type is not registered as an allowed client type.
+    Unauthorized,
+}
+
+impl Status {
+    pub fn is_active(&self) -> bool {
+        *self == Status::Active
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        *self == Status::Frozen
+    }
+
+    pub fn is_expired(&self) -> bool {
+        *self == Status::Expired
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        *self == Status::Unauthorized
+    }
+
+    /// Yields the status as the string of the gRPC `ClientStatus` response.
+    pub fn as_str(&self) -> &'static str {
+        // Note: taken from [ibc-go](https://github.com/cosmos/ibc-go/blob/v7.3.0/modules/core/exported/client.go#L29-L40)
+        match self {
+            Self::Active => "Active",
+            Self::Frozen => "Frozen",
+            Self::Expired => "Expired",
+            Self::Unauthorized => "Unauthorized",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Status {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Active" => Ok(Self::Active),
+            "Frozen" => Ok(Self::Frozen),
+            "Expired" => Ok(Self::Expired),
+            "Unauthorized" => Ok(Self::Unauthorized),
+            _ => Err(ClientError::UnknownClientStatus {
+                status: s.to_string(),
+            }),
+        }
+    }
+}
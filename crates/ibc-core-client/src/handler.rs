@@ -1,5 +1,6 @@
 //! This module implements the processing logic for ICS2 (client abstractions and functions) msgs.
 
 pub mod create_client;
+pub mod migrate_client_store;
 pub mod update_client;
 pub mod upgrade_client;
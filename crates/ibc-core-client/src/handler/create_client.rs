@@ -0,0 +1,108 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgCreateClient`.
+
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateExecution};
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::events::CreateClient;
+use ibc_core_client_types::msgs::create_client::{CreateClientResult, MsgCreateClient};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgCreateClient) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let MsgCreateClient {
+        client_state,
+        consensus_state,
+        signer: _,
+    } = msg;
+
+    // Construct this client's identifier
+    let id_counter = ctx.client_counter()?;
+
+    let decoded_client_state = ctx.decode_client_state(client_state.clone())?;
+
+    let client_type = decoded_client_state.client_type();
+
+    if !ctx.client_params()?.is_allowed_client(&client_type) {
+        return Err(ClientError::ClientTypeNotAllowed { client_type }.into());
+    }
+
+    decoded_client_state.verify_consensus_state(consensus_state.clone())?;
+
+    // Only well-formed clients are checked against the existing ones, so that
+    // malformed ones fail with the error of their decoding
+    if let Some(client_id) = ctx.find_duplicate_client(&client_state, &consensus_state)? {
+        return Err(ClientError::DuplicateClient { client_id }.into());
+    }
+
+    let client_state = decoded_client_state;
+
+    let client_id = ctx
+        .identifier_allocator()
+        .allocate_client_id(client_type, id_counter)
+        .map_err(|e| ClientError::ClientIdentifierConstructor {
+            client_type: client_state.client_type(),
+            counter: id_counter,
+            validation_error: e,
+        })?;
+
+    if ctx.client_state(&client_id).is_ok() {
+        return Err(ClientError::ClientStateAlreadyExists { client_id }.into());
+    };
+
+    Ok(())
+}
+
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgCreateClient) -> Result<CreateClientResult, ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let MsgCreateClient {
+        client_state,
+        consensus_state,
+        signer: _,
+    } = msg;
+
+    // Construct this client's identifier
+    let id_counter = ctx.client_counter()?;
+
+    let client_state = ctx.decode_client_state(client_state)?;
+
+    let client_type = client_state.client_type();
+
+    let client_id = ctx
+        .identifier_allocator()
+        .allocate_client_id(client_type.clone(), id_counter)
+        .map_err(|e| {
+            ContextError::from(ClientError::ClientIdentifierConstructor {
+                client_type: client_type.clone(),
+                counter: id_counter,
+                validation_error: e,
+            })
+        })?;
+
+    client_state.initialise(
+        ctx.get_client_execution_context(),
+        &client_id,
+        consensus_state,
+    )?;
+
+    ctx.increase_client_counter()?;
+
+    let event = IbcEvent::CreateClient(CreateClient::new(
+        client_id.clone(),
+        client_type,
+        client_state.latest_height(),
+    ));
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+    ctx.emit_ibc_event(event)?;
+
+    ctx.log_message(format!(
+        "success: generated new client identifier: {client_id}"
+    ))?;
+
+    Ok(CreateClientResult { client_id })
+}
@@ -0,0 +1,62 @@
+//! Protocol logic specific to processing the administrative messages of type
+//! `MsgMigrateClientStore`.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::msgs::migrate_client_store::MsgMigrateClientStore;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+use crate::migration::MigrateClientStore;
+
+/// Checks that the signer of `msg` may migrate client stores, that the client
+/// exists, and that its store can be migrated to the target version, which
+/// must lie between its stored version and the latest one of the host.
+pub fn validate<Ctx>(ctx: &Ctx, msg: &MsgMigrateClientStore) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext + MigrateClientStore,
+{
+    ctx.validate_migration_authority(&msg.signer)?;
+
+    ctx.client_state(&msg.client_id)?;
+
+    let stored = ctx.client_store_version(&msg.client_id)?;
+    let latest = ctx.latest_client_store_version();
+
+    if msg.target_version < stored || msg.target_version > latest {
+        return Err(ClientError::InvalidStoreVersion {
+            client_id: msg.client_id.clone(),
+            stored,
+            target: msg.target_version,
+            latest,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Migrates the store of the client one version at a time, up to the target
+/// version of `msg`.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgMigrateClientStore) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext + MigrateClientStore,
+{
+    let MsgMigrateClientStore {
+        client_id,
+        target_version,
+        signer: _,
+    } = msg;
+
+    let stored = ctx.client_store_version(&client_id)?;
+
+    for version in stored..target_version {
+        ctx.migrate_client_store(&client_id, version)?;
+    }
+
+    ctx.log_message(format!(
+        "success: migrated the store of client {client_id} from version {stored} to {target_version}"
+    ))?;
+
+    Ok(())
+}
@@ -0,0 +1,175 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgUpdateAnyClient`.
+
+use ibc_core_client_context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc_core_client_context::context::ClientExecutionContext;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::events::{
+    ClientMisbehaviour, ClientNearExpiry, ConflictingHeader, UpdateClient,
+};
+use ibc_core_client_types::msgs::update_client::UpdateClientResult;
+use ibc_core_client_types::msgs::{MsgUpdateOrMisbehaviour, UpdateKind};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+use prost::Message;
+
+/// Returns whether `msg` holds a header or evidence of misbehaviour, as
+/// decoded by the `client_state` of the client to update.
+fn update_kind<CS>(msg: &MsgUpdateOrMisbehaviour, client_state: &CS) -> UpdateKind
+where
+    CS: ClientStateCommon,
+{
+    match msg {
+        MsgUpdateOrMisbehaviour::UpdateClient(msg) => client_state.update_kind(&msg.client_message),
+        MsgUpdateOrMisbehaviour::Misbehaviour(_) => UpdateKind::SubmitMisbehaviour,
+    }
+}
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgUpdateOrMisbehaviour) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let client_id = msg.client_id().clone();
+
+    // Read client state from the host chain store. The client should already exist.
+    let client_state = ctx.client_state(&client_id)?;
+    let update_kind = update_kind(&msg, &client_state);
+
+    {
+        let status = client_state.status(ctx.get_client_validation_context(), &client_id)?;
+        if !status.is_active() {
+            return Err(ClientError::ClientNotActive { status }.into());
+        }
+    }
+
+    let client_message = msg.client_message();
+
+    client_state.verify_client_message(
+        ctx.get_client_validation_context(),
+        &client_id,
+        client_message,
+        &update_kind,
+    )?;
+
+    Ok(())
+}
+
+pub fn execute<Ctx>(
+    ctx: &mut Ctx,
+    msg: MsgUpdateOrMisbehaviour,
+) -> Result<UpdateClientResult, ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let client_id = msg.client_id().clone();
+    let client_state = ctx.client_state(&client_id)?;
+    let update_kind = update_kind(&msg, &client_state);
+    let client_message = msg.client_message();
+
+    let found_misbehaviour = client_state.check_for_misbehaviour(
+        ctx.get_client_validation_context(),
+        &client_id,
+        client_message.clone(),
+        &update_kind,
+    )?;
+
+    if found_misbehaviour {
+        let misbehaviour = ClientMisbehaviour::new(
+            client_id.clone(),
+            client_state.client_type(),
+            client_message.type_url.clone(),
+            client_state.evidence_heights(&client_message),
+        );
+
+        // A header found to be misbehaviour conflicts with the consensus
+        // states of the client.
+        let conflicting_header = matches!(update_kind, UpdateKind::UpdateClient).then(|| {
+            ConflictingHeader::new(
+                client_id.clone(),
+                client_state.client_type(),
+                client_state.evidence_heights(&client_message),
+                client_message.encode_to_vec(),
+            )
+        });
+
+        client_state.update_state_on_misbehaviour(
+            ctx.get_client_execution_context(),
+            &client_id,
+            client_message,
+            &update_kind,
+        )?;
+        ctx.get_client_execution_context()
+            .on_client_frozen(&misbehaviour)?;
+
+        ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+        ctx.emit_ibc_event(IbcEvent::ClientMisbehaviour(misbehaviour))?;
+        if let Some(conflicting_header) = conflicting_header {
+            ctx.emit_ibc_event(IbcEvent::ConflictingHeader(conflicting_header))?;
+        }
+
+        Ok(UpdateClientResult {
+            update_kind,
+            frozen: true,
+            consensus_heights: Vec::new(),
+        })
+    } else {
+        if !matches!(update_kind, UpdateKind::UpdateClient) {
+            return Err(ClientError::MisbehaviourHandlingFailure {
+                reason: "misbehaviour submitted, but none found".to_string(),
+            }
+            .into());
+        }
+
+        let header = client_message;
+
+        let consensus_heights = client_state.update_state(
+            ctx.get_client_execution_context(),
+            &client_id,
+            header.clone(),
+        )?;
+
+        {
+            let event = {
+                let consensus_height = consensus_heights.first().ok_or(ClientError::Other {
+                    description: "client update state returned no updated height".to_string(),
+                })?;
+
+                IbcEvent::UpdateClient(UpdateClient::new(
+                    client_id.clone(),
+                    client_state.client_type(),
+                    *consensus_height,
+                    consensus_heights.clone(),
+                    header.encode_to_vec(),
+                ))
+            };
+            ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+            ctx.emit_ibc_event(event)?;
+        }
+
+        if let Some(margin) = ctx.client_expiry_warning_margin() {
+            let updated_client_state = ctx.client_state(&client_id)?;
+            let remaining_trusting_period = updated_client_state
+                .remaining_trusting_period(ctx.get_client_validation_context(), &client_id)?;
+
+            if let Some(remaining_trusting_period) =
+                remaining_trusting_period.filter(|remaining| *remaining <= margin)
+            {
+                ctx.emit_ibc_event(IbcEvent::ClientNearExpiry(ClientNearExpiry::new(
+                    client_id,
+                    updated_client_state.client_type(),
+                    updated_client_state.latest_height(),
+                    remaining_trusting_period,
+                )))?;
+            }
+        }
+
+        Ok(UpdateClientResult {
+            update_kind,
+            frozen: false,
+            consensus_heights,
+        })
+    }
+}
@@ -1,27 +1,23 @@
 //! Protocol logic specific to processing ICS2 messages of type `MsgUpgradeAnyClient`.
 //!
-use crate::core::context::ContextError;
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::{
+use ibc_core_client_context::client_state::{
     ClientStateCommon, ClientStateExecution, ClientStateValidation,
 };
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics02_client::events::UpgradeClient;
-use crate::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
-use crate::core::ics24_host::path::ClientConsensusStatePath;
-use crate::core::{ExecutionContext, ValidationContext};
-use crate::prelude::*;
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::events::UpgradeClient;
+use ibc_core_client_types::msgs::upgrade_client::MsgUpgradeClient;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::ClientConsensusStatePath;
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
 
-pub(crate) fn validate<Ctx>(ctx: &Ctx, msg: MsgUpgradeClient) -> Result<(), ContextError>
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgUpgradeClient) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
-    let MsgUpgradeClient {
-        client_id, signer, ..
-    } = msg;
-
-    ctx.validate_message_signer(&signer)?;
+    let MsgUpgradeClient { client_id, .. } = msg;
 
     // Read the current latest client state from the host chain store.
     let old_client_state = ctx.client_state(&client_id)?;
@@ -48,15 +44,16 @@ where
     old_client_state.verify_upgrade_client(
         msg.upgraded_client_state.clone(),
         msg.upgraded_consensus_state,
-        msg.proof_upgrade_client,
-        msg.proof_upgrade_consensus_state,
+        ctx.resolve_proof(&msg.proof_upgrade_client)?.into_owned(),
+        ctx.resolve_proof(&msg.proof_upgrade_consensus_state)?
+            .into_owned(),
         old_consensus_state.root(),
     )?;
 
     Ok(())
 }
 
-pub(crate) fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpgradeClient) -> Result<(), ContextError>
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpgradeClient) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
 {
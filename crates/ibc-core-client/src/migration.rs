@@ -0,0 +1,35 @@
+//! Defines the versioning of the client and consensus states stored by hosts,
+//! and the migration of the stores of clients from one version to the next.
+//!
+//! The states stored under the ICS-24 paths are proven to counterparties, so
+//! they keep the encodings counterparties expect: hosts record the version of
+//! the store of each client apart from them.
+
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::signer::Signer;
+
+/// The version of the stores of the clients that were never migrated.
+pub const UNVERSIONED_STORE_VERSION: u32 = 0;
+
+/// Context to be implemented by the hosts that version the stores of their
+/// clients, through which
+/// [`migrate_client_store`](crate::handler::migrate_client_store)
+/// brings the store of a client up to date after an upgrade of the crate.
+pub trait MigrateClientStore {
+    /// Returns the version of the schema with which the host currently
+    /// stores client and consensus states.
+    fn latest_client_store_version(&self) -> u32;
+
+    /// Returns the version of the schema of the stored states of `client_id`.
+    fn client_store_version(&self, client_id: &ClientId) -> Result<u32, ContextError>;
+
+    /// Rewrites the stored client and consensus states of `client_id` from
+    /// version `from` into version `from + 1`, and records the new version.
+    fn migrate_client_store(&mut self, client_id: &ClientId, from: u32)
+        -> Result<(), ContextError>;
+
+    /// Checks that `signer` is allowed to migrate the stores of clients, e.g.
+    /// that it is the governance authority of the host.
+    fn validate_migration_authority(&self, signer: &Signer) -> Result<(), ContextError>;
+}
@@ -0,0 +1,403 @@
+//! Defines core commitment types
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use subtle_encoding::{Encoding, Hex};
+
+use super::merkle::MerkleProof;
+use crate::error::CommitmentError;
+
+/// Encodes a commitment root; most often a Merkle tree root hash.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct CommitmentRoot {
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "ibc_primitives::serializers::ser_hex_upper")
+    )]
+    bytes: Vec<u8>,
+}
+
+impl fmt::Debug for CommitmentRoot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = Hex::upper_case()
+            .encode_to_string(&self.bytes)
+            .map_err(|_| fmt::Error)?;
+        f.debug_tuple("CommitmentRoot").field(&hex).finish()
+    }
+}
+
+impl CommitmentRoot {
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            bytes: Vec::from(bytes),
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+impl From<Vec<u8>> for CommitmentRoot {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+}
+
+/// Demonstrates membership or non-membership for an element or set of elements,
+/// verifiable in conjunction with a known commitment root.
+///
+/// For example, in the case of a proof of membership in a Merkle tree,
+/// this encodes a Merkle proof.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct CommitmentProofBytes {
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "ibc_primitives::serializers::ser_hex_upper")
+    )]
+    bytes: Vec<u8>,
+}
+
+impl fmt::Debug for CommitmentProofBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = Hex::upper_case()
+            .encode_to_string(&self.bytes)
+            .map_err(|_| fmt::Error)?;
+        f.debug_tuple("CommitmentProof").field(&hex).finish()
+    }
+}
+
+impl TryFrom<Vec<u8>> for CommitmentProofBytes {
+    type Error = CommitmentError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        if bytes.is_empty() {
+            Err(Self::Error::EmptyMerkleProof)
+        } else {
+            Ok(Self { bytes })
+        }
+    }
+}
+
+impl AsRef<[u8]> for CommitmentProofBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl From<CommitmentProofBytes> for Vec<u8> {
+    fn from(p: CommitmentProofBytes) -> Vec<u8> {
+        p.bytes
+    }
+}
+
+impl TryFrom<RawMerkleProof> for CommitmentProofBytes {
+    type Error = CommitmentError;
+
+    fn try_from(proof: RawMerkleProof) -> Result<Self, Self::Error> {
+        let mut buf = Vec::new();
+        prost::Message::encode(&proof, &mut buf)
+            .map_err(|e| Self::Error::EncodingFailure(e.to_string()))?;
+        buf.try_into()
+    }
+}
+
+impl TryFrom<MerkleProof> for CommitmentProofBytes {
+    type Error = CommitmentError;
+
+    fn try_from(value: MerkleProof) -> Result<Self, Self::Error> {
+        Self::try_from(RawMerkleProof::from(value))
+    }
+}
+
+impl TryFrom<CommitmentProofBytes> for RawMerkleProof {
+    type Error = CommitmentError;
+
+    fn try_from(value: CommitmentProofBytes) -> Result<Self, Self::Error> {
+        let value: Vec<u8> = value.into();
+        let res: RawMerkleProof = prost::Message::decode(value.as_ref())
+            .map_err(CommitmentError::InvalidRawMerkleProof)?;
+        Ok(res)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CommitmentProofBytes {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            bytes: ibc_primitives::utils::arbitrary::non_empty_bytes(u)?,
+        })
+    }
+}
+
+/// The SHA-256 hash of a proof delivered out of band, by which messages refer
+/// to it on hosts with a [`ProofResolver`].
+///
+/// A reference to a proof is carried in place of the proof, as the proof
+/// bytes made of the byte [`ProofHash::REF_TAG`] followed by the hash: as
+/// protobuf reserves the field number 0, no encoded proof starts with this
+/// byte. Hosts without a resolver never decode references, and handle them as
+/// any other proof bytes.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProofHash([u8; 32]);
+
+impl ProofHash {
+    /// The first byte of the proof bytes referring to a proof by hash.
+    pub const REF_TAG: u8 = 0x00;
+
+    pub const fn new(hash: [u8; 32]) -> Self {
+        Self(hash)
+    }
+
+    /// Returns the hash of the given proof bytes.
+    pub fn of(proof: &[u8]) -> Self {
+        use sha2::Digest;
+
+        Self(sha2::Sha256::digest(proof).into())
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Returns the hash `proof` refers to, or `None` if `proof` is not a
+    /// reference.
+    pub fn from_proof_ref(proof: &CommitmentProofBytes) -> Result<Option<Self>, CommitmentError> {
+        match proof.as_ref().split_first() {
+            Some((&Self::REF_TAG, hash)) => hash
+                .try_into()
+                .map(|hash| Some(Self(hash)))
+                .map_err(|_| CommitmentError::InvalidProofReference { length: hash.len() }),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns the proof of this hash, as resolved by `resolver` and checked
+    /// against the hash.
+    pub fn resolve(
+        &self,
+        resolver: &dyn ProofResolver,
+    ) -> Result<CommitmentProofBytes, CommitmentError> {
+        let bytes = resolver.fetch_proof(self)?;
+
+        let resolved_hash = Self::of(&bytes);
+        if resolved_hash != *self {
+            return Err(CommitmentError::ProofHashMismatch {
+                expected: *self,
+                actual: resolved_hash,
+            });
+        }
+
+        bytes.try_into()
+    }
+}
+
+impl fmt::Display for ProofHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = Hex::upper_case()
+            .encode_to_string(self.0)
+            .map_err(|_| fmt::Error)?;
+        f.write_str(&hex)
+    }
+}
+
+impl fmt::Debug for ProofHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ProofHash")
+            .field(&format!("{self}"))
+            .finish()
+    }
+}
+
+/// Encodes a reference to the proof of this hash, to be carried in place of
+/// the proof.
+impl From<ProofHash> for CommitmentProofBytes {
+    fn from(hash: ProofHash) -> Self {
+        let mut bytes = Vec::with_capacity(1 + hash.0.len());
+        bytes.push(ProofHash::REF_TAG);
+        bytes.extend_from_slice(&hash.0);
+        Self { bytes }
+    }
+}
+
+/// Resolves the proofs messages refer to by hash, which the host receives out
+/// of band.
+///
+/// Resolved proofs are checked against their hash, so resolvers need not be
+/// trusted to return the right proof.
+pub trait ProofResolver {
+    /// Returns the bytes of the proof of hash `hash`, or an error if the host
+    /// did not receive it.
+    fn fetch_proof(&self, hash: &ProofHash) -> Result<Vec<u8>, CommitmentError>;
+}
+
+/// Defines a store prefix of the commitment proof.
+///
+/// See [spec](https://github.com/cosmos/ibc/blob/main/spec/core/ics-023-vector-commitments/README.md#prefix).
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct CommitmentPrefix {
+    bytes: Vec<u8>,
+}
+
+impl CommitmentPrefix {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl TryFrom<Vec<u8>> for CommitmentPrefix {
+    type Error = CommitmentError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        if bytes.is_empty() {
+            Err(Self::Error::EmptyCommitmentPrefix)
+        } else {
+            Ok(Self { bytes })
+        }
+    }
+}
+
+impl fmt::Debug for CommitmentPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let converted = core::str::from_utf8(self.as_bytes());
+        match converted {
+            Ok(s) => write!(f, "{s}"),
+            Err(_e) => write!(f, "<not valid UTF8: {:?}>", self.as_bytes()),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CommitmentPrefix {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            bytes: ibc_primitives::utils::arbitrary::non_empty_bytes(u)?,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CommitmentPrefix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        format!("{self:?}").serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StaticResolver(Vec<u8>);
+
+    impl ProofResolver for StaticResolver {
+        fn fetch_proof(&self, _hash: &ProofHash) -> Result<Vec<u8>, CommitmentError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn proof_refs_are_plain_proof_bytes() {
+        let hash = ProofHash::of(b"proof");
+
+        let proof_ref = CommitmentProofBytes::from(hash);
+        assert_eq!(proof_ref.as_ref().len(), 33);
+        assert_eq!(proof_ref.as_ref()[0], ProofHash::REF_TAG);
+        assert_eq!(
+            CommitmentProofBytes::try_from(Vec::<u8>::from(proof_ref.clone())).unwrap(),
+            proof_ref
+        );
+        assert_eq!(ProofHash::from_proof_ref(&proof_ref).unwrap(), Some(hash));
+
+        let proof = CommitmentProofBytes::try_from(b"proof".to_vec()).unwrap();
+        assert_eq!(ProofHash::from_proof_ref(&proof).unwrap(), None);
+
+        let invalid_ref = CommitmentProofBytes::try_from(vec![ProofHash::REF_TAG; 5]).unwrap();
+        assert_eq!(invalid_ref.as_ref(), [ProofHash::REF_TAG; 5]);
+        assert!(matches!(
+            ProofHash::from_proof_ref(&invalid_ref),
+            Err(CommitmentError::InvalidProofReference { length: 4 })
+        ));
+    }
+
+    #[test]
+    fn proof_refs_are_resolved_against_their_hash() {
+        let hash = ProofHash::of(b"proof");
+
+        let resolved = hash.resolve(&StaticResolver(b"proof".to_vec())).unwrap();
+        assert_eq!(resolved.as_ref(), b"proof");
+
+        assert!(matches!(
+            hash.resolve(&StaticResolver(b"forged".to_vec())),
+            Err(CommitmentError::ProofHashMismatch { .. })
+        ));
+    }
+}
@@ -3,8 +3,11 @@
 use alloc::string::String;
 
 use displaydoc::Display;
+use ibc_primitives::impl_error_sources;
 use prost::DecodeError;
 
+use crate::commitment::ProofHash;
+
 #[derive(Debug, Display)]
 pub enum CommitmentError {
     /// invalid raw merkle proof error: `{0}`
@@ -29,15 +32,27 @@ pub enum CommitmentError {
     VerificationFailure,
     /// encoded commitment prefix is not a valid hex string: `{0}`
     EncodingFailure(String),
+    /// proof of `{size}` bytes exceeds the maximum of `{max}` bytes
+    ProofTooLarge { size: usize, max: usize },
+    /// proof with `{ops}` operations exceeds the maximum of `{max}`
+    TooManyProofOps { ops: usize, max: usize },
+    /// compressed proof refers to inner operation `{index}` out of `{len}`
+    InvalidCompressedProofIndex { index: i32, len: usize },
+    /// proof reference of `{length}` bytes is not a 32 bytes hash
+    InvalidProofReference { length: usize },
+    /// proof of hash `{hash}` is not resolved
+    UnresolvedProof { hash: ProofHash },
+    /// resolved proof has hash `{actual}` instead of `{expected}`
+    ProofHashMismatch {
+        expected: ProofHash,
+        actual: ProofHash,
+    },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for CommitmentError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self {
-            Self::InvalidRawMerkleProof(e) => Some(e),
-            Self::CommitmentProofDecodingFailed(e) => Some(e),
-            _ => None,
-        }
+impl_error_sources!(CommitmentError {
+    ibc {}
+    std {
+        Self::InvalidRawMerkleProof(e) => e,
+        Self::CommitmentProofDecodingFailed(e) => e,
     }
-}
+});
@@ -0,0 +1,469 @@
+//! Merkle proof utilities
+
+use alloc::borrow::Cow;
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::commitment::v1::{MerklePath, MerkleProof as RawMerkleProof, MerkleRoot};
+use ibc_proto::ics23::commitment_proof::Proof;
+use ibc_proto::ics23::{
+    batch_entry, calculate_existence_root, compressed_batch_entry, verify_membership,
+    verify_non_membership, BatchEntry, BatchProof, CommitmentProof, CompressedBatchProof,
+    CompressedExistenceProof, ExistenceProof, InnerOp, NonExistenceProof,
+};
+use prost::Message;
+
+use crate::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::error::CommitmentError;
+use crate::specs::ProofSpecs;
+
+pub fn apply_prefix(prefix: &CommitmentPrefix, mut path: Vec<String>) -> MerklePath {
+    let mut key_path: Vec<String> = vec![format!("{prefix:?}")];
+    key_path.append(&mut path);
+    MerklePath { key_path }
+}
+
+impl From<CommitmentRoot> for MerkleRoot {
+    fn from(root: CommitmentRoot) -> Self {
+        Self {
+            hash: root.into_vec(),
+        }
+    }
+}
+
+/// Bounds on the proofs accepted from relayers, so that a maliciously large
+/// proof is rejected before it is decoded or decompressed rather than
+/// exhausting the memory of the host.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProofLimits {
+    /// The maximum size of a proof in bytes, both as received and once
+    /// decompressed.
+    pub max_proof_size: usize,
+    /// The maximum number of leaf and inner operations in a proof, across all
+    /// of its layers and batch entries.
+    pub max_ops: usize,
+}
+
+impl ProofLimits {
+    /// The default maximum proof size, well above that of the proofs of a
+    /// Cosmos SDK chain.
+    pub const DEFAULT_MAX_PROOF_SIZE: usize = 128 * 1024;
+    /// The default maximum number of operations of a proof.
+    pub const DEFAULT_MAX_OPS: usize = 1024;
+}
+
+impl Default for ProofLimits {
+    fn default() -> Self {
+        Self {
+            max_proof_size: Self::DEFAULT_MAX_PROOF_SIZE,
+            max_ops: Self::DEFAULT_MAX_OPS,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MerkleProof {
+    pub proofs: Vec<CommitmentProof>,
+}
+
+/// Convert to ics23::CommitmentProof
+/// The encoding and decoding shouldn't fail since ics23::CommitmentProof and ibc_proto::ics23::CommitmentProof should be the same
+/// Ref. <https://github.com/informalsystems/ibc-rs/issues/853>
+impl From<RawMerkleProof> for MerkleProof {
+    fn from(proof: RawMerkleProof) -> Self {
+        Self {
+            proofs: proof.proofs,
+        }
+    }
+}
+
+impl From<MerkleProof> for RawMerkleProof {
+    fn from(proof: MerkleProof) -> Self {
+        Self {
+            proofs: proof.proofs,
+        }
+    }
+}
+
+impl MerkleProof {
+    /// Decodes `proof` within `limits`, decompressing any compressed batch
+    /// proof among its layers.
+    ///
+    /// The size of `proof` is checked before decoding, and that of each
+    /// compressed layer before decompressing it.
+    pub fn decode(
+        proof: &CommitmentProofBytes,
+        limits: &ProofLimits,
+    ) -> Result<Self, CommitmentError> {
+        let size = proof.as_ref().len();
+        if size > limits.max_proof_size {
+            return Err(CommitmentError::ProofTooLarge {
+                size,
+                max: limits.max_proof_size,
+            });
+        }
+
+        let raw_proof = RawMerkleProof::decode(proof.as_ref())
+            .map_err(CommitmentError::InvalidRawMerkleProof)?;
+
+        let proofs = raw_proof
+            .proofs
+            .iter()
+            .map(|proof| decompress(proof, limits).map(Cow::into_owned))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let ops: usize = proofs.iter().map(proof_ops).sum();
+        if ops > limits.max_ops {
+            return Err(CommitmentError::TooManyProofOps {
+                ops,
+                max: limits.max_ops,
+            });
+        }
+
+        Ok(Self { proofs })
+    }
+
+    /// Returns the value this proof claims to be stored under its key, i.e.
+    /// that of the existence proof of its innermost layer, if any.
+    ///
+    /// The value is NOT verified against any root: it may only be used to
+    /// explain why verifying the proof failed.
+    pub fn claimed_value(&self) -> Option<&[u8]> {
+        match self.proofs.first()?.proof.as_ref()? {
+            Proof::Exist(existence_proof) => Some(&existence_proof.value),
+            _ => None,
+        }
+    }
+
+    pub fn verify_membership(
+        &self,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        keys: MerklePath,
+        value: Vec<u8>,
+        start_index: u64,
+    ) -> Result<(), CommitmentError> {
+        // validate arguments
+        if self.proofs.is_empty() {
+            return Err(CommitmentError::EmptyMerkleProof);
+        }
+        if root.hash.is_empty() {
+            return Err(CommitmentError::EmptyMerkleRoot);
+        }
+        let num = self.proofs.len();
+        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
+        if ics23_specs.len() != num {
+            return Err(CommitmentError::NumberOfSpecsMismatch);
+        }
+        if keys.key_path.len() != num {
+            return Err(CommitmentError::NumberOfKeysMismatch);
+        }
+        if value.is_empty() {
+            return Err(CommitmentError::EmptyVerifiedValue);
+        }
+
+        let mut subroot = value.clone();
+        let mut value = value;
+        // keys are represented from root-to-leaf
+        for ((proof, spec), key) in self
+            .proofs
+            .iter()
+            .zip(ics23_specs.iter())
+            .zip(keys.key_path.iter().rev())
+            .skip(
+                start_index
+                    .try_into()
+                    .expect("safe because if u64 is more than usize it will skip all anyway"),
+            )
+        {
+            let existence_proof = existence_proof_for(proof, key.as_bytes())
+                .ok_or(CommitmentError::InvalidMerkleProof)?;
+            subroot = calculate_existence_root::<ics23::HostFunctionsManager>(existence_proof)
+                .map_err(|_| CommitmentError::InvalidMerkleProof)?;
+
+            let proof = CommitmentProof {
+                proof: Some(Proof::Exist(existence_proof.clone())),
+            };
+            if !verify_membership::<ics23::HostFunctionsManager>(
+                &proof,
+                spec,
+                &subroot,
+                key.as_bytes(),
+                &value,
+            ) {
+                return Err(CommitmentError::VerificationFailure);
+            }
+            value = subroot.clone();
+        }
+
+        if root.hash != subroot {
+            return Err(CommitmentError::VerificationFailure);
+        }
+
+        Ok(())
+    }
+
+    pub fn verify_non_membership(
+        &self,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        keys: MerklePath,
+    ) -> Result<(), CommitmentError> {
+        // validate arguments
+        if self.proofs.is_empty() {
+            return Err(CommitmentError::EmptyMerkleProof);
+        }
+        if root.hash.is_empty() {
+            return Err(CommitmentError::EmptyMerkleRoot);
+        }
+        let num = self.proofs.len();
+        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
+        if ics23_specs.len() != num {
+            return Err(CommitmentError::NumberOfSpecsMismatch);
+        }
+        if keys.key_path.len() != num {
+            return Err(CommitmentError::NumberOfKeysMismatch);
+        }
+
+        // verify the absence of key in lowest subtree
+        let proof = self
+            .proofs
+            .first()
+            .ok_or(CommitmentError::InvalidMerkleProof)?;
+        let spec = ics23_specs
+            .first()
+            .ok_or(CommitmentError::InvalidMerkleProof)?;
+        // keys are represented from root-to-leaf
+        let key = keys
+            .key_path
+            .get(num - 1)
+            .ok_or(CommitmentError::InvalidMerkleProof)?;
+        let non_existence_proof = non_existence_proof_for(proof, key.as_bytes())
+            .ok_or(CommitmentError::InvalidMerkleProof)?;
+        let subroot = calculate_non_existence_root(non_existence_proof)?;
+
+        let proof = CommitmentProof {
+            proof: Some(Proof::Nonexist(non_existence_proof.clone())),
+        };
+        if !verify_non_membership::<ics23::HostFunctionsManager>(
+            &proof,
+            spec,
+            &subroot,
+            key.as_bytes(),
+        ) {
+            return Err(CommitmentError::VerificationFailure);
+        }
+
+        // verify membership proofs starting from index 1 with value = subroot
+        self.verify_membership(specs, root, keys, subroot, 1)
+    }
+}
+
+/// Decompresses `proof` if it is a compressed batch proof, within `limits`,
+/// and returns it unchanged otherwise.
+///
+/// The size of the decompressed proof is computed, and checked against the
+/// limits, before any of it is built: a compressed proof can refer to the same
+/// inner operation any number of times.
+pub fn decompress<'a>(
+    proof: &'a CommitmentProof,
+    limits: &ProofLimits,
+) -> Result<Cow<'a, CommitmentProof>, CommitmentError> {
+    let compressed = match &proof.proof {
+        Some(Proof::Compressed(compressed)) => compressed,
+        _ => return Ok(Cow::Borrowed(proof)),
+    };
+
+    let (size, ops) = decompressed_size(compressed)?;
+    if size > limits.max_proof_size {
+        return Err(CommitmentError::ProofTooLarge {
+            size,
+            max: limits.max_proof_size,
+        });
+    }
+    if ops > limits.max_ops {
+        return Err(CommitmentError::TooManyProofOps {
+            ops,
+            max: limits.max_ops,
+        });
+    }
+
+    let lookup = &compressed.lookup_inners;
+    let entries = compressed
+        .entries
+        .iter()
+        .map(|entry| {
+            let proof = match &entry.proof {
+                Some(compressed_batch_entry::Proof::Exist(exist)) => {
+                    Some(batch_entry::Proof::Exist(decompress_exist(exist, lookup)?))
+                }
+                Some(compressed_batch_entry::Proof::Nonexist(non_exist)) => {
+                    Some(batch_entry::Proof::Nonexist(NonExistenceProof {
+                        key: non_exist.key.clone(),
+                        left: non_exist
+                            .left
+                            .as_ref()
+                            .map(|left| decompress_exist(left, lookup))
+                            .transpose()?,
+                        right: non_exist
+                            .right
+                            .as_ref()
+                            .map(|right| decompress_exist(right, lookup))
+                            .transpose()?,
+                    }))
+                }
+                None => None,
+            };
+            Ok(BatchEntry { proof })
+        })
+        .collect::<Result<Vec<_>, CommitmentError>>()?;
+
+    Ok(Cow::Owned(CommitmentProof {
+        proof: Some(Proof::Batch(BatchProof { entries })),
+    }))
+}
+
+fn decompress_exist(
+    exist: &CompressedExistenceProof,
+    lookup: &[InnerOp],
+) -> Result<ExistenceProof, CommitmentError> {
+    Ok(ExistenceProof {
+        key: exist.key.clone(),
+        value: exist.value.clone(),
+        leaf: exist.leaf.clone(),
+        path: exist
+            .path
+            .iter()
+            .map(|index| lookup_inner(lookup, *index).cloned())
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn lookup_inner(lookup: &[InnerOp], index: i32) -> Result<&InnerOp, CommitmentError> {
+    usize::try_from(index)
+        .ok()
+        .and_then(|i| lookup.get(i))
+        .ok_or(CommitmentError::InvalidCompressedProofIndex {
+            index,
+            len: lookup.len(),
+        })
+}
+
+/// Returns an upper bound of the encoded size of `compressed` once
+/// decompressed, along with its number of operations.
+fn decompressed_size(compressed: &CompressedBatchProof) -> Result<(usize, usize), CommitmentError> {
+    let lookup = &compressed.lookup_inners;
+    let exist_size = |exist: &CompressedExistenceProof| -> Result<(usize, usize), CommitmentError> {
+        let mut size = exist.encoded_len();
+        for index in &exist.path {
+            // Each index is replaced by the inner operation it refers to, with
+            // a tag and a length prefix of at most 10 bytes.
+            size = size.saturating_add(lookup_inner(lookup, *index)?.encoded_len() + 11);
+        }
+        Ok((size, exist.path.len() + 1))
+    };
+
+    let (mut size, mut ops) = (compressed.encoded_len(), 0usize);
+    for entry in &compressed.entries {
+        let sides = match &entry.proof {
+            Some(compressed_batch_entry::Proof::Exist(exist)) => vec![exist],
+            Some(compressed_batch_entry::Proof::Nonexist(non_exist)) => non_exist
+                .left
+                .iter()
+                .chain(non_exist.right.iter())
+                .collect(),
+            None => Vec::new(),
+        };
+        for exist in sides {
+            let (exist_size, exist_ops) = exist_size(exist)?;
+            size = size.saturating_add(exist_size);
+            ops = ops.saturating_add(exist_ops);
+        }
+    }
+    Ok((size, ops))
+}
+
+/// Returns the number of leaf and inner operations of `proof`.
+fn proof_ops(proof: &CommitmentProof) -> usize {
+    let exist_ops = |exist: &ExistenceProof| exist.path.len() + 1;
+    let non_exist_ops = |non_exist: &NonExistenceProof| {
+        non_exist
+            .left
+            .iter()
+            .chain(non_exist.right.iter())
+            .map(exist_ops)
+            .sum::<usize>()
+    };
+
+    match &proof.proof {
+        Some(Proof::Exist(exist)) => exist_ops(exist),
+        Some(Proof::Nonexist(non_exist)) => non_exist_ops(non_exist),
+        Some(Proof::Batch(batch)) => batch
+            .entries
+            .iter()
+            .map(|entry| match &entry.proof {
+                Some(batch_entry::Proof::Exist(exist)) => exist_ops(exist),
+                Some(batch_entry::Proof::Nonexist(non_exist)) => non_exist_ops(non_exist),
+                None => 0,
+            })
+            .sum(),
+        Some(Proof::Compressed(compressed)) => compressed
+            .entries
+            .iter()
+            .map(|entry| match &entry.proof {
+                Some(compressed_batch_entry::Proof::Exist(exist)) => exist.path.len() + 1,
+                Some(compressed_batch_entry::Proof::Nonexist(non_exist)) => non_exist
+                    .left
+                    .iter()
+                    .chain(non_exist.right.iter())
+                    .map(|exist| exist.path.len() + 1)
+                    .sum(),
+                None => 0,
+            })
+            .sum(),
+        None => 0,
+    }
+}
+
+/// Returns the existence proof of `key` in `proof`, either the proof itself
+/// or one of the entries of a batch proof.
+fn existence_proof_for<'a>(proof: &'a CommitmentProof, key: &[u8]) -> Option<&'a ExistenceProof> {
+    match &proof.proof {
+        Some(Proof::Exist(exist)) => Some(exist),
+        Some(Proof::Batch(batch)) => batch.entries.iter().find_map(|entry| match &entry.proof {
+            Some(batch_entry::Proof::Exist(exist)) if exist.key == key => Some(exist),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Returns the non-existence proof of `key` in `proof`, either the proof
+/// itself or one of the entries of a batch proof.
+fn non_existence_proof_for<'a>(
+    proof: &'a CommitmentProof,
+    key: &[u8],
+) -> Option<&'a NonExistenceProof> {
+    match &proof.proof {
+        Some(Proof::Nonexist(non_exist)) => Some(non_exist),
+        Some(Proof::Batch(batch)) => batch.entries.iter().find_map(|entry| match &entry.proof {
+            Some(batch_entry::Proof::Nonexist(non_exist)) if non_exist.key == key => {
+                Some(non_exist)
+            }
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+// TODO move to ics23
+fn calculate_non_existence_root(proof: &NonExistenceProof) -> Result<Vec<u8>, CommitmentError> {
+    if let Some(left) = &proof.left {
+        calculate_existence_root::<ics23::HostFunctionsManager>(left)
+            .map_err(|_| CommitmentError::InvalidMerkleProof)
+    } else if let Some(right) = &proof.right {
+        calculate_existence_root::<ics23::HostFunctionsManager>(right)
+            .map_err(|_| CommitmentError::InvalidMerkleProof)
+    } else {
+        Err(CommitmentError::InvalidMerkleProof)
+    }
+}
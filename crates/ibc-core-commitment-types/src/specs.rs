@@ -1,8 +1,7 @@
 //! Defines proof specs, which encode the structure of proofs
 
+use ibc_primitives::prelude::*;
 use ibc_proto::ics23::{InnerSpec as RawInnerSpec, LeafOp as RawLeafOp, ProofSpec as RawProofSpec};
-
-use crate::prelude::*;
 /// An array of proof specifications.
 ///
 /// This type encapsulates different types of proof specifications, mostly predefined, e.g., for
@@ -0,0 +1,209 @@
+//! Builders of the messages of the connection handshake, e.g.
+//! `MsgConnectionOpenTry::builder().client_id_on_b(..)...build()`.
+//!
+//! Unlike the struct literals of the messages, the builders leave out their
+//! deprecated fields, and `build` checks the invariants that the decoding of
+//! the messages enforces, such that relayers cannot submit messages which the
+//! counterparty would reject.
+
+use core::time::Duration;
+
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifier::{ClientId, ConnectionId};
+use ibc_primitives::msgs::ValidateBasic;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use ibc_proto::google::protobuf::Any;
+use typed_builder::TypedBuilder;
+
+use crate::connection::Counterparty;
+use crate::error::ConnectionError;
+use crate::msgs::conn_open_ack::MsgConnectionOpenAck;
+use crate::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use crate::msgs::conn_open_init::MsgConnectionOpenInit;
+use crate::msgs::conn_open_try::MsgConnectionOpenTry;
+use crate::version::Version as ConnectionVersion;
+
+/// Returns `msg` once it passes its [`ValidateBasic`] checks.
+fn validated<M>(msg: M) -> Result<M, M::Error>
+where
+    M: ValidateBasic,
+{
+    msg.validate_basic()?;
+    Ok(msg)
+}
+
+/// The fields of a [`MsgConnectionOpenInit`], set through
+/// [`MsgConnectionOpenInit::builder`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(
+    field_defaults(setter(into)),
+    builder_method(vis = ""),
+    build_method(into = Result<MsgConnectionOpenInit, ConnectionError>)
+)]
+pub struct ConnOpenInitFields {
+    pub client_id_on_a: ClientId,
+    pub counterparty: Counterparty,
+    #[builder(default, setter(strip_option))]
+    pub version: Option<ConnectionVersion>,
+    #[builder(default)]
+    pub delay_period: Duration,
+    pub signer: Signer,
+}
+
+impl From<ConnOpenInitFields> for Result<MsgConnectionOpenInit, ConnectionError> {
+    fn from(fields: ConnOpenInitFields) -> Self {
+        validated(MsgConnectionOpenInit {
+            client_id_on_a: fields.client_id_on_a,
+            counterparty: fields.counterparty,
+            version: fields.version,
+            delay_period: fields.delay_period,
+            signer: fields.signer,
+        })
+    }
+}
+
+impl MsgConnectionOpenInit {
+    /// Returns a builder of the message, whose counterparty must not have a
+    /// connection identifier yet.
+    pub fn builder() -> ConnOpenInitFieldsBuilder {
+        ConnOpenInitFields::builder()
+    }
+}
+
+/// The fields of a [`MsgConnectionOpenTry`], set through
+/// [`MsgConnectionOpenTry::builder`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(
+    field_defaults(setter(into)),
+    builder_method(vis = ""),
+    build_method(into = Result<MsgConnectionOpenTry, ConnectionError>)
+)]
+pub struct ConnOpenTryFields {
+    pub client_id_on_b: ClientId,
+    pub client_state_of_b_on_a: Any,
+    pub counterparty: Counterparty,
+    pub versions_on_a: Vec<ConnectionVersion>,
+    pub proof_conn_end_on_a: CommitmentProofBytes,
+    pub proof_client_state_of_b_on_a: CommitmentProofBytes,
+    pub proof_consensus_state_of_b_on_a: CommitmentProofBytes,
+    pub proofs_height_on_a: Height,
+    pub consensus_height_of_b_on_a: Height,
+    #[builder(default)]
+    pub delay_period: Duration,
+    pub signer: Signer,
+    #[builder(default, setter(strip_option))]
+    pub proof_consensus_state_of_b: Option<CommitmentProofBytes>,
+}
+
+impl From<ConnOpenTryFields> for Result<MsgConnectionOpenTry, ConnectionError> {
+    fn from(fields: ConnOpenTryFields) -> Self {
+        #[allow(deprecated)]
+        let msg = MsgConnectionOpenTry {
+            client_id_on_b: fields.client_id_on_b,
+            client_state_of_b_on_a: fields.client_state_of_b_on_a,
+            counterparty: fields.counterparty,
+            versions_on_a: fields.versions_on_a,
+            proof_conn_end_on_a: fields.proof_conn_end_on_a,
+            proof_client_state_of_b_on_a: fields.proof_client_state_of_b_on_a,
+            proof_consensus_state_of_b_on_a: fields.proof_consensus_state_of_b_on_a,
+            proofs_height_on_a: fields.proofs_height_on_a,
+            consensus_height_of_b_on_a: fields.consensus_height_of_b_on_a,
+            delay_period: fields.delay_period,
+            signer: fields.signer,
+            proof_consensus_state_of_b: fields.proof_consensus_state_of_b,
+            previous_connection_id: String::new(),
+        };
+        validated(msg)
+    }
+}
+
+impl MsgConnectionOpenTry {
+    /// Returns a builder of the message, which must offer at least one
+    /// version.
+    pub fn builder() -> ConnOpenTryFieldsBuilder {
+        ConnOpenTryFields::builder()
+    }
+}
+
+/// The fields of a [`MsgConnectionOpenAck`], set through
+/// [`MsgConnectionOpenAck::builder`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(
+    field_defaults(setter(into)),
+    builder_method(vis = ""),
+    build_method(into = Result<MsgConnectionOpenAck, ConnectionError>)
+)]
+pub struct ConnOpenAckFields {
+    pub conn_id_on_a: ConnectionId,
+    pub conn_id_on_b: ConnectionId,
+    pub client_state_of_a_on_b: Any,
+    pub proof_conn_end_on_b: CommitmentProofBytes,
+    pub proof_client_state_of_a_on_b: CommitmentProofBytes,
+    pub proof_consensus_state_of_a_on_b: CommitmentProofBytes,
+    pub proofs_height_on_b: Height,
+    pub consensus_height_of_a_on_b: Height,
+    pub version: ConnectionVersion,
+    pub signer: Signer,
+    #[builder(default, setter(strip_option))]
+    pub proof_consensus_state_of_a: Option<CommitmentProofBytes>,
+}
+
+impl From<ConnOpenAckFields> for Result<MsgConnectionOpenAck, ConnectionError> {
+    fn from(fields: ConnOpenAckFields) -> Self {
+        validated(MsgConnectionOpenAck {
+            conn_id_on_a: fields.conn_id_on_a,
+            conn_id_on_b: fields.conn_id_on_b,
+            client_state_of_a_on_b: fields.client_state_of_a_on_b,
+            proof_conn_end_on_b: fields.proof_conn_end_on_b,
+            proof_client_state_of_a_on_b: fields.proof_client_state_of_a_on_b,
+            proof_consensus_state_of_a_on_b: fields.proof_consensus_state_of_a_on_b,
+            proofs_height_on_b: fields.proofs_height_on_b,
+            consensus_height_of_a_on_b: fields.consensus_height_of_a_on_b,
+            version: fields.version,
+            signer: fields.signer,
+            proof_consensus_state_of_a: fields.proof_consensus_state_of_a,
+        })
+    }
+}
+
+impl MsgConnectionOpenAck {
+    /// Returns a builder of the message.
+    pub fn builder() -> ConnOpenAckFieldsBuilder {
+        ConnOpenAckFields::builder()
+    }
+}
+
+/// The fields of a [`MsgConnectionOpenConfirm`], set through
+/// [`MsgConnectionOpenConfirm::builder`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(
+    field_defaults(setter(into)),
+    builder_method(vis = ""),
+    build_method(into = Result<MsgConnectionOpenConfirm, ConnectionError>)
+)]
+pub struct ConnOpenConfirmFields {
+    pub conn_id_on_b: ConnectionId,
+    pub proof_conn_end_on_a: CommitmentProofBytes,
+    pub proof_height_on_a: Height,
+    pub signer: Signer,
+}
+
+impl From<ConnOpenConfirmFields> for Result<MsgConnectionOpenConfirm, ConnectionError> {
+    fn from(fields: ConnOpenConfirmFields) -> Self {
+        validated(MsgConnectionOpenConfirm {
+            conn_id_on_b: fields.conn_id_on_b,
+            proof_conn_end_on_a: fields.proof_conn_end_on_a,
+            proof_height_on_a: fields.proof_height_on_a,
+            signer: fields.signer,
+        })
+    }
+}
+
+impl MsgConnectionOpenConfirm {
+    /// Returns a builder of the message.
+    pub fn builder() -> ConnOpenConfirmFieldsBuilder {
+        ConnOpenConfirmFields::builder()
+    }
+}
@@ -4,19 +4,19 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use core::time::Duration;
 use core::u64;
 
+use ibc_core_client_types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_host_types::identifier::{ClientId, ConnectionId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::ZERO_DURATION;
 use ibc_proto::ibc::core::connection::v1::{
     ConnectionEnd as RawConnectionEnd, Counterparty as RawCounterparty,
     IdentifiedConnection as RawIdentifiedConnection,
 };
 use ibc_proto::Protobuf;
 
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::error::ConnectionError;
-use crate::core::ics03_connection::version::Version;
-use crate::core::ics23_commitment::commitment::CommitmentPrefix;
-use crate::core::ics24_host::identifier::{ClientId, ConnectionId};
-use crate::core::timestamp::ZERO_DURATION;
-use crate::prelude::*;
+use crate::error::ConnectionError;
+use crate::version::Version;
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -311,6 +311,11 @@ impl ConnectionEnd {
         self.versions = vec![new_version];
     }
 
+    /// Setter for the `delay_period` field.
+    pub fn set_delay_period(&mut self, new_delay_period: Duration) {
+        self.delay_period = new_delay_period;
+    }
+
     /// Helper function to compare the counterparty of this end with another counterparty.
     pub fn counterparty_matches(&self, other: &Counterparty) -> bool {
         self.counterparty.eq(other)
@@ -378,6 +383,7 @@ impl ConnectionEnd {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Counterparty {
     pub client_id: ClientId,
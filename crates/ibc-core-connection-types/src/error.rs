@@ -1,14 +1,16 @@
 //! Defines the connection error type
 
 use alloc::string::String;
+use core::time::Duration;
 
 use displaydoc::Display;
+use ibc_core_client_types::error as client_error;
+use ibc_core_client_types::height::Height;
+use ibc_core_host_types::identifier::{ClientId, ConnectionId, IdentifierError};
+use ibc_primitives::impl_error_sources;
+use ibc_primitives::timestamp::{Timestamp, TimestampOverflowError};
 
-use crate::core::ics02_client::error as client_error;
-use crate::core::ics03_connection::version::Version;
-use crate::core::ics24_host::identifier::{ClientId, ConnectionId, IdentifierError};
-use crate::core::timestamp::{Timestamp, TimestampOverflowError};
-use crate::Height;
+use crate::version::Version;
 
 #[derive(Debug, Display)]
 pub enum ConnectionError {
@@ -47,6 +49,11 @@ pub enum ConnectionError {
     InvalidProof,
     /// verifying connection state error: `{0}`
     VerifyConnectionState(client_error::ClientError),
+    /// delay period mismatch: expected `{expected:?}`, but the counterparty committed to `{committed:?}`
+    DelayPeriodMismatch {
+        expected: Duration,
+        committed: Duration,
+    },
     /// invalid signer error: `{reason}`
     InvalidSigner { reason: String },
     /// no connection was found for the previous connection id provided `{connection_id}`
@@ -84,25 +91,48 @@ pub enum ConnectionError {
     TimestampOverflow(TimestampOverflowError),
     /// connection counter overflow error
     CounterOverflow,
+    /// connection `{connection_id}` already has an upgrade in progress
+    UpgradeInProgress { connection_id: ConnectionId },
+    /// connection `{connection_id}` has no upgrade in progress
+    MissingUpgrade { connection_id: ConnectionId },
+    /// upgrade of connection `{connection_id}` changes none of its parameters
+    UnchangedUpgrade { connection_id: ConnectionId },
+    /// upgrade of connection `{connection_id}` has no timeout
+    MissingUpgradeTimeout { connection_id: ConnectionId },
+    /// upgrade of connection `{connection_id}` timed out at `{timeout_timestamp}`, host is at `{host_timestamp}`
+    UpgradeTimedOut {
+        connection_id: ConnectionId,
+        timeout_timestamp: Timestamp,
+        host_timestamp: Timestamp,
+    },
+    /// upgrade of connection `{connection_id}` times out at `{timeout_timestamp}`, proof is at counterparty timestamp `{counterparty_timestamp}`
+    UpgradeNotTimedOut {
+        connection_id: ConnectionId,
+        timeout_timestamp: Timestamp,
+        counterparty_timestamp: Timestamp,
+    },
+    /// upgrade in progress of connection `{connection_id}` was proposed by its counterparty
+    UpgradeProposedByCounterparty { connection_id: ConnectionId },
+    /// upgrade in progress of connection `{connection_id}` was proposed by the host
+    UpgradeProposedByHost { connection_id: ConnectionId },
+    /// the host does not support the upgrade of connections
+    UpgradesNotSupported,
+    /// verifying connection upgrade error: `{0}`
+    VerifyConnectionUpgrade(client_error::ClientError),
+    /// invalid connection genesis: `{reason}`
+    InvalidGenesis { reason: String },
     /// other error: `{description}`
     Other { description: String },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ConnectionError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self {
-            Self::Client(e) => Some(e),
-            Self::InvalidIdentifier(e) => Some(e),
-            Self::VerifyConnectionState(e) => Some(e),
-            Self::ConsensusStateVerificationFailure {
-                client_error: e, ..
-            } => Some(e),
-            Self::ClientStateVerificationFailure {
-                client_error: e, ..
-            } => Some(e),
-            Self::TimestampOverflow(e) => Some(e),
-            _ => None,
-        }
+impl_error_sources!(ConnectionError {
+    ibc {
+        Self::Client(e) => e,
+        Self::InvalidIdentifier(e) => e,
+        Self::VerifyConnectionState(e) => e,
+        Self::VerifyConnectionUpgrade(e) => e,
+        Self::ConsensusStateVerificationFailure { client_error: e, .. } => e,
+        Self::ClientStateVerificationFailure { client_error: e, .. } => e,
+        Self::TimestampOverflow(e) => e,
     }
-}
+});
@@ -0,0 +1,91 @@
+//! Defines the genesis state of the connection module.
+
+use ibc_core_host_types::identifier::{ClientId, ConnectionId};
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::connection::v1::{
+    ConnectionPaths as RawConnectionPaths, GenesisState as RawConnectionGenesisState,
+};
+use ibc_proto::Protobuf;
+
+use crate::connection::IdentifiedConnectionEnd;
+use crate::error::ConnectionError;
+use crate::params::ConnectionParams;
+
+/// The connections of a client, in the order they are stored in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientConnections {
+    pub client_id: ClientId,
+    pub connection_ids: Vec<ConnectionId>,
+}
+
+/// The state of the connection module, imported at genesis or exported for an
+/// upgrade.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectionGenesisState {
+    pub connections: Vec<IdentifiedConnectionEnd>,
+    pub client_connections: Vec<ClientConnections>,
+    pub params: ConnectionParams,
+    pub next_connection_sequence: u64,
+}
+
+impl Protobuf<RawConnectionGenesisState> for ConnectionGenesisState {}
+
+impl TryFrom<RawConnectionGenesisState> for ConnectionGenesisState {
+    type Error = ConnectionError;
+
+    fn try_from(raw: RawConnectionGenesisState) -> Result<Self, Self::Error> {
+        let connections = raw
+            .connections
+            .into_iter()
+            .map(IdentifiedConnectionEnd::try_from)
+            .collect::<Result<_, _>>()?;
+
+        let client_connections = raw
+            .client_connection_paths
+            .into_iter()
+            .map(|paths| {
+                Ok(ClientConnections {
+                    client_id: paths
+                        .client_id
+                        .parse()
+                        .map_err(ConnectionError::InvalidIdentifier)?,
+                    connection_ids: paths
+                        .paths
+                        .iter()
+                        .map(|conn_id| conn_id.parse())
+                        .collect::<Result<_, _>>()
+                        .map_err(ConnectionError::InvalidIdentifier)?,
+                })
+            })
+            .collect::<Result<_, ConnectionError>>()?;
+
+        Ok(Self {
+            connections,
+            client_connections,
+            params: raw.params.map(Into::into).unwrap_or_default(),
+            next_connection_sequence: raw.next_connection_sequence,
+        })
+    }
+}
+
+impl From<ConnectionGenesisState> for RawConnectionGenesisState {
+    fn from(genesis: ConnectionGenesisState) -> Self {
+        Self {
+            connections: genesis.connections.into_iter().map(Into::into).collect(),
+            client_connection_paths: genesis
+                .client_connections
+                .into_iter()
+                .map(|client_connections| RawConnectionPaths {
+                    client_id: client_connections.client_id.to_string(),
+                    paths: client_connections
+                        .connection_ids
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect(),
+                })
+                .collect(),
+            next_connection_sequence: genesis.next_connection_sequence,
+            params: Some(genesis.params.into()),
+        }
+    }
+}
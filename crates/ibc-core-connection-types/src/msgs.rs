@@ -0,0 +1,111 @@
+//! Message definitions for the connection handshake datagrams.
+//!
+//! We define each of the four messages in the connection handshake protocol as a `struct`.
+//! Each such message comprises the same fields as the datagrams defined in ICS3 English spec:
+//! <https://github.com/cosmos/ibc/tree/master/spec/core/ics-003-connection-semantics>.
+//!
+//! One departure from ICS3 is that we abstract the three counterparty fields (connection id,
+//! prefix, and client id) into a single field of type `Counterparty`; this applies to messages
+//! `MsgConnectionOpenInit` and `MsgConnectionOpenTry`. One other difference with regards to
+//! abstraction is that all proof-related attributes in a message are encapsulated in `Proofs` type.
+//!
+//! Another difference to ICS3 specs is that each message comprises an additional field called
+//! `signer` which is specific to Cosmos-SDK.
+
+use ibc_primitives::msgs::ValidateBasic;
+use ibc_primitives::prelude::*;
+
+use crate::error::ConnectionError;
+use crate::msgs::conn_open_ack::MsgConnectionOpenAck;
+use crate::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use crate::msgs::conn_open_init::MsgConnectionOpenInit;
+use crate::msgs::conn_open_try::MsgConnectionOpenTry;
+#[cfg(feature = "connection-upgrade")]
+use crate::msgs::conn_upgrade_ack::MsgConnectionUpgradeAck;
+#[cfg(feature = "connection-upgrade")]
+use crate::msgs::conn_upgrade_cancel::MsgConnectionUpgradeCancel;
+#[cfg(feature = "connection-upgrade")]
+use crate::msgs::conn_upgrade_confirm::MsgConnectionUpgradeConfirm;
+#[cfg(feature = "connection-upgrade")]
+use crate::msgs::conn_upgrade_init::MsgConnectionUpgradeInit;
+#[cfg(feature = "connection-upgrade")]
+use crate::msgs::conn_upgrade_timeout::MsgConnectionUpgradeTimeout;
+#[cfg(feature = "connection-upgrade")]
+use crate::msgs::conn_upgrade_try::MsgConnectionUpgradeTry;
+
+pub mod conn_open_ack;
+pub mod conn_open_confirm;
+pub mod conn_open_init;
+pub mod conn_open_try;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_ack;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_cancel;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_confirm;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_init;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_timeout;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_try;
+
+/// Enumeration of all possible messages that the ICS3 protocol processes.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
+pub enum ConnectionMsg {
+    OpenInit(MsgConnectionOpenInit),
+    OpenTry(MsgConnectionOpenTry),
+    OpenAck(MsgConnectionOpenAck),
+    OpenConfirm(MsgConnectionOpenConfirm),
+    #[cfg(feature = "connection-upgrade")]
+    UpgradeInit(MsgConnectionUpgradeInit),
+    #[cfg(feature = "connection-upgrade")]
+    UpgradeTry(MsgConnectionUpgradeTry),
+    #[cfg(feature = "connection-upgrade")]
+    UpgradeAck(MsgConnectionUpgradeAck),
+    #[cfg(feature = "connection-upgrade")]
+    UpgradeConfirm(MsgConnectionUpgradeConfirm),
+    #[cfg(feature = "connection-upgrade")]
+    UpgradeTimeout(MsgConnectionUpgradeTimeout),
+    #[cfg(feature = "connection-upgrade")]
+    UpgradeCancel(MsgConnectionUpgradeCancel),
+}
+
+impl ValidateBasic for ConnectionMsg {
+    type Error = ConnectionError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        match self {
+            Self::OpenInit(msg) => msg.validate_basic(),
+            Self::OpenTry(msg) => msg.validate_basic(),
+            Self::OpenAck(msg) => msg.validate_basic(),
+            Self::OpenConfirm(msg) => msg.validate_basic(),
+            #[cfg(feature = "connection-upgrade")]
+            Self::UpgradeInit(msg) => msg.validate_basic(),
+            #[cfg(feature = "connection-upgrade")]
+            Self::UpgradeTry(msg) => msg.validate_basic(),
+            #[cfg(feature = "connection-upgrade")]
+            Self::UpgradeAck(msg) => msg.validate_basic(),
+            #[cfg(feature = "connection-upgrade")]
+            Self::UpgradeConfirm(msg) => msg.validate_basic(),
+            #[cfg(feature = "connection-upgrade")]
+            Self::UpgradeTimeout(msg) => msg.validate_basic(),
+            #[cfg(feature = "connection-upgrade")]
+            Self::UpgradeCancel(msg) => msg.validate_basic(),
+        }
+    }
+}
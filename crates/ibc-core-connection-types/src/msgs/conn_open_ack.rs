@@ -1,25 +1,34 @@
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifier::ConnectionId;
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenAck as RawMsgConnectionOpenAck;
 use ibc_proto::Protobuf;
 
-use crate::core::ics03_connection::error::ConnectionError;
-use crate::core::ics03_connection::version::Version;
-use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
-use crate::core::ics24_host::identifier::ConnectionId;
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
-use crate::Height;
+use crate::error::ConnectionError;
+use crate::version::Version;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnectionOpenAck";
+pub const TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnectionOpenAck";
 
 /// Per our convention, this message is sent to chain A.
 /// The handler will check proofs of chain B.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgConnectionOpenAck {
     /// ConnectionId that chain A has chosen for it's ConnectionEnd
@@ -27,6 +36,7 @@ pub struct MsgConnectionOpenAck {
     /// ConnectionId that chain B has chosen for it's ConnectionEnd
     pub conn_id_on_b: ConnectionId,
     /// ClientState of client tracking chain A on chain B
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::utils::arbitrary::any))]
     pub client_state_of_a_on_b: Any,
     /// proof of ConnectionEnd stored on Chain B during ConnOpenTry
     pub proof_conn_end_on_b: CommitmentProofBytes,
@@ -55,6 +65,14 @@ impl Msg for MsgConnectionOpenAck {
 
 impl Protobuf<RawMsgConnectionOpenAck> for MsgConnectionOpenAck {}
 
+impl ValidateBasic for MsgConnectionOpenAck {
+    type Error = ConnectionError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgConnectionOpenAck> for MsgConnectionOpenAck {
     type Error = ConnectionError;
 
@@ -132,13 +150,13 @@ impl From<MsgConnectionOpenAck> for RawMsgConnectionOpenAck {
 
 #[cfg(test)]
 mod tests {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::client::v1::Height;
     use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenAck as RawMsgConnectionOpenAck;
     use ibc_testkit::utils::core::connection::dummy_raw_msg_conn_open_ack;
     use test_log::test;
 
-    use crate::core::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
-    use crate::prelude::*;
+    use crate::msgs::conn_open_ack::MsgConnectionOpenAck;
 
     #[test]
     fn parse_connection_open_ack_msg() {
@@ -1,20 +1,25 @@
 use core::time::Duration;
 
+use ibc_core_host_types::identifier::{ClientId, ConnectionId};
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenInit as RawMsgConnectionOpenInit;
 use ibc_proto::Protobuf;
 
-use crate::core::ics03_connection::connection::Counterparty;
-use crate::core::ics03_connection::error::ConnectionError;
-use crate::core::ics03_connection::version::Version;
-use crate::core::ics24_host::identifier::ClientId;
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
+use crate::connection::Counterparty;
+use crate::error::ConnectionError;
+use crate::version::Version;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnectionOpenInit";
+pub const TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnectionOpenInit";
 
 /// Per our convention, this message is sent to chain A.
 /// The handler will check proofs of chain B.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MsgConnectionOpenInit {
@@ -34,6 +39,47 @@ impl Msg for MsgConnectionOpenInit {
     }
 }
 
+/// This module encapsulates the workarounds we need to do to implement
+/// `TypeInfo` on `MsgConnectionOpenInit`, whose `Duration` is encoded as its
+/// seconds followed by its subsecond nanoseconds
+#[cfg(feature = "parity-scale-codec")]
+mod scale_impls {
+    use super::*;
+
+    impl scale_info::TypeInfo for MsgConnectionOpenInit {
+        type Identity = Self;
+
+        fn type_info() -> scale_info::Type {
+            scale_info::Type::builder()
+                .path(scale_info::Path::new(
+                    "MsgConnectionOpenInit",
+                    module_path!(),
+                ))
+                .composite(
+                    scale_info::build::Fields::named()
+                        .field(|f| {
+                            f.ty::<ClientId>()
+                                .name("client_id_on_a")
+                                .type_name("ClientId")
+                        })
+                        .field(|f| {
+                            f.ty::<Counterparty>()
+                                .name("counterparty")
+                                .type_name("Counterparty")
+                        })
+                        .field(|f| {
+                            f.ty::<Option<Version>>()
+                                .name("version")
+                                .type_name("Option<Version>")
+                        })
+                        .field(|f| f.ty::<u64>().name("delay_period_secs").type_name("u64"))
+                        .field(|f| f.ty::<u32>().name("delay_period_nanos").type_name("u32"))
+                        .field(|f| f.ty::<Signer>().name("signer").type_name("Signer")),
+                )
+        }
+    }
+}
+
 /// This module encapsulates the workarounds we need to do to implement
 /// `BorshSerialize` and `BorshDeserialize` on `MsgConnectionOpenInit`
 #[cfg(feature = "borsh")]
@@ -92,6 +138,14 @@ mod borsh_impls {
 
 impl Protobuf<RawMsgConnectionOpenInit> for MsgConnectionOpenInit {}
 
+impl ValidateBasic for MsgConnectionOpenInit {
+    type Error = ConnectionError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgConnectionOpenInit> for MsgConnectionOpenInit {
     type Error = ConnectionError;
 
@@ -128,8 +182,28 @@ impl From<MsgConnectionOpenInit> for RawMsgConnectionOpenInit {
     }
 }
 
+/// The outcome of executing a [`MsgConnectionOpenInit`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnOpenInitResult {
+    /// The identifier generated for the new connection.
+    pub conn_id_on_a: ConnectionId,
+}
+
 #[cfg(test)]
 mod tests {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::connection::v1::{
         Counterparty as RawCounterparty, MsgConnectionOpenInit as RawMsgConnectionOpenInit,
     };
@@ -139,7 +213,6 @@ mod tests {
     use test_log::test;
 
     use super::MsgConnectionOpenInit;
-    use crate::prelude::*;
 
     #[test]
     fn parse_connection_open_init_msg() {
@@ -245,4 +318,20 @@ mod tests {
 
         assert_eq!(msg, msg_deserialized);
     }
+
+    /// Test that SCALE encoding/decoding works well with delay periods up to u64::MAX
+    #[cfg(feature = "parity-scale-codec")]
+    #[test]
+    fn test_scale_codec() {
+        use parity_scale_codec::{Decode, Encode};
+
+        let mut raw = dummy_raw_msg_conn_open_init();
+        raw.delay_period = u64::MAX;
+        let msg = MsgConnectionOpenInit::try_from(raw).unwrap();
+
+        let encoded = msg.encode();
+        let msg_decoded = MsgConnectionOpenInit::decode(&mut encoded.as_slice()).unwrap();
+
+        assert_eq!(msg, msg_decoded);
+    }
 }
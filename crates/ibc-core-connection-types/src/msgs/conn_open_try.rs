@@ -1,30 +1,36 @@
 use core::convert::{TryFrom, TryInto};
 use core::time::Duration;
 
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifier::{ClientId, ConnectionId};
+use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenTry as RawMsgConnectionOpenTry;
 use ibc_proto::Protobuf;
 
-use crate::core::ics03_connection::connection::Counterparty;
-use crate::core::ics03_connection::error::ConnectionError;
-use crate::core::ics03_connection::version::Version;
-use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
-use crate::core::ics24_host::identifier::ClientId;
-use crate::core::Msg;
-use crate::prelude::*;
-use crate::signer::Signer;
-use crate::Height;
+use crate::connection::Counterparty;
+use crate::error::ConnectionError;
+use crate::version::Version;
 
-pub(crate) const TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnectionOpenTry";
+pub const TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnectionOpenTry";
 
 /// Per our convention, this message is sent to chain B.
 /// The handler will check proofs of chain A.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode)
+)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgConnectionOpenTry {
     /// ClientId on B that the connection is being opened for
     pub client_id_on_b: ClientId,
     /// ClientState of client tracking chain B on chain A
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::utils::arbitrary::any))]
     pub client_state_of_b_on_a: Any,
     /// ClientId, ConnectionId and prefix of chain A
     pub counterparty: Counterparty,
@@ -58,6 +64,88 @@ impl Msg for MsgConnectionOpenTry {
         TYPE_URL.to_string()
     }
 }
+
+/// This module encapsulates the workarounds we need to do to implement
+/// `TypeInfo` on `MsgConnectionOpenTry`, whose `Duration` is encoded as its
+/// seconds followed by its subsecond nanoseconds
+#[cfg(feature = "parity-scale-codec")]
+mod scale_impls {
+    use super::*;
+
+    impl scale_info::TypeInfo for MsgConnectionOpenTry {
+        type Identity = Self;
+
+        fn type_info() -> scale_info::Type {
+            scale_info::Type::builder()
+                .path(scale_info::Path::new(
+                    "MsgConnectionOpenTry",
+                    module_path!(),
+                ))
+                .composite(
+                    scale_info::build::Fields::named()
+                        .field(|f| {
+                            f.ty::<ClientId>()
+                                .name("client_id_on_b")
+                                .type_name("ClientId")
+                        })
+                        .field(|f| {
+                            f.ty::<Any>()
+                                .name("client_state_of_b_on_a")
+                                .type_name("Any")
+                        })
+                        .field(|f| {
+                            f.ty::<Counterparty>()
+                                .name("counterparty")
+                                .type_name("Counterparty")
+                        })
+                        .field(|f| {
+                            f.ty::<Vec<Version>>()
+                                .name("versions_on_a")
+                                .type_name("Vec<Version>")
+                        })
+                        .field(|f| {
+                            f.ty::<CommitmentProofBytes>()
+                                .name("proof_conn_end_on_a")
+                                .type_name("CommitmentProofBytes")
+                        })
+                        .field(|f| {
+                            f.ty::<CommitmentProofBytes>()
+                                .name("proof_client_state_of_b_on_a")
+                                .type_name("CommitmentProofBytes")
+                        })
+                        .field(|f| {
+                            f.ty::<CommitmentProofBytes>()
+                                .name("proof_consensus_state_of_b_on_a")
+                                .type_name("CommitmentProofBytes")
+                        })
+                        .field(|f| {
+                            f.ty::<Height>()
+                                .name("proofs_height_on_a")
+                                .type_name("Height")
+                        })
+                        .field(|f| {
+                            f.ty::<Height>()
+                                .name("consensus_height_of_b_on_a")
+                                .type_name("Height")
+                        })
+                        .field(|f| f.ty::<u64>().name("delay_period_secs").type_name("u64"))
+                        .field(|f| f.ty::<u32>().name("delay_period_nanos").type_name("u32"))
+                        .field(|f| f.ty::<Signer>().name("signer").type_name("Signer"))
+                        .field(|f| {
+                            f.ty::<Option<CommitmentProofBytes>>()
+                                .name("proof_consensus_state_of_b")
+                                .type_name("Option<CommitmentProofBytes>")
+                        })
+                        .field(|f| {
+                            f.ty::<String>()
+                                .name("previous_connection_id")
+                                .type_name("String")
+                        }),
+                )
+        }
+    }
+}
+
 #[allow(deprecated)]
 #[cfg(feature = "borsh")]
 mod borsh_impls {
@@ -152,6 +240,14 @@ mod borsh_impls {
 
 impl Protobuf<RawMsgConnectionOpenTry> for MsgConnectionOpenTry {}
 
+impl ValidateBasic for MsgConnectionOpenTry {
+    type Error = ConnectionError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        validate_raw(self)
+    }
+}
+
 impl TryFrom<RawMsgConnectionOpenTry> for MsgConnectionOpenTry {
     type Error = ConnectionError;
 
@@ -242,8 +338,28 @@ impl From<MsgConnectionOpenTry> for RawMsgConnectionOpenTry {
     }
 }
 
+/// The outcome of executing a [`MsgConnectionOpenTry`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnOpenTryResult {
+    /// The identifier generated for the new connection.
+    pub conn_id_on_b: ConnectionId,
+}
+
 #[cfg(test)]
 mod tests {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::client::v1::Height;
     use ibc_proto::ibc::core::connection::v1::{
         Counterparty as RawCounterparty, MsgConnectionOpenTry as RawMsgConnectionOpenTry,
@@ -253,8 +369,7 @@ mod tests {
     };
     use test_log::test;
 
-    use crate::core::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
-    use crate::prelude::*;
+    use crate::msgs::conn_open_try::MsgConnectionOpenTry;
 
     #[test]
     fn parse_connection_open_try_msg() {
@@ -390,4 +505,20 @@ mod tests {
 
         assert_eq!(msg, msg_deserialized);
     }
+
+    /// Test that SCALE encoding/decoding works well with delay periods up to u64::MAX
+    #[cfg(feature = "parity-scale-codec")]
+    #[test]
+    fn test_scale_codec() {
+        use parity_scale_codec::{Decode, Encode};
+
+        let mut raw = dummy_raw_msg_conn_open_try(10, 34);
+        raw.delay_period = u64::MAX;
+        let msg = MsgConnectionOpenTry::try_from(raw).unwrap();
+
+        let encoded = msg.encode();
+        let msg_decoded = MsgConnectionOpenTry::decode(&mut encoded.as_slice()).unwrap();
+
+        assert_eq!(msg, msg_decoded);
+    }
 }
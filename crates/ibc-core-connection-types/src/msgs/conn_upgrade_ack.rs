@@ -0,0 +1,44 @@
+//! Definition of domain type message `MsgConnectionUpgradeAck`.
+
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifier::ConnectionId;
+use ibc_primitives::msgs::ValidateBasic;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+
+use crate::error::ConnectionError;
+
+/// Per our convention, this message is sent to chain A, to apply the upgrade
+/// of `conn_id_on_a` once chain B committed to it.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgConnectionUpgradeAck {
+    pub conn_id_on_a: ConnectionId,
+    /// Proof of the upgrade chain B committed to
+    pub proof_upgrade_on_b: CommitmentProofBytes,
+    /// Height at which `proof_upgrade_on_b` in this message was taken
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl ValidateBasic for MsgConnectionUpgradeAck {
+    type Error = ConnectionError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+//! Definition of domain type message `MsgConnectionUpgradeCancel`.
+
+use ibc_core_host_types::identifier::ConnectionId;
+use ibc_primitives::msgs::ValidateBasic;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+
+use crate::error::ConnectionError;
+
+/// Per our convention, this message is sent to chain A, to drop the upgrade
+/// of `conn_id_on_a` it proposed, e.g. on behalf of its governance.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgConnectionUpgradeCancel {
+    pub conn_id_on_a: ConnectionId,
+    pub signer: Signer,
+}
+
+impl ValidateBasic for MsgConnectionUpgradeCancel {
+    type Error = ConnectionError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
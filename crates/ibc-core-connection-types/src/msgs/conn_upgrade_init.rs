@@ -0,0 +1,151 @@
+//! Definition of domain type message `MsgConnectionUpgradeInit`.
+
+use core::time::Duration;
+
+use ibc_core_host_types::identifier::ConnectionId;
+use ibc_primitives::msgs::ValidateBasic;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use ibc_primitives::timestamp::Timestamp;
+
+use crate::error::ConnectionError;
+
+/// Per our convention, this message is sent to chain A, to propose the upgrade
+/// of the delay period of its open connection `conn_id_on_a`.
+///
+/// Like the rest of the connection upgrade handshake, it has no protobuf
+/// encoding yet: hosts wrap it in a
+/// [`ConnectionMsg`](crate::msgs::ConnectionMsg)
+/// themselves, typically on behalf of their governance.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgConnectionUpgradeInit {
+    pub conn_id_on_a: ConnectionId,
+    pub delay_period: Duration,
+    /// Timestamp on both chains after which the upgrade may no longer be
+    /// applied
+    pub timeout_timestamp: Timestamp,
+    pub signer: Signer,
+}
+
+impl MsgConnectionUpgradeInit {
+    pub fn new(
+        conn_id_on_a: ConnectionId,
+        delay_period: Duration,
+        timeout_timestamp: Timestamp,
+        signer: Signer,
+    ) -> Self {
+        Self {
+            conn_id_on_a,
+            delay_period,
+            timeout_timestamp,
+            signer,
+        }
+    }
+}
+
+impl ValidateBasic for MsgConnectionUpgradeInit {
+    type Error = ConnectionError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        if !self.timeout_timestamp.is_set() {
+            return Err(ConnectionError::MissingUpgradeTimeout {
+                connection_id: self.conn_id_on_a.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// This module encapsulates the workarounds we need to do to implement
+/// `TypeInfo` on `MsgConnectionUpgradeInit`, whose `Duration` is encoded as
+/// its seconds followed by its subsecond nanoseconds
+#[cfg(feature = "parity-scale-codec")]
+mod scale_impls {
+    use super::*;
+
+    impl scale_info::TypeInfo for MsgConnectionUpgradeInit {
+        type Identity = Self;
+
+        fn type_info() -> scale_info::Type {
+            scale_info::Type::builder()
+                .path(scale_info::Path::new(
+                    "MsgConnectionUpgradeInit",
+                    module_path!(),
+                ))
+                .composite(
+                    scale_info::build::Fields::named()
+                        .field(|f| {
+                            f.ty::<ConnectionId>()
+                                .name("conn_id_on_a")
+                                .type_name("ConnectionId")
+                        })
+                        .field(|f| f.ty::<u64>().name("delay_period_secs").type_name("u64"))
+                        .field(|f| f.ty::<u32>().name("delay_period_nanos").type_name("u32"))
+                        .field(|f| {
+                            f.ty::<Timestamp>()
+                                .name("timeout_timestamp")
+                                .type_name("Timestamp")
+                        })
+                        .field(|f| f.ty::<Signer>().name("signer").type_name("Signer")),
+                )
+        }
+    }
+}
+
+/// This module encapsulates the workarounds we need to do to implement
+/// `BorshSerialize` and `BorshDeserialize` on `MsgConnectionUpgradeInit`
+#[cfg(feature = "borsh")]
+mod borsh_impls {
+    use borsh::maybestd::io::{self, Read};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use super::*;
+
+    #[derive(BorshSerialize, BorshDeserialize)]
+    pub struct InnerMsgConnectionUpgradeInit {
+        pub conn_id_on_a: ConnectionId,
+        pub delay_period_nanos: u64,
+        pub timeout_timestamp: Timestamp,
+        pub signer: Signer,
+    }
+
+    impl BorshSerialize for MsgConnectionUpgradeInit {
+        fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            let delay_period_nanos: u64 =
+                self.delay_period.as_nanos().try_into().map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("Duration too long: {} nanos", self.delay_period.as_nanos()),
+                    )
+                })?;
+
+            let inner = InnerMsgConnectionUpgradeInit {
+                conn_id_on_a: self.conn_id_on_a.clone(),
+                delay_period_nanos,
+                timeout_timestamp: self.timeout_timestamp,
+                signer: self.signer.clone(),
+            };
+
+            inner.serialize(writer)
+        }
+    }
+
+    impl BorshDeserialize for MsgConnectionUpgradeInit {
+        fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+            let inner = InnerMsgConnectionUpgradeInit::deserialize_reader(reader)?;
+
+            Ok(MsgConnectionUpgradeInit {
+                conn_id_on_a: inner.conn_id_on_a,
+                delay_period: Duration::from_nanos(inner.delay_period_nanos),
+                timeout_timestamp: inner.timeout_timestamp,
+                signer: inner.signer,
+            })
+        }
+    }
+}
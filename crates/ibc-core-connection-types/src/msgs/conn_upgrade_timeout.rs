@@ -0,0 +1,47 @@
+//! Definition of domain type message `MsgConnectionUpgradeTimeout`.
+
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifier::ConnectionId;
+use ibc_primitives::msgs::ValidateBasic;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+
+use crate::error::ConnectionError;
+
+/// This message is sent to either end of a connection to drop its upgrade in
+/// progress once timed out, while the connection end of its counterparty was
+/// left as is.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgConnectionUpgradeTimeout {
+    pub conn_id: ConnectionId,
+    /// Proof of the connection end of the counterparty, as of a height past
+    /// the timeout of the upgrade
+    pub proof_conn_end_on_counterparty: CommitmentProofBytes,
+    /// Height at which `proof_conn_end_on_counterparty` in this message was
+    /// taken
+    pub proof_height_on_counterparty: Height,
+    pub signer: Signer,
+}
+
+impl ValidateBasic for MsgConnectionUpgradeTimeout {
+    type Error = ConnectionError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
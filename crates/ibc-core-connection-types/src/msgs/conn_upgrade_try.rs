@@ -0,0 +1,152 @@
+//! Definition of domain type message `MsgConnectionUpgradeTry`.
+
+use core::time::Duration;
+
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifier::ConnectionId;
+use ibc_primitives::msgs::ValidateBasic;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use ibc_primitives::timestamp::Timestamp;
+
+use crate::error::ConnectionError;
+
+/// Per our convention, this message is sent to chain B, to commit to the
+/// upgrade chain A proposed for the counterparty of `conn_id_on_b`.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgConnectionUpgradeTry {
+    pub conn_id_on_b: ConnectionId,
+    /// The delay period proposed by chain A
+    pub delay_period: Duration,
+    /// The timeout of the upgrade proposed by chain A
+    pub timeout_timestamp: Timestamp,
+    /// Proof of the upgrade proposed by chain A
+    pub proof_upgrade_on_a: CommitmentProofBytes,
+    /// Height at which `proof_upgrade_on_a` in this message was taken
+    pub proof_height_on_a: Height,
+    pub signer: Signer,
+}
+
+impl ValidateBasic for MsgConnectionUpgradeTry {
+    type Error = ConnectionError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        if !self.timeout_timestamp.is_set() {
+            return Err(ConnectionError::MissingUpgradeTimeout {
+                connection_id: self.conn_id_on_b.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// This module encapsulates the workarounds we need to do to implement
+/// `TypeInfo` on `MsgConnectionUpgradeTry`, whose `Duration` is encoded as
+/// its seconds followed by its subsecond nanoseconds
+#[cfg(feature = "parity-scale-codec")]
+mod scale_impls {
+    use super::*;
+
+    impl scale_info::TypeInfo for MsgConnectionUpgradeTry {
+        type Identity = Self;
+
+        fn type_info() -> scale_info::Type {
+            scale_info::Type::builder()
+                .path(scale_info::Path::new(
+                    "MsgConnectionUpgradeTry",
+                    module_path!(),
+                ))
+                .composite(
+                    scale_info::build::Fields::named()
+                        .field(|f| {
+                            f.ty::<ConnectionId>()
+                                .name("conn_id_on_b")
+                                .type_name("ConnectionId")
+                        })
+                        .field(|f| f.ty::<u64>().name("delay_period_secs").type_name("u64"))
+                        .field(|f| f.ty::<u32>().name("delay_period_nanos").type_name("u32"))
+                        .field(|f| {
+                            f.ty::<Timestamp>()
+                                .name("timeout_timestamp")
+                                .type_name("Timestamp")
+                        })
+                        .field(|f| {
+                            f.ty::<CommitmentProofBytes>()
+                                .name("proof_upgrade_on_a")
+                                .type_name("CommitmentProofBytes")
+                        })
+                        .field(|f| {
+                            f.ty::<Height>()
+                                .name("proof_height_on_a")
+                                .type_name("Height")
+                        })
+                        .field(|f| f.ty::<Signer>().name("signer").type_name("Signer")),
+                )
+        }
+    }
+}
+
+/// This module encapsulates the workarounds we need to do to implement
+/// `BorshSerialize` and `BorshDeserialize` on `MsgConnectionUpgradeTry`
+#[cfg(feature = "borsh")]
+mod borsh_impls {
+    use borsh::maybestd::io::{self, Read};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use super::*;
+
+    #[derive(BorshSerialize, BorshDeserialize)]
+    pub struct InnerMsgConnectionUpgradeTry {
+        pub conn_id_on_b: ConnectionId,
+        pub delay_period_nanos: u64,
+        pub timeout_timestamp: Timestamp,
+        pub proof_upgrade_on_a: CommitmentProofBytes,
+        pub proof_height_on_a: Height,
+        pub signer: Signer,
+    }
+
+    impl BorshSerialize for MsgConnectionUpgradeTry {
+        fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            let delay_period_nanos: u64 =
+                self.delay_period.as_nanos().try_into().map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("Duration too long: {} nanos", self.delay_period.as_nanos()),
+                    )
+                })?;
+
+            let inner = InnerMsgConnectionUpgradeTry {
+                conn_id_on_b: self.conn_id_on_b.clone(),
+                delay_period_nanos,
+                timeout_timestamp: self.timeout_timestamp,
+                proof_upgrade_on_a: self.proof_upgrade_on_a.clone(),
+                proof_height_on_a: self.proof_height_on_a,
+                signer: self.signer.clone(),
+            };
+
+            inner.serialize(writer)
+        }
+    }
+
+    impl BorshDeserialize for MsgConnectionUpgradeTry {
+        fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+            let inner = InnerMsgConnectionUpgradeTry::deserialize_reader(reader)?;
+
+            Ok(MsgConnectionUpgradeTry {
+                conn_id_on_b: inner.conn_id_on_b,
+                delay_period: Duration::from_nanos(inner.delay_period_nanos),
+                timeout_timestamp: inner.timeout_timestamp,
+                proof_upgrade_on_a: inner.proof_upgrade_on_a,
+                proof_height_on_a: inner.proof_height_on_a,
+                signer: inner.signer,
+            })
+        }
+    }
+}
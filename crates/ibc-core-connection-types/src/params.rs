@@ -0,0 +1,190 @@
+//! Defines the parameters of the connection module.
+
+use core::time::Duration;
+
+use ibc_proto::ibc::core::connection::v1::Params as RawParams;
+use ibc_proto::Protobuf;
+
+/// The default maximum expected time per block, as in ibc-go.
+pub const DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK: Duration = Duration::from_secs(30);
+
+/// Parameters of the connection module, set at genesis and updatable by
+/// the host afterwards.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConnectionParams {
+    /// The maximum expected time per block of the host, used to enforce the
+    /// block delay of connections in addition to their time delay.
+    pub max_expected_time_per_block: Duration,
+}
+
+impl ConnectionParams {
+    pub fn new(max_expected_time_per_block: Duration) -> Self {
+        Self {
+            max_expected_time_per_block,
+        }
+    }
+
+    /// Returns the number of blocks a connection with the given delay period
+    /// must wait for, rounded up.
+    pub fn block_delay(&self, delay_period_time: &Duration) -> u64 {
+        calculate_block_delay(delay_period_time, &self.max_expected_time_per_block)
+    }
+}
+
+fn calculate_block_delay(
+    delay_period_time: &Duration,
+    max_expected_time_per_block: &Duration,
+) -> u64 {
+    let delay_period_time = delay_period_time.as_secs();
+    let max_expected_time_per_block = max_expected_time_per_block.as_secs();
+    if max_expected_time_per_block == 0 {
+        return 0;
+    }
+    if delay_period_time % max_expected_time_per_block == 0 {
+        return delay_period_time / max_expected_time_per_block;
+    }
+    (delay_period_time / max_expected_time_per_block) + 1
+}
+
+/// The parameters of a chain at genesis.
+impl Default for ConnectionParams {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK)
+    }
+}
+
+/// This module encapsulates the workarounds we need to do to implement
+/// `BorshSerialize` and `BorshDeserialize` on `ConnectionParams`
+#[cfg(feature = "borsh")]
+mod borsh_impls {
+    use borsh::maybestd::io::{self, Read};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use super::*;
+
+    #[derive(BorshSerialize, BorshDeserialize)]
+    struct InnerConnectionParams {
+        max_expected_time_per_block_secs: u64,
+        max_expected_time_per_block_nanos: u32,
+    }
+
+    impl BorshSerialize for ConnectionParams {
+        fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            InnerConnectionParams {
+                max_expected_time_per_block_secs: self.max_expected_time_per_block.as_secs(),
+                max_expected_time_per_block_nanos: self.max_expected_time_per_block.subsec_nanos(),
+            }
+            .serialize(writer)
+        }
+    }
+
+    impl BorshDeserialize for ConnectionParams {
+        fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+            let inner = InnerConnectionParams::deserialize_reader(reader)?;
+
+            Ok(Self::new(Duration::new(
+                inner.max_expected_time_per_block_secs,
+                inner.max_expected_time_per_block_nanos,
+            )))
+        }
+    }
+}
+
+/// This module encapsulates the workarounds we need to do to implement
+/// `TypeInfo` on `ConnectionParams`, whose `Duration` is encoded as its
+/// seconds followed by its subsecond nanoseconds
+#[cfg(feature = "parity-scale-codec")]
+mod scale_impls {
+    use super::*;
+
+    impl scale_info::TypeInfo for ConnectionParams {
+        type Identity = Self;
+
+        fn type_info() -> scale_info::Type {
+            scale_info::Type::builder()
+                .path(scale_info::Path::new("ConnectionParams", module_path!()))
+                .composite(
+                    scale_info::build::Fields::named()
+                        .field(|f| {
+                            f.ty::<u64>()
+                                .name("max_expected_time_per_block_secs")
+                                .type_name("u64")
+                        })
+                        .field(|f| {
+                            f.ty::<u32>()
+                                .name("max_expected_time_per_block_nanos")
+                                .type_name("u32")
+                        }),
+                )
+        }
+    }
+}
+
+impl Protobuf<RawParams> for ConnectionParams {}
+
+impl From<RawParams> for ConnectionParams {
+    fn from(raw: RawParams) -> Self {
+        Self::new(Duration::from_nanos(raw.max_expected_time_per_block))
+    }
+}
+
+impl From<ConnectionParams> for RawParams {
+    fn from(params: ConnectionParams) -> Self {
+        Self {
+            max_expected_time_per_block: params.max_expected_time_per_block.as_nanos() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::remainder_zero(10, 2, 5)]
+    #[case::remainder_not_zero(10, 3, 4)]
+    #[case::max_expected_zero(10, 0, 0)]
+    #[case::delay_period_zero(0, 2, 0)]
+    #[case::both_zero(0, 0, 0)]
+    #[case::delay_less_than_max(10, 11, 1)]
+    fn test_calculate_block_delay_zero(
+        #[case] delay_period_time: u64,
+        #[case] max_expected_time_per_block: u64,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(
+            calculate_block_delay(
+                &Duration::from_secs(delay_period_time),
+                &Duration::from_secs(max_expected_time_per_block)
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn params_round_trip_in_nanoseconds() {
+        let params = ConnectionParams::default();
+        let raw = RawParams::from(params);
+        assert_eq!(raw.max_expected_time_per_block, 30_000_000_000);
+        assert_eq!(ConnectionParams::from(raw), params);
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn params_borsh_round_trip() {
+        let params = ConnectionParams::new(Duration::new(12, 345));
+
+        let serialized = borsh::to_vec(&params).unwrap();
+        let deserialized =
+            <ConnectionParams as borsh::BorshDeserialize>::try_from_slice(&serialized).unwrap();
+
+        assert_eq!(params, deserialized);
+    }
+}
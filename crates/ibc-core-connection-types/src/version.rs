@@ -2,13 +2,12 @@
 
 use core::fmt::Display;
 
+use ibc_primitives::prelude::*;
+use ibc_primitives::utils::pretty::PrettySlice;
 use ibc_proto::ibc::core::connection::v1::Version as RawVersion;
 use ibc_proto::Protobuf;
 
-use crate::core::ics03_connection::error::ConnectionError;
-use crate::core::ics04_channel::channel::Order;
-use crate::prelude::*;
-use crate::utils::pretty::PrettySlice;
+use crate::error::ConnectionError;
 
 /// Stores the identifier and the features supported by a version
 #[cfg_attr(
@@ -25,6 +24,7 @@ use crate::utils::pretty::PrettySlice;
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Version {
     /// unique version identifier
@@ -94,10 +94,8 @@ impl Default for Version {
     fn default() -> Self {
         Version {
             identifier: "1".to_string(),
-            features: vec![
-                Order::Ordered.as_str().to_owned(),
-                Order::Unordered.as_str().to_owned(),
-            ],
+            // The channel orderings, as `Order::as_str` of ICS-04 yields them
+            features: vec!["ORDER_ORDERED".to_owned(), "ORDER_UNORDERED".to_owned()],
         }
     }
 }
@@ -189,12 +187,12 @@ fn get_feature_set_intersection(
 
 #[cfg(test)]
 mod tests {
+    use ibc_primitives::prelude::*;
     use ibc_proto::ibc::core::connection::v1::Version as RawVersion;
     use test_log::test;
 
-    use crate::core::ics03_connection::error::ConnectionError;
-    use crate::core::ics03_connection::version::{get_compatible_versions, pick_version, Version};
-    use crate::prelude::*;
+    use crate::error::ConnectionError;
+    use crate::version::{get_compatible_versions, pick_version, Version};
 
     fn get_dummy_features() -> Vec<String> {
         vec!["ORDER_RANDOM".to_string(), "ORDER_UNORDERED".to_string()]
@@ -0,0 +1,172 @@
+//! Verification of the delay period of connections, which packet proofs must
+//! wait out after the update of the client they are verified against.
+
+use core::time::Duration;
+
+use ibc_core_client_context::context::ClientValidationContext;
+use ibc_core_client_types::height::Height;
+use ibc_core_connection_types::connection::ConnectionEnd;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::context::ValidationContext;
+use ibc_primitives::timestamp::Timestamp;
+
+/// Verifies that the delay period of `connection_end` has passed on the host
+/// since its client was updated to `packet_proof_height`, both in time and in
+/// the number of blocks derived from it with
+/// [`ValidationContext::block_delay`].
+///
+/// The packet handlers call it before verifying proofs of the counterparty,
+/// and so can the applications verifying proofs of their own.
+pub fn verify_conn_delay_passed<Ctx>(
+    ctx: &Ctx,
+    packet_proof_height: Height,
+    connection_end: &ConnectionEnd,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    // Fetch the current host chain time and height.
+    let current_host_time = ctx.host_timestamp()?;
+    let current_host_height = ctx.host_height()?;
+
+    // Fetch the latest time and height that the counterparty client was updated on the host chain.
+    let client_id = connection_end.client_id();
+    let last_client_update_time = ctx
+        .get_client_validation_context()
+        .client_update_time(client_id, &packet_proof_height)?;
+    let last_client_update_height = ctx
+        .get_client_validation_context()
+        .client_update_height(client_id, &packet_proof_height)?;
+
+    // Fetch the connection delay time and height periods.
+    let conn_delay_time_period = connection_end.delay_period();
+    let conn_delay_height_period = ctx.block_delay(&conn_delay_time_period)?;
+
+    verify_delay_passed(
+        current_host_time,
+        current_host_height,
+        last_client_update_time,
+        last_client_update_height,
+        conn_delay_time_period,
+        conn_delay_height_period,
+    )
+    .map_err(ContextError::ConnectionError)
+}
+
+/// Verifies that at least `delay_period_time` and `delay_period_blocks` have
+/// passed between the update of a client, at `last_client_update_time` and
+/// `last_client_update_height`, and the current time and height of the host.
+///
+/// The delay has passed as soon as the host reaches both the earliest valid
+/// time and the earliest valid height.
+pub fn verify_delay_passed(
+    current_host_time: Timestamp,
+    current_host_height: Height,
+    last_client_update_time: Timestamp,
+    last_client_update_height: Height,
+    delay_period_time: Duration,
+    delay_period_blocks: u64,
+) -> Result<(), ConnectionError> {
+    // Verify that the current host chain time is later than the last client update time
+    let earliest_valid_time = (last_client_update_time + delay_period_time)
+        .map_err(ConnectionError::TimestampOverflow)?;
+    if current_host_time < earliest_valid_time {
+        return Err(ConnectionError::NotEnoughTimeElapsed {
+            current_host_time,
+            earliest_valid_time,
+        });
+    }
+
+    // Verify that the current host chain height is later than the last client update height
+    let earliest_valid_height = last_client_update_height.add(delay_period_blocks);
+    if current_host_height < earliest_valid_height {
+        return Err(ConnectionError::NotEnoughBlocksElapsed {
+            current_host_height,
+            earliest_valid_height,
+        });
+    };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const DELAY_PERIOD_NANOS: u64 = 10_000_000_000;
+    const DELAY_PERIOD_BLOCKS: u64 = 2;
+
+    fn timestamp(nanoseconds: u64) -> Timestamp {
+        Timestamp::from_nanoseconds(nanoseconds).unwrap()
+    }
+
+    fn height(revision_height: u64) -> Height {
+        Height::new(0, revision_height).unwrap()
+    }
+
+    /// Verifies the delay on a host at `elapsed_nanos` and `elapsed_blocks`
+    /// after a client update at 1s and height 10.
+    fn verify(elapsed_nanos: u64, elapsed_blocks: u64) -> Result<(), ConnectionError> {
+        let update_time = 1_000_000_000;
+        let update_height = 10;
+
+        verify_delay_passed(
+            timestamp(update_time + elapsed_nanos),
+            height(update_height + elapsed_blocks),
+            timestamp(update_time),
+            height(update_height),
+            Duration::from_nanos(DELAY_PERIOD_NANOS),
+            DELAY_PERIOD_BLOCKS,
+        )
+    }
+
+    #[rstest]
+    #[case::exactly_passed(DELAY_PERIOD_NANOS, DELAY_PERIOD_BLOCKS)]
+    #[case::well_passed(2 * DELAY_PERIOD_NANOS, 2 * DELAY_PERIOD_BLOCKS)]
+    fn test_delay_passed(#[case] elapsed_nanos: u64, #[case] elapsed_blocks: u64) {
+        verify(elapsed_nanos, elapsed_blocks).unwrap();
+    }
+
+    #[rstest]
+    #[case::one_nanosecond_short(DELAY_PERIOD_NANOS - 1, DELAY_PERIOD_BLOCKS)]
+    #[case::no_time_elapsed(0, DELAY_PERIOD_BLOCKS)]
+    fn test_not_enough_time_elapsed(#[case] elapsed_nanos: u64, #[case] elapsed_blocks: u64) {
+        assert!(matches!(
+            verify(elapsed_nanos, elapsed_blocks),
+            Err(ConnectionError::NotEnoughTimeElapsed { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case::one_block_short(DELAY_PERIOD_NANOS, DELAY_PERIOD_BLOCKS - 1)]
+    #[case::no_block_elapsed(2 * DELAY_PERIOD_NANOS, 0)]
+    fn test_not_enough_blocks_elapsed(#[case] elapsed_nanos: u64, #[case] elapsed_blocks: u64) {
+        let err = verify(elapsed_nanos, elapsed_blocks).unwrap_err();
+        match err {
+            ConnectionError::NotEnoughBlocksElapsed {
+                current_host_height,
+                earliest_valid_height,
+            } => {
+                assert_eq!(current_host_height, height(10 + elapsed_blocks));
+                assert_eq!(earliest_valid_height, height(10 + DELAY_PERIOD_BLOCKS));
+            }
+            _ => panic!("unexpected error: {err}"),
+        }
+    }
+
+    #[test]
+    fn test_zero_delay_passes_immediately() {
+        verify_delay_passed(
+            timestamp(1),
+            height(10),
+            timestamp(1),
+            height(10),
+            Duration::ZERO,
+            0,
+        )
+        .unwrap();
+    }
+}
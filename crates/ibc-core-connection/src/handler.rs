@@ -0,0 +1,107 @@
+//! This module implements the processing logic for ICS3 (connection open
+//! handshake) messages.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_commitment_types::merkle::{MerkleProof, ProofLimits};
+use ibc_core_connection_types::connection::ConnectionEnd;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::context::ValidationContext;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+pub mod conn_open_ack;
+pub mod conn_open_confirm;
+pub mod conn_open_init;
+pub mod conn_open_try;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_ack;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_cancel;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_confirm;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_init;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_timeout;
+#[cfg(feature = "connection-upgrade")]
+pub mod conn_upgrade_try;
+
+/// Validates the representation of the host on the counterparty reported in
+/// `ConnOpenTry` and `ConnOpenAck` messages: the consensus height of the host
+/// must not be ahead of it, and the client state must pass
+/// [`ValidationContext::validate_self_client`], which Tendermint hosts can
+/// implement with `ValidateSelfClientContext`.
+///
+/// Hosts accepting other messages that carry their own client state, e.g.
+/// in custom handshakes, can reuse it to reject bogus representations of
+/// themselves.
+pub fn validate_self_client_on_counterparty<Ctx>(
+    ctx: &Ctx,
+    client_state_of_host_on_counterparty: Any,
+    consensus_height_of_host_on_counterparty: Height,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let host_height = ctx.host_height().map_err(|_| ConnectionError::Other {
+        description: "failed to get host height".to_string(),
+    })?;
+    if consensus_height_of_host_on_counterparty > host_height {
+        // Fail if the consensus height is too advanced.
+        return Err(ConnectionError::InvalidConsensusHeight {
+            target_height: consensus_height_of_host_on_counterparty,
+            current_height: host_height,
+        }
+        .into());
+    }
+
+    ctx.validate_self_client(client_state_of_host_on_counterparty)
+}
+
+/// Turns the failure to verify the proof of the counterparty connection end
+/// in `ConnOpenTry` and `ConnOpenAck` messages into a connection error.
+///
+/// If the connection end the proof claims to prove only differs from
+/// `expected_conn_end` by its delay period, the relayer most likely passed a
+/// delay period other than the one the counterparty committed to, which is
+/// reported as [`ConnectionError::DelayPeriodMismatch`]. Any other failure is
+/// reported as [`ConnectionError::VerifyConnectionState`].
+///
+/// As the proof failed verification, the connection end read from it is
+/// untrusted, and is only used to pick the error.
+pub fn connection_proof_error(
+    proof: &CommitmentProofBytes,
+    expected_conn_end: &ConnectionEnd,
+    client_error: ClientError,
+) -> ConnectionError {
+    let committed_conn_end = MerkleProof::decode(proof, &ProofLimits::default())
+        .ok()
+        .and_then(|merkle_proof| {
+            merkle_proof
+                .claimed_value()
+                .and_then(|value| ConnectionEnd::decode_vec(value).ok())
+        });
+
+    match committed_conn_end {
+        Some(committed_conn_end)
+            if committed_conn_end.delay_period() != expected_conn_end.delay_period() =>
+        {
+            let mut conn_end_with_committed_delay = expected_conn_end.clone();
+            conn_end_with_committed_delay.set_delay_period(committed_conn_end.delay_period());
+
+            if conn_end_with_committed_delay == committed_conn_end {
+                ConnectionError::DelayPeriodMismatch {
+                    expected: expected_conn_end.delay_period(),
+                    committed: committed_conn_end.delay_period(),
+                }
+            } else {
+                ConnectionError::VerifyConnectionState(client_error)
+            }
+        }
+        _ => ConnectionError::VerifyConnectionState(client_error),
+    }
+}
@@ -1,25 +1,26 @@
 //! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenAck`.
 
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection_types::connection::{ConnectionEnd, Counterparty, State};
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::events::OpenAck;
+use ibc_core_connection_types::msgs::conn_open_ack::MsgConnectionOpenAck;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{
+    ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
+};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host_types::identifier::ClientId;
+use ibc_primitives::prelude::*;
 use ibc_proto::Protobuf;
 use prost::Message;
 
-use crate::core::context::ContextError;
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
-use crate::core::ics03_connection::error::ConnectionError;
-use crate::core::ics03_connection::events::OpenAck;
-use crate::core::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
-use crate::core::ics24_host::identifier::ClientId;
-use crate::core::ics24_host::path::{
-    ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
-};
-use crate::core::{ExecutionContext, ValidationContext};
-use crate::prelude::*;
+use crate::handler::{connection_proof_error, validate_self_client_on_counterparty};
 
-pub(crate) fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
+pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
@@ -35,20 +36,11 @@ fn validate_impl<Ctx>(
 where
     Ctx: ValidationContext,
 {
-    ctx_a.validate_message_signer(&msg.signer)?;
-
-    let host_height = ctx_a.host_height().map_err(|_| ConnectionError::Other {
-        description: "failed to get host height".to_string(),
-    })?;
-    if msg.consensus_height_of_a_on_b > host_height {
-        return Err(ConnectionError::InvalidConsensusHeight {
-            target_height: msg.consensus_height_of_a_on_b,
-            current_height: host_height,
-        }
-        .into());
-    }
-
-    ctx_a.validate_self_client(msg.client_state_of_a_on_b.clone())?;
+    validate_self_client_on_counterparty(
+        ctx_a,
+        msg.client_state_of_a_on_b.clone(),
+        msg.consensus_height_of_a_on_b,
+    )?;
 
     msg.version
         .verify_is_supported(vars.conn_end_on_a.versions())?;
@@ -88,21 +80,25 @@ where
                 vars.conn_end_on_a.delay_period(),
             )?;
 
+            let proof_conn_end_on_b = ctx_a.resolve_proof(&msg.proof_conn_end_on_b)?;
+
             client_state_of_b_on_a
                 .verify_membership(
                     prefix_on_b,
-                    &msg.proof_conn_end_on_b,
+                    &proof_conn_end_on_b,
                     consensus_state_of_b_on_a.root(),
                     Path::Connection(ConnectionPath::new(&msg.conn_id_on_b)),
-                    expected_conn_end_on_b.encode_vec(),
+                    expected_conn_end_on_b.clone().encode_vec(),
                 )
-                .map_err(ConnectionError::VerifyConnectionState)?;
+                .map_err(|e| {
+                    connection_proof_error(&proof_conn_end_on_b, &expected_conn_end_on_b, e)
+                })?;
         }
 
         client_state_of_b_on_a
             .verify_membership(
                 prefix_on_b,
-                &msg.proof_client_state_of_a_on_b,
+                &*ctx_a.resolve_proof(&msg.proof_client_state_of_a_on_b)?,
                 consensus_state_of_b_on_a.root(),
                 Path::ClientState(ClientStatePath::new(vars.client_id_on_b())),
                 msg.client_state_of_a_on_b.encode_to_vec(),
@@ -121,7 +117,7 @@ where
         client_state_of_b_on_a
             .verify_membership(
                 prefix_on_b,
-                &msg.proof_consensus_state_of_a_on_b,
+                &*ctx_a.resolve_proof(&msg.proof_consensus_state_of_a_on_b)?,
                 consensus_state_of_b_on_a.root(),
                 Path::ClientConsensusState(client_cons_state_path_on_b),
                 expected_consensus_state_of_a_on_b.encode_vec(),
@@ -135,7 +131,7 @@ where
     Ok(())
 }
 
-pub(crate) fn execute<Ctx>(ctx_a: &mut Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
+pub fn execute<Ctx>(ctx_a: &mut Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
 {
@@ -1,22 +1,21 @@
 //! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenConfirm`.
 
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection_types::connection::{ConnectionEnd, Counterparty, State};
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::events::OpenConfirm;
+use ibc_core_connection_types::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{ClientConsensusStatePath, ConnectionPath, Path};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host_types::identifier::{ClientId, ConnectionId};
+use ibc_primitives::prelude::*;
 use ibc_proto::Protobuf;
 
-use crate::core::context::ContextError;
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
-use crate::core::ics03_connection::error::ConnectionError;
-use crate::core::ics03_connection::events::OpenConfirm;
-use crate::core::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
-use crate::core::ics24_host::identifier::{ClientId, ConnectionId};
-use crate::core::ics24_host::path::{ClientConsensusStatePath, ConnectionPath, Path};
-use crate::core::{ExecutionContext, ValidationContext};
-use crate::prelude::*;
-
-pub(crate) fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgConnectionOpenConfirm) -> Result<(), ContextError>
+pub fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgConnectionOpenConfirm) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
@@ -32,8 +31,6 @@ fn validate_impl<Ctx>(
 where
     Ctx: ValidationContext,
 {
-    ctx_b.validate_message_signer(&msg.signer)?;
-
     let conn_end_on_b = vars.conn_end_on_b();
 
     conn_end_on_b.verify_state_matches(&State::TryOpen)?;
@@ -77,7 +74,7 @@ where
         client_state_of_a_on_b
             .verify_membership(
                 prefix_on_a,
-                &msg.proof_conn_end_on_a,
+                &*ctx_b.resolve_proof(&msg.proof_conn_end_on_a)?,
                 consensus_state_of_a_on_b.root(),
                 Path::Connection(ConnectionPath::new(conn_id_on_a)),
                 expected_conn_end_on_a.encode_vec(),
@@ -88,10 +85,7 @@ where
     Ok(())
 }
 
-pub(crate) fn execute<Ctx>(
-    ctx_b: &mut Ctx,
-    msg: &MsgConnectionOpenConfirm,
-) -> Result<(), ContextError>
+pub fn execute<Ctx>(ctx_b: &mut Ctx, msg: &MsgConnectionOpenConfirm) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
 {
@@ -1,22 +1,20 @@
 //! Protocol logic specific to ICS3 messages of type `MsgConnectionOpenInit`.
-use crate::core::context::ContextError;
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::ClientStateValidation;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
-use crate::core::ics03_connection::events::OpenInit;
-use crate::core::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
-use crate::core::ics24_host::identifier::ConnectionId;
-use crate::core::ics24_host::path::{ClientConnectionPath, ConnectionPath};
-use crate::core::{ExecutionContext, ValidationContext};
-use crate::prelude::*;
+use ibc_core_client_context::client_state::ClientStateValidation;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection_types::connection::{ConnectionEnd, Counterparty, State};
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::events::OpenInit;
+use ibc_core_connection_types::msgs::conn_open_init::{ConnOpenInitResult, MsgConnectionOpenInit};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{ClientConnectionPath, ConnectionPath};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
 
-pub(crate) fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenInit) -> Result<(), ContextError>
+pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenInit) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
-    ctx_a.validate_message_signer(&msg.signer)?;
-
     // An IBC client running on the local (host) chain should exist.
     let client_state_of_b_on_a = ctx_a.client_state(&msg.client_id_on_a)?;
 
@@ -35,7 +33,10 @@ where
     Ok(())
 }
 
-pub(crate) fn execute<Ctx>(ctx_a: &mut Ctx, msg: MsgConnectionOpenInit) -> Result<(), ContextError>
+pub fn execute<Ctx>(
+    ctx_a: &mut Ctx,
+    msg: MsgConnectionOpenInit,
+) -> Result<ConnOpenInitResult, ContextError>
 where
     Ctx: ExecutionContext,
 {
@@ -59,7 +60,10 @@ where
     )?;
 
     // Construct the identifier for the new connection.
-    let conn_id_on_a = ConnectionId::new(ctx_a.connection_counter()?);
+    let conn_id_on_a = ctx_a
+        .identifier_allocator()
+        .allocate_connection_id(ctx_a.connection_counter()?)
+        .map_err(ConnectionError::InvalidIdentifier)?;
 
     ctx_a.log_message(format!(
         "success: conn_open_init: generated new connection identifier: {conn_id_on_a}"
@@ -84,5 +88,5 @@ where
     )?;
     ctx_a.store_connection(&ConnectionPath::new(&conn_id_on_a), conn_end_on_a)?;
 
-    Ok(())
+    Ok(ConnOpenInitResult { conn_id_on_a })
 }
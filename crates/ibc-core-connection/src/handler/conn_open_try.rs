@@ -1,25 +1,26 @@
 //! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenTry`.
 
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection_types::connection::{ConnectionEnd, Counterparty, State};
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::events::OpenTry;
+use ibc_core_connection_types::msgs::conn_open_try::{ConnOpenTryResult, MsgConnectionOpenTry};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{
+    ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
+};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host_types::identifier::{ClientId, ConnectionId};
+use ibc_primitives::prelude::*;
 use ibc_proto::Protobuf;
 use prost::Message;
 
-use crate::core::context::ContextError;
-use crate::core::events::{IbcEvent, MessageEvent};
-use crate::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
-use crate::core::ics02_client::consensus_state::ConsensusState;
-use crate::core::ics02_client::error::ClientError;
-use crate::core::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
-use crate::core::ics03_connection::error::ConnectionError;
-use crate::core::ics03_connection::events::OpenTry;
-use crate::core::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
-use crate::core::ics24_host::identifier::{ClientId, ConnectionId};
-use crate::core::ics24_host::path::{
-    ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
-};
-use crate::core::{ExecutionContext, ValidationContext};
-use crate::prelude::*;
+use crate::handler::{connection_proof_error, validate_self_client_on_counterparty};
 
-pub(crate) fn validate<Ctx>(ctx_b: &Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
+pub fn validate<Ctx>(ctx_b: &Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
@@ -35,21 +36,11 @@ fn validate_impl<Ctx>(
 where
     Ctx: ValidationContext,
 {
-    ctx_b.validate_message_signer(&msg.signer)?;
-
-    ctx_b.validate_self_client(msg.client_state_of_b_on_a.clone())?;
-
-    let host_height = ctx_b.host_height().map_err(|_| ConnectionError::Other {
-        description: "failed to get host height".to_string(),
-    })?;
-    if msg.consensus_height_of_b_on_a > host_height {
-        // Fail if the consensus height is too advanced.
-        return Err(ConnectionError::InvalidConsensusHeight {
-            target_height: msg.consensus_height_of_b_on_a,
-            current_height: host_height,
-        }
-        .into());
-    }
+    validate_self_client_on_counterparty(
+        ctx_b,
+        msg.client_state_of_b_on_a.clone(),
+        msg.consensus_height_of_b_on_a,
+    )?;
 
     let client_id_on_a = msg.counterparty.client_id();
 
@@ -82,21 +73,25 @@ where
                 msg.delay_period,
             )?;
 
+            let proof_conn_end_on_a = ctx_b.resolve_proof(&msg.proof_conn_end_on_a)?;
+
             client_state_of_a_on_b
                 .verify_membership(
                     prefix_on_a,
-                    &msg.proof_conn_end_on_a,
+                    &proof_conn_end_on_a,
                     consensus_state_of_a_on_b.root(),
                     Path::Connection(ConnectionPath::new(&vars.conn_id_on_a)),
-                    expected_conn_end_on_a.encode_vec(),
+                    expected_conn_end_on_a.clone().encode_vec(),
                 )
-                .map_err(ConnectionError::VerifyConnectionState)?;
+                .map_err(|e| {
+                    connection_proof_error(&proof_conn_end_on_a, &expected_conn_end_on_a, e)
+                })?;
         }
 
         client_state_of_a_on_b
             .verify_membership(
                 prefix_on_a,
-                &msg.proof_client_state_of_b_on_a,
+                &*ctx_b.resolve_proof(&msg.proof_client_state_of_b_on_a)?,
                 consensus_state_of_a_on_b.root(),
                 Path::ClientState(ClientStatePath::new(client_id_on_a)),
                 msg.client_state_of_b_on_a.encode_to_vec(),
@@ -115,7 +110,7 @@ where
         client_state_of_a_on_b
             .verify_membership(
                 prefix_on_a,
-                &msg.proof_consensus_state_of_b_on_a,
+                &*ctx_b.resolve_proof(&msg.proof_consensus_state_of_b_on_a)?,
                 consensus_state_of_a_on_b.root(),
                 Path::ClientConsensusState(client_cons_state_path_on_a),
                 expected_consensus_state_of_b_on_a.encode_vec(),
@@ -129,7 +124,10 @@ where
     Ok(())
 }
 
-pub(crate) fn execute<Ctx>(ctx_b: &mut Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
+pub fn execute<Ctx>(
+    ctx_b: &mut Ctx,
+    msg: MsgConnectionOpenTry,
+) -> Result<ConnOpenTryResult, ContextError>
 where
     Ctx: ExecutionContext,
 {
@@ -141,7 +139,7 @@ fn execute_impl<Ctx>(
     ctx_b: &mut Ctx,
     msg: MsgConnectionOpenTry,
     vars: LocalVars,
-) -> Result<(), ContextError>
+) -> Result<ConnOpenTryResult, ContextError>
 where
     Ctx: ExecutionContext,
 {
@@ -167,7 +165,9 @@ where
     )?;
     ctx_b.store_connection(&ConnectionPath::new(&vars.conn_id_on_b), vars.conn_end_on_b)?;
 
-    Ok(())
+    Ok(ConnOpenTryResult {
+        conn_id_on_b: vars.conn_id_on_b,
+    })
 }
 
 struct LocalVars {
@@ -185,7 +185,10 @@ impl LocalVars {
         let version_on_b = ctx_b.pick_version(&msg.versions_on_a)?;
 
         Ok(Self {
-            conn_id_on_b: ConnectionId::new(ctx_b.connection_counter()?),
+            conn_id_on_b: ctx_b
+                .identifier_allocator()
+                .allocate_connection_id(ctx_b.connection_counter()?)
+                .map_err(ConnectionError::InvalidIdentifier)?,
             conn_end_on_b: ConnectionEnd::new(
                 State::TryOpen,
                 msg.client_id_on_b.clone(),
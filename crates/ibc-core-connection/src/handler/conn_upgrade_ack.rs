@@ -0,0 +1,78 @@
+//! Protocol logic specific to processing ICS3 messages of type `MsgConnectionUpgradeAck`.
+
+use ibc_core_connection_types::connection::State;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::events::UpgradeAck;
+use ibc_core_connection_types::msgs::conn_upgrade_ack::MsgConnectionUpgradeAck;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{ConnectionPath, ConnectionUpgradePath, Path};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host::upgrade::{
+    pending_upgrade, upgrade_context_mut, verify_counterparty_state, verify_not_timed_out,
+};
+use ibc_primitives::prelude::*;
+
+/// Checks that the connection has an upgrade in progress, which it proposed,
+/// which is not timed out, and to which its counterparty committed.
+pub fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgConnectionUpgradeAck) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let conn_end_on_a = ctx_a.connection_end(&msg.conn_id_on_a)?;
+    conn_end_on_a.verify_state_matches(&State::Open)?;
+
+    let upgrade = pending_upgrade(ctx_a, &msg.conn_id_on_a)?;
+    if !upgrade.proposed_by_host {
+        return Err(ConnectionError::UpgradeProposedByCounterparty {
+            connection_id: msg.conn_id_on_a.clone(),
+        }
+        .into());
+    }
+    verify_not_timed_out(ctx_a, &msg.conn_id_on_a, &upgrade)?;
+
+    let conn_id_on_b = conn_end_on_a
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::InvalidCounterparty)?;
+
+    verify_counterparty_state(
+        ctx_a,
+        &conn_end_on_a,
+        &msg.proof_upgrade_on_b,
+        msg.proof_height_on_b,
+        Path::ConnectionUpgrade(ConnectionUpgradePath::new(conn_id_on_b)),
+        upgrade.encode_vec(),
+        ConnectionError::VerifyConnectionUpgrade,
+    )
+}
+
+/// Applies the upgrade in progress to the connection.
+pub fn execute<Ctx>(ctx_a: &mut Ctx, msg: &MsgConnectionUpgradeAck) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let mut conn_end_on_a = ctx_a.connection_end(&msg.conn_id_on_a)?;
+    let upgrade = pending_upgrade(ctx_a, &msg.conn_id_on_a)?;
+    let conn_id_on_b = conn_end_on_a
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::InvalidCounterparty)?
+        .clone();
+
+    conn_end_on_a.set_delay_period(upgrade.delay_period);
+    ctx_a.store_connection(&ConnectionPath::new(&msg.conn_id_on_a), conn_end_on_a)?;
+    upgrade_context_mut(ctx_a)?
+        .delete_connection_upgrade(&ConnectionUpgradePath::new(&msg.conn_id_on_a))?;
+
+    let event = IbcEvent::UpgradeAckConnection(UpgradeAck::new(
+        msg.conn_id_on_a.clone(),
+        conn_id_on_b,
+        upgrade.delay_period,
+    ));
+    ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
+    ctx_a.emit_ibc_event(event)?;
+    ctx_a.log_message("success: conn_upgrade_ack verification passed".to_string())?;
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+//! Protocol logic specific to processing ICS3 messages of type `MsgConnectionUpgradeCancel`.
+
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::events::UpgradeCancel;
+use ibc_core_connection_types::msgs::conn_upgrade_cancel::MsgConnectionUpgradeCancel;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::ConnectionUpgradePath;
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host::upgrade::{pending_upgrade, upgrade_context, upgrade_context_mut};
+use ibc_primitives::prelude::*;
+
+/// Checks that the signer of `msg` may upgrade connections, and that the
+/// connection has an upgrade in progress which it proposed.
+///
+/// Should the counterparty have committed to the upgrade already, it drops
+/// it in turn with `ConnUpgradeTimeout` once timed out.
+pub fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgConnectionUpgradeCancel) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    upgrade_context(ctx_a)?.validate_upgrade_authority(&msg.signer)?;
+
+    let upgrade = pending_upgrade(ctx_a, &msg.conn_id_on_a)?;
+    if !upgrade.proposed_by_host {
+        return Err(ConnectionError::UpgradeProposedByCounterparty {
+            connection_id: msg.conn_id_on_a.clone(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Drops the upgrade in progress of the connection.
+pub fn execute<Ctx>(ctx_a: &mut Ctx, msg: &MsgConnectionUpgradeCancel) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let conn_end_on_a = ctx_a.connection_end(&msg.conn_id_on_a)?;
+    let upgrade = pending_upgrade(ctx_a, &msg.conn_id_on_a)?;
+    let conn_id_on_b = conn_end_on_a
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::InvalidCounterparty)?
+        .clone();
+
+    upgrade_context_mut(ctx_a)?
+        .delete_connection_upgrade(&ConnectionUpgradePath::new(&msg.conn_id_on_a))?;
+
+    let event = IbcEvent::UpgradeCancelConnection(UpgradeCancel::new(
+        msg.conn_id_on_a.clone(),
+        conn_id_on_b,
+        upgrade.delay_period,
+    ));
+    ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
+    ctx_a.emit_ibc_event(event)?;
+    ctx_a.log_message("success: conn_upgrade_cancel verification passed".to_string())?;
+
+    Ok(())
+}
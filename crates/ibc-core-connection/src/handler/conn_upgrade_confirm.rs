@@ -0,0 +1,88 @@
+//! Protocol logic specific to processing ICS3 messages of type `MsgConnectionUpgradeConfirm`.
+
+use ibc_core_connection_types::connection::State;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::events::UpgradeConfirm;
+use ibc_core_connection_types::msgs::conn_upgrade_confirm::MsgConnectionUpgradeConfirm;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{ConnectionPath, ConnectionUpgradePath, Path};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host::upgrade::{
+    counterparty_conn_end, pending_upgrade, upgrade_context_mut, verify_counterparty_state,
+};
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+
+/// Checks that the connection has an upgrade in progress, which its
+/// counterparty proposed and applied to its own end.
+///
+/// The upgrade is not checked for timeout: once the counterparty applied it,
+/// the connection has to follow.
+pub fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgConnectionUpgradeConfirm) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let conn_end_on_b = ctx_b.connection_end(&msg.conn_id_on_b)?;
+    conn_end_on_b.verify_state_matches(&State::Open)?;
+
+    let upgrade = pending_upgrade(ctx_b, &msg.conn_id_on_b)?;
+    if upgrade.proposed_by_host {
+        return Err(ConnectionError::UpgradeProposedByHost {
+            connection_id: msg.conn_id_on_b.clone(),
+        }
+        .into());
+    }
+
+    let conn_id_on_a = conn_end_on_b
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::InvalidCounterparty)?;
+
+    let expected_conn_end_on_a = counterparty_conn_end(
+        ctx_b,
+        &msg.conn_id_on_b,
+        &conn_end_on_b,
+        upgrade.delay_period,
+    )?;
+
+    verify_counterparty_state(
+        ctx_b,
+        &conn_end_on_b,
+        &msg.proof_conn_end_on_a,
+        msg.proof_height_on_a,
+        Path::Connection(ConnectionPath::new(conn_id_on_a)),
+        expected_conn_end_on_a.encode_vec(),
+        ConnectionError::VerifyConnectionState,
+    )
+}
+
+/// Applies the upgrade in progress to the connection.
+pub fn execute<Ctx>(ctx_b: &mut Ctx, msg: &MsgConnectionUpgradeConfirm) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let mut conn_end_on_b = ctx_b.connection_end(&msg.conn_id_on_b)?;
+    let upgrade = pending_upgrade(ctx_b, &msg.conn_id_on_b)?;
+    let conn_id_on_a = conn_end_on_b
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::InvalidCounterparty)?
+        .clone();
+
+    conn_end_on_b.set_delay_period(upgrade.delay_period);
+    ctx_b.store_connection(&ConnectionPath::new(&msg.conn_id_on_b), conn_end_on_b)?;
+    upgrade_context_mut(ctx_b)?
+        .delete_connection_upgrade(&ConnectionUpgradePath::new(&msg.conn_id_on_b))?;
+
+    let event = IbcEvent::UpgradeConfirmConnection(UpgradeConfirm::new(
+        msg.conn_id_on_b.clone(),
+        conn_id_on_a,
+        upgrade.delay_period,
+    ));
+    ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
+    ctx_b.emit_ibc_event(event)?;
+    ctx_b.log_message("success: conn_upgrade_confirm verification passed".to_string())?;
+
+    Ok(())
+}
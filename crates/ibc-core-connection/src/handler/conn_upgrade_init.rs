@@ -0,0 +1,82 @@
+//! Protocol logic specific to processing ICS3 messages of type `MsgConnectionUpgradeInit`.
+
+use ibc_core_connection_types::connection::State;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::events::UpgradeInit;
+use ibc_core_connection_types::msgs::conn_upgrade_init::MsgConnectionUpgradeInit;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::ConnectionUpgradePath;
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host::upgrade::{
+    upgrade_context, upgrade_context_mut, verify_not_timed_out, ConnectionUpgrade,
+};
+use ibc_primitives::prelude::*;
+
+/// Checks that the signer of `msg` may upgrade connections, that the
+/// connection is open, has no upgrade in progress, and would see its delay
+/// period change, and that the upgrade is not timed out already.
+pub fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgConnectionUpgradeInit) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let upgrades = upgrade_context(ctx_a)?;
+    upgrades.validate_upgrade_authority(&msg.signer)?;
+
+    let conn_end_on_a = ctx_a.connection_end(&msg.conn_id_on_a)?;
+    conn_end_on_a.verify_state_matches(&State::Open)?;
+
+    if upgrades.connection_upgrade(&msg.conn_id_on_a)?.is_some() {
+        return Err(ConnectionError::UpgradeInProgress {
+            connection_id: msg.conn_id_on_a.clone(),
+        }
+        .into());
+    }
+
+    if conn_end_on_a.delay_period() == msg.delay_period {
+        return Err(ConnectionError::UnchangedUpgrade {
+            connection_id: msg.conn_id_on_a.clone(),
+        }
+        .into());
+    }
+
+    if !msg.timeout_timestamp.is_set() {
+        return Err(ConnectionError::MissingUpgradeTimeout {
+            connection_id: msg.conn_id_on_a.clone(),
+        }
+        .into());
+    }
+    verify_not_timed_out(
+        ctx_a,
+        &msg.conn_id_on_a,
+        &ConnectionUpgrade::proposed(msg.delay_period, msg.timeout_timestamp),
+    )
+}
+
+/// Records the upgrade proposed by `msg`, for the counterparty to commit to.
+pub fn execute<Ctx>(ctx_a: &mut Ctx, msg: &MsgConnectionUpgradeInit) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let conn_end_on_a = ctx_a.connection_end(&msg.conn_id_on_a)?;
+    let conn_id_on_b = conn_end_on_a
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::InvalidCounterparty)?;
+
+    upgrade_context_mut(ctx_a)?.store_connection_upgrade(
+        &ConnectionUpgradePath::new(&msg.conn_id_on_a),
+        ConnectionUpgrade::proposed(msg.delay_period, msg.timeout_timestamp),
+    )?;
+
+    let event = IbcEvent::UpgradeInitConnection(UpgradeInit::new(
+        msg.conn_id_on_a.clone(),
+        conn_id_on_b.clone(),
+        msg.delay_period,
+    ));
+    ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
+    ctx_a.emit_ibc_event(event)?;
+    ctx_a.log_message("success: conn_upgrade_init verification passed".to_string())?;
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Protocol logic specific to processing ICS3 messages of type `MsgConnectionUpgradeTimeout`.
+
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_connection_types::connection::State;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::events::UpgradeTimeout;
+use ibc_core_connection_types::msgs::conn_upgrade_timeout::MsgConnectionUpgradeTimeout;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{
+    ClientConsensusStatePath, ConnectionPath, ConnectionUpgradePath, Path,
+};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host::upgrade::{
+    counterparty_conn_end, pending_upgrade, upgrade_context_mut, verify_counterparty_state,
+};
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+
+/// Checks that the connection has an upgrade in progress, that the
+/// counterparty reached its timeout as of the proof height, and that the
+/// counterparty did not apply it by then.
+pub fn validate<Ctx>(ctx: &Ctx, msg: &MsgConnectionUpgradeTimeout) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let conn_end = ctx.connection_end(&msg.conn_id)?;
+    conn_end.verify_state_matches(&State::Open)?;
+
+    let upgrade = pending_upgrade(ctx, &msg.conn_id)?;
+
+    let counterparty_timestamp = ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            conn_end.client_id(),
+            &msg.proof_height_on_counterparty,
+        ))?
+        .timestamp();
+    if !upgrade.timed_out(&counterparty_timestamp) {
+        return Err(ConnectionError::UpgradeNotTimedOut {
+            connection_id: msg.conn_id.clone(),
+            timeout_timestamp: upgrade.timeout_timestamp,
+            counterparty_timestamp,
+        }
+        .into());
+    }
+
+    let counterparty_conn_id = conn_end
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::InvalidCounterparty)?;
+
+    let expected_counterparty_conn_end =
+        counterparty_conn_end(ctx, &msg.conn_id, &conn_end, conn_end.delay_period())?;
+
+    verify_counterparty_state(
+        ctx,
+        &conn_end,
+        &msg.proof_conn_end_on_counterparty,
+        msg.proof_height_on_counterparty,
+        Path::Connection(ConnectionPath::new(counterparty_conn_id)),
+        expected_counterparty_conn_end.encode_vec(),
+        ConnectionError::VerifyConnectionState,
+    )
+}
+
+/// Drops the upgrade in progress of the connection.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: &MsgConnectionUpgradeTimeout) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let conn_end = ctx.connection_end(&msg.conn_id)?;
+    let upgrade = pending_upgrade(ctx, &msg.conn_id)?;
+    let counterparty_conn_id = conn_end
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::InvalidCounterparty)?
+        .clone();
+
+    upgrade_context_mut(ctx)?
+        .delete_connection_upgrade(&ConnectionUpgradePath::new(&msg.conn_id))?;
+
+    let event = IbcEvent::UpgradeTimeoutConnection(UpgradeTimeout::new(
+        msg.conn_id.clone(),
+        counterparty_conn_id,
+        upgrade.delay_period,
+    ));
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
+    ctx.emit_ibc_event(event)?;
+    ctx.log_message("success: conn_upgrade_timeout verification passed".to_string())?;
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! Protocol logic specific to processing ICS3 messages of type `MsgConnectionUpgradeTry`.
+
+use ibc_core_connection_types::connection::State;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::events::UpgradeTry;
+use ibc_core_connection_types::msgs::conn_upgrade_try::MsgConnectionUpgradeTry;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::path::{ConnectionUpgradePath, Path};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host::upgrade::{
+    upgrade_context, upgrade_context_mut, verify_counterparty_state, verify_not_timed_out,
+    ConnectionUpgrade,
+};
+use ibc_primitives::prelude::*;
+
+/// Checks that the connection is open, has no upgrade in progress, and that
+/// its counterparty proposed the upgrade of `msg`, which is not timed out.
+pub fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgConnectionUpgradeTry) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let conn_end_on_b = ctx_b.connection_end(&msg.conn_id_on_b)?;
+    conn_end_on_b.verify_state_matches(&State::Open)?;
+
+    if upgrade_context(ctx_b)?
+        .connection_upgrade(&msg.conn_id_on_b)?
+        .is_some()
+    {
+        return Err(ConnectionError::UpgradeInProgress {
+            connection_id: msg.conn_id_on_b.clone(),
+        }
+        .into());
+    }
+
+    let upgrade = ConnectionUpgrade::accepted(msg.delay_period, msg.timeout_timestamp);
+    verify_not_timed_out(ctx_b, &msg.conn_id_on_b, &upgrade)?;
+
+    let conn_id_on_a = conn_end_on_b
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::InvalidCounterparty)?;
+
+    verify_counterparty_state(
+        ctx_b,
+        &conn_end_on_b,
+        &msg.proof_upgrade_on_a,
+        msg.proof_height_on_a,
+        Path::ConnectionUpgrade(ConnectionUpgradePath::new(conn_id_on_a)),
+        upgrade.encode_vec(),
+        ConnectionError::VerifyConnectionUpgrade,
+    )
+}
+
+/// Records the upgrade of `msg`, for the counterparty to apply.
+pub fn execute<Ctx>(ctx_b: &mut Ctx, msg: &MsgConnectionUpgradeTry) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let conn_end_on_b = ctx_b.connection_end(&msg.conn_id_on_b)?;
+    let conn_id_on_a = conn_end_on_b
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::InvalidCounterparty)?;
+
+    upgrade_context_mut(ctx_b)?.store_connection_upgrade(
+        &ConnectionUpgradePath::new(&msg.conn_id_on_b),
+        ConnectionUpgrade::accepted(msg.delay_period, msg.timeout_timestamp),
+    )?;
+
+    let event = IbcEvent::UpgradeTryConnection(UpgradeTry::new(
+        msg.conn_id_on_b.clone(),
+        conn_id_on_a.clone(),
+        msg.delay_period,
+    ));
+    ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
+    ctx_b.emit_ibc_event(event)?;
+    ctx_b.log_message("success: conn_upgrade_try verification passed".to_string())?;
+
+    Ok(())
+}
@@ -0,0 +1,177 @@
+//! Defines the errors of the IBC handlers
+
+use derive_more::From;
+use displaydoc::Display;
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::Height;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_host_types::identifier::PortId;
+use ibc_primitives::impl_error_sources;
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
+
+use crate::module::{Binding, ModuleId};
+
+/// Top-level error
+#[derive(Debug, Display, From)]
+pub enum ContextError {
+    /// ICS02 Client error: {0}
+    ClientError(ClientError),
+    /// ICS03 Connection error: {0}
+    ConnectionError(ConnectionError),
+    /// ICS04 Channel error: {0}
+    ChannelError(ChannelError),
+    /// ICS04 Packet error: {0}
+    PacketError(PacketError),
+}
+
+impl From<ContextError> for ClientError {
+    fn from(context_error: ContextError) -> Self {
+        match context_error {
+            ContextError::ClientError(e) => e,
+            _ => ClientError::Other {
+                description: context_error.to_string(),
+            },
+        }
+    }
+}
+
+impl_error_sources!(ContextError {
+    ibc {
+        Self::ClientError(e) => e,
+        Self::ConnectionError(e) => e,
+        Self::ChannelError(e) => e,
+        Self::PacketError(e) => e,
+    }
+});
+
+/// Error returned from entrypoint functions `dispatch`, `validate` and
+/// `execute`.
+#[derive(Debug, Display)]
+pub enum RouterError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// unknown type URL `{url}`
+    UnknownMessageTypeUrl { url: String },
+    /// the message is malformed and cannot be decoded error: `{reason}`
+    MalformedMessageBytes { reason: String },
+    /// port `{port_id}` is unknown
+    UnknownPort { port_id: PortId },
+    /// module not found
+    ModuleNotFound,
+    /// {binding} is already bound to module `{bound_module_id}`, cannot bind it to module `{module_id}`
+    BindingConflict {
+        binding: Binding,
+        bound_module_id: ModuleId,
+        module_id: ModuleId,
+    },
+    /// unknown client state type `{client_state_type}`, known types are: `{known_types:?}`
+    UnknownClientStateType {
+        client_state_type: String,
+        known_types: Vec<String>,
+    },
+    /// message rejected by the host: `{reason}`
+    MessageRejected { reason: String },
+    /// message expired at height `{expiry_height}`, host is at height `{host_height}`
+    MessageExpiredAtHeight {
+        expiry_height: Height,
+        host_height: Height,
+    },
+    /// message expired at timestamp `{expiry_timestamp}`, host is at timestamp `{host_timestamp}`
+    MessageExpiredAtTimestamp {
+        expiry_timestamp: Timestamp,
+        host_timestamp: Timestamp,
+    },
+}
+
+impl From<ContextError> for RouterError {
+    fn from(error: ContextError) -> Self {
+        Self::ContextError(error)
+    }
+}
+
+impl_error_sources!(RouterError {
+    ibc {
+        Self::ContextError(e) => e,
+    }
+});
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_channel_types::packet::Sequence;
+    use ibc_core_commitment_types::error::CommitmentError;
+    use ibc_core_host_types::identifier::IdentifierError;
+    use ibc_primitives::error::{root_cause, sources, IbcError};
+
+    use super::*;
+
+    fn packet_verification_error() -> ContextError {
+        PacketError::Channel(ChannelError::PacketVerificationFailed {
+            sequence: Sequence::from(1),
+            client_error: ClientError::InvalidCommitmentProof(CommitmentError::EmptyMerkleProof),
+        })
+        .into()
+    }
+
+    fn source_messages(error: &dyn IbcError) -> Vec<String> {
+        sources(error).map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn packet_error_keeps_its_sources() {
+        let error = packet_verification_error();
+
+        assert_eq!(sources(&error).count(), 5);
+        assert_eq!(
+            root_cause(&error).to_string(),
+            CommitmentError::EmptyMerkleProof.to_string()
+        );
+        assert!(matches!(
+            error,
+            ContextError::PacketError(PacketError::Channel(_))
+        ));
+    }
+
+    #[test]
+    fn router_error_keeps_the_sources_of_its_context_error() {
+        let context_error = ContextError::from(ConnectionError::Client(
+            ClientError::InvalidClientIdentifier(IdentifierError::Empty),
+        ));
+        let context_messages = source_messages(&context_error);
+
+        let router_error = RouterError::from(context_error);
+        let router_messages = source_messages(&router_error);
+
+        assert_eq!(router_messages.len(), 5);
+        assert_eq!(router_messages[1..], context_messages[..]);
+        assert_eq!(
+            root_cause(&router_error).to_string(),
+            IdentifierError::Empty.to_string()
+        );
+    }
+
+    #[test]
+    fn error_without_source_is_its_own_root_cause() {
+        let error = ChannelError::MissingCounterparty;
+
+        assert_eq!(sources(&error).count(), 1);
+        assert_eq!(root_cause(&error).to_string(), error.to_string());
+    }
+
+    /// Under `std`, `std::error::Error::source` reaches the same causes.
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_sources_match() {
+        let error = packet_verification_error();
+
+        let mut std_messages = Vec::new();
+        let mut next: Option<&(dyn std::error::Error + 'static)> = Some(&error);
+        while let Some(e) = next {
+            std_messages.push(e.to_string());
+            next = e.source();
+        }
+
+        assert_eq!(std_messages, source_messages(&error));
+    }
+}
@@ -1,18 +1,21 @@
 //! Events emitted during message handling
 
+pub mod commitment;
+pub mod subscription;
+pub mod tx;
+
 use core::convert::{TryFrom, TryInto};
 
 use displaydoc::Display;
+use ibc_core_channel_types::{error as channel_error, events as ChannelEvents};
+use ibc_core_client_types::{error as client_error, events as ClientEvents};
+use ibc_core_connection_types::{error as connection_error, events as ConnectionEvents};
+use ibc_core_host_types::identifier::IdentifierError;
+use ibc_primitives::impl_error_sources;
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::ParseTimestampError;
 use tendermint::abci;
 
-use super::ics24_host::identifier::IdentifierError;
-use crate::core::ics02_client::error as client_error;
-use crate::core::ics02_client::events::{self as ClientEvents};
-use crate::core::ics03_connection::{error as connection_error, events as ConnectionEvents};
-use crate::core::ics04_channel::{error as channel_error, events as ChannelEvents};
-use crate::core::timestamp::ParseTimestampError;
-use crate::prelude::*;
-
 /// All error variants related to IBC events
 #[derive(Debug, Display)]
 pub enum Error {
@@ -32,24 +35,26 @@ pub enum Error {
     Decode(prost::DecodeError),
     /// incorrect event type: `{event}`
     IncorrectEventType { event: String },
+    /// missing event attribute: `{key}`
+    MissingAttribute { key: String },
+    /// invalid value `{value}` of event attribute `{key}`
+    InvalidAttribute { key: String, value: String },
     /// module event cannot use core event types: `{event:?}`
     MalformedModuleEvent { event: ModuleEvent },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self {
-            Self::Parse(e) => Some(e),
-            Self::Client(e) => Some(e),
-            Self::Connection(e) => Some(e),
-            Self::Channel(e) => Some(e),
-            Self::Timestamp(e) => Some(e),
-            Self::Decode(e) => Some(e),
-            _ => None,
-        }
+impl_error_sources!(Error {
+    ibc {
+        Self::Parse(e) => e,
+        Self::Client(e) => e,
+        Self::Connection(e) => e,
+        Self::Channel(e) => e,
+        Self::Timestamp(e) => e,
     }
-}
+    std {
+        Self::Decode(e) => e,
+    }
+});
 
 const MESSAGE_EVENT: &str = "message";
 
@@ -73,11 +78,19 @@ pub enum IbcEvent {
     UpdateClient(ClientEvents::UpdateClient),
     UpgradeClient(ClientEvents::UpgradeClient),
     ClientMisbehaviour(ClientEvents::ClientMisbehaviour),
+    ClientNearExpiry(ClientEvents::ClientNearExpiry),
+    ConflictingHeader(ClientEvents::ConflictingHeader),
 
     OpenInitConnection(ConnectionEvents::OpenInit),
     OpenTryConnection(ConnectionEvents::OpenTry),
     OpenAckConnection(ConnectionEvents::OpenAck),
     OpenConfirmConnection(ConnectionEvents::OpenConfirm),
+    UpgradeInitConnection(ConnectionEvents::UpgradeInit),
+    UpgradeTryConnection(ConnectionEvents::UpgradeTry),
+    UpgradeAckConnection(ConnectionEvents::UpgradeAck),
+    UpgradeConfirmConnection(ConnectionEvents::UpgradeConfirm),
+    UpgradeTimeoutConnection(ConnectionEvents::UpgradeTimeout),
+    UpgradeCancelConnection(ConnectionEvents::UpgradeCancel),
 
     OpenInitChannel(ChannelEvents::OpenInit),
     OpenTryChannel(ChannelEvents::OpenTry),
@@ -106,10 +119,18 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::UpdateClient(event) => event.into(),
             IbcEvent::UpgradeClient(event) => event.into(),
             IbcEvent::ClientMisbehaviour(event) => event.into(),
+            IbcEvent::ClientNearExpiry(event) => event.into(),
+            IbcEvent::ConflictingHeader(event) => event.into(),
             IbcEvent::OpenInitConnection(event) => event.into(),
             IbcEvent::OpenTryConnection(event) => event.into(),
             IbcEvent::OpenAckConnection(event) => event.into(),
             IbcEvent::OpenConfirmConnection(event) => event.into(),
+            IbcEvent::UpgradeInitConnection(event) => event.into(),
+            IbcEvent::UpgradeTryConnection(event) => event.into(),
+            IbcEvent::UpgradeAckConnection(event) => event.into(),
+            IbcEvent::UpgradeConfirmConnection(event) => event.into(),
+            IbcEvent::UpgradeTimeoutConnection(event) => event.into(),
+            IbcEvent::UpgradeCancelConnection(event) => event.into(),
             IbcEvent::OpenInitChannel(event) => event.into(),
             IbcEvent::OpenTryChannel(event) => event.into(),
             IbcEvent::OpenAckChannel(event) => event.into(),
@@ -138,10 +159,18 @@ impl IbcEvent {
             IbcEvent::UpdateClient(event) => event.event_type(),
             IbcEvent::ClientMisbehaviour(event) => event.event_type(),
             IbcEvent::UpgradeClient(event) => event.event_type(),
+            IbcEvent::ClientNearExpiry(event) => event.event_type(),
+            IbcEvent::ConflictingHeader(event) => event.event_type(),
             IbcEvent::OpenInitConnection(event) => event.event_type(),
             IbcEvent::OpenTryConnection(event) => event.event_type(),
             IbcEvent::OpenAckConnection(event) => event.event_type(),
             IbcEvent::OpenConfirmConnection(event) => event.event_type(),
+            IbcEvent::UpgradeInitConnection(event) => event.event_type(),
+            IbcEvent::UpgradeTryConnection(event) => event.event_type(),
+            IbcEvent::UpgradeAckConnection(event) => event.event_type(),
+            IbcEvent::UpgradeConfirmConnection(event) => event.event_type(),
+            IbcEvent::UpgradeTimeoutConnection(event) => event.event_type(),
+            IbcEvent::UpgradeCancelConnection(event) => event.event_type(),
             IbcEvent::OpenInitChannel(event) => event.event_type(),
             IbcEvent::OpenTryChannel(event) => event.event_type(),
             IbcEvent::OpenAckChannel(event) => event.event_type(),
@@ -283,13 +312,13 @@ impl From<MessageEvent> for IbcEvent {
 pub mod tests {
     use alloc::vec;
 
+    use ibc_core_channel_types::channel::Order;
+    use ibc_core_channel_types::events::SendPacket;
+    use ibc_core_channel_types::packet::Packet;
+    use ibc_core_host_types::identifier::ConnectionId;
     use ibc_testkit::utils::core::channel::dummy_raw_packet;
 
     use super::*;
-    use crate::core::ics04_channel::channel::Order;
-    use crate::core::ics04_channel::events::SendPacket;
-    use crate::core::ics04_channel::packet::Packet;
-    use crate::core::ics24_host::identifier::ConnectionId;
 
     #[test]
     /// Ensures that we don't panic when packet data is not valid UTF-8.
@@ -0,0 +1,322 @@
+//! Commitments to the IBC events emitted by a message, for hosts that commit
+//! to these events, e.g. in the blocks of a rollup, and later prove their
+//! emission to other chains.
+//!
+//! Each event is hashed from its canonical encoding: its ABCI form, i.e. its
+//! type followed by its attributes in order, where every string is prefixed
+//! with its length as a big-endian `u64`, and so is the list of attributes:
+//!
+//! ```text
+//! len(type) || type || len(attributes) || (len(key) || key || len(value) || value)*
+//! ```
+//!
+//! The `index` flag of the attributes is left out, as it is a setting of the
+//! node rather than part of the event.
+//!
+//! The events of a message are then merkleized in the order they were
+//! emitted, as in [RFC 6962](https://www.rfc-editor.org/rfc/rfc6962#section-2.1),
+//! which CometBFT also follows to commit to the transactions of a block: with
+//! SHA256, a leaf is hashed as `H(0x00 || encoding)`, an inner node as
+//! `H(0x01 || left || right)`, and the root of no events is `H("")`.
+
+use core::fmt;
+
+use ibc_primitives::prelude::*;
+use sha2::{Digest, Sha256};
+use subtle_encoding::{Encoding, Hex};
+use tendermint::abci;
+
+use super::{Error, IbcEvent};
+
+const LEAF_PREFIX: u8 = 0;
+const INNER_PREFIX: u8 = 1;
+
+/// A SHA256 hash of the event commitment scheme, either of a single event or
+/// the root of the events of a message.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventHash([u8; 32]);
+
+impl EventHash {
+    pub const fn new(hash: [u8; 32]) -> Self {
+        Self(hash)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Display for EventHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = Hex::upper_case()
+            .encode_to_string(self.0)
+            .map_err(|_| fmt::Error)?;
+        f.write_str(&hex)
+    }
+}
+
+impl fmt::Debug for EventHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EventHash({self})")
+    }
+}
+
+/// Returns the canonical encoding of the given event, as hashed by
+/// [`event_hash`].
+pub fn encode_event(event: &IbcEvent) -> Result<Vec<u8>, Error> {
+    let event = abci::Event::try_from(event.clone())?;
+
+    let mut bytes = Vec::new();
+    encode_bytes(&mut bytes, event.kind.as_bytes());
+    bytes.extend((event.attributes.len() as u64).to_be_bytes());
+    for attribute in &event.attributes {
+        encode_bytes(&mut bytes, attribute.key.as_bytes());
+        encode_bytes(&mut bytes, attribute.value.as_bytes());
+    }
+
+    Ok(bytes)
+}
+
+/// Returns the hash of the given event, i.e. its leaf in the commitment to
+/// the events of a message.
+pub fn event_hash(event: &IbcEvent) -> Result<EventHash, Error> {
+    Ok(leaf_hash(&encode_event(event)?))
+}
+
+/// Returns the root of the commitment to the given events, in order.
+pub fn events_root(events: &[IbcEvent]) -> Result<EventHash, Error> {
+    Ok(root(&leaf_hashes(events)?))
+}
+
+/// Returns the proof that the event at `index` is part of the commitment to
+/// the given events, or `None` if there is no event at `index`.
+pub fn event_proof(events: &[IbcEvent], index: usize) -> Result<Option<EventProof>, Error> {
+    if index >= events.len() {
+        return Ok(None);
+    }
+
+    Ok(Some(EventProof {
+        index: index as u64,
+        total: events.len() as u64,
+        aunts: aunts(&leaf_hashes(events)?, index),
+    }))
+}
+
+/// The proof that an event is part of the commitment to the events of a
+/// message, as returned by [`event_proof`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventProof {
+    /// The position of the event among the events of the message.
+    pub index: u64,
+    /// The number of events of the message.
+    pub total: u64,
+    /// The hashes of the siblings on the path from the event to the root,
+    /// from the bottom up.
+    pub aunts: Vec<EventHash>,
+}
+
+impl EventProof {
+    /// Returns whether this proof proves that `event` is part of the
+    /// commitment with the given root.
+    pub fn verify(&self, root: &EventHash, event: &IbcEvent) -> Result<bool, Error> {
+        let leaf = event_hash(event)?;
+
+        Ok(root_from_aunts(self.index, self.total, leaf, &self.aunts).as_ref() == Some(root))
+    }
+}
+
+fn encode_bytes(bytes: &mut Vec<u8>, value: &[u8]) {
+    bytes.extend((value.len() as u64).to_be_bytes());
+    bytes.extend(value);
+}
+
+fn leaf_hashes(events: &[IbcEvent]) -> Result<Vec<EventHash>, Error> {
+    events.iter().map(event_hash).collect()
+}
+
+fn leaf_hash(encoding: &[u8]) -> EventHash {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(encoding);
+    EventHash(hasher.finalize().into())
+}
+
+fn inner_hash(left: &EventHash, right: &EventHash) -> EventHash {
+    let mut hasher = Sha256::new();
+    hasher.update([INNER_PREFIX]);
+    hasher.update(left.0);
+    hasher.update(right.0);
+    EventHash(hasher.finalize().into())
+}
+
+/// Returns the largest power of two strictly less than `n`, where `n > 1`.
+fn split_point(n: u64) -> u64 {
+    let mut k = 1;
+    while k * 2 < n {
+        k *= 2;
+    }
+    k
+}
+
+fn root(leaves: &[EventHash]) -> EventHash {
+    match leaves {
+        [] => EventHash(Sha256::digest(b"").into()),
+        [leaf] => *leaf,
+        _ => {
+            let k = split_point(leaves.len() as u64) as usize;
+            inner_hash(&root(&leaves[..k]), &root(&leaves[k..]))
+        }
+    }
+}
+
+fn aunts(leaves: &[EventHash], index: usize) -> Vec<EventHash> {
+    if leaves.len() <= 1 {
+        return Vec::new();
+    }
+
+    let k = split_point(leaves.len() as u64) as usize;
+    let (mut aunts, sibling) = if index < k {
+        (aunts(&leaves[..k], index), root(&leaves[k..]))
+    } else {
+        (aunts(&leaves[k..], index - k), root(&leaves[..k]))
+    };
+    aunts.push(sibling);
+    aunts
+}
+
+fn root_from_aunts(
+    index: u64,
+    total: u64,
+    leaf: EventHash,
+    aunts: &[EventHash],
+) -> Option<EventHash> {
+    if index >= total {
+        return None;
+    }
+
+    if total == 1 {
+        return aunts.is_empty().then_some(leaf);
+    }
+
+    let (sibling, aunts) = aunts.split_last()?;
+    let k = split_point(total);
+    if index < k {
+        let left = root_from_aunts(index, k, leaf, aunts)?;
+        Some(inner_hash(&left, sibling))
+    } else {
+        let right = root_from_aunts(index - k, total - k, leaf, aunts)?;
+        Some(inner_hash(sibling, &right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{MessageEvent, ModuleEvent};
+
+    fn module_event(value: u64) -> IbcEvent {
+        ModuleEvent {
+            kind: "transfer".to_string(),
+            attributes: vec![("amount", value).into(), ("denom", "uatom").into()],
+        }
+        .into()
+    }
+
+    fn events(n: u64) -> Vec<IbcEvent> {
+        (0..n).map(module_event).collect()
+    }
+
+    #[test]
+    fn canonical_encoding() {
+        let encoding = encode_event(&MessageEvent::Channel.into()).unwrap();
+
+        let mut expected = Vec::new();
+        expected.extend(7u64.to_be_bytes());
+        expected.extend(b"message");
+        expected.extend(1u64.to_be_bytes());
+        expected.extend(6u64.to_be_bytes());
+        expected.extend(b"module");
+        expected.extend(11u64.to_be_bytes());
+        expected.extend(b"ibc_channel");
+
+        assert_eq!(encoding, expected);
+    }
+
+    #[test]
+    fn roots() {
+        assert_eq!(
+            events_root(&[]).unwrap().to_string(),
+            "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855"
+        );
+
+        let leaves = leaf_hashes(&events(3)).unwrap();
+        assert_eq!(events_root(&events(1)).unwrap(), leaves[0]);
+        assert_eq!(
+            events_root(&events(3)).unwrap(),
+            inner_hash(&inner_hash(&leaves[0], &leaves[1]), &leaves[2])
+        );
+
+        // the order of the events is committed to
+        let mut reversed = events(3);
+        reversed.reverse();
+        assert_ne!(
+            events_root(&reversed).unwrap(),
+            events_root(&events(3)).unwrap()
+        );
+    }
+
+    #[test]
+    fn proofs() {
+        for n in 1..=9 {
+            let events = events(n);
+            let root = events_root(&events).unwrap();
+
+            for (index, event) in events.iter().enumerate() {
+                let proof = event_proof(&events, index).unwrap().unwrap();
+                assert!(
+                    proof.verify(&root, event).unwrap(),
+                    "{n} events, at {index}"
+                );
+
+                // another event, or the same at another position, is not proven
+                let other = module_event(n);
+                assert!(!proof.verify(&root, &other).unwrap());
+                if n > 1 {
+                    let moved = EventProof {
+                        index: (proof.index + 1) % n,
+                        ..proof.clone()
+                    };
+                    assert!(!moved.verify(&root, event).unwrap());
+                }
+            }
+
+            assert!(event_proof(&events, n as usize).unwrap().is_none());
+        }
+    }
+}
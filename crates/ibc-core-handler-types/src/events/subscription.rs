@@ -0,0 +1,197 @@
+//! Conversions of IBC events into the `events` of the messages pushed by the
+//! `subscribe` JSON-RPC endpoint of CometBFT, on which relayers such as Hermes
+//! listen for the events of the chains they relay, e.g.
+//!
+//! ```json
+//! {
+//!   "query": "tm.event='Tx'",
+//!   "data": { "type": "tendermint/event/Tx", "value": { ... } },
+//!   "events": {
+//!     "tm.event": ["Tx"],
+//!     "tx.hash": ["C4E8..."],
+//!     "tx.height": ["42"],
+//!     "message.module": ["ibc_channel"],
+//!     "send_packet.packet_src_channel": ["channel-0"]
+//!   }
+//! }
+//! ```
+//!
+//! There, the attributes of all the events of a transaction are flattened into
+//! a single map, under the composite key `<event type>.<attribute key>`, each
+//! holding the values of the attribute across all the events of that type, in
+//! the order they were emitted.
+
+use alloc::collections::btree_map::BTreeMap;
+
+use ibc_primitives::prelude::*;
+use subtle_encoding::{Encoding, Hex};
+use tendermint::abci;
+
+use super::{Error, IbcEvent};
+
+/// The composite key of the type of the subscription event.
+pub const TM_EVENT_KEY: &str = "tm.event";
+
+/// The type of the subscription events of transactions.
+pub const TX_EVENT_TYPE: &str = "Tx";
+
+/// The composite key of the hash of the transaction of a subscription event.
+pub const TX_HASH_KEY: &str = "tx.hash";
+
+/// The composite key of the height of the transaction of a subscription event.
+pub const TX_HEIGHT_KEY: &str = "tx.height";
+
+/// The `events` of a subscription message, i.e. the values of the attributes
+/// by composite key.
+pub type CompositeEvents = BTreeMap<String, Vec<String>>;
+
+/// Returns the composite key of the attribute `key` of the events of type
+/// `kind`, e.g. `send_packet.packet_src_channel`.
+pub fn composite_key(kind: &str, key: &str) -> String {
+    format!("{kind}.{key}")
+}
+
+/// Flattens the given events, in order, into the `events` of a subscription
+/// message.
+pub fn composite_events<I>(events: I) -> Result<CompositeEvents, Error>
+where
+    I: IntoIterator<Item = IbcEvent>,
+{
+    let mut composite_events = CompositeEvents::new();
+    for event in events {
+        let event = abci::Event::try_from(event)?;
+        for attribute in event.attributes {
+            composite_events
+                .entry(composite_key(&event.kind, &attribute.key))
+                .or_default()
+                .push(attribute.value);
+        }
+    }
+
+    Ok(composite_events)
+}
+
+/// Flattens the events emitted while handling the messages of a transaction,
+/// in order, into the `events` of the subscription message of the
+/// transaction, of hash `tx_hash` and included in the block at `height`.
+pub fn tx_composite_events<I>(
+    events: I,
+    tx_hash: &[u8],
+    height: u64,
+) -> Result<CompositeEvents, Error>
+where
+    I: IntoIterator<Item = IbcEvent>,
+{
+    let mut composite_events = composite_events(events)?;
+    composite_events.insert(TM_EVENT_KEY.to_string(), vec![TX_EVENT_TYPE.to_string()]);
+    composite_events.insert(
+        TX_HASH_KEY.to_string(),
+        vec![Hex::upper_case()
+            .encode_to_string(tx_hash)
+            .expect("Never fails")],
+    );
+    composite_events.insert(TX_HEIGHT_KEY.to_string(), vec![height.to_string()]);
+
+    Ok(composite_events)
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_channel_types::channel::Order;
+    use ibc_core_channel_types::events::SendPacket;
+    use ibc_core_channel_types::packet::Packet;
+    use ibc_core_channel_types::timeout::TimeoutHeight;
+    use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
+    use ibc_primitives::timestamp::Timestamp;
+
+    use super::*;
+    use crate::events::{MessageEvent, ModuleEvent};
+
+    fn module_event(amount: u64) -> IbcEvent {
+        ModuleEvent {
+            kind: "fungible_token_packet".to_string(),
+            attributes: vec![("amount", amount).into(), ("success", true).into()],
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_composite_events() {
+        let events = composite_events([
+            IbcEvent::Message(MessageEvent::Channel),
+            module_event(1),
+            module_event(2),
+        ])
+        .unwrap();
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(events["message.module"], ["ibc_channel"]);
+        assert_eq!(events["fungible_token_packet.amount"], ["1", "2"]);
+        assert_eq!(events["fungible_token_packet.success"], ["true", "true"]);
+    }
+
+    fn send_packet(seq: u64) -> IbcEvent {
+        let packet = Packet {
+            seq_on_a: seq.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: b"hello".to_vec(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+        IbcEvent::SendPacket(SendPacket::new(
+            packet,
+            Order::Unordered,
+            ConnectionId::new(0),
+        ))
+    }
+
+    #[test]
+    fn test_send_packet_composite_events() {
+        let events = composite_events([
+            IbcEvent::Message(MessageEvent::Channel),
+            send_packet(1),
+            send_packet(2),
+        ])
+        .unwrap();
+
+        assert_eq!(events["message.module"], ["ibc_channel"]);
+        assert_eq!(events["send_packet.packet_sequence"], ["1", "2"]);
+        assert_eq!(
+            events["send_packet.packet_src_port"],
+            ["transfer", "transfer"]
+        );
+        assert_eq!(
+            events["send_packet.packet_src_channel"],
+            ["channel-0", "channel-0"]
+        );
+        assert_eq!(
+            events["send_packet.packet_dst_port"],
+            ["transfer", "transfer"]
+        );
+        assert_eq!(
+            events["send_packet.packet_dst_channel"],
+            ["channel-1", "channel-1"]
+        );
+        assert_eq!(
+            events["send_packet.packet_connection"],
+            ["connection-0", "connection-0"]
+        );
+        assert_eq!(events["send_packet.packet_data"], ["hello", "hello"]);
+        assert!(events
+            .keys()
+            .all(|key| key.starts_with("message.") || key.starts_with("send_packet.packet_")));
+    }
+
+    #[test]
+    fn test_tx_composite_events() {
+        let events = tx_composite_events([module_event(1)], &[0xc4, 0xe8], 42).unwrap();
+
+        assert_eq!(events[TM_EVENT_KEY], ["Tx"]);
+        assert_eq!(events[TX_HASH_KEY], ["C4E8"]);
+        assert_eq!(events[TX_HEIGHT_KEY], ["42"]);
+        assert_eq!(events["fungible_token_packet.amount"], ["1"]);
+    }
+}
@@ -0,0 +1,206 @@
+//! Conversions between IBC events and the events of a transaction result, as
+//! found in the JSON returned by the `tx` and `tx_search` RPC endpoints of
+//! CometBFT, or in the `logs` of a Cosmos SDK `TxResponse`.
+
+use ibc_primitives::prelude::*;
+use tendermint::abci;
+
+use super::{Error, IbcEvent, ModuleEvent, ModuleEventAttribute};
+
+/// An event of a transaction result, in the JSON schema of the Cosmos SDK:
+///
+/// ```json
+/// {
+///   "type": "send_packet",
+///   "attributes": [{ "key": "packet_sequence", "value": "1", "index": true }]
+/// }
+/// ```
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxEvent {
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub kind: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub attributes: Vec<TxEventAttribute>,
+}
+
+impl TxEvent {
+    /// Returns the value of the first attribute with the given `key`, if any.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+    }
+}
+
+/// A single key/value pair in a [`TxEvent`]. The `index` flag, which tells
+/// whether the node indexes the attribute, is left out of the JSON of older
+/// nodes and then defaults to `false`.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxEventAttribute {
+    pub key: String,
+    pub value: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub index: bool,
+}
+
+impl From<abci::Event> for TxEvent {
+    fn from(event: abci::Event) -> Self {
+        Self {
+            kind: event.kind,
+            attributes: event
+                .attributes
+                .into_iter()
+                .map(|attr| TxEventAttribute {
+                    key: attr.key,
+                    value: attr.value,
+                    index: attr.index,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<TxEvent> for abci::Event {
+    fn from(event: TxEvent) -> Self {
+        Self {
+            kind: event.kind,
+            attributes: event
+                .attributes
+                .into_iter()
+                .map(|attr| (attr.key, attr.value, attr.index).into())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<IbcEvent> for TxEvent {
+    type Error = Error;
+
+    fn try_from(event: IbcEvent) -> Result<Self, Self::Error> {
+        abci::Event::try_from(event).map(Into::into)
+    }
+}
+
+/// Drops the `index` flags, so that events of applications read from a
+/// transaction result can be handled like the ones emitted by the modules of
+/// the host.
+impl From<TxEvent> for ModuleEvent {
+    fn from(event: TxEvent) -> Self {
+        Self {
+            kind: event.kind,
+            attributes: event
+                .attributes
+                .into_iter()
+                .map(|attr| ModuleEventAttribute {
+                    key: attr.key,
+                    value: attr.value,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Converts the events emitted while handling the messages of a transaction,
+/// in order, into the events of its result.
+pub fn tx_events<I>(events: I) -> Result<Vec<TxEvent>, Error>
+where
+    I: IntoIterator<Item = IbcEvent>,
+{
+    events.into_iter().map(TxEvent::try_from).collect()
+}
+
+/// Iterates over the events of type `kind` among the events of one or more
+/// transaction results, preserving their order.
+pub fn events_of_type<'a, I>(events: I, kind: &'a str) -> impl Iterator<Item = &'a TxEvent>
+where
+    I: IntoIterator<Item = &'a TxEvent>,
+    I::IntoIter: 'a,
+{
+    events.into_iter().filter(move |event| event.kind == kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::MessageEvent;
+
+    fn module_event() -> ModuleEvent {
+        ModuleEvent {
+            kind: "fungible_token_packet".to_string(),
+            attributes: vec![("receiver", "cosmos1xyz").into(), ("success", true).into()],
+        }
+    }
+
+    #[test]
+    fn test_tx_events() {
+        let events = tx_events([
+            IbcEvent::Message(MessageEvent::Channel),
+            IbcEvent::Module(module_event()),
+        ])
+        .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, "message");
+        assert_eq!(events[0].attribute("module"), Some("ibc_channel"));
+        assert_eq!(events[1].attribute("success"), Some("true"));
+        assert_eq!(events[1].attribute("sender"), None);
+
+        let mut found = events_of_type(&events, "fungible_token_packet");
+        assert_eq!(found.next(), Some(&events[1]));
+        assert_eq!(found.next(), None);
+
+        assert_eq!(ModuleEvent::from(events[1].clone()), module_event());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_tx_event_json() {
+        let json = r#"{
+            "type": "message",
+            "attributes": [
+                {"key": "module", "value": "ibc_channel", "index": true},
+                {"key": "sender", "value": "cosmos1xyz"}
+            ]
+        }"#;
+
+        let event: TxEvent = serde_json::from_str(json).unwrap();
+        assert_eq!(event.kind, "message");
+        assert!(event.attributes[0].index);
+        assert!(!event.attributes[1].index);
+
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"type":"message","attributes":[{"key":"module","value":"ibc_channel","index":true},{"key":"sender","value":"cosmos1xyz","index":false}]}"#
+        );
+
+        let abci_event = abci::Event::from(event.clone());
+        assert_eq!(TxEvent::from(abci_event), event);
+    }
+}
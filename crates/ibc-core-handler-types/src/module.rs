@@ -0,0 +1,65 @@
+//! Defines the identifiers of the modules of the host
+
+use alloc::borrow::Borrow;
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core_host_types::identifier::{ClientId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+
+/// What a module can be bound to on a router.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, derive_more::From)]
+pub enum Binding {
+    Port(PortId),
+    Connection(ConnectionId),
+    Client(ClientId),
+}
+
+impl Display for Binding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Port(port_id) => write!(f, "port `{port_id}`"),
+            Self::Connection(connection_id) => write!(f, "connection `{connection_id}`"),
+            Self::Client(client_id) => write!(f, "client `{client_id}`"),
+        }
+    }
+}
+
+/// Module name, internal to the chain.
+///
+/// That is, the IBC protocol never exposes this name. Note that this is
+/// different from IBC host [identifiers][ibc_core_host_types::identifier],
+/// which are exposed to other chains by the protocol.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ModuleId(String);
+
+impl ModuleId {
+    pub fn new(s: String) -> Self {
+        Self(s)
+    }
+}
+
+impl Display for ModuleId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Borrow<str> for ModuleId {
+    fn borrow(&self) -> &str {
+        self.0.as_str()
+    }
+}
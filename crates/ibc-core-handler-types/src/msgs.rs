@@ -0,0 +1,578 @@
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::msgs::{
+    acknowledgement, chan_close_confirm, chan_close_init, chan_open_ack, chan_open_confirm,
+    chan_open_init, chan_open_try, channel_msg_to_port_id, packet_msg_to_port_id, recv_packet,
+    timeout, timeout_on_close, ChannelMsg, PacketMsg,
+};
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::Height;
+use ibc_core_client_types::msgs::{
+    create_client, misbehaviour, update_client, upgrade_client, ClientMsg,
+};
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::msgs::{
+    conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try, ConnectionMsg,
+};
+use ibc_core_host_types::identifier::{
+    ChannelId, ConnectionId, IdentifierError, IdentifierValidationConfig, PortId,
+};
+pub use ibc_primitives::msgs::{validate_raw, Msg, ValidateBasic};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use ibc_primitives::timestamp::{Expiry, Timestamp};
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::error::{ContextError, RouterError};
+use crate::events::commitment::{events_root, EventHash};
+use crate::events::{Error as EventError, IbcEvent};
+
+/// Enumeration of all messages that the local ICS26 module is capable of routing.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
+pub enum MsgEnvelope {
+    Client(ClientMsg),
+    Connection(ConnectionMsg),
+    Channel(ChannelMsg),
+    Packet(PacketMsg),
+}
+
+impl MsgEnvelope {
+    /// Returns the signer of the message.
+    pub fn signer(&self) -> &Signer {
+        match self {
+            Self::Client(msg) => match msg {
+                ClientMsg::CreateClient(msg) => &msg.signer,
+                ClientMsg::UpdateClient(msg) => &msg.signer,
+                ClientMsg::Misbehaviour(msg) => &msg.signer,
+                ClientMsg::UpgradeClient(msg) => &msg.signer,
+            },
+            Self::Connection(msg) => match msg {
+                ConnectionMsg::OpenInit(msg) => &msg.signer,
+                ConnectionMsg::OpenTry(msg) => &msg.signer,
+                ConnectionMsg::OpenAck(msg) => &msg.signer,
+                ConnectionMsg::OpenConfirm(msg) => &msg.signer,
+                #[cfg(feature = "connection-upgrade")]
+                ConnectionMsg::UpgradeInit(msg) => &msg.signer,
+                #[cfg(feature = "connection-upgrade")]
+                ConnectionMsg::UpgradeTry(msg) => &msg.signer,
+                #[cfg(feature = "connection-upgrade")]
+                ConnectionMsg::UpgradeAck(msg) => &msg.signer,
+                #[cfg(feature = "connection-upgrade")]
+                ConnectionMsg::UpgradeConfirm(msg) => &msg.signer,
+                #[cfg(feature = "connection-upgrade")]
+                ConnectionMsg::UpgradeTimeout(msg) => &msg.signer,
+                #[cfg(feature = "connection-upgrade")]
+                ConnectionMsg::UpgradeCancel(msg) => &msg.signer,
+            },
+            Self::Channel(msg) => match msg {
+                ChannelMsg::OpenInit(msg) => &msg.signer,
+                ChannelMsg::OpenTry(msg) => &msg.signer,
+                ChannelMsg::OpenAck(msg) => &msg.signer,
+                ChannelMsg::OpenConfirm(msg) => &msg.signer,
+                ChannelMsg::CloseInit(msg) => &msg.signer,
+                ChannelMsg::CloseConfirm(msg) => &msg.signer,
+            },
+            Self::Packet(msg) => match msg {
+                PacketMsg::Recv(msg) => &msg.signer,
+                PacketMsg::Ack(msg) => &msg.signer,
+                PacketMsg::Timeout(msg) => &msg.signer,
+                PacketMsg::TimeoutOnClose(msg) => &msg.signer,
+            },
+        }
+    }
+
+    /// Returns the port of the module handling the message on the host, for
+    /// channel and packet messages.
+    pub fn port_id(&self) -> Option<&PortId> {
+        match self {
+            Self::Client(_) | Self::Connection(_) => None,
+            Self::Channel(msg) => Some(channel_msg_to_port_id(msg)),
+            Self::Packet(msg) => Some(packet_msg_to_port_id(msg)),
+        }
+    }
+
+    /// Checks the identifiers referring to the host, e.g. the client of a
+    /// `MsgUpdateClient` or the destination port and channel of a
+    /// `MsgRecvPacket`, against its validation `config`.
+    ///
+    /// The identifiers referring to the counterparty are left out, as they
+    /// follow the config of the counterparty.
+    pub fn validate_identifiers(
+        &self,
+        config: &IdentifierValidationConfig,
+    ) -> Result<(), RouterError> {
+        let result = match self {
+            Self::Client(msg) => {
+                let client_id = match msg {
+                    ClientMsg::CreateClient(_) => return Ok(()),
+                    ClientMsg::UpdateClient(msg) => &msg.client_id,
+                    ClientMsg::Misbehaviour(msg) => &msg.client_id,
+                    ClientMsg::UpgradeClient(msg) => &msg.client_id,
+                };
+                config
+                    .validate_client_identifier(client_id.as_str())
+                    .map_err(|e| ContextError::from(ClientError::InvalidClientIdentifier(e)))
+            }
+            Self::Connection(msg) => {
+                let result = match msg {
+                    ConnectionMsg::OpenInit(msg) => {
+                        config.validate_client_identifier(msg.client_id_on_a.as_str())
+                    }
+                    ConnectionMsg::OpenTry(msg) => {
+                        config.validate_client_identifier(msg.client_id_on_b.as_str())
+                    }
+                    ConnectionMsg::OpenAck(msg) => {
+                        config.validate_connection_identifier(msg.conn_id_on_a.as_str())
+                    }
+                    ConnectionMsg::OpenConfirm(msg) => {
+                        config.validate_connection_identifier(msg.conn_id_on_b.as_str())
+                    }
+                    #[cfg(feature = "connection-upgrade")]
+                    ConnectionMsg::UpgradeInit(msg) => {
+                        config.validate_connection_identifier(msg.conn_id_on_a.as_str())
+                    }
+                    #[cfg(feature = "connection-upgrade")]
+                    ConnectionMsg::UpgradeTry(msg) => {
+                        config.validate_connection_identifier(msg.conn_id_on_b.as_str())
+                    }
+                    #[cfg(feature = "connection-upgrade")]
+                    ConnectionMsg::UpgradeAck(msg) => {
+                        config.validate_connection_identifier(msg.conn_id_on_a.as_str())
+                    }
+                    #[cfg(feature = "connection-upgrade")]
+                    ConnectionMsg::UpgradeConfirm(msg) => {
+                        config.validate_connection_identifier(msg.conn_id_on_b.as_str())
+                    }
+                    #[cfg(feature = "connection-upgrade")]
+                    ConnectionMsg::UpgradeTimeout(msg) => {
+                        config.validate_connection_identifier(msg.conn_id.as_str())
+                    }
+                    #[cfg(feature = "connection-upgrade")]
+                    ConnectionMsg::UpgradeCancel(msg) => {
+                        config.validate_connection_identifier(msg.conn_id_on_a.as_str())
+                    }
+                };
+                result.map_err(|e| ContextError::from(ConnectionError::InvalidIdentifier(e)))
+            }
+            Self::Channel(msg) => {
+                let result = match msg {
+                    ChannelMsg::OpenInit(msg) => validate_port_and_connection_hops(
+                        config,
+                        &msg.port_id_on_a,
+                        &msg.connection_hops_on_a,
+                    ),
+                    ChannelMsg::OpenTry(msg) => validate_port_and_connection_hops(
+                        config,
+                        &msg.port_id_on_b,
+                        &msg.connection_hops_on_b,
+                    ),
+                    ChannelMsg::OpenAck(msg) => {
+                        validate_port_and_channel(config, &msg.port_id_on_a, &msg.chan_id_on_a)
+                    }
+                    ChannelMsg::OpenConfirm(msg) => {
+                        validate_port_and_channel(config, &msg.port_id_on_b, &msg.chan_id_on_b)
+                    }
+                    ChannelMsg::CloseInit(msg) => {
+                        validate_port_and_channel(config, &msg.port_id_on_a, &msg.chan_id_on_a)
+                    }
+                    ChannelMsg::CloseConfirm(msg) => {
+                        validate_port_and_channel(config, &msg.port_id_on_b, &msg.chan_id_on_b)
+                    }
+                };
+                result.map_err(|e| ContextError::from(ChannelError::InvalidIdentifier(e)))
+            }
+            Self::Packet(msg) => {
+                let result = match msg {
+                    PacketMsg::Recv(msg) => validate_port_and_channel(
+                        config,
+                        &msg.packet.port_id_on_b,
+                        &msg.packet.chan_id_on_b,
+                    ),
+                    PacketMsg::Ack(msg) => validate_port_and_channel(
+                        config,
+                        &msg.packet.port_id_on_a,
+                        &msg.packet.chan_id_on_a,
+                    ),
+                    PacketMsg::Timeout(msg) => validate_port_and_channel(
+                        config,
+                        &msg.packet.port_id_on_a,
+                        &msg.packet.chan_id_on_a,
+                    ),
+                    PacketMsg::TimeoutOnClose(msg) => validate_port_and_channel(
+                        config,
+                        &msg.packet.port_id_on_a,
+                        &msg.packet.chan_id_on_a,
+                    ),
+                };
+                result.map_err(|e| ContextError::from(PacketError::InvalidIdentifier(e)))
+            }
+        };
+        result.map_err(RouterError::from)
+    }
+}
+
+fn validate_port_and_connection_hops(
+    config: &IdentifierValidationConfig,
+    port_id: &PortId,
+    connection_hops: &[ConnectionId],
+) -> Result<(), IdentifierError> {
+    config.validate_port_identifier(port_id.as_str())?;
+    connection_hops
+        .iter()
+        .try_for_each(|conn_id| config.validate_connection_identifier(conn_id.as_str()))
+}
+
+fn validate_port_and_channel(
+    config: &IdentifierValidationConfig,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+) -> Result<(), IdentifierError> {
+    config.validate_port_identifier(port_id.as_str())?;
+    config.validate_channel_identifier(chan_id.as_str())
+}
+
+impl ValidateBasic for MsgEnvelope {
+    type Error = RouterError;
+
+    fn validate_basic(&self) -> Result<(), Self::Error> {
+        let result = match self {
+            Self::Client(msg) => msg.validate_basic().map_err(ContextError::from),
+            Self::Connection(msg) => msg.validate_basic().map_err(ContextError::from),
+            Self::Channel(msg) => msg.validate_basic().map_err(ContextError::from),
+            Self::Packet(msg) => msg.validate_basic().map_err(ContextError::from),
+        };
+        result.map_err(RouterError::from)
+    }
+}
+
+/// The outcome of executing a [`MsgEnvelope`], returned by
+/// `execute` as the result of its [`HandlerOutput`].
+///
+/// It carries what the handlers decide while executing messages, e.g. the
+/// identifiers they generate, which integrators would otherwise extract from
+/// the events.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MsgOutput {
+    CreateClient(create_client::CreateClientResult),
+    /// Output of both `MsgUpdateClient` and `MsgSubmitMisbehaviour`.
+    UpdateClient(update_client::UpdateClientResult),
+    ConnOpenInit(conn_open_init::ConnOpenInitResult),
+    ConnOpenTry(conn_open_try::ConnOpenTryResult),
+    ChanOpenInit(chan_open_init::ChanOpenInitResult),
+    ChanOpenTry(chan_open_try::ChanOpenTryResult),
+    RecvPacket(recv_packet::RecvOutcome),
+    /// The message was executed, with nothing to report but its events.
+    Executed,
+}
+
+/// What executing a [`MsgEnvelope`] did, as returned by
+/// `execute` and `dispatch`.
+///
+/// The events and logs are emitted through the
+/// `ExecutionContext` all the same: they are
+/// also returned so that embedders, e.g. tests, simulators or contracts, can
+/// inspect them without buffering them in their context.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HandlerOutput {
+    /// The outcome of the message.
+    pub result: MsgOutput,
+    /// The events emitted by the message, in order.
+    pub events: Vec<IbcEvent>,
+    /// The messages logged by the handlers, in order.
+    pub logs: Vec<String>,
+}
+
+impl HandlerOutput {
+    /// Returns the root of the commitment to the events of the message, as
+    /// described in [`commitment`](crate::events::commitment).
+    pub fn events_root(&self) -> Result<EventHash, EventError> {
+        events_root(&self.events)
+    }
+}
+
+/// Optional expiry attached by the submitter of a message, past which the
+/// host rejects it before running its handler.
+///
+/// Relayers set it so that messages lingering in a mempool, or resubmitted
+/// from one, fail fast once stale instead of going through full proof
+/// verification. It is not part of the message itself: hosts carry it
+/// alongside, e.g. in the transaction, and check it with
+/// `validate_with_expiry`.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MsgExpiry {
+    /// The last host height at which the message may be processed.
+    pub height: Option<Height>,
+    /// The last host timestamp at which the message may be processed.
+    pub timestamp: Option<Timestamp>,
+}
+
+impl MsgExpiry {
+    /// Checks that a host at `host_height` and `host_timestamp` is not past
+    /// this expiry.
+    ///
+    /// The timestamp bound is not enforced on hosts without a valid clock.
+    pub fn verify(
+        &self,
+        host_height: Height,
+        host_timestamp: Timestamp,
+    ) -> Result<(), RouterError> {
+        if let Some(height) = self.height {
+            if host_height > height {
+                return Err(RouterError::MessageExpiredAtHeight {
+                    expiry_height: height,
+                    host_height,
+                });
+            }
+        }
+
+        if let Some(timestamp) = self.timestamp {
+            if let Expiry::Expired = host_timestamp.check_expiry(&timestamp) {
+                return Err(RouterError::MessageExpiredAtTimestamp {
+                    expiry_timestamp: timestamp,
+                    host_timestamp,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Host policy deciding which messages are handled at all, like the
+/// antedecorators of ibc-go.
+///
+/// `validate` consults the filter returned by
+/// `ValidationContext::msg_filter`
+/// before any other processing of a message, so that hosts can e.g. block
+/// specific ports, or refuse the packets of disabled channels, without forking
+/// the dispatcher.
+pub trait MsgFilter {
+    /// Returns an error if the host refuses to handle `msg`, typically
+    /// [`RouterError::MessageRejected`].
+    fn filter_msg(&self, msg: &MsgEnvelope) -> Result<(), RouterError>;
+}
+
+/// The default [`MsgFilter`], which accepts any message.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AcceptAllMsgs;
+
+impl MsgFilter for AcceptAllMsgs {
+    fn filter_msg(&self, _msg: &MsgEnvelope) -> Result<(), RouterError> {
+        Ok(())
+    }
+}
+
+/// A [`MsgFilter`] rejecting the channel and packet messages handled by the
+/// modules bound to any of the given ports.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockedPorts {
+    port_ids: Vec<PortId>,
+}
+
+impl BlockedPorts {
+    pub fn new(port_ids: impl IntoIterator<Item = PortId>) -> Self {
+        Self {
+            port_ids: port_ids.into_iter().collect(),
+        }
+    }
+}
+
+impl MsgFilter for BlockedPorts {
+    fn filter_msg(&self, msg: &MsgEnvelope) -> Result<(), RouterError> {
+        match msg.port_id() {
+            Some(port_id) if self.port_ids.contains(port_id) => Err(RouterError::MessageRejected {
+                reason: format!("port `{port_id}` is blocked"),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgEnvelope {
+    type Error = RouterError;
+
+    fn try_from(any_msg: Any) -> Result<Self, Self::Error> {
+        match any_msg.type_url.as_str() {
+            // ICS2 messages
+            create_client::TYPE_URL => {
+                // Pop out the message and then wrap it in the corresponding type.
+                let domain_msg = create_client::MsgCreateClient::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Client(ClientMsg::CreateClient(domain_msg)))
+            }
+            update_client::TYPE_URL => {
+                let domain_msg = update_client::MsgUpdateClient::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Client(ClientMsg::UpdateClient(domain_msg)))
+            }
+            upgrade_client::TYPE_URL => {
+                let domain_msg = upgrade_client::MsgUpgradeClient::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Client(ClientMsg::UpgradeClient(domain_msg)))
+            }
+            misbehaviour::TYPE_URL => {
+                let domain_msg = misbehaviour::MsgSubmitMisbehaviour::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Client(ClientMsg::Misbehaviour(domain_msg)))
+            }
+
+            // ICS03
+            conn_open_init::TYPE_URL => {
+                let domain_msg = conn_open_init::MsgConnectionOpenInit::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenInit(domain_msg)))
+            }
+            conn_open_try::TYPE_URL => {
+                let domain_msg = conn_open_try::MsgConnectionOpenTry::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenTry(domain_msg)))
+            }
+            conn_open_ack::TYPE_URL => {
+                let domain_msg = conn_open_ack::MsgConnectionOpenAck::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenAck(domain_msg)))
+            }
+            conn_open_confirm::TYPE_URL => {
+                let domain_msg =
+                    conn_open_confirm::MsgConnectionOpenConfirm::decode_vec(&any_msg.value)
+                        .map_err(|e| RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        })?;
+                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenConfirm(
+                    domain_msg,
+                )))
+            }
+
+            // ICS04 channel messages
+            chan_open_init::TYPE_URL => {
+                let domain_msg = chan_open_init::MsgChannelOpenInit::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::OpenInit(domain_msg)))
+            }
+            chan_open_try::TYPE_URL => {
+                let domain_msg = chan_open_try::MsgChannelOpenTry::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::OpenTry(domain_msg)))
+            }
+            chan_open_ack::TYPE_URL => {
+                let domain_msg = chan_open_ack::MsgChannelOpenAck::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::OpenAck(domain_msg)))
+            }
+            chan_open_confirm::TYPE_URL => {
+                let domain_msg = chan_open_confirm::MsgChannelOpenConfirm::decode_vec(
+                    &any_msg.value,
+                )
+                .map_err(|e| RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::OpenConfirm(domain_msg)))
+            }
+            chan_close_init::TYPE_URL => {
+                let domain_msg = chan_close_init::MsgChannelCloseInit::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::CloseInit(domain_msg)))
+            }
+            chan_close_confirm::TYPE_URL => {
+                let domain_msg =
+                    chan_close_confirm::MsgChannelCloseConfirm::decode_vec(&any_msg.value)
+                        .map_err(|e| RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::CloseConfirm(domain_msg)))
+            }
+            // ICS04 packet messages
+            recv_packet::TYPE_URL => {
+                let domain_msg =
+                    recv_packet::MsgRecvPacket::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Packet(PacketMsg::Recv(domain_msg)))
+            }
+            acknowledgement::TYPE_URL => {
+                let domain_msg = acknowledgement::MsgAcknowledgement::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Packet(PacketMsg::Ack(domain_msg)))
+            }
+            timeout::TYPE_URL => {
+                let domain_msg = timeout::MsgTimeout::decode_vec(&any_msg.value).map_err(|e| {
+                    RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Ok(MsgEnvelope::Packet(PacketMsg::Timeout(domain_msg)))
+            }
+            timeout_on_close::TYPE_URL => {
+                let domain_msg = timeout_on_close::MsgTimeoutOnClose::decode_vec(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(domain_msg)))
+            }
+            _ => Err(RouterError::UnknownMessageTypeUrl {
+                url: any_msg.type_url,
+            }),
+        }
+    }
+}
@@ -7,19 +7,19 @@
 use core::str::FromStr;
 
 use derive_more::{Display, From};
-
-use crate::core::ics04_channel::packet::Sequence;
-use crate::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
-use crate::prelude::*;
-use crate::Height;
-
-/// ABCI client upgrade keys
-/// - The key identifying the upgraded IBC state within the upgrade sub-store
-const UPGRADED_IBC_STATE: &str = "upgradedIBCState";
-///- The key identifying the upgraded client state
-const UPGRADED_CLIENT_STATE: &str = "upgradedClient";
-/// - The key identifying the upgraded consensus state
-const UPGRADED_CLIENT_CONSENSUS_STATE: &str = "upgradedConsState";
+use ibc_core_channel_types::packet::Sequence;
+use ibc_core_client_types::height::Height;
+use ibc_core_host_types::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_primitives::error::IbcError;
+use ibc_primitives::prelude::*;
+
+use crate::storage::{
+    CHANNEL_END_PREFIX, CHANNEL_PREFIX, CHUNK_PREFIX, CLIENT_PREFIX, CLIENT_STATE,
+    CONNECTION_PREFIX, CONNECTION_UPGRADE_PREFIX, CONSENSUS_STATE_PREFIX, NEXT_SEQ_ACK_PREFIX,
+    NEXT_SEQ_RECV_PREFIX, NEXT_SEQ_SEND_PREFIX, PACKET_ACK_PREFIX, PACKET_COMMITMENT_PREFIX,
+    PACKET_RECEIPT_PREFIX, PORT_PREFIX, RECEIPT_BITMAP_PREFIX, SEQUENCE_PREFIX,
+    UPGRADED_CLIENT_CONSENSUS_STATE, UPGRADED_CLIENT_STATE, UPGRADED_IBC_STATE,
+};
 
 /// The Path enum abstracts out the different sub-paths.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From, Display)]
@@ -28,6 +28,7 @@ pub enum Path {
     ClientConsensusState(ClientConsensusStatePath),
     ClientConnection(ClientConnectionPath),
     Connection(ConnectionPath),
+    ConnectionUpgrade(ConnectionUpgradePath),
     Ports(PortPath),
     ChannelEnd(ChannelEndPath),
     SeqSend(SeqSendPath),
@@ -36,6 +37,7 @@ pub enum Path {
     Commitment(CommitmentPath),
     Ack(AckPath),
     Receipt(ReceiptPath),
+    ReceiptBitmap(ReceiptBitmapPath),
     UpgradeClient(UpgradeClientPath),
 }
 
@@ -53,7 +55,7 @@ pub enum Path {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "clients/{_0}/clientState")]
+#[display(fmt = "{CLIENT_PREFIX}/{_0}/{CLIENT_STATE}")]
 pub struct ClientStatePath(pub ClientId);
 
 impl ClientStatePath {
@@ -76,7 +78,7 @@ impl ClientStatePath {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "clients/{client_id}/consensusStates/{epoch}-{height}")]
+#[display(fmt = "{CLIENT_PREFIX}/{client_id}/{CONSENSUS_STATE_PREFIX}/{epoch}-{height}")]
 pub struct ClientConsensusStatePath {
     pub client_id: ClientId,
     pub epoch: u64,
@@ -107,7 +109,7 @@ impl ClientConsensusStatePath {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "clients/{_0}/connections")]
+#[display(fmt = "{CLIENT_PREFIX}/{_0}/{CONNECTION_PREFIX}")]
 pub struct ClientConnectionPath(pub ClientId);
 
 impl ClientConnectionPath {
@@ -130,7 +132,7 @@ impl ClientConnectionPath {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "connections/{_0}")]
+#[display(fmt = "{CONNECTION_PREFIX}/{_0}")]
 pub struct ConnectionPath(pub ConnectionId);
 
 impl ConnectionPath {
@@ -139,6 +141,30 @@ impl ConnectionPath {
     }
 }
 
+/// The path of the upgrade in progress of a connection.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{CONNECTION_UPGRADE_PREFIX}/{_0}")]
+pub struct ConnectionUpgradePath(pub ConnectionId);
+
+impl ConnectionUpgradePath {
+    pub fn new(connection_id: &ConnectionId) -> ConnectionUpgradePath {
+        ConnectionUpgradePath(connection_id.clone())
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -153,7 +179,7 @@ impl ConnectionPath {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "ports/{_0}")]
+#[display(fmt = "{PORT_PREFIX}/{_0}")]
 pub struct PortPath(pub PortId);
 
 #[cfg_attr(
@@ -170,7 +196,7 @@ pub struct PortPath(pub PortId);
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "channelEnds/ports/{_0}/channels/{_1}")]
+#[display(fmt = "{CHANNEL_END_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
 pub struct ChannelEndPath(pub PortId, pub ChannelId);
 
 impl ChannelEndPath {
@@ -193,7 +219,7 @@ impl ChannelEndPath {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "nextSequenceSend/ports/{_0}/channels/{_1}")]
+#[display(fmt = "{NEXT_SEQ_SEND_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
 pub struct SeqSendPath(pub PortId, pub ChannelId);
 
 impl SeqSendPath {
@@ -216,7 +242,7 @@ impl SeqSendPath {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "nextSequenceRecv/ports/{_0}/channels/{_1}")]
+#[display(fmt = "{NEXT_SEQ_RECV_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
 pub struct SeqRecvPath(pub PortId, pub ChannelId);
 
 impl SeqRecvPath {
@@ -239,7 +265,7 @@ impl SeqRecvPath {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "nextSequenceAck/ports/{_0}/channels/{_1}")]
+#[display(fmt = "{NEXT_SEQ_ACK_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
 pub struct SeqAckPath(pub PortId, pub ChannelId);
 
 impl SeqAckPath {
@@ -262,7 +288,9 @@ impl SeqAckPath {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "commitments/ports/{port_id}/channels/{channel_id}/sequences/{sequence}")]
+#[display(
+    fmt = "{PACKET_COMMITMENT_PREFIX}/{PORT_PREFIX}/{port_id}/{CHANNEL_PREFIX}/{channel_id}/{SEQUENCE_PREFIX}/{sequence}"
+)]
 pub struct CommitmentPath {
     pub port_id: PortId,
     pub channel_id: ChannelId,
@@ -293,7 +321,9 @@ impl CommitmentPath {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "acks/ports/{port_id}/channels/{channel_id}/sequences/{sequence}")]
+#[display(
+    fmt = "{PACKET_ACK_PREFIX}/{PORT_PREFIX}/{port_id}/{CHANNEL_PREFIX}/{channel_id}/{SEQUENCE_PREFIX}/{sequence}"
+)]
 pub struct AckPath {
     pub port_id: PortId,
     pub channel_id: ChannelId,
@@ -324,7 +354,9 @@ impl AckPath {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
-#[display(fmt = "receipts/ports/{port_id}/channels/{channel_id}/sequences/{sequence}")]
+#[display(
+    fmt = "{PACKET_RECEIPT_PREFIX}/{PORT_PREFIX}/{port_id}/{CHANNEL_PREFIX}/{channel_id}/{SEQUENCE_PREFIX}/{sequence}"
+)]
 pub struct ReceiptPath {
     pub port_id: PortId,
     pub channel_id: ChannelId,
@@ -341,6 +373,39 @@ impl ReceiptPath {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{RECEIPT_BITMAP_PREFIX}/{PORT_PREFIX}/{port_id}/{CHANNEL_PREFIX}/{channel_id}/{CHUNK_PREFIX}/{chunk}"
+)]
+pub struct ReceiptBitmapPath {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub chunk: u64,
+}
+
+impl ReceiptBitmapPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId, chunk: u64) -> ReceiptBitmapPath {
+        ReceiptBitmapPath {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            chunk,
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -363,6 +428,22 @@ pub enum UpgradeClientPath {
     UpgradedClientConsensusState(u64),
 }
 
+impl UpgradeClientPath {
+    /// Returns the key of this path in a store keeping the upgraded states
+    /// under `upgrade_key` rather than [`UPGRADED_IBC_STATE`], e.g.
+    /// `{upgrade_key}/{height}/upgradedClient`.
+    pub fn key_under(&self, upgrade_key: &str) -> String {
+        match self {
+            Self::UpgradedClientState(height) => {
+                format!("{upgrade_key}/{height}/{UPGRADED_CLIENT_STATE}")
+            }
+            Self::UpgradedClientConsensusState(height) => {
+                format!("{upgrade_key}/{height}/{UPGRADED_CLIENT_CONSENSUS_STATE}")
+            }
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -400,11 +481,15 @@ impl Path {
 pub enum PathError {
     /// `{path}` could not be parsed into a Path
     ParseFailure { path: String },
+    /// `{path}` is a valid path, but not of the expected kind `{expected}`
+    UnexpectedPathKind { path: String, expected: String },
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for PathError {}
 
+impl IbcError for PathError {}
+
 /// The FromStr trait allows paths encoded as strings to be parsed into Paths.
 impl FromStr for Path {
     type Err = PathError;
@@ -414,12 +499,14 @@ impl FromStr for Path {
 
         parse_client_paths(&components)
             .or_else(|| parse_connections(&components))
+            .or_else(|| parse_connection_upgrades(&components))
             .or_else(|| parse_ports(&components))
             .or_else(|| parse_channel_ends(&components))
             .or_else(|| parse_seqs(&components))
             .or_else(|| parse_commitments(&components))
             .or_else(|| parse_acks(&components))
             .or_else(|| parse_receipts(&components))
+            .or_else(|| parse_receipt_bitmaps(&components))
             .or_else(|| parse_upgrades(&components))
             .ok_or(PathError::ParseFailure {
                 path: s.to_string(),
@@ -427,13 +514,70 @@ impl FromStr for Path {
     }
 }
 
+impl TryFrom<&str> for Path {
+    type Error = PathError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+/// Implements `FromStr` and `TryFrom<&str>` for the paths wrapped by the
+/// variants of [`Path`], so that raw store keys can be parsed directly into
+/// the kind of path their reader expects.
+macro_rules! impl_path_from_str {
+    ($($variant:ident($path:ident)),+ $(,)?) => {
+        $(
+            impl FromStr for $path {
+                type Err = PathError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match Path::from_str(s)? {
+                        Path::$variant(path) => Ok(path),
+                        _ => Err(PathError::UnexpectedPathKind {
+                            path: s.to_string(),
+                            expected: stringify!($path).to_string(),
+                        }),
+                    }
+                }
+            }
+
+            impl TryFrom<&str> for $path {
+                type Error = PathError;
+
+                fn try_from(s: &str) -> Result<Self, Self::Error> {
+                    Self::from_str(s)
+                }
+            }
+        )+
+    };
+}
+
+impl_path_from_str!(
+    ClientState(ClientStatePath),
+    ClientConsensusState(ClientConsensusStatePath),
+    ClientConnection(ClientConnectionPath),
+    Connection(ConnectionPath),
+    ConnectionUpgrade(ConnectionUpgradePath),
+    Ports(PortPath),
+    ChannelEnd(ChannelEndPath),
+    SeqSend(SeqSendPath),
+    SeqRecv(SeqRecvPath),
+    SeqAck(SeqAckPath),
+    Commitment(CommitmentPath),
+    Ack(AckPath),
+    Receipt(ReceiptPath),
+    ReceiptBitmap(ReceiptBitmapPath),
+    UpgradeClient(UpgradeClientPath),
+);
+
 fn parse_client_paths(components: &[&str]) -> Option<Path> {
     let first = match components.first() {
         Some(f) => *f,
         None => return None,
     };
 
-    if first != "clients" {
+    if first != CLIENT_PREFIX {
         return None;
     }
 
@@ -444,12 +588,12 @@ fn parse_client_paths(components: &[&str]) -> Option<Path> {
 
     if components.len() == 3 {
         match components[2] {
-            "clientState" => Some(ClientStatePath(client_id).into()),
-            "connections" => Some(ClientConnectionPath(client_id).into()),
+            CLIENT_STATE => Some(ClientStatePath(client_id).into()),
+            CONNECTION_PREFIX => Some(ClientConnectionPath(client_id).into()),
             _ => None,
         }
     } else if components.len() == 4 {
-        if "consensusStates" != components[2] {
+        if CONSENSUS_STATE_PREFIX != components[2] {
             return None;
         }
 
@@ -500,7 +644,7 @@ fn parse_connections(components: &[&str]) -> Option<Path> {
         None => return None,
     };
 
-    if first != "connections" {
+    if first != CONNECTION_PREFIX {
         return None;
     }
 
@@ -517,6 +661,15 @@ fn parse_connections(components: &[&str]) -> Option<Path> {
     Some(ConnectionPath(connection_id).into())
 }
 
+fn parse_connection_upgrades(components: &[&str]) -> Option<Path> {
+    match components {
+        [CONNECTION_UPGRADE_PREFIX, connection_id] => ConnectionId::from_str(connection_id)
+            .ok()
+            .map(|connection_id| ConnectionUpgradePath(connection_id).into()),
+        _ => None,
+    }
+}
+
 fn parse_ports(components: &[&str]) -> Option<Path> {
     if components.len() != 2 {
         return None;
@@ -527,7 +680,7 @@ fn parse_ports(components: &[&str]) -> Option<Path> {
         None => return None,
     };
 
-    if first != "ports" {
+    if first != PORT_PREFIX {
         return None;
     }
 
@@ -554,7 +707,7 @@ fn parse_channels(components: &[&str]) -> Option<SubPath> {
         None => return None,
     };
 
-    if first != "channels" {
+    if first != CHANNEL_PREFIX {
         return None;
     }
 
@@ -581,7 +734,7 @@ fn parse_sequences(components: &[&str]) -> Option<SubPath> {
         None => return None,
     };
 
-    if first != "sequences" {
+    if first != SEQUENCE_PREFIX {
         return None;
     }
 
@@ -606,7 +759,7 @@ fn parse_channel_ends(components: &[&str]) -> Option<Path> {
         None => return None,
     };
 
-    if first != "channelEnds" {
+    if first != CHANNEL_END_PREFIX {
         return None;
     }
 
@@ -654,9 +807,9 @@ fn parse_seqs(components: &[&str]) -> Option<Path> {
     };
 
     match first {
-        "nextSequenceSend" => Some(SeqSendPath(port_id, channel_id).into()),
-        "nextSequenceRecv" => Some(SeqRecvPath(port_id, channel_id).into()),
-        "nextSequenceAck" => Some(SeqAckPath(port_id, channel_id).into()),
+        NEXT_SEQ_SEND_PREFIX => Some(SeqSendPath(port_id, channel_id).into()),
+        NEXT_SEQ_RECV_PREFIX => Some(SeqRecvPath(port_id, channel_id).into()),
+        NEXT_SEQ_ACK_PREFIX => Some(SeqAckPath(port_id, channel_id).into()),
         _ => None,
     }
 }
@@ -671,7 +824,7 @@ fn parse_commitments(components: &[&str]) -> Option<Path> {
         None => return None,
     };
 
-    if first != "commitments" {
+    if first != PACKET_COMMITMENT_PREFIX {
         return None;
     }
 
@@ -717,7 +870,7 @@ fn parse_acks(components: &[&str]) -> Option<Path> {
         None => return None,
     };
 
-    if first != "acks" {
+    if first != PACKET_ACK_PREFIX {
         return None;
     }
 
@@ -763,7 +916,7 @@ fn parse_receipts(components: &[&str]) -> Option<Path> {
         None => return None,
     };
 
-    if first != "receipts" {
+    if first != PACKET_RECEIPT_PREFIX {
         return None;
     }
 
@@ -799,6 +952,22 @@ fn parse_receipts(components: &[&str]) -> Option<Path> {
     )
 }
 
+fn parse_receipt_bitmaps(components: &[&str]) -> Option<Path> {
+    match components {
+        [RECEIPT_BITMAP_PREFIX, PORT_PREFIX, port_id, CHANNEL_PREFIX, channel_id, CHUNK_PREFIX, chunk] => {
+            Some(
+                ReceiptBitmapPath {
+                    port_id: PortId::from_str(port_id).ok()?,
+                    channel_id: ChannelId::from_str(channel_id).ok()?,
+                    chunk: chunk.parse().ok()?,
+                }
+                .into(),
+            )
+        }
+        _ => None,
+    }
+}
+
 fn parse_upgrades(components: &[&str]) -> Option<Path> {
     if components.len() != 3 {
         return None;
@@ -834,6 +1003,7 @@ fn parse_upgrades(components: &[&str]) -> Option<Path> {
 
 #[cfg(test)]
 mod tests {
+    use core::fmt::{Debug, Display};
     use core::str::FromStr;
 
     use super::*;
@@ -931,6 +1101,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn connection_upgrade_path_parses() {
+        let path = "connectionUpgrades/connection-0";
+
+        assert_eq!(
+            Path::from_str(path).unwrap(),
+            Path::ConnectionUpgrade(ConnectionUpgradePath(ConnectionId::new(0)))
+        );
+        assert_eq!(
+            ConnectionUpgradePath::new(&ConnectionId::new(0)).to_string(),
+            path
+        );
+    }
+
     #[test]
     fn test_parse_ports_fn() {
         let path = "ports/transfer";
@@ -1203,6 +1387,17 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_upgrade_client_path_key_under() {
+        let path = UpgradeClientPath::UpgradedClientState(5);
+        assert_eq!(path.key_under(UPGRADED_IBC_STATE), path.to_string());
+        assert_eq!(path.key_under("ibc"), "ibc/5/upgradedClient");
+
+        let path = UpgradeClientPath::UpgradedClientConsensusState(5);
+        assert_eq!(path.key_under(UPGRADED_IBC_STATE), path.to_string());
+        assert_eq!(path.key_under("ibc"), "ibc/5/upgradedConsState");
+    }
+
     #[test]
     fn upgrade_client_state_path_parses() {
         let path = "upgradedIBCState/0/upgradedClient";
@@ -1226,4 +1421,58 @@ mod tests {
             Path::UpgradeClient(UpgradeClientPath::UpgradedClientConsensusState(0)),
         );
     }
+
+    fn assert_round_trip<P>(path: P)
+    where
+        P: FromStr<Err = PathError> + for<'a> TryFrom<&'a str, Error = PathError>,
+        P: Into<Path> + Clone + Display + PartialEq + Debug,
+    {
+        let raw = path.to_string();
+        assert_eq!(P::from_str(&raw).unwrap(), path);
+        assert_eq!(P::try_from(raw.as_str()).unwrap(), path);
+        assert_eq!(Path::try_from(raw.as_str()).unwrap(), path.clone().into());
+    }
+
+    #[test]
+    fn test_every_path_round_trips() {
+        let client_id = ClientId::default();
+        let conn_id = ConnectionId::new(7);
+        let port_id = PortId::transfer();
+        let chan_id = ChannelId::new(3);
+        let sequence = Sequence::from(42);
+
+        assert_round_trip(ClientStatePath::new(&client_id));
+        assert_round_trip(ClientConsensusStatePath::new(
+            &client_id,
+            &Height::new(15, 31).unwrap(),
+        ));
+        assert_round_trip(ClientConnectionPath::new(&client_id));
+        assert_round_trip(ConnectionPath::new(&conn_id));
+        assert_round_trip(PortPath(port_id.clone()));
+        assert_round_trip(ChannelEndPath::new(&port_id, &chan_id));
+        assert_round_trip(SeqSendPath::new(&port_id, &chan_id));
+        assert_round_trip(SeqRecvPath::new(&port_id, &chan_id));
+        assert_round_trip(SeqAckPath::new(&port_id, &chan_id));
+        assert_round_trip(CommitmentPath::new(&port_id, &chan_id, sequence));
+        assert_round_trip(AckPath::new(&port_id, &chan_id, sequence));
+        assert_round_trip(ReceiptPath::new(&port_id, &chan_id, sequence));
+        assert_round_trip(ReceiptBitmapPath::new(&port_id, &chan_id, 3));
+        assert_round_trip(UpgradeClientPath::UpgradedClientState(5));
+        assert_round_trip(UpgradeClientPath::UpgradedClientConsensusState(5));
+    }
+
+    #[test]
+    fn test_path_of_unexpected_kind() {
+        let raw = "commitments/ports/transfer/channels/channel-0/sequences/1";
+
+        assert!(CommitmentPath::from_str(raw).is_ok());
+        assert!(matches!(
+            AckPath::from_str(raw),
+            Err(PathError::UnexpectedPathKind { expected, .. }) if expected == "AckPath"
+        ));
+        assert!(matches!(
+            ReceiptPath::try_from("receipts/ports/transfer"),
+            Err(PathError::ParseFailure { .. })
+        ));
+    }
 }
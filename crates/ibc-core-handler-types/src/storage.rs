@@ -0,0 +1,171 @@
+//! Defines the layout of the IBC store: the segments of the ICS-24 paths, and
+//! the keys under which hosts store the value of each [`Path`].
+//!
+//! The key of a path is its string form, e.g.
+//! `commitments/ports/transfer/channels/channel-0/sequences/1`, so that the
+//! proofs of hosts verify against the paths counterparties build.
+
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_commitment_types::merkle::apply_prefix;
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::commitment::v1::MerklePath;
+
+use crate::path::Path;
+
+/// Prefix of the keys of the client states, consensus states and connections
+/// of each client, e.g. `clients/07-tendermint-0/clientState`.
+pub const CLIENT_PREFIX: &str = "clients";
+/// Last segment of the keys of client states.
+pub const CLIENT_STATE: &str = "clientState";
+/// Segment of the keys of consensus states, followed by their height.
+pub const CONSENSUS_STATE_PREFIX: &str = "consensusStates";
+/// Prefix of the keys of connection ends, and last segment of the keys of the
+/// connections of a client.
+pub const CONNECTION_PREFIX: &str = "connections";
+/// Prefix of the keys of the upgrades in progress of connections.
+pub const CONNECTION_UPGRADE_PREFIX: &str = "connectionUpgrades";
+/// Prefix of the keys of port capabilities, and segment of the port
+/// identifier in the keys of channels and packets.
+pub const PORT_PREFIX: &str = "ports";
+/// Segment of the channel identifier in the keys of channels and packets.
+pub const CHANNEL_PREFIX: &str = "channels";
+/// Prefix of the keys of channel ends, e.g.
+/// `channelEnds/ports/transfer/channels/channel-0`.
+pub const CHANNEL_END_PREFIX: &str = "channelEnds";
+/// Segment of the packet sequence in the keys of packets.
+pub const SEQUENCE_PREFIX: &str = "sequences";
+/// Prefix of the keys of the next sequence to send on each channel.
+pub const NEXT_SEQ_SEND_PREFIX: &str = "nextSequenceSend";
+/// Prefix of the keys of the next sequence to receive on each channel.
+pub const NEXT_SEQ_RECV_PREFIX: &str = "nextSequenceRecv";
+/// Prefix of the keys of the next sequence to acknowledge on each channel.
+pub const NEXT_SEQ_ACK_PREFIX: &str = "nextSequenceAck";
+/// Prefix of the keys of packet commitments.
+pub const PACKET_COMMITMENT_PREFIX: &str = "commitments";
+/// Prefix of the keys of packet acknowledgement commitments.
+pub const PACKET_ACK_PREFIX: &str = "acks";
+/// Prefix of the keys of packet receipts.
+pub const PACKET_RECEIPT_PREFIX: &str = "receipts";
+/// Prefix of the keys of the bitmaps in which some hosts record the packet
+/// receipts of a channel by chunks of sequences.
+pub const RECEIPT_BITMAP_PREFIX: &str = "receiptBitmaps";
+/// Segment of the chunk index in the keys of receipt bitmaps.
+pub const CHUNK_PREFIX: &str = "chunks";
+/// Prefix of the keys of the upgraded client and consensus states committed
+/// to by the upgrade module, within its own store.
+pub const UPGRADED_IBC_STATE: &str = "upgradedIBCState";
+/// Last segment of the keys of upgraded client states.
+pub const UPGRADED_CLIENT_STATE: &str = "upgradedClient";
+/// Last segment of the keys of upgraded consensus states.
+pub const UPGRADED_CLIENT_CONSENSUS_STATE: &str = "upgradedConsState";
+
+/// Returns the key under which hosts store the value at `path`.
+pub fn key(path: &Path) -> Vec<u8> {
+    path.to_string().into_bytes()
+}
+
+/// Returns the first segment of the key of `path`, which hosts iterating over
+/// all the values of a kind use as the prefix of their keys.
+pub fn key_prefix(path: &Path) -> &'static str {
+    match path {
+        Path::ClientState(_) | Path::ClientConsensusState(_) | Path::ClientConnection(_) => {
+            CLIENT_PREFIX
+        }
+        Path::Connection(_) => CONNECTION_PREFIX,
+        Path::ConnectionUpgrade(_) => CONNECTION_UPGRADE_PREFIX,
+        Path::Ports(_) => PORT_PREFIX,
+        Path::ChannelEnd(_) => CHANNEL_END_PREFIX,
+        Path::SeqSend(_) => NEXT_SEQ_SEND_PREFIX,
+        Path::SeqRecv(_) => NEXT_SEQ_RECV_PREFIX,
+        Path::SeqAck(_) => NEXT_SEQ_ACK_PREFIX,
+        Path::Commitment(_) => PACKET_COMMITMENT_PREFIX,
+        Path::Ack(_) => PACKET_ACK_PREFIX,
+        Path::Receipt(_) => PACKET_RECEIPT_PREFIX,
+        Path::ReceiptBitmap(_) => RECEIPT_BITMAP_PREFIX,
+        Path::UpgradeClient(_) => UPGRADED_IBC_STATE,
+    }
+}
+
+/// Returns the Merkle path of `path` in a host whose IBC store is a sub-store
+/// named after its commitment `prefix`, as in the Cosmos SDK, against which
+/// the proofs of its counterparties are verified.
+pub fn merkle_path(prefix: &CommitmentPrefix, path: &Path) -> MerklePath {
+    apply_prefix(prefix, vec![path.to_string()])
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use ibc_core_channel_types::packet::Sequence;
+    use ibc_core_client_types::height::Height;
+    use ibc_core_host_types::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::path::{
+        AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
+        CommitmentPath, ConnectionPath, ConnectionUpgradePath, PortPath, ReceiptBitmapPath,
+        ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath, UpgradeClientPath,
+    };
+
+    fn port_id() -> PortId {
+        PortId::transfer()
+    }
+
+    fn chan_id() -> ChannelId {
+        ChannelId::new(0)
+    }
+
+    #[rstest]
+    #[case(ClientStatePath::new(&ClientId::default()).into(), "clients/07-tendermint-0/clientState")]
+    #[case(
+        ClientConsensusStatePath::new(&ClientId::default(), &Height::new(1, 10).unwrap()).into(),
+        "clients/07-tendermint-0/consensusStates/1-10"
+    )]
+    #[case(ClientConnectionPath::new(&ClientId::default()).into(), "clients/07-tendermint-0/connections")]
+    #[case(ConnectionPath::new(&ConnectionId::new(0)).into(), "connections/connection-0")]
+    #[case(ConnectionUpgradePath::new(&ConnectionId::new(0)).into(), "connectionUpgrades/connection-0")]
+    #[case(PortPath(port_id()).into(), "ports/transfer")]
+    #[case(ChannelEndPath::new(&port_id(), &chan_id()).into(), "channelEnds/ports/transfer/channels/channel-0")]
+    #[case(SeqSendPath::new(&port_id(), &chan_id()).into(), "nextSequenceSend/ports/transfer/channels/channel-0")]
+    #[case(SeqRecvPath::new(&port_id(), &chan_id()).into(), "nextSequenceRecv/ports/transfer/channels/channel-0")]
+    #[case(SeqAckPath::new(&port_id(), &chan_id()).into(), "nextSequenceAck/ports/transfer/channels/channel-0")]
+    #[case(
+        CommitmentPath::new(&port_id(), &chan_id(), Sequence::from(1)).into(),
+        "commitments/ports/transfer/channels/channel-0/sequences/1"
+    )]
+    #[case(
+        AckPath::new(&port_id(), &chan_id(), Sequence::from(1)).into(),
+        "acks/ports/transfer/channels/channel-0/sequences/1"
+    )]
+    #[case(
+        ReceiptPath::new(&port_id(), &chan_id(), Sequence::from(1)).into(),
+        "receipts/ports/transfer/channels/channel-0/sequences/1"
+    )]
+    #[case(
+        ReceiptBitmapPath::new(&port_id(), &chan_id(), 2).into(),
+        "receiptBitmaps/ports/transfer/channels/channel-0/chunks/2"
+    )]
+    #[case(UpgradeClientPath::UpgradedClientState(5).into(), "upgradedIBCState/5/upgradedClient")]
+    #[case(
+        UpgradeClientPath::UpgradedClientConsensusState(5).into(),
+        "upgradedIBCState/5/upgradedConsState"
+    )]
+    fn test_store_keys(#[case] path: Path, #[case] expected: &str) {
+        assert_eq!(key(&path), expected.as_bytes());
+        assert!(expected.starts_with(key_prefix(&path)));
+        assert_eq!(Path::from_str(expected).unwrap(), path);
+    }
+
+    #[test]
+    fn test_merkle_path() {
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let path = Path::from(ConnectionPath::new(&ConnectionId::new(0)));
+
+        assert_eq!(
+            merkle_path(&prefix, &path).key_path,
+            vec!["ibc".to_string(), "connections/connection-0".to_string()]
+        );
+    }
+}
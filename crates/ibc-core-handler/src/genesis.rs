@@ -0,0 +1,486 @@
+//! Import and export of the IBC core state at genesis.
+//!
+//! [`init_genesis`] writes a [`GenesisState`] to the store of a host through
+//! its [`ExecutionContext`], while [`export_genesis`] reads it back through
+//! the listing methods of [`GenesisContext`], so that chains can carry their
+//! IBC state over an upgrade that restarts them from genesis.
+
+use ibc_core_channel_types::channel::IdentifiedChannelEnd;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::genesis::{ChannelGenesisState, PacketSequence};
+use ibc_core_channel_types::packet::{PacketState, Receipt, Sequence};
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateExecution};
+use ibc_core_client_context::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::genesis::{ClientGenesisState, GenesisClient, ProcessedConsensusState};
+use ibc_core_client_types::height::Height;
+use ibc_core_connection_types::connection::IdentifiedConnectionEnd;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::genesis::{ClientConnections, ConnectionGenesisState};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::path::{
+    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, CommitmentPath,
+    ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host_types::identifier::{ClientId, ConnectionId};
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::types::v1::GenesisState as RawGenesisState;
+use ibc_proto::Protobuf;
+
+/// The state of the IBC core modules, imported at genesis or exported for an
+/// upgrade.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenesisState {
+    pub client_genesis: ClientGenesisState,
+    pub connection_genesis: ConnectionGenesisState,
+    pub channel_genesis: ChannelGenesisState,
+}
+
+impl Protobuf<RawGenesisState> for GenesisState {}
+
+impl TryFrom<RawGenesisState> for GenesisState {
+    type Error = ContextError;
+
+    fn try_from(raw: RawGenesisState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_genesis: raw.client_genesis.unwrap_or_default().try_into()?,
+            connection_genesis: raw.connection_genesis.unwrap_or_default().try_into()?,
+            channel_genesis: raw.channel_genesis.unwrap_or_default().try_into()?,
+        })
+    }
+}
+
+impl From<GenesisState> for RawGenesisState {
+    fn from(genesis: GenesisState) -> Self {
+        Self {
+            client_genesis: Some(genesis.client_genesis.into()),
+            connection_genesis: Some(genesis.connection_genesis.into()),
+            channel_genesis: Some(genesis.channel_genesis.into()),
+        }
+    }
+}
+
+/// Context to be implemented by the host to export its IBC state, listing the
+/// entries of its store.
+///
+/// The methods may return the entries in any order.
+pub trait GenesisContext: ValidationContext {
+    /// Returns the list of all clients.
+    fn client_states(&self) -> Result<Vec<(ClientId, Self::AnyClientState)>, ContextError>;
+
+    /// Returns the list of all consensus states for the given client.
+    fn consensus_states(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<(Height, Self::AnyConsensusState)>, ContextError>;
+
+    /// Returns the list of all connection ends.
+    fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError>;
+
+    /// Returns the list of all connection ids of the given client, in the
+    /// order they are stored in.
+    fn client_connection_ends(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<ConnectionId>, ContextError>;
+
+    /// Returns the list of all channel ends.
+    fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError>;
+
+    /// Returns the list of all packet commitments for the given channel end.
+    fn packet_commitments(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<PacketState>, ContextError>;
+
+    /// Returns the list of all packet acknowledgements for the given channel
+    /// end.
+    fn packet_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<PacketState>, ContextError>;
+
+    /// Returns the sequences of all packet receipts for the given channel end.
+    fn packet_receipts(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, ContextError>;
+}
+
+/// Writes `genesis` to the store of `ctx`.
+///
+/// Each client is initialised with its consensus state at its latest height,
+/// as when it is created, before its other consensus states are stored. The
+/// consensus states are recorded as processed at the times and heights of the
+/// genesis, so that the connection delays elapsed before the export still
+/// count, or at those of the host if the genesis has none.
+/// The identifier counters are raised to the next sequences of the genesis,
+/// which must not be below them.
+pub fn init_genesis<Ctx>(ctx: &mut Ctx, genesis: GenesisState) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    <Ctx::E as ClientExecutionContext>::AnyConsensusState: TryFrom<Any, Error = ClientError>,
+{
+    let GenesisState {
+        client_genesis,
+        connection_genesis,
+        channel_genesis,
+    } = genesis;
+
+    init_client_genesis(ctx, client_genesis)?;
+    init_connection_genesis(ctx, connection_genesis)?;
+    init_channel_genesis(ctx, channel_genesis)
+}
+
+fn init_client_genesis<Ctx>(ctx: &mut Ctx, genesis: ClientGenesisState) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    <Ctx::E as ClientExecutionContext>::AnyConsensusState: TryFrom<Any, Error = ClientError>,
+{
+    genesis.params.validate_basic()?;
+    ctx.store_client_params(genesis.params)?;
+
+    let host_timestamp = ctx.host_timestamp()?;
+    let host_height = ctx.host_height()?;
+
+    for client in genesis.clients {
+        let client_state = ctx.decode_client_state(client.client_state)?;
+        let latest_height = client_state.latest_height();
+
+        let mut latest_consensus_state = None;
+        let mut consensus_states = Vec::with_capacity(client.consensus_states.len());
+        for (height, consensus_state) in client.consensus_states {
+            if height == latest_height {
+                latest_consensus_state = Some(consensus_state);
+            } else {
+                consensus_states.push((height, consensus_state));
+            }
+        }
+        let latest_consensus_state =
+            latest_consensus_state.ok_or_else(|| ClientError::InvalidGenesis {
+                reason: format!(
+                    "no consensus state of client `{}` at its latest height {latest_height}",
+                    client.client_id
+                ),
+            })?;
+
+        client_state.initialise(
+            ctx.get_client_execution_context(),
+            &client.client_id,
+            latest_consensus_state,
+        )?;
+
+        let client_exec_ctx = ctx.get_client_execution_context();
+        let mut consensus_heights = vec![latest_height];
+        for (height, consensus_state) in consensus_states {
+            client_exec_ctx.store_consensus_state(
+                ClientConsensusStatePath::new(&client.client_id, &height),
+                consensus_state.try_into()?,
+            )?;
+            client_exec_ctx.store_update_time(client.client_id.clone(), height, host_timestamp)?;
+            client_exec_ctx.store_update_height(client.client_id.clone(), height, host_height)?;
+            consensus_heights.push(height);
+        }
+
+        for processed in client.processed_consensus_states {
+            if !consensus_heights.contains(&processed.height) {
+                return Err(ClientError::InvalidGenesis {
+                    reason: format!(
+                        "client `{}` processed at height {} without consensus state",
+                        client.client_id, processed.height
+                    ),
+                }
+                .into());
+            }
+            client_exec_ctx.store_update_time(
+                client.client_id.clone(),
+                processed.height,
+                processed.processed_time,
+            )?;
+            client_exec_ctx.store_update_height(
+                client.client_id.clone(),
+                processed.height,
+                processed.processed_height,
+            )?;
+        }
+    }
+
+    let counter = ctx.client_counter()?;
+    if counter > genesis.next_client_sequence {
+        return Err(ClientError::InvalidGenesis {
+            reason: format!(
+                "next client sequence {} is below the client counter {counter}",
+                genesis.next_client_sequence
+            ),
+        }
+        .into());
+    }
+    for _ in counter..genesis.next_client_sequence {
+        ctx.increase_client_counter()?;
+    }
+
+    Ok(())
+}
+
+fn init_connection_genesis<Ctx>(
+    ctx: &mut Ctx,
+    genesis: ConnectionGenesisState,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx.store_connection_params(genesis.params)?;
+
+    for connection in genesis.connections {
+        ctx.store_connection(
+            &ConnectionPath::new(&connection.connection_id),
+            connection.connection_end,
+        )?;
+    }
+    for client_connections in genesis.client_connections {
+        let client_connection_path = ClientConnectionPath::new(&client_connections.client_id);
+        for conn_id in client_connections.connection_ids {
+            ctx.store_connection_to_client(&client_connection_path, conn_id)?;
+        }
+    }
+
+    let counter = ctx.connection_counter()?;
+    if counter > genesis.next_connection_sequence {
+        return Err(ConnectionError::InvalidGenesis {
+            reason: format!(
+                "next connection sequence {} is below the connection counter {counter}",
+                genesis.next_connection_sequence
+            ),
+        }
+        .into());
+    }
+    for _ in counter..genesis.next_connection_sequence {
+        ctx.increase_connection_counter()?;
+    }
+
+    Ok(())
+}
+
+fn init_channel_genesis<Ctx>(
+    ctx: &mut Ctx,
+    genesis: ChannelGenesisState,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    for channel in genesis.channels {
+        ctx.store_channel(
+            &ChannelEndPath::new(&channel.port_id, &channel.channel_id),
+            channel.channel_end,
+        )?;
+    }
+
+    for commitment in genesis.commitments {
+        ctx.store_packet_commitment(
+            &CommitmentPath::new(&commitment.port_id, &commitment.chan_id, commitment.seq),
+            commitment.data.into(),
+        )?;
+    }
+    for ack in genesis.acknowledgements {
+        ctx.store_packet_acknowledgement(
+            &AckPath::new(&ack.port_id, &ack.chan_id, ack.seq),
+            ack.data.into(),
+        )?;
+    }
+    for receipt in genesis.receipts {
+        ctx.store_packet_receipt(
+            &ReceiptPath::new(&receipt.port_id, &receipt.chan_id, receipt.seq),
+            Receipt::Ok,
+        )?;
+    }
+
+    for sequence in genesis.send_sequences {
+        ctx.store_next_sequence_send(
+            &SeqSendPath::new(&sequence.port_id, &sequence.chan_id),
+            sequence.seq,
+        )?;
+    }
+    for sequence in genesis.recv_sequences {
+        ctx.store_next_sequence_recv(
+            &SeqRecvPath::new(&sequence.port_id, &sequence.chan_id),
+            sequence.seq,
+        )?;
+    }
+    for sequence in genesis.ack_sequences {
+        ctx.store_next_sequence_ack(
+            &SeqAckPath::new(&sequence.port_id, &sequence.chan_id),
+            sequence.seq,
+        )?;
+    }
+
+    let counter = ctx.channel_counter()?;
+    if counter > genesis.next_channel_sequence {
+        return Err(ChannelError::InvalidGenesis {
+            reason: format!(
+                "next channel sequence {} is below the channel counter {counter}",
+                genesis.next_channel_sequence
+            ),
+        }
+        .into());
+    }
+    for _ in counter..genesis.next_channel_sequence {
+        ctx.increase_channel_counter()?;
+    }
+
+    Ok(())
+}
+
+/// Reads the IBC state of `ctx` as a [`GenesisState`].
+///
+/// Clients, connections and channels are sorted by identifier, and packet
+/// states by sequence, so that the export does not depend on the order the
+/// host lists them in.
+pub fn export_genesis<Ctx>(ctx: &Ctx) -> Result<GenesisState, ContextError>
+where
+    Ctx: GenesisContext,
+    Ctx::AnyClientState: Into<Any>,
+    Ctx::AnyConsensusState: Into<Any>,
+{
+    let mut client_states = ctx.client_states()?;
+    client_states.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut clients = Vec::with_capacity(client_states.len());
+    let mut client_connections = Vec::new();
+    for (client_id, client_state) in client_states {
+        let mut consensus_states = ctx.consensus_states(&client_id)?;
+        consensus_states.sort_by_key(|(height, _)| *height);
+
+        let connection_ids = ctx.client_connection_ends(&client_id)?;
+        if !connection_ids.is_empty() {
+            client_connections.push(ClientConnections {
+                client_id: client_id.clone(),
+                connection_ids,
+            });
+        }
+
+        let processed_consensus_states = consensus_states
+            .iter()
+            .filter_map(|(height, _)| {
+                processed_consensus_state(ctx, &client_id, *height).transpose()
+            })
+            .collect::<Result<_, _>>()?;
+
+        clients.push(GenesisClient {
+            client_id,
+            client_state: client_state.into(),
+            consensus_states: consensus_states
+                .into_iter()
+                .map(|(height, consensus_state)| (height, consensus_state.into()))
+                .collect(),
+            processed_consensus_states,
+        });
+    }
+
+    let mut connections = ctx.connection_ends()?;
+    connections.sort_by(|a, b| a.connection_id.cmp(&b.connection_id));
+
+    let mut channels = ctx.channel_ends()?;
+    channels.sort_by(|a, b| (&a.port_id, &a.channel_id).cmp(&(&b.port_id, &b.channel_id)));
+
+    let mut channel_genesis = ChannelGenesisState {
+        channels: Vec::with_capacity(channels.len()),
+        acknowledgements: Vec::new(),
+        commitments: Vec::new(),
+        receipts: Vec::new(),
+        send_sequences: Vec::with_capacity(channels.len()),
+        recv_sequences: Vec::with_capacity(channels.len()),
+        ack_sequences: Vec::with_capacity(channels.len()),
+        next_channel_sequence: ctx.channel_counter()?,
+    };
+    for channel in channels {
+        let port_id = &channel.port_id;
+        let chan_id = &channel.channel_id;
+        let channel_end_path = ChannelEndPath::new(port_id, chan_id);
+        let packet_sequence = |seq| PacketSequence {
+            port_id: port_id.clone(),
+            chan_id: chan_id.clone(),
+            seq,
+        };
+
+        let mut commitments = ctx.packet_commitments(&channel_end_path)?;
+        commitments.sort_by_key(|state| state.seq);
+        channel_genesis.commitments.extend(commitments);
+
+        let mut acks = ctx.packet_acknowledgements(&channel_end_path)?;
+        acks.sort_by_key(|state| state.seq);
+        channel_genesis.acknowledgements.extend(acks);
+
+        let mut receipts = ctx.packet_receipts(&channel_end_path)?;
+        receipts.sort();
+        channel_genesis
+            .receipts
+            .extend(receipts.into_iter().map(|seq| PacketState {
+                port_id: port_id.clone(),
+                chan_id: chan_id.clone(),
+                seq,
+                data: vec![1],
+            }));
+
+        channel_genesis.send_sequences.push(packet_sequence(
+            ctx.get_next_sequence_send(&SeqSendPath::new(port_id, chan_id))?,
+        ));
+        channel_genesis.recv_sequences.push(packet_sequence(
+            ctx.get_next_sequence_recv(&SeqRecvPath::new(port_id, chan_id))?,
+        ));
+        channel_genesis.ack_sequences.push(packet_sequence(
+            ctx.get_next_sequence_ack(&SeqAckPath::new(port_id, chan_id))?,
+        ));
+
+        channel_genesis.channels.push(channel);
+    }
+
+    Ok(GenesisState {
+        client_genesis: ClientGenesisState {
+            clients,
+            params: ctx.client_params()?,
+            next_client_sequence: ctx.client_counter()?,
+        },
+        connection_genesis: ConnectionGenesisState {
+            connections,
+            client_connections,
+            params: ctx.connection_params()?,
+            next_connection_sequence: ctx.connection_counter()?,
+        },
+        channel_genesis,
+    })
+}
+
+/// Returns the time and height at which `ctx` processed the consensus state
+/// of `client_id` at `height`, if it recorded them.
+fn processed_consensus_state<Ctx>(
+    ctx: &Ctx,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<Option<ProcessedConsensusState>, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let client_val_ctx = ctx.get_client_validation_context();
+    let processed_time = match client_val_ctx.client_update_time(client_id, &height) {
+        Ok(processed_time) => processed_time,
+        Err(ContextError::ClientError(ClientError::ProcessedTimeNotFound { .. })) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err),
+    };
+    let processed_height = match client_val_ctx.client_update_height(client_id, &height) {
+        Ok(processed_height) => processed_height,
+        Err(ContextError::ClientError(ClientError::ProcessedHeightNotFound { .. })) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err),
+    };
+
+    Ok(Some(ProcessedConsensusState {
+        height,
+        processed_time,
+        processed_height,
+    }))
+}
@@ -0,0 +1,390 @@
+use ibc_core_channel::handler::acknowledgement::{
+    acknowledgement_packet_execute, acknowledgement_packet_validate,
+};
+use ibc_core_channel::handler::chan_close_confirm::{
+    chan_close_confirm_execute, chan_close_confirm_validate,
+};
+use ibc_core_channel::handler::chan_close_init::{
+    chan_close_init_execute, chan_close_init_validate,
+};
+use ibc_core_channel::handler::chan_open_ack::{chan_open_ack_execute, chan_open_ack_validate};
+use ibc_core_channel::handler::chan_open_confirm::{
+    chan_open_confirm_execute, chan_open_confirm_validate,
+};
+use ibc_core_channel::handler::chan_open_init::{chan_open_init_execute, chan_open_init_validate};
+use ibc_core_channel::handler::chan_open_try::{chan_open_try_execute, chan_open_try_validate};
+#[cfg(feature = "localhost")]
+use ibc_core_channel::handler::loopback::{is_localhost_channel, loopback_packet};
+use ibc_core_channel::handler::recv_packet::{recv_packet_execute, recv_packet_validate};
+#[cfg(feature = "localhost")]
+use ibc_core_channel::handler::send_packet::send_packet;
+use ibc_core_channel::handler::timeout::{
+    timeout_packet_execute, timeout_packet_validate, TimeoutMsgType,
+};
+#[cfg(feature = "localhost")]
+use ibc_core_channel_types::msgs::recv_packet::RecvOutcome;
+use ibc_core_channel_types::msgs::{
+    channel_msg_to_port_id, packet_msg_to_port_id, ChannelMsg, PacketMsg,
+};
+#[cfg(feature = "localhost")]
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_client::handler::{create_client, update_client, upgrade_client};
+use ibc_core_client_types::msgs::{ClientMsg, MsgUpdateOrMisbehaviour};
+use ibc_core_connection::handler::{
+    conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try,
+};
+#[cfg(feature = "connection-upgrade")]
+use ibc_core_connection::handler::{
+    conn_upgrade_ack, conn_upgrade_cancel, conn_upgrade_confirm, conn_upgrade_init,
+    conn_upgrade_timeout, conn_upgrade_try,
+};
+use ibc_core_connection_types::msgs::ConnectionMsg;
+use ibc_core_handler_types::error::RouterError;
+use ibc_core_handler_types::msgs::{HandlerOutput, MsgEnvelope, MsgExpiry, MsgOutput};
+#[cfg(feature = "localhost")]
+use ibc_core_handler_types::path::ChannelEndPath;
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+#[cfg(feature = "localhost")]
+use ibc_primitives::signer::Signer;
+
+use super::simulation::RecordingContext;
+
+/// Entrypoint which performs both validation and message execution
+pub fn dispatch(
+    ctx: &mut impl ExecutionContext,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+) -> Result<HandlerOutput, RouterError> {
+    validate(ctx, router, msg.clone())?;
+    execute(ctx, router, msg)
+}
+
+/// Entrypoint which performs both validation and message execution, rejecting
+/// the message upfront if the host is past its `expiry`
+pub fn dispatch_with_expiry(
+    ctx: &mut impl ExecutionContext,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+    expiry: &MsgExpiry,
+) -> Result<HandlerOutput, RouterError> {
+    validate_with_expiry(ctx, router, msg.clone(), expiry)?;
+    execute(ctx, router, msg)
+}
+
+/// Entrypoint which sends `packet` from a module of the host, and loops it
+/// back if it is sent on a localhost channel, i.e. to the host itself: the
+/// packet is then received by the module of its destination port and its
+/// acknowledgement delivered to the sending module, all at once.
+///
+/// Returns the outcome of the loopback, or `None` if the packet is sent to
+/// another chain, over which relayers carry it as usual.
+#[cfg(feature = "localhost")]
+pub fn send_packet_with_loopback(
+    ctx: &mut impl ExecutionContext,
+    router: &mut impl Router,
+    packet: Packet,
+    signer: &Signer,
+) -> Result<Option<RecvOutcome>, RouterError> {
+    send_packet(ctx, packet.clone())?;
+
+    let chan_end_on_a = ctx.channel_end(&ChannelEndPath::new(
+        &packet.port_id_on_a,
+        &packet.chan_id_on_a,
+    ))?;
+    if !is_localhost_channel(ctx, &chan_end_on_a)? {
+        return Ok(None);
+    }
+
+    loopback_packet(ctx, router, packet, signer).map(Some)
+}
+
+/// Entrypoint which performs message validation like [`validate`], after
+/// checking the host clock against the `expiry` of the message.
+///
+/// The expiry is checked before any store access or proof verification, so
+/// that stale messages are rejected at the lowest possible cost.
+pub fn validate_with_expiry<Ctx>(
+    ctx: &Ctx,
+    router: &impl Router,
+    msg: MsgEnvelope,
+    expiry: &MsgExpiry,
+) -> Result<(), RouterError>
+where
+    Ctx: ValidationContext,
+{
+    expiry.verify(ctx.host_height()?, ctx.host_timestamp()?)?;
+
+    validate(ctx, router, msg)
+}
+
+/// Entrypoint which only performs message validation
+///
+/// If a transaction contains `n` messages `m_1` ... `m_n`, then
+/// they MUST be processed as follows:
+///     validate(m_1), execute(m_1), ..., validate(m_n), execute(m_n)
+/// That is, the state transition of message `i` must be applied before
+/// message `i+1` is validated. This is equivalent to calling
+/// `dispatch()` on each successively.
+///
+/// Messages refused by the [`MsgFilter`](ibc_core_handler_types::msgs::MsgFilter) of the host are
+/// rejected before any other check, and those whose signer the host refuses
+/// through [`ValidationContext::validate_message_signer`] right after. The
+/// identifiers referring to the host are then checked against its
+/// [`ValidationContext::identifier_validation_config`]. Clients of types
+/// unknown to the host, as told by [`ValidationContext::known_client_types`],
+/// are rejected with a [`RouterError::UnknownClientStateType`] listing the
+/// known ones.
+pub fn validate<Ctx>(ctx: &Ctx, router: &impl Router, msg: MsgEnvelope) -> Result<(), RouterError>
+where
+    Ctx: ValidationContext,
+{
+    #[cfg(feature = "tracing")]
+    let _span = super::span::validate_span(&msg).entered();
+
+    ctx.msg_filter().filter_msg(&msg)?;
+
+    ctx.validate_message_signer(msg.signer())?;
+
+    msg.validate_identifiers(&ctx.identifier_validation_config())?;
+
+    if let MsgEnvelope::Client(ClientMsg::CreateClient(ref msg)) = msg {
+        let client_state_type = &msg.client_state.type_url;
+        let known_client_types = ctx.known_client_types();
+        if !known_client_types.is_known_client_state(client_state_type) {
+            return Err(RouterError::UnknownClientStateType {
+                client_state_type: client_state_type.clone(),
+                known_types: known_client_types.known_client_state_types(),
+            });
+        }
+    }
+
+    match msg {
+        MsgEnvelope::Client(msg) => match msg {
+            ClientMsg::CreateClient(msg) => create_client::validate(ctx, msg),
+            ClientMsg::UpdateClient(msg) => {
+                update_client::validate(ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg))
+            }
+            ClientMsg::Misbehaviour(msg) => {
+                update_client::validate(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))
+            }
+            ClientMsg::UpgradeClient(msg) => upgrade_client::validate(ctx, msg),
+        }
+        .map_err(RouterError::ContextError),
+        MsgEnvelope::Connection(msg) => match msg {
+            ConnectionMsg::OpenInit(msg) => conn_open_init::validate(ctx, msg),
+            ConnectionMsg::OpenTry(msg) => conn_open_try::validate(ctx, msg),
+            ConnectionMsg::OpenAck(msg) => conn_open_ack::validate(ctx, msg),
+            ConnectionMsg::OpenConfirm(ref msg) => conn_open_confirm::validate(ctx, msg),
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeInit(ref msg) => conn_upgrade_init::validate(ctx, msg),
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeTry(ref msg) => conn_upgrade_try::validate(ctx, msg),
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeAck(ref msg) => conn_upgrade_ack::validate(ctx, msg),
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeConfirm(ref msg) => conn_upgrade_confirm::validate(ctx, msg),
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeTimeout(ref msg) => conn_upgrade_timeout::validate(ctx, msg),
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeCancel(ref msg) => conn_upgrade_cancel::validate(ctx, msg),
+        }
+        .map_err(RouterError::ContextError),
+        MsgEnvelope::Channel(msg) => {
+            let port_id = channel_msg_to_port_id(&msg);
+            let module_id = router
+                .lookup_module(port_id)
+                .ok_or(RouterError::UnknownPort {
+                    port_id: port_id.clone(),
+                })?;
+            let module = router
+                .get_route(&module_id)
+                .ok_or(RouterError::ModuleNotFound)?;
+
+            match msg {
+                ChannelMsg::OpenInit(msg) => chan_open_init_validate(ctx, module, msg),
+                ChannelMsg::OpenTry(msg) => chan_open_try_validate(ctx, module, msg),
+                ChannelMsg::OpenAck(msg) => chan_open_ack_validate(ctx, module, msg),
+                ChannelMsg::OpenConfirm(msg) => chan_open_confirm_validate(ctx, module, msg),
+                ChannelMsg::CloseInit(msg) => chan_close_init_validate(ctx, module, msg),
+                ChannelMsg::CloseConfirm(msg) => chan_close_confirm_validate(ctx, module, msg),
+            }
+            .map_err(RouterError::ContextError)
+        }
+        MsgEnvelope::Packet(msg) => {
+            let port_id = packet_msg_to_port_id(&msg);
+            let module_id = router
+                .lookup_module(port_id)
+                .ok_or(RouterError::UnknownPort {
+                    port_id: port_id.clone(),
+                })?;
+            let module = router
+                .get_route(&module_id)
+                .ok_or(RouterError::ModuleNotFound)?;
+
+            match msg {
+                PacketMsg::Recv(msg) => recv_packet_validate(ctx, msg),
+                PacketMsg::Ack(msg) => acknowledgement_packet_validate(ctx, module, msg),
+                PacketMsg::Timeout(msg) => {
+                    timeout_packet_validate(ctx, module, TimeoutMsgType::Timeout(msg))
+                }
+                PacketMsg::TimeoutOnClose(msg) => {
+                    timeout_packet_validate(ctx, module, TimeoutMsgType::TimeoutOnClose(msg))
+                }
+            }
+            .map_err(RouterError::ContextError)
+        }
+    }
+}
+
+/// Entrypoint which only performs message execution, returning the
+/// [`HandlerOutput`] of the handler: its [`MsgOutput`], e.g. the identifiers
+/// it generated, along with the events it emitted and the messages it logged
+/// through `ctx`.
+pub fn execute<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+) -> Result<HandlerOutput, RouterError>
+where
+    Ctx: ExecutionContext,
+{
+    #[cfg(feature = "tracing")]
+    let _span = super::span::execute_span(&msg).entered();
+
+    let mut recorder = RecordingContext::without_writes(ctx);
+    let result = execute_msg(&mut recorder, router, msg)?;
+
+    Ok(recorder.into_handler_output(result))
+}
+
+/// Executes the message on `ctx`, returning the [`MsgOutput`] of its handler.
+pub(crate) fn execute_msg<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+) -> Result<MsgOutput, RouterError>
+where
+    Ctx: ExecutionContext,
+{
+    match msg {
+        MsgEnvelope::Client(msg) => match msg {
+            ClientMsg::CreateClient(msg) => {
+                create_client::execute(ctx, msg).map(MsgOutput::CreateClient)
+            }
+            ClientMsg::UpdateClient(msg) => {
+                update_client::execute(ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg))
+                    .map(MsgOutput::UpdateClient)
+            }
+            ClientMsg::Misbehaviour(msg) => {
+                update_client::execute(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))
+                    .map(MsgOutput::UpdateClient)
+            }
+            ClientMsg::UpgradeClient(msg) => {
+                upgrade_client::execute(ctx, msg).map(|()| MsgOutput::Executed)
+            }
+        }
+        .map_err(RouterError::ContextError),
+        MsgEnvelope::Connection(msg) => match msg {
+            ConnectionMsg::OpenInit(msg) => {
+                conn_open_init::execute(ctx, msg).map(MsgOutput::ConnOpenInit)
+            }
+            ConnectionMsg::OpenTry(msg) => {
+                conn_open_try::execute(ctx, msg).map(MsgOutput::ConnOpenTry)
+            }
+            ConnectionMsg::OpenAck(msg) => {
+                conn_open_ack::execute(ctx, msg).map(|()| MsgOutput::Executed)
+            }
+            ConnectionMsg::OpenConfirm(ref msg) => {
+                conn_open_confirm::execute(ctx, msg).map(|()| MsgOutput::Executed)
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeInit(ref msg) => {
+                conn_upgrade_init::execute(ctx, msg).map(|()| MsgOutput::Executed)
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeTry(ref msg) => {
+                conn_upgrade_try::execute(ctx, msg).map(|()| MsgOutput::Executed)
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeAck(ref msg) => {
+                conn_upgrade_ack::execute(ctx, msg).map(|()| MsgOutput::Executed)
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeConfirm(ref msg) => {
+                conn_upgrade_confirm::execute(ctx, msg).map(|()| MsgOutput::Executed)
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeTimeout(ref msg) => {
+                conn_upgrade_timeout::execute(ctx, msg).map(|()| MsgOutput::Executed)
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeCancel(ref msg) => {
+                conn_upgrade_cancel::execute(ctx, msg).map(|()| MsgOutput::Executed)
+            }
+        }
+        .map_err(RouterError::ContextError),
+        MsgEnvelope::Channel(msg) => {
+            let port_id = channel_msg_to_port_id(&msg);
+            let module_id = router
+                .lookup_module(port_id)
+                .ok_or(RouterError::UnknownPort {
+                    port_id: port_id.clone(),
+                })?;
+            let module = router
+                .get_route_mut(&module_id)
+                .ok_or(RouterError::ModuleNotFound)?;
+
+            match msg {
+                ChannelMsg::OpenInit(msg) => {
+                    chan_open_init_execute(ctx, module, msg).map(MsgOutput::ChanOpenInit)
+                }
+                ChannelMsg::OpenTry(msg) => {
+                    chan_open_try_execute(ctx, module, msg).map(MsgOutput::ChanOpenTry)
+                }
+                ChannelMsg::OpenAck(msg) => {
+                    chan_open_ack_execute(ctx, module, msg).map(|()| MsgOutput::Executed)
+                }
+                ChannelMsg::OpenConfirm(msg) => {
+                    chan_open_confirm_execute(ctx, module, msg).map(|()| MsgOutput::Executed)
+                }
+                ChannelMsg::CloseInit(msg) => {
+                    chan_close_init_execute(ctx, module, msg).map(|()| MsgOutput::Executed)
+                }
+                ChannelMsg::CloseConfirm(msg) => {
+                    chan_close_confirm_execute(ctx, module, msg).map(|()| MsgOutput::Executed)
+                }
+            }
+            .map_err(RouterError::ContextError)
+        }
+        MsgEnvelope::Packet(msg) => {
+            let port_id = packet_msg_to_port_id(&msg);
+            let module_id = router
+                .lookup_module(port_id)
+                .ok_or(RouterError::UnknownPort {
+                    port_id: port_id.clone(),
+                })?;
+            let module = router
+                .get_route_mut(&module_id)
+                .ok_or(RouterError::ModuleNotFound)?;
+
+            match msg {
+                PacketMsg::Recv(msg) => {
+                    recv_packet_execute(ctx, module, msg).map(MsgOutput::RecvPacket)
+                }
+                PacketMsg::Ack(msg) => {
+                    acknowledgement_packet_execute(ctx, module, msg).map(|()| MsgOutput::Executed)
+                }
+                PacketMsg::Timeout(msg) => {
+                    timeout_packet_execute(ctx, module, TimeoutMsgType::Timeout(msg))
+                        .map(|()| MsgOutput::Executed)
+                }
+                PacketMsg::TimeoutOnClose(msg) => {
+                    timeout_packet_execute(ctx, module, TimeoutMsgType::TimeoutOnClose(msg))
+                        .map(|()| MsgOutput::Executed)
+                }
+            }
+            .map_err(RouterError::ContextError)
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Consistency checks between the packet stores of a channel, for hosts to
+//! run where a broken store would otherwise go unnoticed, e.g. in upgrade
+//! handlers or after a store migration.
+//!
+//! The checks list the stored packets through the [`GenesisContext`] of the
+//! host, which it implements to export its state at genesis.
+
+use displaydoc::Display;
+use ibc_core_channel_types::channel::Order;
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::packet::{PacketState, Sequence};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::path::{
+    ChannelEndPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_primitives::error::IbcError;
+use ibc_primitives::prelude::*;
+
+use crate::genesis::GenesisContext;
+
+/// An inconsistency found between the packet stores of a channel end.
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum InvariantViolation {
+    /// packet `{sequence}` is acknowledged but has no receipt
+    AckWithoutReceipt { sequence: Sequence },
+    /// packet `{sequence}` is acknowledged but not below the next receive sequence `{next_seq_recv}`
+    AckNotReceived {
+        sequence: Sequence,
+        next_seq_recv: Sequence,
+    },
+    /// packet `{sequence}` is committed but not below the next send sequence `{next_seq_send}`
+    CommitmentNotSent {
+        sequence: Sequence,
+        next_seq_send: Sequence,
+    },
+    /// packet `{sequence}` is committed but below the next acknowledgement sequence `{next_seq_ack}`
+    CommitmentAlreadyAcknowledged {
+        sequence: Sequence,
+        next_seq_ack: Sequence,
+    },
+    /// the next acknowledgement sequence `{next_seq_ack}` is above the next send sequence `{next_seq_send}`
+    AckSequenceAheadOfSend {
+        next_seq_ack: Sequence,
+        next_seq_send: Sequence,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvariantViolation {}
+
+impl IbcError for InvariantViolation {}
+
+/// Checks the packet stores of the given channel end against each other, and
+/// returns the inconsistencies found, if any.
+///
+/// On every channel, packets are only committed once sent, i.e. below the
+/// next send sequence. Unordered channels must hold a receipt for each packet
+/// they acknowledge, which is read through
+/// [`get_packet_receipt`](ibc_core_host::context::ValidationContext::get_packet_receipt)
+/// so that the receipts recorded in
+/// [bitmaps](ibc_core_host::receipt_bitmap) are found too.
+/// Ordered channels receive and acknowledge packets in sequence, so that their
+/// acknowledgements lie below the next receive sequence, their commitments at
+/// or above the next acknowledgement sequence, which itself cannot overtake
+/// the next send sequence.
+pub fn check_channel_invariants<Ctx>(
+    ctx: &Ctx,
+    channel_end_path: &ChannelEndPath,
+) -> Result<Vec<InvariantViolation>, ContextError>
+where
+    Ctx: GenesisContext,
+{
+    let chan_end = ctx.channel_end(channel_end_path)?;
+    let ChannelEndPath(port_id, chan_id) = channel_end_path;
+
+    let next_seq_send = ctx.get_next_sequence_send(&SeqSendPath::new(port_id, chan_id))?;
+    let commitments = sorted_sequences(ctx.packet_commitments(channel_end_path)?);
+    let acks = sorted_sequences(ctx.packet_acknowledgements(channel_end_path)?);
+
+    let mut violations: Vec<_> = commitments
+        .iter()
+        .filter(|&&sequence| sequence >= next_seq_send)
+        .map(|&sequence| InvariantViolation::CommitmentNotSent {
+            sequence,
+            next_seq_send,
+        })
+        .collect();
+
+    match chan_end.ordering() {
+        Order::Ordered => {
+            let next_seq_recv = ctx.get_next_sequence_recv(&SeqRecvPath::new(port_id, chan_id))?;
+            let next_seq_ack = ctx.get_next_sequence_ack(&SeqAckPath::new(port_id, chan_id))?;
+
+            violations.extend(
+                commitments
+                    .iter()
+                    .filter(|&&sequence| sequence < next_seq_ack)
+                    .map(
+                        |&sequence| InvariantViolation::CommitmentAlreadyAcknowledged {
+                            sequence,
+                            next_seq_ack,
+                        },
+                    ),
+            );
+            violations.extend(
+                acks.iter()
+                    .filter(|&&sequence| sequence >= next_seq_recv)
+                    .map(|&sequence| InvariantViolation::AckNotReceived {
+                        sequence,
+                        next_seq_recv,
+                    }),
+            );
+            if next_seq_ack > next_seq_send {
+                violations.push(InvariantViolation::AckSequenceAheadOfSend {
+                    next_seq_ack,
+                    next_seq_send,
+                });
+            }
+        }
+        _ => {
+            for &sequence in &acks {
+                match ctx.get_packet_receipt(&ReceiptPath::new(port_id, chan_id, sequence)) {
+                    Ok(_) => {}
+                    Err(ContextError::PacketError(PacketError::PacketReceiptNotFound {
+                        ..
+                    })) => violations.push(InvariantViolation::AckWithoutReceipt { sequence }),
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+    }
+
+    Ok(violations)
+}
+
+/// Returns the sequences of the given packet states, in increasing order, as
+/// [`GenesisContext`] lists them in any order.
+fn sorted_sequences(packet_states: Vec<PacketState>) -> Vec<Sequence> {
+    let mut sequences: Vec<_> = packet_states.into_iter().map(|state| state.seq).collect();
+    sequences.sort();
+    sequences
+}
+
+/// Runs [`check_channel_invariants`] on each of the given channel ends, and
+/// returns the inconsistencies found along with the channel end they were
+/// found on.
+pub fn check_invariants<Ctx>(
+    ctx: &Ctx,
+    channel_end_paths: impl IntoIterator<Item = ChannelEndPath>,
+) -> Result<Vec<(ChannelEndPath, InvariantViolation)>, ContextError>
+where
+    Ctx: GenesisContext,
+{
+    let mut violations = Vec::new();
+    for channel_end_path in channel_end_paths {
+        violations.extend(
+            check_channel_invariants(ctx, &channel_end_path)?
+                .into_iter()
+                .map(|violation| (channel_end_path.clone(), violation)),
+        );
+    }
+    Ok(violations)
+}
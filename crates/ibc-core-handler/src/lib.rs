@@ -0,0 +1,28 @@
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+// https://github.com/cosmos/ibc-rs/issues/342
+#![allow(clippy::result_large_err)]
+//! Entrypoints dispatching the IBC messages to their handlers, along with
+//! the simulation, genesis and relayer utilities built on them.
+
+extern crate alloc;
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod genesis;
+pub mod handler;
+pub mod invariants;
+pub mod relayer;
+pub mod simulation;
+#[cfg(feature = "tracing")]
+mod span;
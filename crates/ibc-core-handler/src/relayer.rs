@@ -0,0 +1,321 @@
+//! Assembly of the messages a relayer submits to a chain from the results of
+//! its queries to the counterparty chain, e.g. the channel end to open a
+//! channel with, or the acknowledgement written for a packet.
+//!
+//! Each helper takes the queried values as is, along with their proofs and
+//! the height these prove them at, and populates the message with the fields
+//! of the right end, so that relayers do not have to tell apart the
+//! identifiers on either chain themselves. The proof height is the height of
+//! the consensus state of the counterparty that the client on the chain holds
+//! to verify the proofs, which relayers update it to beforehand.
+//!
+//! As a convention, chain `A` is the chain the handshake or packet comes
+//! from, and chain `B` the one it goes to.
+
+use ibc_core_channel_types::channel::{ChannelEnd, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::{SendPacket, WriteAcknowledgement};
+use ibc_core_channel_types::msgs::acknowledgement::MsgAcknowledgement;
+use ibc_core_channel_types::msgs::chan_close_confirm::MsgChannelCloseConfirm;
+use ibc_core_channel_types::msgs::chan_open_ack::MsgChannelOpenAck;
+use ibc_core_channel_types::msgs::chan_open_confirm::MsgChannelOpenConfirm;
+use ibc_core_channel_types::msgs::chan_open_try::MsgChannelOpenTry;
+use ibc_core_channel_types::msgs::recv_packet::MsgRecvPacket;
+use ibc_core_channel_types::msgs::timeout::MsgTimeout;
+use ibc_core_channel_types::packet::{Packet, Sequence};
+use ibc_core_channel_types::version::Version as ChannelVersion;
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_connection_types::connection::ConnectionEnd;
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+
+/// Assembles the `MsgChannelOpenTry` to submit to chain `B` for the channel
+/// end `chan_end_on_a` of `(port_id_on_a, chan_id_on_a)`, in the `Init` state,
+/// and the end `conn_end_on_a` of its connection, as queried from chain `A`.
+pub fn build_chan_open_try(
+    port_id_on_a: PortId,
+    chan_id_on_a: ChannelId,
+    chan_end_on_a: &ChannelEnd,
+    conn_end_on_a: &ConnectionEnd,
+    proof_chan_end_on_a: CommitmentProofBytes,
+    proof_height_on_a: Height,
+    signer: Signer,
+) -> Result<MsgChannelOpenTry, ChannelError> {
+    chan_end_on_a.verify_state_matches(&State::Init)?;
+
+    let conn_id_on_b = connection_counterparty(chan_end_on_a, conn_end_on_a)?;
+
+    #[allow(deprecated)]
+    let msg = MsgChannelOpenTry {
+        port_id_on_b: chan_end_on_a.counterparty().port_id().clone(),
+        connection_hops_on_b: vec![conn_id_on_b],
+        port_id_on_a,
+        chan_id_on_a,
+        version_supported_on_a: chan_end_on_a.version().clone(),
+        proof_chan_end_on_a,
+        proof_height_on_a,
+        ordering: *chan_end_on_a.ordering(),
+        signer,
+        version_proposal: ChannelVersion::empty(),
+    };
+
+    Ok(msg)
+}
+
+/// Assembles the `MsgChannelOpenAck` to submit to chain `A` for the channel
+/// end `chan_end_on_b` of `chan_id_on_b`, in the `TryOpen` state, as queried
+/// from chain `B`.
+pub fn build_chan_open_ack(
+    chan_id_on_b: ChannelId,
+    chan_end_on_b: &ChannelEnd,
+    proof_chan_end_on_b: CommitmentProofBytes,
+    proof_height_on_b: Height,
+    signer: Signer,
+) -> Result<MsgChannelOpenAck, ChannelError> {
+    chan_end_on_b.verify_state_matches(&State::TryOpen)?;
+
+    let (port_id_on_a, chan_id_on_a) = channel_counterparty(chan_end_on_b)?;
+
+    Ok(MsgChannelOpenAck {
+        port_id_on_a,
+        chan_id_on_a,
+        chan_id_on_b,
+        version_on_b: chan_end_on_b.version().clone(),
+        proof_chan_end_on_b,
+        proof_height_on_b,
+        signer,
+    })
+}
+
+/// Assembles the `MsgChannelOpenConfirm` to submit to chain `B` for the
+/// channel end `chan_end_on_a`, in the `Open` state, as queried from chain
+/// `A`.
+pub fn build_chan_open_confirm(
+    chan_end_on_a: &ChannelEnd,
+    proof_chan_end_on_a: CommitmentProofBytes,
+    proof_height_on_a: Height,
+    signer: Signer,
+) -> Result<MsgChannelOpenConfirm, ChannelError> {
+    chan_end_on_a.verify_state_matches(&State::Open)?;
+
+    let (port_id_on_b, chan_id_on_b) = channel_counterparty(chan_end_on_a)?;
+
+    Ok(MsgChannelOpenConfirm {
+        port_id_on_b,
+        chan_id_on_b,
+        proof_chan_end_on_a,
+        proof_height_on_a,
+        signer,
+    })
+}
+
+/// Assembles the `MsgChannelCloseConfirm` to submit to chain `B` for the
+/// channel end `chan_end_on_a`, in the `Closed` state, as queried from chain
+/// `A`.
+pub fn build_chan_close_confirm(
+    chan_end_on_a: &ChannelEnd,
+    proof_chan_end_on_a: CommitmentProofBytes,
+    proof_height_on_a: Height,
+    signer: Signer,
+) -> Result<MsgChannelCloseConfirm, ChannelError> {
+    chan_end_on_a.verify_state_matches(&State::Closed)?;
+
+    let (port_id_on_b, chan_id_on_b) = channel_counterparty(chan_end_on_a)?;
+
+    Ok(MsgChannelCloseConfirm {
+        port_id_on_b,
+        chan_id_on_b,
+        proof_chan_end_on_a,
+        proof_height_on_a,
+        signer,
+    })
+}
+
+/// Assembles the `MsgRecvPacket` to submit to chain `B` for the packet sent
+/// on chain `A`, as told by its `SendPacket` event.
+pub fn build_recv_from_send(
+    send_packet: &SendPacket,
+    proof_commitment_on_a: CommitmentProofBytes,
+    proof_height_on_a: Height,
+    signer: Signer,
+) -> MsgRecvPacket {
+    MsgRecvPacket {
+        packet: send_packet.packet(),
+        proof_commitment_on_a,
+        proof_height_on_a,
+        signer,
+    }
+}
+
+/// Assembles the `MsgAcknowledgement` to submit to chain `A` for the packet
+/// received on chain `B`, as told by the `WriteAcknowledgement` event of its
+/// receipt.
+pub fn build_ack_from_recv(
+    write_ack: &WriteAcknowledgement,
+    proof_acked_on_b: CommitmentProofBytes,
+    proof_height_on_b: Height,
+    signer: Signer,
+) -> MsgAcknowledgement {
+    MsgAcknowledgement {
+        packet: write_ack.packet(),
+        acknowledgement: write_ack.acknowledgement().clone(),
+        proof_acked_on_b,
+        proof_height_on_b,
+        signer,
+    }
+}
+
+/// Assembles the `MsgTimeout` to submit to chain `A` for `packet`, which
+/// timed out on chain `B`, whose next sequence to receive on the channel was
+/// `next_seq_recv_on_b` at the proof height.
+///
+/// The proof is that of the absence of the receipt of the packet on unordered
+/// channels, and that of `next_seq_recv_on_b` on ordered ones.
+pub fn build_timeout(
+    packet: Packet,
+    next_seq_recv_on_b: Sequence,
+    proof_unreceived_on_b: CommitmentProofBytes,
+    proof_height_on_b: Height,
+    signer: Signer,
+) -> MsgTimeout {
+    MsgTimeout {
+        packet,
+        next_seq_recv_on_b,
+        proof_unreceived_on_b,
+        proof_height_on_b,
+        signer,
+    }
+}
+
+/// Returns the port and channel identifiers of the counterparty of
+/// `chan_end`.
+fn channel_counterparty(chan_end: &ChannelEnd) -> Result<(PortId, ChannelId), ChannelError> {
+    let counterparty = chan_end.counterparty();
+    let chan_id = counterparty
+        .channel_id()
+        .ok_or(ChannelError::MissingCounterparty)?;
+
+    Ok((counterparty.port_id().clone(), chan_id.clone()))
+}
+
+/// Returns the identifier of the counterparty of the connection of
+/// `chan_end`, whose end is `conn_end`.
+fn connection_counterparty(
+    chan_end: &ChannelEnd,
+    conn_end: &ConnectionEnd,
+) -> Result<ConnectionId, ChannelError> {
+    chan_end.verify_connection_hops_length()?;
+
+    conn_end
+        .counterparty()
+        .connection_id()
+        .cloned()
+        .ok_or_else(|| ChannelError::UndefinedConnectionCounterparty {
+            connection_id: chan_end.connection_hops()[0].clone(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use ibc_core_channel_types::acknowledgement::Acknowledgement;
+    use ibc_core_channel_types::channel::{Counterparty, Order};
+    use ibc_core_connection_types::connection::{
+        Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use ibc_core_connection_types::version::get_compatible_versions;
+    use ibc_core_host_types::identifier::ClientId;
+    use ibc_testkit::utils::core::channel::dummy_raw_packet;
+    use ibc_testkit::utils::core::signer::dummy_bech32_account;
+
+    use super::*;
+
+    fn proof() -> CommitmentProofBytes {
+        vec![1].try_into().unwrap()
+    }
+
+    fn proof_height() -> Height {
+        Height::new(0, 10).unwrap()
+    }
+
+    #[test]
+    fn test_build_chan_open_try() {
+        let chan_end_on_a = ChannelEnd::new(
+            State::Init,
+            Order::Ordered,
+            Counterparty::new(PortId::transfer(), None),
+            vec![ConnectionId::new(0)],
+            ChannelVersion::new("ics20-1".to_string()),
+        )
+        .unwrap();
+        let conn_end_on_a = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::new(7)),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            Duration::ZERO,
+        )
+        .unwrap();
+
+        let msg = build_chan_open_try(
+            PortId::transfer(),
+            ChannelId::new(3),
+            &chan_end_on_a,
+            &conn_end_on_a,
+            proof(),
+            proof_height(),
+            dummy_bech32_account().into(),
+        )
+        .unwrap();
+
+        assert_eq!(msg.port_id_on_b, PortId::transfer());
+        assert_eq!(msg.connection_hops_on_b, vec![ConnectionId::new(7)]);
+        assert_eq!(msg.chan_id_on_a, ChannelId::new(3));
+        assert_eq!(msg.ordering, Order::Ordered);
+        assert_eq!(msg.version_supported_on_a, *chan_end_on_a.version());
+        assert_eq!(msg.proof_height_on_a, proof_height());
+
+        // the channel end must be the one of a channel being opened
+        let mut chan_end_on_a = chan_end_on_a;
+        chan_end_on_a.set_state(State::Open);
+        assert!(build_chan_open_try(
+            PortId::transfer(),
+            ChannelId::new(3),
+            &chan_end_on_a,
+            &conn_end_on_a,
+            proof(),
+            proof_height(),
+            dummy_bech32_account().into(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_build_ack_from_recv() {
+        let packet = Packet::try_from(dummy_raw_packet(1, 1)).unwrap();
+        let acknowledgement = Acknowledgement::try_from(vec![1]).unwrap();
+        let write_ack = WriteAcknowledgement::new(
+            packet.clone(),
+            acknowledgement.clone(),
+            ConnectionId::new(0),
+        );
+
+        let msg = build_ack_from_recv(
+            &write_ack,
+            proof(),
+            proof_height(),
+            dummy_bech32_account().into(),
+        );
+
+        assert_eq!(msg.packet, packet);
+        assert_eq!(msg.acknowledgement, acknowledgement);
+        assert_eq!(msg.proof_height_on_b, proof_height());
+    }
+}
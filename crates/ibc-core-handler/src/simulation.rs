@@ -0,0 +1,664 @@
+//! Simulation of the handling of messages, which reports what dispatching
+//! them would write, emit and cost without committing any of it, e.g. for the
+//! transaction simulation endpoints of hosts.
+
+use alloc::borrow::Cow;
+use core::cell::Cell;
+use core::time::Duration;
+
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::packet::{Receipt, Sequence};
+use ibc_core_client_context::registry::KnownClientTypes;
+use ibc_core_client_types::height::Height;
+use ibc_core_client_types::params::ClientParams;
+use ibc_core_client_types::status::Status;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, ProofResolver,
+};
+use ibc_core_connection_types::connection::ConnectionEnd;
+use ibc_core_connection_types::params::ConnectionParams;
+use ibc_core_connection_types::version::Version as ConnectionVersion;
+use ibc_core_handler_types::error::{ContextError, RouterError};
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_handler_types::msgs::{HandlerOutput, MsgEnvelope, MsgFilter, MsgOutput};
+use ibc_core_handler_types::path::{
+    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host::context::{ExecutionContext, ValidationContext};
+use ibc_core_host::timeout_queue::TimeoutQueueContext;
+#[cfg(feature = "connection-upgrade")]
+use ibc_core_host::upgrade::ConnectionUpgradeContext;
+use ibc_core_host_types::allocator::IdentifierAllocator;
+use ibc_core_host_types::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, IdentifierValidationConfig, PortId,
+};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use ibc_primitives::timestamp::Timestamp;
+use ibc_proto::google::protobuf::Any;
+
+use super::handler::{execute_msg, validate};
+
+/// Context of the hosts able to simulate messages with [`dispatch_simulate`].
+pub trait SimulationContext: ExecutionContext + Sized {
+    /// Returns a branch of this context, which reads the same state but whose
+    /// writes are discarded along with it instead of reaching the store of
+    /// the host, like the cached contexts of the Cosmos SDK.
+    fn branch(&self) -> Self;
+
+    /// Returns the gas charged for the store accesses of simulated messages,
+    /// which defaults to the flat costs of the Cosmos SDK.
+    fn gas_costs(&self) -> GasCosts {
+        GasCosts::default()
+    }
+}
+
+/// Router of the hosts able to simulate messages with [`dispatch_simulate`].
+pub trait SimulationRouter: Router + Sized {
+    /// Returns a branch of this router, whose modules start from the same
+    /// state but whose writes are discarded along with it instead of reaching
+    /// the modules of this router.
+    fn branch(&self) -> Self;
+}
+
+/// The gas charged for each access to the store by a simulated message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasCosts {
+    pub read: u64,
+    pub write: u64,
+    pub delete: u64,
+}
+
+impl Default for GasCosts {
+    fn default() -> Self {
+        Self {
+            read: 1000,
+            write: 2000,
+            delete: 1000,
+        }
+    }
+}
+
+/// A write into the store of the host, as requested through the
+/// [`ExecutionContext`].
+///
+/// The light clients store their client and consensus states through their
+/// own client execution context, so these writes are reported by path, as
+/// they are announced by the client events.
+#[derive(Clone, Debug)]
+pub enum StateWrite {
+    ClientState(ClientStatePath),
+    ConsensusState(ClientConsensusStatePath),
+    ClientCounter,
+    ClientParams(ClientParams),
+    Connection(ConnectionPath, ConnectionEnd),
+    ConnectionToClient(ClientConnectionPath, ConnectionId),
+    ConnectionCounter,
+    ConnectionParams(ConnectionParams),
+    PacketCommitment(CommitmentPath, PacketCommitment),
+    DeletePacketCommitment(CommitmentPath),
+    PacketReceipt(ReceiptPath, Receipt),
+    PacketAcknowledgement(AckPath, AcknowledgementCommitment),
+    DeletePacketAcknowledgement(AckPath),
+    Channel(ChannelEndPath, ChannelEnd),
+    NextSequenceSend(SeqSendPath, Sequence),
+    NextSequenceRecv(SeqRecvPath, Sequence),
+    NextSequenceAck(SeqAckPath, Sequence),
+    ChannelCounter,
+}
+
+impl StateWrite {
+    /// Returns whether this write deletes a value from the store.
+    pub fn is_delete(&self) -> bool {
+        matches!(
+            self,
+            Self::DeletePacketCommitment(_) | Self::DeletePacketAcknowledgement(_)
+        )
+    }
+}
+
+/// What dispatching a message would do, as reported by [`dispatch_simulate`].
+#[derive(Clone, Debug)]
+pub struct SimulationReport {
+    /// The outcome of the message.
+    pub output: MsgOutput,
+    /// The writes into the store, in the order of the handlers.
+    pub writes: Vec<StateWrite>,
+    /// The events the message would emit.
+    pub events: Vec<IbcEvent>,
+    /// The messages the handlers would log.
+    pub logs: Vec<String>,
+    /// The gas of the reads and writes of the message, according to the
+    /// [`GasCosts`] of the host.
+    pub gas_estimate: u64,
+}
+
+/// Entrypoint which validates and executes a message like
+/// [`dispatch`](crate::handler::dispatch), against branches of `ctx` and
+/// `router`, and reports its writes, events and gas instead of committing
+/// them.
+///
+/// The callbacks of the modules run on the branch of `router`, so that
+/// neither the host nor its modules are changed by the simulation.
+pub fn dispatch_simulate<Ctx, R>(
+    ctx: &Ctx,
+    router: &R,
+    msg: MsgEnvelope,
+) -> Result<SimulationReport, RouterError>
+where
+    Ctx: SimulationContext,
+    R: SimulationRouter,
+{
+    let gas_costs = ctx.gas_costs();
+    let mut branch = ctx.branch();
+    let mut router = router.branch();
+    let mut recorder = RecordingContext::new(&mut branch);
+
+    validate(&recorder, &router, msg.clone())?;
+    let output = execute_msg(&mut recorder, &mut router, msg)?;
+
+    let gas_estimate = recorder.gas_estimate(&gas_costs);
+    Ok(SimulationReport {
+        output,
+        writes: recorder.writes,
+        events: recorder.events,
+        logs: recorder.logs,
+        gas_estimate,
+    })
+}
+
+/// Overlay of the host context, recording the reads, events and logs of the
+/// handlers, as well as their writes unless created with
+/// [`RecordingContext::without_writes`].
+///
+/// The events and logs still reach the host context. Every method of the
+/// contexts is forwarded to the host context, including those with a default
+/// implementation, so that the overrides of the host keep applying.
+pub(crate) struct RecordingContext<'a, Ctx> {
+    ctx: &'a mut Ctx,
+    record_writes: bool,
+    reads: Cell<u64>,
+    writes: Vec<StateWrite>,
+    events: Vec<IbcEvent>,
+    logs: Vec<String>,
+}
+
+impl<'a, Ctx> RecordingContext<'a, Ctx> {
+    fn new(ctx: &'a mut Ctx) -> Self {
+        Self {
+            ctx,
+            record_writes: true,
+            reads: Cell::new(0),
+            writes: Vec::new(),
+            events: Vec::new(),
+            logs: Vec::new(),
+        }
+    }
+
+    /// Returns an overlay which does not record the writes of the handlers,
+    /// sparing the copies of the written values.
+    pub(crate) fn without_writes(ctx: &'a mut Ctx) -> Self {
+        Self {
+            record_writes: false,
+            ..Self::new(ctx)
+        }
+    }
+
+    /// Returns the [`HandlerOutput`] of the message executed on this overlay
+    /// with the given `result`.
+    pub(crate) fn into_handler_output(self, result: MsgOutput) -> HandlerOutput {
+        HandlerOutput {
+            result,
+            events: self.events,
+            logs: self.logs,
+        }
+    }
+
+    fn read(&self) {
+        self.reads.set(self.reads.get() + 1);
+    }
+
+    fn record_write(&mut self, write: impl FnOnce() -> StateWrite) {
+        if self.record_writes {
+            self.writes.push(write());
+        }
+    }
+
+    fn gas_estimate(&self, gas_costs: &GasCosts) -> u64 {
+        self.writes.iter().fold(
+            self.reads.get().saturating_mul(gas_costs.read),
+            |gas, write| {
+                let cost = if write.is_delete() {
+                    gas_costs.delete
+                } else {
+                    gas_costs.write
+                };
+                gas.saturating_add(cost)
+            },
+        )
+    }
+}
+
+impl<Ctx> ValidationContext for RecordingContext<'_, Ctx>
+where
+    Ctx: ValidationContext,
+{
+    type V = Ctx::V;
+    type E = Ctx::E;
+    type AnyConsensusState = Ctx::AnyConsensusState;
+    type AnyClientState = Ctx::AnyClientState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self.ctx.get_client_validation_context()
+    }
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::AnyClientState, ContextError> {
+        self.read();
+        self.ctx.client_state(client_id)
+    }
+
+    fn decode_client_state(&self, client_state: Any) -> Result<Self::AnyClientState, ContextError> {
+        self.ctx.decode_client_state(client_state)
+    }
+
+    fn known_client_types(&self) -> &dyn KnownClientTypes {
+        self.ctx.known_client_types()
+    }
+
+    fn find_duplicate_client(
+        &self,
+        client_state: &Any,
+        consensus_state: &Any,
+    ) -> Result<Option<ClientId>, ContextError> {
+        self.ctx
+            .find_duplicate_client(client_state, consensus_state)
+    }
+
+    fn client_params(&self) -> Result<ClientParams, ContextError> {
+        self.read();
+        self.ctx.client_params()
+    }
+
+    fn client_status(&self, client_id: &ClientId) -> Result<Status, ContextError> {
+        self.read();
+        self.ctx.client_status(client_id)
+    }
+
+    fn counterparty_chain_id(&self, client_id: &ClientId) -> Result<Option<ChainId>, ContextError> {
+        self.read();
+        self.ctx.counterparty_chain_id(client_id)
+    }
+
+    fn client_expiry_warning_margin(&self) -> Option<Duration> {
+        self.ctx.client_expiry_warning_margin()
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        self.read();
+        self.ctx.consensus_state(client_cons_state_path)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.ctx.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.ctx.host_timestamp()
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        self.read();
+        self.ctx.host_consensus_state(height)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        self.read();
+        self.ctx.client_counter()
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        self.read();
+        self.ctx.connection_end(conn_id)
+    }
+
+    fn connection_id_by_counterparty(
+        &self,
+        client_id_on_b: &ClientId,
+        conn_id_on_b: &ConnectionId,
+    ) -> Result<Option<ConnectionId>, ContextError> {
+        self.read();
+        self.ctx
+            .connection_id_by_counterparty(client_id_on_b, conn_id_on_b)
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Any,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.ctx.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        self.read();
+        self.ctx.connection_counter()
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.ctx.get_compatible_versions()
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        self.ctx.pick_version(counterparty_candidate_versions)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        self.read();
+        self.ctx.channel_end(channel_end_path)
+    }
+
+    fn channel_id_by_counterparty(
+        &self,
+        client_id_on_b: &ClientId,
+        port_id_on_b: &PortId,
+        chan_id_on_b: &ChannelId,
+    ) -> Result<Option<(PortId, ChannelId)>, ContextError> {
+        self.read();
+        self.ctx
+            .channel_id_by_counterparty(client_id_on_b, port_id_on_b, chan_id_on_b)
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        self.read();
+        self.ctx.get_next_sequence_send(seq_send_path)
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.read();
+        self.ctx.get_next_sequence_recv(seq_recv_path)
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        self.read();
+        self.ctx.get_next_sequence_ack(seq_ack_path)
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        self.read();
+        self.ctx.get_packet_commitment(commitment_path)
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.read();
+        self.ctx.get_packet_receipt(receipt_path)
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.read();
+        self.ctx.get_packet_acknowledgement(ack_path)
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        self.read();
+        self.ctx.channel_counter()
+    }
+
+    fn connection_params(&self) -> Result<ConnectionParams, ContextError> {
+        self.read();
+        self.ctx.connection_params()
+    }
+
+    fn identifier_allocator(&self) -> &dyn IdentifierAllocator {
+        self.ctx.identifier_allocator()
+    }
+
+    fn identifier_validation_config(&self) -> IdentifierValidationConfig {
+        self.ctx.identifier_validation_config()
+    }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        self.ctx.max_packet_data_size()
+    }
+
+    fn counterparty_uses_receipt_bitmap(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.ctx
+            .counterparty_uses_receipt_bitmap(port_id, channel_id)
+    }
+
+    fn block_delay(&self, delay_period_time: &Duration) -> Result<u64, ContextError> {
+        self.ctx.block_delay(delay_period_time)
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.ctx.validate_message_signer(signer)
+    }
+
+    fn msg_filter(&self) -> &dyn MsgFilter {
+        self.ctx.msg_filter()
+    }
+
+    fn proof_resolver(&self) -> Option<&dyn ProofResolver> {
+        self.ctx.proof_resolver()
+    }
+
+    fn resolve_proof<'a>(
+        &self,
+        proof: &'a CommitmentProofBytes,
+    ) -> Result<Cow<'a, CommitmentProofBytes>, ContextError> {
+        self.ctx.resolve_proof(proof)
+    }
+
+    #[cfg(feature = "connection-upgrade")]
+    fn connection_upgrades(&self) -> Option<&dyn ConnectionUpgradeContext> {
+        self.ctx.connection_upgrades()
+    }
+}
+
+impl<Ctx> ExecutionContext for RecordingContext<'_, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self.ctx.get_client_execution_context()
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::ClientCounter);
+        self.ctx.increase_client_counter()
+    }
+
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::ClientParams(params.clone()));
+        self.ctx.store_client_params(params)
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.record_write(|| {
+            StateWrite::Connection(connection_path.clone(), connection_end.clone())
+        });
+        self.ctx.store_connection(connection_path, connection_end)
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        self.record_write(|| {
+            StateWrite::ConnectionToClient(client_connection_path.clone(), conn_id.clone())
+        });
+        self.ctx
+            .store_connection_to_client(client_connection_path, conn_id)
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::ConnectionCounter);
+        self.ctx.increase_connection_counter()
+    }
+
+    fn store_connection_params(&mut self, params: ConnectionParams) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::ConnectionParams(params));
+        self.ctx.store_connection_params(params)
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.record_write(|| {
+            StateWrite::PacketCommitment(commitment_path.clone(), commitment.clone())
+        });
+        self.ctx
+            .store_packet_commitment(commitment_path, commitment)
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::DeletePacketCommitment(commitment_path.clone()));
+        self.ctx.delete_packet_commitment(commitment_path)
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::PacketReceipt(receipt_path.clone(), receipt.clone()));
+        self.ctx.store_packet_receipt(receipt_path, receipt)
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.record_write(|| {
+            StateWrite::PacketAcknowledgement(ack_path.clone(), ack_commitment.clone())
+        });
+        self.ctx
+            .store_packet_acknowledgement(ack_path, ack_commitment)
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::DeletePacketAcknowledgement(ack_path.clone()));
+        self.ctx.delete_packet_acknowledgement(ack_path)
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::Channel(channel_end_path.clone(), channel_end.clone()));
+        self.ctx.store_channel(channel_end_path, channel_end)
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::NextSequenceSend(seq_send_path.clone(), seq));
+        self.ctx.store_next_sequence_send(seq_send_path, seq)
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::NextSequenceRecv(seq_recv_path.clone(), seq));
+        self.ctx.store_next_sequence_recv(seq_recv_path, seq)
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::NextSequenceAck(seq_ack_path.clone(), seq));
+        self.ctx.store_next_sequence_ack(seq_ack_path, seq)
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.record_write(|| StateWrite::ChannelCounter);
+        self.ctx.increase_channel_counter()
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.ctx.emit_ibc_event(event.clone())?;
+        if self.record_writes {
+            self.writes.extend(client_writes(&event));
+        }
+        self.events.push(event);
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.ctx.log_message(message.clone())?;
+        self.logs.push(message);
+        Ok(())
+    }
+
+    fn timeout_queue(&mut self) -> Option<&mut dyn TimeoutQueueContext> {
+        self.ctx.timeout_queue()
+    }
+
+    #[cfg(feature = "connection-upgrade")]
+    fn connection_upgrades_mut(&mut self) -> Option<&mut dyn ConnectionUpgradeContext> {
+        self.ctx.connection_upgrades_mut()
+    }
+}
+
+/// Returns the writes of the light client announced by `event`, which the
+/// client handlers emit once the client has stored its states.
+fn client_writes(event: &IbcEvent) -> Vec<StateWrite> {
+    let (client_id, consensus_heights) = match event {
+        IbcEvent::CreateClient(event) => (event.client_id(), vec![*event.consensus_height()]),
+        IbcEvent::UpdateClient(event) => (event.client_id(), event.consensus_heights().to_vec()),
+        IbcEvent::UpgradeClient(event) => (event.client_id(), vec![*event.consensus_height()]),
+        IbcEvent::ClientMisbehaviour(event) => (event.client_id(), Vec::new()),
+        _ => return Vec::new(),
+    };
+
+    core::iter::once(StateWrite::ClientState(ClientStatePath::new(client_id)))
+        .chain(consensus_heights.iter().map(|height| {
+            StateWrite::ConsensusState(ClientConsensusStatePath::new(client_id, height))
+        }))
+        .collect()
+}
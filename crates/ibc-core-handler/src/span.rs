@@ -0,0 +1,187 @@
+//! Structured `tracing` spans for the top-level handler entry points.
+//!
+//! Enabled by the `tracing` feature. Every message processed by
+//! [`validate`](crate::handler::validate) and [`execute`](crate::handler::execute)
+//! is wrapped in a span named after the handler stage, carrying the message
+//! type along with whichever identifiers the message refers to. This sits
+//! alongside [`ExecutionContext::log_message`](ibc_core_host::context::ExecutionContext::log_message),
+//! which keeps receiving the same raw strings as before.
+
+use ibc_core_channel_types::msgs::{ChannelMsg, PacketMsg};
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_client_types::msgs::ClientMsg;
+use ibc_core_connection_types::msgs::ConnectionMsg;
+use ibc_core_handler_types::msgs::MsgEnvelope;
+use tracing::field::{display, Empty};
+use tracing::Span;
+
+/// Creates the span for the `validate` stage of the given message.
+pub(crate) fn validate_span(msg: &MsgEnvelope) -> Span {
+    let span = tracing::debug_span!(
+        "ibc_validate",
+        msg_type = msg_type(msg),
+        client_id = Empty,
+        conn_id = Empty,
+        port_id = Empty,
+        chan_id = Empty,
+        sequence = Empty,
+    );
+    record_ids(&span, msg);
+    span
+}
+
+/// Creates the span for the `execute` stage of the given message.
+pub(crate) fn execute_span(msg: &MsgEnvelope) -> Span {
+    let span = tracing::debug_span!(
+        "ibc_execute",
+        msg_type = msg_type(msg),
+        client_id = Empty,
+        conn_id = Empty,
+        port_id = Empty,
+        chan_id = Empty,
+        sequence = Empty,
+    );
+    record_ids(&span, msg);
+    span
+}
+
+fn msg_type(msg: &MsgEnvelope) -> &'static str {
+    match msg {
+        MsgEnvelope::Client(msg) => match msg {
+            ClientMsg::CreateClient(_) => "create_client",
+            ClientMsg::UpdateClient(_) => "update_client",
+            ClientMsg::Misbehaviour(_) => "submit_misbehaviour",
+            ClientMsg::UpgradeClient(_) => "upgrade_client",
+        },
+        MsgEnvelope::Connection(msg) => match msg {
+            ConnectionMsg::OpenInit(_) => "connection_open_init",
+            ConnectionMsg::OpenTry(_) => "connection_open_try",
+            ConnectionMsg::OpenAck(_) => "connection_open_ack",
+            ConnectionMsg::OpenConfirm(_) => "connection_open_confirm",
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeInit(_) => "connection_upgrade_init",
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeTry(_) => "connection_upgrade_try",
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeAck(_) => "connection_upgrade_ack",
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeConfirm(_) => "connection_upgrade_confirm",
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeTimeout(_) => "connection_upgrade_timeout",
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeCancel(_) => "connection_upgrade_cancel",
+        },
+        MsgEnvelope::Channel(msg) => match msg {
+            ChannelMsg::OpenInit(_) => "channel_open_init",
+            ChannelMsg::OpenTry(_) => "channel_open_try",
+            ChannelMsg::OpenAck(_) => "channel_open_ack",
+            ChannelMsg::OpenConfirm(_) => "channel_open_confirm",
+            ChannelMsg::CloseInit(_) => "channel_close_init",
+            ChannelMsg::CloseConfirm(_) => "channel_close_confirm",
+        },
+        MsgEnvelope::Packet(msg) => match msg {
+            PacketMsg::Recv(_) => "recv_packet",
+            PacketMsg::Ack(_) => "acknowledge_packet",
+            PacketMsg::Timeout(_) => "timeout_packet",
+            PacketMsg::TimeoutOnClose(_) => "timeout_on_close_packet",
+        },
+    }
+}
+
+/// Records the identifiers of the host-side objects touched by `msg`.
+fn record_ids(span: &Span, msg: &MsgEnvelope) {
+    match msg {
+        MsgEnvelope::Client(msg) => match msg {
+            ClientMsg::CreateClient(_) => {}
+            ClientMsg::UpdateClient(msg) => {
+                span.record("client_id", display(&msg.client_id));
+            }
+            ClientMsg::Misbehaviour(msg) => {
+                span.record("client_id", display(&msg.client_id));
+            }
+            ClientMsg::UpgradeClient(msg) => {
+                span.record("client_id", display(&msg.client_id));
+            }
+        },
+        MsgEnvelope::Connection(msg) => match msg {
+            ConnectionMsg::OpenInit(msg) => {
+                span.record("client_id", display(&msg.client_id_on_a));
+            }
+            ConnectionMsg::OpenTry(msg) => {
+                span.record("client_id", display(&msg.client_id_on_b));
+            }
+            ConnectionMsg::OpenAck(msg) => {
+                span.record("conn_id", display(&msg.conn_id_on_a));
+            }
+            ConnectionMsg::OpenConfirm(msg) => {
+                span.record("conn_id", display(&msg.conn_id_on_b));
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeInit(msg) => {
+                span.record("conn_id", display(&msg.conn_id_on_a));
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeTry(msg) => {
+                span.record("conn_id", display(&msg.conn_id_on_b));
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeAck(msg) => {
+                span.record("conn_id", display(&msg.conn_id_on_a));
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeConfirm(msg) => {
+                span.record("conn_id", display(&msg.conn_id_on_b));
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeTimeout(msg) => {
+                span.record("conn_id", display(&msg.conn_id));
+            }
+            #[cfg(feature = "connection-upgrade")]
+            ConnectionMsg::UpgradeCancel(msg) => {
+                span.record("conn_id", display(&msg.conn_id_on_a));
+            }
+        },
+        MsgEnvelope::Channel(msg) => match msg {
+            ChannelMsg::OpenInit(msg) => {
+                span.record("port_id", display(&msg.port_id_on_a));
+            }
+            ChannelMsg::OpenTry(msg) => {
+                span.record("port_id", display(&msg.port_id_on_b));
+            }
+            ChannelMsg::OpenAck(msg) => {
+                span.record("port_id", display(&msg.port_id_on_a));
+                span.record("chan_id", display(&msg.chan_id_on_a));
+            }
+            ChannelMsg::OpenConfirm(msg) => {
+                span.record("port_id", display(&msg.port_id_on_b));
+                span.record("chan_id", display(&msg.chan_id_on_b));
+            }
+            ChannelMsg::CloseInit(msg) => {
+                span.record("port_id", display(&msg.port_id_on_a));
+                span.record("chan_id", display(&msg.chan_id_on_a));
+            }
+            ChannelMsg::CloseConfirm(msg) => {
+                span.record("port_id", display(&msg.port_id_on_b));
+                span.record("chan_id", display(&msg.chan_id_on_b));
+            }
+        },
+        MsgEnvelope::Packet(msg) => match msg {
+            PacketMsg::Recv(msg) => record_packet_on_b(span, &msg.packet),
+            PacketMsg::Ack(msg) => record_packet_on_a(span, &msg.packet),
+            PacketMsg::Timeout(msg) => record_packet_on_a(span, &msg.packet),
+            PacketMsg::TimeoutOnClose(msg) => record_packet_on_a(span, &msg.packet),
+        },
+    }
+}
+
+fn record_packet_on_a(span: &Span, packet: &Packet) {
+    span.record("port_id", display(&packet.port_id_on_a));
+    span.record("chan_id", display(&packet.chan_id_on_a));
+    span.record("sequence", display(&packet.seq_on_a));
+}
+
+fn record_packet_on_b(span: &Span, packet: &Packet) {
+    span.record("port_id", display(&packet.port_id_on_b));
+    span.record("chan_id", display(&packet.chan_id_on_b));
+    span.record("sequence", display(&packet.seq_on_a));
+}
@@ -0,0 +1,160 @@
+//! Defines how the handlers allocate the identifiers of the clients,
+//! connections and channels created on the host.
+
+use super::identifier::{
+    validate_unreserved_identifier, ChannelId, ClientId, ConnectionId, IdentifierError,
+};
+use crate::client_type::ClientType;
+
+/// Allocates the identifiers of the clients, connections and channels created
+/// on the host from the values of its counters, as returned by
+/// `ValidationContext::client_counter` and its connection and channel
+/// equivalents.
+///
+/// The handlers allocate an identifier during validation and again during
+/// execution, so allocators must be deterministic: the same counter always
+/// yields the same identifier. They must not return any of the
+/// [`RESERVED_IDENTIFIERS`](super::identifier::RESERVED_IDENTIFIERS), which
+/// the handlers reject. The default methods format identifiers as
+/// ibc-go does, e.g. `07-tendermint-3`, `connection-5` and `channel-17`.
+pub trait IdentifierAllocator {
+    /// Returns the identifier of a new client of type `client_type`.
+    fn client_id(
+        &self,
+        client_type: ClientType,
+        counter: u64,
+    ) -> Result<ClientId, IdentifierError> {
+        ClientId::new(client_type, counter)
+    }
+
+    /// Returns the identifier of a new connection.
+    fn connection_id(&self, counter: u64) -> Result<ConnectionId, IdentifierError> {
+        Ok(ConnectionId::new(counter))
+    }
+
+    /// Returns the identifier of a new channel.
+    fn channel_id(&self, counter: u64) -> Result<ChannelId, IdentifierError> {
+        Ok(ChannelId::new(counter))
+    }
+}
+
+impl dyn IdentifierAllocator + '_ {
+    /// Allocates the identifier of a new client, rejecting the
+    /// [`RESERVED_IDENTIFIERS`](super::identifier::RESERVED_IDENTIFIERS).
+    pub fn allocate_client_id(
+        &self,
+        client_type: ClientType,
+        counter: u64,
+    ) -> Result<ClientId, IdentifierError> {
+        let client_id = self.client_id(client_type, counter)?;
+        validate_unreserved_identifier(client_id.as_str())?;
+        Ok(client_id)
+    }
+
+    /// Allocates the identifier of a new connection, rejecting the reserved
+    /// ones.
+    pub fn allocate_connection_id(&self, counter: u64) -> Result<ConnectionId, IdentifierError> {
+        let conn_id = self.connection_id(counter)?;
+        validate_unreserved_identifier(conn_id.as_str())?;
+        Ok(conn_id)
+    }
+
+    /// Allocates the identifier of a new channel, rejecting the reserved ones.
+    pub fn allocate_channel_id(&self, counter: u64) -> Result<ChannelId, IdentifierError> {
+        let chan_id = self.channel_id(counter)?;
+        validate_unreserved_identifier(chan_id.as_str())?;
+        Ok(chan_id)
+    }
+}
+
+/// The default [`IdentifierAllocator`], which formats the counters of the host
+/// into the identifiers of the IBC specification.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CounterAllocator;
+
+impl IdentifierAllocator for CounterAllocator {}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use ibc_primitives::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::identifier::LOCALHOST_CONNECTION_ID;
+
+    #[rstest]
+    #[case("07-tendermint", 0, "07-tendermint-0")]
+    #[case("07-tendermint", 42, "07-tendermint-42")]
+    #[case("9999-mock", u64::MAX, "9999-mock-18446744073709551615")]
+    fn test_client_id_format(
+        #[case] client_type: &str,
+        #[case] counter: u64,
+        #[case] expected: &str,
+    ) {
+        let client_type = ClientType::from_str(client_type).unwrap();
+        let client_id = CounterAllocator.client_id(client_type, counter).unwrap();
+        assert_eq!(client_id.as_str(), expected);
+    }
+
+    #[rstest]
+    #[case(0, "connection-0", "channel-0")]
+    #[case(17, "connection-17", "channel-17")]
+    #[case(
+        u64::MAX,
+        "connection-18446744073709551615",
+        "channel-18446744073709551615"
+    )]
+    fn test_connection_and_channel_id_format(
+        #[case] counter: u64,
+        #[case] expected_conn_id: &str,
+        #[case] expected_chan_id: &str,
+    ) {
+        let conn_id = CounterAllocator.connection_id(counter).unwrap();
+        let chan_id = CounterAllocator.channel_id(counter).unwrap();
+        assert_eq!(conn_id.as_str(), expected_conn_id);
+        assert_eq!(chan_id.as_str(), expected_chan_id);
+    }
+
+    #[test]
+    fn test_custom_allocator() {
+        struct PrefixedAllocator;
+
+        impl IdentifierAllocator for PrefixedAllocator {
+            fn channel_id(&self, counter: u64) -> Result<ChannelId, IdentifierError> {
+                ChannelId::from_str(&format!("channel-{}", counter + 100))
+            }
+        }
+
+        assert_eq!(
+            PrefixedAllocator.channel_id(1).unwrap().as_str(),
+            "channel-101"
+        );
+        assert_eq!(
+            PrefixedAllocator.connection_id(1).unwrap().as_str(),
+            "connection-1"
+        );
+    }
+
+    #[test]
+    fn test_reserved_ids_are_not_allocated() {
+        struct LocalhostAllocator;
+
+        impl IdentifierAllocator for LocalhostAllocator {
+            fn connection_id(&self, _counter: u64) -> Result<ConnectionId, IdentifierError> {
+                ConnectionId::from_str(LOCALHOST_CONNECTION_ID)
+            }
+        }
+
+        let allocator: &dyn IdentifierAllocator = &LocalhostAllocator;
+        assert!(matches!(
+            allocator.allocate_connection_id(0),
+            Err(IdentifierError::Reserved { .. })
+        ));
+        assert_eq!(
+            allocator.allocate_channel_id(0).unwrap().as_str(),
+            "channel-0"
+        );
+    }
+}
@@ -3,9 +3,10 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
-use crate::core::ics24_host::identifier::validate::validate_client_type;
-use crate::core::ics24_host::identifier::IdentifierError;
-use crate::prelude::*;
+use ibc_primitives::prelude::*;
+
+use crate::identifier::validate::validate_client_type;
+use crate::identifier::IdentifierError;
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -1,21 +1,37 @@
 //! Defines identifier types
 
+#[cfg(feature = "inline-identifiers")]
+pub mod inline;
 pub(crate) mod validate;
 use core::fmt::{Debug, Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
 use derive_more::Into;
 use displaydoc::Display;
+use ibc_primitives::error::IbcError;
+use ibc_primitives::prelude::*;
 use validate::*;
+pub use validate::{
+    is_reserved_identifier, is_valid_channel_identifier, is_valid_client_identifier,
+    is_valid_client_type, is_valid_connection_identifier, is_valid_port_identifier,
+    validate_unreserved_identifier, IdentifierValidationConfig, LengthBounds, SpecialChars,
+    RESERVED_IDENTIFIERS,
+};
 
-use crate::clients::ics07_tendermint::client_type as tm_client_type;
-use crate::core::ics02_client::client_type::ClientType;
-use crate::prelude::*;
+use crate::client_type::ClientType;
 
 const CONNECTION_ID_PREFIX: &str = "connection";
 const CHANNEL_ID_PREFIX: &str = "channel";
 
-const TRANSFER_PORT_ID: &str = "transfer";
+/// The identifier of the localhost client, over whose connections the host
+/// loops packets back to itself.
+pub const LOCALHOST_CLIENT_ID: &str = "09-localhost";
+/// The identifier of the connection of the localhost client.
+pub const LOCALHOST_CONNECTION_ID: &str = "connection-localhost";
+/// The identifier of the well-known transfer port.
+pub const TRANSFER_PORT_ID: &str = "transfer";
+const ICA_HOST_PORT_ID: &str = "icahost";
+const ICA_CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
 
 /// Defines the domain type for chain identifiers.
 ///
@@ -57,7 +73,7 @@ impl ChainId {
     /// the revision number is parsed. Otherwise, revision number is set to 0.
     ///
     /// ```
-    /// use ibc::core::ics24_host::identifier::ChainId;
+    /// use ibc_core_host_types::identifier::ChainId;
     ///
     /// let chain_id = "chainA";
     /// let id = ChainId::new(chain_id).unwrap();
@@ -87,13 +103,43 @@ impl ChainId {
         self.revision_number
     }
 
+    /// Returns the chain name, i.e. the chain identifier without its revision
+    /// number, or the whole chain identifier if it is not in
+    /// `{chain_name}-{revision_number}` format.
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifier::ChainId;
+    ///
+    /// assert_eq!(ChainId::new("chainA-12").unwrap().chain_name(), "chainA");
+    /// assert_eq!(ChainId::new("chainA").unwrap().chain_name(), "chainA");
+    /// ```
+    pub fn chain_name(&self) -> &str {
+        self.split_chain_id()
+            .map(|(chain_name, _)| chain_name)
+            .unwrap_or_else(|_| self.as_str())
+    }
+
+    /// Returns the chain identifier of the same chain name at the given
+    /// revision number, as a chain upgrading to a new revision takes on.
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifier::ChainId;
+    ///
+    /// let chain_id = ChainId::new("chainA").unwrap();
+    /// let upgraded_chain_id = chain_id.with_revision_number(1).unwrap();
+    /// assert_eq!(upgraded_chain_id, ChainId::new("chainA-1").unwrap());
+    /// ```
+    pub fn with_revision_number(&self, revision_number: u64) -> Result<Self, IdentifierError> {
+        Self::new(&format!("{}-{}", self.chain_name(), revision_number))
+    }
+
     /// Increases `ChainId`s revision number by one.
     /// Fails if the chain identifier is not in
     /// `{chain_name}-{revision_number}` format or
     /// the revision number overflows.
     ///
     /// ```
-    /// use ibc::core::ics24_host::identifier::ChainId;
+    /// use ibc_core_host_types::identifier::ChainId;
     ///
     /// let mut chain_id = ChainId::new("chainA-1").unwrap();
     /// assert!(chain_id.increment_revision_number().is_ok());
@@ -208,8 +254,8 @@ impl ClientId {
     /// `counter`; these are separated by a dash "-".
     ///
     /// ```
-    /// # use ibc::core::ics24_host::identifier::ClientId;
-    /// # use ibc::core::ics02_client::client_type::ClientType;
+    /// # use ibc_core_host_types::identifier::ClientId;
+    /// # use ibc_core_host_types::client_type::ClientType;
     /// # use std::str::FromStr;
     /// let tm_client_id = ClientId::new(ClientType::from_str("07-tendermint").unwrap(), 0);
     /// assert!(tm_client_id.is_ok());
@@ -231,6 +277,16 @@ impl ClientId {
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    /// Infallible creation of the [`LOCALHOST_CLIENT_ID`] client identifier
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CLIENT_ID.to_string())
+    }
+
+    /// Returns whether this is the [`LOCALHOST_CLIENT_ID`] client identifier.
+    pub fn is_localhost(&self) -> bool {
+        self.as_str() == LOCALHOST_CLIENT_ID
+    }
 }
 
 /// This implementation provides a `to_string` method.
@@ -250,14 +306,14 @@ impl FromStr for ClientId {
 
 impl Default for ClientId {
     fn default() -> Self {
-        Self::new(tm_client_type(), 0).expect("Never fails because we use a valid client type")
+        crate::client_id!("07-tendermint-0")
     }
 }
 
 /// Equality check against string literal (satisfies &ClientId == &str).
 /// ```
 /// use core::str::FromStr;
-/// use ibc::core::ics24_host::identifier::ClientId;
+/// use ibc_core_host_types::identifier::ClientId;
 /// let client_id = ClientId::from_str("clientidtwo");
 /// assert!(client_id.is_ok());
 /// client_id.map(|id| {assert_eq!(&id, "clientidtwo")});
@@ -292,7 +348,7 @@ impl ConnectionId {
     /// `ConnectionId::prefix()`) so this method accepts a single argument, the `counter`.
     ///
     /// ```
-    /// # use ibc::core::ics24_host::identifier::ConnectionId;
+    /// # use ibc_core_host_types::identifier::ConnectionId;
     /// let conn_id = ConnectionId::new(11);
     /// assert_eq!(&conn_id, "connection-11");
     /// ```
@@ -315,6 +371,18 @@ impl ConnectionId {
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    /// Infallible creation of the [`LOCALHOST_CONNECTION_ID`] connection
+    /// identifier
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CONNECTION_ID.to_string())
+    }
+
+    /// Returns whether this is the [`LOCALHOST_CONNECTION_ID`] connection
+    /// identifier.
+    pub fn is_localhost(&self) -> bool {
+        self.as_str() == LOCALHOST_CONNECTION_ID
+    }
 }
 
 /// This implementation provides a `to_string` method.
@@ -341,7 +409,7 @@ impl Default for ConnectionId {
 /// Equality check against string literal (satisfies &ConnectionId == &str).
 /// ```
 /// use core::str::FromStr;
-/// use ibc::core::ics24_host::identifier::ConnectionId;
+/// use ibc_core_host_types::identifier::ConnectionId;
 /// let conn_id = ConnectionId::from_str("connectionId-0");
 /// assert!(conn_id.is_ok());
 /// conn_id.map(|id| {assert_eq!(&id, "connectionId-0")});
@@ -379,6 +447,46 @@ impl PortId {
         Self(TRANSFER_PORT_ID.to_string())
     }
 
+    /// Infallible creation of the well-known ICS-27 interchain accounts host
+    /// port
+    pub fn ica_host() -> Self {
+        Self(ICA_HOST_PORT_ID.to_string())
+    }
+
+    /// Builds the ICS-27 interchain accounts controller port of `owner`,
+    /// formatted as `icacontroller-{owner}`.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifier::PortId;
+    /// let port_id = PortId::ica_controller("cosmos1owner").unwrap();
+    /// assert_eq!(port_id.as_str(), "icacontroller-cosmos1owner");
+    /// assert_eq!(port_id.ica_controller_owner(), Some("cosmos1owner"));
+    /// ```
+    pub fn ica_controller(owner: &str) -> Result<Self, IdentifierError> {
+        if owner.trim().is_empty() {
+            return Err(IdentifierError::Empty);
+        }
+        Self::from_str(&format!("{ICA_CONTROLLER_PORT_PREFIX}{owner}"))
+    }
+
+    /// Returns the owner address of an ICS-27 interchain accounts controller
+    /// port, or `None` if this is not a controller port.
+    pub fn ica_controller_owner(&self) -> Option<&str> {
+        self.as_str()
+            .strip_prefix(ICA_CONTROLLER_PORT_PREFIX)
+            .filter(|owner| !owner.is_empty())
+    }
+
+    /// Returns whether this is the well-known transfer port.
+    pub fn is_transfer(&self) -> bool {
+        self.as_str() == TRANSFER_PORT_ID
+    }
+
+    /// Returns whether this is the well-known interchain accounts host port.
+    pub fn is_ica_host(&self) -> bool {
+        self.as_str() == ICA_HOST_PORT_ID
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -440,7 +548,7 @@ impl ChannelId {
     /// accepts a single argument, the `counter`.
     ///
     /// ```
-    /// # use ibc::core::ics24_host::identifier::ChannelId;
+    /// # use ibc_core_host_types::identifier::ChannelId;
     /// let chan_id = ChannelId::new(27);
     /// assert_eq!(chan_id.to_string(), "channel-27");
     /// ```
@@ -495,7 +603,7 @@ impl Default for ChannelId {
 /// Equality check against string literal (satisfies &ChannelId == &str).
 /// ```
 /// use core::str::FromStr;
-/// use ibc::core::ics24_host::identifier::ChannelId;
+/// use ibc_core_host_types::identifier::ChannelId;
 /// let channel_id = ChannelId::from_str("channelId-0");
 /// assert!(channel_id.is_ok());
 /// channel_id.map(|id| {assert_eq!(&id, "channelId-0")});
@@ -506,6 +614,50 @@ impl PartialEq<str> for ChannelId {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+    use arbitrary::{Arbitrary, Result, Unstructured};
+    use ibc_primitives::utils::arbitrary::{string_of, IDENTIFIER_CHARS};
+
+    use super::*;
+
+    /// The client types of the light clients of this crate and of ibc-go.
+    const CLIENT_TYPES: &[&str] = &["07-tendermint", "06-solomachine", "08-wasm", "09-localhost"];
+
+    impl<'a> Arbitrary<'a> for ChainId {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let name = string_of(u, b"abcdefghijklmnopqrstuvwxyz", 1, 20)?;
+            let revision_number: u64 = u.arbitrary()?;
+            Ok(Self::new(&format!("{name}-{revision_number}")).expect("Never fails"))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for ClientId {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let client_type = ClientType::new(u.choose(CLIENT_TYPES)?).expect("Never fails");
+            Ok(Self::new(client_type, u.arbitrary()?).expect("Never fails"))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for ConnectionId {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for PortId {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(string_of(u, IDENTIFIER_CHARS, 2, 128)?).expect("Never fails"))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for ChannelId {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?))
+        }
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Debug, Display)]
 pub enum IdentifierError {
@@ -518,8 +670,12 @@ pub enum IdentifierError {
         min: u64,
         max: u64,
     },
-    /// identifier `{id}` must only contain alphanumeric characters or `.`, `_`, `+`, `-`, `#`, - `[`, `]`, `<`, `>`
+    /// identifier `{id}` must only contain alphanumeric characters or the special characters allowed by the validation config
     InvalidCharacter { id: String },
+    /// identifier length bounds `{min}`-`{max}` do not admit any length
+    InvalidLengthBounds { min: u64, max: u64 },
+    /// identifier `{id}` is reserved
+    Reserved { id: String },
     /// identifier prefix `{prefix}` is invalid
     InvalidPrefix { prefix: String },
     /// chain identifier is not formatted with revision number
@@ -533,6 +689,8 @@ pub enum IdentifierError {
 #[cfg(feature = "std")]
 impl std::error::Error for IdentifierError {}
 
+impl IbcError for IdentifierError {}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -605,6 +763,64 @@ mod tests {
         assert!(ChainId::new(chain_id_str).is_err());
     }
 
+    #[test]
+    fn test_well_known_ports() {
+        assert_eq!(PortId::transfer().as_str(), "transfer");
+        assert!(PortId::transfer().is_transfer());
+        assert_eq!(PortId::ica_host().as_str(), "icahost");
+        assert!(PortId::ica_host().is_ica_host());
+        assert!(!PortId::ica_host().is_transfer());
+        assert_eq!(PortId::ica_host().ica_controller_owner(), None);
+    }
+
+    #[test]
+    fn test_localhost_identifiers() {
+        let client_id = ClientId::localhost();
+        assert!(client_id.is_localhost());
+        assert_eq!(ClientId::from_str(LOCALHOST_CLIENT_ID).unwrap(), client_id);
+        assert!(!ClientId::default().is_localhost());
+
+        let conn_id = ConnectionId::localhost();
+        assert!(conn_id.is_localhost());
+        assert_eq!(
+            ConnectionId::from_str(LOCALHOST_CONNECTION_ID).unwrap(),
+            conn_id
+        );
+        assert!(!ConnectionId::default().is_localhost());
+    }
+
+    #[rstest]
+    #[case("cosmos1owner")]
+    #[case("osmo1x9z8y7")]
+    #[case("a")]
+    fn test_ica_controller_port(#[case] owner: &str) {
+        let port_id = PortId::ica_controller(owner).unwrap();
+        assert_eq!(port_id.as_str(), format!("icacontroller-{owner}"));
+        assert_eq!(port_id.ica_controller_owner(), Some(owner));
+
+        let parsed = PortId::from_str(port_id.as_str()).unwrap();
+        assert_eq!(parsed.ica_controller_owner(), Some(owner));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::blank("  ")]
+    #[case::separator("cosmos/owner")]
+    #[case::too_long(&"a".repeat(128))]
+    fn test_invalid_ica_controller_port(#[case] owner: &str) {
+        assert!(PortId::ica_controller(owner).is_err());
+    }
+
+    #[rstest]
+    #[case("transfer")]
+    #[case("icacontroller-")]
+    #[case("icacontroller")]
+    #[case("icahost")]
+    fn test_not_ica_controller_port(#[case] port_id: &str) {
+        let port_id = PortId::from_str(port_id).unwrap();
+        assert_eq!(port_id.ica_controller_owner(), None);
+    }
+
     #[test]
     fn test_inc_revision_number() {
         let mut chain_id = ChainId::new("chainA-1").unwrap();
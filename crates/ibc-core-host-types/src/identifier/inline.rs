@@ -0,0 +1,134 @@
+//! Defines identifier types stored inline, in a buffer of the maximum length
+//! ICS-24 allows for their kind, which hosts handling many packets parse and
+//! copy without allocating, converting them to the `String`-backed
+//! identifiers only where those are required.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use arrayvec::ArrayString;
+use ibc_primitives::prelude::*;
+
+use super::validate::{
+    validate_channel_identifier, validate_client_identifier, validate_connection_identifier,
+    validate_port_identifier,
+};
+use super::{ChannelId, ClientId, ConnectionId, IdentifierError, PortId};
+
+macro_rules! inline_identifier {
+    ($(#[$attr:meta])* $name:ident($id:ident, $max_len:literal, $validate:ident)) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(ArrayString<$max_len>);
+
+        impl $name {
+            /// The maximum length of the identifier, as specified in ICS-24.
+            pub const MAX_LEN: usize = $max_len;
+
+            pub fn as_str(&self) -> &str {
+                self.0.as_str()
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = IdentifierError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $validate(s)?;
+                // The validator already bounds the identifier by `MAX_LEN`,
+                // this only guards against the two drifting apart.
+                ArrayString::from(s)
+                    .map(Self)
+                    .map_err(|_| IdentifierError::InvalidLength {
+                        id: s.into(),
+                        length: s.len() as u64,
+                        min: 0,
+                        max: $max_len,
+                    })
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl TryFrom<&$id> for $name {
+            type Error = IdentifierError;
+
+            fn try_from(id: &$id) -> Result<Self, Self::Error> {
+                Self::from_str(id.as_str())
+            }
+        }
+
+        impl From<$name> for $id {
+            fn from(id: $name) -> Self {
+                Self(id.as_str().to_string())
+            }
+        }
+
+        impl PartialEq<$id> for $name {
+            fn eq(&self, other: &$id) -> bool {
+                self.as_str() == other.as_str()
+            }
+        }
+    };
+}
+
+inline_identifier!(
+    /// A [`ClientId`] stored inline.
+    InlineClientId(ClientId, 64, validate_client_identifier)
+);
+
+inline_identifier!(
+    /// A [`ConnectionId`] stored inline.
+    InlineConnectionId(ConnectionId, 64, validate_connection_identifier)
+);
+
+inline_identifier!(
+    /// A [`PortId`] stored inline.
+    InlinePortId(PortId, 128, validate_port_identifier)
+);
+
+inline_identifier!(
+    /// A [`ChannelId`] stored inline.
+    InlineChannelId(ChannelId, 64, validate_channel_identifier)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_identifiers() {
+        let channel_id = InlineChannelId::from_str("channel-7").unwrap();
+        assert_eq!(channel_id, ChannelId::new(7));
+        assert_eq!(ChannelId::from(channel_id), ChannelId::new(7));
+        assert_eq!(
+            InlineChannelId::try_from(&ChannelId::new(7)).unwrap(),
+            channel_id
+        );
+
+        let port_id = "a".repeat(InlinePortId::MAX_LEN);
+        assert_eq!(InlinePortId::from_str(&port_id).unwrap().as_str(), port_id);
+        assert!(matches!(
+            InlinePortId::from_str(&format!("{port_id}a")),
+            Err(IdentifierError::InvalidLength { .. })
+        ));
+        assert!(InlineConnectionId::from_str("connection/0").is_err());
+
+        // Identifiers that were not validated may not fit inline.
+        let client_id = ClientId("a".repeat(InlineClientId::MAX_LEN + 1));
+        assert!(matches!(
+            InlineClientId::try_from(&client_id),
+            Err(IdentifierError::InvalidLength { .. })
+        ));
+    }
+}
@@ -0,0 +1,697 @@
+use ibc_primitives::prelude::*;
+
+use super::{
+    IdentifierError as Error, LOCALHOST_CLIENT_ID, LOCALHOST_CONNECTION_ID, TRANSFER_PORT_ID,
+};
+
+/// Path separator (ie. forward slash '/')
+const PATH_SEPARATOR: char = '/';
+const VALID_SPECIAL_CHARS: &str = "._+-#[]<>";
+const STRICT_SPECIAL_CHARS: &str = "._+-#";
+
+/// Identifiers reserved by the host: the localhost client and connection,
+/// and the port of the transfer application.
+pub const RESERVED_IDENTIFIERS: &[&str] = &[
+    LOCALHOST_CLIENT_ID,
+    LOCALHOST_CONNECTION_ID,
+    TRANSFER_PORT_ID,
+];
+
+/// The special characters identifiers may contain, besides alphanumeric
+/// characters. The path separator '/' is never allowed.
+///
+/// Identifiers are parsed against the ICS-24 set, so hosts may only narrow it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpecialChars {
+    /// `.`, `_`, `+`, `-` and `#`, i.e. the ICS-24 set without `[`, `]`,
+    /// `<` and `>`, which some chains forbid.
+    Strict,
+    /// `.`, `_`, `+`, `-`, `#`, `[`, `]`, `<` and `>`, as specified by ICS-24.
+    #[default]
+    Ics24,
+}
+
+impl SpecialChars {
+    /// Returns whether `c` belongs to this set.
+    pub fn contains(&self, c: char) -> bool {
+        match self {
+            Self::Strict => STRICT_SPECIAL_CHARS.contains(c),
+            Self::Ics24 => VALID_SPECIAL_CHARS.contains(c),
+        }
+    }
+}
+
+/// The minimum and maximum lengths, in bytes, of the identifiers of a kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LengthBounds {
+    min: u8,
+    max: u8,
+}
+
+impl LengthBounds {
+    /// The bounds of client identifiers specified by ICS-24.
+    pub const CLIENT_ID: Self = Self { min: 9, max: 64 };
+    /// The bounds of connection identifiers specified by ICS-24.
+    pub const CONNECTION_ID: Self = Self { min: 10, max: 64 };
+    /// The bounds of port identifiers specified by ICS-24.
+    pub const PORT_ID: Self = Self { min: 2, max: 128 };
+    /// The bounds of channel identifiers specified by ICS-24.
+    pub const CHANNEL_ID: Self = Self { min: 8, max: 64 };
+
+    /// Returns the bounds of identifiers between `min` and `max` bytes long,
+    /// both included, unless `min` is greater than `max`.
+    pub fn new(min: u8, max: u8) -> Result<Self, Error> {
+        if min > max {
+            return Err(Error::InvalidLengthBounds {
+                min: min.into(),
+                max: max.into(),
+            });
+        }
+
+        Ok(Self { min, max })
+    }
+
+    pub const fn min(&self) -> u64 {
+        self.min as u64
+    }
+
+    pub const fn max(&self) -> u64 {
+        self.max as u64
+    }
+
+    /// Const counterpart of [`Self::validate`].
+    pub const fn contains(&self, id: &str) -> bool {
+        is_valid_identifier_length(id, self.min(), self.max())
+    }
+
+    /// Returns the intersection of these bounds with `other`, i.e. the
+    /// bounds satisfying both, unless they have no length in common.
+    pub fn tightened(self, other: Self) -> Result<Self, Error> {
+        Self::new(self.min.max(other.min), self.max.min(other.max))
+    }
+
+    /// Checks if the identifier is within these bounds.
+    pub fn validate(&self, id: &str) -> Result<(), Error> {
+        validate_identifier_length(id, self.min(), self.max())
+    }
+}
+
+/// The rules a host validates the identifiers of the messages it handles
+/// against, as returned by
+/// `ValidationContext::identifier_validation_config`.
+///
+/// Identifiers are always parsed against ICS-24, e.g. by `ChannelId::from_str`.
+/// A config only tightens these rules, and only for the identifiers that refer
+/// to the host itself, e.g. the port and channel on the host of a packet it
+/// receives: those of the counterparty follow the config of the counterparty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IdentifierValidationConfig {
+    special_chars: SpecialChars,
+    client_id_length: LengthBounds,
+    connection_id_length: LengthBounds,
+    port_id_length: LengthBounds,
+    channel_id_length: LengthBounds,
+}
+
+impl Default for IdentifierValidationConfig {
+    fn default() -> Self {
+        Self::ics24()
+    }
+}
+
+impl IdentifierValidationConfig {
+    /// The config of ICS-24, which hosts follow unless they tighten it.
+    pub const fn ics24() -> Self {
+        Self {
+            special_chars: SpecialChars::Ics24,
+            client_id_length: LengthBounds::CLIENT_ID,
+            connection_id_length: LengthBounds::CONNECTION_ID,
+            port_id_length: LengthBounds::PORT_ID,
+            channel_id_length: LengthBounds::CHANNEL_ID,
+        }
+    }
+
+    pub const fn with_special_chars(self, special_chars: SpecialChars) -> Self {
+        Self {
+            special_chars,
+            ..self
+        }
+    }
+
+    /// Tightens the length bounds of client identifiers, e.g. to at most 42
+    /// characters. Bounds looser than those of ICS-24 are tightened to them,
+    /// and bounds disjoint from them are rejected.
+    pub fn with_client_id_length(self, bounds: LengthBounds) -> Result<Self, Error> {
+        Ok(Self {
+            client_id_length: LengthBounds::CLIENT_ID.tightened(bounds)?,
+            ..self
+        })
+    }
+
+    /// Tightens the length bounds of connection identifiers, as
+    /// [`Self::with_client_id_length`] does.
+    pub fn with_connection_id_length(self, bounds: LengthBounds) -> Result<Self, Error> {
+        Ok(Self {
+            connection_id_length: LengthBounds::CONNECTION_ID.tightened(bounds)?,
+            ..self
+        })
+    }
+
+    /// Tightens the length bounds of port identifiers, as
+    /// [`Self::with_client_id_length`] does.
+    pub fn with_port_id_length(self, bounds: LengthBounds) -> Result<Self, Error> {
+        Ok(Self {
+            port_id_length: LengthBounds::PORT_ID.tightened(bounds)?,
+            ..self
+        })
+    }
+
+    /// Tightens the length bounds of channel identifiers, as
+    /// [`Self::with_client_id_length`] does.
+    pub fn with_channel_id_length(self, bounds: LengthBounds) -> Result<Self, Error> {
+        Ok(Self {
+            channel_id_length: LengthBounds::CHANNEL_ID.tightened(bounds)?,
+            ..self
+        })
+    }
+
+    pub fn special_chars(&self) -> SpecialChars {
+        self.special_chars
+    }
+
+    pub fn client_id_length(&self) -> LengthBounds {
+        self.client_id_length
+    }
+
+    pub fn connection_id_length(&self) -> LengthBounds {
+        self.connection_id_length
+    }
+
+    pub fn port_id_length(&self) -> LengthBounds {
+        self.port_id_length
+    }
+
+    pub fn channel_id_length(&self) -> LengthBounds {
+        self.channel_id_length
+    }
+
+    /// Checks if the identifier only contains characters this config allows.
+    pub fn validate_identifier_chars(&self, id: &str) -> Result<(), Error> {
+        // Check identifier does not contain path separators
+        if id.contains(PATH_SEPARATOR) {
+            return Err(Error::ContainSeparator { id: id.into() });
+        }
+
+        // Check that the identifier comprises only valid characters:
+        // - Alphanumeric
+        // - the special characters of the config
+        if !id
+            .chars()
+            .all(|c| c.is_alphanumeric() || self.special_chars.contains(c))
+        {
+            return Err(Error::InvalidCharacter { id: id.into() });
+        }
+
+        // All good!
+        Ok(())
+    }
+
+    /// Checks if the client type forms valid client identifiers under this
+    /// config.
+    pub fn validate_client_type(&self, id: &str) -> Result<(), Error> {
+        self.validate_identifier_chars(id)?;
+        validate_prefix_length(id, self.client_id_length.min(), self.client_id_length.max())
+    }
+
+    /// Checks if the client identifier is valid under this config.
+    pub fn validate_client_identifier(&self, id: &str) -> Result<(), Error> {
+        self.validate_identifier_chars(id)?;
+        self.client_id_length.validate(id)
+    }
+
+    /// Checks if the connection identifier is valid under this config.
+    pub fn validate_connection_identifier(&self, id: &str) -> Result<(), Error> {
+        self.validate_identifier_chars(id)?;
+        self.connection_id_length.validate(id)
+    }
+
+    /// Checks if the port identifier is valid under this config.
+    pub fn validate_port_identifier(&self, id: &str) -> Result<(), Error> {
+        self.validate_identifier_chars(id)?;
+        self.port_id_length.validate(id)
+    }
+
+    /// Checks if the channel identifier is valid under this config.
+    pub fn validate_channel_identifier(&self, id: &str) -> Result<(), Error> {
+        self.validate_identifier_chars(id)?;
+        self.channel_id_length.validate(id)
+    }
+}
+
+/// Checks if the identifier only contains valid characters as specified in the
+/// [`ICS-24`](https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#paths-identifiers-separators)]
+/// spec.
+pub fn validate_identifier_chars(id: &str) -> Result<(), Error> {
+    IdentifierValidationConfig::ics24().validate_identifier_chars(id)
+}
+
+/// Returns whether the identifier is one of the [`RESERVED_IDENTIFIERS`].
+pub fn is_reserved_identifier(id: &str) -> bool {
+    RESERVED_IDENTIFIERS.contains(&id)
+}
+
+/// Checks that the identifier is not one of the [`RESERVED_IDENTIFIERS`], as
+/// the handlers do for the identifiers obtained from the
+/// [`IdentifierAllocator`](crate::allocator::IdentifierAllocator)
+/// of the host.
+pub fn validate_unreserved_identifier(id: &str) -> Result<(), Error> {
+    if is_reserved_identifier(id) {
+        return Err(Error::Reserved { id: id.into() });
+    }
+
+    Ok(())
+}
+
+/// Checks if the identifier forms a valid identifier with the given min/max length as specified in the
+/// [`ICS-24`](https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#paths-identifiers-separators)]
+/// spec.
+pub fn validate_identifier_length(id: &str, min: u64, max: u64) -> Result<(), Error> {
+    // Make sure min is at least one so we reject empty identifiers.
+    let min = min.max(1);
+    let length = id.len() as u64;
+    if (min..=max).contains(&length) {
+        Ok(())
+    } else {
+        Err(Error::InvalidLength {
+            id: id.into(),
+            length,
+            min,
+            max,
+        })
+    }
+}
+
+/// Checks if a prefix forms a valid identifier with the given min/max identifier's length.
+/// The prefix must be between `min_id_length - 2`, considering `u64::MIN` (1 char) and "-"
+/// and `max_id_length - 21` characters, considering `u64::MAX` (20 chars) and "-".
+pub fn validate_prefix_length(
+    prefix: &str,
+    min_id_length: u64,
+    max_id_length: u64,
+) -> Result<(), Error> {
+    // Prefix must be at least `min_id_length - 2` characters long since the
+    // shortest identifier we can construct is `{prefix}-0` which extends prefix
+    // by 2 characters.
+    let min = min_id_length.saturating_sub(2);
+    // Prefix must be at most `max_id_length - 21` characters long since the
+    // longest identifier we can construct is `{prefix}-{u64::MAX}` which
+    // extends prefix by 21 characters.
+    let max = max_id_length.saturating_sub(21);
+
+    validate_identifier_length(prefix, min, max)
+}
+
+/// Default validator function for the Client types.
+pub fn validate_client_type(id: &str) -> Result<(), Error> {
+    IdentifierValidationConfig::ics24().validate_client_type(id)
+}
+
+/// Default validator function for Client identifiers.
+///
+/// A valid client identifier must be between 9-64 characters as specified in
+/// the ICS-24 spec.
+pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
+    IdentifierValidationConfig::ics24().validate_client_identifier(id)
+}
+
+/// Default validator function for Connection identifiers.
+///
+/// A valid connection identifier must be between 10-64 characters as specified
+/// in the ICS-24 spec.
+pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
+    IdentifierValidationConfig::ics24().validate_connection_identifier(id)
+}
+
+/// Default validator function for Port identifiers.
+///
+/// A valid port identifier must be between 2-128 characters as specified in the
+/// ICS-24 spec.
+pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
+    IdentifierValidationConfig::ics24().validate_port_identifier(id)
+}
+
+/// Default validator function for Channel identifiers.
+///
+/// A valid channel identifier must be between 8-64 characters as specified in
+/// the ICS-24 spec.
+pub fn validate_channel_identifier(id: &str) -> Result<(), Error> {
+    IdentifierValidationConfig::ics24().validate_channel_identifier(id)
+}
+
+/// Const counterpart of [`validate_identifier_chars`], for validating
+/// identifiers at compile time.
+///
+/// Only ASCII alphanumeric characters are accepted, along with the special
+/// characters allowed by ICS-24.
+pub const fn is_valid_identifier_chars(id: &str) -> bool {
+    let bytes = id.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !is_valid_identifier_byte(bytes[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn is_valid_identifier_byte(byte: u8) -> bool {
+    if byte.is_ascii_alphanumeric() {
+        return true;
+    }
+
+    let special_chars = VALID_SPECIAL_CHARS.as_bytes();
+    let mut i = 0;
+    while i < special_chars.len() {
+        if special_chars[i] == byte {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Const counterpart of [`validate_identifier_length`].
+pub const fn is_valid_identifier_length(id: &str, min: u64, max: u64) -> bool {
+    let min = if min < 1 { 1 } else { min };
+    let length = id.len() as u64;
+    min <= length && length <= max
+}
+
+/// Const counterpart of [`validate_prefix_length`].
+pub const fn is_valid_prefix_length(prefix: &str, min_id_length: u64, max_id_length: u64) -> bool {
+    is_valid_identifier_length(
+        prefix,
+        min_id_length.saturating_sub(2),
+        max_id_length.saturating_sub(21),
+    )
+}
+
+/// Const counterpart of the validation of a
+/// [`ClientType`](crate::client_type::ClientType).
+pub const fn is_valid_client_type(id: &str) -> bool {
+    is_valid_identifier_chars(id)
+        && is_valid_prefix_length(
+            id,
+            LengthBounds::CLIENT_ID.min(),
+            LengthBounds::CLIENT_ID.max(),
+        )
+}
+
+/// Const counterpart of the validation of a [`ClientId`](super::ClientId).
+pub const fn is_valid_client_identifier(id: &str) -> bool {
+    is_valid_identifier_chars(id) && LengthBounds::CLIENT_ID.contains(id)
+}
+
+/// Const counterpart of the validation of a [`ConnectionId`](super::ConnectionId).
+pub const fn is_valid_connection_identifier(id: &str) -> bool {
+    is_valid_identifier_chars(id) && LengthBounds::CONNECTION_ID.contains(id)
+}
+
+/// Const counterpart of the validation of a [`PortId`](super::PortId).
+pub const fn is_valid_port_identifier(id: &str) -> bool {
+    is_valid_identifier_chars(id) && LengthBounds::PORT_ID.contains(id)
+}
+
+/// Const counterpart of the validation of a [`ChannelId`](super::ChannelId).
+pub const fn is_valid_channel_identifier(id: &str) -> bool {
+    is_valid_identifier_chars(id) && LengthBounds::CHANNEL_ID.contains(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use test_log::test;
+
+    use super::*;
+
+    #[test]
+    fn parse_invalid_port_id_min() {
+        // invalid min port id
+        let id = validate_port_identifier("p");
+        assert!(id.is_err())
+    }
+
+    #[test]
+    fn parse_invalid_port_id_max() {
+        // invalid max port id (test string length is 130 chars)
+        let id = validate_port_identifier(
+            "9anxkcme6je544d5lnj46zqiiiygfqzf8w4bjecbnyj4lj6s7zlpst67yln64tixp9anxkcme6je544d5lnj46zqiiiygfqzf8w4bjecbnyj4lj6s7zlpst67yln64tixp",
+        );
+        assert!(id.is_err())
+    }
+
+    #[test]
+    fn parse_invalid_connection_id_min() {
+        // invalid min connection id
+        let id = validate_connection_identifier("connect01");
+        assert!(id.is_err())
+    }
+
+    #[test]
+    fn parse_connection_id_max() {
+        // invalid max connection id (test string length is 65)
+        let id = validate_connection_identifier(
+            "ihhankr30iy4nna65hjl2wjod7182io1t2s7u3ip3wqtbbn1sl0rgcntqc540r36r",
+        );
+        assert!(id.is_err())
+    }
+
+    #[test]
+    fn parse_invalid_channel_id_min() {
+        // invalid channel id, must be at least 8 characters
+        let id = validate_channel_identifier("channel");
+        assert!(id.is_err())
+    }
+
+    #[test]
+    fn parse_channel_id_max() {
+        // invalid channel id (test string length is 65)
+        let id = validate_channel_identifier(
+            "ihhankr30iy4nna65hjl2wjod7182io1t2s7u3ip3wqtbbn1sl0rgcntqc540r36r",
+        );
+        assert!(id.is_err())
+    }
+
+    #[test]
+    fn parse_invalid_client_id_min() {
+        // invalid min client id
+        let id = validate_client_identifier("client");
+        assert!(id.is_err())
+    }
+
+    #[test]
+    fn parse_client_id_max() {
+        // invalid max client id (test string length is 65)
+        let id = validate_client_identifier(
+            "f0isrs5enif9e4td3r2jcbxoevhz6u1fthn4aforq7ams52jn5m48eiesfht9ckpn",
+        );
+        assert!(id.is_err())
+    }
+
+    #[test]
+    fn parse_invalid_id_chars() {
+        // invalid id chars
+        let id = validate_identifier_chars("channel@01");
+        assert!(id.is_err())
+    }
+
+    #[test]
+    fn validate_chars_empty_id() {
+        // validate_identifier_chars allows empty identifiers
+        assert!(validate_identifier_chars("").is_ok());
+    }
+
+    #[test]
+    fn validate_length_empty_id() {
+        // validate_identifier_length does not allow empty identifiers
+        assert!(validate_identifier_length("", 0, 64).is_err());
+    }
+
+    #[test]
+    fn validate_min_gt_max_constraints() {
+        // validate_identifier_length rejects the id if min > max.
+        assert!(validate_identifier_length("foobar", 5, 3).is_err());
+    }
+
+    #[test]
+    fn parse_invalid_id_path_separator() {
+        // invalid id with path separator
+        let id = validate_identifier_chars("id/1");
+        assert!(id.is_err())
+    }
+
+    #[test]
+    fn parse_healthy_client_type() {
+        let id = validate_client_type("07-tendermint");
+        assert!(id.is_ok())
+    }
+
+    #[test]
+    fn parse_invalid_short_client_type() {
+        let id = validate_client_type("<7Char");
+        assert!(id.is_err())
+    }
+
+    #[test]
+    fn parse_invalid_lengthy_client_type() {
+        let id = validate_client_type("InvalidClientTypeWithLengthOfClientId>65Char");
+        assert!(id.is_err())
+    }
+
+    #[rstest]
+    #[case("transfer", true)]
+    #[case("07-tendermint-0", true)]
+    #[case("channel-0", true)]
+    #[case("a.b_c+d-e#f[g]<h>", true)]
+    #[case("", false)]
+    #[case("id/1", false)]
+    #[case("channel@01", false)]
+    #[case("chännel-0", false)]
+    #[test_log::test]
+    fn test_const_validation_agrees(#[case] id: &str, #[case] valid: bool) {
+        assert_eq!(is_valid_port_identifier(id), valid);
+        if valid {
+            assert!(validate_port_identifier(id).is_ok());
+        }
+        assert_eq!(
+            is_valid_client_identifier(id),
+            validate_client_identifier(id).is_ok() && id.is_ascii()
+        );
+        assert_eq!(
+            is_valid_channel_identifier(id),
+            validate_channel_identifier(id).is_ok() && id.is_ascii()
+        );
+        assert_eq!(
+            is_valid_connection_identifier(id),
+            validate_connection_identifier(id).is_ok() && id.is_ascii()
+        );
+        assert_eq!(
+            is_valid_client_type(id),
+            validate_client_type(id).is_ok() && id.is_ascii()
+        );
+    }
+
+    #[rstest]
+    #[case::strict("a.b_c+d-e#f", SpecialChars::Strict, true)]
+    #[case::strict_brackets("a[b]", SpecialChars::Strict, false)]
+    #[case::strict_angle_brackets("a<b>", SpecialChars::Strict, false)]
+    #[case::ics24_brackets("a[b]<c>", SpecialChars::Ics24, true)]
+    #[case::ics24_at("channel@01", SpecialChars::Ics24, false)]
+    #[case::strict_separator("id/1", SpecialChars::Strict, false)]
+    #[test_log::test]
+    fn test_config_special_chars(
+        #[case] id: &str,
+        #[case] special_chars: SpecialChars,
+        #[case] valid: bool,
+    ) {
+        let config = IdentifierValidationConfig::ics24().with_special_chars(special_chars);
+        assert_eq!(config.validate_identifier_chars(id).is_ok(), valid);
+    }
+
+    #[test]
+    fn default_config_is_ics24() {
+        assert_eq!(
+            IdentifierValidationConfig::default(),
+            IdentifierValidationConfig::ics24()
+        );
+        assert_eq!(
+            IdentifierValidationConfig::default().special_chars(),
+            SpecialChars::Ics24
+        );
+    }
+
+    #[test]
+    fn test_config_length_bounds() {
+        let bounds = |min, max| LengthBounds::new(min, max).unwrap();
+
+        let config = IdentifierValidationConfig::ics24()
+            .with_client_id_length(bounds(0, 16))
+            .unwrap();
+        assert_eq!(config.client_id_length(), bounds(9, 16));
+        assert!(config.validate_client_identifier("07-tendermint-0").is_ok());
+        assert!(config
+            .validate_client_identifier("07-tendermint-100")
+            .is_err());
+        assert!(config.validate_client_type("07-tendermint").is_err());
+        assert_eq!(
+            config.connection_id_length(),
+            IdentifierValidationConfig::ics24().connection_id_length()
+        );
+
+        // bounds looser than those of ICS-24 are tightened to them
+        let config = IdentifierValidationConfig::ics24()
+            .with_port_id_length(bounds(1, 255))
+            .unwrap();
+        assert_eq!(config.port_id_length(), LengthBounds::PORT_ID);
+        assert!(config.validate_port_identifier("p").is_err());
+    }
+
+    #[test]
+    fn test_empty_length_bounds() {
+        assert!(matches!(
+            LengthBounds::new(10, 9),
+            Err(Error::InvalidLengthBounds { min: 10, max: 9 })
+        ));
+
+        // bounds disjoint from those of ICS-24 leave no length to tighten to
+        let bounds = LengthBounds::new(65, 70).unwrap();
+        assert!(matches!(
+            LengthBounds::CHANNEL_ID.tightened(bounds),
+            Err(Error::InvalidLengthBounds { min: 65, max: 64 })
+        ));
+        assert!(IdentifierValidationConfig::ics24()
+            .with_channel_id_length(bounds)
+            .is_err());
+    }
+
+    #[rstest]
+    #[case(LOCALHOST_CLIENT_ID, true)]
+    #[case(LOCALHOST_CONNECTION_ID, true)]
+    #[case(TRANSFER_PORT_ID, true)]
+    #[case("07-tendermint-0", false)]
+    #[case("connection-0", false)]
+    #[case("transfer-1", false)]
+    #[test_log::test]
+    fn test_reserved_identifiers(#[case] id: &str, #[case] reserved: bool) {
+        assert_eq!(is_reserved_identifier(id), reserved);
+        assert_eq!(
+            matches!(
+                validate_unreserved_identifier(id),
+                Err(Error::Reserved { .. })
+            ),
+            reserved
+        );
+    }
+
+    #[rstest]
+    #[case::zero_min_length("", 0, 64, false)]
+    #[case::empty_prefix("", 1, 64, false)]
+    #[case::max_is_low("a", 1, 10, false)]
+    #[case::min_greater_than_max("foobar", 5, 3, false)]
+    #[case::u64_max_is_too_big("a", 3, 21, false)]
+    #[case::u64_min_is_too_small("a", 4, 22, false)]
+    #[case::u64_min_max_boundary("a", 3, 22, true)]
+    #[case("chainA", 1, 32, true)]
+    #[case("chainA", 1, 64, true)]
+    #[test_log::test]
+    fn test_prefix_length_validation(
+        #[case] prefix: &str,
+        #[case] min: u64,
+        #[case] max: u64,
+        #[case] success: bool,
+    ) {
+        let result = validate_prefix_length(prefix, min, max);
+        assert_eq!(result.is_ok(), success);
+    }
+}
@@ -0,0 +1,25 @@
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+// https://github.com/cosmos/ibc-rs/issues/342
+#![allow(clippy::result_large_err)]
+//! ICS 24: Host defines the minimal set of interfaces that a
+//! state machine hosting an IBC-enabled chain must implement.
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod allocator;
+pub mod client_type;
+pub mod identifier;
+
+mod macros;
@@ -0,0 +1,174 @@
+/// Downcast the given arguments to the associated enum variant.
+///
+/// ## Note
+/// Only works for enums whose variants only hold a single value.
+///
+/// ## Example
+///
+/// ```rust
+/// use ibc_core_host_types::downcast;
+///
+/// enum Foo {
+///     Bar(u32),
+///     Baz(bool),
+/// }
+///
+/// let bar = Foo::Bar(42);
+/// let baz = Foo::Baz(true);
+///
+/// if let Some(value) = downcast!(bar => Foo::Bar) {
+///     println!("value is a u32: {}", value);
+/// }
+///
+/// if let Some(value) = downcast!(baz => Foo::Baz) {
+///     println!("value is a bool: {}", value);
+/// }
+///
+/// if let Some((a, b)) = downcast!(bar => Foo::Bar, baz => Foo::Baz) {
+///     println!("a is a u32: {}", a);
+///     println!("b is a bool: {}", b);
+/// }
+/// ```
+#[macro_export]
+macro_rules! downcast {
+    ( $e1:expr => $p1:path, $( $e:expr => $p:path ),+ $(,)? ) => {
+        downcast!($e1 => $p1).zip(downcast!($($e => $p),+))
+    };
+
+    ($e:expr => $p:path) => {
+        match $e {
+            $p(e) => Some(e),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    };
+
+    () => {
+        None
+    }
+}
+
+/// Builds a [`ClientType`](crate::client_type::ClientType)
+/// from a constant string, validated at compile time.
+///
+/// ```rust
+/// use ibc_core_host_types::client_type;
+///
+/// let client_type = client_type!("07-tendermint");
+/// assert_eq!(client_type.as_str(), "07-tendermint");
+/// ```
+///
+/// ```compile_fail
+/// let client_type = ibc_core_host_types::client_type!("07/tendermint");
+/// ```
+#[macro_export]
+macro_rules! client_type {
+    ($id:expr) => {{
+        const _: () = ::core::assert!(
+            $crate::identifier::is_valid_client_type($id),
+            "invalid client type"
+        );
+        <$crate::client_type::ClientType as ::core::str::FromStr>::from_str($id)
+            .expect("Never fails because it was validated at compile time")
+    }};
+}
+
+/// Builds a [`ClientId`](crate::identifier::ClientId) from a
+/// constant string, validated at compile time.
+///
+/// ```rust
+/// use ibc_core_host_types::client_id;
+///
+/// let client_id = client_id!("07-tendermint-0");
+/// assert_eq!(client_id.as_str(), "07-tendermint-0");
+/// ```
+///
+/// ```compile_fail
+/// let client_id = ibc_core_host_types::client_id!("client");
+/// ```
+#[macro_export]
+macro_rules! client_id {
+    ($id:expr) => {{
+        const _: () = ::core::assert!(
+            $crate::identifier::is_valid_client_identifier($id),
+            "invalid client identifier"
+        );
+        <$crate::identifier::ClientId as ::core::str::FromStr>::from_str($id)
+            .expect("Never fails because it was validated at compile time")
+    }};
+}
+
+/// Builds a [`ConnectionId`](crate::identifier::ConnectionId)
+/// from a constant string, validated at compile time.
+///
+/// ```rust
+/// use ibc_core_host_types::connection_id;
+///
+/// let conn_id = connection_id!("connection-0");
+/// assert_eq!(conn_id.as_str(), "connection-0");
+/// ```
+///
+/// ```compile_fail
+/// let conn_id = ibc_core_host_types::connection_id!("connect01");
+/// ```
+#[macro_export]
+macro_rules! connection_id {
+    ($id:expr) => {{
+        const _: () = ::core::assert!(
+            $crate::identifier::is_valid_connection_identifier($id),
+            "invalid connection identifier"
+        );
+        <$crate::identifier::ConnectionId as ::core::str::FromStr>::from_str($id)
+            .expect("Never fails because it was validated at compile time")
+    }};
+}
+
+/// Builds a [`PortId`](crate::identifier::PortId) from a
+/// constant string, validated at compile time.
+///
+/// ```rust
+/// use ibc_core_host_types::port_id;
+///
+/// let port_id = port_id!("transfer");
+/// assert_eq!(port_id.as_str(), "transfer");
+/// ```
+///
+/// ```compile_fail
+/// let port_id = ibc_core_host_types::port_id!("p");
+/// ```
+#[macro_export]
+macro_rules! port_id {
+    ($id:expr) => {{
+        const _: () = ::core::assert!(
+            $crate::identifier::is_valid_port_identifier($id),
+            "invalid port identifier"
+        );
+        <$crate::identifier::PortId as ::core::str::FromStr>::from_str($id)
+            .expect("Never fails because it was validated at compile time")
+    }};
+}
+
+/// Builds a [`ChannelId`](crate::identifier::ChannelId) from
+/// a constant string, validated at compile time.
+///
+/// ```rust
+/// use ibc_core_host_types::channel_id;
+///
+/// let chan_id = channel_id!("channel-0");
+/// assert_eq!(chan_id.as_str(), "channel-0");
+/// ```
+///
+/// ```compile_fail
+/// let chan_id = ibc_core_host_types::channel_id!("channel");
+/// ```
+#[macro_export]
+macro_rules! channel_id {
+    ($id:expr) => {{
+        const _: () = ::core::assert!(
+            $crate::identifier::is_valid_channel_identifier($id),
+            "invalid channel identifier"
+        );
+        <$crate::identifier::ChannelId as ::core::str::FromStr>::from_str($id)
+            .expect("Never fails because it was validated at compile time")
+    }};
+}
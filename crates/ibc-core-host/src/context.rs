@@ -0,0 +1,494 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::time::Duration;
+
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::packet::{Receipt, Sequence};
+use ibc_core_client_context::client_state::{
+    ClientState, ClientStateCommon, ClientStateValidation,
+};
+use ibc_core_client_context::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client_context::registry::{KnownClientTypes, UnlistedClientTypes};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::Height;
+use ibc_core_client_types::params::ClientParams;
+use ibc_core_client_types::status::Status;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, ProofHash, ProofResolver,
+};
+use ibc_core_connection_types::connection::ConnectionEnd;
+use ibc_core_connection_types::params::ConnectionParams;
+use ibc_core_connection_types::version::{
+    get_compatible_versions, pick_version, Version as ConnectionVersion,
+};
+pub use ibc_core_handler_types::error::{ContextError, RouterError};
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_handler_types::msgs::{AcceptAllMsgs, MsgFilter};
+use ibc_core_handler_types::path::{
+    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, CommitmentPath,
+    ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host_types::allocator::{CounterAllocator, IdentifierAllocator};
+use ibc_core_host_types::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, IdentifierValidationConfig, PortId,
+};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use ibc_primitives::timestamp::Timestamp;
+use ibc_proto::google::protobuf::Any;
+
+use crate::timeout_queue::TimeoutQueueContext;
+#[cfg(feature = "connection-upgrade")]
+use crate::upgrade::ConnectionUpgradeContext;
+
+/// Context to be implemented by the host that provides all "read-only" methods.
+///
+/// Trait used for the top-level `validate`
+pub trait ValidationContext {
+    type V: ClientValidationContext;
+    type E: ClientExecutionContext;
+    type AnyConsensusState: ConsensusState;
+    type AnyClientState: ClientState<Self::V, Self::E>;
+
+    /// Retrieve the context that implements all clients' `ValidationContext`.
+    fn get_client_validation_context(&self) -> &Self::V;
+
+    /// Returns the ClientState for the given identifier `client_id`.
+    ///
+    /// Note: Clients have the responsibility to store client states on client creation and update.
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::AnyClientState, ContextError>;
+
+    /// Tries to decode the given `client_state` into a concrete light client state.
+    fn decode_client_state(&self, client_state: Any) -> Result<Self::AnyClientState, ContextError>;
+
+    /// Returns the client state types known to the host, which
+    /// `validate` checks the client states of
+    /// `MsgCreateClient`s against. Defaults to [`UnlistedClientTypes`], which
+    /// leaves them all to [`decode_client_state`](Self::decode_client_state).
+    fn known_client_types(&self) -> &dyn KnownClientTypes {
+        &UnlistedClientTypes
+    }
+
+    /// Returns the identifier of an existing client created from the same
+    /// `client_state` and `consensus_state`, if any.
+    ///
+    /// Hosts can opt into replay protection for `MsgCreateClient` by
+    /// implementing this lookup: a message that would create a client
+    /// identical to an existing one is then rejected, so that relayers racing
+    /// each other do not litter the store with redundant clients. The default
+    /// implementation never reports a duplicate.
+    fn find_duplicate_client(
+        &self,
+        _client_state: &Any,
+        _consensus_state: &Any,
+    ) -> Result<Option<ClientId>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns the current parameters of the client module.
+    ///
+    /// Hosts store [`ClientParams::default`], allowing all client types, at
+    /// genesis unless configured otherwise, and update them through
+    /// [`ExecutionContext::store_client_params`].
+    fn client_params(&self) -> Result<ClientParams, ContextError>;
+
+    /// Returns the status of the client `client_id`, as reported by the gRPC
+    /// `ClientStatus` query of ibc-go.
+    ///
+    /// Clients whose type is not in the [`ClientParams::allowed_clients`] are
+    /// `Unauthorized`, the others report their status through
+    /// [`ClientStateValidation::status`].
+    fn client_status(&self, client_id: &ClientId) -> Result<Status, ContextError> {
+        let client_state = self.client_state(client_id)?;
+
+        if !self
+            .client_params()?
+            .is_allowed_client(&client_state.client_type())
+        {
+            return Ok(Status::Unauthorized);
+        }
+
+        Ok(client_state.status(self.get_client_validation_context(), client_id)?)
+    }
+
+    /// Returns the identifier of the chain tracked by the client `client_id`,
+    /// i.e. of the counterparty chain of its connections, or `None` if its
+    /// client type has no notion of chain identifier.
+    ///
+    /// Spares hosts and applications from decoding the client state to
+    /// display or validate the peer chain.
+    fn counterparty_chain_id(&self, client_id: &ClientId) -> Result<Option<ChainId>, ContextError> {
+        Ok(self.client_state(client_id)?.counterparty_chain_id())
+    }
+
+    /// Returns how long before their expiry clients updated on this host
+    /// trigger a [`ClientNearExpiry`](ibc_core_client_types::events::ClientNearExpiry)
+    /// event, or `None` if the event is never emitted, which is the default.
+    ///
+    /// Hosts set it to warn operators early of the clients that relayers
+    /// need to update more often.
+    fn client_expiry_warning_margin(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Retrieve the consensus state for the given client ID at the specified
+    /// height.
+    ///
+    /// Returns an error if no such state exists.
+    ///
+    /// Note: Clients have the responsibility to store consensus states on client creation and update.
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::AnyConsensusState, ContextError>;
+
+    /// Returns the current height of the local chain.
+    fn host_height(&self) -> Result<Height, ContextError>;
+
+    /// Returns the current timestamp of the local chain.
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
+    /// Returns the `ConsensusState` of the host (local) chain at a specific height.
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::AnyConsensusState, ContextError>;
+
+    /// Returns a natural number, counting how many clients have been created
+    /// thus far. The value of this counter should increase only via method
+    /// `ExecutionContext::increase_client_counter`.
+    fn client_counter(&self) -> Result<u64, ContextError>;
+
+    /// Returns the ConnectionEnd for the given identifier `conn_id`.
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError>;
+
+    /// Returns the identifier of the local connection whose counterparty is
+    /// `conn_id_on_b`, opened on the counterparty chain with the client
+    /// `client_id_on_b`, if any.
+    ///
+    /// Relayers and ICS-29 payee registration flows use this reverse lookup
+    /// to find the local end of a connection from the counterparty's view of
+    /// it. The default implementation never finds a match.
+    fn connection_id_by_counterparty(
+        &self,
+        _client_id_on_b: &ClientId,
+        _conn_id_on_b: &ConnectionId,
+    ) -> Result<Option<ConnectionId>, ContextError> {
+        Ok(None)
+    }
+
+    /// Validates the `ClientState` of the client (a client referring to host) stored on the counterparty chain against the host's internal state.
+    ///
+    /// For more information on the specific requirements for validating the
+    /// client state of a host chain, please refer to the [ICS24 host
+    /// requirements](https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#client-state-validation)
+    ///
+    /// Additionally, implementations specific to individual chains can be found
+    /// in the hosts module.
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Any,
+    ) -> Result<(), ContextError>;
+
+    /// Returns the prefix that the local chain uses in the KV store.
+    fn commitment_prefix(&self) -> CommitmentPrefix;
+
+    /// Returns a counter on how many connections have been created thus far.
+    fn connection_counter(&self) -> Result<u64, ContextError>;
+
+    /// Function required by ICS 03. Returns the list of all possible versions that the connection
+    /// handshake protocol supports.
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        get_compatible_versions()
+    }
+
+    /// Function required by ICS 03. Returns one version out of the supplied list of versions, which the
+    /// connection handshake protocol prefers.
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        let version = pick_version(
+            &self.get_compatible_versions(),
+            counterparty_candidate_versions,
+        )?;
+        Ok(version)
+    }
+
+    /// Returns the `ChannelEnd` for the given `port_id` and `chan_id`.
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError>;
+
+    /// Returns the port and channel identifiers of the local channel whose
+    /// counterparty is `chan_id_on_b` bound to `port_id_on_b`, on the
+    /// counterparty chain that tracks this host with the client
+    /// `client_id_on_b`, if any.
+    ///
+    /// The client is needed to disambiguate the lookup, since channel
+    /// identifiers are only unique per chain. The default implementation never
+    /// finds a match.
+    fn channel_id_by_counterparty(
+        &self,
+        _client_id_on_b: &ClientId,
+        _port_id_on_b: &PortId,
+        _chan_id_on_b: &ChannelId,
+    ) -> Result<Option<(PortId, ChannelId)>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns the sequence number for the next packet to be sent for the given store path
+    fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
+        -> Result<Sequence, ContextError>;
+
+    /// Returns the sequence number for the next packet to be received for the given store path
+    fn get_next_sequence_recv(&self, seq_recv_path: &SeqRecvPath)
+        -> Result<Sequence, ContextError>;
+
+    /// Returns the sequence number for the next packet to be acknowledged for the given store path
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError>;
+
+    /// Returns the packet commitment for the given store path
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError>;
+
+    /// Returns the packet receipt for the given store path
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError>;
+
+    /// Returns the packet acknowledgement for the given store path
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError>;
+
+    /// Returns a counter on the number of channel ids have been created thus far.
+    /// The value of this counter should increase only via method
+    /// `ExecutionContext::increase_channel_counter`.
+    fn channel_counter(&self) -> Result<u64, ContextError>;
+
+    /// Returns the current parameters of the connection module.
+    ///
+    /// Hosts store [`ConnectionParams::default`] at genesis, unless configured
+    /// otherwise, and update them through
+    /// [`ExecutionContext::store_connection_params`].
+    fn connection_params(&self) -> Result<ConnectionParams, ContextError>;
+
+    /// Returns the allocator from which the handlers obtain the identifiers of
+    /// the clients, connections and channels created on this host, which
+    /// defaults to the [`CounterAllocator`].
+    fn identifier_allocator(&self) -> &dyn IdentifierAllocator {
+        &CounterAllocator
+    }
+
+    /// Returns the config the identifiers of the messages handled by the
+    /// host are validated against, on top of ICS-24, by
+    /// `validate`. Defaults to
+    /// [`IdentifierValidationConfig::ics24`].
+    fn identifier_validation_config(&self) -> IdentifierValidationConfig {
+        IdentifierValidationConfig::ics24()
+    }
+
+    /// Returns the maximum size, in bytes, of the data of the packets sent
+    /// from this host, or `None` if it is unbounded, which is the default.
+    ///
+    /// Hosts set it to bound the memory relayers and counterparties need to
+    /// process the packets they send.
+    fn max_packet_data_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns whether the counterparty of the channel records the receipts
+    /// of the packets it receives in bitmaps (see
+    /// [`receipt_bitmap`](crate::receipt_bitmap)), which
+    /// it does not by default. The timeouts of the packets sent on such a
+    /// channel prove the absence of the bitmap of their chunk rather than of
+    /// their own receipt, which the counterparty never writes.
+    fn counterparty_uses_receipt_bitmap(&self, _port_id: &PortId, _channel_id: &ChannelId) -> bool {
+        false
+    }
+
+    /// Calculates the block delay period using the connection's delay period and the maximum
+    /// expected time per block of the connection parameters.
+    fn block_delay(&self, delay_period_time: &Duration) -> Result<u64, ContextError> {
+        Ok(self.connection_params()?.block_delay(delay_period_time))
+    }
+
+    /// Validates the `signer` field of IBC messages, which represents the address
+    /// of the user/relayer that signed the given message.
+    ///
+    /// `validate` runs it before handling any message.
+    /// Hosts requiring a canonical account format may implement it with one of
+    /// the [`SignerValidator`](ibc_primitives::signer::SignerValidator)s.
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+
+    /// Returns the policy deciding which messages the host handles at all,
+    /// which `validate` consults before any other
+    /// processing. Defaults to [`AcceptAllMsgs`].
+    fn msg_filter(&self) -> &dyn MsgFilter {
+        &AcceptAllMsgs
+    }
+
+    /// Returns the resolver of the proofs that messages refer to by hash,
+    /// which the host receives out of band, or `None` if the host receives no
+    /// proofs out of band, which is the default.
+    fn proof_resolver(&self) -> Option<&dyn ProofResolver> {
+        None
+    }
+
+    /// Returns `proof`, or the proof it refers to by hash if the host has a
+    /// [`proof_resolver`](Self::proof_resolver), as resolved through it, for
+    /// handlers to verify.
+    ///
+    /// Without a resolver, proofs are always handled as they are, so that
+    /// hosts not opting in see no change in the proof bytes they verify.
+    fn resolve_proof<'a>(
+        &self,
+        proof: &'a CommitmentProofBytes,
+    ) -> Result<Cow<'a, CommitmentProofBytes>, ContextError> {
+        let resolver = match self.proof_resolver() {
+            Some(resolver) => resolver,
+            None => return Ok(Cow::Borrowed(proof)),
+        };
+
+        ProofHash::from_proof_ref(proof)
+            .and_then(|hash| hash.map(|hash| hash.resolve(resolver)).transpose())
+            .map(|resolved| resolved.map_or(Cow::Borrowed(proof), Cow::Owned))
+            .map_err(|e| ClientError::InvalidCommitmentProof(e).into())
+    }
+
+    /// Returns the context in which the connection upgrade handlers keep
+    /// track of the upgrades in progress, or `None` if the host does not
+    /// support the upgrade of its connections, which is the default.
+    #[cfg(feature = "connection-upgrade")]
+    fn connection_upgrades(&self) -> Option<&dyn ConnectionUpgradeContext> {
+        None
+    }
+}
+
+/// Context to be implemented by the host that provides all "write-only" methods.
+///
+/// Trait used for the top-level `execute` and `dispatch`
+pub trait ExecutionContext: ValidationContext {
+    /// Retrieve the context that implements all clients' `ExecutionContext`.
+    fn get_client_execution_context(&mut self) -> &mut Self::E;
+
+    /// Called upon client creation.
+    /// Increases the counter which keeps track of how many clients have been created.
+    /// Should never fail.
+    fn increase_client_counter(&mut self) -> Result<(), ContextError>;
+
+    /// Stores the given parameters of the client module, replacing the
+    /// current ones.
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError>;
+
+    /// Stores the given connection_end at path
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given connection_id at a path associated with the client_id.
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError>;
+
+    /// Called upon connection identifier creation (Init or Try process).
+    /// Increases the counter which keeps track of how many connections have been created.
+    /// Should never fail.
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError>;
+
+    /// Stores the given parameters of the connection module, replacing the
+    /// current ones.
+    fn store_connection_params(&mut self, params: ConnectionParams) -> Result<(), ContextError>;
+
+    /// Stores the given packet commitment at the given store path
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError>;
+
+    /// Deletes the packet commitment at the given store path
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given packet receipt at the given store path
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given packet acknowledgement at the given store path
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError>;
+
+    /// Deletes the packet acknowledgement at the given store path
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError>;
+
+    /// Stores the given channel_end at a path associated with the port_id and channel_id.
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given `nextSequenceSend` number at the given store path
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given `nextSequenceRecv` number at the given store path
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given `nextSequenceAck` number at the given store path
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError>;
+
+    /// Called upon channel identifier creation (Init or Try message processing).
+    /// Increases the counter which keeps track of how many channels have been created.
+    /// Should never fail.
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError>;
+
+    /// Emit the given IBC event
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
+
+    /// Log the given message.
+    fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+
+    /// Returns the queue in which the handlers record the timeouts of the
+    /// packets sent from this host, or `None` if the host does not track
+    /// them, which is the default.
+    ///
+    /// Hosts set it to time out expired packets from the chain itself, see
+    /// [`TimeoutQueue`](crate::timeout_queue::TimeoutQueue).
+    fn timeout_queue(&mut self) -> Option<&mut dyn TimeoutQueueContext> {
+        None
+    }
+
+    /// Returns the [`connection_upgrades`](ValidationContext::connection_upgrades)
+    /// of the host for the connection upgrade handlers to write to.
+    #[cfg(feature = "connection-upgrade")]
+    fn connection_upgrades_mut(&mut self) -> Option<&mut dyn ConnectionUpgradeContext> {
+        None
+    }
+}
@@ -0,0 +1,24 @@
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+// https://github.com/cosmos/ibc-rs/issues/342
+#![allow(clippy::result_large_err)]
+//! ICS 24: Contexts the hosts of IBC implement, and the host-side stores
+//! of the packet timeouts and receipts.
+
+extern crate alloc;
+
+pub mod context;
+pub mod receipt_bitmap;
+pub mod timeout_queue;
+#[cfg(feature = "connection-upgrade")]
+pub mod upgrade;
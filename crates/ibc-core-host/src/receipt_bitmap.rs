@@ -0,0 +1,403 @@
+//! Defines the optional recording of the packet receipts of unordered channels
+//! in bitmaps, each covering a fixed-size chunk of sequences, so that hosts
+//! with a high throughput write one key per [`RECEIPT_BITMAP_CHUNK_SIZE`]
+//! packets rather than one per packet.
+//!
+//! Counterparties prove that a packet was not received, to time it out,
+//! against its [`ReceiptPath`], which is never written once the receipts are
+//! recorded in bitmaps. Channels must therefore only opt in, through
+//! [`ReceiptBitmapContext::uses_receipt_bitmap`], if their counterparty reports
+//! them from [`ValidationContext::counterparty_uses_receipt_bitmap`], so that
+//! its timeouts carry a [`ReceiptBitmapProof`] instead: the absence of the
+//! [`ReceiptBitmapPath`] of the chunk of the packet (see
+//! [`unreceived_packet_path`]) while none of its packets were received, and the
+//! bitmap committed under it, with the bit of the packet clear, afterwards.
+//!
+//! Hosts delegate their `get_packet_receipt` and `store_packet_receipt` to
+//! [`get_packet_receipt`] and [`store_packet_receipt`], which keep the
+//! standard per-key behavior for all the other channels.
+
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::{Packet, Receipt, Sequence};
+use ibc_core_client_context::client_state::ClientStateCommon;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::path::{Path, ReceiptBitmapPath, ReceiptPath};
+use ibc_core_host_types::identifier::{ChannelId, PortId};
+
+use crate::context::ValidationContext;
+
+/// The number of sequences whose receipts a [`ReceiptBitmap`] records.
+pub const RECEIPT_BITMAP_CHUNK_SIZE: u64 = 256;
+
+const RECEIPT_BITMAP_LEN: usize = (RECEIPT_BITMAP_CHUNK_SIZE / 8) as usize;
+
+/// The receipts of a chunk of [`RECEIPT_BITMAP_CHUNK_SIZE`] sequences, the bit
+/// of each sequence being set once its packet is received.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReceiptBitmap([u8; RECEIPT_BITMAP_LEN]);
+
+impl ReceiptBitmap {
+    /// Returns the index of the chunk of `sequence`, that of its
+    /// [`ReceiptBitmapPath`].
+    pub fn chunk(sequence: Sequence) -> u64 {
+        u64::from(sequence) / RECEIPT_BITMAP_CHUNK_SIZE
+    }
+
+    /// Returns whether the receipt of `sequence` is recorded.
+    pub fn contains(&self, sequence: Sequence) -> bool {
+        let (byte, mask) = Self::bit(sequence);
+        self.0[byte] & mask != 0
+    }
+
+    /// Records the receipt of `sequence`, returning whether it was not yet.
+    pub fn insert(&mut self, sequence: Sequence) -> bool {
+        let (byte, mask) = Self::bit(sequence);
+        let inserted = self.0[byte] & mask == 0;
+        self.0[byte] |= mask;
+        inserted
+    }
+
+    /// Returns the bytes hosts store, and commit to, under the
+    /// [`ReceiptBitmapPath`] of the chunk.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Decodes the bytes returned by [`Self::as_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok().map(Self)
+    }
+
+    fn bit(sequence: Sequence) -> (usize, u8) {
+        let index = u64::from(sequence) % RECEIPT_BITMAP_CHUNK_SIZE;
+        ((index / 8) as usize, 1 << (index % 8))
+    }
+}
+
+/// Context to be implemented by the hosts recording the receipts of some of
+/// their channels in bitmaps.
+pub trait ReceiptBitmapContext {
+    /// Returns whether the receipts of the channel are recorded in bitmaps
+    /// rather than under their own [`ReceiptPath`], which they are by default.
+    fn uses_receipt_bitmap(&self, _port_id: &PortId, _channel_id: &ChannelId) -> bool {
+        false
+    }
+
+    /// Returns the bitmap stored at `bitmap_path`, if any.
+    fn receipt_bitmap(
+        &self,
+        bitmap_path: &ReceiptBitmapPath,
+    ) -> Result<Option<ReceiptBitmap>, ContextError>;
+
+    /// Stores the bitmap of a chunk, committing to [`ReceiptBitmap::as_bytes`]
+    /// under `bitmap_path`.
+    fn store_receipt_bitmap(
+        &mut self,
+        bitmap_path: &ReceiptBitmapPath,
+        bitmap: ReceiptBitmap,
+    ) -> Result<(), ContextError>;
+}
+
+/// Returns the receipt at `receipt_path` from the bitmap of its chunk if its
+/// channel uses one, or from `per_key` otherwise.
+pub fn get_packet_receipt<Ctx, F>(
+    ctx: &Ctx,
+    receipt_path: &ReceiptPath,
+    per_key: F,
+) -> Result<Receipt, ContextError>
+where
+    Ctx: ReceiptBitmapContext,
+    F: FnOnce(&Ctx, &ReceiptPath) -> Result<Receipt, ContextError>,
+{
+    if !ctx.uses_receipt_bitmap(&receipt_path.port_id, &receipt_path.channel_id) {
+        return per_key(ctx, receipt_path);
+    }
+
+    let sequence = receipt_path.sequence;
+    match ctx.receipt_bitmap(&bitmap_path(receipt_path))? {
+        Some(bitmap) if bitmap.contains(sequence) => Ok(Receipt::Ok),
+        _ => Err(PacketError::PacketReceiptNotFound { sequence }.into()),
+    }
+}
+
+/// Stores the receipt at `receipt_path` in the bitmap of its chunk if its
+/// channel uses one, or with `per_key` otherwise.
+pub fn store_packet_receipt<Ctx, F>(
+    ctx: &mut Ctx,
+    receipt_path: &ReceiptPath,
+    receipt: Receipt,
+    per_key: F,
+) -> Result<(), ContextError>
+where
+    Ctx: ReceiptBitmapContext,
+    F: FnOnce(&mut Ctx, &ReceiptPath, Receipt) -> Result<(), ContextError>,
+{
+    if !ctx.uses_receipt_bitmap(&receipt_path.port_id, &receipt_path.channel_id) {
+        return per_key(ctx, receipt_path, receipt);
+    }
+
+    let bitmap_path = bitmap_path(receipt_path);
+    let mut bitmap = ctx.receipt_bitmap(&bitmap_path)?.unwrap_or_default();
+    if bitmap.insert(receipt_path.sequence) {
+        ctx.store_receipt_bitmap(&bitmap_path, bitmap)?;
+    }
+
+    Ok(())
+}
+
+/// The proof that a packet was not received by a counterparty recording its
+/// receipts in bitmaps, carried by its timeouts in place of the proof of the
+/// absence of its [`ReceiptPath`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReceiptBitmapProof {
+    /// None of the packets of the chunk were received, as proven by the
+    /// absence of its [`ReceiptBitmapPath`].
+    Absent(CommitmentProofBytes),
+    /// Some packets of the chunk were received, as recorded in `bitmap`, whose
+    /// commitment under the [`ReceiptBitmapPath`] of the chunk is proven by
+    /// `proof`.
+    Present {
+        bitmap: ReceiptBitmap,
+        proof: CommitmentProofBytes,
+    },
+}
+
+impl ReceiptBitmapProof {
+    /// The first byte of the proof bytes of [`Self::Absent`].
+    pub const ABSENT_TAG: u8 = 0x01;
+    /// The first byte of the proof bytes of [`Self::Present`], followed by the
+    /// bitmap bytes.
+    pub const PRESENT_TAG: u8 = 0x02;
+
+    /// Decodes the proof bytes of a proof: its tag followed by, for
+    /// [`Self::Present`], the bitmap bytes, then by the commitment proof. As
+    /// protobuf reserves the field number 0, no encoded commitment proof
+    /// starts with either tag.
+    pub fn from_proof_bytes(bytes: &[u8]) -> Option<Self> {
+        let (tag, rest) = bytes.split_first()?;
+        match *tag {
+            Self::ABSENT_TAG => rest.to_vec().try_into().ok().map(Self::Absent),
+            Self::PRESENT_TAG if rest.len() > RECEIPT_BITMAP_LEN => {
+                let (bitmap, proof) = rest.split_at(RECEIPT_BITMAP_LEN);
+                Some(Self::Present {
+                    bitmap: ReceiptBitmap::from_bytes(bitmap)?,
+                    proof: proof.to_vec().try_into().ok()?,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<ReceiptBitmapProof> for CommitmentProofBytes {
+    fn from(proof: ReceiptBitmapProof) -> Self {
+        let bytes = match proof {
+            ReceiptBitmapProof::Absent(proof) => {
+                [&[ReceiptBitmapProof::ABSENT_TAG], proof.as_ref()].concat()
+            }
+            ReceiptBitmapProof::Present { bitmap, proof } => [
+                &[ReceiptBitmapProof::PRESENT_TAG],
+                bitmap.as_bytes(),
+                proof.as_ref(),
+            ]
+            .concat(),
+        };
+        bytes
+            .try_into()
+            .expect("the tag makes the proof bytes non-empty")
+    }
+}
+
+/// Returns the path on the counterparty against which the proof that `packet`
+/// was not received is verified, to time it out: the [`ReceiptBitmapPath`] of
+/// its chunk if the counterparty records its receipts in bitmaps, or its
+/// [`ReceiptPath`] otherwise.
+pub fn unreceived_packet_path<Ctx: ValidationContext>(ctx_a: &Ctx, packet: &Packet) -> Path {
+    let receipt_path_on_b =
+        ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+    if ctx_a.counterparty_uses_receipt_bitmap(&packet.port_id_on_a, &packet.chan_id_on_a) {
+        Path::ReceiptBitmap(bitmap_path(&receipt_path_on_b))
+    } else {
+        Path::Receipt(receipt_path_on_b)
+    }
+}
+
+/// Verifies `proof_unreceived_on_b`, the proof that `packet` was not received
+/// on an unordered channel: the absence of its [`ReceiptPath`], or the
+/// [`ReceiptBitmapProof`] of its chunk if the counterparty records its
+/// receipts in bitmaps.
+pub fn verify_packet_unreceived<Ctx: ValidationContext>(
+    ctx_a: &Ctx,
+    client_state_of_b_on_a: &impl ClientStateCommon,
+    prefix_on_b: &CommitmentPrefix,
+    root_of_b: &CommitmentRoot,
+    proof_unreceived_on_b: &CommitmentProofBytes,
+    packet: &Packet,
+) -> Result<(), PacketError> {
+    let sequence = packet.seq_on_a;
+    let path_on_b = unreceived_packet_path(ctx_a, packet);
+
+    let verification_result = match path_on_b {
+        Path::ReceiptBitmap(_) => {
+            match ReceiptBitmapProof::from_proof_bytes(proof_unreceived_on_b.as_ref()) {
+                Some(ReceiptBitmapProof::Absent(proof)) => client_state_of_b_on_a
+                    .verify_non_membership(prefix_on_b, &proof, root_of_b, path_on_b),
+                Some(ReceiptBitmapProof::Present { bitmap, proof }) => {
+                    if bitmap.contains(sequence) {
+                        return Err(PacketError::PacketReceived { sequence });
+                    }
+                    client_state_of_b_on_a.verify_membership(
+                        prefix_on_b,
+                        &proof,
+                        root_of_b,
+                        path_on_b,
+                        bitmap.as_bytes().to_vec(),
+                    )
+                }
+                None => return Err(PacketError::InvalidReceiptBitmapProof { sequence }),
+            }
+        }
+        _ => client_state_of_b_on_a.verify_non_membership(
+            prefix_on_b,
+            proof_unreceived_on_b,
+            root_of_b,
+            path_on_b,
+        ),
+    };
+
+    verification_result
+        .map_err(|e| ChannelError::PacketVerificationFailed {
+            sequence,
+            client_error: e,
+        })
+        .map_err(PacketError::Channel)
+}
+
+fn bitmap_path(receipt_path: &ReceiptPath) -> ReceiptBitmapPath {
+    ReceiptBitmapPath::new(
+        &receipt_path.port_id,
+        &receipt_path.channel_id,
+        ReceiptBitmap::chunk(receipt_path.sequence),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+
+    use ibc_primitives::prelude::*;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Store {
+        bitmap_channels: Vec<ChannelId>,
+        bitmaps: BTreeMap<ReceiptBitmapPath, ReceiptBitmap>,
+        receipts: BTreeMap<ReceiptPath, Receipt>,
+    }
+
+    impl ReceiptBitmapContext for Store {
+        fn uses_receipt_bitmap(&self, _port_id: &PortId, channel_id: &ChannelId) -> bool {
+            self.bitmap_channels.contains(channel_id)
+        }
+
+        fn receipt_bitmap(
+            &self,
+            bitmap_path: &ReceiptBitmapPath,
+        ) -> Result<Option<ReceiptBitmap>, ContextError> {
+            Ok(self.bitmaps.get(bitmap_path).copied())
+        }
+
+        fn store_receipt_bitmap(
+            &mut self,
+            bitmap_path: &ReceiptBitmapPath,
+            bitmap: ReceiptBitmap,
+        ) -> Result<(), ContextError> {
+            self.bitmaps.insert(bitmap_path.clone(), bitmap);
+            Ok(())
+        }
+    }
+
+    fn get(store: &Store, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        get_packet_receipt(store, receipt_path, |store, path| {
+            store.receipts.get(path).cloned().ok_or_else(|| {
+                PacketError::PacketReceiptNotFound {
+                    sequence: path.sequence,
+                }
+                .into()
+            })
+        })
+    }
+
+    fn store(store: &mut Store, receipt_path: &ReceiptPath) {
+        store_packet_receipt(store, receipt_path, Receipt::Ok, |store, path, receipt| {
+            store.receipts.insert(path.clone(), receipt);
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_receipt_bitmap() {
+        let mut bitmap = ReceiptBitmap::default();
+        assert!(bitmap.insert(Sequence::from(257)));
+        assert!(!bitmap.insert(Sequence::from(257)));
+        assert!(bitmap.contains(Sequence::from(1)));
+        assert!(!bitmap.contains(Sequence::from(2)));
+        assert_eq!(ReceiptBitmap::chunk(Sequence::from(257)), 1);
+        assert_eq!(ReceiptBitmap::from_bytes(bitmap.as_bytes()), Some(bitmap));
+        assert_eq!(ReceiptBitmap::from_bytes(&[0; 4]), None);
+    }
+
+    #[test]
+    fn test_bitmap_and_per_key_receipts() {
+        let mut ctx = Store {
+            bitmap_channels: vec![ChannelId::new(1)],
+            ..Default::default()
+        };
+        let per_key = ReceiptPath::new(&PortId::transfer(), &ChannelId::new(0), 3.into());
+        let in_bitmap = ReceiptPath::new(&PortId::transfer(), &ChannelId::new(1), 3.into());
+
+        for path in [&per_key, &in_bitmap] {
+            assert!(get(&ctx, path).is_err());
+            store(&mut ctx, path);
+            assert!(matches!(get(&ctx, path), Ok(Receipt::Ok)));
+        }
+        assert_eq!(ctx.receipts.len(), 1);
+        assert_eq!(ctx.bitmaps.len(), 1);
+
+        let other = ReceiptPath::new(&PortId::transfer(), &ChannelId::new(1), 4.into());
+        assert!(get(&ctx, &other).is_err());
+    }
+
+    #[test]
+    fn test_receipt_bitmap_proof_bytes() {
+        let proof = CommitmentProofBytes::try_from(vec![0x0a, 0x01]).unwrap();
+        let mut bitmap = ReceiptBitmap::default();
+        bitmap.insert(Sequence::from(1));
+
+        for unreceived_proof in [
+            ReceiptBitmapProof::Absent(proof.clone()),
+            ReceiptBitmapProof::Present { bitmap, proof },
+        ] {
+            let bytes = CommitmentProofBytes::from(unreceived_proof.clone());
+            assert_eq!(
+                ReceiptBitmapProof::from_proof_bytes(bytes.as_ref()),
+                Some(unreceived_proof)
+            );
+        }
+        assert_eq!(ReceiptBitmapProof::from_proof_bytes(&[0x0a, 0x01]), None);
+        assert_eq!(
+            ReceiptBitmapProof::from_proof_bytes(&[ReceiptBitmapProof::ABSENT_TAG]),
+            None
+        );
+        assert_eq!(
+            ReceiptBitmapProof::from_proof_bytes(&[ReceiptBitmapProof::PRESENT_TAG; 33]),
+            None
+        );
+    }
+}
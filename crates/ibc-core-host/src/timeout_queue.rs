@@ -0,0 +1,278 @@
+//! Tracking of the timeouts of the packets sent by the host.
+//!
+//! Hosts opting in through
+//! [`ExecutionContext::timeout_queue`](crate::context::ExecutionContext::timeout_queue)
+//! get each packet they send recorded along with its timeout, and removed
+//! once it is acknowledged or timed out. At each block, they can then peel off
+//! the packets that have expired on the counterparty, for instance to prove
+//! their timeouts from the chain itself rather than waiting for relayers to.
+
+use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::btree_set::BTreeSet;
+
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::packet::{Packet, Sequence};
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_client_types::height::Height;
+use ibc_core_handler_types::error::ContextError;
+#[cfg(feature = "inline-identifiers")]
+use ibc_core_host_types::identifier::inline::{InlineChannelId, InlinePortId};
+use ibc_core_host_types::identifier::{ChannelId, IdentifierError, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::timestamp::Timestamp;
+
+/// A packet sent by the host, along with when it times out on the
+/// counterparty.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingTimeout {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub seq_on_a: Sequence,
+    pub timeout_height_on_b: TimeoutHeight,
+    pub timeout_timestamp_on_b: Timestamp,
+}
+
+impl PendingTimeout {
+    /// Checks whether the packet has timed out on the counterparty, given its
+    /// latest height and timestamp, as [`Packet::timed_out`] does.
+    pub fn has_expired(&self, height_on_b: Height, timestamp_on_b: &Timestamp) -> bool {
+        self.timeout_height_on_b.has_expired(height_on_b)
+            || (self.timeout_timestamp_on_b.is_set()
+                && timestamp_on_b.nanoseconds() > self.timeout_timestamp_on_b.nanoseconds())
+    }
+
+    fn key(&self) -> Result<PacketKey, IdentifierError> {
+        PacketKey::new(&self.port_id_on_a, &self.chan_id_on_a, self.seq_on_a)
+    }
+}
+
+/// The key under which [`TimeoutQueue`] indexes a packet, whose identifiers
+/// are stored inline with the `inline-identifiers` feature so that packets are
+/// indexed and looked up without allocating.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct PacketKey {
+    #[cfg(feature = "inline-identifiers")]
+    port_id: InlinePortId,
+    #[cfg(feature = "inline-identifiers")]
+    chan_id: InlineChannelId,
+    #[cfg(not(feature = "inline-identifiers"))]
+    port_id: PortId,
+    #[cfg(not(feature = "inline-identifiers"))]
+    chan_id: ChannelId,
+    seq: Sequence,
+}
+
+impl PacketKey {
+    fn new(port_id: &PortId, chan_id: &ChannelId, seq: Sequence) -> Result<Self, IdentifierError> {
+        Ok(Self {
+            #[cfg(feature = "inline-identifiers")]
+            port_id: port_id.try_into()?,
+            #[cfg(feature = "inline-identifiers")]
+            chan_id: chan_id.try_into()?,
+            #[cfg(not(feature = "inline-identifiers"))]
+            port_id: port_id.clone(),
+            #[cfg(not(feature = "inline-identifiers"))]
+            chan_id: chan_id.clone(),
+            seq,
+        })
+    }
+}
+
+impl From<&Packet> for PendingTimeout {
+    fn from(packet: &Packet) -> Self {
+        Self {
+            port_id_on_a: packet.port_id_on_a.clone(),
+            chan_id_on_a: packet.chan_id_on_a.clone(),
+            seq_on_a: packet.seq_on_a,
+            timeout_height_on_b: packet.timeout_height_on_b,
+            timeout_timestamp_on_b: packet.timeout_timestamp_on_b,
+        }
+    }
+}
+
+/// Methods a host implements to keep track of the timeouts of the packets it
+/// sends, for which [`TimeoutQueue`] is a ready-made implementation.
+pub trait TimeoutQueueContext {
+    /// Records a packet, upon being sent.
+    fn enqueue_timeout(&mut self, timeout: PendingTimeout) -> Result<(), ContextError>;
+
+    /// Forgets a packet, upon being acknowledged or timed out. Packets that
+    /// were never recorded are ignored.
+    fn dequeue_timeout(
+        &mut self,
+        port_id_on_a: &PortId,
+        chan_id_on_a: &ChannelId,
+        seq_on_a: Sequence,
+    ) -> Result<(), ContextError>;
+}
+
+/// An in-memory [`TimeoutQueueContext`], indexing packets by timeout height
+/// and timestamp so that the expired ones are found without going through
+/// the others.
+#[derive(Clone, Debug, Default)]
+pub struct TimeoutQueue {
+    pending: BTreeMap<PacketKey, PendingTimeout>,
+    by_height: BTreeSet<(Height, PacketKey)>,
+    by_timestamp: BTreeSet<(u64, PacketKey)>,
+}
+
+impl TimeoutQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Returns the recorded packets, in order of port, channel and sequence.
+    pub fn iter(&self) -> impl Iterator<Item = &PendingTimeout> {
+        self.pending.values()
+    }
+
+    /// Removes and returns the packets that have timed out on a counterparty
+    /// whose latest height and timestamp are `height_on_b` and
+    /// `timestamp_on_b`, in order of port, channel and sequence.
+    pub fn pop_expired(
+        &mut self,
+        height_on_b: Height,
+        timestamp_on_b: &Timestamp,
+    ) -> Vec<PendingTimeout> {
+        let mut expired = BTreeSet::new();
+
+        expired.extend(
+            self.by_height
+                .iter()
+                .take_while(|(timeout_height, _)| *timeout_height < height_on_b)
+                .map(|(_, key)| key)
+                .cloned(),
+        );
+        if timestamp_on_b.is_set() {
+            let nanos_on_b = timestamp_on_b.nanoseconds();
+            expired.extend(
+                self.by_timestamp
+                    .iter()
+                    .take_while(|(timeout_nanos, _)| *timeout_nanos < nanos_on_b)
+                    .map(|(_, key)| key)
+                    .cloned(),
+            );
+        }
+
+        expired
+            .into_iter()
+            .filter_map(|key| self.remove_key(&key))
+            .collect()
+    }
+
+    /// Removes and returns the packet recorded under `seq_on_a` on the given
+    /// channel, if any.
+    pub fn remove(
+        &mut self,
+        port_id_on_a: &PortId,
+        chan_id_on_a: &ChannelId,
+        seq_on_a: Sequence,
+    ) -> Option<PendingTimeout> {
+        // Packets whose identifiers do not fit in a key were never recorded.
+        let key = PacketKey::new(port_id_on_a, chan_id_on_a, seq_on_a).ok()?;
+        self.remove_key(&key)
+    }
+
+    fn remove_key(&mut self, key: &PacketKey) -> Option<PendingTimeout> {
+        let timeout = self.pending.remove(key)?;
+
+        if let TimeoutHeight::At(height) = timeout.timeout_height_on_b {
+            self.by_height.remove(&(height, key.clone()));
+        }
+        if timeout.timeout_timestamp_on_b.is_set() {
+            let nanos = timeout.timeout_timestamp_on_b.nanoseconds();
+            self.by_timestamp.remove(&(nanos, key.clone()));
+        }
+
+        Some(timeout)
+    }
+}
+
+impl TimeoutQueueContext for TimeoutQueue {
+    fn enqueue_timeout(&mut self, timeout: PendingTimeout) -> Result<(), ContextError> {
+        let key = timeout.key().map_err(PacketError::from)?;
+        self.remove_key(&key);
+
+        if let TimeoutHeight::At(height) = timeout.timeout_height_on_b {
+            self.by_height.insert((height, key.clone()));
+        }
+        if timeout.timeout_timestamp_on_b.is_set() {
+            let nanos = timeout.timeout_timestamp_on_b.nanoseconds();
+            self.by_timestamp.insert((nanos, key.clone()));
+        }
+        self.pending.insert(key, timeout);
+
+        Ok(())
+    }
+
+    fn dequeue_timeout(
+        &mut self,
+        port_id_on_a: &PortId,
+        chan_id_on_a: &ChannelId,
+        seq_on_a: Sequence,
+    ) -> Result<(), ContextError> {
+        self.remove(port_id_on_a, chan_id_on_a, seq_on_a);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending(seq: u64, height: Option<u64>, nanos: Option<u64>) -> PendingTimeout {
+        PendingTimeout {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::default(),
+            seq_on_a: seq.into(),
+            timeout_height_on_b: height.map_or(TimeoutHeight::Never, |h| {
+                TimeoutHeight::At(Height::new(0, h).unwrap())
+            }),
+            timeout_timestamp_on_b: nanos.map_or(Timestamp::none(), |n| {
+                Timestamp::from_nanoseconds(n).unwrap()
+            }),
+        }
+    }
+
+    #[test]
+    fn expired_packets_are_popped_once() {
+        let mut queue = TimeoutQueue::new();
+        queue.enqueue_timeout(pending(1, Some(10), None)).unwrap();
+        queue
+            .enqueue_timeout(pending(2, None, Some(1_000)))
+            .unwrap();
+        queue
+            .enqueue_timeout(pending(3, Some(20), Some(5_000)))
+            .unwrap();
+        queue.enqueue_timeout(pending(4, None, None)).unwrap();
+        queue
+            .dequeue_timeout(&PortId::transfer(), &ChannelId::default(), 2.into())
+            .unwrap();
+
+        let height = |h| Height::new(0, h).unwrap();
+        let timestamp = |n| Timestamp::from_nanoseconds(n).unwrap();
+
+        // Packets time out strictly after their timeout height or timestamp.
+        assert!(queue.pop_expired(height(10), &timestamp(5_000)).is_empty());
+
+        let expired = queue.pop_expired(height(11), &timestamp(5_000));
+        assert_eq!(expired, vec![pending(1, Some(10), None)]);
+
+        let expired = queue.pop_expired(height(11), &timestamp(5_001));
+        assert_eq!(expired, vec![pending(3, Some(20), Some(5_000))]);
+        assert!(expired[0].has_expired(height(11), &timestamp(5_001)));
+
+        assert!(queue
+            .pop_expired(height(100), &Timestamp::none())
+            .is_empty());
+        assert_eq!(queue.len(), 1);
+    }
+}
@@ -0,0 +1,297 @@
+//! Defines the upgrade of open connections, through which both ends renegotiate
+//! their parameters, e.g. the delay period, without closing the channels built
+//! on top of them.
+//!
+//! The handshake mirrors the opening one: chain A proposes the upgrade with
+//! `ConnUpgradeInit`, chain B commits to it with `ConnUpgradeTry` after
+//! verifying the proposal of A, then A applies it with `ConnUpgradeAck` after
+//! verifying the commitment of B, and B applies it with `ConnUpgradeConfirm`
+//! after verifying the upgraded connection end of A. Meanwhile each end keeps
+//! its pending upgrade under a [`ConnectionUpgradePath`].
+//!
+//! An upgrade which is not applied by its timeout is dropped by either end
+//! with `ConnUpgradeTimeout`, after verifying that the connection end of its
+//! counterparty was left as is past the timeout. Until its counterparty
+//! commits to it, the proposer may also drop it with `ConnUpgradeCancel`.
+//!
+//! Its specification is still in progress, hence the `connection-upgrade`
+//! feature gating it.
+
+use core::time::Duration;
+
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client_types::consensus_state::ConsensusState;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::height::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_connection_types::connection::{ConnectionEnd, Counterparty, State};
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::path::{ClientConsensusStatePath, ConnectionUpgradePath, Path};
+use ibc_core_host_types::identifier::ConnectionId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+use ibc_primitives::timestamp::{Expiry, Timestamp};
+use prost::encoding;
+
+use crate::context::{ExecutionContext, ValidationContext};
+
+/// The parameters of a connection an upgrade in progress sets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectionUpgrade {
+    pub delay_period: Duration,
+    /// The timestamp after which the upgrade may no longer be applied.
+    pub timeout_timestamp: Timestamp,
+    /// Whether the host proposed the upgrade with `ConnUpgradeInit`, rather
+    /// than committed to the proposal of its counterparty with
+    /// `ConnUpgradeTry`. It is not part of the commitment.
+    pub proposed_by_host: bool,
+}
+
+impl ConnectionUpgrade {
+    /// Returns the upgrade proposed by the host.
+    pub fn proposed(delay_period: Duration, timeout_timestamp: Timestamp) -> Self {
+        Self {
+            delay_period,
+            timeout_timestamp,
+            proposed_by_host: true,
+        }
+    }
+
+    /// Returns the upgrade proposed by the counterparty, to which the host
+    /// committed.
+    pub fn accepted(delay_period: Duration, timeout_timestamp: Timestamp) -> Self {
+        Self {
+            delay_period,
+            timeout_timestamp,
+            proposed_by_host: false,
+        }
+    }
+
+    /// Returns whether the upgrade may no longer be applied at `timestamp`,
+    /// like [`Packet::timed_out`](ibc_core_channel_types::packet::Packet::timed_out).
+    pub fn timed_out(&self, timestamp: &Timestamp) -> bool {
+        self.timeout_timestamp.is_set()
+            && timestamp.check_expiry(&self.timeout_timestamp) == Expiry::Expired
+    }
+
+    /// Encodes the upgrade as committed to under its [`ConnectionUpgradePath`],
+    /// against which counterparties verify their proofs:
+    ///
+    /// ```proto
+    /// message ConnectionUpgrade {
+    ///   uint64 delay_period = 1;
+    ///   uint64 timeout_timestamp = 2;
+    /// }
+    /// ```
+    ///
+    /// where the delay period and the timeout are in nanoseconds, as in
+    /// `ConnectionEnd` and `Packet` respectively.
+    pub fn encode_vec(&self) -> Vec<u8> {
+        let delay_period = self.delay_period.as_nanos() as u64;
+        let timeout_timestamp = self.timeout_timestamp.nanoseconds();
+        let mut buf = Vec::new();
+        if delay_period != 0 {
+            encoding::uint64::encode(1, &delay_period, &mut buf);
+        }
+        if timeout_timestamp != 0 {
+            encoding::uint64::encode(2, &timeout_timestamp, &mut buf);
+        }
+        buf
+    }
+}
+
+/// Context to be implemented by the hosts supporting the upgrade of their
+/// connections, through which the `conn_upgrade_*` handlers keep track of the
+/// upgrades in progress. Hosts return it from
+/// [`ValidationContext::connection_upgrades`] and
+/// [`ExecutionContext::connection_upgrades_mut`].
+pub trait ConnectionUpgradeContext {
+    /// Returns the upgrade in progress of `conn_id`, if any.
+    fn connection_upgrade(
+        &self,
+        conn_id: &ConnectionId,
+    ) -> Result<Option<ConnectionUpgrade>, ContextError>;
+
+    /// Stores the upgrade in progress of a connection, committing to
+    /// [`ConnectionUpgrade::encode_vec`] under `upgrade_path`.
+    fn store_connection_upgrade(
+        &mut self,
+        upgrade_path: &ConnectionUpgradePath,
+        upgrade: ConnectionUpgrade,
+    ) -> Result<(), ContextError>;
+
+    /// Deletes the upgrade of a connection once applied.
+    fn delete_connection_upgrade(
+        &mut self,
+        upgrade_path: &ConnectionUpgradePath,
+    ) -> Result<(), ContextError>;
+
+    /// Checks that `signer` is allowed to propose the upgrade of connections,
+    /// e.g. that it is the governance authority of the host.
+    fn validate_upgrade_authority(&self, signer: &Signer) -> Result<(), ContextError>;
+}
+
+/// Returns the [`ValidationContext::connection_upgrades`] of the host,
+/// failing if it does not support the upgrade of its connections.
+pub fn upgrade_context<Ctx>(ctx: &Ctx) -> Result<&dyn ConnectionUpgradeContext, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.connection_upgrades()
+        .ok_or_else(|| ConnectionError::UpgradesNotSupported.into())
+}
+
+/// Returns the [`ExecutionContext::connection_upgrades_mut`] of the host,
+/// failing if it does not support the upgrade of its connections.
+pub fn upgrade_context_mut<Ctx>(
+    ctx: &mut Ctx,
+) -> Result<&mut dyn ConnectionUpgradeContext, ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx.connection_upgrades_mut()
+        .ok_or_else(|| ConnectionError::UpgradesNotSupported.into())
+}
+
+/// Returns the upgrade in progress of `conn_id`, failing if there is none.
+pub fn pending_upgrade<Ctx>(
+    ctx: &Ctx,
+    conn_id: &ConnectionId,
+) -> Result<ConnectionUpgrade, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    upgrade_context(ctx)?
+        .connection_upgrade(conn_id)?
+        .ok_or_else(|| {
+            ConnectionError::MissingUpgrade {
+                connection_id: conn_id.clone(),
+            }
+            .into()
+        })
+}
+
+/// Checks that `upgrade` may still be applied to `conn_id` as of the
+/// timestamp of the host.
+pub fn verify_not_timed_out<Ctx>(
+    ctx: &Ctx,
+    conn_id: &ConnectionId,
+    upgrade: &ConnectionUpgrade,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let host_timestamp = ctx.host_timestamp()?;
+    if upgrade.timed_out(&host_timestamp) {
+        return Err(ConnectionError::UpgradeTimedOut {
+            connection_id: conn_id.clone(),
+            timeout_timestamp: upgrade.timeout_timestamp,
+            host_timestamp,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Returns the connection end the counterparty of `conn_end`, the end of
+/// `conn_id` on the host, has when its delay period is `delay_period`.
+pub fn counterparty_conn_end<Ctx>(
+    ctx: &Ctx,
+    conn_id: &ConnectionId,
+    conn_end: &ConnectionEnd,
+    delay_period: Duration,
+) -> Result<ConnectionEnd, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    Ok(ConnectionEnd::new(
+        State::Open,
+        conn_end.counterparty().client_id().clone(),
+        Counterparty::new(
+            conn_end.client_id().clone(),
+            Some(conn_id.clone()),
+            ctx.commitment_prefix(),
+        ),
+        conn_end.versions().to_vec(),
+        delay_period,
+    )?)
+}
+
+/// Verifies the proof that the counterparty of `conn_end` stores `value` at
+/// `path` as of `proof_height`, mapping verification failures with `map_err`.
+pub fn verify_counterparty_state<Ctx>(
+    ctx: &Ctx,
+    conn_end: &ConnectionEnd,
+    proof: &CommitmentProofBytes,
+    proof_height: Height,
+    path: Path,
+    value: Vec<u8>,
+    map_err: fn(ClientError) -> ConnectionError,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let client_id = conn_end.client_id();
+    let client_state = ctx.client_state(client_id)?;
+
+    {
+        let status = client_state.status(ctx.get_client_validation_context(), client_id)?;
+        if !status.is_active() {
+            return Err(ClientError::ClientNotActive { status }.into());
+        }
+    }
+    client_state.validate_proof_height(proof_height)?;
+
+    let consensus_state =
+        ctx.consensus_state(&ClientConsensusStatePath::new(client_id, &proof_height))?;
+
+    client_state
+        .verify_membership(
+            conn_end.counterparty().prefix(),
+            &*ctx.resolve_proof(proof)?,
+            consensus_state.root(),
+            path,
+            value,
+        )
+        .map_err(map_err)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_upgrade_encoding() {
+        assert!(
+            ConnectionUpgrade::proposed(Duration::ZERO, Timestamp::none())
+                .encode_vec()
+                .is_empty()
+        );
+        assert_eq!(
+            ConnectionUpgrade::proposed(Duration::from_nanos(300), Timestamp::none()).encode_vec(),
+            vec![0x08, 0xac, 0x02]
+        );
+        assert_eq!(
+            ConnectionUpgrade::accepted(
+                Duration::from_nanos(300),
+                Timestamp::from_nanoseconds(1).unwrap()
+            )
+            .encode_vec(),
+            vec![0x08, 0xac, 0x02, 0x10, 0x01]
+        );
+    }
+
+    #[test]
+    fn test_connection_upgrade_timeout() {
+        let upgrade =
+            ConnectionUpgrade::proposed(Duration::ZERO, Timestamp::from_nanoseconds(10).unwrap());
+
+        assert!(!upgrade.timed_out(&Timestamp::from_nanoseconds(9).unwrap()));
+        assert!(!upgrade.timed_out(&Timestamp::from_nanoseconds(10).unwrap()));
+        assert!(upgrade.timed_out(&Timestamp::from_nanoseconds(11).unwrap()));
+    }
+}
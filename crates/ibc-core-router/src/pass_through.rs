@@ -0,0 +1,177 @@
+//! A module that performs no application logic, meant for hosts that only
+//! relay packets on behalf of others (e.g. multihop or forwarding
+//! intermediaries).
+//!
+//! Core handlers already take care of storing packet receipts and
+//! acknowledgements as required by ICS-04, so this module only has to accept
+//! every channel handshake and answer every received packet with a successful
+//! acknowledgement. Hosts typically bind it to the ports that have no
+//! dedicated application, by returning [`MODULE_ID_STR`] from
+//! [`Router::lookup_module`](crate::router::Router::lookup_module) for
+//! those ports.
+
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{Counterparty, Order};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::version::Version;
+use ibc_core_host_types::identifier::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
+
+use crate::router::{Module, ModuleExtras};
+
+/// Module identifier for the pass-through module.
+pub const MODULE_ID_STR: &str = "pass-through";
+
+/// The successful acknowledgement written for every received packet, in the
+/// standard `{"result": <base64>}` envelope, equivalent to the one produced by
+/// ibc-go's `NewResultAcknowledgement([]byte{1})`.
+pub const ACK_SUCCESS_JSON: &[u8] = br#"{"result":"AQ=="}"#;
+
+/// Returns the successful acknowledgement written by the pass-through module.
+pub fn ack_success() -> Acknowledgement {
+    Acknowledgement::try_from(ACK_SUCCESS_JSON.to_vec())
+        .expect("the success acknowledgement is never empty")
+}
+
+/// An IBC [`Module`] that accepts any channel and forwards packets to the
+/// core handlers without interpreting their data.
+#[derive(Clone, Debug, Default)]
+pub struct PassThroughModule;
+
+impl PassThroughModule {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Module for PassThroughModule {
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), version.clone()))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), counterparty_version.clone()))
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        let extras = ModuleExtras {
+            events: Vec::new(),
+            log: vec![format!(
+                "pass-through: received packet {} on {}/{}",
+                packet.seq_on_a, packet.port_id_on_b, packet.chan_id_on_b
+            )],
+        };
+
+        (extras, ack_success())
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_channel_types::packet::Sequence;
+    use ibc_core_channel_types::timeout::TimeoutHeight;
+    use ibc_primitives::timestamp::Timestamp;
+
+    use super::*;
+
+    #[test]
+    fn recv_packet_returns_success_ack() {
+        let packet = Packet {
+            seq_on_a: Sequence::from(1),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::default(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::default(),
+            data: b"opaque".to_vec(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+        let relayer = Signer::from("relayer".to_string());
+
+        let mut module = PassThroughModule::new();
+        let (extras, ack) = module.on_recv_packet_execute(&packet, &relayer);
+
+        assert_eq!(ack.as_bytes(), ACK_SUCCESS_JSON);
+        assert_eq!(extras.log.len(), 1);
+    }
+}
@@ -1,17 +1,19 @@
-//! Defines the `Router`, which binds modules to ports
+//! Defines the `Router`, which binds modules to ports, connections and clients
 
-use alloc::borrow::Borrow;
-use core::fmt::{Debug, Display, Error as FmtError, Formatter};
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::fmt::Debug;
 
-use crate::core::events::ModuleEvent;
-use crate::core::ics04_channel::acknowledgement::Acknowledgement;
-use crate::core::ics04_channel::channel::{Counterparty, Order};
-use crate::core::ics04_channel::error::{ChannelError, PacketError};
-use crate::core::ics04_channel::packet::Packet;
-use crate::core::ics04_channel::Version;
-use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
-use crate::prelude::*;
-use crate::signer::Signer;
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{Counterparty, Order};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::version::Version;
+use ibc_core_handler_types::error::RouterError;
+use ibc_core_handler_types::events::ModuleEvent;
+pub use ibc_core_handler_types::module::{Binding, ModuleId};
+use ibc_core_host_types::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::signer::Signer;
 
 /// Router as defined in ICS-26, which binds modules to ports.
 pub trait Router {
@@ -23,44 +25,104 @@ pub trait Router {
 
     /// Return the module_id associated with a given port_id
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId>;
+
+    /// Returns the module bound to the given connection, e.g. a
+    /// connection-level middleware. Defaults to none.
+    fn lookup_module_by_connection(&self, _connection_id: &ConnectionId) -> Option<ModuleId> {
+        None
+    }
+
+    /// Returns the module bound to the given client. Defaults to none.
+    fn lookup_module_by_client(&self, _client_id: &ClientId) -> Option<ModuleId> {
+        None
+    }
+
+    /// Returns the identifiers of the modules registered on this router, so
+    /// that hosts can introspect them at runtime. Defaults to none, for
+    /// routers unable to enumerate their modules.
+    fn module_ids(&self) -> Vec<ModuleId> {
+        Vec::new()
+    }
 }
 
-/// Module name, internal to the chain.
+/// A registry of the [`Binding`]s of modules, on which routers may build
+/// their lookups.
 ///
-/// That is, the IBC protocol never exposes this name. Note that this is
-/// different from IBC host [identifiers][crate::core::ics24_host::identifier],
-/// which are exposed to other chains by the protocol.
-#[cfg_attr(
-    feature = "parity-scale-codec",
-    derive(
-        parity_scale_codec::Encode,
-        parity_scale_codec::Decode,
-        scale_info::TypeInfo
-    )
-)]
-#[cfg_attr(
-    feature = "borsh",
-    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
-)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct ModuleId(String);
+/// Each port, connection or client is bound to at most one module: binding
+/// it to another module fails, instead of silently rerouting its messages.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleBindings {
+    bindings: BTreeMap<Binding, ModuleId>,
+}
 
-impl ModuleId {
-    pub fn new(s: String) -> Self {
-        Self(s)
+impl ModuleBindings {
+    pub fn new() -> Self {
+        Self::default()
     }
-}
 
-impl Display for ModuleId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
-        write!(f, "{}", self.0)
+    /// Binds `binding` to the module `module_id`, which is a no-op if it is
+    /// already bound to that module.
+    pub fn bind(
+        &mut self,
+        binding: impl Into<Binding>,
+        module_id: ModuleId,
+    ) -> Result<(), RouterError> {
+        let binding = binding.into();
+
+        match self.bindings.get(&binding) {
+            Some(bound_module_id) if *bound_module_id != module_id => {
+                Err(RouterError::BindingConflict {
+                    binding,
+                    bound_module_id: bound_module_id.clone(),
+                    module_id,
+                })
+            }
+            Some(_) => Ok(()),
+            None => {
+                self.bindings.insert(binding, module_id);
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes `binding`, returning the module it was bound to.
+    pub fn unbind(&mut self, binding: &Binding) -> Option<ModuleId> {
+        self.bindings.remove(binding)
+    }
+
+    /// Returns the module `binding` is bound to.
+    pub fn lookup(&self, binding: &Binding) -> Option<&ModuleId> {
+        self.bindings.get(binding)
+    }
+
+    pub fn lookup_port(&self, port_id: &PortId) -> Option<&ModuleId> {
+        self.lookup(&Binding::Port(port_id.clone()))
+    }
+
+    pub fn lookup_connection(&self, connection_id: &ConnectionId) -> Option<&ModuleId> {
+        self.lookup(&Binding::Connection(connection_id.clone()))
+    }
+
+    pub fn lookup_client(&self, client_id: &ClientId) -> Option<&ModuleId> {
+        self.lookup(&Binding::Client(client_id.clone()))
+    }
+
+    /// Returns all the bindings, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Binding, &ModuleId)> {
+        self.bindings.iter()
+    }
+
+    /// Returns the bindings of the module `module_id`, in order.
+    pub fn bindings_of<'a>(&'a self, module_id: &'a ModuleId) -> impl Iterator<Item = &'a Binding> {
+        self.bindings
+            .iter()
+            .filter(move |(_, bound_module_id)| *bound_module_id == module_id)
+            .map(|(binding, _)| binding)
     }
-}
 
-impl Borrow<str> for ModuleId {
-    fn borrow(&self) -> &str {
-        self.0.as_str()
+    /// Returns the identifiers of the modules with at least one binding.
+    pub fn module_ids(&self) -> BTreeSet<ModuleId> {
+        self.bindings.values().cloned().collect()
     }
 }
 
@@ -169,6 +231,8 @@ pub trait Module: Debug {
         Ok(ModuleExtras::empty())
     }
 
+    /// Modules refusing to close the channel return
+    /// [`ChannelError::CloseVetoed`] with the reason why.
     fn on_chan_close_init_validate(
         &self,
         _port_id: &PortId,
@@ -177,6 +241,10 @@ pub trait Module: Debug {
         Ok(())
     }
 
+    /// The packets still in flight on the channel are left as they are, as
+    /// the counterparty may have received them: modules refund them once they
+    /// are timed out through `MsgTimeoutOnClose`, in
+    /// [`Self::on_timeout_packet_execute`].
     fn on_chan_close_init_execute(
         &mut self,
         _port_id: &PortId,
@@ -185,6 +253,8 @@ pub trait Module: Debug {
         Ok(ModuleExtras::empty())
     }
 
+    /// As [`Self::on_chan_close_init_validate`], for a channel closed by the
+    /// counterparty.
     fn on_chan_close_confirm_validate(
         &self,
         _port_id: &PortId,
@@ -193,6 +263,8 @@ pub trait Module: Debug {
         Ok(())
     }
 
+    /// As [`Self::on_chan_close_init_execute`], for a channel closed by the
+    /// counterparty.
     fn on_chan_close_confirm_execute(
         &mut self,
         _port_id: &PortId,
@@ -0,0 +1,104 @@
+//! A minimal CosmWasm contract hosting IBC on top of its own storage, with the
+//! Tendermint light client and no application module.
+//!
+//! Its main purpose is to be built for `wasm32-unknown-unknown`, guaranteeing
+//! that `ibc` compiles for that target without its `std` feature. The crate is
+//! `no_std` itself, so that any `std` dependency it picks up comes from `ibc`.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use cosmwasm_std::{Binary, DepsMut, Env, MessageInfo, Response};
+use displaydoc::Display;
+use ibc::clients::ics07_tendermint::client_state::ClientState as TmClientState;
+use ibc::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc::core::events::Error as EventError;
+use ibc::core::ics24_host::identifier::PortId;
+use ibc::core::router::{Module, ModuleId, Router};
+use ibc::core::{dispatch, MsgEnvelope, RouterError};
+use ibc::hosts::cosmwasm::CwContext;
+use ibc::proto::Any;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+/// The IBC context of the contract, which only supports Tendermint clients.
+pub type Context<'a> = CwContext<'a, TmClientState, TmConsensusState>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Delivers the IBC messages, each a protobuf-encoded `Any`, in order.
+    Deliver { messages: Vec<Binary> },
+}
+
+#[derive(Debug, Display)]
+pub enum ContractError {
+    /// failed to decode the IBC message: `{0}`
+    Decode(prost::DecodeError),
+    /// IBC router error: `{0}`
+    Router(RouterError),
+    /// failed to convert the IBC events: `{0}`
+    Event(EventError),
+}
+
+/// The router of the contract, to which no module is bound: the contract
+/// handles the client, connection and channel messages, but rejects packets.
+pub struct NoModules;
+
+impl Router for NoModules {
+    fn get_route(&self, _module_id: &ModuleId) -> Option<&dyn Module> {
+        None
+    }
+
+    fn get_route_mut(&mut self, _module_id: &ModuleId) -> Option<&mut dyn Module> {
+        None
+    }
+
+    fn lookup_module(&self, _port_id: &PortId) -> Option<ModuleId> {
+        None
+    }
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn instantiate(
+    _deps: DepsMut<'_>,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    Ok(Response::new().add_attribute("action", "instantiate"))
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn execute(
+    deps: DepsMut<'_>,
+    env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Deliver { messages } => deliver(deps, env, messages),
+    }
+}
+
+fn deliver(deps: DepsMut<'_>, env: Env, messages: Vec<Binary>) -> Result<Response, ContractError> {
+    let mut ctx = Context::new(deps, env);
+    let mut router = NoModules;
+
+    for message in messages {
+        let any = Any::decode(message.as_slice()).map_err(ContractError::Decode)?;
+        let envelope = MsgEnvelope::try_from(any).map_err(ContractError::Router)?;
+        dispatch(&mut ctx, &mut router, envelope).map_err(ContractError::Router)?;
+    }
+
+    let events = ctx.into_cw_events().map_err(ContractError::Event)?;
+    Ok(Response::new()
+        .add_attribute("action", "deliver")
+        .add_events(events))
+}
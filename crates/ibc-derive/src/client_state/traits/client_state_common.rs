@@ -45,6 +45,21 @@ pub(crate) fn impl_ClientStateCommon(
         enum_variants.iter(),
         quote! {verify_non_membership(cs, prefix, proof, root, path)},
     );
+    let evidence_heights_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {evidence_heights(cs, client_message)},
+    );
+    let update_kind_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {update_kind(cs, client_message)},
+    );
+    let counterparty_chain_id_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {counterparty_chain_id(cs)},
+    );
 
     let HostClientState = client_state_enum_name;
 
@@ -54,9 +69,11 @@ pub(crate) fn impl_ClientStateCommon(
     let CommitmentProofBytes = Imports::CommitmentProofBytes();
     let ClientStateCommon = Imports::ClientStateCommon();
     let ClientType = Imports::ClientType();
+    let ChainId = Imports::ChainId();
     let ClientError = Imports::ClientError();
     let Height = Imports::Height();
     let Path = Imports::Path();
+    let UpdateKind = Imports::UpdateKind();
 
     quote! {
         impl #ClientStateCommon for #HostClientState {
@@ -120,6 +137,24 @@ pub(crate) fn impl_ClientStateCommon(
                     #(#verify_non_membership_impl),*
                 }
             }
+
+            fn evidence_heights(&self, client_message: &#Any) -> Vec<#Height> {
+                match self {
+                    #(#evidence_heights_impl),*
+                }
+            }
+
+            fn update_kind(&self, client_message: &#Any) -> #UpdateKind {
+                match self {
+                    #(#update_kind_impl),*
+                }
+            }
+
+            fn counterparty_chain_id(&self) -> Option<#ChainId> {
+                match self {
+                    #(#counterparty_chain_id_impl),*
+                }
+            }
         }
 
     }
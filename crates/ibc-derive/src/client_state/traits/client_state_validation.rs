@@ -33,6 +33,13 @@ pub(crate) fn impl_ClientStateValidation(
         quote! { status(cs, ctx, client_id) },
     );
 
+    let remaining_trusting_period_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { remaining_trusting_period(cs, ctx, client_id) },
+    );
+
     let HostClientState = client_state_enum_name;
     let ClientValidationContext = &opts.client_validation_context;
 
@@ -79,6 +86,16 @@ pub(crate) fn impl_ClientStateValidation(
                 }
 
             }
+
+            fn remaining_trusting_period(
+                &self,
+                ctx: &#ClientValidationContext,
+                client_id: &#ClientId,
+            ) -> core::result::Result<core::option::Option<core::time::Duration>, #ClientError> {
+                match self {
+                    #(#remaining_trusting_period_impl),*
+                }
+            }
         }
 
     }
@@ -45,6 +45,10 @@ impl Imports {
         quote! {ibc::core::ics24_host::identifier::ClientId}
     }
 
+    pub fn ChainId() -> TokenStream {
+        quote! {ibc::core::ics24_host::identifier::ChainId}
+    }
+
     pub fn ClientType() -> TokenStream {
         quote! {ibc::core::ics02_client::client_type::ClientType}
     }
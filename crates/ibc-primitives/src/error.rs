@@ -0,0 +1,90 @@
+//! Defines the [`IbcError`] trait, through which the errors of the IBC crates
+//! expose their causes, with or without `std`.
+//!
+//! Under the `std` feature, the errors also implement `std::error::Error`,
+//! whose `source` method reaches the same causes. As `core::error::Error` is
+//! not stable on the Rust versions this crate supports, `no_std` hosts walk
+//! the causes through [`IbcError::source`] instead, e.g. with [`sources`].
+
+use core::fmt::{Debug, Display};
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std::error::Error as StdError;
+
+/// An error of the IBC crates, which may wrap a lower-level error as its
+/// source.
+///
+/// Errors of dependencies, such as decoding errors, are not sources: they are
+/// only reported through the `Display` of the errors wrapping them.
+pub trait IbcError: Debug + Display {
+    /// Returns the lower-level error this error wraps, if any.
+    fn source(&self) -> Option<&dyn IbcError> {
+        None
+    }
+}
+
+/// Implements [`IbcError`] for an error type, along with `std::error::Error`
+/// under the `std` feature, from a single table of the variants wrapping a
+/// source, so that both reach the same causes.
+///
+/// The variants under `ibc` wrap an error of the IBC crates, which both traits
+/// report as their source. Those under `std` wrap an error of a dependency,
+/// which only `std::error::Error` reports.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_error_sources {
+    ($error:ty {
+        ibc { $($ibc_variant:pat => $ibc_source:ident),* $(,)? }
+        $(std { $($std_variant:pat => $std_source:ident),* $(,)? })?
+    }) => {
+        impl $crate::error::IbcError for $error {
+            // The table may list every variant, or none.
+            #[allow(unreachable_patterns, clippy::match_single_binding)]
+            fn source(&self) -> Option<&dyn $crate::error::IbcError> {
+                match self {
+                    $($ibc_variant => Some($ibc_source),)*
+                    _ => None,
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl $crate::error::StdError for $error {
+            #[allow(unreachable_patterns, clippy::match_single_binding)]
+            fn source(&self) -> Option<&(dyn $crate::error::StdError + 'static)> {
+                match self {
+                    $($ibc_variant => Some($ibc_source),)*
+                    $($($std_variant => Some($std_source),)*)?
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+/// Returns an iterator over `error` and its sources, from `error` itself down
+/// to its root cause.
+pub fn sources(error: &dyn IbcError) -> Sources<'_> {
+    Sources { next: Some(error) }
+}
+
+/// Returns the deepest source of `error`, or `error` itself if it wraps none.
+pub fn root_cause(error: &dyn IbcError) -> &dyn IbcError {
+    sources(error).last().unwrap_or(error)
+}
+
+/// An iterator over an error and its sources, as returned by [`sources`].
+#[derive(Clone, Debug)]
+pub struct Sources<'a> {
+    next: Option<&'a dyn IbcError>,
+}
+
+impl<'a> Iterator for Sources<'a> {
+    type Item = &'a dyn IbcError;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = current.source();
+        Some(current)
+    }
+}
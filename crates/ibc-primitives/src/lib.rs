@@ -0,0 +1,29 @@
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+// https://github.com/cosmos/ibc-rs/issues/342
+#![allow(clippy::result_large_err)]
+//! Primitives shared by the IBC crates: the prelude, timestamps, signers, the
+//! message traits and the error helpers.
+
+extern crate alloc;
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod error;
+pub mod msgs;
+pub mod prelude;
+#[cfg(feature = "serde")]
+pub mod serializers;
+pub mod signer;
+pub mod timestamp;
+pub mod utils;
@@ -0,0 +1,46 @@
+//! Traits implemented by all the IBC messages
+
+use ibc_proto::google::protobuf::Any;
+
+use crate::prelude::*;
+
+/// Trait to be implemented by all IBC messages
+pub trait Msg: Clone {
+    type Raw: From<Self> + prost::Message;
+
+    /// Unique type identifier for this message, to support encoding to/from `prost_types::Any`.
+    fn type_url(&self) -> String;
+
+    fn get_sign_bytes(self) -> Vec<u8> {
+        let raw_msg: Self::Raw = self.into();
+        prost::Message::encode_to_vec(&raw_msg)
+    }
+
+    fn to_any(self) -> Any {
+        Any {
+            type_url: self.type_url(),
+            value: self.get_sign_bytes(),
+        }
+    }
+}
+
+/// Stateless checks of a message, e.g. of the validity of its identifiers,
+/// proofs and heights, which need no access to the host so that mempools can
+/// run them before dispatching it.
+///
+/// Messages decoded from protobuf already passed these checks, unlike those
+/// built field by field or decoded through serde, borsh or SCALE.
+pub trait ValidateBasic {
+    type Error;
+
+    fn validate_basic(&self) -> Result<(), Self::Error>;
+}
+
+/// Checks that `msg` satisfies the invariants enforced on decoding, by
+/// decoding its raw form.
+pub fn validate_raw<M>(msg: &M) -> Result<(), <M as TryFrom<M::Raw>>::Error>
+where
+    M: Msg + TryFrom<<M as Msg>::Raw>,
+{
+    <M as TryFrom<M::Raw>>::try_from(msg.clone().into()).map(|_| ())
+}
@@ -0,0 +1,476 @@
+use alloc::collections::btree_map::BTreeMap;
+use core::cell::RefCell;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+use derive_more::Display;
+use subtle_encoding::{bech32, hex};
+
+use crate::error::IbcError;
+use crate::prelude::*;
+
+/// Represents the address of the signer of the current transaction
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+pub struct Signer(String);
+
+impl From<String> for Signer {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+
+impl AsRef<str> for Signer {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+/// Errors raised by a [`SignerValidator`] when rejecting a signer
+#[derive(Debug, displaydoc::Display)]
+pub enum SignerError {
+    /// signer is empty
+    EmptySigner,
+    /// signer `{signer}` is not a valid bech32 address: `{reason}`
+    InvalidBech32 { signer: Signer, reason: String },
+    /// signer `{signer}` has prefix `{actual}`, expected `{expected}`
+    UnexpectedPrefix {
+        signer: Signer,
+        expected: String,
+        actual: String,
+    },
+    /// signer `{signer}` is not a valid hex address
+    InvalidHex { signer: Signer },
+    /// signer `{signer}` is `{length}` bytes long, expected `{expected}` bytes
+    InvalidLength {
+        signer: Signer,
+        length: usize,
+        expected: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SignerError {}
+
+impl IbcError for SignerError {}
+
+/// Checks that the signers of messages are well-formed addresses of the host.
+///
+/// [`Signer`]s are opaque strings, which the handlers only hand over to
+/// `ValidationContext::validate_message_signer`
+/// and to the applications. Hosts requiring a canonical account format check
+/// the signers against a validator there, so that the messages with malformed
+/// signers are rejected before they are handled.
+pub trait SignerValidator {
+    /// Returns an error if `signer` is not a well-formed address.
+    fn validate_signer(&self, signer: &Signer) -> Result<(), SignerError>;
+}
+
+/// The default [`SignerValidator`], which accepts any signer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OpaqueSignerValidator;
+
+impl SignerValidator for OpaqueSignerValidator {
+    fn validate_signer(&self, _signer: &Signer) -> Result<(), SignerError> {
+        Ok(())
+    }
+}
+
+/// Decodes the bytes of any address, as is.
+impl AddressCodec for OpaqueSignerValidator {
+    fn decode_address(&self, address: &Signer) -> Result<Vec<u8>, SignerError> {
+        Ok(address.as_ref().as_bytes().to_vec())
+    }
+}
+
+/// A [`SignerValidator`] accepting the bech32 addresses with a given
+/// human-readable prefix, e.g. `cosmos` for `cosmos1...` addresses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bech32SignerValidator {
+    prefix: String,
+}
+
+impl Bech32SignerValidator {
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+}
+
+impl SignerValidator for Bech32SignerValidator {
+    fn validate_signer(&self, signer: &Signer) -> Result<(), SignerError> {
+        self.decode_address(signer).map(|_| ())
+    }
+}
+
+/// Decodes the data of the bech32 addresses with the prefix of the validator.
+impl AddressCodec for Bech32SignerValidator {
+    fn decode_address(&self, address: &Signer) -> Result<Vec<u8>, SignerError> {
+        if address.as_ref().is_empty() {
+            return Err(SignerError::EmptySigner);
+        }
+
+        let (prefix, data) =
+            bech32::decode(address.as_ref()).map_err(|e| SignerError::InvalidBech32 {
+                signer: address.clone(),
+                reason: e.to_string(),
+            })?;
+
+        if prefix != self.prefix {
+            return Err(SignerError::UnexpectedPrefix {
+                signer: address.clone(),
+                expected: self.prefix.clone(),
+                actual: prefix,
+            });
+        }
+
+        Ok(data)
+    }
+}
+
+/// A [`SignerValidator`] accepting the hex-encoded addresses of a given
+/// length, with or without a `0x` prefix, e.g. the 20-byte addresses of
+/// Ethereum accounts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HexSignerValidator {
+    length: usize,
+}
+
+impl HexSignerValidator {
+    /// Returns a validator accepting the addresses of `length` bytes.
+    pub fn new(length: usize) -> Self {
+        Self { length }
+    }
+}
+
+impl SignerValidator for HexSignerValidator {
+    fn validate_signer(&self, signer: &Signer) -> Result<(), SignerError> {
+        self.decode_address(signer).map(|_| ())
+    }
+}
+
+/// Decodes the hex-encoded addresses of the length of the validator.
+impl AddressCodec for HexSignerValidator {
+    fn decode_address(&self, address: &Signer) -> Result<Vec<u8>, SignerError> {
+        if address.as_ref().is_empty() {
+            return Err(SignerError::EmptySigner);
+        }
+
+        let encoded = address.as_ref();
+        let encoded = encoded.strip_prefix("0x").unwrap_or(encoded);
+        let bytes = hex::decode(encoded.to_lowercase()).map_err(|_| SignerError::InvalidHex {
+            signer: address.clone(),
+        })?;
+
+        if bytes.len() != self.length {
+            return Err(SignerError::InvalidLength {
+                signer: address.clone(),
+                length: bytes.len(),
+                expected: self.length,
+            });
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Decodes the addresses of the accounts of the host, in whichever format it
+/// uses, e.g. bech32 on Cosmos SDK chains, hex on EVM chains or SS58 on
+/// Substrate chains.
+///
+/// Applications handling addresses of the host that were submitted on other
+/// chains, e.g. the receivers of inbound token transfers, resolve them into
+/// accounts through a [`CodecAccountResolver`] of the host rather than
+/// assuming their format. The validators of this module decode the addresses
+/// they accept.
+pub trait AddressCodec {
+    /// Returns the raw bytes of the account designated by `address`, or an
+    /// error if it is not a well-formed address of the host.
+    fn decode_address(&self, address: &Signer) -> Result<Vec<u8>, SignerError>;
+}
+
+/// Converts [`Signer`]s into the host's native account type.
+///
+/// Parsing a signer (e.g. decoding a bech32 address) can be costly. A resolver
+/// lets the host do it once per signer and hand the resulting account to the
+/// application callbacks, instead of having every callback parse it again.
+pub trait AccountResolver {
+    type AccountId;
+    type Error;
+
+    /// Returns the account designated by `signer`.
+    fn resolve_account(&self, signer: &Signer) -> Result<Self::AccountId, Self::Error>;
+}
+
+/// An [`AccountResolver`] parsing signers with the `TryFrom<Signer>`
+/// implementation of the account type.
+pub struct TryFromSignerResolver<A>(PhantomData<A>);
+
+impl<A> TryFromSignerResolver<A> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A> Default for TryFromSignerResolver<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> Debug for TryFromSignerResolver<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("TryFromSignerResolver")
+    }
+}
+
+impl<A: TryFrom<Signer>> AccountResolver for TryFromSignerResolver<A> {
+    type AccountId = A;
+    type Error = A::Error;
+
+    fn resolve_account(&self, signer: &Signer) -> Result<Self::AccountId, Self::Error> {
+        A::try_from(signer.clone())
+    }
+}
+
+/// An [`AccountResolver`] decoding signers with the [`AddressCodec`] of the
+/// host, and building the accounts from the raw bytes of their addresses.
+///
+/// Hosts whose accounts are not designated by bech32 addresses, e.g. with a
+/// [`HexSignerValidator`] for EVM addresses, resolve the senders and
+/// receivers of transfers with it, so that each address is decoded once, in
+/// their own format, and malformed ones are rejected.
+pub struct CodecAccountResolver<C, A> {
+    codec: C,
+    account: PhantomData<A>,
+}
+
+impl<C, A> CodecAccountResolver<C, A> {
+    pub const fn new(codec: C) -> Self {
+        Self {
+            codec,
+            account: PhantomData,
+        }
+    }
+
+    pub fn codec(&self) -> &C {
+        &self.codec
+    }
+}
+
+impl<C: Debug, A> Debug for CodecAccountResolver<C, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CodecAccountResolver")
+            .field("codec", &self.codec)
+            .finish()
+    }
+}
+
+impl<C: AddressCodec, A: From<Vec<u8>>> AccountResolver for CodecAccountResolver<C, A> {
+    type AccountId = A;
+    type Error = SignerError;
+
+    fn resolve_account(&self, signer: &Signer) -> Result<Self::AccountId, Self::Error> {
+        self.codec.decode_address(signer).map(A::from)
+    }
+}
+
+/// An [`AccountResolver`] that memoizes the resolutions of an inner resolver,
+/// so that each signer is parsed at most once.
+///
+/// Failed resolutions are not cached. The cache lives as long as the resolver:
+/// hosts typically create one per transaction.
+pub struct CachingAccountResolver<R: AccountResolver> {
+    inner: R,
+    cache: RefCell<BTreeMap<Signer, R::AccountId>>,
+}
+
+impl<R: AccountResolver> CachingAccountResolver<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            cache: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+}
+
+impl<R: AccountResolver + Default> Default for CachingAccountResolver<R> {
+    fn default() -> Self {
+        Self::new(R::default())
+    }
+}
+
+impl<R: AccountResolver + Debug> Debug for CachingAccountResolver<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CachingAccountResolver")
+            .field("inner", &self.inner)
+            .field("cached", &self.cache.borrow().len())
+            .finish()
+    }
+}
+
+impl<R> AccountResolver for CachingAccountResolver<R>
+where
+    R: AccountResolver,
+    R::AccountId: Clone,
+{
+    type AccountId = R::AccountId;
+    type Error = R::Error;
+
+    fn resolve_account(&self, signer: &Signer) -> Result<Self::AccountId, Self::Error> {
+        if let Some(account) = self.cache.borrow().get(signer) {
+            return Ok(account.clone());
+        }
+
+        let account = self.inner.resolve_account(signer)?;
+        self.cache
+            .borrow_mut()
+            .insert(signer.clone(), account.clone());
+
+        Ok(account)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    struct CountingResolver {
+        calls: Cell<u32>,
+    }
+
+    impl AccountResolver for CountingResolver {
+        type AccountId = String;
+        type Error = ();
+
+        fn resolve_account(&self, signer: &Signer) -> Result<Self::AccountId, Self::Error> {
+            self.calls.set(self.calls.get() + 1);
+            if signer.as_ref().is_empty() {
+                return Err(());
+            }
+            Ok(signer.as_ref().to_uppercase())
+        }
+    }
+
+    fn signer(s: &str) -> Signer {
+        Signer::from(s.to_string())
+    }
+
+    #[test]
+    fn bech32_signer_validator() {
+        let validator = Bech32SignerValidator::new("cosmos");
+
+        assert!(validator
+            .validate_signer(&signer("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"))
+            .is_ok());
+        assert!(matches!(
+            validator.validate_signer(&signer("")),
+            Err(SignerError::EmptySigner)
+        ));
+        assert!(matches!(
+            validator.validate_signer(&signer("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7nh")),
+            Err(SignerError::InvalidBech32 { .. })
+        ));
+        assert!(matches!(
+            Bech32SignerValidator::new("osmo")
+                .validate_signer(&signer("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng")),
+            Err(SignerError::UnexpectedPrefix { .. })
+        ));
+    }
+
+    #[test]
+    fn hex_signer_validator() {
+        let validator = HexSignerValidator::new(20);
+
+        assert!(validator
+            .validate_signer(&signer("0x52908400098527886E0F7030069857D2E4169EE7"))
+            .is_ok());
+        assert!(validator
+            .validate_signer(&signer("52908400098527886e0f7030069857d2e4169ee7"))
+            .is_ok());
+        assert!(matches!(
+            validator.validate_signer(&signer("0x5290840009852788")),
+            Err(SignerError::InvalidLength { length: 8, .. })
+        ));
+        assert!(matches!(
+            validator.validate_signer(&signer("0xzz908400098527886e0f7030069857d2e4169ee7")),
+            Err(SignerError::InvalidHex { .. })
+        ));
+    }
+
+    #[test]
+    fn address_codecs() {
+        assert_eq!(
+            HexSignerValidator::new(2)
+                .decode_address(&signer("0xABcd"))
+                .unwrap(),
+            vec![0xab, 0xcd]
+        );
+        assert_eq!(
+            Bech32SignerValidator::new("cosmos")
+                .decode_address(&signer(&bech32::encode("cosmos", [1u8, 2, 3])))
+                .unwrap(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            OpaqueSignerValidator
+                .decode_address(&signer("5Grwva"))
+                .unwrap(),
+            b"5Grwva".to_vec()
+        );
+    }
+
+    #[test]
+    fn codec_resolver_builds_accounts_from_decoded_addresses() {
+        let resolver = CodecAccountResolver::<_, Vec<u8>>::new(HexSignerValidator::new(2));
+
+        assert_eq!(
+            resolver.resolve_account(&signer("0xABcd")).unwrap(),
+            vec![0xab, 0xcd]
+        );
+        assert!(matches!(
+            resolver.resolve_account(&signer("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng")),
+            Err(SignerError::InvalidHex { .. })
+        ));
+    }
+
+    #[test]
+    fn caching_resolver_resolves_each_signer_once() {
+        let resolver = CachingAccountResolver::new(CountingResolver {
+            calls: Cell::new(0),
+        });
+        let alice = Signer::from("alice".to_string());
+        let bob = Signer::from("bob".to_string());
+
+        assert_eq!(resolver.resolve_account(&alice), Ok("ALICE".to_string()));
+        assert_eq!(resolver.resolve_account(&alice), Ok("ALICE".to_string()));
+        assert_eq!(resolver.resolve_account(&bob), Ok("BOB".to_string()));
+        assert_eq!(resolver.inner().calls.get(), 2);
+
+        let empty = Signer::from(String::new());
+        assert_eq!(resolver.resolve_account(&empty), Err(()));
+        assert_eq!(resolver.resolve_account(&empty), Err(()));
+        assert_eq!(resolver.inner().calls.get(), 4);
+    }
+}
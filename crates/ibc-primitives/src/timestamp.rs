@@ -11,6 +11,8 @@ use displaydoc::Display;
 use tendermint::Time;
 use time::OffsetDateTime;
 
+use crate::error::IbcError;
+use crate::impl_error_sources;
 use crate::prelude::*;
 
 pub const ZERO_DURATION: Duration = Duration::from_secs(0);
@@ -138,7 +140,13 @@ impl Timestamp {
     }
 
     /// Returns a `Timestamp` representation of the current time.
-    #[cfg(feature = "std")]
+    ///
+    /// The handlers never read the clock, but take the time from
+    /// `ValidationContext::host_timestamp`
+    /// instead. This is only meant for off-chain use, hence unavailable on
+    /// `wasm32` targets, which have no clock to read.
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    #[allow(clippy::disallowed_methods)]
     pub fn now() -> Timestamp {
         Time::now().into()
     }
@@ -170,7 +178,7 @@ impl Timestamp {
     /// Convert a `Timestamp` to `u64` value in nanoseconds. If no timestamp
     /// is set, the result is 0.
     /// ```
-    /// use ibc::core::timestamp::Timestamp;
+    /// use ibc_primitives::timestamp::Timestamp;
     ///
     /// let max = u64::MAX;
     /// let tx = Timestamp::from_nanoseconds(max).unwrap();
@@ -246,6 +254,8 @@ pub enum TimestampOverflowError {
 #[cfg(feature = "std")]
 impl std::error::Error for TimestampOverflowError {}
 
+impl IbcError for TimestampOverflowError {}
+
 impl Add<Duration> for Timestamp {
     type Output = Result<Timestamp, TimestampOverflowError>;
 
@@ -284,15 +294,12 @@ pub enum ParseTimestampError {
     DataOutOfRange(String),
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ParseTimestampError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self {
-            ParseTimestampError::ParseInt(e) => Some(e),
-            ParseTimestampError::DataOutOfRange(_) => None,
-        }
+impl_error_sources!(ParseTimestampError {
+    ibc {}
+    std {
+        ParseTimestampError::ParseInt(e) => e,
     }
-}
+});
 
 impl FromStr for Timestamp {
     type Err = ParseTimestampError;
@@ -312,6 +319,13 @@ impl From<Time> for Timestamp {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Timestamp {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Timestamp::from_nanoseconds(u.arbitrary()?).expect("Never fails"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::time::Duration;
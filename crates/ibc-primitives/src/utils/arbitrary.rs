@@ -0,0 +1,35 @@
+//! Helpers of the `Arbitrary` implementations, through which fuzzers generate
+//! structured inputs, e.g. messages whose identifiers are all valid.
+
+use arbitrary::{Result, Unstructured};
+use ibc_proto::google::protobuf::Any;
+
+use crate::prelude::*;
+
+/// The characters ICS-24 allows in identifiers.
+pub const IDENTIFIER_CHARS: &[u8] =
+    b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789._+-#[]<>";
+
+/// Returns a string of `min` to `max` characters taken from `chars`.
+pub fn string_of(u: &mut Unstructured<'_>, chars: &[u8], min: usize, max: usize) -> Result<String> {
+    let len = u.int_in_range(min..=max)?;
+    (0..len)
+        .map(|_| u.choose(chars).map(|c| char::from(*c)))
+        .collect()
+}
+
+/// Returns arbitrary bytes, at least one, as proofs and acknowledgements hold.
+pub fn non_empty_bytes(u: &mut Unstructured<'_>) -> Result<Vec<u8>> {
+    let mut bytes = vec![u.arbitrary()?];
+    bytes.extend_from_slice(u.arbitrary()?);
+    Ok(bytes)
+}
+
+/// Returns an `Any` of arbitrary type URL and value, for the fields of the
+/// messages holding client states, consensus states or client messages.
+pub fn any(u: &mut Unstructured<'_>) -> Result<Any> {
+    Ok(Any {
+        type_url: u.arbitrary()?,
+        value: u.arbitrary()?,
+    })
+}
@@ -0,0 +1,4 @@
+//! Various utilities used internally
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+pub mod pretty;
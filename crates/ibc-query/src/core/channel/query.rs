@@ -28,6 +28,9 @@ use ibc_proto::ibc::core::channel::v1::{
 use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
 
 use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::ordering::{
+    sort_channel_ends, sort_packet_acknowledgements, sort_packet_commitments,
+};
 use crate::error::QueryError;
 
 /// Queries for a specific IBC channel by the given channel and port ids and
@@ -72,7 +75,8 @@ pub fn query_channels<I>(
 where
     I: QueryContext,
 {
-    let channel_ends = ibc_ctx.channel_ends()?;
+    let mut channel_ends = ibc_ctx.channel_ends()?;
+    sort_channel_ends(&mut channel_ends);
 
     Ok(QueryChannelsResponse {
         channels: channel_ends.into_iter().map(Into::into).collect(),
@@ -92,7 +96,8 @@ where
 {
     let connection_id = ConnectionId::from_str(request.connection.as_str())?;
 
-    let all_channel_ends = ibc_ctx.channel_ends()?;
+    let mut all_channel_ends = ibc_ctx.channel_ends()?;
+    sort_channel_ends(&mut all_channel_ends);
 
     let connection_channel_ends = all_channel_ends
         .into_iter()
@@ -271,14 +276,11 @@ where
 
     let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
 
-    let commitments = ibc_ctx
-        .packet_commitments(&channel_end_path)?
-        .into_iter()
-        .map(Into::into)
-        .collect();
+    let mut commitments = ibc_ctx.packet_commitments(&channel_end_path)?;
+    sort_packet_commitments(&mut commitments);
 
     Ok(QueryPacketCommitmentsResponse {
-        commitments,
+        commitments: commitments.into_iter().map(Into::into).collect(),
         height: Some(ibc_ctx.host_height()?.into()),
         // no support for pagination yet
         pagination: None,
@@ -381,14 +383,12 @@ where
 
     let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
 
-    let acknowledgements = ibc_ctx
-        .packet_acknowledgements(&channel_end_path, commitment_sequences)?
-        .into_iter()
-        .map(Into::into)
-        .collect();
+    let mut acknowledgements =
+        ibc_ctx.packet_acknowledgements(&channel_end_path, commitment_sequences)?;
+    sort_packet_acknowledgements(&mut acknowledgements);
 
     Ok(QueryPacketAcknowledgementsResponse {
-        acknowledgements,
+        acknowledgements: acknowledgements.into_iter().map(Into::into).collect(),
         height: Some(ibc_ctx.host_height()?.into()),
         // no support for pagination yet
         pagination: None,
@@ -3,7 +3,6 @@
 use alloc::format;
 use core::str::FromStr;
 
-use ibc::core::ics02_client::client_state::ClientStateValidation;
 use ibc::core::ics02_client::error::ClientError;
 use ibc::core::ics24_host::identifier::ClientId;
 use ibc::core::ics24_host::path::{
@@ -14,9 +13,10 @@ use ibc::hosts::tendermint::upgrade_proposal::UpgradeValidationContext;
 use ibc::Height;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::{
-    ConsensusStateWithHeight, IdentifiedClientState, QueryClientStateRequest,
-    QueryClientStateResponse, QueryClientStatesRequest, QueryClientStatesResponse,
-    QueryClientStatusRequest, QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
+    ConsensusStateWithHeight, IdentifiedClientState, QueryClientParamsRequest,
+    QueryClientParamsResponse, QueryClientStateRequest, QueryClientStateResponse,
+    QueryClientStatesRequest, QueryClientStatesResponse, QueryClientStatusRequest,
+    QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
     QueryConsensusStateHeightsResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
     QueryConsensusStatesRequest, QueryConsensusStatesResponse, QueryUpgradedClientStateRequest,
     QueryUpgradedClientStateResponse, QueryUpgradedConsensusStateRequest,
@@ -24,6 +24,9 @@ use ibc_proto::ibc::core::client::v1::{
 };
 
 use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::ordering::{
+    sort_client_states, sort_consensus_state_heights, sort_consensus_states,
+};
 use crate::error::QueryError;
 
 /// Queries for the client state of a given client id.
@@ -66,7 +69,8 @@ where
     I: QueryContext,
     <I as ValidationContext>::AnyClientState: Into<Any>,
 {
-    let client_states = ibc_ctx.client_states()?;
+    let mut client_states = ibc_ctx.client_states()?;
+    sort_client_states(&mut client_states);
 
     Ok(QueryClientStatesResponse {
         client_states: client_states
@@ -138,7 +142,8 @@ where
 {
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
-    let consensus_states = ibc_ctx.consensus_states(&client_id)?;
+    let mut consensus_states = ibc_ctx.consensus_states(&client_id)?;
+    sort_consensus_states(&client_id, &mut consensus_states);
 
     Ok(QueryConsensusStatesResponse {
         consensus_states: consensus_states
@@ -163,7 +168,8 @@ where
 {
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
-    let consensus_state_heights = ibc_ctx.consensus_state_heights(&client_id)?;
+    let mut consensus_state_heights = ibc_ctx.consensus_state_heights(&client_id)?;
+    sort_consensus_state_heights(&client_id, &mut consensus_state_heights);
 
     Ok(QueryConsensusStateHeightsResponse {
         consensus_state_heights: consensus_state_heights
@@ -185,12 +191,23 @@ where
 {
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
-    let client_state = ibc_ctx.client_state(&client_id)?;
-    let client_validation_ctx = ibc_ctx.get_client_validation_context();
-    let client_status = client_state.status(client_validation_ctx, &client_id)?;
+    let client_status = ibc_ctx.client_status(&client_id)?;
 
     Ok(QueryClientStatusResponse {
-        status: format!("{client_status}"),
+        status: client_status.as_str().into(),
+    })
+}
+
+/// Queries for the client parameters.
+pub fn query_client_params<I>(
+    ibc_ctx: &I,
+    _request: &QueryClientParamsRequest,
+) -> Result<QueryClientParamsResponse, QueryError>
+where
+    I: ValidationContext,
+{
+    Ok(QueryClientParamsResponse {
+        params: Some(ibc_ctx.client_params()?.into()),
     })
 }
 
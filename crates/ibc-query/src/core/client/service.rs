@@ -21,9 +21,9 @@ use ibc_proto::ibc::core::client::v1::{
 use tonic::{Request, Response, Status};
 
 use super::{
-    query_client_state, query_client_states, query_client_status, query_consensus_state,
-    query_consensus_state_heights, query_consensus_states, query_upgraded_client_state,
-    query_upgraded_consensus_state,
+    query_client_params, query_client_state, query_client_states, query_client_status,
+    query_consensus_state, query_consensus_state_heights, query_consensus_states,
+    query_upgraded_client_state, query_upgraded_consensus_state,
 };
 use crate::core::context::QueryContext;
 
@@ -129,11 +129,11 @@ where
 
     async fn client_params(
         &self,
-        _request: Request<QueryClientParamsRequest>,
+        request: Request<QueryClientParamsRequest>,
     ) -> Result<Response<QueryClientParamsResponse>, Status> {
-        Err(Status::unimplemented(
-            "Querying ClientParams is not supported yet",
-        ))
+        let response = query_client_params(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
     }
 
     async fn upgraded_client_state(
@@ -13,7 +13,7 @@ use ibc::Height;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
 use ibc_proto::ibc::core::connection::v1::{
-    Params as ConnectionParams, QueryClientConnectionsRequest, QueryClientConnectionsResponse,
+    QueryClientConnectionsRequest, QueryClientConnectionsResponse,
     QueryConnectionClientStateRequest, QueryConnectionClientStateResponse,
     QueryConnectionConsensusStateRequest, QueryConnectionConsensusStateResponse,
     QueryConnectionParamsRequest, QueryConnectionParamsResponse, QueryConnectionRequest,
@@ -21,6 +21,7 @@ use ibc_proto::ibc::core::connection::v1::{
 };
 
 use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::ordering::sort_connection_ends;
 use crate::error::QueryError;
 
 /// Queries for the connection end of a given connection id.
@@ -61,7 +62,8 @@ pub fn query_connections<I>(
 where
     I: QueryContext,
 {
-    let connections = ibc_ctx.connection_ends()?;
+    let mut connections = ibc_ctx.connection_ends()?;
+    sort_connection_ends(&mut connections);
 
     Ok(QueryConnectionsResponse {
         connections: connections.into_iter().map(Into::into).collect(),
@@ -188,8 +190,6 @@ where
     I: QueryContext,
 {
     Ok(QueryConnectionParamsResponse {
-        params: Some(ConnectionParams {
-            max_expected_time_per_block: ibc_ctx.max_expected_time_per_block().as_secs(),
-        }),
+        params: Some(ibc_ctx.connection_params()?.into()),
     })
 }
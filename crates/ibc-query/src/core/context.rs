@@ -3,6 +3,7 @@
 use ibc::core::ics03_connection::connection::IdentifiedConnectionEnd;
 use ibc::core::ics04_channel::channel::IdentifiedChannelEnd;
 use ibc::core::ics04_channel::packet::{PacketState, Sequence};
+use ibc::core::ics04_channel::query as channel_query;
 use ibc::core::ics24_host::identifier::{ClientId, ConnectionId};
 use ibc::core::ics24_host::path::{ChannelEndPath, Path};
 use ibc::core::{ContextError, ValidationContext};
@@ -17,6 +18,11 @@ pub trait ProvableContext {
 }
 
 /// Context to be implemented by the host that provides gRPC query services.
+///
+/// The methods listing store entries may return them in any order. The query
+/// methods of this crate respond with them sorted in the lexicographic order of
+/// their ICS-24 path, as `ibc-go` iterates over its store, so that paginating
+/// consumers get consistent results across implementations.
 pub trait QueryContext: ProvableContext + ValidationContext {
     // Client queries
 
@@ -40,6 +46,8 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError>;
 
     /// Returns the list of all connection ids of the given client.
+    ///
+    /// As this list is stored under a single path, its order is preserved.
     fn client_connection_ends(
         &self,
         client_id: &ClientId,
@@ -67,17 +75,28 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     ) -> Result<Vec<PacketState>, ContextError>;
 
     /// Filters the packet sequences for the given channel end that are not received.
+    /// The order of `sequences` is preserved.
+    ///
+    /// Defaults to [`unreceived_packets`](channel_query::unreceived_packets),
+    /// which agrees with ibc-go.
     fn unreceived_packets(
         &self,
         channel_end_path: &ChannelEndPath,
         sequences: impl ExactSizeIterator<Item = Sequence>,
-    ) -> Result<Vec<Sequence>, ContextError>;
+    ) -> Result<Vec<Sequence>, ContextError> {
+        channel_query::unreceived_packets(self, channel_end_path, sequences)
+    }
 
     /// Filters the list of packet sequences for the given channel end whose acknowledgement is not received.
-    /// Returns all the unreceived acknowledgements if `sequences` is empty.
+    /// The order of `sequences` is preserved.
+    ///
+    /// Defaults to [`unreceived_acks`](channel_query::unreceived_acks), which
+    /// agrees with ibc-go.
     fn unreceived_acks(
         &self,
         channel_end_path: &ChannelEndPath,
         sequences: impl ExactSizeIterator<Item = Sequence>,
-    ) -> Result<Vec<Sequence>, ContextError>;
+    ) -> Result<Vec<Sequence>, ContextError> {
+        channel_query::unreceived_acks(self, channel_end_path, sequences)
+    }
 }
@@ -0,0 +1,137 @@
+//! Ordering of the entries returned by the iteration methods of
+//! [`QueryContext`](crate::core::context::QueryContext).
+//!
+//! `ibc-go` iterates over its store, and thus paginates, in the lexicographic
+//! order of the keys, i.e. of the ICS-24 paths under which entries are stored.
+//! As hosts may return entries in any order, the query methods sort them by
+//! path before responding, so that gRPC consumers paginating across
+//! implementations get consistent results. Note that numbers embedded in paths
+//! are compared as strings, e.g. `channel-10` sorts before `channel-2`.
+
+use alloc::string::ToString;
+use core::fmt::Display;
+
+use ibc::core::ics03_connection::connection::IdentifiedConnectionEnd;
+use ibc::core::ics04_channel::channel::IdentifiedChannelEnd;
+use ibc::core::ics04_channel::packet::PacketState;
+use ibc::core::ics24_host::identifier::ClientId;
+use ibc::core::ics24_host::path::{
+    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath,
+    ConnectionPath,
+};
+use ibc::Height;
+
+fn sort_by_path<T, P: Display>(entries: &mut [T], path: impl Fn(&T) -> P) {
+    entries.sort_by_cached_key(|entry| path(entry).to_string());
+}
+
+pub(crate) fn sort_client_states<T>(client_states: &mut [(ClientId, T)]) {
+    sort_by_path(client_states, |(client_id, _)| {
+        ClientStatePath::new(client_id)
+    });
+}
+
+pub(crate) fn sort_consensus_states<T>(client_id: &ClientId, consensus_states: &mut [(Height, T)]) {
+    sort_by_path(consensus_states, |(height, _)| {
+        ClientConsensusStatePath::new(client_id, height)
+    });
+}
+
+pub(crate) fn sort_consensus_state_heights(client_id: &ClientId, heights: &mut [Height]) {
+    sort_by_path(heights, |height| {
+        ClientConsensusStatePath::new(client_id, height)
+    });
+}
+
+pub(crate) fn sort_connection_ends(connection_ends: &mut [IdentifiedConnectionEnd]) {
+    sort_by_path(connection_ends, |connection_end| {
+        ConnectionPath::new(&connection_end.connection_id)
+    });
+}
+
+pub(crate) fn sort_channel_ends(channel_ends: &mut [IdentifiedChannelEnd]) {
+    sort_by_path(channel_ends, |channel_end| {
+        ChannelEndPath::new(&channel_end.port_id, &channel_end.channel_id)
+    });
+}
+
+pub(crate) fn sort_packet_commitments(commitments: &mut [PacketState]) {
+    sort_by_path(commitments, |state| {
+        CommitmentPath::new(&state.port_id, &state.chan_id, state.seq)
+    });
+}
+
+pub(crate) fn sort_packet_acknowledgements(acknowledgements: &mut [PacketState]) {
+    sort_by_path(acknowledgements, |state| {
+        AckPath::new(&state.port_id, &state.chan_id, state.seq)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use ibc::core::ics02_client::client_type::ClientType;
+    use ibc::core::ics04_channel::packet::Sequence;
+    use ibc::core::ics24_host::identifier::{ChannelId, PortId};
+
+    use super::*;
+
+    fn client_id(counter: u64) -> ClientId {
+        ClientId::new(ClientType::new("07-tendermint").unwrap(), counter).unwrap()
+    }
+
+    fn packet_state(port_id: &str, channel: u64, seq: u64) -> PacketState {
+        PacketState {
+            port_id: PortId::new(port_id.to_string()).unwrap(),
+            chan_id: ChannelId::new(channel),
+            seq: Sequence::from(seq),
+            data: vec![1],
+        }
+    }
+
+    #[test]
+    fn client_states_are_sorted_by_key() {
+        let mut client_states = vec![(client_id(2), ()), (client_id(10), ()), (client_id(1), ())];
+
+        sort_client_states(&mut client_states);
+
+        let client_ids: Vec<_> = client_states.into_iter().map(|(id, _)| id).collect();
+        assert_eq!(client_ids, vec![client_id(1), client_id(10), client_id(2)]);
+    }
+
+    #[test]
+    fn consensus_state_heights_are_sorted_by_key() {
+        let height = |revision_number, revision_height| {
+            Height::new(revision_number, revision_height).unwrap()
+        };
+        let mut heights = vec![height(1, 2), height(0, 5), height(1, 10)];
+
+        sort_consensus_state_heights(&client_id(0), &mut heights);
+
+        assert_eq!(heights, vec![height(0, 5), height(1, 10), height(1, 2)]);
+    }
+
+    #[test]
+    fn packet_states_are_sorted_by_key() {
+        let mut commitments = vec![
+            packet_state("transfer", 1, 9),
+            packet_state("transfer", 10, 1),
+            packet_state("transfer", 1, 10),
+            packet_state("oracle", 2, 1),
+        ];
+
+        sort_packet_commitments(&mut commitments);
+
+        assert_eq!(
+            commitments,
+            vec![
+                packet_state("oracle", 2, 1),
+                packet_state("transfer", 1, 10),
+                packet_state("transfer", 1, 9),
+                packet_state("transfer", 10, 1),
+            ]
+        );
+    }
+}
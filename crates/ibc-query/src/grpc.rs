@@ -0,0 +1,252 @@
+//! A ready-to-mount gRPC server of the `ibc.core.client.v1.Query`,
+//! `ibc.core.connection.v1.Query` and `ibc.core.channel.v1.Query` services,
+//! over a single handle to the host.
+//!
+//! [`IbcQueryServer`] bundles the [`ClientQueryService`],
+//! [`ConnectionQueryService`] and [`ChannelQueryService`] of this crate, so
+//! that node authors mount them all at once, either alongside their own
+//! services through [`IbcQueryServer::into_routes`], or as a standalone server
+//! through [`IbcQueryServer::serve`].
+//!
+//! ```rust,ignore
+//! use ibc_query::grpc::IbcQueryServer;
+//!
+//! // `ibc` and `upgrade` must be thread-safe, and `ibc` cheap to clone,
+//! // e.g. `Arc<RwLock<_>>`
+//! IbcQueryServer::new(ibc, upgrade).serve(addr).await?;
+//! ```
+
+use std::net::SocketAddr;
+
+use ibc::core::ValidationContext;
+use ibc::hosts::tendermint::upgrade_proposal::UpgradeValidationContext;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::query_server::QueryServer as ChannelQueryServer;
+use ibc_proto::ibc::core::client::v1::query_server::QueryServer as ClientQueryServer;
+use ibc_proto::ibc::core::connection::v1::query_server::QueryServer as ConnectionQueryServer;
+use tonic::transport::server::Routes;
+use tonic::transport::{Error, Server};
+
+use crate::core::channel::ChannelQueryService;
+use crate::core::client::ClientQueryService;
+use crate::core::connection::ConnectionQueryService;
+use crate::core::context::QueryContext;
+
+/// The gRPC server of the IBC core query services of a host.
+///
+/// The generic `I` is the handle through which the services read the host
+/// state, which they share, hence cloned for each of them. As for `U`, it must
+/// be a type where writes from one thread are readable from another. This
+/// means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+pub struct IbcQueryServer<I, U>
+where
+    I: QueryContext + Clone + Send + Sync + 'static,
+    U: UpgradeValidationContext + Send + Sync + 'static,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+    <I as ValidationContext>::AnyConsensusState: Into<Any>,
+    <U as UpgradeValidationContext>::AnyClientState: Into<Any>,
+    <U as UpgradeValidationContext>::AnyConsensusState: Into<Any>,
+{
+    ibc_context: I,
+    upgrade_context: U,
+}
+
+impl<I, U> IbcQueryServer<I, U>
+where
+    I: QueryContext + Clone + Send + Sync + 'static,
+    U: UpgradeValidationContext + Send + Sync + 'static,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+    <I as ValidationContext>::AnyConsensusState: Into<Any>,
+    <U as UpgradeValidationContext>::AnyClientState: Into<Any>,
+    <U as UpgradeValidationContext>::AnyConsensusState: Into<Any>,
+{
+    pub fn new(ibc_context: I, upgrade_context: U) -> Self {
+        Self {
+            ibc_context,
+            upgrade_context,
+        }
+    }
+
+    /// Returns the routes of the client, connection and channel query
+    /// services, to be added to a [`Server`] along with those of the other
+    /// services of the node.
+    pub fn into_routes(self) -> Routes {
+        let client_service = ClientQueryServer::new(ClientQueryService::new(
+            self.ibc_context.clone(),
+            self.upgrade_context,
+        ));
+        let connection_service =
+            ConnectionQueryServer::new(ConnectionQueryService::new(self.ibc_context.clone()));
+        let channel_service = ChannelQueryServer::new(ChannelQueryService::new(self.ibc_context));
+
+        Routes::new(client_service)
+            .add_service(connection_service)
+            .add_service(channel_service)
+    }
+
+    /// Serves the client, connection and channel query services on `addr`,
+    /// until the server fails.
+    pub async fn serve(self, addr: SocketAddr) -> Result<(), Error> {
+        Server::builder()
+            .add_routes(self.into_routes())
+            .serve(addr)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use std::format;
+
+    use ibc::core::genesis::GenesisContext;
+    use ibc::core::ics02_client::error::UpgradeClientError;
+    use ibc::core::ics03_connection::connection::IdentifiedConnectionEnd;
+    use ibc::core::ics04_channel::channel::IdentifiedChannelEnd;
+    use ibc::core::ics04_channel::packet::{PacketState, Sequence};
+    use ibc::core::ics24_host::identifier::{ClientId, ConnectionId};
+    use ibc::core::ics24_host::path::{ChannelEndPath, Path, UpgradeClientPath};
+    use ibc::core::ContextError;
+    use ibc::hosts::tendermint::upgrade_proposal::Plan;
+    use ibc::Height;
+    use ibc_proto::ibc::core::client::v1::query_client::QueryClient as ClientQueryClient;
+    use ibc_proto::ibc::core::client::v1::QueryClientStateRequest;
+    use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+    use ibc_testkit::testapp::ibc::core::types::MockContext;
+    use tokio::net::TcpListener;
+    use tokio_stream::wrappers::TcpListenerStream;
+
+    use super::*;
+    use crate::core::context::ProvableContext;
+
+    impl ProvableContext for MockContext {
+        fn get_proof(&self, height: Height, path: &Path) -> Option<Vec<u8>> {
+            self.proofs.proof(path, &height).map(Into::into)
+        }
+    }
+
+    impl QueryContext for MockContext {
+        fn client_states(&self) -> Result<Vec<(ClientId, AnyClientState)>, ContextError> {
+            GenesisContext::client_states(self)
+        }
+
+        fn consensus_states(
+            &self,
+            client_id: &ClientId,
+        ) -> Result<Vec<(Height, AnyConsensusState)>, ContextError> {
+            GenesisContext::consensus_states(self, client_id)
+        }
+
+        fn consensus_state_heights(
+            &self,
+            client_id: &ClientId,
+        ) -> Result<Vec<Height>, ContextError> {
+            let consensus_states = GenesisContext::consensus_states(self, client_id)?;
+            Ok(consensus_states
+                .into_iter()
+                .map(|(height, _)| height)
+                .collect())
+        }
+
+        fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError> {
+            GenesisContext::connection_ends(self)
+        }
+
+        fn client_connection_ends(
+            &self,
+            client_id: &ClientId,
+        ) -> Result<Vec<ConnectionId>, ContextError> {
+            GenesisContext::client_connection_ends(self, client_id)
+        }
+
+        fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError> {
+            GenesisContext::channel_ends(self)
+        }
+
+        fn packet_commitments(
+            &self,
+            channel_end_path: &ChannelEndPath,
+        ) -> Result<Vec<PacketState>, ContextError> {
+            GenesisContext::packet_commitments(self, channel_end_path)
+        }
+
+        fn packet_acknowledgements(
+            &self,
+            channel_end_path: &ChannelEndPath,
+            sequences: impl ExactSizeIterator<Item = Sequence>,
+        ) -> Result<Vec<PacketState>, ContextError> {
+            let sequences: Vec<_> = sequences.collect();
+            let acks = GenesisContext::packet_acknowledgements(self, channel_end_path)?;
+            Ok(acks
+                .into_iter()
+                .filter(|ack| sequences.is_empty() || sequences.contains(&ack.seq))
+                .collect())
+        }
+    }
+
+    /// The upgrade context of a host which never scheduled an upgrade.
+    struct NoUpgrade;
+
+    impl UpgradeValidationContext for NoUpgrade {
+        type V = MockContext;
+        type E = MockContext;
+        type AnyConsensusState = AnyConsensusState;
+        type AnyClientState = AnyClientState;
+
+        fn upgrade_plan(&self) -> Result<Plan, UpgradeClientError> {
+            Err(UpgradeClientError::InvalidUpgradePlan {
+                reason: "no upgrade scheduled".to_string(),
+            })
+        }
+
+        fn upgraded_client_state(
+            &self,
+            _upgrade_path: &UpgradeClientPath,
+        ) -> Result<Self::AnyClientState, UpgradeClientError> {
+            Err(UpgradeClientError::InvalidUpgradePlan {
+                reason: "no upgrade scheduled".to_string(),
+            })
+        }
+
+        fn upgraded_consensus_state(
+            &self,
+            _upgrade_path: &UpgradeClientPath,
+        ) -> Result<Self::AnyConsensusState, UpgradeClientError> {
+            Err(UpgradeClientError::InvalidUpgradePlan {
+                reason: "no upgrade scheduled".to_string(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn routes_serve_client_state_queries() {
+        let client_id = ClientId::default();
+        let mut ctx = MockContext::default().with_client(&client_id, Height::new(0, 42).unwrap());
+        // commits the client state in a block, against which it is proven
+        ctx.advance_host_chain_height();
+        let client_state: Any = ctx.client_state(&client_id).unwrap().into();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(
+            Server::builder()
+                .add_routes(IbcQueryServer::new(ctx, NoUpgrade).into_routes())
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+
+        let mut client = ClientQueryClient::connect(format!("http://{addr}"))
+            .await
+            .unwrap();
+        let response = client
+            .client_state(QueryClientStateRequest {
+                client_id: client_id.to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(response.client_state, Some(client_state));
+        assert!(!response.proof.is_empty());
+    }
+}
@@ -51,6 +51,8 @@
 //!       .serve(addr);
 //! ```
 //!
+//! With the `grpc` feature, the [`grpc::IbcQueryServer`] mounts all three
+//! services at once.
 
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
 #![no_std]
@@ -65,6 +67,10 @@
 #![forbid(unsafe_code)]
 
 extern crate alloc;
+#[cfg(any(test, feature = "grpc"))]
+extern crate std;
 
 pub mod core;
 pub mod error;
+#[cfg(feature = "grpc")]
+pub mod grpc;
@@ -5,6 +5,7 @@ use core::str::FromStr;
 use ibc::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
 use ibc::clients::ics07_tendermint::header::{Header, TENDERMINT_HEADER_TYPE_URL};
 use ibc::core::ics02_client::error::ClientError;
+use ibc::core::ics23_commitment::commitment::CommitmentRoot;
 use ibc::core::ics24_host::identifier::ChainId;
 use ibc::core::timestamp::Timestamp;
 use ibc::prelude::*;
@@ -12,6 +13,7 @@ use ibc::proto::tendermint::v1::Header as RawHeader;
 use ibc::proto::{Any, Protobuf};
 use ibc::Height;
 use tendermint::block::Header as TmHeader;
+use tendermint::hash::AppHash;
 use tendermint::validator::Set as ValidatorSet;
 use tendermint_testgen::light_block::TmLightBlock;
 use tendermint_testgen::{
@@ -171,17 +173,42 @@ impl HostBlock {
         chain_id: ChainId,
         height: u64,
         timestamp: Timestamp,
+    ) -> SyntheticTmBlock {
+        Self::generate_tm_block_with_header(chain_id, height, timestamp, |header| header)
+    }
+
+    /// Same as [`Self::generate_tm_block`], but the block header commits to
+    /// `app_hash`, against which proofs of the host state are verified.
+    pub fn generate_tm_block_with_app_hash(
+        chain_id: ChainId,
+        height: u64,
+        timestamp: Timestamp,
+        app_hash: CommitmentRoot,
+    ) -> SyntheticTmBlock {
+        let app_hash = AppHash::try_from(app_hash.into_vec()).expect("Never fails");
+        Self::generate_tm_block_with_header(chain_id, height, timestamp, |header| {
+            header.app_hash(app_hash)
+        })
+    }
+
+    fn generate_tm_block_with_header(
+        chain_id: ChainId,
+        height: u64,
+        timestamp: Timestamp,
+        customize: impl FnOnce(TestgenHeader) -> TestgenHeader,
     ) -> SyntheticTmBlock {
         let validators = [
             TestgenValidator::new("1").voting_power(50),
             TestgenValidator::new("2").voting_power(50),
         ];
 
-        let header = TestgenHeader::new(&validators)
-            .height(height)
-            .chain_id(chain_id.as_str())
-            .next_validators(&validators)
-            .time(timestamp.into_tm_time().expect("Never fails"));
+        let header = customize(
+            TestgenHeader::new(&validators)
+                .height(height)
+                .chain_id(chain_id.as_str())
+                .next_validators(&validators)
+                .time(timestamp.into_tm_time().expect("Never fails")),
+        );
 
         let light_block = TestgenLightBlock::new_default_with_header(header)
             .generate()
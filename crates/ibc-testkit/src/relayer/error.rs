@@ -1,6 +1,7 @@
 use displaydoc::Display;
 use ibc::core::ics24_host::identifier::ClientId;
-use ibc::core::{ics03_connection, RouterError};
+use ibc::core::{ics03_connection, ContextError, RouterError};
+use ibc::prelude::*;
 use ibc::Height;
 
 #[derive(Debug, Display)]
@@ -23,6 +24,12 @@ pub enum RelayerError {
     TransactionFailed(RouterError),
     /// connection error: `{0}`
     Connection(ics03_connection::error::ConnectionError),
+    /// context error: `{0}`
+    Context(ContextError),
+    /// expected event `{event_type}` was not emitted
+    MissingEvent { event_type: String },
+    /// no proof of `{path}` could be generated from the store of the source chain
+    MissingProof { path: String },
 }
 
 #[cfg(feature = "std")]
@@ -31,7 +38,14 @@ impl std::error::Error for RelayerError {
         match &self {
             Self::TransactionFailed(e) => Some(e),
             Self::Connection(e) => Some(e),
+            Self::Context(e) => Some(e),
             _ => None,
         }
     }
 }
+
+impl From<ContextError> for RelayerError {
+    fn from(error: ContextError) -> Self {
+        Self::Context(error)
+    }
+}
@@ -0,0 +1,1168 @@
+//! A relayer harness that drives two or more [`MockContext`]s through the
+//! full IBC lifecycle: client creation, connection and channel handshakes, and
+//! packet round-trips.
+//!
+//! [`RelayerHarness`] covers the common case of a pair of chains, while
+//! [`MultiChainHarness`] relays between any pair of an arbitrary number of
+//! chains.
+//!
+//! The harness plays the role of an off-chain relayer. Every message it builds
+//! carries the ICS-23 proof of the state it refers to, generated from the
+//! Merkle store of the source chain (see [`MockContext::membership_proof`]),
+//! and the receiving client is updated to the latest source height before
+//! delivery. All chains must be of [`HostType::Mock`], since the harness
+//! updates clients with mock headers, whose light clients do not check the
+//! proofs against a commitment root.
+
+use alloc::collections::btree_map::BTreeMap;
+use core::time::Duration;
+
+use ibc::core::events::IbcEvent;
+use ibc::core::ics02_client::client_state::ClientStateCommon;
+use ibc::core::ics02_client::msgs::create_client::MsgCreateClient;
+use ibc::core::ics02_client::msgs::update_client::MsgUpdateClient;
+use ibc::core::ics02_client::msgs::ClientMsg;
+use ibc::core::ics03_connection::connection::{
+    Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
+use ibc::core::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use ibc::core::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use ibc::core::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
+use ibc::core::ics03_connection::msgs::ConnectionMsg;
+use ibc::core::ics04_channel::acknowledgement::Acknowledgement;
+use ibc::core::ics04_channel::channel::{Order, State as ChannelState};
+use ibc::core::ics04_channel::msgs::{
+    ChannelMsg, MsgAcknowledgement, MsgChannelOpenAck, MsgChannelOpenConfirm, MsgChannelOpenInit,
+    MsgChannelOpenTry, MsgRecvPacket, MsgTimeout, PacketMsg,
+};
+use ibc::core::ics04_channel::packet::Packet;
+use ibc::core::ics04_channel::receipt_bitmap::{
+    unreceived_packet_path, ReceiptBitmapContext, ReceiptBitmapProof,
+};
+use ibc::core::ics04_channel::Version as ChannelVersion;
+use ibc::core::ics23_commitment::commitment::CommitmentProofBytes;
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::{
+    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath,
+    ConnectionPath, Path, SeqRecvPath,
+};
+use ibc::core::{send_packet, MsgEnvelope, ValidationContext};
+use ibc::prelude::*;
+use ibc::Height;
+
+use super::context::RelayerContext;
+use super::error::RelayerError;
+use crate::hosts::block::{HostBlock, HostType};
+use crate::testapp::ibc::clients::mock::client_state::MockClientState;
+use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use crate::testapp::ibc::core::router::MockRouter;
+use crate::testapp::ibc::core::types::MockContext;
+
+/// A chain taking part in a [`RelayerHarness`]: its context along with the
+/// router holding the modules bound to its ports.
+pub struct HarnessChain {
+    pub ctx: MockContext,
+    pub router: MockRouter,
+}
+
+impl HarnessChain {
+    pub fn new(ctx: MockContext, router: MockRouter) -> Self {
+        assert!(
+            matches!(ctx.host_chain_type, HostType::Mock),
+            "the relayer harness only supports mock hosts"
+        );
+
+        Self { ctx, router }
+    }
+
+    fn latest_block(&self) -> HostBlock {
+        self.ctx
+            .query_latest_header()
+            .expect("latest block always exists")
+    }
+
+    fn deliver(&mut self, msg: impl Into<MsgEnvelope>) -> Result<(), RelayerError> {
+        self.ctx.deliver(&mut self.router, msg.into())
+    }
+
+    /// Advances the chain until its clock is not behind `timestamp`, so that
+    /// consensus states of the counterparty never appear to be in the future.
+    fn catch_up(&mut self, counterparty: &HarnessChain) {
+        let target = counterparty.latest_block().timestamp();
+        while self.ctx.host_timestamp().expect("Never fails") < target {
+            self.ctx.advance_host_chain_height();
+        }
+    }
+}
+
+/// Owns two chains and relays messages between them.
+///
+/// Following the conventions of the core handlers, chain A is the chain that
+/// initiates handshakes, while chain B is the one that answers them. Packets
+/// may flow in either direction once a channel is open.
+pub struct RelayerHarness {
+    pub chain_a: HarnessChain,
+    pub chain_b: HarnessChain,
+}
+
+impl RelayerHarness {
+    pub fn new(chain_a: HarnessChain, chain_b: HarnessChain) -> Self {
+        Self { chain_a, chain_b }
+    }
+
+    /// Creates a client of chain B on chain A, returning its identifier.
+    pub fn create_client_on_a(&mut self) -> Result<ClientId, RelayerError> {
+        create_client(&mut self.chain_a, &self.chain_b)
+    }
+
+    /// Creates a client of chain A on chain B, returning its identifier.
+    pub fn create_client_on_b(&mut self) -> Result<ClientId, RelayerError> {
+        create_client(&mut self.chain_b, &self.chain_a)
+    }
+
+    /// Updates the client of chain B on chain A to the latest height of chain
+    /// B, returning that height.
+    pub fn update_client_on_a(
+        &mut self,
+        client_id_on_a: &ClientId,
+    ) -> Result<Height, RelayerError> {
+        update_client(&mut self.chain_a, &self.chain_b, client_id_on_a)
+    }
+
+    /// Updates the client of chain A on chain B to the latest height of chain
+    /// A, returning that height.
+    pub fn update_client_on_b(
+        &mut self,
+        client_id_on_b: &ClientId,
+    ) -> Result<Height, RelayerError> {
+        update_client(&mut self.chain_b, &self.chain_a, client_id_on_b)
+    }
+
+    /// Creates a client on each chain and runs the connection handshake over
+    /// them, returning the client and connection identifiers on both ends.
+    pub fn setup_connection(
+        &mut self,
+    ) -> Result<(ClientId, ClientId, ConnectionId, ConnectionId), RelayerError> {
+        let client_id_on_a = self.create_client_on_a()?;
+        let client_id_on_b = self.create_client_on_b()?;
+        let (conn_id_on_a, conn_id_on_b) =
+            self.connection_handshake(&client_id_on_a, &client_id_on_b)?;
+
+        Ok((client_id_on_a, client_id_on_b, conn_id_on_a, conn_id_on_b))
+    }
+
+    /// Runs the four steps of the connection handshake between the given
+    /// clients, returning the identifiers of the connection on A and on B.
+    pub fn connection_handshake(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+    ) -> Result<(ConnectionId, ConnectionId), RelayerError> {
+        connection_handshake(
+            &mut self.chain_a,
+            &mut self.chain_b,
+            client_id_on_a,
+            client_id_on_b,
+        )
+    }
+
+    /// Runs the four steps of the channel handshake on top of an open
+    /// connection, returning the identifiers of the channel on A and on B.
+    #[allow(clippy::too_many_arguments)]
+    pub fn channel_handshake(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        conn_id_on_a: &ConnectionId,
+        conn_id_on_b: &ConnectionId,
+        port_id_on_a: &PortId,
+        port_id_on_b: &PortId,
+        ordering: Order,
+        version: ChannelVersion,
+    ) -> Result<(ChannelId, ChannelId), RelayerError> {
+        channel_handshake(
+            &mut self.chain_a,
+            &mut self.chain_b,
+            client_id_on_a,
+            client_id_on_b,
+            conn_id_on_a,
+            conn_id_on_b,
+            port_id_on_a,
+            port_id_on_b,
+            ordering,
+            version,
+        )
+    }
+
+    /// Sends `packet` from chain A, committing it to A's store.
+    pub fn send_packet_on_a(&mut self, packet: Packet) -> Result<(), RelayerError> {
+        send_on(&mut self.chain_a, packet)
+    }
+
+    /// Sends `packet` from chain B, committing it to B's store.
+    pub fn send_packet_on_b(&mut self, packet: Packet) -> Result<(), RelayerError> {
+        send_on(&mut self.chain_b, packet)
+    }
+
+    /// Relays a packet sent by chain A to chain B, then relays the resulting
+    /// acknowledgement back to chain A.
+    ///
+    /// `client_id_on_a` tracks chain B on chain A, and `client_id_on_b` tracks
+    /// chain A on chain B.
+    pub fn relay_packet_a_to_b(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        packet: Packet,
+    ) -> Result<Acknowledgement, RelayerError> {
+        relay_packet(
+            &mut self.chain_a,
+            &mut self.chain_b,
+            client_id_on_a,
+            client_id_on_b,
+            packet,
+        )
+    }
+
+    /// Relays a packet sent by chain B to chain A, then relays the resulting
+    /// acknowledgement back to chain B.
+    pub fn relay_packet_b_to_a(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        packet: Packet,
+    ) -> Result<Acknowledgement, RelayerError> {
+        relay_packet(
+            &mut self.chain_b,
+            &mut self.chain_a,
+            client_id_on_b,
+            client_id_on_a,
+            packet,
+        )
+    }
+
+    /// Relays the timeout of a packet sent by chain A that was never received
+    /// by chain B.
+    pub fn relay_timeout_a_to_b(
+        &mut self,
+        client_id_on_a: &ClientId,
+        packet: Packet,
+    ) -> Result<(), RelayerError> {
+        relay_timeout(&mut self.chain_a, &self.chain_b, client_id_on_a, packet)
+    }
+
+    /// Relays the timeout of a packet sent by chain B that was never received
+    /// by chain A.
+    pub fn relay_timeout_b_to_a(
+        &mut self,
+        client_id_on_b: &ClientId,
+        packet: Packet,
+    ) -> Result<(), RelayerError> {
+        relay_timeout(&mut self.chain_b, &self.chain_a, client_id_on_b, packet)
+    }
+
+    /// Checks the invariants that must hold between the two chains at any
+    /// point in time:
+    ///
+    /// - both contexts have a valid block history,
+    /// - every open connection or channel end points to a counterparty end
+    ///   that points back to it and that has at least reached `TryOpen`,
+    /// - no chain has received more packets on an ordered channel than its
+    ///   counterparty has sent.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        self.chain_a.ctx.validate()?;
+        self.chain_b.ctx.validate()?;
+
+        check_counterparty_ends(&self.chain_a.ctx, |_| Some(&self.chain_b.ctx))?;
+        check_counterparty_ends(&self.chain_b.ctx, |_| Some(&self.chain_a.ctx))
+    }
+}
+
+/// Owns any number of chains and relays messages between any pair of them,
+/// for topologies that involve more than two chains, such as multi-hop
+/// channels or packets forwarded through an intermediate chain.
+///
+/// Chains are identified by their index in [`Self::chains`]. Each of them
+/// keeps its own height and clock: blocks are only produced on a chain when a
+/// message is delivered to it, when it is explicitly advanced, or when it
+/// needs to catch up with the clock of a chain it is receiving headers from.
+pub struct MultiChainHarness {
+    pub chains: Vec<HarnessChain>,
+    /// The chain tracked by each client created through the harness, keyed by
+    /// the index of the chain hosting the client and the client identifier.
+    counterparties: BTreeMap<(usize, ClientId), usize>,
+}
+
+impl MultiChainHarness {
+    pub fn new(chains: Vec<HarnessChain>) -> Self {
+        assert!(
+            chains.len() >= 2,
+            "the relayer harness needs at least two chains"
+        );
+
+        Self {
+            chains,
+            counterparties: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the index of the chain tracked by `client_id` on chain `host`,
+    /// if that client was created through the harness.
+    pub fn counterparty_of(&self, host: usize, client_id: &ClientId) -> Option<usize> {
+        self.counterparties.get(&(host, client_id.clone())).copied()
+    }
+
+    /// Produces `blocks` new blocks on chain `chain`, leaving the other chains
+    /// untouched.
+    pub fn advance_chain(&mut self, chain: usize, blocks: u64) {
+        for _ in 0..blocks {
+            self.chains[chain].ctx.advance_host_chain_height();
+        }
+    }
+
+    /// Creates a client of chain `counterparty` on chain `host`, returning its
+    /// identifier.
+    pub fn create_client(
+        &mut self,
+        host: usize,
+        counterparty: usize,
+    ) -> Result<ClientId, RelayerError> {
+        let (host_chain, counterparty_chain) = self.pair_mut(host, counterparty);
+        let client_id = create_client(host_chain, counterparty_chain)?;
+        self.counterparties
+            .insert((host, client_id.clone()), counterparty);
+
+        Ok(client_id)
+    }
+
+    /// Updates `client_id` on chain `host` to the latest height of the chain
+    /// it tracks, returning that height.
+    pub fn update_client(
+        &mut self,
+        host: usize,
+        client_id: &ClientId,
+    ) -> Result<Height, RelayerError> {
+        let counterparty = self.tracked_chain(host, client_id)?;
+        let (host_chain, counterparty_chain) = self.pair_mut(host, counterparty);
+        update_client(host_chain, counterparty_chain, client_id)
+    }
+
+    /// Creates a client on chains `a` and `b` tracking each other and runs the
+    /// connection handshake over them, initiated by `a`. Returns the client
+    /// and connection identifiers on both ends.
+    pub fn setup_connection(
+        &mut self,
+        a: usize,
+        b: usize,
+    ) -> Result<(ClientId, ClientId, ConnectionId, ConnectionId), RelayerError> {
+        let client_id_on_a = self.create_client(a, b)?;
+        let client_id_on_b = self.create_client(b, a)?;
+        let (conn_id_on_a, conn_id_on_b) =
+            self.connection_handshake(a, b, &client_id_on_a, &client_id_on_b)?;
+
+        Ok((client_id_on_a, client_id_on_b, conn_id_on_a, conn_id_on_b))
+    }
+
+    /// Runs the four steps of the connection handshake between chains `a` and
+    /// `b`, initiated by `a`.
+    pub fn connection_handshake(
+        &mut self,
+        a: usize,
+        b: usize,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+    ) -> Result<(ConnectionId, ConnectionId), RelayerError> {
+        let (chain_a, chain_b) = self.pair_mut(a, b);
+        connection_handshake(chain_a, chain_b, client_id_on_a, client_id_on_b)
+    }
+
+    /// Runs the four steps of the channel handshake between chains `a` and
+    /// `b` on top of an open connection, initiated by `a`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn channel_handshake(
+        &mut self,
+        a: usize,
+        b: usize,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        conn_id_on_a: &ConnectionId,
+        conn_id_on_b: &ConnectionId,
+        port_id_on_a: &PortId,
+        port_id_on_b: &PortId,
+        ordering: Order,
+        version: ChannelVersion,
+    ) -> Result<(ChannelId, ChannelId), RelayerError> {
+        let (chain_a, chain_b) = self.pair_mut(a, b);
+        channel_handshake(
+            chain_a,
+            chain_b,
+            client_id_on_a,
+            client_id_on_b,
+            conn_id_on_a,
+            conn_id_on_b,
+            port_id_on_a,
+            port_id_on_b,
+            ordering,
+            version,
+        )
+    }
+
+    /// Sends `packet` from chain `chain`, committing it to its store.
+    pub fn send_packet(&mut self, chain: usize, packet: Packet) -> Result<(), RelayerError> {
+        send_on(&mut self.chains[chain], packet)
+    }
+
+    /// Relays a packet sent by chain `src` to chain `dst`, then relays the
+    /// resulting acknowledgement back to `src`.
+    pub fn relay_packet(
+        &mut self,
+        src: usize,
+        dst: usize,
+        client_id_on_src: &ClientId,
+        client_id_on_dst: &ClientId,
+        packet: Packet,
+    ) -> Result<Acknowledgement, RelayerError> {
+        let (src_chain, dst_chain) = self.pair_mut(src, dst);
+        relay_packet(
+            src_chain,
+            dst_chain,
+            client_id_on_src,
+            client_id_on_dst,
+            packet,
+        )
+    }
+
+    /// Relays the timeout of a packet sent by chain `src` that was never
+    /// received by chain `dst`.
+    pub fn relay_timeout(
+        &mut self,
+        src: usize,
+        dst: usize,
+        client_id_on_src: &ClientId,
+        packet: Packet,
+    ) -> Result<(), RelayerError> {
+        let (src_chain, dst_chain) = self.pair_mut(src, dst);
+        relay_timeout(src_chain, dst_chain, client_id_on_src, packet)
+    }
+
+    /// Checks the invariants of [`RelayerHarness::check_invariants`] on every
+    /// chain, matching each connection or channel end against the chain
+    /// tracked by its client. Ends whose client was not created through the
+    /// harness are skipped.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        for chain in &self.chains {
+            chain.ctx.validate()?;
+        }
+
+        for (host, chain) in self.chains.iter().enumerate() {
+            check_counterparty_ends(&chain.ctx, |client_id| {
+                self.counterparty_of(host, client_id)
+                    .map(|counterparty| &self.chains[counterparty].ctx)
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn tracked_chain(&self, host: usize, client_id: &ClientId) -> Result<usize, RelayerError> {
+        self.counterparty_of(host, client_id)
+            .ok_or_else(|| RelayerError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            })
+    }
+
+    /// Borrows two distinct chains mutably at once.
+    fn pair_mut(&mut self, a: usize, b: usize) -> (&mut HarnessChain, &mut HarnessChain) {
+        assert_ne!(a, b, "a chain cannot be its own counterparty");
+
+        if a < b {
+            let (left, right) = self.chains.split_at_mut(b);
+            (&mut left[a], &mut right[0])
+        } else {
+            let (left, right) = self.chains.split_at_mut(a);
+            (&mut right[0], &mut left[b])
+        }
+    }
+}
+
+/// Generates, from the Merkle store of `ctx`, the proof that `path` is present
+/// in its current IBC state.
+fn membership_proof(
+    ctx: &MockContext,
+    path: impl Into<Path>,
+) -> Result<CommitmentProofBytes, RelayerError> {
+    let path = path.into();
+    ctx.membership_proof(&path)
+        .ok_or_else(|| RelayerError::MissingProof {
+            path: path.to_string(),
+        })
+}
+
+/// Generates, from the Merkle store of `ctx`, the proof that `path` is absent
+/// from its current IBC state.
+fn non_membership_proof(
+    ctx: &MockContext,
+    path: impl Into<Path>,
+) -> Result<CommitmentProofBytes, RelayerError> {
+    let path = path.into();
+    ctx.non_membership_proof(&path)
+        .ok_or_else(|| RelayerError::MissingProof {
+            path: path.to_string(),
+        })
+}
+
+fn find_event<T>(
+    ctx: &MockContext,
+    event_type: &str,
+    f: impl Fn(&IbcEvent) -> Option<T>,
+) -> Result<T, RelayerError> {
+    ctx.events
+        .iter()
+        .rev()
+        .find_map(f)
+        .ok_or_else(|| RelayerError::MissingEvent {
+            event_type: event_type.to_string(),
+        })
+}
+
+fn create_client(
+    host: &mut HarnessChain,
+    counterparty: &HarnessChain,
+) -> Result<ClientId, RelayerError> {
+    let header = match counterparty.latest_block() {
+        HostBlock::Mock(header) => *header,
+        HostBlock::SyntheticTendermint(_) => unreachable!("harness chains are mock hosts"),
+    };
+
+    host.catch_up(counterparty);
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(header).into(),
+        MockConsensusState::new(header).into(),
+        host.ctx.signer(),
+    );
+    host.deliver(ClientMsg::CreateClient(msg))?;
+
+    find_event(&host.ctx, "create_client", |e| match e {
+        IbcEvent::CreateClient(e) => Some(e.client_id().clone()),
+        _ => None,
+    })
+}
+
+fn update_client(
+    host: &mut HarnessChain,
+    counterparty: &HarnessChain,
+    client_id: &ClientId,
+) -> Result<Height, RelayerError> {
+    let block = counterparty.latest_block();
+    let target_height = block.height();
+
+    let client_height = host.ctx.client_state(client_id)?.latest_height();
+    if client_height >= target_height {
+        return Ok(client_height);
+    }
+
+    host.catch_up(counterparty);
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: host.ctx.signer(),
+    };
+    host.deliver(ClientMsg::UpdateClient(msg))?;
+
+    Ok(target_height)
+}
+
+/// Runs the four steps of the connection handshake between the given
+/// clients, returning the identifiers of the connection on A and on B.
+fn connection_handshake(
+    a: &mut HarnessChain,
+    b: &mut HarnessChain,
+    client_id_on_a: &ClientId,
+    client_id_on_b: &ClientId,
+) -> Result<(ConnectionId, ConnectionId), RelayerError> {
+    // ConnOpenInit on A
+    let msg = MsgConnectionOpenInit {
+        client_id_on_a: client_id_on_a.clone(),
+        counterparty: ConnectionCounterparty::new(
+            client_id_on_b.clone(),
+            None,
+            b.ctx.commitment_prefix(),
+        ),
+        version: None,
+        delay_period: Duration::ZERO,
+        signer: a.ctx.signer(),
+    };
+    a.deliver(ConnectionMsg::OpenInit(msg))?;
+    let conn_id_on_a = find_event(&a.ctx, "connection_open_init", |e| match e {
+        IbcEvent::OpenInitConnection(e) => Some(e.conn_id_on_a().clone()),
+        _ => None,
+    })?;
+
+    // ConnOpenTry on B, caught up first so that updating the client of A
+    // does not push its consensus state seen by A out of its history
+    b.catch_up(a);
+    let consensus_height_of_b_on_a = update_client(a, b, client_id_on_a)?;
+    let proofs_height_on_a = update_client(b, a, client_id_on_b)?;
+    let client_state_of_b_on_a = a.ctx.client_state(client_id_on_a)?;
+    #[allow(deprecated)]
+    let msg = MsgConnectionOpenTry {
+        client_id_on_b: client_id_on_b.clone(),
+        client_state_of_b_on_a: client_state_of_b_on_a.into(),
+        counterparty: ConnectionCounterparty::new(
+            client_id_on_a.clone(),
+            Some(conn_id_on_a.clone()),
+            a.ctx.commitment_prefix(),
+        ),
+        versions_on_a: a.ctx.get_compatible_versions(),
+        proof_conn_end_on_a: membership_proof(&a.ctx, ConnectionPath::new(&conn_id_on_a))?,
+        proof_client_state_of_b_on_a: membership_proof(
+            &a.ctx,
+            ClientStatePath::new(client_id_on_a),
+        )?,
+        proof_consensus_state_of_b_on_a: membership_proof(
+            &a.ctx,
+            ClientConsensusStatePath::new(client_id_on_a, &consensus_height_of_b_on_a),
+        )?,
+        proofs_height_on_a,
+        consensus_height_of_b_on_a,
+        delay_period: Duration::ZERO,
+        signer: b.ctx.signer(),
+        proof_consensus_state_of_b: None,
+        previous_connection_id: String::new(),
+    };
+    b.deliver(ConnectionMsg::OpenTry(msg))?;
+    let conn_id_on_b = find_event(&b.ctx, "connection_open_try", |e| match e {
+        IbcEvent::OpenTryConnection(e) => Some(e.conn_id_on_b().clone()),
+        _ => None,
+    })?;
+
+    // ConnOpenAck on A, caught up first for the same reason
+    a.catch_up(b);
+    let consensus_height_of_a_on_b = update_client(b, a, client_id_on_b)?;
+    let proofs_height_on_b = update_client(a, b, client_id_on_a)?;
+    let client_state_of_a_on_b = b.ctx.client_state(client_id_on_b)?;
+    let version = b
+        .ctx
+        .connection_end(&conn_id_on_b)?
+        .versions()
+        .first()
+        .cloned()
+        .expect("connection end on B always has a version");
+    let msg = MsgConnectionOpenAck {
+        conn_id_on_a: conn_id_on_a.clone(),
+        conn_id_on_b: conn_id_on_b.clone(),
+        client_state_of_a_on_b: client_state_of_a_on_b.into(),
+        proof_conn_end_on_b: membership_proof(&b.ctx, ConnectionPath::new(&conn_id_on_b))?,
+        proof_client_state_of_a_on_b: membership_proof(
+            &b.ctx,
+            ClientStatePath::new(client_id_on_b),
+        )?,
+        proof_consensus_state_of_a_on_b: membership_proof(
+            &b.ctx,
+            ClientConsensusStatePath::new(client_id_on_b, &consensus_height_of_a_on_b),
+        )?,
+        proofs_height_on_b,
+        consensus_height_of_a_on_b,
+        version,
+        signer: a.ctx.signer(),
+        proof_consensus_state_of_a: None,
+    };
+    a.deliver(ConnectionMsg::OpenAck(msg))?;
+
+    // ConnOpenConfirm on B
+    let proof_height_on_a = update_client(b, a, client_id_on_b)?;
+    let msg = MsgConnectionOpenConfirm {
+        conn_id_on_b: conn_id_on_b.clone(),
+        proof_conn_end_on_a: membership_proof(&a.ctx, ConnectionPath::new(&conn_id_on_a))?,
+        proof_height_on_a,
+        signer: b.ctx.signer(),
+    };
+    b.deliver(ConnectionMsg::OpenConfirm(msg))?;
+
+    Ok((conn_id_on_a, conn_id_on_b))
+}
+
+/// Runs the four steps of the channel handshake on top of an open
+/// connection, returning the identifiers of the channel on A and on B.
+#[allow(clippy::too_many_arguments)]
+fn channel_handshake(
+    a: &mut HarnessChain,
+    b: &mut HarnessChain,
+    client_id_on_a: &ClientId,
+    client_id_on_b: &ClientId,
+    conn_id_on_a: &ConnectionId,
+    conn_id_on_b: &ConnectionId,
+    port_id_on_a: &PortId,
+    port_id_on_b: &PortId,
+    ordering: Order,
+    version: ChannelVersion,
+) -> Result<(ChannelId, ChannelId), RelayerError> {
+    // ChanOpenInit on A
+    let msg = MsgChannelOpenInit {
+        port_id_on_a: port_id_on_a.clone(),
+        connection_hops_on_a: vec![conn_id_on_a.clone()],
+        port_id_on_b: port_id_on_b.clone(),
+        ordering,
+        signer: a.ctx.signer(),
+        version_proposal: version,
+    };
+    a.deliver(ChannelMsg::OpenInit(msg))?;
+    let chan_id_on_a = find_event(&a.ctx, "channel_open_init", |e| match e {
+        IbcEvent::OpenInitChannel(e) => Some(e.chan_id_on_a().clone()),
+        _ => None,
+    })?;
+
+    // ChanOpenTry on B
+    let proof_height_on_a = update_client(b, a, client_id_on_b)?;
+    let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, &chan_id_on_a);
+    let version_on_a = a.ctx.channel_end(&chan_end_path_on_a)?.version().clone();
+    #[allow(deprecated)]
+    let msg = MsgChannelOpenTry {
+        port_id_on_b: port_id_on_b.clone(),
+        connection_hops_on_b: vec![conn_id_on_b.clone()],
+        port_id_on_a: port_id_on_a.clone(),
+        chan_id_on_a: chan_id_on_a.clone(),
+        version_supported_on_a: version_on_a,
+        proof_chan_end_on_a: membership_proof(&a.ctx, chan_end_path_on_a)?,
+        proof_height_on_a,
+        ordering,
+        signer: b.ctx.signer(),
+        version_proposal: ChannelVersion::empty(),
+    };
+    b.deliver(ChannelMsg::OpenTry(msg))?;
+    let chan_id_on_b = find_event(&b.ctx, "channel_open_try", |e| match e {
+        IbcEvent::OpenTryChannel(e) => Some(e.chan_id_on_b().clone()),
+        _ => None,
+    })?;
+
+    // ChanOpenAck on A
+    let proof_height_on_b = update_client(a, b, client_id_on_a)?;
+    let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, &chan_id_on_b);
+    let version_on_b = b.ctx.channel_end(&chan_end_path_on_b)?.version().clone();
+    let msg = MsgChannelOpenAck {
+        port_id_on_a: port_id_on_a.clone(),
+        chan_id_on_a: chan_id_on_a.clone(),
+        chan_id_on_b: chan_id_on_b.clone(),
+        version_on_b,
+        proof_chan_end_on_b: membership_proof(&b.ctx, chan_end_path_on_b)?,
+        proof_height_on_b,
+        signer: a.ctx.signer(),
+    };
+    a.deliver(ChannelMsg::OpenAck(msg))?;
+
+    // ChanOpenConfirm on B
+    let proof_height_on_a = update_client(b, a, client_id_on_b)?;
+    let msg = MsgChannelOpenConfirm {
+        port_id_on_b: port_id_on_b.clone(),
+        chan_id_on_b: chan_id_on_b.clone(),
+        proof_chan_end_on_a: membership_proof(
+            &a.ctx,
+            ChannelEndPath::new(port_id_on_a, &chan_id_on_a),
+        )?,
+        proof_height_on_a,
+        signer: b.ctx.signer(),
+    };
+    b.deliver(ChannelMsg::OpenConfirm(msg))?;
+
+    Ok((chan_id_on_a, chan_id_on_b))
+}
+
+fn send_on(host: &mut HarnessChain, packet: Packet) -> Result<(), RelayerError> {
+    send_packet(&mut host.ctx, packet).map_err(|e| RelayerError::TransactionFailed(e.into()))?;
+    host.ctx.advance_host_chain_height();
+
+    Ok(())
+}
+
+fn relay_packet(
+    src: &mut HarnessChain,
+    dst: &mut HarnessChain,
+    client_id_on_src: &ClientId,
+    client_id_on_dst: &ClientId,
+    packet: Packet,
+) -> Result<Acknowledgement, RelayerError> {
+    let proof_height_on_src = update_client(dst, src, client_id_on_dst)?;
+    let msg = MsgRecvPacket {
+        proof_commitment_on_a: membership_proof(
+            &src.ctx,
+            CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a),
+        )?,
+        proof_height_on_a: proof_height_on_src,
+        signer: dst.ctx.signer(),
+        packet: packet.clone(),
+    };
+    dst.deliver(PacketMsg::Recv(msg))?;
+
+    let acknowledgement = find_event(&dst.ctx, "write_acknowledgement", |e| match e {
+        IbcEvent::WriteAcknowledgement(e)
+            if e.port_id_on_b() == &packet.port_id_on_b
+                && e.chan_id_on_b() == &packet.chan_id_on_b
+                && e.seq_on_a() == &packet.seq_on_a =>
+        {
+            Some(e.acknowledgement().clone())
+        }
+        _ => None,
+    })?;
+
+    let proof_height_on_dst = update_client(src, dst, client_id_on_src)?;
+    let msg = MsgAcknowledgement {
+        proof_acked_on_b: membership_proof(
+            &dst.ctx,
+            AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a),
+        )?,
+        proof_height_on_b: proof_height_on_dst,
+        signer: src.ctx.signer(),
+        acknowledgement: acknowledgement.clone(),
+        packet,
+    };
+    src.deliver(PacketMsg::Ack(msg))?;
+
+    Ok(acknowledgement)
+}
+
+fn relay_timeout(
+    src: &mut HarnessChain,
+    dst: &HarnessChain,
+    client_id_on_src: &ClientId,
+    packet: Packet,
+) -> Result<(), RelayerError> {
+    let proof_height_on_dst = update_client(src, dst, client_id_on_src)?;
+    let next_seq_recv_on_b = dst.ctx.get_next_sequence_recv(&SeqRecvPath::new(
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+    ))?;
+    let proof_unreceived_on_b = match unreceived_packet_path(&src.ctx, &packet) {
+        Path::ReceiptBitmap(bitmap_path) => match dst.ctx.receipt_bitmap(&bitmap_path)? {
+            Some(bitmap) => ReceiptBitmapProof::Present {
+                bitmap,
+                proof: membership_proof(&dst.ctx, bitmap_path)?,
+            },
+            None => ReceiptBitmapProof::Absent(non_membership_proof(&dst.ctx, bitmap_path)?),
+        }
+        .into(),
+        path => non_membership_proof(&dst.ctx, path)?,
+    };
+    let msg = MsgTimeout {
+        next_seq_recv_on_b,
+        proof_unreceived_on_b,
+        proof_height_on_b: proof_height_on_dst,
+        signer: src.ctx.signer(),
+        packet,
+    };
+    src.deliver(PacketMsg::Timeout(msg))
+}
+
+/// Checks the connection and channel ends of `host` against those of the
+/// chain returned by `counterparty_of` for the client they are built on. Ends
+/// whose counterparty chain is unknown are skipped.
+fn check_counterparty_ends<'a>(
+    host: &MockContext,
+    counterparty_of: impl Fn(&ClientId) -> Option<&'a MockContext>,
+) -> Result<(), String> {
+    let store = host.ibc_store.lock();
+
+    for (conn_id, conn_end) in store.connections.iter() {
+        if *conn_end.state() != ConnectionState::Open {
+            continue;
+        }
+        let counterparty = match counterparty_of(conn_end.client_id()) {
+            Some(counterparty) => counterparty,
+            None => continue,
+        };
+        let cp_conn_id = match conn_end.counterparty().connection_id() {
+            Some(cp_conn_id) => cp_conn_id,
+            None => return Err(format!("open connection {conn_id} has no counterparty")),
+        };
+        let cp_conn_end = counterparty
+            .connection_end(cp_conn_id)
+            .map_err(|e| format!("counterparty of connection {conn_id}: {e}"))?;
+        if !matches!(
+            cp_conn_end.state(),
+            ConnectionState::TryOpen | ConnectionState::Open
+        ) || cp_conn_end.counterparty().connection_id() != Some(conn_id)
+            || cp_conn_end.counterparty().client_id() != conn_end.client_id()
+        {
+            return Err(format!(
+                "connection {conn_id} and counterparty {cp_conn_id} do not match"
+            ));
+        }
+    }
+
+    for (port_id, channels) in store.channels.iter() {
+        for (chan_id, chan_end) in channels.iter() {
+            if *chan_end.state() != ChannelState::Open {
+                continue;
+            }
+            let counterparty = match chan_end
+                .connection_hops()
+                .first()
+                .and_then(|conn_id| store.connections.get(conn_id))
+                .and_then(|conn_end| counterparty_of(conn_end.client_id()))
+            {
+                Some(counterparty) => counterparty,
+                None => continue,
+            };
+            let cp_port_id = chan_end.counterparty().port_id();
+            let cp_chan_id = match chan_end.counterparty().channel_id() {
+                Some(cp_chan_id) => cp_chan_id,
+                None => {
+                    return Err(format!(
+                        "open channel {port_id}/{chan_id} has no counterparty"
+                    ))
+                }
+            };
+            let cp_chan_end = counterparty
+                .channel_end(&ChannelEndPath::new(cp_port_id, cp_chan_id))
+                .map_err(|e| format!("counterparty of channel {port_id}/{chan_id}: {e}"))?;
+            if !matches!(
+                cp_chan_end.state(),
+                ChannelState::TryOpen | ChannelState::Open
+            ) || cp_chan_end.counterparty().port_id() != port_id
+                || cp_chan_end.counterparty().channel_id() != Some(chan_id)
+            {
+                return Err(format!(
+                    "channel {port_id}/{chan_id} and counterparty {cp_port_id}/{cp_chan_id} do not match"
+                ));
+            }
+
+            if *chan_end.ordering() == Order::Ordered {
+                let next_seq_recv = store
+                    .next_sequence_recv
+                    .get(port_id)
+                    .and_then(|m| m.get(chan_id))
+                    .copied();
+                let cp_next_seq_send = counterparty
+                    .ibc_store
+                    .lock()
+                    .next_sequence_send
+                    .get(cp_port_id)
+                    .and_then(|m| m.get(cp_chan_id))
+                    .copied();
+                if let (Some(recv), Some(send)) = (next_seq_recv, cp_next_seq_send) {
+                    if recv > send {
+                        return Err(format!(
+                            "channel {port_id}/{chan_id} received {recv} packets but only {send} were sent"
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::ics04_channel::packet::Sequence;
+    use ibc::core::ics04_channel::timeout::TimeoutHeight;
+    use ibc::core::ics24_host::identifier::ChainId;
+    use ibc::core::timestamp::Timestamp;
+    use test_log::test;
+
+    use super::*;
+
+    fn harness() -> RelayerHarness {
+        let ctx_a = MockContext::new(
+            ChainId::new("mockgaiaA-1").unwrap(),
+            HostType::Mock,
+            5,
+            Height::new(1, 10).unwrap(),
+        );
+        let ctx_b = MockContext::new(
+            ChainId::new("mockgaiaB-1").unwrap(),
+            HostType::Mock,
+            5,
+            Height::new(1, 20).unwrap(),
+        );
+
+        RelayerHarness::new(
+            HarnessChain::new(ctx_a, MockRouter::new_with_transfer()),
+            HarnessChain::new(ctx_b, MockRouter::new_with_transfer()),
+        )
+    }
+
+    #[test]
+    fn packet_round_trip() {
+        let mut harness = harness();
+
+        let (client_id_on_a, client_id_on_b, conn_id_on_a, conn_id_on_b) =
+            harness.setup_connection().unwrap();
+        harness.check_invariants().unwrap();
+
+        let (chan_id_on_a, chan_id_on_b) = harness
+            .channel_handshake(
+                &client_id_on_a,
+                &client_id_on_b,
+                &conn_id_on_a,
+                &conn_id_on_b,
+                &PortId::transfer(),
+                &PortId::transfer(),
+                Order::Unordered,
+                ChannelVersion::new("ics20-1".to_string()),
+            )
+            .unwrap();
+        harness.check_invariants().unwrap();
+
+        let packet = Packet {
+            seq_on_a: Sequence::from(1),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: chan_id_on_a.clone(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: chan_id_on_b.clone(),
+            data: b"ping".to_vec(),
+            timeout_height_on_b: TimeoutHeight::no_timeout(),
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+        harness.send_packet_on_a(packet.clone()).unwrap();
+
+        let ack = harness
+            .relay_packet_a_to_b(&client_id_on_a, &client_id_on_b, packet.clone())
+            .unwrap();
+        assert_eq!(ack.as_bytes(), &[1u8]);
+
+        let commitment_path = CommitmentPath::new(&packet.port_id_on_a, &chan_id_on_a, 1.into());
+        assert!(harness
+            .chain_a
+            .ctx
+            .get_packet_commitment(&commitment_path)
+            .is_err());
+        harness.check_invariants().unwrap();
+    }
+
+    #[test]
+    fn packet_timeout() {
+        let mut harness = harness();
+
+        let (client_id_on_a, client_id_on_b, conn_id_on_a, conn_id_on_b) =
+            harness.setup_connection().unwrap();
+        let (chan_id_on_a, chan_id_on_b) = harness
+            .channel_handshake(
+                &client_id_on_a,
+                &client_id_on_b,
+                &conn_id_on_a,
+                &conn_id_on_b,
+                &PortId::transfer(),
+                &PortId::transfer(),
+                Order::Unordered,
+                ChannelVersion::new("ics20-1".to_string()),
+            )
+            .unwrap();
+
+        let timeout_height = harness.chain_b.ctx.latest_height().add(2);
+        let packet = Packet {
+            seq_on_a: Sequence::from(1),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a,
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b,
+            data: b"ping".to_vec(),
+            timeout_height_on_b: TimeoutHeight::At(timeout_height),
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+        harness.send_packet_on_a(packet.clone()).unwrap();
+
+        for _ in 0..3 {
+            harness.chain_b.ctx.advance_host_chain_height();
+        }
+
+        harness
+            .relay_timeout_a_to_b(&client_id_on_a, packet)
+            .unwrap();
+        harness.check_invariants().unwrap();
+    }
+
+    #[test]
+    fn three_chain_forwarding() {
+        const A: usize = 0;
+        const B: usize = 1;
+        const C: usize = 2;
+
+        let chain = |chain_id: &str, height: u64, block_time_secs: u64| {
+            let mut ctx = MockContext::new(
+                ChainId::new(chain_id).unwrap(),
+                HostType::Mock,
+                5,
+                Height::new(1, height).unwrap(),
+            );
+            ctx.block_time = Duration::from_secs(block_time_secs);
+            HarnessChain::new(ctx, MockRouter::new_with_transfer())
+        };
+        let mut harness = MultiChainHarness::new(vec![
+            chain("mockgaiaA-1", 10, 1),
+            chain("mockgaiaB-1", 20, 3),
+            chain("mockgaiaC-1", 30, 7),
+        ]);
+
+        let open_channel = |harness: &mut MultiChainHarness, a: usize, b: usize| {
+            let (client_id_on_a, client_id_on_b, conn_id_on_a, conn_id_on_b) =
+                harness.setup_connection(a, b).unwrap();
+            let (chan_id_on_a, chan_id_on_b) = harness
+                .channel_handshake(
+                    a,
+                    b,
+                    &client_id_on_a,
+                    &client_id_on_b,
+                    &conn_id_on_a,
+                    &conn_id_on_b,
+                    &PortId::transfer(),
+                    &PortId::transfer(),
+                    Order::Unordered,
+                    ChannelVersion::new("ics20-1".to_string()),
+                )
+                .unwrap();
+            (client_id_on_a, client_id_on_b, chan_id_on_a, chan_id_on_b)
+        };
+        let (client_id_on_a, client_id_of_a_on_b, chan_id_on_a, chan_id_of_a_on_b) =
+            open_channel(&mut harness, A, B);
+        let (client_id_of_c_on_b, client_id_on_c, chan_id_of_c_on_b, chan_id_on_c) =
+            open_channel(&mut harness, B, C);
+        harness.check_invariants().unwrap();
+        assert_eq!(harness.counterparty_of(B, &client_id_of_c_on_b), Some(C));
+
+        // Chains only move forward when they are involved.
+        let heights = |harness: &MultiChainHarness| {
+            harness
+                .chains
+                .iter()
+                .map(|chain| chain.ctx.latest_height())
+                .collect::<Vec<_>>()
+        };
+        let before = heights(&harness);
+        harness.advance_chain(C, 4);
+        let after = heights(&harness);
+        assert_eq!(after[A], before[A]);
+        assert_eq!(after[B], before[B]);
+        assert_eq!(after[C], before[C].add(4));
+
+        let packet = |seq: u64, chan_id_on_a: &ChannelId, chan_id_on_b: &ChannelId| Packet {
+            seq_on_a: Sequence::from(seq),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: chan_id_on_a.clone(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: chan_id_on_b.clone(),
+            data: b"ping".to_vec(),
+            timeout_height_on_b: TimeoutHeight::no_timeout(),
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+
+        // A sends to B, which forwards to C.
+        let packet_a_to_b = packet(1, &chan_id_on_a, &chan_id_of_a_on_b);
+        harness.send_packet(A, packet_a_to_b.clone()).unwrap();
+        harness
+            .relay_packet(A, B, &client_id_on_a, &client_id_of_a_on_b, packet_a_to_b)
+            .unwrap();
+
+        let packet_b_to_c = packet(1, &chan_id_of_c_on_b, &chan_id_on_c);
+        harness.send_packet(B, packet_b_to_c.clone()).unwrap();
+        let ack = harness
+            .relay_packet(B, C, &client_id_of_c_on_b, &client_id_on_c, packet_b_to_c)
+            .unwrap();
+        assert_eq!(ack.as_bytes(), &[1u8]);
+
+        harness.check_invariants().unwrap();
+    }
+}
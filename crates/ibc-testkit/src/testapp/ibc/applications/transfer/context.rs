@@ -5,18 +5,24 @@ use ibc::applications::transfer::error::TokenTransferError;
 use ibc::applications::transfer::PrefixedCoin;
 use ibc::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc::prelude::*;
-use ibc::Signer;
+use ibc::{Signer, TryFromSignerResolver};
 use subtle_encoding::bech32;
 
 use super::types::DummyTransferModule;
 
 impl TokenTransferValidationContext for DummyTransferModule {
     type AccountId = Signer;
+    type AccountResolver = TryFromSignerResolver<Signer>;
 
     fn get_port(&self) -> Result<PortId, TokenTransferError> {
         Ok(PortId::transfer())
     }
 
+    fn account_resolver(&self) -> &Self::AccountResolver {
+        static ACCOUNT_RESOLVER: TryFromSignerResolver<Signer> = TryFromSignerResolver::new();
+        &ACCOUNT_RESOLVER
+    }
+
     fn get_escrow_account(
         &self,
         port_id: &PortId,
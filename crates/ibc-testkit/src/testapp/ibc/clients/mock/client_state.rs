@@ -1,4 +1,3 @@
-use core::str::FromStr;
 use core::time::Duration;
 
 use ibc::core::ics02_client::client_state::{
@@ -18,6 +17,7 @@ use ibc::prelude::*;
 use ibc::proto::mock::ClientState as RawMockClientState;
 use ibc::proto::{Any, Protobuf};
 use ibc::Height;
+use typed_builder::TypedBuilder;
 
 use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
@@ -28,7 +28,29 @@ pub const MOCK_CLIENT_STATE_TYPE_URL: &str = "/ibc.mock.ClientState";
 pub const MOCK_CLIENT_TYPE: &str = "9999-mock";
 
 pub fn client_type() -> ClientType {
-    ClientType::from_str(MOCK_CLIENT_TYPE).expect("never fails because it's valid client type")
+    ibc::client_type!(MOCK_CLIENT_TYPE)
+}
+
+/// Behaviour programmed into a [`MockClientState`], overriding the one it
+/// derives from its header and consensus states, for negative tests of the
+/// handlers relying on the client.
+///
+/// It is not part of the encoding of the client state, so it is lost whenever
+/// the client state goes through an `Any`, but it survives client updates.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, TypedBuilder)]
+pub struct MockClientBehavior {
+    /// The status reported by the client, whatever its consensus states.
+    #[builder(default, setter(strip_option))]
+    pub status: Option<Status>,
+    /// Whether the verification of membership and non-membership proofs
+    /// fails.
+    #[builder(default)]
+    pub fail_proof_verification: bool,
+    /// The latest height reported by the client, instead of that of its
+    /// header. Unless `status` is set, the client is expired if it has no
+    /// consensus state at that height.
+    #[builder(default, setter(strip_option))]
+    pub latest_height: Option<Height>,
 }
 
 /// A mock of a client state. For an example of a real structure that this mocks, you can see
@@ -39,6 +61,8 @@ pub fn client_type() -> ClientType {
 pub struct MockClientState {
     pub header: MockHeader,
     pub frozen_height: Option<Height>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub behavior: MockClientBehavior,
 }
 
 impl MockClientState {
@@ -46,11 +70,14 @@ impl MockClientState {
         Self {
             header,
             frozen_height: None,
+            behavior: MockClientBehavior::default(),
         }
     }
 
     pub fn latest_height(&self) -> Height {
-        self.header.height()
+        self.behavior
+            .latest_height
+            .unwrap_or_else(|| self.header.height())
     }
 
     pub fn refresh_time(&self) -> Option<Duration> {
@@ -64,6 +91,10 @@ impl MockClientState {
         }
     }
 
+    pub fn with_behavior(self, behavior: MockClientBehavior) -> Self {
+        Self { behavior, ..self }
+    }
+
     pub fn is_frozen(&self) -> bool {
         self.frozen_height.is_some()
     }
@@ -71,6 +102,15 @@ impl MockClientState {
     fn expired(&self, _elapsed: Duration) -> bool {
         false
     }
+
+    fn programmed_proof_verification(&self) -> Result<(), ClientError> {
+        if self.behavior.fail_proof_verification {
+            return Err(ClientError::ClientSpecific {
+                description: "mock client programmed to fail proof verification".to_string(),
+            });
+        }
+        Ok(())
+    }
 }
 
 impl Protobuf<RawMockClientState> for MockClientState {}
@@ -163,7 +203,7 @@ impl ClientStateCommon for MockClientState {
     }
 
     fn latest_height(&self) -> Height {
-        self.header.height()
+        MockClientState::latest_height(self)
     }
 
     fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
@@ -203,7 +243,7 @@ impl ClientStateCommon for MockClientState {
         _path: Path,
         _value: Vec<u8>,
     ) -> Result<(), ClientError> {
-        Ok(())
+        self.programmed_proof_verification()
     }
 
     fn verify_non_membership(
@@ -213,7 +253,17 @@ impl ClientStateCommon for MockClientState {
         _root: &CommitmentRoot,
         _path: Path,
     ) -> Result<(), ClientError> {
-        Ok(())
+        self.programmed_proof_verification()
+    }
+
+    fn evidence_heights(&self, client_message: &Any) -> Vec<Height> {
+        if let Ok(misbehaviour) = Misbehaviour::try_from(client_message.clone()) {
+            vec![misbehaviour.header1.height(), misbehaviour.header2.height()]
+        } else if let Ok(header) = MockHeader::try_from(client_message.clone()) {
+            vec![header.height()]
+        } else {
+            Vec::new()
+        }
     }
 }
 
@@ -272,6 +322,10 @@ where
     }
 
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if let Some(status) = self.behavior.status {
+            return Ok(status);
+        }
+
         if self.is_frozen() {
             return Ok(Status::Frozen);
         }
@@ -320,7 +374,7 @@ where
 
         ctx.store_client_state(ClientStatePath::new(client_id), (*self).into())?;
         ctx.store_consensus_state(
-            ClientConsensusStatePath::new(client_id, &self.latest_height()),
+            ClientConsensusStatePath::new(client_id, &self.header.height()),
             mock_consensus_state.into(),
         )?;
 
@@ -336,11 +390,11 @@ where
         let header = MockHeader::try_from(header)?;
         let header_height = header.height;
 
-        let new_client_state = MockClientState::new(header);
+        let new_client_state = MockClientState::new(header).with_behavior(self.behavior);
         let new_consensus_state = MockConsensusState::new(header);
 
         ctx.store_consensus_state(
-            ClientConsensusStatePath::new(client_id, &new_client_state.latest_height()),
+            ClientConsensusStatePath::new(client_id, &header_height),
             new_consensus_state.into(),
         )?;
         ctx.store_client_state(ClientStatePath::new(client_id), new_client_state.into())?;
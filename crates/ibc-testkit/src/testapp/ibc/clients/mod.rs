@@ -10,6 +10,7 @@ use ibc::clients::ics07_tendermint::consensus_state::{
 use ibc::core::ics02_client::client_state::ClientState;
 use ibc::core::ics02_client::consensus_state::ConsensusState;
 use ibc::core::ics02_client::error::ClientError;
+use ibc::core::ics02_client::registry::ClientTypeRegistry;
 use ibc::prelude::*;
 use ibc::proto::{Any, Protobuf};
 
@@ -21,6 +22,28 @@ use crate::testapp::ibc::clients::mock::consensus_state::{
 };
 use crate::testapp::ibc::core::types::MockContext;
 
+/// Returns the registry of the client types supported by the mock context.
+pub fn client_type_registry() -> ClientTypeRegistry<AnyClientState, AnyConsensusState> {
+    ClientTypeRegistry::new()
+        .with_client::<TmClientState, TmConsensusState>(
+            TENDERMINT_CLIENT_STATE_TYPE_URL,
+            TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+        )
+        .with_client_state_decoder(TENDERMINT_CLIENT_STATE_TYPE_URL, decode_tm_client_state)
+        .with_client::<MockClientState, MockConsensusState>(
+            MOCK_CLIENT_STATE_TYPE_URL,
+            MOCK_CONSENSUS_STATE_TYPE_URL,
+        )
+}
+
+/// Decodes a Tendermint client state, rejecting those whose parameters are
+/// invalid.
+fn decode_tm_client_state(client_state: Any) -> Result<AnyClientState, ClientError> {
+    let client_state = TmClientState::try_from(client_state)?;
+    client_state.validate().map_err(ClientError::from)?;
+    Ok(client_state.into())
+}
+
 #[derive(Debug, Clone, From, PartialEq, ClientState)]
 #[generics(ClientValidationContext = MockContext,
            ClientExecutionContext = MockContext)
@@ -36,15 +59,7 @@ impl TryFrom<Any> for AnyClientState {
     type Error = ClientError;
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
-        if raw.type_url == TENDERMINT_CLIENT_STATE_TYPE_URL {
-            TmClientState::try_from(raw).map(Into::into)
-        } else if raw.type_url == MOCK_CLIENT_STATE_TYPE_URL {
-            MockClientState::try_from(raw).map(Into::into)
-        } else {
-            Err(ClientError::Other {
-                description: "failed to deserialize message".to_string(),
-            })
-        }
+        client_type_registry().decode_client_state(raw)
     }
 }
 
@@ -69,15 +84,7 @@ impl TryFrom<Any> for AnyConsensusState {
     type Error = ClientError;
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
-        if raw.type_url == TENDERMINT_CONSENSUS_STATE_TYPE_URL {
-            TmConsensusState::try_from(raw).map(Into::into)
-        } else if raw.type_url == MOCK_CONSENSUS_STATE_TYPE_URL {
-            MockConsensusState::try_from(raw).map(Into::into)
-        } else {
-            Err(ClientError::Other {
-                description: "failed to deserialize message".to_string(),
-            })
-        }
+        client_type_registry().decode_consensus_state(raw)
     }
 }
 
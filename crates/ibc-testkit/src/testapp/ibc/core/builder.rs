@@ -0,0 +1,451 @@
+//! Declarative set-up of a [`MockContext`] with clients, connections and
+//! channels in arbitrary states.
+
+use core::time::Duration;
+
+use ibc::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::channel::{
+    ChannelEnd, Counterparty as ChannelCounterparty, Order, State as ChannelState,
+};
+use ibc::core::ics04_channel::commitment::compute_packet_commitment;
+use ibc::core::ics04_channel::packet::{Packet, Sequence};
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::ics04_channel::Version as ChannelVersion;
+use ibc::core::ics23_commitment::commitment::CommitmentPrefix;
+use ibc::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
+use ibc::prelude::*;
+use ibc::Height;
+use typed_builder::TypedBuilder;
+
+use super::types::{MockClientConfig, MockContext};
+use crate::hosts::block::HostType;
+
+/// Describes a connection end stored by [`TestChainBuilder`].
+///
+/// Unless the connection is in `Init`, its counterparty connection carries the
+/// same identifier as the local one.
+#[derive(Debug, TypedBuilder)]
+pub struct ConnectionConfig {
+    conn_id: ConnectionId,
+    client_id: ClientId,
+    #[builder(default = ConnectionState::Open)]
+    state: ConnectionState,
+    #[builder(default)]
+    counterparty_client_id: ClientId,
+    #[builder(default = ZERO_DURATION)]
+    delay_period: Duration,
+}
+
+impl ConnectionConfig {
+    fn connection_end(&self) -> ConnectionEnd {
+        let counterparty_conn_id = match self.state {
+            ConnectionState::Uninitialized | ConnectionState::Init => None,
+            _ => Some(self.conn_id.clone()),
+        };
+
+        ConnectionEnd::new(
+            self.state,
+            self.client_id.clone(),
+            ConnectionCounterparty::new(
+                self.counterparty_client_id.clone(),
+                counterparty_conn_id,
+                CommitmentPrefix::try_from(b"ibc".to_vec()).expect("Never fails"),
+            ),
+            get_compatible_versions(),
+            self.delay_period,
+        )
+        .expect("Never fails")
+    }
+}
+
+/// Describes a channel end stored by [`TestChainBuilder`], along with its
+/// sequences and in-flight packets.
+///
+/// Unless the channel is in `Init`, its counterparty is bound to the same port
+/// and channel identifiers as the local end.
+#[derive(Debug, TypedBuilder)]
+pub struct ChannelConfig {
+    #[builder(default = PortId::transfer())]
+    port_id: PortId,
+    chan_id: ChannelId,
+    conn_id: ConnectionId,
+    #[builder(default = ChannelState::Open)]
+    state: ChannelState,
+    #[builder(default = Order::Unordered)]
+    ordering: Order,
+    #[builder(default = ChannelVersion::empty())]
+    version: ChannelVersion,
+    /// Number of packets sent on the channel whose acknowledgement has not
+    /// been received yet. Their commitments are stored for sequences
+    /// `1..=unacked_packets` (see [`unacked_packet`]).
+    #[builder(default)]
+    unacked_packets: u64,
+}
+
+impl ChannelConfig {
+    fn channel_end(&self) -> ChannelEnd {
+        let counterparty_chan_id = match self.state {
+            ChannelState::Uninitialized | ChannelState::Init => None,
+            _ => Some(self.chan_id.clone()),
+        };
+
+        ChannelEnd::new(
+            self.state,
+            self.ordering,
+            ChannelCounterparty::new(self.port_id.clone(), counterparty_chan_id),
+            vec![self.conn_id.clone()],
+            self.version.clone(),
+        )
+        .expect("channel config must describe a valid channel end")
+    }
+}
+
+/// Returns the packet with sequence `seq` that [`TestChainBuilder`] records
+/// as sent, but not yet acknowledged, on the given channel. It times out at a
+/// height of the counterparty that tests never reach.
+pub fn unacked_packet(port_id: &PortId, chan_id: &ChannelId, seq: Sequence) -> Packet {
+    Packet {
+        seq_on_a: seq,
+        port_id_on_a: port_id.clone(),
+        chan_id_on_a: chan_id.clone(),
+        port_id_on_b: port_id.clone(),
+        chan_id_on_b: chan_id.clone(),
+        data: format!("packet-{seq}").into_bytes(),
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 1_000_000).expect("Never fails")),
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+/// Builds a [`MockContext`] from a declarative description of its IBC state,
+/// e.g. "a chain with a client at height 20, a connection in `TryOpen` and an
+/// open channel with 3 unacknowledged packets":
+///
+/// ```
+/// # use ibc::core::ics03_connection::connection::State as ConnectionState;
+/// # use ibc::core::ics04_channel::channel::State as ChannelState;
+/// # use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId};
+/// # use ibc::Height;
+/// # use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type;
+/// # use ibc_testkit::testapp::ibc::core::builder::{ChannelConfig, TestChainBuilder};
+/// let client_id = ClientId::new(client_type(), 0).unwrap();
+///
+/// let ctx = TestChainBuilder::default()
+///     .client(client_id.clone(), Height::new(0, 20).unwrap())
+///     .connection(ConnectionId::new(0), client_id, ConnectionState::TryOpen)
+///     .channel_config(
+///         ChannelConfig::builder()
+///             .chan_id(ChannelId::new(0))
+///             .conn_id(ConnectionId::new(0))
+///             .state(ChannelState::Open)
+///             .unacked_packets(3)
+///             .build(),
+///     )
+///     .build();
+/// ```
+///
+/// Identifiers are never generated: the resulting state only depends on the
+/// description. The identifier counters of the context count the described
+/// clients, connections and channels, as if they had been created by the
+/// handlers.
+#[derive(Debug)]
+pub struct TestChainBuilder {
+    chain_id: ChainId,
+    host_type: HostType,
+    max_history_size: u64,
+    latest_height: Height,
+    clients: Vec<MockClientConfig>,
+    connections: Vec<ConnectionConfig>,
+    channels: Vec<ChannelConfig>,
+}
+
+/// Describes the same host chain as [`MockContext::default`], without any IBC
+/// state.
+impl Default for TestChainBuilder {
+    fn default() -> Self {
+        Self::new(ChainId::new("mockgaia-0").expect("Never fails"))
+    }
+}
+
+impl TestChainBuilder {
+    /// Describes a mock host chain at height 5 of the revision in `chain_id`.
+    pub fn new(chain_id: ChainId) -> Self {
+        let latest_height = Height::new(chain_id.revision_number(), 5).expect("Never fails");
+
+        Self {
+            chain_id,
+            host_type: HostType::Mock,
+            max_history_size: 5,
+            latest_height,
+            clients: Vec::new(),
+            connections: Vec::new(),
+            channels: Vec::new(),
+        }
+    }
+
+    pub fn host_type(mut self, host_type: HostType) -> Self {
+        self.host_type = host_type;
+        self
+    }
+
+    pub fn max_history_size(mut self, max_history_size: u64) -> Self {
+        self.max_history_size = max_history_size;
+        self
+    }
+
+    pub fn latest_height(mut self, latest_height: Height) -> Self {
+        self.latest_height = latest_height;
+        self
+    }
+
+    /// Adds a mock client whose latest height is `height`.
+    pub fn client(self, client_id: ClientId, height: Height) -> Self {
+        let client = MockClientConfig::builder()
+            .client_chain_id(self.chain_id.clone())
+            .client_id(client_id)
+            .client_state_height(height)
+            .build();
+
+        self.client_config(client)
+    }
+
+    pub fn client_config(mut self, client: MockClientConfig) -> Self {
+        self.clients.push(client);
+        self
+    }
+
+    /// Adds a connection in the given `state`, built on top of `client_id`.
+    pub fn connection(
+        self,
+        conn_id: ConnectionId,
+        client_id: ClientId,
+        state: ConnectionState,
+    ) -> Self {
+        let connection = ConnectionConfig::builder()
+            .conn_id(conn_id)
+            .client_id(client_id)
+            .state(state)
+            .build();
+
+        self.connection_config(connection)
+    }
+
+    pub fn connection_config(mut self, connection: ConnectionConfig) -> Self {
+        self.connections.push(connection);
+        self
+    }
+
+    /// Adds an unordered channel in the given `state` on the transfer port,
+    /// running over `conn_id`.
+    pub fn channel(self, chan_id: ChannelId, conn_id: ConnectionId, state: ChannelState) -> Self {
+        let channel = ChannelConfig::builder()
+            .chan_id(chan_id)
+            .conn_id(conn_id)
+            .state(state)
+            .build();
+
+        self.channel_config(channel)
+    }
+
+    pub fn channel_config(mut self, channel: ChannelConfig) -> Self {
+        self.channels.push(channel);
+        self
+    }
+
+    /// Builds the described context.
+    pub fn build(self) -> MockContext {
+        let (n_clients, n_connections, n_channels) = (
+            self.clients.len() as u64,
+            self.connections.len() as u64,
+            self.channels.len() as u64,
+        );
+
+        let mut ctx = MockContext::new(
+            self.chain_id,
+            self.host_type,
+            self.max_history_size,
+            self.latest_height,
+        );
+
+        for client in self.clients {
+            ctx = ctx.with_client_config(client);
+        }
+
+        for connection in self.connections {
+            let conn_end = connection.connection_end();
+            ctx = ctx.with_connection(connection.conn_id, conn_end);
+        }
+
+        for channel in self.channels {
+            let chan_end = channel.channel_end();
+            let port_id = channel.port_id;
+            let chan_id = channel.chan_id;
+
+            ctx = ctx
+                .with_channel(port_id.clone(), chan_id.clone(), chan_end)
+                .with_send_sequence(
+                    port_id.clone(),
+                    chan_id.clone(),
+                    Sequence::from(channel.unacked_packets + 1),
+                )
+                .with_recv_sequence(port_id.clone(), chan_id.clone(), Sequence::from(1))
+                .with_ack_sequence(port_id.clone(), chan_id.clone(), Sequence::from(1));
+
+            for seq in 1..=channel.unacked_packets {
+                let packet = unacked_packet(&port_id, &chan_id, Sequence::from(seq));
+                let commitment = compute_packet_commitment(
+                    &packet.data,
+                    &packet.timeout_height_on_b,
+                    &packet.timeout_timestamp_on_b,
+                );
+                ctx = ctx.with_packet_commitment(
+                    port_id.clone(),
+                    chan_id.clone(),
+                    packet.seq_on_a,
+                    commitment,
+                );
+            }
+        }
+
+        // The described clients were last updated in the latest block.
+        let latest_block = ctx.query_latest_header().expect("Never fails");
+
+        {
+            let mut ibc_store = ctx.ibc_store.lock();
+            let consensus_keys: Vec<_> = ibc_store
+                .clients
+                .iter()
+                .flat_map(|(client_id, record)| {
+                    record
+                        .consensus_states
+                        .keys()
+                        .map(move |height| (client_id.clone(), *height))
+                })
+                .collect();
+            for key in consensus_keys {
+                ibc_store
+                    .client_processed_times
+                    .insert(key.clone(), latest_block.timestamp());
+                ibc_store
+                    .client_processed_heights
+                    .insert(key, latest_block.height());
+            }
+
+            ibc_store.client_ids_counter = n_clients;
+            ibc_store.connection_ids_counter = n_connections;
+            ibc_store.channel_ids_counter = n_channels;
+        }
+
+        ctx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::ics02_client::client_state::ClientStateCommon;
+    use ibc::core::ics04_channel::commitment::PacketCommitment;
+    use ibc::core::ics24_host::path::{
+        ChannelEndPath, CommitmentPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+    };
+    use ibc::core::ValidationContext;
+
+    use super::*;
+    use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+
+    #[test]
+    fn builds_described_state() {
+        let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+        let conn_id = ConnectionId::new(0);
+        let port_id = PortId::transfer();
+        let chan_id = ChannelId::new(0);
+
+        let ctx = TestChainBuilder::default()
+            .client(client_id.clone(), Height::new(0, 20).unwrap())
+            .connection(conn_id.clone(), client_id.clone(), ConnectionState::TryOpen)
+            .channel_config(
+                ChannelConfig::builder()
+                    .chan_id(chan_id.clone())
+                    .conn_id(conn_id.clone())
+                    .unacked_packets(3)
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(
+            ctx.client_state(&client_id).unwrap().latest_height(),
+            Height::new(0, 20).unwrap()
+        );
+
+        let conn_end = ctx.connection_end(&conn_id).unwrap();
+        assert_eq!(conn_end.state(), &ConnectionState::TryOpen);
+        assert_eq!(conn_end.client_id(), &client_id);
+
+        let chan_end = ctx
+            .channel_end(&ChannelEndPath::new(&port_id, &chan_id))
+            .unwrap();
+        assert!(chan_end.is_open());
+        assert_eq!(chan_end.connection_hops(), &vec![conn_id]);
+
+        assert_eq!(
+            ctx.get_next_sequence_send(&SeqSendPath::new(&port_id, &chan_id))
+                .unwrap(),
+            Sequence::from(4)
+        );
+        assert_eq!(
+            ctx.get_next_sequence_recv(&SeqRecvPath::new(&port_id, &chan_id))
+                .unwrap(),
+            Sequence::from(1)
+        );
+        assert_eq!(
+            ctx.get_next_sequence_ack(&SeqAckPath::new(&port_id, &chan_id))
+                .unwrap(),
+            Sequence::from(1)
+        );
+
+        for seq in 1..=3 {
+            let packet = unacked_packet(&port_id, &chan_id, Sequence::from(seq));
+            let commitment: PacketCommitment = ctx
+                .get_packet_commitment(&CommitmentPath::new(&port_id, &chan_id, packet.seq_on_a))
+                .unwrap();
+            assert_eq!(
+                commitment,
+                compute_packet_commitment(
+                    &packet.data,
+                    &packet.timeout_height_on_b,
+                    &packet.timeout_timestamp_on_b,
+                )
+            );
+        }
+        assert!(ctx
+            .get_packet_commitment(&CommitmentPath::new(&port_id, &chan_id, Sequence::from(4)))
+            .is_err());
+
+        assert_eq!(ctx.client_counter().unwrap(), 1);
+        assert_eq!(ctx.connection_counter().unwrap(), 1);
+        assert_eq!(ctx.channel_counter().unwrap(), 1);
+    }
+
+    #[test]
+    fn init_ends_have_no_counterparty_id() {
+        let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+        let conn_id = ConnectionId::new(0);
+        let chan_id = ChannelId::new(0);
+
+        let ctx = TestChainBuilder::default()
+            .client(client_id.clone(), Height::new(0, 5).unwrap())
+            .connection(conn_id.clone(), client_id, ConnectionState::Init)
+            .channel(chan_id.clone(), conn_id.clone(), ChannelState::Init)
+            .build();
+
+        let conn_end = ctx.connection_end(&conn_id).unwrap();
+        assert!(conn_end.counterparty().connection_id().is_none());
+
+        let chan_end = ctx
+            .channel_end(&ChannelEndPath::new(&PortId::transfer(), &chan_id))
+            .unwrap();
+        assert!(chan_end.counterparty().channel_id().is_none());
+    }
+}
@@ -5,6 +5,7 @@ use ibc::clients::ics07_tendermint::{
     CommonContext as TmCommonContext, ValidationContext as TmValidationContext,
 };
 use ibc::core::ics02_client::error::ClientError;
+use ibc::core::ics02_client::events::ClientMisbehaviour;
 use ibc::core::ics02_client::{ClientExecutionContext, ClientValidationContext};
 use ibc::core::ics24_host::identifier::{ChannelId, ClientId, PortId};
 use ibc::core::ics24_host::path::{ClientConsensusStatePath, ClientStatePath};
@@ -323,4 +324,12 @@ impl ClientExecutionContext for MockContext {
 
         Ok(())
     }
+
+    fn on_client_frozen(&mut self, misbehaviour: &ClientMisbehaviour) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .frozen_clients
+            .push(misbehaviour.clone());
+        Ok(())
+    }
 }
@@ -1,30 +1,47 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
-use core::ops::Add;
+use alloc::sync::Arc;
 use core::time::Duration;
 
-use ibc::clients::ics07_tendermint::client_state::ClientState as TmClientState;
 use ibc::core::events::IbcEvent;
+use ibc::core::genesis::GenesisContext;
+use ibc::core::ics02_client::client_state::ClientStateCommon;
 use ibc::core::ics02_client::error::ClientError;
-use ibc::core::ics03_connection::connection::ConnectionEnd;
+use ibc::core::ics02_client::migration::{MigrateClientStore, UNVERSIONED_STORE_VERSION};
+use ibc::core::ics02_client::params::ClientParams;
+use ibc::core::ics02_client::registry::KnownClientTypes;
+use ibc::core::ics03_connection::connection::{ConnectionEnd, IdentifiedConnectionEnd};
 use ibc::core::ics03_connection::error::ConnectionError;
-use ibc::core::ics04_channel::channel::ChannelEnd;
+use ibc::core::ics03_connection::params::ConnectionParams;
+use ibc::core::ics03_connection::upgrade::{ConnectionUpgrade, ConnectionUpgradeContext};
+use ibc::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc::core::ics04_channel::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::ics04_channel::error::{ChannelError, PacketError};
-use ibc::core::ics04_channel::packet::{Receipt, Sequence};
-use ibc::core::ics23_commitment::commitment::CommitmentPrefix;
-use ibc::core::ics24_host::identifier::{ClientId, ConnectionId};
+use ibc::core::ics04_channel::msgs::PacketMsg;
+use ibc::core::ics04_channel::packet::{PacketState, Receipt, Sequence};
+use ibc::core::ics04_channel::receipt_bitmap::{self, ReceiptBitmap, ReceiptBitmapContext};
+use ibc::core::ics04_channel::timeout_queue::TimeoutQueueContext;
+use ibc::core::ics23_commitment::commitment::{CommitmentPrefix, ProofHash, ProofResolver};
+use ibc::core::ics23_commitment::error::CommitmentError;
+use ibc::core::ics24_host::identifier::{
+    ChannelId, ClientId, ConnectionId, IdentifierValidationConfig, PortId,
+};
 use ibc::core::ics24_host::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, CommitmentPath,
-    ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+    ConnectionPath, ConnectionUpgradePath, ReceiptBitmapPath, ReceiptPath, SeqAckPath, SeqRecvPath,
+    SeqSendPath,
 };
+use ibc::core::simulation::SimulationContext;
 use ibc::core::timestamp::Timestamp;
-use ibc::core::{ContextError, ExecutionContext, ValidationContext};
+use ibc::core::{
+    ContextError, ExecutionContext, MsgEnvelope, MsgFilter, RouterError, ValidationContext,
+};
 use ibc::prelude::*;
 use ibc::proto::Any;
-use ibc::{Height, Signer};
+use ibc::{Height, Signer, SignerValidator};
+use parking_lot::Mutex;
 
-use super::types::MockContext;
+use super::types::{MockContext, MOCK_COMMITMENT_PREFIX};
 use crate::testapp::ibc::clients::mock::client_state::MockClientState;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 
@@ -52,17 +69,34 @@ impl ValidationContext for MockContext {
     }
 
     fn decode_client_state(&self, client_state: Any) -> Result<Self::AnyClientState, ContextError> {
-        if let Ok(client_state) = TmClientState::try_from(client_state.clone()) {
-            client_state.validate().map_err(ClientError::from)?;
-            Ok(client_state.into())
-        } else if let Ok(client_state) = MockClientState::try_from(client_state.clone()) {
-            Ok(client_state.into())
-        } else {
-            Err(ClientError::UnknownClientStateType {
-                client_state_type: client_state.type_url,
-            })
+        Ok(self.client_types.decode_client_state(client_state)?)
+    }
+
+    fn find_duplicate_client(
+        &self,
+        client_state: &Any,
+        consensus_state: &Any,
+    ) -> Result<Option<ClientId>, ContextError> {
+        let ibc_store = self.ibc_store.lock();
+        if !ibc_store.reject_duplicate_clients {
+            return Ok(None);
         }
-        .map_err(ContextError::ClientError)
+
+        let duplicate = ibc_store
+            .clients
+            .iter()
+            .find(|(_, record)| {
+                record.client_state.as_ref().map_or(false, |cs| {
+                    Any::from(cs.clone()) == *client_state
+                        && record
+                            .consensus_states
+                            .get(&cs.latest_height())
+                            .map_or(false, |cons| Any::from(cons.clone()) == *consensus_state)
+                })
+            })
+            .map(|(client_id, _)| client_id.clone());
+
+        Ok(duplicate)
     }
 
     fn consensus_state(
@@ -92,13 +126,7 @@ impl ValidationContext for MockContext {
     }
 
     fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
-        Ok(self
-            .history
-            .last()
-            .expect("history cannot be empty")
-            .timestamp()
-            .add(self.block_time)
-            .expect("Never fails"))
+        Ok(self.next_block_timestamp())
     }
 
     fn host_consensus_state(&self, height: &Height) -> Result<AnyConsensusState, ContextError> {
@@ -124,6 +152,19 @@ impl ValidationContext for MockContext {
         .map_err(ContextError::ConnectionError)
     }
 
+    fn connection_id_by_counterparty(
+        &self,
+        client_id_on_b: &ClientId,
+        conn_id_on_b: &ConnectionId,
+    ) -> Result<Option<ConnectionId>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .connection_ids_by_counterparty
+            .get(&(client_id_on_b.clone(), conn_id_on_b.clone()))
+            .cloned())
+    }
+
     fn validate_self_client(
         &self,
         client_state_of_host_on_counterparty: Any,
@@ -172,7 +213,7 @@ impl ValidationContext for MockContext {
     }
 
     fn commitment_prefix(&self) -> CommitmentPrefix {
-        CommitmentPrefix::try_from(b"mock".to_vec()).expect("Never fails")
+        CommitmentPrefix::try_from(MOCK_COMMITMENT_PREFIX.to_vec()).expect("Never fails")
     }
 
     fn connection_counter(&self) -> Result<u64, ContextError> {
@@ -199,6 +240,30 @@ impl ValidationContext for MockContext {
         .map_err(ContextError::ChannelError)
     }
 
+    fn channel_id_by_counterparty(
+        &self,
+        client_id_on_b: &ClientId,
+        port_id_on_b: &PortId,
+        chan_id_on_b: &ChannelId,
+    ) -> Result<Option<(PortId, ChannelId)>, ContextError> {
+        let ibc_store = self.ibc_store.lock();
+
+        // Channel ids are only unique per chain, so first narrow the search
+        // down to the connections towards the given counterparty.
+        let found = ibc_store
+            .connections
+            .iter()
+            .filter(|(_, conn_end)| conn_end.counterparty().client_id() == client_id_on_b)
+            .find_map(|(conn_id, _)| {
+                ibc_store
+                    .channel_ids_by_counterparty
+                    .get(&(conn_id.clone(), port_id_on_b.clone(), chan_id_on_b.clone()))
+                    .cloned()
+            });
+
+        Ok(found)
+    }
+
     fn get_next_sequence_send(
         &self,
         seq_send_path: &SeqSendPath,
@@ -288,22 +353,24 @@ impl ValidationContext for MockContext {
     }
 
     fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
-        let port_id = &receipt_path.port_id;
-        let channel_id = &receipt_path.channel_id;
-        let seq = &receipt_path.sequence;
-
-        match self
-            .ibc_store
-            .lock()
-            .packet_receipt
-            .get(port_id)
-            .and_then(|map| map.get(channel_id))
-            .and_then(|map| map.get(seq))
-        {
-            Some(receipt) => Ok(receipt.clone()),
-            None => Err(PacketError::PacketReceiptNotFound { sequence: *seq }),
-        }
-        .map_err(ContextError::PacketError)
+        receipt_bitmap::get_packet_receipt(self, receipt_path, |ctx, receipt_path| {
+            let port_id = &receipt_path.port_id;
+            let channel_id = &receipt_path.channel_id;
+            let seq = &receipt_path.sequence;
+
+            match ctx
+                .ibc_store
+                .lock()
+                .packet_receipt
+                .get(port_id)
+                .and_then(|map| map.get(channel_id))
+                .and_then(|map| map.get(seq))
+            {
+                Some(receipt) => Ok(receipt.clone()),
+                None => Err(PacketError::PacketReceiptNotFound { sequence: *seq }),
+            }
+            .map_err(ContextError::PacketError)
+        })
     }
 
     fn get_packet_acknowledgement(
@@ -332,12 +399,60 @@ impl ValidationContext for MockContext {
         Ok(self.ibc_store.lock().channel_ids_counter)
     }
 
-    fn max_expected_time_per_block(&self) -> Duration {
-        self.block_time
+    fn client_params(&self) -> Result<ClientParams, ContextError> {
+        Ok(self.ibc_store.lock().client_params.clone())
     }
 
-    fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
-        Ok(())
+    fn connection_params(&self) -> Result<ConnectionParams, ContextError> {
+        Ok(self.ibc_store.lock().connection_params)
+    }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        self.ibc_store.lock().max_packet_data_size
+    }
+
+    fn counterparty_uses_receipt_bitmap(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.uses_receipt_bitmap(port_id, channel_id)
+    }
+
+    fn client_expiry_warning_margin(&self) -> Option<Duration> {
+        self.ibc_store.lock().client_expiry_warning_margin
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        match &self.ibc_store.lock().signer_validator {
+            Some(signer_validator) => signer_validator.validate_signer(signer).map_err(|e| {
+                ClientError::InvalidSigner {
+                    reason: e.to_string(),
+                }
+                .into()
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn known_client_types(&self) -> &dyn KnownClientTypes {
+        &self.client_types
+    }
+
+    fn msg_filter(&self) -> &dyn MsgFilter {
+        self
+    }
+
+    fn proof_resolver(&self) -> Option<&dyn ProofResolver> {
+        if self.ibc_store.lock().out_of_band_proofs.is_some() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    fn identifier_validation_config(&self) -> IdentifierValidationConfig {
+        self.ibc_store.lock().identifier_validation_config
+    }
+
+    fn connection_upgrades(&self) -> Option<&dyn ConnectionUpgradeContext> {
+        Some(self)
     }
 
     fn get_client_validation_context(&self) -> &Self::V {
@@ -345,6 +460,43 @@ impl ValidationContext for MockContext {
     }
 }
 
+impl MsgFilter for MockContext {
+    fn filter_msg(&self, msg: &MsgEnvelope) -> Result<(), RouterError> {
+        let ibc_store = self.ibc_store.lock();
+
+        ibc_store.blocked_ports.filter_msg(msg)?;
+
+        if let MsgEnvelope::Packet(PacketMsg::Recv(msg)) = msg {
+            let packet = &msg.packet;
+            if ibc_store
+                .disabled_channels
+                .contains(&(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone()))
+            {
+                return Err(RouterError::MessageRejected {
+                    reason: format!(
+                        "channel `{}` of port `{}` is disabled",
+                        packet.chan_id_on_b, packet.port_id_on_b
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ProofResolver for MockContext {
+    fn fetch_proof(&self, hash: &ProofHash) -> Result<Vec<u8>, CommitmentError> {
+        self.ibc_store
+            .lock()
+            .out_of_band_proofs
+            .as_ref()
+            .and_then(|proofs| proofs.get(hash))
+            .cloned()
+            .ok_or(CommitmentError::UnresolvedProof { hash: *hash })
+    }
+}
+
 impl ExecutionContext for MockContext {
     fn get_client_execution_context(&mut self) -> &mut Self::E {
         self
@@ -363,8 +515,7 @@ impl ExecutionContext for MockContext {
         let connection_id = connection_path.0.clone();
         self.ibc_store
             .lock()
-            .connections
-            .insert(connection_id, connection_end);
+            .insert_connection(connection_id, connection_end);
         Ok(())
     }
 
@@ -386,6 +537,16 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.ibc_store.lock().client_params = params;
+        Ok(())
+    }
+
+    fn store_connection_params(&mut self, params: ConnectionParams) -> Result<(), ContextError> {
+        self.ibc_store.lock().connection_params = params;
+        Ok(())
+    }
+
     fn store_packet_commitment(
         &mut self,
         commitment_path: &CommitmentPath,
@@ -420,15 +581,17 @@ impl ExecutionContext for MockContext {
         path: &ReceiptPath,
         receipt: Receipt,
     ) -> Result<(), ContextError> {
-        self.ibc_store
-            .lock()
-            .packet_receipt
-            .entry(path.port_id.clone())
-            .or_default()
-            .entry(path.channel_id.clone())
-            .or_default()
-            .insert(path.sequence, receipt);
-        Ok(())
+        receipt_bitmap::store_packet_receipt(self, path, receipt, |ctx, path, receipt| {
+            ctx.ibc_store
+                .lock()
+                .packet_receipt
+                .entry(path.port_id.clone())
+                .or_default()
+                .entry(path.channel_id.clone())
+                .or_default()
+                .insert(path.sequence, receipt);
+            Ok(())
+        })
     }
 
     fn store_packet_acknowledgement(
@@ -475,10 +638,7 @@ impl ExecutionContext for MockContext {
 
         self.ibc_store
             .lock()
-            .channels
-            .entry(port_id)
-            .or_default()
-            .insert(channel_id, channel_end);
+            .insert_channel(port_id, channel_id, channel_end);
         Ok(())
     }
 
@@ -547,4 +707,296 @@ impl ExecutionContext for MockContext {
         self.logs.push(message);
         Ok(())
     }
+
+    fn timeout_queue(&mut self) -> Option<&mut dyn TimeoutQueueContext> {
+        self.timeout_queue
+            .as_mut()
+            .map(|timeout_queue| -> &mut dyn TimeoutQueueContext { timeout_queue })
+    }
+
+    fn connection_upgrades_mut(&mut self) -> Option<&mut dyn ConnectionUpgradeContext> {
+        Some(self)
+    }
+}
+
+/// Migrating a client store of the mock context only records its new version,
+/// as the mock stores domain types rather than their encodings.
+impl SimulationContext for MockContext {
+    fn branch(&self) -> Self {
+        Self {
+            host_chain_type: self.host_chain_type,
+            host_chain_id: self.host_chain_id.clone(),
+            max_history_size: self.max_history_size,
+            history: self.history.clone(),
+            block_time: self.block_time,
+            block_delay: self.block_delay,
+            clock: self.clock.clone(),
+            client_types: self.client_types.clone(),
+            ibc_store: Arc::new(Mutex::new(self.ibc_store.lock().clone())),
+            proofs: self.proofs.clone(),
+            timeout_queue: self.timeout_queue.clone(),
+            events: self.events.clone(),
+            logs: self.logs.clone(),
+        }
+    }
+}
+
+impl MigrateClientStore for MockContext {
+    fn latest_client_store_version(&self) -> u32 {
+        self.ibc_store.lock().latest_client_store_version
+    }
+
+    fn client_store_version(&self, client_id: &ClientId) -> Result<u32, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .client_store_versions
+            .get(client_id)
+            .copied()
+            .unwrap_or(UNVERSIONED_STORE_VERSION))
+    }
+
+    fn migrate_client_store(
+        &mut self,
+        client_id: &ClientId,
+        from: u32,
+    ) -> Result<(), ContextError> {
+        let mut ibc_store = self.ibc_store.lock();
+
+        let migration = *ibc_store
+            .client_store_migrations
+            .get(&from)
+            .ok_or_else(|| ClientError::Other {
+                description: format!("no migration of client stores from version {from}"),
+            })?;
+        let client_record = ibc_store.clients.get_mut(client_id).ok_or_else(|| {
+            ClientError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            }
+        })?;
+        migration(client_record)?;
+
+        ibc_store
+            .client_store_versions
+            .insert(client_id.clone(), from + 1);
+        Ok(())
+    }
+
+    fn validate_migration_authority(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.validate_message_signer(signer)
+    }
+}
+
+impl ConnectionUpgradeContext for MockContext {
+    fn connection_upgrade(
+        &self,
+        conn_id: &ConnectionId,
+    ) -> Result<Option<ConnectionUpgrade>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .connection_upgrades
+            .get(conn_id)
+            .cloned())
+    }
+
+    fn store_connection_upgrade(
+        &mut self,
+        upgrade_path: &ConnectionUpgradePath,
+        upgrade: ConnectionUpgrade,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .connection_upgrades
+            .insert(upgrade_path.0.clone(), upgrade);
+        Ok(())
+    }
+
+    fn delete_connection_upgrade(
+        &mut self,
+        upgrade_path: &ConnectionUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .connection_upgrades
+            .remove(&upgrade_path.0);
+        Ok(())
+    }
+
+    fn validate_upgrade_authority(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.validate_message_signer(signer)
+    }
+}
+
+impl ReceiptBitmapContext for MockContext {
+    fn uses_receipt_bitmap(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.ibc_store
+            .lock()
+            .receipt_bitmap_channels
+            .contains(&(port_id.clone(), channel_id.clone()))
+    }
+
+    fn receipt_bitmap(
+        &self,
+        bitmap_path: &ReceiptBitmapPath,
+    ) -> Result<Option<ReceiptBitmap>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .receipt_bitmaps
+            .get(bitmap_path)
+            .copied())
+    }
+
+    fn store_receipt_bitmap(
+        &mut self,
+        bitmap_path: &ReceiptBitmapPath,
+        bitmap: ReceiptBitmap,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .receipt_bitmaps
+            .insert(bitmap_path.clone(), bitmap);
+        Ok(())
+    }
+}
+
+impl GenesisContext for MockContext {
+    fn client_states(&self) -> Result<Vec<(ClientId, AnyClientState)>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .clients
+            .iter()
+            .filter_map(|(client_id, record)| {
+                record
+                    .client_state
+                    .clone()
+                    .map(|client_state| (client_id.clone(), client_state))
+            })
+            .collect())
+    }
+
+    fn consensus_states(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<(Height, AnyConsensusState)>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .clients
+            .get(client_id)
+            .map(|record| {
+                record
+                    .consensus_states
+                    .iter()
+                    .map(|(height, consensus_state)| (*height, consensus_state.clone()))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .connections
+            .iter()
+            .map(|(conn_id, conn_end)| {
+                IdentifiedConnectionEnd::new(conn_id.clone(), conn_end.clone())
+            })
+            .collect())
+    }
+
+    fn client_connection_ends(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<ConnectionId>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .client_connections
+            .get(client_id)
+            .cloned()
+            .into_iter()
+            .collect())
+    }
+
+    fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .channels
+            .iter()
+            .flat_map(|(port_id, channels)| {
+                channels.iter().map(|(chan_id, chan_end)| {
+                    IdentifiedChannelEnd::new(port_id.clone(), chan_id.clone(), chan_end.clone())
+                })
+            })
+            .collect())
+    }
+
+    fn packet_commitments(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<PacketState>, ContextError> {
+        let ChannelEndPath(port_id, chan_id) = channel_end_path;
+        Ok(self
+            .ibc_store
+            .lock()
+            .packet_commitment
+            .get(port_id)
+            .and_then(|map| map.get(chan_id))
+            .map(|commitments| {
+                commitments
+                    .iter()
+                    .map(|(seq, commitment)| PacketState {
+                        port_id: port_id.clone(),
+                        chan_id: chan_id.clone(),
+                        seq: *seq,
+                        data: commitment.clone().into_vec(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn packet_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<PacketState>, ContextError> {
+        let ChannelEndPath(port_id, chan_id) = channel_end_path;
+        Ok(self
+            .ibc_store
+            .lock()
+            .packet_acknowledgement
+            .get(port_id)
+            .and_then(|map| map.get(chan_id))
+            .map(|acks| {
+                acks.iter()
+                    .map(|(seq, ack)| PacketState {
+                        port_id: port_id.clone(),
+                        chan_id: chan_id.clone(),
+                        seq: *seq,
+                        data: ack.clone().into_vec(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn packet_receipts(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let ChannelEndPath(port_id, chan_id) = channel_end_path;
+        Ok(self
+            .ibc_store
+            .lock()
+            .packet_receipt
+            .get(port_id)
+            .and_then(|map| map.get(chan_id))
+            .map(|receipts| receipts.keys().copied().collect())
+            .unwrap_or_default())
+    }
 }
@@ -0,0 +1,500 @@
+//! A minimal ICS-23 provable key-value store, used to back the commitments of
+//! a `MockContext` with genuine Merkle proofs.
+//!
+//! Proofs are laid out the way a Cosmos-SDK chain lays them out, so that they
+//! verify against [`ProofSpecs::cosmos()`](ibc::core::ics23_commitment::specs::ProofSpecs::cosmos):
+//! the IBC store is a binary tree whose nodes use the IAVL encoding, and its
+//! root is in turn committed under the store key in a multistore that uses the
+//! Tendermint simple Merkle encoding. The tree shape is not balanced as an
+//! actual IAVL tree would be, which is irrelevant to proof verification.
+
+use alloc::collections::btree_map::BTreeMap;
+
+use ibc::core::ics23_commitment::commitment::{CommitmentProofBytes, CommitmentRoot};
+use ibc::core::ics23_commitment::merkle::MerkleProof;
+use ibc::prelude::*;
+use ibc::proto::ics23::commitment_proof::Proof;
+use ibc::proto::ics23::{
+    CommitmentProof, ExistenceProof, HashOp, InnerOp, LeafOp, LengthOp, NonExistenceProof,
+};
+use sha2::{Digest, Sha256};
+
+/// All nodes are written at the same version, as the store keeps no history.
+const NODE_VERSION: u64 = 1;
+
+/// Length prefix of a child hash in the IAVL inner node encoding.
+const HASH_LENGTH_PREFIX: u8 = 32;
+
+/// An ordered key-value store able to produce ICS-23 proofs for its content.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MerkleStore {
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+struct Node {
+    hash: Vec<u8>,
+    height: u64,
+    size: u64,
+}
+
+impl MerkleStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, key: impl Into<Vec<u8>>, value: Vec<u8>) {
+        self.entries.insert(key.into(), value);
+    }
+
+    /// Returns the value stored under `key`, if any.
+    pub fn get(&self, key: &[u8]) -> Option<&[u8]> {
+        self.entries.get(key).map(Vec::as_slice)
+    }
+
+    /// Returns the entries of the store, in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&[u8], &[u8])> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_slice(), value.as_slice()))
+    }
+
+    /// Returns the root hash of the store, which is empty if the store is.
+    pub fn root(&self) -> Vec<u8> {
+        let leaves: Vec<_> = self.entries.iter().collect();
+        if leaves.is_empty() {
+            return Vec::new();
+        }
+        build(&leaves).hash
+    }
+
+    /// Returns a proof that `key` is present in the store, or `None` if it is
+    /// not.
+    pub fn existence_proof(&self, key: &[u8]) -> Option<ExistenceProof> {
+        let leaves: Vec<_> = self.entries.iter().collect();
+        let index = leaves.iter().position(|(k, _)| k.as_slice() == key)?;
+        Some(existence_proof(&leaves, index))
+    }
+
+    /// Returns a proof that `key` is absent from the store, or `None` if it is
+    /// present or the store is empty.
+    pub fn non_existence_proof(&self, key: &[u8]) -> Option<NonExistenceProof> {
+        if self.entries.is_empty() || self.entries.contains_key(key) {
+            return None;
+        }
+
+        let leaves: Vec<_> = self.entries.iter().collect();
+        let index = leaves.partition_point(|(k, _)| k.as_slice() < key);
+
+        Some(NonExistenceProof {
+            key: key.to_vec(),
+            left: index.checked_sub(1).map(|i| existence_proof(&leaves, i)),
+            right: (index < leaves.len()).then(|| existence_proof(&leaves, index)),
+        })
+    }
+}
+
+/// Returns the root of a multistore holding a single store, whose root is
+/// `store_root`, under `store_key`.
+pub fn multistore_root(store_key: &[u8], store_root: &[u8]) -> CommitmentRoot {
+    let leaf = tendermint_leaf_op();
+    CommitmentRoot::from(leaf_hash(&leaf, store_key, store_root))
+}
+
+/// Builds the two-layer proof that `key` is in `store`, itself committed under
+/// `store_key` in the multistore.
+pub fn membership_proof(
+    store: &MerkleStore,
+    store_key: &[u8],
+    key: &[u8],
+) -> Option<CommitmentProofBytes> {
+    let proof = store.existence_proof(key)?;
+    merkle_proof(
+        Proof::Exist(proof),
+        multistore_proof(store_key, &store.root()),
+    )
+}
+
+/// Builds the two-layer proof that `key` is not in `store`, itself committed
+/// under `store_key` in the multistore.
+pub fn non_membership_proof(
+    store: &MerkleStore,
+    store_key: &[u8],
+    key: &[u8],
+) -> Option<CommitmentProofBytes> {
+    let proof = store.non_existence_proof(key)?;
+    merkle_proof(
+        Proof::Nonexist(proof),
+        multistore_proof(store_key, &store.root()),
+    )
+}
+
+fn merkle_proof(store_proof: Proof, multistore_proof: Proof) -> Option<CommitmentProofBytes> {
+    let proofs = vec![
+        CommitmentProof {
+            proof: Some(store_proof),
+        },
+        CommitmentProof {
+            proof: Some(multistore_proof),
+        },
+    ];
+    CommitmentProofBytes::try_from(MerkleProof { proofs }).ok()
+}
+
+fn multistore_proof(store_key: &[u8], store_root: &[u8]) -> Proof {
+    Proof::Exist(ExistenceProof {
+        key: store_key.to_vec(),
+        value: store_root.to_vec(),
+        leaf: Some(tendermint_leaf_op()),
+        path: Vec::new(),
+    })
+}
+
+fn build(leaves: &[(&Vec<u8>, &Vec<u8>)]) -> Node {
+    match leaves {
+        [(key, value)] => Node {
+            hash: leaf_hash(&iavl_leaf_op(), key, value),
+            height: 0,
+            size: 1,
+        },
+        _ => {
+            let (left, right) = leaves.split_at(split_point(leaves.len()));
+            parent(&build(left), &build(right))
+        }
+    }
+}
+
+fn existence_proof(leaves: &[(&Vec<u8>, &Vec<u8>)], index: usize) -> ExistenceProof {
+    let (key, value) = leaves[index];
+    let mut path = Vec::new();
+    prove(leaves, index, &mut path);
+
+    ExistenceProof {
+        key: key.clone(),
+        value: value.clone(),
+        leaf: Some(iavl_leaf_op()),
+        path,
+    }
+}
+
+/// Pushes onto `path` the inner nodes from the leaf at `index` up to the root
+/// of the tree built from `leaves`, and returns that root.
+fn prove(leaves: &[(&Vec<u8>, &Vec<u8>)], index: usize, path: &mut Vec<InnerOp>) -> Node {
+    if leaves.len() == 1 {
+        return build(leaves);
+    }
+
+    let split = split_point(leaves.len());
+    let (left, right) = leaves.split_at(split);
+    if index < split {
+        let left = prove(left, index, path);
+        let right = build(right);
+        path.push(left_child_op(&left, &right));
+        parent(&left, &right)
+    } else {
+        let left = build(left);
+        let right = prove(right, index - split, path);
+        path.push(right_child_op(&left, &right));
+        parent(&left, &right)
+    }
+}
+
+fn parent(left: &Node, right: &Node) -> Node {
+    let op = left_child_op(left, right);
+    Node {
+        hash: inner_hash(&op, &left.hash),
+        height: left.height.max(right.height) + 1,
+        size: left.size + right.size,
+    }
+}
+
+/// The inner node operation applied to the hash of its left child.
+fn left_child_op(left: &Node, right: &Node) -> InnerOp {
+    InnerOp {
+        hash: HashOp::Sha256.into(),
+        prefix: inner_prefix(left, right),
+        suffix: [&[HASH_LENGTH_PREFIX], right.hash.as_slice()].concat(),
+    }
+}
+
+/// The inner node operation applied to the hash of its right child.
+fn right_child_op(left: &Node, right: &Node) -> InnerOp {
+    InnerOp {
+        hash: HashOp::Sha256.into(),
+        prefix: [
+            inner_prefix(left, right).as_slice(),
+            &left.hash,
+            &[HASH_LENGTH_PREFIX],
+        ]
+        .concat(),
+        suffix: Vec::new(),
+    }
+}
+
+/// Splits at the largest power of two strictly smaller than `len`, as the
+/// Tendermint simple Merkle tree does.
+fn split_point(len: usize) -> usize {
+    let mut split = 1;
+    while split * 2 < len {
+        split *= 2;
+    }
+    split
+}
+
+fn iavl_leaf_op() -> LeafOp {
+    LeafOp {
+        hash: HashOp::Sha256.into(),
+        prehash_key: HashOp::NoHash.into(),
+        prehash_value: HashOp::Sha256.into(),
+        length: LengthOp::VarProto.into(),
+        prefix: [zigzag(0), zigzag(1), zigzag(NODE_VERSION)].concat(),
+    }
+}
+
+fn tendermint_leaf_op() -> LeafOp {
+    LeafOp {
+        hash: HashOp::Sha256.into(),
+        prehash_key: HashOp::NoHash.into(),
+        prehash_value: HashOp::Sha256.into(),
+        length: LengthOp::VarProto.into(),
+        prefix: vec![0],
+    }
+}
+
+/// The IAVL encoding of the parent of `left` and `right`, followed by the
+/// length prefix of the left child hash.
+fn inner_prefix(left: &Node, right: &Node) -> Vec<u8> {
+    [
+        zigzag(left.height.max(right.height) + 1),
+        zigzag(left.size + right.size),
+        zigzag(NODE_VERSION),
+        vec![HASH_LENGTH_PREFIX],
+    ]
+    .concat()
+}
+
+/// Hashes a leaf as ICS-23 does for a `LeafOp` with no key prehashing,
+/// SHA-256 value prehashing and protobuf varint length prefixes.
+fn leaf_hash(leaf: &LeafOp, key: &[u8], value: &[u8]) -> Vec<u8> {
+    let value = Sha256::digest(value);
+    let preimage = [
+        leaf.prefix.as_slice(),
+        &varint(key.len() as u64),
+        key,
+        &varint(value.len() as u64),
+        &value,
+    ]
+    .concat();
+    Sha256::digest(preimage).to_vec()
+}
+
+fn inner_hash(op: &InnerOp, child: &[u8]) -> Vec<u8> {
+    let preimage = [op.prefix.as_slice(), child, &op.suffix].concat();
+    Sha256::digest(preimage).to_vec()
+}
+
+fn varint(value: u64) -> Vec<u8> {
+    let mut buf = Vec::new();
+    prost::encoding::encode_varint(value, &mut buf);
+    buf
+}
+
+/// Encodes a non-negative integer as a zigzag varint, as IAVL does.
+fn zigzag(value: u64) -> Vec<u8> {
+    varint(value << 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::ics23_commitment::commitment::CommitmentPrefix;
+    use ibc::core::ics23_commitment::error::CommitmentError;
+    use ibc::core::ics23_commitment::merkle::{apply_prefix, ProofLimits};
+    use ibc::core::ics23_commitment::specs::ProofSpecs;
+    use ibc::proto::core::commitment::v1::MerkleProof as RawMerkleProof;
+    use ibc::proto::ics23::{
+        compressed_batch_entry, CompressedBatchEntry, CompressedBatchProof,
+        CompressedExistenceProof,
+    };
+
+    use super::*;
+
+    const STORE_KEY: &[u8] = b"mock";
+
+    fn store(n: u8) -> MerkleStore {
+        let mut store = MerkleStore::new();
+        for i in 0..n {
+            store.set(format!("key/{}", i * 2), vec![i + 1]);
+        }
+        store
+    }
+
+    fn decode(proof: CommitmentProofBytes) -> MerkleProof {
+        RawMerkleProof::try_from(proof).expect("valid proof").into()
+    }
+
+    fn keys(key: &str) -> ibc::proto::core::commitment::v1::MerklePath {
+        let prefix = CommitmentPrefix::try_from(STORE_KEY.to_vec()).expect("non-empty");
+        apply_prefix(&prefix, vec![key.to_string()])
+    }
+
+    #[test]
+    fn membership_proofs_verify() {
+        for n in 1..10 {
+            let store = store(n);
+            let root = multistore_root(STORE_KEY, &store.root());
+
+            for i in 0..n {
+                let key = format!("key/{}", i * 2);
+                let proof = membership_proof(&store, STORE_KEY, key.as_bytes()).expect("present");
+
+                decode(proof.clone())
+                    .verify_membership(
+                        &ProofSpecs::cosmos(),
+                        root.clone().into(),
+                        keys(&key),
+                        vec![i + 1],
+                        0,
+                    )
+                    .expect("proof verifies");
+
+                assert!(decode(proof)
+                    .verify_membership(
+                        &ProofSpecs::cosmos(),
+                        root.clone().into(),
+                        keys(&key),
+                        vec![i + 2],
+                        0,
+                    )
+                    .is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn non_membership_proofs_verify() {
+        for n in 1..10 {
+            let store = store(n);
+            let root = multistore_root(STORE_KEY, &store.root());
+
+            // Odd keys fall before, between and after the stored even keys.
+            for i in 0..=n {
+                let key = format!("key/{}", i * 2 + 1);
+                let proof =
+                    non_membership_proof(&store, STORE_KEY, key.as_bytes()).expect("absent");
+
+                decode(proof)
+                    .verify_non_membership(&ProofSpecs::cosmos(), root.clone().into(), keys(&key))
+                    .expect("proof verifies");
+            }
+
+            assert!(non_membership_proof(&store, STORE_KEY, b"key/0").is_none());
+        }
+    }
+
+    /// Compresses `exist`, adding the inner operations it uses to `lookup`.
+    fn compress(exist: &ExistenceProof, lookup: &mut Vec<InnerOp>) -> CompressedBatchEntry {
+        let path = exist
+            .path
+            .iter()
+            .map(|op| match lookup.iter().position(|known| known == op) {
+                Some(index) => index as i32,
+                None => {
+                    lookup.push(op.clone());
+                    lookup.len() as i32 - 1
+                }
+            })
+            .collect();
+
+        CompressedBatchEntry {
+            proof: Some(compressed_batch_entry::Proof::Exist(
+                CompressedExistenceProof {
+                    key: exist.key.clone(),
+                    value: exist.value.clone(),
+                    leaf: exist.leaf.clone(),
+                    path,
+                },
+            )),
+        }
+    }
+
+    /// Returns the proof that `key` is in `store`, whose store layer is a
+    /// compressed batch also proving the other keys of `batch`.
+    fn compressed_proof(store: &MerkleStore, key: &str, batch: &[&str]) -> MerkleProof {
+        let mut lookup_inners = Vec::new();
+        let entries = batch
+            .iter()
+            .chain([&key])
+            .map(|key| {
+                compress(
+                    &store.existence_proof(key.as_bytes()).unwrap(),
+                    &mut lookup_inners,
+                )
+            })
+            .collect();
+
+        let mut proof = decode(membership_proof(store, STORE_KEY, key.as_bytes()).unwrap());
+        proof.proofs[0] = CommitmentProof {
+            proof: Some(Proof::Compressed(CompressedBatchProof {
+                entries,
+                lookup_inners,
+            })),
+        };
+        proof
+    }
+
+    #[test]
+    fn compressed_proofs_verify_within_limits() {
+        let store = store(8);
+        let root = multistore_root(STORE_KEY, &store.root());
+        let bytes =
+            CommitmentProofBytes::try_from(compressed_proof(&store, "key/4", &["key/0", "key/10"]))
+                .unwrap();
+
+        MerkleProof::decode(&bytes, &ProofLimits::default())
+            .expect("proof decompresses")
+            .verify_membership(
+                &ProofSpecs::cosmos(),
+                root.into(),
+                keys("key/4"),
+                vec![3],
+                0,
+            )
+            .expect("proof verifies");
+
+        let limits = ProofLimits {
+            max_proof_size: bytes.as_ref().len() - 1,
+            ..Default::default()
+        };
+        assert!(matches!(
+            MerkleProof::decode(&bytes, &limits),
+            Err(CommitmentError::ProofTooLarge { .. })
+        ));
+
+        let limits = ProofLimits {
+            max_ops: 4,
+            ..Default::default()
+        };
+        assert!(matches!(
+            MerkleProof::decode(&bytes, &limits),
+            Err(CommitmentError::TooManyProofOps { .. })
+        ));
+    }
+
+    #[test]
+    fn compressed_proofs_with_unknown_inner_ops_are_rejected() {
+        let store = store(8);
+        let mut proof = compressed_proof(&store, "key/4", &[]);
+        if let Some(Proof::Compressed(compressed)) = &mut proof.proofs[0].proof {
+            if let Some(compressed_batch_entry::Proof::Exist(exist)) =
+                &mut compressed.entries[0].proof
+            {
+                exist.path[0] = compressed.lookup_inners.len() as i32;
+            }
+        }
+        let bytes = CommitmentProofBytes::try_from(proof).unwrap();
+
+        assert!(matches!(
+            MerkleProof::decode(&bytes, &ProofLimits::default()),
+            Err(CommitmentError::InvalidCompressedProofIndex { .. })
+        ));
+    }
+}
@@ -1,4 +1,6 @@
+pub mod builder;
 pub mod client_ctx;
 pub mod core_ctx;
+pub mod merkle;
 pub mod router;
 pub mod types;
@@ -1,31 +1,46 @@
-use alloc::sync::Arc;
-
-use ibc::core::ics24_host::identifier::PortId;
+use ibc::core::ics24_host::identifier::{ClientId, ConnectionId, PortId};
 use ibc::core::router::{Module, ModuleId, Router};
+use ibc::core::simulation::SimulationRouter;
+use ibc::prelude::*;
 
 use super::types::MockRouter;
 
 impl Router for MockRouter {
     fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
-        self.router.get(module_id).map(Arc::as_ref)
+        self.router.get(module_id).map(|module| module.as_module())
     }
     fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
-        // NOTE: The following:
+        self.router
+            .get_mut(module_id)
+            .map(|module| module.as_module_mut())
+    }
 
-        // self.router.get_mut(module_id).and_then(Arc::get_mut)
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.bindings.lookup_port(port_id).cloned()
+    }
 
-        // doesn't work due to a compiler bug. So we expand it out manually.
+    fn lookup_module_by_connection(&self, connection_id: &ConnectionId) -> Option<ModuleId> {
+        self.bindings.lookup_connection(connection_id).cloned()
+    }
 
-        match self.router.get_mut(module_id) {
-            Some(arc_mod) => match Arc::get_mut(arc_mod) {
-                Some(m) => Some(m),
-                None => None,
-            },
-            None => None,
-        }
+    fn lookup_module_by_client(&self, client_id: &ClientId) -> Option<ModuleId> {
+        self.bindings.lookup_client(client_id).cloned()
     }
 
-    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
-        self.port_to_module.get(port_id).cloned()
+    fn module_ids(&self) -> Vec<ModuleId> {
+        self.router.keys().cloned().collect()
+    }
+}
+
+impl SimulationRouter for MockRouter {
+    fn branch(&self) -> Self {
+        Self {
+            router: self
+                .router
+                .iter()
+                .map(|(module_id, module)| (module_id.clone(), module.boxed_clone()))
+                .collect(),
+            bindings: self.bindings.clone(),
+        }
     }
 }
@@ -1,19 +1,46 @@
 use alloc::collections::BTreeMap;
-use alloc::sync::Arc;
 
 use ibc::applications::transfer::MODULE_ID_STR;
 use ibc::core::ics24_host::identifier::PortId;
-use ibc::core::router::{Module, ModuleId};
+use ibc::core::router::{Module, ModuleBindings, ModuleId};
+use ibc::core::RouterError;
 use ibc::prelude::*;
 
 use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
 
+/// A [`Module`] which the [`MockRouter`] copies along with its state when it
+/// is branched to simulate messages.
+pub trait MockModule: Module {
+    fn boxed_clone(&self) -> Box<dyn MockModule>;
+
+    fn as_module(&self) -> &dyn Module;
+
+    fn as_module_mut(&mut self) -> &mut dyn Module;
+}
+
+impl<M> MockModule for M
+where
+    M: Module + Clone + 'static,
+{
+    fn boxed_clone(&self) -> Box<dyn MockModule> {
+        Box::new(self.clone())
+    }
+
+    fn as_module(&self) -> &dyn Module {
+        self
+    }
+
+    fn as_module_mut(&mut self) -> &mut dyn Module {
+        self
+    }
+}
+
 #[derive(Default)]
 pub struct MockRouter {
-    pub router: BTreeMap<ModuleId, Arc<dyn Module>>,
+    pub router: BTreeMap<ModuleId, Box<dyn MockModule>>,
 
-    /// Maps ports to the the module that owns it
-    pub port_to_module: BTreeMap<PortId, ModuleId>,
+    /// Maps ports, connections and clients to the module that owns them
+    pub bindings: ModuleBindings,
 }
 
 impl MockRouter {
@@ -22,7 +49,9 @@ impl MockRouter {
 
         let module_id = ModuleId::new(MODULE_ID_STR.to_string());
 
-        router.scope_port_to_module(PortId::transfer(), module_id.clone());
+        router
+            .scope_port_to_module(PortId::transfer(), module_id.clone())
+            .expect("Never fails");
 
         let transfer_mod = DummyTransferModule::new();
 
@@ -36,15 +65,19 @@ impl MockRouter {
     pub fn add_route(
         &mut self,
         module_id: ModuleId,
-        module: impl Module + 'static,
+        module: impl MockModule + 'static,
     ) -> Result<(), String> {
-        match self.router.insert(module_id, Arc::new(module)) {
+        match self.router.insert(module_id, Box::new(module)) {
             None => Ok(()),
             Some(_) => Err("Duplicate module_id".to_owned()),
         }
     }
 
-    pub fn scope_port_to_module(&mut self, port_id: PortId, module_id: ModuleId) {
-        self.port_to_module.insert(port_id, module_id);
+    pub fn scope_port_to_module(
+        &mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+    ) -> Result<(), RouterError> {
+        self.bindings.bind(port_id, module_id)
     }
 }
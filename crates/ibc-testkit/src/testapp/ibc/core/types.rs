@@ -1,6 +1,7 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
 use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::btree_set::BTreeSet;
 use alloc::sync::Arc;
 use core::cmp::min;
 use core::fmt::Debug;
@@ -11,35 +12,94 @@ use ibc::clients::ics07_tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::ics07_tendermint::TENDERMINT_CLIENT_TYPE;
 use ibc::core::events::IbcEvent;
 use ibc::core::ics02_client::client_type::ClientType;
+use ibc::core::ics02_client::error::ClientError;
+use ibc::core::ics02_client::events::ClientMisbehaviour;
+use ibc::core::ics02_client::params::ClientParams;
+use ibc::core::ics02_client::registry::ClientTypeRegistry;
 use ibc::core::ics03_connection::connection::ConnectionEnd;
+use ibc::core::ics03_connection::params::ConnectionParams;
+use ibc::core::ics03_connection::upgrade::ConnectionUpgrade;
 use ibc::core::ics04_channel::channel::ChannelEnd;
 use ibc::core::ics04_channel::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::ics04_channel::packet::{Receipt, Sequence};
-use ibc::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics04_channel::receipt_bitmap::ReceiptBitmap;
+use ibc::core::ics04_channel::timeout_queue::TimeoutQueue;
+use ibc::core::ics23_commitment::commitment::{CommitmentProofBytes, CommitmentRoot, ProofHash};
+use ibc::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, IdentifierValidationConfig, PortId,
+};
+use ibc::core::ics24_host::path::{
+    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath,
+    ConnectionPath, Path, ReceiptBitmapPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
 use ibc::core::router::Router;
-use ibc::core::timestamp::Timestamp;
-use ibc::core::{dispatch, MsgEnvelope, ValidationContext};
+use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
+use ibc::core::{dispatch, BlockedPorts, MsgEnvelope, ValidationContext};
 use ibc::prelude::*;
-use ibc::Height;
+use ibc::proto::{Any, Protobuf};
+use ibc::{Bech32SignerValidator, Height};
 use parking_lot::Mutex;
+use prost::Message;
 use tendermint_testgen::Validator as TestgenValidator;
 use tracing::debug;
 use typed_builder::TypedBuilder;
 
 use super::client_ctx::{MockClientRecord, PortChannelIdMap};
+use super::merkle::{membership_proof, multistore_root, non_membership_proof, MerkleStore};
 use crate::hosts::block::{HostBlock, HostType};
 use crate::relayer::error::RelayerError;
 use crate::testapp::ibc::clients::mock::client_state::{
-    client_type as mock_client_type, MockClientState, MOCK_CLIENT_TYPE,
+    client_type as mock_client_type, MockClientBehavior, MockClientState, MOCK_CLIENT_TYPE,
 };
 use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use crate::testapp::ibc::clients::mock::header::MockHeader;
-use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+use crate::testapp::ibc::clients::{client_type_registry, AnyClientState, AnyConsensusState};
 use crate::utils::clients::tendermint::{
     dummy_tm_client_state_from_header, ClientStateConfig as TmClientStateConfig,
 };
+use crate::utils::proofs::ProofGenerator;
 pub const DEFAULT_BLOCK_TIME_SECS: u64 = 3;
 
+/// The prefix under which the IBC state of a [`MockContext`] is committed.
+pub const MOCK_COMMITMENT_PREFIX: &[u8] = b"mock";
+
+/// The clock of the host chain of a [`MockContext`], which timestamps its
+/// initial history of blocks, as well as the states of the clients it is
+/// set up with.
+///
+/// Once the context is created, its blocks are produced at the pace of its
+/// block time, or as driven by [`MockContext::advance_time`] and
+/// [`MockContext::advance_blocks`].
+pub trait MockClock: Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> Timestamp;
+}
+
+/// The system clock, which the contexts use by default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl MockClock for SystemClock {
+    fn now(&self) -> Timestamp {
+        Timestamp::now()
+    }
+}
+
+/// A clock stopped at the given time, so that the timestamps of a context
+/// are the same on every run.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedClock(pub Timestamp);
+
+impl MockClock for FixedClock {
+    fn now(&self) -> Timestamp {
+        self.0
+    }
+}
+
+/// Rewrites the stored states of a client from one version of their schema
+/// into the next.
+pub type ClientStoreMigration = fn(&mut MockClientRecord) -> Result<(), ClientError>;
+
 /// An object that stores all IBC related data.
 #[derive(Clone, Debug, Default)]
 pub struct MockIbcStore {
@@ -65,12 +125,21 @@ pub struct MockIbcStore {
     /// Counter for connection identifiers (see `increase_connection_counter`).
     pub connection_ids_counter: u64,
 
+    /// Reverse index from the counterparty client and connection ids of a
+    /// connection to its local id.
+    pub connection_ids_by_counterparty: BTreeMap<(ClientId, ConnectionId), ConnectionId>,
+
     /// Association between connection ids and channel ids.
     pub connection_channels: BTreeMap<ConnectionId, Vec<(PortId, ChannelId)>>,
 
     /// Counter for channel identifiers (see `increase_channel_counter`).
     pub channel_ids_counter: u64,
 
+    /// Reverse index from the local connection and counterparty port and
+    /// channel ids of a channel to its local port and channel ids.
+    pub channel_ids_by_counterparty:
+        BTreeMap<(ConnectionId, PortId, ChannelId), (PortId, ChannelId)>,
+
     /// All the channels in the store. TODO Make new key PortId X ChannelId
     pub channels: PortChannelIdMap<ChannelEnd>,
 
@@ -90,6 +159,257 @@ pub struct MockIbcStore {
 
     // Used by unordered channel
     pub packet_receipt: PortChannelIdMap<BTreeMap<Sequence, Receipt>>,
+
+    /// Maximum size of the data of sent packets (see
+    /// [`ValidationContext::max_packet_data_size`]).
+    pub max_packet_data_size: Option<usize>,
+
+    /// How long before their expiry updated clients are reported as near
+    /// expiry (see [`ValidationContext::client_expiry_warning_margin`]).
+    pub client_expiry_warning_margin: Option<Duration>,
+
+    /// The parameters of the client module.
+    pub client_params: ClientParams,
+
+    /// The parameters of the connection module.
+    pub connection_params: ConnectionParams,
+
+    /// The misbehaviours that froze clients, in the order they were reported
+    /// to [`on_client_frozen`](ibc::core::ics02_client::ClientExecutionContext::on_client_frozen).
+    pub frozen_clients: Vec<ClientMisbehaviour>,
+
+    /// The version of the schema of the stored states of each client, which
+    /// is unversioned until migrated (see
+    /// [`MigrateClientStore`](ibc::core::ics02_client::migration::MigrateClientStore)).
+    pub client_store_versions: BTreeMap<ClientId, u32>,
+
+    /// The latest version of the schema of client stores.
+    pub latest_client_store_version: u32,
+
+    /// The migrations of the stores of clients, indexed by the version they
+    /// migrate from.
+    pub client_store_migrations: BTreeMap<u32, ClientStoreMigration>,
+
+    /// The upgrades in progress of connections (see
+    /// [`ConnectionUpgradeContext`](ibc::core::ics03_connection::upgrade::ConnectionUpgradeContext)).
+    pub connection_upgrades: BTreeMap<ConnectionId, ConnectionUpgrade>,
+
+    /// The ports whose messages the host rejects (see
+    /// [`ValidationContext::msg_filter`]).
+    pub blocked_ports: BlockedPorts,
+
+    /// The channels on which the host rejects received packets (see
+    /// [`ValidationContext::msg_filter`]).
+    pub disabled_channels: BTreeSet<(PortId, ChannelId)>,
+
+    /// The bech32 format the host requires of the signers of messages (see
+    /// [`ValidationContext::validate_message_signer`]), which accepts any
+    /// signer if unset.
+    pub signer_validator: Option<Bech32SignerValidator>,
+
+    /// Whether the host rejects the creation of clients identical to existing
+    /// ones (see [`ValidationContext::find_duplicate_client`]), which it does
+    /// not by default.
+    pub reject_duplicate_clients: bool,
+
+    /// The config the host validates the identifiers of messages against
+    /// (see [`ValidationContext::identifier_validation_config`]).
+    pub identifier_validation_config: IdentifierValidationConfig,
+
+    /// The proofs the host received out of band, by hash (see
+    /// [`ValidationContext::proof_resolver`]), or `None` if the host receives
+    /// no proofs out of band.
+    pub out_of_band_proofs: Option<BTreeMap<ProofHash, Vec<u8>>>,
+
+    /// The channels recording their receipts, as their counterparties do, in
+    /// bitmaps (see [`MockContext::with_receipt_bitmap`]).
+    pub receipt_bitmap_channels: BTreeSet<(PortId, ChannelId)>,
+
+    /// The receipt bitmaps of the chunks of the channels recording their
+    /// receipts in bitmaps.
+    pub receipt_bitmaps: BTreeMap<ReceiptBitmapPath, ReceiptBitmap>,
+}
+
+impl MockIbcStore {
+    /// Returns the store of a chain at genesis, whose expected time per block
+    /// is `block_time`.
+    pub fn new(block_time: Duration) -> Self {
+        Self {
+            connection_params: ConnectionParams::new(block_time),
+            ..Default::default()
+        }
+    }
+
+    /// Stores the given connection end, keeping the counterparty reverse
+    /// index up to date.
+    pub fn insert_connection(&mut self, conn_id: ConnectionId, conn_end: ConnectionEnd) {
+        self.remove_connection(&conn_id);
+        if let Some(key) = connection_index_key(&conn_end) {
+            self.connection_ids_by_counterparty
+                .insert(key, conn_id.clone());
+        }
+        self.connections.insert(conn_id, conn_end);
+    }
+
+    /// Removes the given connection end, along with its entry in the
+    /// counterparty reverse index.
+    pub fn remove_connection(&mut self, conn_id: &ConnectionId) -> Option<ConnectionEnd> {
+        let conn_end = self.connections.remove(conn_id)?;
+        if let Some(key) = connection_index_key(&conn_end) {
+            if self.connection_ids_by_counterparty.get(&key) == Some(conn_id) {
+                self.connection_ids_by_counterparty.remove(&key);
+            }
+        }
+        Some(conn_end)
+    }
+
+    /// Stores the given channel end, keeping the counterparty reverse index
+    /// up to date.
+    pub fn insert_channel(&mut self, port_id: PortId, chan_id: ChannelId, chan_end: ChannelEnd) {
+        self.remove_channel(&port_id, &chan_id);
+        if let Some(key) = channel_index_key(&chan_end) {
+            self.channel_ids_by_counterparty
+                .insert(key, (port_id.clone(), chan_id.clone()));
+        }
+        self.channels
+            .entry(port_id)
+            .or_default()
+            .insert(chan_id, chan_end);
+    }
+
+    /// Removes the given channel end, along with its entry in the
+    /// counterparty reverse index.
+    pub fn remove_channel(&mut self, port_id: &PortId, chan_id: &ChannelId) -> Option<ChannelEnd> {
+        let chan_end = self.channels.get_mut(port_id)?.remove(chan_id)?;
+        if let Some(key) = channel_index_key(&chan_end) {
+            let ids = self.channel_ids_by_counterparty.get(&key);
+            if ids.map_or(false, |(p, c)| p == port_id && c == chan_id) {
+                self.channel_ids_by_counterparty.remove(&key);
+            }
+        }
+        Some(chan_end)
+    }
+
+    /// Returns a Merkle store holding the provable part of the IBC state,
+    /// indexed by ICS-24 paths and encoded the way handlers expect to find it
+    /// on the counterparty.
+    pub fn commitment_store(&self) -> MerkleStore {
+        let mut store = MerkleStore::new();
+
+        for (client_id, record) in &self.clients {
+            if let Some(client_state) = &record.client_state {
+                store.set(
+                    ClientStatePath::new(client_id).to_string(),
+                    Any::from(client_state.clone()).encode_to_vec(),
+                );
+            }
+            for (height, consensus_state) in &record.consensus_states {
+                store.set(
+                    ClientConsensusStatePath::new(client_id, height).to_string(),
+                    Any::from(consensus_state.clone()).encode_to_vec(),
+                );
+            }
+        }
+
+        for (conn_id, conn_end) in &self.connections {
+            store.set(
+                ConnectionPath::new(conn_id).to_string(),
+                conn_end.clone().encode_vec(),
+            );
+        }
+
+        for (port_id, chan_id, chan_end) in iter_port_channels(&self.channels) {
+            store.set(
+                ChannelEndPath::new(port_id, chan_id).to_string(),
+                chan_end.clone().encode_vec(),
+            );
+        }
+
+        for (port_id, chan_id, seq) in iter_port_channels(&self.next_sequence_send) {
+            store.set(
+                SeqSendPath::new(port_id, chan_id).to_string(),
+                u64::from(*seq).encode_to_vec(),
+            );
+        }
+        for (port_id, chan_id, seq) in iter_port_channels(&self.next_sequence_recv) {
+            store.set(
+                SeqRecvPath::new(port_id, chan_id).to_string(),
+                u64::from(*seq).encode_to_vec(),
+            );
+        }
+        for (port_id, chan_id, seq) in iter_port_channels(&self.next_sequence_ack) {
+            store.set(
+                SeqAckPath::new(port_id, chan_id).to_string(),
+                u64::from(*seq).encode_to_vec(),
+            );
+        }
+
+        for (port_id, chan_id, commitments) in iter_port_channels(&self.packet_commitment) {
+            for (seq, commitment) in commitments {
+                store.set(
+                    CommitmentPath::new(port_id, chan_id, *seq).to_string(),
+                    commitment.clone().into_vec(),
+                );
+            }
+        }
+        for (port_id, chan_id, receipts) in iter_port_channels(&self.packet_receipt) {
+            for seq in receipts.keys() {
+                store.set(
+                    ReceiptPath::new(port_id, chan_id, *seq).to_string(),
+                    vec![1],
+                );
+            }
+        }
+        for (bitmap_path, bitmap) in &self.receipt_bitmaps {
+            store.set(bitmap_path.to_string(), bitmap.as_bytes().to_vec());
+        }
+        for (port_id, chan_id, acks) in iter_port_channels(&self.packet_acknowledgement) {
+            for (seq, ack) in acks {
+                store.set(
+                    AckPath::new(port_id, chan_id, *seq).to_string(),
+                    ack.clone().into_vec(),
+                );
+            }
+        }
+
+        store
+    }
+}
+
+/// Returns the key of a connection in the counterparty reverse index, if its
+/// counterparty connection id is known.
+fn connection_index_key(conn_end: &ConnectionEnd) -> Option<(ClientId, ConnectionId)> {
+    let counterparty = conn_end.counterparty();
+    counterparty
+        .connection_id()
+        .map(|conn_id_on_b| (counterparty.client_id().clone(), conn_id_on_b.clone()))
+}
+
+/// Returns the key of a channel in the counterparty reverse index, if its
+/// counterparty channel id is known.
+fn channel_index_key(chan_end: &ChannelEnd) -> Option<(ConnectionId, PortId, ChannelId)> {
+    let counterparty = chan_end.counterparty();
+    match (
+        chan_end.connection_hops().first(),
+        counterparty.channel_id(),
+    ) {
+        (Some(conn_id), Some(chan_id_on_b)) => Some((
+            conn_id.clone(),
+            counterparty.port_id().clone(),
+            chan_id_on_b.clone(),
+        )),
+        _ => None,
+    }
+}
+
+fn iter_port_channels<V>(
+    map: &PortChannelIdMap<V>,
+) -> impl Iterator<Item = (&PortId, &ChannelId, &V)> {
+    map.iter().flat_map(|(port_id, channels)| {
+        channels
+            .iter()
+            .map(move |(chan_id, value)| (port_id, chan_id, value))
+    })
 }
 
 /// A context implementing the dependencies necessary for testing any IBC module.
@@ -111,9 +431,30 @@ pub struct MockContext {
     /// Average time duration between blocks
     pub block_time: Duration,
 
+    /// Time advanced through [`MockContext::advance_time`] since the latest
+    /// block, by which the next block is delayed.
+    pub block_delay: Duration,
+
+    /// The clock timestamping the clients this context is set up with.
+    pub clock: Arc<dyn MockClock>,
+
+    /// The client types known to the host (see
+    /// [`ValidationContext::known_client_types`]), from which it decodes the
+    /// client states of the messages, and which default to those of
+    /// [`client_type_registry`].
+    pub client_types: ClientTypeRegistry<AnyClientState, AnyConsensusState>,
+
     /// An object that stores all IBC related data.
     pub ibc_store: Arc<Mutex<MockIbcStore>>,
 
+    /// The IBC state committed by each block this context produced that is
+    /// still in its history, against which ICS-24 paths are proven.
+    pub proofs: ProofGenerator,
+
+    /// The timeouts of the packets sent by the host, tracked once set up with
+    /// [`MockContext::with_timeout_queue`].
+    pub timeout_queue: Option<TimeoutQueue>,
+
     pub events: Vec<IbcEvent>,
 
     pub logs: Vec<String>,
@@ -135,6 +476,10 @@ pub struct MockClientConfig {
     pub trusting_period: Duration,
     #[builder(default = Duration::from_millis(3000))]
     max_clock_drift: Duration,
+
+    /// The behaviour programmed into the client, if it is a mock client.
+    #[builder(default)]
+    behavior: MockClientBehavior,
 }
 
 /// Returns a MockContext with bare minimum initialization: no clients, no connections and no channels are
@@ -166,7 +511,12 @@ impl Clone for MockContext {
             max_history_size: self.max_history_size,
             history: self.history.clone(),
             block_time: self.block_time,
+            block_delay: self.block_delay,
+            clock: self.clock.clone(),
+            client_types: self.client_types.clone(),
             ibc_store,
+            proofs: self.proofs.clone(),
+            timeout_queue: self.timeout_queue.clone(),
             events: self.events.clone(),
             logs: self.logs.clone(),
         }
@@ -185,6 +535,24 @@ impl MockContext {
         host_type: HostType,
         max_history_size: u64,
         latest_height: Height,
+    ) -> Self {
+        Self::new_with_clock(
+            host_id,
+            host_type,
+            max_history_size,
+            latest_height,
+            Arc::new(SystemClock),
+        )
+    }
+
+    /// Same as [Self::new] but with the given clock, whose current time is
+    /// the timestamp of the latest block.
+    pub fn new_with_clock(
+        host_id: ChainId,
+        host_type: HostType,
+        max_history_size: u64,
+        latest_height: Height,
+        clock: Arc<dyn MockClock>,
     ) -> Self {
         assert_ne!(
             max_history_size, 0,
@@ -207,7 +575,7 @@ impl MockContext {
         );
 
         let block_time = Duration::from_secs(DEFAULT_BLOCK_TIME_SECS);
-        let next_block_timestamp = Timestamp::now().add(block_time).expect("Never fails");
+        let next_block_timestamp = clock.now().add(block_time).expect("Never fails");
         MockContext {
             host_chain_type: host_type,
             host_chain_id: host_id.clone(),
@@ -216,7 +584,7 @@ impl MockContext {
                 .rev()
                 .map(|i| {
                     // generate blocks with timestamps -> N, N - BT, N - 2BT, ...
-                    // where N = now(), BT = block_time
+                    // where N = clock.now(), BT = block_time
                     HostBlock::generate_block(
                         host_id.clone(),
                         host_type,
@@ -228,7 +596,12 @@ impl MockContext {
                 })
                 .collect(),
             block_time,
-            ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
+            block_delay: ZERO_DURATION,
+            clock,
+            client_types: client_type_registry(),
+            ibc_store: Arc::new(Mutex::new(MockIbcStore::new(block_time))),
+            proofs: ProofGenerator::new(MOCK_COMMITMENT_PREFIX.to_vec()),
+            timeout_queue: None,
             events: Vec::new(),
             logs: Vec::new(),
         }
@@ -295,7 +668,12 @@ impl MockContext {
             max_history_size,
             history,
             block_time,
-            ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
+            block_delay: ZERO_DURATION,
+            clock: Arc::new(SystemClock),
+            client_types: client_type_registry(),
+            ibc_store: Arc::new(Mutex::new(MockIbcStore::new(block_time))),
+            proofs: ProofGenerator::new(MOCK_COMMITMENT_PREFIX.to_vec()),
+            timeout_queue: None,
             events: Vec::new(),
             logs: Vec::new(),
         }
@@ -351,17 +729,20 @@ impl MockContext {
             (
                 Some(
                     MockClientState::new(
-                        MockHeader::new(client_state_height).with_current_timestamp(),
+                        MockHeader::new(client_state_height).with_timestamp(self.clock.now()),
                     )
                     .into(),
                 ),
-                MockConsensusState::new(MockHeader::new(cs_height).with_current_timestamp()).into(),
+                MockConsensusState::new(
+                    MockHeader::new(cs_height).with_timestamp(self.clock.now()),
+                )
+                .into(),
             )
         } else if client_type.as_str() == TENDERMINT_CLIENT_TYPE {
             let light_block = HostBlock::generate_tm_block(
                 client_chain_id,
                 cs_height.revision_height(),
-                Timestamp::now(),
+                self.clock.now(),
             );
 
             let client_state =
@@ -417,7 +798,7 @@ impl MockContext {
         let prev_cs_height = cs_height.clone().sub(1).unwrap_or(client_state_height);
 
         let client_type = client_type.unwrap_or_else(mock_client_type);
-        let now = Timestamp::now();
+        let now = self.clock.now();
 
         let (client_state, consensus_state): (Option<AnyClientState>, AnyConsensusState) =
             if client_type.as_str() == MOCK_CLIENT_TYPE {
@@ -483,12 +864,25 @@ impl MockContext {
 
         let (client_state, consensus_states) = match client.client_type.as_str() {
             MOCK_CLIENT_TYPE => {
+                let n_blocks = cs_heights.len();
                 let blocks: Vec<_> = cs_heights
                     .into_iter()
-                    .map(|cs_height| (cs_height, MockHeader::new(cs_height)))
+                    .enumerate()
+                    .map(|(i, cs_height)| {
+                        (
+                            cs_height,
+                            MockHeader::new(cs_height).with_timestamp(
+                                client
+                                    .latest_timestamp
+                                    .sub(self.block_time * ((n_blocks - 1 - i) as u32))
+                                    .expect("never fails"),
+                            ),
+                        )
+                    })
                     .collect();
 
-                let client_state = MockClientState::new(blocks.last().expect("never fails").1);
+                let client_state = MockClientState::new(blocks.last().expect("never fails").1)
+                    .with_behavior(client.behavior);
 
                 let cs_states = blocks
                     .into_iter()
@@ -558,8 +952,7 @@ impl MockContext {
     ) -> Self {
         self.ibc_store
             .lock()
-            .connections
-            .insert(connection_id, connection_end);
+            .insert_connection(connection_id, connection_end);
         self
     }
 
@@ -570,12 +963,84 @@ impl MockContext {
         chan_id: ChannelId,
         channel_end: ChannelEnd,
     ) -> Self {
-        let mut channels = self.ibc_store.lock().channels.clone();
-        channels
-            .entry(port_id)
-            .or_default()
-            .insert(chan_id, channel_end);
-        self.ibc_store.lock().channels = channels;
+        self.ibc_store
+            .lock()
+            .insert_channel(port_id, chan_id, channel_end);
+        self
+    }
+
+    /// Rejects the channel and packet messages on any of the given ports.
+    pub fn with_blocked_ports(self, port_ids: impl IntoIterator<Item = PortId>) -> Self {
+        self.ibc_store.lock().blocked_ports = BlockedPorts::new(port_ids);
+        self
+    }
+
+    /// Records the receipts of the given channel, as its counterparty does,
+    /// in bitmaps.
+    pub fn with_receipt_bitmap(self, port_id: PortId, chan_id: ChannelId) -> Self {
+        self.ibc_store
+            .lock()
+            .receipt_bitmap_channels
+            .insert((port_id, chan_id));
+        self
+    }
+
+    /// Tracks the timeouts of the packets sent by the host in a
+    /// [`TimeoutQueue`].
+    pub fn with_timeout_queue(self) -> Self {
+        Self {
+            timeout_queue: Some(TimeoutQueue::new()),
+            ..self
+        }
+    }
+
+    /// Receives the given proof out of band, so that messages may refer to it
+    /// by hash.
+    pub fn with_out_of_band_proof(self, proof: Vec<u8>) -> Self {
+        self.ibc_store
+            .lock()
+            .out_of_band_proofs
+            .get_or_insert_with(BTreeMap::new)
+            .insert(ProofHash::of(&proof), proof);
+        self
+    }
+
+    /// Resolves the proofs messages refer to by hash, before receiving any of
+    /// them out of band.
+    pub fn with_proof_resolver(self) -> Self {
+        self.ibc_store
+            .lock()
+            .out_of_band_proofs
+            .get_or_insert_with(BTreeMap::new);
+        self
+    }
+
+    /// Rejects the messages whose signers are not bech32 addresses with the
+    /// given human-readable prefix.
+    pub fn with_bech32_signers(self, prefix: impl Into<String>) -> Self {
+        self.ibc_store.lock().signer_validator = Some(Bech32SignerValidator::new(prefix));
+        self
+    }
+
+    /// Rejects the creation of clients identical to existing ones.
+    pub fn with_duplicate_clients_rejected(self) -> Self {
+        self.ibc_store.lock().reject_duplicate_clients = true;
+        self
+    }
+
+    /// Validates the identifiers of the messages handled by the host against
+    /// the given config.
+    pub fn with_identifier_validation_config(self, config: IdentifierValidationConfig) -> Self {
+        self.ibc_store.lock().identifier_validation_config = config;
+        self
+    }
+
+    /// Rejects the packets received on the given channel.
+    pub fn with_disabled_channel(self, port_id: PortId, chan_id: ChannelId) -> Self {
+        self.ibc_store
+            .lock()
+            .disabled_channels
+            .insert((port_id, chan_id));
         self
     }
 
@@ -615,7 +1080,7 @@ impl MockContext {
         chan_id: ChannelId,
         seq_number: Sequence,
     ) -> Self {
-        let mut next_sequence_ack = self.ibc_store.lock().next_sequence_send.clone();
+        let mut next_sequence_ack = self.ibc_store.lock().next_sequence_ack.clone();
         next_sequence_ack
             .entry(port_id)
             .or_default()
@@ -678,17 +1143,69 @@ impl MockContext {
     }
 
     /// Triggers the advancing of the host chain, by extending the history of blocks (or headers).
+    ///
+    /// On a synthetic Tendermint host, the new block commits to the current
+    /// IBC state in its app hash (see [`Self::commitment_root`]).
     pub fn advance_host_chain_height(&mut self) {
-        let latest_block = self.history.last().expect("history cannot be empty");
-        let new_block = HostBlock::generate_block(
-            self.host_chain_id.clone(),
-            self.host_chain_type,
-            latest_block.height().increment().revision_height(),
-            latest_block
+        self.advance_blocks(1, self.block_time)
+    }
+
+    /// Advances the host chain by `n` blocks, each produced `block_time` after
+    /// the previous one, whatever the block time of this context.
+    ///
+    /// Any time advanced through [`Self::advance_time`] since the latest block
+    /// further delays the first of them.
+    pub fn advance_blocks(&mut self, n: u64, block_time: Duration) {
+        for _ in 0..n {
+            let latest_block = self.history.last().expect("history cannot be empty");
+            let height = latest_block.height().increment().revision_height();
+            let timestamp = latest_block
                 .timestamp()
-                .add(self.block_time)
-                .expect("Never fails"),
-        );
+                .add(block_time + self.block_delay)
+                .expect("Never fails");
+            self.block_delay = ZERO_DURATION;
+
+            self.push_block(height, timestamp);
+        }
+    }
+
+    /// Advances the host clock by `duration`, without producing any block: the
+    /// host timestamp moves forward while the host height stays the same, and
+    /// the next block is produced that much later.
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.block_delay += duration;
+    }
+
+    /// Returns the timestamp of the next block, which is the current host
+    /// timestamp.
+    pub fn next_block_timestamp(&self) -> Timestamp {
+        self.history
+            .last()
+            .expect("history cannot be empty")
+            .timestamp()
+            .add(self.block_time + self.block_delay)
+            .expect("Never fails")
+    }
+
+    fn push_block(&mut self, height: u64, timestamp: Timestamp) {
+        let new_block = match self.host_chain_type {
+            HostType::Mock => HostBlock::generate_block(
+                self.host_chain_id.clone(),
+                self.host_chain_type,
+                height,
+                timestamp,
+            ),
+            HostType::SyntheticTendermint => HostBlock::SyntheticTendermint(Box::new(
+                HostBlock::generate_tm_block_with_app_hash(
+                    self.host_chain_id.clone(),
+                    height,
+                    timestamp,
+                    self.commitment_root(),
+                ),
+            )),
+        };
+
+        let new_height = new_block.height();
 
         // Append the new header at the tip of the history.
         if self.history.len() as u64 >= self.max_history_size {
@@ -699,6 +1216,10 @@ impl MockContext {
             // History is not full yet.
             self.history.push(new_block);
         }
+
+        let store = self.ibc_store.lock().commitment_store();
+        self.proofs.record(new_height, store);
+        self.proofs.prune_below(&self.history[0].height());
     }
 
     /// A datagram passes from the relayer to the IBC module (on host chain).
@@ -774,6 +1295,37 @@ impl MockContext {
         self.ibc_store.clone()
     }
 
+    /// Returns the root committing to the current IBC state of this context,
+    /// under its commitment prefix.
+    pub fn commitment_root(&self) -> CommitmentRoot {
+        let store = self.ibc_store.lock().commitment_store();
+        multistore_root(self.commitment_prefix().as_bytes(), &store.root())
+    }
+
+    /// Returns a proof that `path` is present in the current IBC state, to be
+    /// verified against the root of the next block (see
+    /// [`Self::commitment_root`]). Returns `None` if the path is absent.
+    pub fn membership_proof(&self, path: &Path) -> Option<CommitmentProofBytes> {
+        let store = self.ibc_store.lock().commitment_store();
+        membership_proof(
+            &store,
+            self.commitment_prefix().as_bytes(),
+            path.to_string().as_bytes(),
+        )
+    }
+
+    /// Returns a proof that `path` is absent from the current IBC state, to be
+    /// verified against the root of the next block (see
+    /// [`Self::commitment_root`]). Returns `None` if the path is present.
+    pub fn non_membership_proof(&self, path: &Path) -> Option<CommitmentProofBytes> {
+        let store = self.ibc_store.lock().commitment_store();
+        non_membership_proof(
+            &store,
+            self.commitment_prefix().as_bytes(),
+            path.to_string().as_bytes(),
+        )
+    }
+
     pub fn query_latest_header(&self) -> Option<HostBlock> {
         let block_ref = self.host_block(&self.host_height().expect("Never fails"));
         block_ref.cloned()
@@ -782,8 +1334,12 @@ impl MockContext {
 
 #[cfg(test)]
 mod tests {
+    use ibc::core::ics03_connection::connection::{
+        Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use ibc::core::ics03_connection::version::get_compatible_versions;
     use ibc::core::ics04_channel::acknowledgement::Acknowledgement;
-    use ibc::core::ics04_channel::channel::{Counterparty, Order};
+    use ibc::core::ics04_channel::channel::{Counterparty, Order, State};
     use ibc::core::ics04_channel::error::{ChannelError, PacketError};
     use ibc::core::ics04_channel::packet::Packet;
     use ibc::core::ics04_channel::Version;
@@ -797,6 +1353,55 @@ mod tests {
     use crate::utils::core::channel::PacketConfig;
     use crate::utils::core::signer::dummy_bech32_account;
 
+    #[test]
+    fn test_counterparty_index_cleanup() {
+        let mut store = MockIbcStore::default();
+        let client_id = ClientId::default();
+        let conn_id = ConnectionId::new(0);
+        let conn_id_on_b = ConnectionId::new(1);
+
+        let conn_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            client_id.clone(),
+            ConnectionCounterparty::new(
+                client_id.clone(),
+                Some(conn_id_on_b.clone()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            ZERO_DURATION,
+        )
+        .unwrap();
+        store.insert_connection(conn_id.clone(), conn_end);
+        assert_eq!(
+            store
+                .connection_ids_by_counterparty
+                .get(&(client_id.clone(), conn_id_on_b.clone())),
+            Some(&conn_id)
+        );
+        assert!(store.remove_connection(&conn_id).is_some());
+        assert!(store.connection_ids_by_counterparty.is_empty());
+
+        let port_id = PortId::transfer();
+        let chan_id = ChannelId::new(0);
+        let chan_end = |chan_id_on_b: u64| {
+            ChannelEnd::new(
+                State::Open,
+                Order::Unordered,
+                Counterparty::new(PortId::transfer(), Some(ChannelId::new(chan_id_on_b))),
+                vec![conn_id.clone()],
+                Version::new("ics20-1".to_string()),
+            )
+            .unwrap()
+        };
+        store.insert_channel(port_id.clone(), chan_id.clone(), chan_end(1));
+        // replacing the end evicts the entry of the previous counterparty
+        store.insert_channel(port_id.clone(), chan_id.clone(), chan_end(2));
+        assert_eq!(store.channel_ids_by_counterparty.len(), 1);
+        assert!(store.remove_channel(&port_id, &chan_id).is_some());
+        assert!(store.channel_ids_by_counterparty.is_empty());
+    }
+
     #[test]
     fn test_history_manipulation() {
         pub struct Test {
@@ -941,9 +1546,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_time_travel() {
+        let genesis = Timestamp::from_nanoseconds(1_000_000_000_000).expect("Never fails");
+        let block_time = Duration::from_secs(DEFAULT_BLOCK_TIME_SECS);
+
+        let new_ctx = || {
+            MockContext::new_with_clock(
+                ChainId::new("mockgaia-0").expect("Never fails"),
+                HostType::Mock,
+                5,
+                Height::new(0, 5).expect("Never fails"),
+                Arc::new(FixedClock(genesis)),
+            )
+        };
+        let mut ctx = new_ctx();
+
+        // the clock timestamps the latest block
+        assert_eq!(ctx.query_latest_header().unwrap().timestamp(), genesis);
+        assert_eq!(
+            ctx.host_timestamp().unwrap(),
+            (genesis + block_time).unwrap()
+        );
+        assert_eq!(new_ctx().history, ctx.history);
+
+        // time passes, but no block is produced
+        ctx.advance_time(Duration::from_secs(60));
+        assert_eq!(ctx.latest_height(), Height::new(0, 5).unwrap());
+        assert_eq!(
+            ctx.host_timestamp().unwrap(),
+            (genesis + Duration::from_secs(63)).unwrap()
+        );
+
+        // until the next block, which is delayed as much
+        ctx.advance_host_chain_height();
+        assert_eq!(ctx.latest_height(), Height::new(0, 6).unwrap());
+        assert_eq!(
+            ctx.query_latest_header().unwrap().timestamp(),
+            (genesis + Duration::from_secs(63)).unwrap()
+        );
+        assert_eq!(
+            ctx.host_timestamp().unwrap(),
+            (genesis + Duration::from_secs(66)).unwrap()
+        );
+
+        // blocks produced at another pace
+        ctx.advance_blocks(10, Duration::from_secs(1));
+        assert!(ctx.validate().is_ok());
+        assert_eq!(ctx.latest_height(), Height::new(0, 16).unwrap());
+        assert_eq!(
+            ctx.query_latest_header().unwrap().timestamp(),
+            (genesis + Duration::from_secs(73)).unwrap()
+        );
+        assert_eq!(
+            ctx.host_timestamp().unwrap(),
+            (genesis + Duration::from_secs(76)).unwrap()
+        );
+    }
+
     #[test]
     fn test_router() {
-        #[derive(Debug, Default)]
+        #[derive(Clone, Debug, Default)]
         struct FooModule {
             counter: u64,
         }
@@ -1045,7 +1708,7 @@ mod tests {
             }
         }
 
-        #[derive(Debug, Default)]
+        #[derive(Clone, Debug, Default)]
         struct BarModule;
 
         impl Module for BarModule {
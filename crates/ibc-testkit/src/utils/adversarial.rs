@@ -0,0 +1,144 @@
+//! Generators of adversarial inputs, for testing that light clients and
+//! handlers reject what a byzantine counterparty or a faulty relayer could
+//! submit, without handcrafting binary fixtures.
+
+use core::ops::Sub;
+use core::time::Duration;
+
+use ibc::clients::ics07_tendermint::client_type as tm_client_type;
+use ibc::clients::ics07_tendermint::header::Header as TmHeader;
+use ibc::clients::ics07_tendermint::misbehaviour::Misbehaviour as TmMisbehaviour;
+use ibc::core::ics04_channel::acknowledgement::Acknowledgement;
+use ibc::core::ics04_channel::channel::Order;
+use ibc::core::ics04_channel::packet::{Packet, Receipt};
+use ibc::core::ics23_commitment::commitment::{CommitmentProofBytes, CommitmentRoot};
+use ibc::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc::core::ics24_host::path::Path;
+use ibc::core::timestamp::Timestamp;
+use ibc::prelude::*;
+use ibc::proto::core::commitment::v1::MerkleProof as RawMerkleProof;
+use ibc::proto::ics23::commitment_proof::Proof;
+use ibc::proto::ics23::ExistenceProof;
+use ibc::Height;
+
+use crate::hosts::block::HostBlock;
+use crate::testapp::ibc::core::types::{MockClientConfig, MockContext};
+
+/// Returns two headers of `chain_id` at `height`, both trusting
+/// `trusted_height` and signed by the same validators, that commit to
+/// different app hashes: evidence that the chain forked.
+pub fn forked_tm_headers(
+    chain_id: ChainId,
+    height: Height,
+    trusted_height: Height,
+    timestamp: Timestamp,
+) -> (TmHeader, TmHeader) {
+    let fork = |app_hash: &[u8]| -> TmHeader {
+        let mut tm_block = HostBlock::generate_tm_block_with_app_hash(
+            chain_id.clone(),
+            height.revision_height(),
+            timestamp,
+            CommitmentRoot::from_bytes(app_hash),
+        );
+        tm_block.trusted_height = trusted_height;
+        tm_block.into()
+    };
+
+    (fork(&[0x01; 32]), fork(&[0x02; 32]))
+}
+
+/// Returns the misbehaviour evidence of `client_id` built from
+/// [`forked_tm_headers`].
+pub fn forked_tm_misbehaviour(
+    client_id: ClientId,
+    chain_id: ChainId,
+    height: Height,
+    trusted_height: Height,
+    timestamp: Timestamp,
+) -> TmMisbehaviour {
+    let (header1, header2) = forked_tm_headers(chain_id, height, trusted_height, timestamp);
+    TmMisbehaviour::new(client_id, header1, header2)
+}
+
+/// Returns the configuration of a Tendermint client of `chain_id` whose latest
+/// consensus state, at `height`, is older than its trusting period as of
+/// `now`. The client is expired as soon as it is added to a context.
+pub fn expired_tm_client_config(
+    client_id: ClientId,
+    chain_id: ChainId,
+    height: Height,
+    now: Timestamp,
+) -> MockClientConfig {
+    let trusting_period = Duration::from_secs(64);
+
+    MockClientConfig::builder()
+        .client_chain_id(chain_id)
+        .client_id(client_id)
+        .client_type(tm_client_type())
+        .client_state_height(height)
+        .latest_timestamp(now.sub(trusting_period * 2).expect("Never fails"))
+        .trusting_period(trusting_period)
+        .build()
+}
+
+/// Returns a genuine proof that `path` is present in the state of
+/// `ctx`, meant to be presented as a proof of some other path: verification
+/// must fail since the proof commits to a different key.
+///
+/// Panics if `path` is absent from `ctx`.
+pub fn wrong_path_proof(ctx: &MockContext, path: &Path) -> CommitmentProofBytes {
+    ctx.membership_proof(path)
+        .expect("the proven path must be present in the context")
+}
+
+/// Returns `proof` with the value of each of its leaves altered, so that it no
+/// longer hashes to the root it was generated against.
+pub fn tampered_proof(proof: &CommitmentProofBytes) -> CommitmentProofBytes {
+    let tamper = |leaf: &mut ExistenceProof| leaf.value.push(0xff);
+
+    let mut raw_proof = RawMerkleProof::try_from(proof.clone()).expect("Never fails");
+    for commitment_proof in &mut raw_proof.proofs {
+        match &mut commitment_proof.proof {
+            Some(Proof::Exist(leaf)) => tamper(leaf),
+            Some(Proof::Nonexist(non_exist)) => {
+                non_exist.left.iter_mut().for_each(tamper);
+                non_exist.right.iter_mut().for_each(tamper);
+            }
+            _ => {}
+        }
+    }
+
+    CommitmentProofBytes::try_from(raw_proof).expect("Never fails")
+}
+
+/// Records `packet` as already received on `ctx`, so that relaying it again
+/// is a replay: a receipt is stored on unordered channels, and the next
+/// receive sequence is moved past the packet on ordered ones.
+pub fn with_received_packet(ctx: MockContext, packet: &Packet, ordering: Order) -> MockContext {
+    match ordering {
+        Order::Ordered => ctx.with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a.increment(),
+        ),
+        _ => {
+            ctx.ibc_store
+                .lock()
+                .packet_receipt
+                .entry(packet.port_id_on_b.clone())
+                .or_default()
+                .entry(packet.chan_id_on_b.clone())
+                .or_default()
+                .insert(packet.seq_on_a, Receipt::Ok);
+            ctx
+        }
+    }
+}
+
+/// Returns `ack` with one more byte, so that it no longer matches the
+/// acknowledgement commitment stored by the chain that wrote it.
+pub fn tampered_ack(ack: &Acknowledgement) -> Acknowledgement {
+    let mut bytes = ack.as_bytes().to_vec();
+    bytes.push(0xff);
+    Acknowledgement::try_from(bytes).expect("Never fails")
+}
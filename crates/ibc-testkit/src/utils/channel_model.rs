@@ -0,0 +1,227 @@
+//! An executable model of the ICS-04 channel state machine, against which the
+//! concrete channel handlers are checked on random interleavings of handshake
+//! and closing steps.
+//!
+//! The model tracks a single channel between two chains, A and B. Its
+//! transitions follow the [`TRANSITIONS`] table, taken from the ICS-04
+//! specification. A step carrying a proof of the counterparty end is only
+//! submitted when that end is in the proven state, since an honest relayer
+//! could not build the proof otherwise; whether the step is then accepted only
+//! depends on the state of the local end.
+
+use ibc::core::ics04_channel::channel::State;
+use ibc::prelude::*;
+use proptest::prelude::*;
+
+/// One of the two chains of the model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    A,
+    B,
+}
+
+impl Side {
+    pub fn counterparty(self) -> Self {
+        match self {
+            Self::A => Self::B,
+            Self::B => Self::A,
+        }
+    }
+}
+
+/// A step of the channel handshake or closing, submitted on one chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChannelStep {
+    OpenInit,
+    OpenTry,
+    OpenAck,
+    OpenConfirm,
+    CloseInit,
+    CloseConfirm,
+}
+
+impl ChannelStep {
+    /// Returns whether the step creates a new channel end rather than
+    /// updating an existing one.
+    pub fn creates_channel(self) -> bool {
+        matches!(self, Self::OpenInit | Self::OpenTry)
+    }
+}
+
+/// A row of the channel transition table.
+#[derive(Clone, Copy, Debug)]
+pub struct Transition {
+    pub step: ChannelStep,
+    /// The states the local end must be in for the step to be accepted. Empty
+    /// if the step creates the local end.
+    pub from: &'static [State],
+    /// The state of the counterparty end the step carries a proof of, if any.
+    pub proves: Option<State>,
+    /// The state the local end is in once the step is accepted.
+    pub to: State,
+}
+
+/// The states an end can be closed from. An end in `Init` cannot, as it does
+/// not know the counterparty channel yet.
+const CLOSABLE: &[State] = &[State::TryOpen, State::Open];
+
+/// The channel transition table.
+pub const TRANSITIONS: &[Transition] = &[
+    Transition {
+        step: ChannelStep::OpenInit,
+        from: &[],
+        proves: None,
+        to: State::Init,
+    },
+    Transition {
+        step: ChannelStep::OpenTry,
+        from: &[],
+        proves: Some(State::Init),
+        to: State::TryOpen,
+    },
+    Transition {
+        step: ChannelStep::OpenAck,
+        from: &[State::Init],
+        proves: Some(State::TryOpen),
+        to: State::Open,
+    },
+    Transition {
+        step: ChannelStep::OpenConfirm,
+        from: &[State::TryOpen],
+        proves: Some(State::Open),
+        to: State::Open,
+    },
+    Transition {
+        step: ChannelStep::CloseInit,
+        from: CLOSABLE,
+        proves: None,
+        to: State::Closed,
+    },
+    Transition {
+        step: ChannelStep::CloseConfirm,
+        from: CLOSABLE,
+        proves: Some(State::Closed),
+        to: State::Closed,
+    },
+];
+
+/// Returns the row of [`TRANSITIONS`] for `step`.
+pub fn transition(step: ChannelStep) -> &'static Transition {
+    TRANSITIONS
+        .iter()
+        .find(|transition| transition.step == step)
+        .expect("every step has a transition")
+}
+
+/// The abstract state of the channel: the state of its end on each chain, or
+/// `None` if that end does not exist yet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ChannelModel {
+    on_a: Option<State>,
+    on_b: Option<State>,
+}
+
+impl ChannelModel {
+    pub fn state(&self, side: Side) -> Option<State> {
+        match side {
+            Side::A => self.on_a,
+            Side::B => self.on_b,
+        }
+    }
+
+    /// Returns whether `step` can be submitted on `side`.
+    ///
+    /// Steps creating a channel end are only submitted on chains that have
+    /// none, as the model tracks a single channel, and steps carrying a proof
+    /// are only submitted when the counterparty end is in the proven state.
+    pub fn is_submittable(&self, side: Side, step: ChannelStep) -> bool {
+        if step.creates_channel() && self.state(side).is_some() {
+            return false;
+        }
+
+        match transition(step).proves {
+            Some(proven) => self.state(side.counterparty()) == Some(proven),
+            None => true,
+        }
+    }
+
+    /// Applies the submittable `step` on `side`, returning whether the
+    /// handlers are expected to accept it.
+    pub fn apply(&mut self, side: Side, step: ChannelStep) -> bool {
+        let transition = transition(step);
+        let accepted = match self.state(side) {
+            None => step.creates_channel(),
+            Some(state) => transition.from.contains(&state),
+        };
+
+        if accepted {
+            let end = match side {
+                Side::A => &mut self.on_a,
+                Side::B => &mut self.on_b,
+            };
+            *end = Some(transition.to);
+        }
+
+        accepted
+    }
+}
+
+pub fn arb_side() -> impl Strategy<Value = Side> {
+    prop_oneof![Just(Side::A), Just(Side::B)]
+}
+
+pub fn arb_channel_step() -> impl Strategy<Value = ChannelStep> {
+    prop_oneof![
+        Just(ChannelStep::OpenInit),
+        Just(ChannelStep::OpenTry),
+        Just(ChannelStep::OpenAck),
+        Just(ChannelStep::OpenConfirm),
+        Just(ChannelStep::CloseInit),
+        Just(ChannelStep::CloseConfirm),
+    ]
+}
+
+/// Generates interleavings of up to `max_len` steps on either chain.
+pub fn arb_channel_steps(max_len: usize) -> impl Strategy<Value = Vec<(Side, ChannelStep)>> {
+    prop::collection::vec((arb_side(), arb_channel_step()), 1..=max_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handshake_then_close() {
+        let mut model = ChannelModel::default();
+
+        for (side, step) in [
+            (Side::A, ChannelStep::OpenInit),
+            (Side::B, ChannelStep::OpenTry),
+            (Side::A, ChannelStep::OpenAck),
+            (Side::B, ChannelStep::OpenConfirm),
+            (Side::B, ChannelStep::CloseInit),
+            (Side::A, ChannelStep::CloseConfirm),
+        ] {
+            assert!(model.is_submittable(side, step), "{step:?} on {side:?}");
+            assert!(model.apply(side, step), "{step:?} on {side:?}");
+        }
+
+        assert_eq!(model.state(Side::A), Some(State::Closed));
+        assert_eq!(model.state(Side::B), Some(State::Closed));
+    }
+
+    #[test]
+    fn out_of_order_steps_are_rejected() {
+        let mut model = ChannelModel::default();
+        assert!(!model.apply(Side::A, ChannelStep::CloseInit));
+        assert!(model.apply(Side::A, ChannelStep::OpenInit));
+        assert!(!model.apply(Side::A, ChannelStep::OpenConfirm));
+        assert!(!model.apply(Side::A, ChannelStep::CloseInit));
+        assert!(model.apply(Side::B, ChannelStep::OpenTry));
+        assert!(model.apply(Side::A, ChannelStep::OpenAck));
+        assert!(model.apply(Side::A, ChannelStep::CloseInit));
+        assert!(!model.apply(Side::A, ChannelStep::CloseInit));
+        assert!(!model.is_submittable(Side::A, ChannelStep::OpenInit));
+        assert!(!model.is_submittable(Side::B, ChannelStep::OpenTry));
+    }
+}
@@ -0,0 +1,466 @@
+//! A conformance suite for light client implementations.
+//!
+//! Any implementation of [`ClientState`] can be certified against the
+//! behaviour the IBC handlers expect from it, by implementing
+//! [`ClientConformance`] to provide the fixtures the checks run on, and
+//! running [`check_conformance`]. The checks cover updates, misbehaviour and
+//! the frozen status that follows, proof verification, upgrades and expiry;
+//! those whose fixture a client does not provide are skipped.
+//!
+//! Every check runs on a fresh context from [`ClientConformance::context`],
+//! and calls the client state the way the handlers do, so that a client
+//! passing the suite can be relied upon by them.
+
+use displaydoc::Display;
+use ibc::core::ics02_client::client_state::{
+    ClientState, ClientStateCommon, ClientStateExecution, ClientStateValidation, Status, UpdateKind,
+};
+use ibc::core::ics02_client::error::ClientError;
+use ibc::core::ics02_client::{ClientExecutionContext, ClientValidationContext};
+use ibc::core::ics23_commitment::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc::core::ics24_host::identifier::ClientId;
+use ibc::core::ics24_host::path::Path;
+use ibc::core::ContextError;
+use ibc::prelude::*;
+use ibc::proto::Any;
+use ibc::Height;
+
+/// A light client implementation under test, along with the fixtures the
+/// conformance checks run on.
+pub trait ClientConformance {
+    /// The host context the client runs on.
+    type Context: ClientValidationContext + ClientExecutionContext;
+    /// The client state of the client under test.
+    type ClientState: ClientState<Self::Context, Self::Context>;
+
+    /// The identifier of the client under test.
+    fn client_id(&self) -> ClientId;
+
+    /// A fresh host context holding the client under test, active, along with
+    /// its consensus state at its latest height.
+    fn context(&self) -> Self::Context;
+
+    /// Reads the client state the client stored in `ctx`.
+    fn client_state(&self, ctx: &Self::Context) -> Result<Self::ClientState, ContextError>;
+
+    /// A header the client must accept, updating it above its latest height.
+    fn header(&self) -> Any;
+
+    /// A header the client must reject, e.g. one with a forged commit.
+    fn invalid_header(&self) -> Any;
+
+    /// Evidence of misbehaviour the client must freeze upon, if the client
+    /// can be frozen.
+    fn misbehaviour(&self) -> Option<Any> {
+        None
+    }
+
+    /// A membership proof the client must verify, if it verifies proofs.
+    fn membership(&self) -> Option<MembershipFixture> {
+        None
+    }
+
+    /// A non-membership proof the client must verify, if it verifies proofs.
+    fn non_membership(&self) -> Option<NonMembershipFixture> {
+        None
+    }
+
+    /// An upgrade the client must accept, if it can be upgraded.
+    fn upgrade(&self) -> Option<UpgradeFixture> {
+        None
+    }
+
+    /// A fresh host context holding the client under test, expired, if the
+    /// client can expire.
+    fn expired_context(&self) -> Option<Self::Context> {
+        None
+    }
+}
+
+/// A proof of `value` under `path` in the state committed to by `root`.
+#[derive(Clone, Debug)]
+pub struct MembershipFixture {
+    pub prefix: CommitmentPrefix,
+    pub proof: CommitmentProofBytes,
+    pub root: CommitmentRoot,
+    pub path: Path,
+    pub value: Vec<u8>,
+}
+
+/// A proof that nothing is stored under `path` in the state committed to by
+/// `root`.
+#[derive(Clone, Debug)]
+pub struct NonMembershipFixture {
+    pub prefix: CommitmentPrefix,
+    pub proof: CommitmentProofBytes,
+    pub root: CommitmentRoot,
+    pub path: Path,
+}
+
+/// An upgraded client and consensus state, along with the proofs of their
+/// commitment by the upgrading chain in the state committed to by `root`.
+#[derive(Clone, Debug)]
+pub struct UpgradeFixture {
+    pub client_state: Any,
+    pub consensus_state: Any,
+    pub proof_client_state: CommitmentProofBytes,
+    pub proof_consensus_state: CommitmentProofBytes,
+    pub root: CommitmentRoot,
+}
+
+/// A conformance check.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum Check {
+    /// update
+    Update,
+    /// invalid header
+    InvalidHeader,
+    /// misbehaviour
+    Misbehaviour,
+    /// membership proof
+    Membership,
+    /// non-membership proof
+    NonMembership,
+    /// upgrade
+    Upgrade,
+    /// expiry
+    Expiry,
+}
+
+/// A behaviour of the client under test diverging from the one the handlers
+/// expect.
+#[derive(Debug, Display)]
+pub enum ConformanceFailure {
+    /// {check}: the client failed: `{error}`
+    Client { check: Check, error: ClientError },
+    /// {check}: the host context failed: `{error}`
+    Context { check: Check, error: ContextError },
+    /// {check}: the client is `{actual}` instead of `{expected}`
+    Status {
+        check: Check,
+        expected: Status,
+        actual: Status,
+    },
+    /// {check}: the client is at height `{actual}` instead of `{expected}`
+    LatestHeight {
+        check: Check,
+        expected: Height,
+        actual: Height,
+    },
+    /// update: a valid header was reported as misbehaviour
+    HeaderReportedAsMisbehaviour,
+    /// update: the client reported no consensus height
+    NoConsensusHeight,
+    /// update: the client did not record when its consensus state at `{height}` was processed
+    MissingProcessedHeight { height: Height },
+    /// invalid header: the client accepted the header
+    InvalidHeaderAccepted,
+    /// misbehaviour: the client did not detect the misbehaviour
+    MisbehaviourNotDetected,
+    /// membership proof: the client verified the proof against a forged value
+    ForgedValueAccepted,
+}
+
+/// Runs every conformance check the fixtures of `client` allow for, and
+/// returns the failures found, which is empty if the client conforms.
+pub fn check_conformance<C>(client: &C) -> Vec<ConformanceFailure>
+where
+    C: ClientConformance,
+{
+    let checks = [
+        check_update(client),
+        check_invalid_header(client),
+        check_misbehaviour(client),
+        check_membership(client),
+        check_non_membership(client),
+        check_upgrade(client),
+        check_expiry(client),
+    ];
+
+    checks.into_iter().filter_map(Result::err).collect()
+}
+
+/// Checks that the client accepts the header, stores the consensus states it
+/// reports, and remains active.
+pub fn check_update<C>(client: &C) -> Result<(), ConformanceFailure>
+where
+    C: ClientConformance,
+{
+    let check = Check::Update;
+    let client_id = client.client_id();
+    let mut ctx = client.context();
+    let client_state = read_client_state(client, &ctx, check)?;
+    let header = client.header();
+
+    client_state
+        .verify_client_message(&ctx, &client_id, header.clone(), &UpdateKind::UpdateClient)
+        .map_err(|error| ConformanceFailure::Client { check, error })?;
+
+    let found_misbehaviour = client_state
+        .check_for_misbehaviour(&ctx, &client_id, header.clone(), &UpdateKind::UpdateClient)
+        .map_err(|error| ConformanceFailure::Client { check, error })?;
+    if found_misbehaviour {
+        return Err(ConformanceFailure::HeaderReportedAsMisbehaviour);
+    }
+
+    let consensus_heights = client_state
+        .update_state(&mut ctx, &client_id, header)
+        .map_err(|error| ConformanceFailure::Client { check, error })?;
+    let expected = *consensus_heights
+        .iter()
+        .max()
+        .ok_or(ConformanceFailure::NoConsensusHeight)?;
+
+    for height in &consensus_heights {
+        if ctx.client_update_height(&client_id, height).is_err() {
+            return Err(ConformanceFailure::MissingProcessedHeight { height: *height });
+        }
+    }
+
+    let updated_client_state = read_client_state(client, &ctx, check)?;
+    let actual = updated_client_state.latest_height();
+    if actual != expected {
+        return Err(ConformanceFailure::LatestHeight {
+            check,
+            expected,
+            actual,
+        });
+    }
+
+    expect_status(
+        &updated_client_state,
+        &ctx,
+        &client_id,
+        check,
+        Status::Active,
+    )
+}
+
+/// Checks that the client rejects the invalid header.
+pub fn check_invalid_header<C>(client: &C) -> Result<(), ConformanceFailure>
+where
+    C: ClientConformance,
+{
+    let check = Check::InvalidHeader;
+    let ctx = client.context();
+    let client_state = read_client_state(client, &ctx, check)?;
+
+    match client_state.verify_client_message(
+        &ctx,
+        &client.client_id(),
+        client.invalid_header(),
+        &UpdateKind::UpdateClient,
+    ) {
+        Ok(()) => Err(ConformanceFailure::InvalidHeaderAccepted),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Checks that the client detects the misbehaviour, and is frozen by it.
+pub fn check_misbehaviour<C>(client: &C) -> Result<(), ConformanceFailure>
+where
+    C: ClientConformance,
+{
+    let check = Check::Misbehaviour;
+    let misbehaviour = match client.misbehaviour() {
+        Some(misbehaviour) => misbehaviour,
+        None => return Ok(()),
+    };
+    let client_id = client.client_id();
+    let mut ctx = client.context();
+    let client_state = read_client_state(client, &ctx, check)?;
+    let update_kind = UpdateKind::SubmitMisbehaviour;
+
+    client_state
+        .verify_client_message(&ctx, &client_id, misbehaviour.clone(), &update_kind)
+        .map_err(|error| ConformanceFailure::Client { check, error })?;
+
+    let found_misbehaviour = client_state
+        .check_for_misbehaviour(&ctx, &client_id, misbehaviour.clone(), &update_kind)
+        .map_err(|error| ConformanceFailure::Client { check, error })?;
+    if !found_misbehaviour {
+        return Err(ConformanceFailure::MisbehaviourNotDetected);
+    }
+
+    client_state
+        .update_state_on_misbehaviour(&mut ctx, &client_id, misbehaviour, &update_kind)
+        .map_err(|error| ConformanceFailure::Client { check, error })?;
+
+    let frozen_client_state = read_client_state(client, &ctx, check)?;
+    expect_status(
+        &frozen_client_state,
+        &ctx,
+        &client_id,
+        check,
+        Status::Frozen,
+    )
+}
+
+/// Checks that the client verifies the membership proof, but not against
+/// another value.
+pub fn check_membership<C>(client: &C) -> Result<(), ConformanceFailure>
+where
+    C: ClientConformance,
+{
+    let check = Check::Membership;
+    let fixture = match client.membership() {
+        Some(fixture) => fixture,
+        None => return Ok(()),
+    };
+    let ctx = client.context();
+    let client_state = read_client_state(client, &ctx, check)?;
+
+    client_state
+        .verify_membership(
+            &fixture.prefix,
+            &fixture.proof,
+            &fixture.root,
+            fixture.path.clone(),
+            fixture.value.clone(),
+        )
+        .map_err(|error| ConformanceFailure::Client { check, error })?;
+
+    let mut forged_value = fixture.value;
+    forged_value.push(0);
+    match client_state.verify_membership(
+        &fixture.prefix,
+        &fixture.proof,
+        &fixture.root,
+        fixture.path,
+        forged_value,
+    ) {
+        Ok(()) => Err(ConformanceFailure::ForgedValueAccepted),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Checks that the client verifies the non-membership proof.
+pub fn check_non_membership<C>(client: &C) -> Result<(), ConformanceFailure>
+where
+    C: ClientConformance,
+{
+    let check = Check::NonMembership;
+    let fixture = match client.non_membership() {
+        Some(fixture) => fixture,
+        None => return Ok(()),
+    };
+    let ctx = client.context();
+    let client_state = read_client_state(client, &ctx, check)?;
+
+    client_state
+        .verify_non_membership(&fixture.prefix, &fixture.proof, &fixture.root, fixture.path)
+        .map_err(|error| ConformanceFailure::Client { check, error })
+}
+
+/// Checks that the client accepts the upgrade, and is active at the height
+/// it reports once upgraded.
+pub fn check_upgrade<C>(client: &C) -> Result<(), ConformanceFailure>
+where
+    C: ClientConformance,
+{
+    let check = Check::Upgrade;
+    let fixture = match client.upgrade() {
+        Some(fixture) => fixture,
+        None => return Ok(()),
+    };
+    let client_id = client.client_id();
+    let mut ctx = client.context();
+    let client_state = read_client_state(client, &ctx, check)?;
+
+    client_state
+        .verify_upgrade_client(
+            fixture.client_state.clone(),
+            fixture.consensus_state.clone(),
+            fixture.proof_client_state,
+            fixture.proof_consensus_state,
+            &fixture.root,
+        )
+        .map_err(|error| ConformanceFailure::Client { check, error })?;
+
+    let expected = client_state
+        .update_state_on_upgrade(
+            &mut ctx,
+            &client_id,
+            fixture.client_state,
+            fixture.consensus_state,
+        )
+        .map_err(|error| ConformanceFailure::Client { check, error })?;
+
+    let upgraded_client_state = read_client_state(client, &ctx, check)?;
+    let actual = upgraded_client_state.latest_height();
+    if actual != expected {
+        return Err(ConformanceFailure::LatestHeight {
+            check,
+            expected,
+            actual,
+        });
+    }
+
+    expect_status(
+        &upgraded_client_state,
+        &ctx,
+        &client_id,
+        check,
+        Status::Active,
+    )
+}
+
+/// Checks that the client reports itself as expired in the expired context.
+pub fn check_expiry<C>(client: &C) -> Result<(), ConformanceFailure>
+where
+    C: ClientConformance,
+{
+    let check = Check::Expiry;
+    let ctx = match client.expired_context() {
+        Some(ctx) => ctx,
+        None => return Ok(()),
+    };
+    let client_state = read_client_state(client, &ctx, check)?;
+
+    expect_status(
+        &client_state,
+        &ctx,
+        &client.client_id(),
+        check,
+        Status::Expired,
+    )
+}
+
+fn read_client_state<C>(
+    client: &C,
+    ctx: &C::Context,
+    check: Check,
+) -> Result<C::ClientState, ConformanceFailure>
+where
+    C: ClientConformance,
+{
+    client
+        .client_state(ctx)
+        .map_err(|error| ConformanceFailure::Context { check, error })
+}
+
+fn expect_status<Ctx, CS>(
+    client_state: &CS,
+    ctx: &Ctx,
+    client_id: &ClientId,
+    check: Check,
+    expected: Status,
+) -> Result<(), ConformanceFailure>
+where
+    Ctx: ClientValidationContext + ClientExecutionContext,
+    CS: ClientState<Ctx, Ctx>,
+{
+    let actual = client_state
+        .status(ctx, client_id)
+        .map_err(|error| ConformanceFailure::Client { check, error })?;
+    if actual != expected {
+        return Err(ConformanceFailure::Status {
+            check,
+            expected,
+            actual,
+        });
+    }
+    Ok(())
+}
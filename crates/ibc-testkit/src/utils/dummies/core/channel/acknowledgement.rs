@@ -1,26 +1,53 @@
+use ibc::prelude::*;
 use ibc::proto::core::channel::v1::{
     MsgAcknowledgement as RawMsgAcknowledgement, Packet as RawPacket,
 };
-use ibc::proto::core::client::v1::Height as RawHeight;
+use ibc::{Height, Signer};
+use typed_builder::TypedBuilder;
 
 use super::{dummy_proof, dummy_raw_packet};
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgAcknowledgement` message for building dummy
+/// messages.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgAcknowledgement))]
+pub struct MsgAcknowledgementConfig {
+    pub packet: RawPacket,
+    #[builder(default = dummy_proof())]
+    pub acknowledgement: Vec<u8>,
+    #[builder(default = dummy_proof())]
+    pub proof_acked_on_b: Vec<u8>,
+    pub proof_height_on_b: Height,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgAcknowledgementConfig> for RawMsgAcknowledgement {
+    fn from(config: MsgAcknowledgementConfig) -> Self {
+        RawMsgAcknowledgement {
+            packet: Some(config.packet),
+            acknowledgement: config.acknowledgement,
+            proof_acked: config.proof_acked_on_b,
+            proof_height: Some(config.proof_height_on_b.into()),
+            signer: config.signer.to_string(),
+        }
+    }
+}
+
 /// Returns a dummy `RawMsgAcknowledgement`, for testing purposes only!
 /// The `height` parametrizes both the proof height as well as the timeout height.
 pub fn dummy_raw_msg_acknowledgement(height: u64) -> RawMsgAcknowledgement {
     dummy_raw_msg_ack_with_packet(dummy_raw_packet(height, 1), height)
 }
 
+/// Returns a dummy `RawMsgAcknowledgement` of the given packet, proven at
+/// `height`.
+///
+/// See [`MsgAcknowledgementConfig`] to configure the other fields.
 pub fn dummy_raw_msg_ack_with_packet(packet: RawPacket, height: u64) -> RawMsgAcknowledgement {
-    RawMsgAcknowledgement {
-        packet: Some(packet),
-        acknowledgement: dummy_proof(),
-        proof_acked: dummy_proof(),
-        proof_height: Some(RawHeight {
-            revision_number: 0,
-            revision_height: height,
-        }),
-        signer: dummy_bech32_account(),
-    }
+    MsgAcknowledgementConfig::builder()
+        .packet(packet)
+        .proof_height_on_b(Height::new(0, height).expect("Never fails"))
+        .build()
 }
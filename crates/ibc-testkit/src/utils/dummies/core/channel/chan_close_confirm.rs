@@ -1,21 +1,45 @@
 use ibc::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc::prelude::*;
 use ibc::proto::core::channel::v1::MsgChannelCloseConfirm as RawMsgChannelCloseConfirm;
-use ibc::proto::core::client::v1::Height;
+use ibc::{Height, Signer};
+use typed_builder::TypedBuilder;
 
 use super::dummy_proof;
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgChannelCloseConfirm` message for building dummy
+/// messages.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgChannelCloseConfirm))]
+pub struct MsgChannelCloseConfirmConfig {
+    #[builder(default = PortId::transfer())]
+    pub port_id_on_b: PortId,
+    #[builder(default)]
+    pub chan_id_on_b: ChannelId,
+    #[builder(default = dummy_proof())]
+    pub proof_chan_end_on_a: Vec<u8>,
+    pub proof_height_on_a: Height,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgChannelCloseConfirmConfig> for RawMsgChannelCloseConfirm {
+    fn from(config: MsgChannelCloseConfirmConfig) -> Self {
+        RawMsgChannelCloseConfirm {
+            port_id: config.port_id_on_b.to_string(),
+            channel_id: config.chan_id_on_b.to_string(),
+            proof_init: config.proof_chan_end_on_a,
+            proof_height: Some(config.proof_height_on_a.into()),
+            signer: config.signer.to_string(),
+        }
+    }
+}
+
 /// Returns a dummy `RawMsgChannelCloseConfirm`, for testing purposes only!
+///
+/// See [`MsgChannelCloseConfirmConfig`] to configure the other fields.
 pub fn dummy_raw_msg_chan_close_confirm(proof_height: u64) -> RawMsgChannelCloseConfirm {
-    RawMsgChannelCloseConfirm {
-        port_id: PortId::transfer().to_string(),
-        channel_id: ChannelId::default().to_string(),
-        proof_init: dummy_proof(),
-        proof_height: Some(Height {
-            revision_number: 0,
-            revision_height: proof_height,
-        }),
-        signer: dummy_bech32_account(),
-    }
+    MsgChannelCloseConfirmConfig::builder()
+        .proof_height_on_a(Height::new(0, proof_height).expect("Never fails"))
+        .build()
 }
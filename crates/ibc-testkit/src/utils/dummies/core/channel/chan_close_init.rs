@@ -1,14 +1,37 @@
 use ibc::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc::prelude::*;
 use ibc::proto::core::channel::v1::MsgChannelCloseInit as RawMsgChannelCloseInit;
+use ibc::Signer;
+use typed_builder::TypedBuilder;
 
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgChannelCloseInit` message for building dummy
+/// messages.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgChannelCloseInit))]
+pub struct MsgChannelCloseInitConfig {
+    #[builder(default = PortId::transfer())]
+    pub port_id_on_a: PortId,
+    #[builder(default)]
+    pub chan_id_on_a: ChannelId,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgChannelCloseInitConfig> for RawMsgChannelCloseInit {
+    fn from(config: MsgChannelCloseInitConfig) -> Self {
+        RawMsgChannelCloseInit {
+            port_id: config.port_id_on_a.to_string(),
+            channel_id: config.chan_id_on_a.to_string(),
+            signer: config.signer.to_string(),
+        }
+    }
+}
+
 /// Returns a dummy `RawMsgChannelCloseInit`, for testing purposes only!
+///
+/// See [`MsgChannelCloseInitConfig`] to configure its fields.
 pub fn dummy_raw_msg_chan_close_init() -> RawMsgChannelCloseInit {
-    RawMsgChannelCloseInit {
-        port_id: PortId::transfer().to_string(),
-        channel_id: ChannelId::default().to_string(),
-        signer: dummy_bech32_account(),
-    }
+    MsgChannelCloseInitConfig::builder().build()
 }
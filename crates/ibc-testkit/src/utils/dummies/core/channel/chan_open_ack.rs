@@ -1,23 +1,52 @@
+use ibc::core::ics04_channel::Version;
 use ibc::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc::prelude::*;
 use ibc::proto::core::channel::v1::MsgChannelOpenAck as RawMsgChannelOpenAck;
-use ibc::proto::core::client::v1::Height;
+use ibc::{Height, Signer};
+use typed_builder::TypedBuilder;
 
 use super::dummy_proof;
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgChannelOpenAck` message for building dummy
+/// messages.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgChannelOpenAck))]
+pub struct MsgChannelOpenAckConfig {
+    #[builder(default = PortId::transfer())]
+    pub port_id_on_a: PortId,
+    #[builder(default)]
+    pub chan_id_on_a: ChannelId,
+    #[builder(default)]
+    pub chan_id_on_b: ChannelId,
+    #[builder(default = Version::empty())]
+    pub version_on_b: Version,
+    #[builder(default = dummy_proof())]
+    pub proof_chan_end_on_b: Vec<u8>,
+    pub proof_height_on_b: Height,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgChannelOpenAckConfig> for RawMsgChannelOpenAck {
+    fn from(config: MsgChannelOpenAckConfig) -> Self {
+        RawMsgChannelOpenAck {
+            port_id: config.port_id_on_a.to_string(),
+            channel_id: config.chan_id_on_a.to_string(),
+            counterparty_channel_id: config.chan_id_on_b.to_string(),
+            counterparty_version: config.version_on_b.to_string(),
+            proof_try: config.proof_chan_end_on_b,
+            proof_height: Some(config.proof_height_on_b.into()),
+            signer: config.signer.to_string(),
+        }
+    }
+}
+
 /// Returns a dummy `RawMsgChannelOpenAck`, for testing purposes only!
+///
+/// See [`MsgChannelOpenAckConfig`] to configure the other fields.
 pub fn dummy_raw_msg_chan_open_ack(proof_height: u64) -> RawMsgChannelOpenAck {
-    RawMsgChannelOpenAck {
-        port_id: PortId::transfer().to_string(),
-        channel_id: ChannelId::default().to_string(),
-        counterparty_channel_id: ChannelId::default().to_string(),
-        counterparty_version: "".to_string(),
-        proof_try: dummy_proof(),
-        proof_height: Some(Height {
-            revision_number: 0,
-            revision_height: proof_height,
-        }),
-        signer: dummy_bech32_account(),
-    }
+    MsgChannelOpenAckConfig::builder()
+        .proof_height_on_b(Height::new(0, proof_height).expect("Never fails"))
+        .build()
 }
@@ -1,15 +1,51 @@
-use ibc::core::ics24_host::identifier::PortId;
+use ibc::core::ics04_channel::channel::State;
+use ibc::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc::prelude::*;
-use ibc::proto::core::channel::v1::MsgChannelOpenInit as RawMsgChannelOpenInit;
+use ibc::proto::core::channel::v1::{
+    Channel as RawChannel, MsgChannelOpenInit as RawMsgChannelOpenInit,
+};
+use ibc::Signer;
+use typed_builder::TypedBuilder;
 
-use super::dummy_raw_channel_end;
+use super::ChannelEndConfig;
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgChannelOpenInit` message for building dummy
+/// messages.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgChannelOpenInit))]
+pub struct MsgChannelOpenInitConfig {
+    #[builder(default = PortId::transfer())]
+    pub port_id_on_a: PortId,
+    #[builder(default = ChannelEndConfig::builder().state(State::Init).build())]
+    pub chan_end_on_a: RawChannel,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgChannelOpenInitConfig> for RawMsgChannelOpenInit {
+    fn from(config: MsgChannelOpenInitConfig) -> Self {
+        RawMsgChannelOpenInit {
+            port_id: config.port_id_on_a.to_string(),
+            channel: Some(config.chan_end_on_a),
+            signer: config.signer.to_string(),
+        }
+    }
+}
+
 /// Returns a dummy `RawMsgChannelOpenInit`, for testing purposes only!
+///
+/// See [`MsgChannelOpenInitConfig`] to configure the other fields.
 pub fn dummy_raw_msg_chan_open_init(counterparty_channel_id: Option<u64>) -> RawMsgChannelOpenInit {
-    RawMsgChannelOpenInit {
-        port_id: PortId::transfer().to_string(),
-        channel: Some(dummy_raw_channel_end(1, counterparty_channel_id)),
-        signer: dummy_bech32_account(),
-    }
+    let chan_end_on_a = match counterparty_channel_id {
+        Some(id) => ChannelEndConfig::builder()
+            .state(State::Init)
+            .counterparty_channel_id(ChannelId::new(id))
+            .build(),
+        None => ChannelEndConfig::builder().state(State::Init).build(),
+    };
+
+    MsgChannelOpenInitConfig::builder()
+        .chan_end_on_a(chan_end_on_a)
+        .build()
 }
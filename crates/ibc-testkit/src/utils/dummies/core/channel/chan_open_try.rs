@@ -1,24 +1,57 @@
-use ibc::core::ics24_host::identifier::PortId;
+use ibc::core::ics04_channel::channel::State;
+use ibc::core::ics04_channel::Version;
+use ibc::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc::prelude::*;
-use ibc::proto::core::channel::v1::MsgChannelOpenTry as RawMsgChannelOpenTry;
-use ibc::proto::core::client::v1::Height;
+use ibc::proto::core::channel::v1::{
+    Channel as RawChannel, MsgChannelOpenTry as RawMsgChannelOpenTry,
+};
+use ibc::{Height, Signer};
+use typed_builder::TypedBuilder;
 
-use super::{dummy_proof, dummy_raw_channel_end};
+use super::{dummy_proof, ChannelEndConfig};
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgChannelOpenTry` message for building dummy
+/// messages.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgChannelOpenTry))]
+pub struct MsgChannelOpenTryConfig {
+    #[builder(default = PortId::transfer())]
+    pub port_id_on_b: PortId,
+    #[builder(default = ChannelEndConfig::builder()
+        .state(State::TryOpen)
+        .counterparty_channel_id(ChannelId::default())
+        .build())]
+    pub chan_end_on_b: RawChannel,
+    #[builder(default = Version::empty())]
+    pub version_supported_on_a: Version,
+    #[builder(default = dummy_proof())]
+    pub proof_chan_end_on_a: Vec<u8>,
+    pub proof_height_on_a: Height,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgChannelOpenTryConfig> for RawMsgChannelOpenTry {
+    fn from(config: MsgChannelOpenTryConfig) -> Self {
+        #[allow(deprecated)]
+        RawMsgChannelOpenTry {
+            port_id: config.port_id_on_b.to_string(),
+            previous_channel_id: "".to_string(),
+            channel: Some(config.chan_end_on_b),
+            counterparty_version: config.version_supported_on_a.to_string(),
+            proof_init: config.proof_chan_end_on_a,
+            proof_height: Some(config.proof_height_on_a.into()),
+            signer: config.signer.to_string(),
+        }
+    }
+}
+
 /// Returns a dummy `RawMsgChannelOpenTry`, for testing purposes only!
+///
+/// See [`MsgChannelOpenTryConfig`] to configure the other fields.
 pub fn dummy_raw_msg_chan_open_try(proof_height: u64) -> RawMsgChannelOpenTry {
-    #[allow(deprecated)]
-    RawMsgChannelOpenTry {
-        port_id: PortId::transfer().to_string(),
-        previous_channel_id: "".to_string(),
-        channel: Some(dummy_raw_channel_end(2, Some(0))),
-        counterparty_version: "".to_string(),
-        proof_init: dummy_proof(),
-        proof_height: Some(Height {
-            revision_number: 0,
-            revision_height: proof_height,
-        }),
-        signer: dummy_bech32_account(),
-    }
+    MsgChannelOpenTryConfig::builder()
+        .proof_height_on_a(Height::new(0, proof_height).expect("Never fails"))
+        .build()
 }
@@ -17,6 +17,8 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+use ibc::core::ics04_channel::channel::{Order, State};
+use ibc::core::ics04_channel::Version;
 use ibc::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
 use ibc::prelude::*;
 use ibc::proto::core::channel::v1::{Channel as RawChannel, Counterparty as RawCounterparty};
@@ -24,6 +26,52 @@ pub use packet::*;
 pub use recv_packet::*;
 pub use timeout::*;
 pub use timeout_on_close::*;
+use typed_builder::TypedBuilder;
+
+/// Configuration of the `RawChannel` type for building dummy channel ends, as
+/// carried by the `ChanOpenInit` and `ChanOpenTry` messages.
+///
+/// The channel end is ordered, over the default connection, with a
+/// counterparty on the transfer port.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawChannel))]
+pub struct ChannelEndConfig {
+    pub state: State,
+    #[builder(default = Order::Ordered)]
+    pub ordering: Order,
+    #[builder(default = PortId::transfer())]
+    pub counterparty_port_id: PortId,
+    #[builder(default, setter(strip_option))]
+    pub counterparty_channel_id: Option<ChannelId>,
+    #[builder(default = vec![ConnectionId::default()])]
+    pub connection_hops: Vec<ConnectionId>,
+    #[builder(default = Version::empty())]
+    pub version: Version,
+}
+
+impl From<ChannelEndConfig> for RawChannel {
+    fn from(config: ChannelEndConfig) -> Self {
+        let channel_id = config
+            .counterparty_channel_id
+            .map(|chan_id| chan_id.to_string())
+            .unwrap_or_default();
+
+        RawChannel {
+            state: config.state as i32,
+            ordering: config.ordering as i32,
+            counterparty: Some(RawCounterparty {
+                port_id: config.counterparty_port_id.to_string(),
+                channel_id,
+            }),
+            connection_hops: config
+                .connection_hops
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            version: config.version.to_string(),
+        }
+    }
+}
 
 /// Returns a dummy `RawCounterparty`, for testing purposes only!
 /// Can be optionally parametrized with a specific channel identifier.
@@ -35,6 +83,8 @@ pub fn dummy_raw_counterparty_chan(channel_id: String) -> RawCounterparty {
 }
 
 /// Returns a dummy `RawChannel`, for testing purposes only!
+///
+/// See [`ChannelEndConfig`] to configure the other fields.
 pub fn dummy_raw_channel_end(state: i32, channel_id: Option<u64>) -> RawChannel {
     let channel_id = match channel_id {
         Some(id) => ChannelId::new(id).to_string(),
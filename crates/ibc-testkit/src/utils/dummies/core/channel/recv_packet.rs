@@ -5,13 +5,38 @@ use ibc::core::ics04_channel::msgs::MsgRecvPacket;
 use ibc::core::ics04_channel::packet::Packet;
 use ibc::core::ics23_commitment::commitment::CommitmentProofBytes;
 use ibc::core::timestamp::Timestamp;
-use ibc::proto::core::channel::v1::MsgRecvPacket as RawMsgRecvPacket;
-use ibc::proto::core::client::v1::Height as RawHeight;
+use ibc::prelude::*;
+use ibc::proto::core::channel::v1::{MsgRecvPacket as RawMsgRecvPacket, Packet as RawPacket};
 use ibc::{Height, Signer};
+use typed_builder::TypedBuilder;
 
 use super::{dummy_proof, dummy_raw_packet};
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgRecvPacket` message for building dummy
+/// messages.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgRecvPacket))]
+pub struct MsgRecvPacketConfig {
+    pub packet: RawPacket,
+    #[builder(default = dummy_proof())]
+    pub proof_commitment_on_a: Vec<u8>,
+    pub proof_height_on_a: Height,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgRecvPacketConfig> for RawMsgRecvPacket {
+    fn from(config: MsgRecvPacketConfig) -> Self {
+        RawMsgRecvPacket {
+            packet: Some(config.packet),
+            proof_commitment: config.proof_commitment_on_a,
+            proof_height: Some(config.proof_height_on_a.into()),
+            signer: config.signer.to_string(),
+        }
+    }
+}
+
 pub fn dummy_msg_recv_packet(
     packet: Packet,
     proof_commitment_on_a: CommitmentProofBytes,
@@ -28,18 +53,16 @@ pub fn dummy_msg_recv_packet(
 
 /// Returns a dummy `RawMsgRecvPacket`, for testing purposes only! The `height`
 /// parametrizes both the proof height as well as the timeout height.
+///
+/// See [`MsgRecvPacketConfig`] to configure the other fields.
 pub fn dummy_raw_msg_recv_packet(height: u64) -> RawMsgRecvPacket {
     let timestamp = Timestamp::now().add(Duration::from_secs(9));
-    RawMsgRecvPacket {
-        packet: Some(dummy_raw_packet(
+
+    MsgRecvPacketConfig::builder()
+        .packet(dummy_raw_packet(
             height,
             timestamp.expect("timestamp").nanoseconds(),
-        )),
-        proof_commitment: dummy_proof(),
-        proof_height: Some(RawHeight {
-            revision_number: 0,
-            revision_height: height,
-        }),
-        signer: dummy_bech32_account(),
-    }
+        ))
+        .proof_height_on_a(Height::new(0, height).expect("Never fails"))
+        .build()
 }
@@ -1,24 +1,49 @@
-use ibc::proto::core::channel::v1::MsgTimeout as RawMsgTimeout;
-use ibc::proto::core::client::v1::Height as RawHeight;
+use ibc::core::ics04_channel::packet::Sequence;
+use ibc::prelude::*;
+use ibc::proto::core::channel::v1::{MsgTimeout as RawMsgTimeout, Packet as RawPacket};
+use ibc::{Height, Signer};
+use typed_builder::TypedBuilder;
 
 use super::{dummy_proof, dummy_raw_packet};
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgTimeout` message for building dummy messages.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgTimeout))]
+pub struct MsgTimeoutConfig {
+    pub packet: RawPacket,
+    #[builder(default = Sequence::from(1))]
+    pub next_seq_recv_on_b: Sequence,
+    #[builder(default = dummy_proof())]
+    pub proof_unreceived_on_b: Vec<u8>,
+    pub proof_height_on_b: Height,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgTimeoutConfig> for RawMsgTimeout {
+    fn from(config: MsgTimeoutConfig) -> Self {
+        RawMsgTimeout {
+            packet: Some(config.packet),
+            proof_unreceived: config.proof_unreceived_on_b,
+            proof_height: Some(config.proof_height_on_b.into()),
+            next_sequence_recv: config.next_seq_recv_on_b.into(),
+            signer: config.signer.to_string(),
+        }
+    }
+}
+
 /// Returns a dummy `RawMsgTimeout`, for testing purposes only!
 /// The `height` parametrizes both the proof height as well as the timeout height.
+///
+/// See [`MsgTimeoutConfig`] to configure the other fields.
 pub fn dummy_raw_msg_timeout(
     proof_height: u64,
     timeout_height: u64,
     timeout_timestamp: u64,
 ) -> RawMsgTimeout {
-    RawMsgTimeout {
-        packet: Some(dummy_raw_packet(timeout_height, timeout_timestamp)),
-        proof_unreceived: dummy_proof(),
-        proof_height: Some(RawHeight {
-            revision_number: 0,
-            revision_height: proof_height,
-        }),
-        next_sequence_recv: 1,
-        signer: dummy_bech32_account(),
-    }
+    MsgTimeoutConfig::builder()
+        .packet(dummy_raw_packet(timeout_height, timeout_timestamp))
+        .proof_height_on_b(Height::new(0, proof_height).expect("Never fails"))
+        .build()
 }
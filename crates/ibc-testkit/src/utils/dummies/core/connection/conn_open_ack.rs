@@ -3,14 +3,62 @@ use ibc::core::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
 use ibc::core::ics03_connection::version::Version;
 use ibc::core::ics24_host::identifier::ConnectionId;
 use ibc::prelude::*;
-use ibc::proto::core::client::v1::Height as RawHeight;
 use ibc::proto::core::connection::v1::MsgConnectionOpenAck as RawMsgConnectionOpenAck;
+use ibc::proto::Any;
+use ibc::Signer;
+use typed_builder::TypedBuilder;
 
 use crate::testapp::ibc::clients::mock::client_state::MockClientState;
 use crate::testapp::ibc::clients::mock::header::MockHeader;
 use crate::utils::dummies::core::channel::dummy_proof;
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgConnectionOpenAck` message for building dummy
+/// messages.
+///
+/// Unless set, the client state of the host on the counterparty is a mock
+/// client state at `consensus_height_of_a_on_b`.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgConnectionOpenAck))]
+pub struct MsgConnectionOpenAckConfig {
+    #[builder(default = ConnectionId::new(0))]
+    pub conn_id_on_a: ConnectionId,
+    #[builder(default = ConnectionId::new(1))]
+    pub conn_id_on_b: ConnectionId,
+    pub proofs_height_on_b: Height,
+    pub consensus_height_of_a_on_b: Height,
+    #[builder(default = MockClientState::new(MockHeader::new(consensus_height_of_a_on_b)).into())]
+    pub client_state_of_a_on_b: Any,
+    #[builder(default)]
+    pub version: Version,
+    #[builder(default = dummy_proof())]
+    pub proof_conn_end_on_b: Vec<u8>,
+    #[builder(default = dummy_proof())]
+    pub proof_client_state_of_a_on_b: Vec<u8>,
+    #[builder(default = dummy_proof())]
+    pub proof_consensus_state_of_a_on_b: Vec<u8>,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgConnectionOpenAckConfig> for RawMsgConnectionOpenAck {
+    fn from(config: MsgConnectionOpenAckConfig) -> Self {
+        RawMsgConnectionOpenAck {
+            connection_id: config.conn_id_on_a.to_string(),
+            counterparty_connection_id: config.conn_id_on_b.to_string(),
+            proof_try: config.proof_conn_end_on_b,
+            proof_height: Some(config.proofs_height_on_b.into()),
+            proof_consensus: config.proof_consensus_state_of_a_on_b,
+            consensus_height: Some(config.consensus_height_of_a_on_b.into()),
+            client_state: Some(config.client_state_of_a_on_b),
+            proof_client: config.proof_client_state_of_a_on_b,
+            version: Some(config.version.into()),
+            signer: config.signer.to_string(),
+            host_consensus_state_proof: vec![],
+        }
+    }
+}
+
 /// Returns a dummy `MsgConnectionOpenAck` with dummy values.
 pub fn dummy_msg_conn_open_ack(proof_height: u64, consensus_height: u64) -> MsgConnectionOpenAck {
     MsgConnectionOpenAck::try_from(dummy_raw_msg_conn_open_ack(proof_height, consensus_height))
@@ -18,28 +66,14 @@ pub fn dummy_msg_conn_open_ack(proof_height: u64, consensus_height: u64) -> MsgC
 }
 
 /// Returns a dummy `RawMsgConnectionOpenAck`, for testing purposes only!
+///
+/// See [`MsgConnectionOpenAckConfig`] to configure the other fields.
 pub fn dummy_raw_msg_conn_open_ack(
     proof_height: u64,
     consensus_height: u64,
 ) -> RawMsgConnectionOpenAck {
-    let client_state_height = Height::new(0, consensus_height).expect("invalid height");
-    RawMsgConnectionOpenAck {
-        connection_id: ConnectionId::new(0).to_string(),
-        counterparty_connection_id: ConnectionId::new(1).to_string(),
-        proof_try: dummy_proof(),
-        proof_height: Some(RawHeight {
-            revision_number: 0,
-            revision_height: proof_height,
-        }),
-        proof_consensus: dummy_proof(),
-        consensus_height: Some(RawHeight {
-            revision_number: 0,
-            revision_height: consensus_height,
-        }),
-        client_state: Some(MockClientState::new(MockHeader::new(client_state_height)).into()),
-        proof_client: dummy_proof(),
-        version: Some(Version::default().into()),
-        signer: dummy_bech32_account(),
-        host_consensus_state_proof: vec![],
-    }
+    MsgConnectionOpenAckConfig::builder()
+        .proofs_height_on_b(Height::new(0, proof_height).expect("invalid height"))
+        .consensus_height_of_a_on_b(Height::new(0, consensus_height).expect("invalid height"))
+        .build()
 }
@@ -1,26 +1,49 @@
-use alloc::string::ToString;
-
 use ibc::core::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
-use ibc::proto::core::client::v1::Height;
+use ibc::core::ics24_host::identifier::ConnectionId;
+use ibc::prelude::*;
 use ibc::proto::core::connection::v1::MsgConnectionOpenConfirm as RawMsgConnectionOpenConfirm;
+use ibc::{Height, Signer};
+use typed_builder::TypedBuilder;
 
 use crate::utils::dummies::core::channel::dummy_proof;
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgConnectionOpenConfirm` message for building
+/// dummy messages.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgConnectionOpenConfirm))]
+pub struct MsgConnectionOpenConfirmConfig {
+    #[builder(default)]
+    pub conn_id_on_b: ConnectionId,
+    #[builder(default = dummy_proof())]
+    pub proof_conn_end_on_a: Vec<u8>,
+    #[builder(default = Height::new(0, 10).expect("Never fails"))]
+    pub proof_height_on_a: Height,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgConnectionOpenConfirmConfig> for RawMsgConnectionOpenConfirm {
+    fn from(config: MsgConnectionOpenConfirmConfig) -> Self {
+        RawMsgConnectionOpenConfirm {
+            connection_id: config.conn_id_on_b.to_string(),
+            proof_ack: config.proof_conn_end_on_a,
+            proof_height: Some(config.proof_height_on_a.into()),
+            signer: config.signer.to_string(),
+        }
+    }
+}
+
 /// Returns a dummy `MsgConnectionOpenConfirm` for testing purposes only!
 pub fn dummy_conn_open_confirm() -> MsgConnectionOpenConfirm {
     MsgConnectionOpenConfirm::try_from(dummy_raw_msg_conn_open_confirm()).expect("Never fails")
 }
 
 /// Returns a dummy `RawMsgConnectionOpenConfirm` for testing purposes only!
+///
+/// See [`MsgConnectionOpenConfirmConfig`] to configure its fields.
 pub fn dummy_raw_msg_conn_open_confirm() -> RawMsgConnectionOpenConfirm {
-    RawMsgConnectionOpenConfirm {
-        connection_id: "srcconnection".to_string(),
-        proof_ack: dummy_proof(),
-        proof_height: Some(Height {
-            revision_number: 0,
-            revision_height: 10,
-        }),
-        signer: dummy_bech32_account(),
-    }
+    MsgConnectionOpenConfirmConfig::builder()
+        .conn_id_on_b("srcconnection".parse().expect("Never fails"))
+        .build()
 }
@@ -1,15 +1,50 @@
+use core::time::Duration;
+
 use ibc::core::ics03_connection::connection::Counterparty;
 use ibc::core::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
 use ibc::core::ics03_connection::version::Version;
 use ibc::core::ics24_host::identifier::ClientId;
+use ibc::core::timestamp::ZERO_DURATION;
 use ibc::prelude::*;
 use ibc::proto::core::connection::v1::{
-    MsgConnectionOpenInit as RawMsgConnectionOpenInit, Version as RawVersion,
+    Counterparty as RawCounterparty, MsgConnectionOpenInit as RawMsgConnectionOpenInit,
+    Version as RawVersion,
 };
+use ibc::Signer;
+use typed_builder::TypedBuilder;
 
 use super::dummy_raw_counterparty_conn;
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgConnectionOpenInit` message for building dummy
+/// messages.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgConnectionOpenInit))]
+pub struct MsgConnectionOpenInitConfig {
+    #[builder(default)]
+    pub client_id_on_a: ClientId,
+    #[builder(default = dummy_raw_counterparty_conn(None))]
+    pub counterparty: RawCounterparty,
+    #[builder(default = Some(Version::default()))]
+    pub version: Option<Version>,
+    #[builder(default = ZERO_DURATION)]
+    pub delay_period: Duration,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgConnectionOpenInitConfig> for RawMsgConnectionOpenInit {
+    fn from(config: MsgConnectionOpenInitConfig) -> Self {
+        RawMsgConnectionOpenInit {
+            client_id: config.client_id_on_a.to_string(),
+            counterparty: Some(config.counterparty),
+            version: config.version.map(Into::into),
+            delay_period: config.delay_period.as_nanos() as u64,
+            signer: config.signer.to_string(),
+        }
+    }
+}
+
 pub fn raw_version_from_identifier(identifier: &str) -> Option<RawVersion> {
     if identifier.is_empty() {
         return None;
@@ -69,12 +104,8 @@ pub fn msg_conn_open_with_version(
 }
 
 /// Returns a dummy `RawMsgConnectionOpenInit`, for testing purposes only!
+///
+/// See [`MsgConnectionOpenInitConfig`] to configure its fields.
 pub fn dummy_raw_msg_conn_open_init() -> RawMsgConnectionOpenInit {
-    RawMsgConnectionOpenInit {
-        client_id: ClientId::default().to_string(),
-        counterparty: Some(dummy_raw_counterparty_conn(None)),
-        version: Some(Version::default().into()),
-        delay_period: 0,
-        signer: dummy_bech32_account(),
-    }
+    MsgConnectionOpenInitConfig::builder().build()
 }
@@ -1,10 +1,17 @@
+use core::time::Duration;
+
 use ibc::core::ics02_client::height::Height;
 use ibc::core::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
-use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics03_connection::version::{get_compatible_versions, Version};
 use ibc::core::ics24_host::identifier::{ClientId, ConnectionId};
+use ibc::core::timestamp::ZERO_DURATION;
 use ibc::prelude::*;
-use ibc::proto::core::client::v1::Height as RawHeight;
-use ibc::proto::core::connection::v1::MsgConnectionOpenTry as RawMsgConnectionOpenTry;
+use ibc::proto::core::connection::v1::{
+    Counterparty as RawCounterparty, MsgConnectionOpenTry as RawMsgConnectionOpenTry,
+};
+use ibc::proto::Any;
+use ibc::Signer;
+use typed_builder::TypedBuilder;
 
 use super::dummy_raw_counterparty_conn;
 use crate::testapp::ibc::clients::mock::client_state::MockClientState;
@@ -12,6 +19,57 @@ use crate::testapp::ibc::clients::mock::header::MockHeader;
 use crate::utils::dummies::core::channel::dummy_proof;
 use crate::utils::dummies::core::signer::dummy_bech32_account;
 
+/// Configuration of the `RawMsgConnectionOpenTry` message for building dummy
+/// messages.
+///
+/// Unless set, the client state of the host on the counterparty is a mock
+/// client state at `consensus_height_of_b_on_a`.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = RawMsgConnectionOpenTry))]
+pub struct MsgConnectionOpenTryConfig {
+    #[builder(default)]
+    pub client_id_on_b: ClientId,
+    pub proofs_height_on_a: Height,
+    pub consensus_height_of_b_on_a: Height,
+    #[builder(default = MockClientState::new(MockHeader::new(consensus_height_of_b_on_a)).into())]
+    pub client_state_of_b_on_a: Any,
+    #[builder(default = dummy_raw_counterparty_conn(Some(0)))]
+    pub counterparty: RawCounterparty,
+    #[builder(default = get_compatible_versions())]
+    pub versions_on_a: Vec<Version>,
+    #[builder(default = ZERO_DURATION)]
+    pub delay_period: Duration,
+    #[builder(default = dummy_proof())]
+    pub proof_conn_end_on_a: Vec<u8>,
+    #[builder(default = dummy_proof())]
+    pub proof_client_state_of_b_on_a: Vec<u8>,
+    #[builder(default = dummy_proof())]
+    pub proof_consensus_state_of_b_on_a: Vec<u8>,
+    #[builder(default = dummy_bech32_account().into())]
+    pub signer: Signer,
+}
+
+impl From<MsgConnectionOpenTryConfig> for RawMsgConnectionOpenTry {
+    fn from(config: MsgConnectionOpenTryConfig) -> Self {
+        #[allow(deprecated)]
+        RawMsgConnectionOpenTry {
+            client_id: config.client_id_on_b.to_string(),
+            previous_connection_id: ConnectionId::default().to_string(),
+            client_state: Some(config.client_state_of_b_on_a),
+            counterparty: Some(config.counterparty),
+            delay_period: config.delay_period.as_nanos() as u64,
+            counterparty_versions: config.versions_on_a.into_iter().map(Into::into).collect(),
+            proof_init: config.proof_conn_end_on_a,
+            proof_height: Some(config.proofs_height_on_a.into()),
+            proof_consensus: config.proof_consensus_state_of_b_on_a,
+            consensus_height: Some(config.consensus_height_of_b_on_a.into()),
+            proof_client: config.proof_client_state_of_b_on_a,
+            signer: config.signer.to_string(),
+            host_consensus_state_proof: vec![],
+        }
+    }
+}
+
 /// Returns a dummy `MsgConnectionOpenTry` for testing purposes only!
 pub fn dummy_msg_conn_open_try(proof_height: u64, consensus_height: u64) -> MsgConnectionOpenTry {
     MsgConnectionOpenTry::try_from(dummy_raw_msg_conn_open_try(proof_height, consensus_height))
@@ -32,35 +90,16 @@ pub fn msg_conn_open_try_with_client_id(
 /// `proof_height` represents the height, on the source chain, at which this chain produced the
 /// proof. Parameter `consensus_height` represents the height of destination chain which a
 /// client on the source chain stores.
+///
+/// See [`MsgConnectionOpenTryConfig`] to configure the other fields.
 pub fn dummy_raw_msg_conn_open_try(
     proof_height: u64,
     consensus_height: u64,
 ) -> RawMsgConnectionOpenTry {
-    let client_state_height = Height::new(0, consensus_height).expect("could not create height");
-
-    #[allow(deprecated)]
-    RawMsgConnectionOpenTry {
-        client_id: ClientId::default().to_string(),
-        previous_connection_id: ConnectionId::default().to_string(),
-        client_state: Some(MockClientState::new(MockHeader::new(client_state_height)).into()),
-        counterparty: Some(dummy_raw_counterparty_conn(Some(0))),
-        delay_period: 0,
-        counterparty_versions: get_compatible_versions()
-            .iter()
-            .map(|v| v.clone().into())
-            .collect(),
-        proof_init: dummy_proof(),
-        proof_height: Some(RawHeight {
-            revision_number: 0,
-            revision_height: proof_height,
-        }),
-        proof_consensus: dummy_proof(),
-        consensus_height: Some(RawHeight {
-            revision_number: 0,
-            revision_height: consensus_height,
-        }),
-        proof_client: dummy_proof(),
-        signer: dummy_bech32_account(),
-        host_consensus_state_proof: vec![],
-    }
+    MsgConnectionOpenTryConfig::builder()
+        .proofs_height_on_a(Height::new(0, proof_height).expect("could not create height"))
+        .consensus_height_of_b_on_a(
+            Height::new(0, consensus_height).expect("could not create height"),
+        )
+        .build()
 }
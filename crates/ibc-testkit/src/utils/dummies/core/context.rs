@@ -4,7 +4,7 @@ use core::ops::{Add, Sub};
 use core::time::Duration;
 
 use ibc::core::ics24_host::identifier::ChainId;
-use ibc::core::timestamp::Timestamp;
+use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
 use ibc::prelude::*;
 use ibc::Height;
 use parking_lot::Mutex;
@@ -12,7 +12,12 @@ use tendermint_testgen::Validator as TestgenValidator;
 use typed_builder::TypedBuilder;
 
 use crate::hosts::block::{HostBlock, HostType};
-use crate::testapp::ibc::core::types::{MockContext, MockIbcStore, DEFAULT_BLOCK_TIME_SECS};
+use crate::testapp::ibc::clients::client_type_registry;
+use crate::testapp::ibc::core::types::{
+    MockClock, MockContext, MockIbcStore, SystemClock, DEFAULT_BLOCK_TIME_SECS,
+    MOCK_COMMITMENT_PREFIX,
+};
+use crate::utils::proofs::ProofGenerator;
 
 /// Configuration of the `MockContext` type for generating dummy contexts.
 #[derive(Debug, TypedBuilder)]
@@ -35,7 +40,13 @@ pub struct MockContextConfig {
 
     latest_height: Height,
 
-    #[builder(default = Timestamp::now())]
+    /// The clock of the host, which timestamps its clients.
+    #[builder(default = Arc::new(SystemClock))]
+    clock: Arc<dyn MockClock>,
+
+    /// The timestamp of the latest block, which defaults to the current time
+    /// of the clock.
+    #[builder(default = clock.now())]
     latest_timestamp: Timestamp,
 }
 
@@ -74,7 +85,7 @@ impl From<MockContextConfig> for MockContext {
                 .rev()
                 .map(|i| {
                     // generate blocks with timestamps -> N, N - BT, N - 2BT, ...
-                    // where N = now(), BT = block_time
+                    // where N = latest_timestamp, BT = block_time
                     HostBlock::generate_block_with_validators(
                         params.host_id.clone(),
                         params.host_type,
@@ -96,7 +107,7 @@ impl From<MockContextConfig> for MockContext {
                 .rev()
                 .map(|i| {
                     // generate blocks with timestamps -> N, N - BT, N - 2BT, ...
-                    // where N = now(), BT = block_time
+                    // where N = latest_timestamp, BT = block_time
                     HostBlock::generate_block(
                         params.host_id.clone(),
                         params.host_type,
@@ -119,7 +130,12 @@ impl From<MockContextConfig> for MockContext {
             max_history_size: params.max_history_size,
             history,
             block_time: params.block_time,
-            ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
+            block_delay: ZERO_DURATION,
+            clock: params.clock,
+            client_types: client_type_registry(),
+            ibc_store: Arc::new(Mutex::new(MockIbcStore::new(params.block_time))),
+            proofs: ProofGenerator::new(MOCK_COMMITMENT_PREFIX.to_vec()),
+            timeout_queue: None,
             events: Vec::new(),
             logs: Vec::new(),
         }
@@ -1,3 +1,12 @@
+//! Fixtures of the messages and states of IBC, re-exported under
+//! [`utils`](crate::utils) for host chains and applications to build their own
+//! tests upon.
+//!
+//! Each message comes with a `*Config` type, whose builder sets every field
+//! to a dummy value unless configured otherwise, and builds the raw message,
+//! e.g. [`MsgChannelOpenTryConfig`](crate::utils::core::channel::MsgChannelOpenTryConfig).
+//! The `dummy_*` functions return the messages built from these defaults.
+
 pub mod applications;
 pub mod clients;
 pub mod core;
@@ -17,11 +17,11 @@ pub struct Fixture<M: Debug> {
 }
 
 impl<M: Debug> Fixture<M> {
-    pub fn generate_error_msg(
+    pub fn generate_error_msg<T: Debug>(
         &self,
         expect: &Expect,
         process: &str,
-        res: &Result<(), RouterError>,
+        res: &Result<T, RouterError>,
     ) -> String {
         let base_error = match expect {
             Expect::Success => "step failed!",
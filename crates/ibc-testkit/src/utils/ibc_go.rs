@@ -0,0 +1,268 @@
+//! Loading of the regression cases recorded from ibc-go.
+//!
+//! `ci/ibc-go-vectors` dispatches the messages of each case on a test chain
+//! of ibc-go and writes what its handlers did with them to a JSON file per
+//! case, which [`load_corpus`] turns into [`RegressionCase`]s for
+//! [`run_corpus`](crate::utils::regression::run_corpus). A case reads:
+//!
+//! ```json
+//! {
+//!   "name": "create_client",
+//!   "host": { "chain_id": "testchain1-1", "revision_number": 1, "height": 2, "timestamp": 1577923355000000000 },
+//!   "msgs": ["<hex of the encoded Any>"],
+//!   "outcomes": [
+//!     { "accepted": { "events": [{ "type": "create_client", "attributes": [["client_id", "07-tendermint-0"]] }],
+//!                     "writes": { "clients/07-tendermint-0/clientState": "<hex>" } } },
+//!     { "rejected": { "reason": "light client not found" } }
+//!   ]
+//! }
+//! ```
+//!
+//! The cases start from a host without any IBC state. Only the writes under
+//! the paths whose values the handlers encode are compared: the sequences,
+//! which ibc-go writes in big endian and the mock context as protobuf
+//! varints, and the client metadata are left out.
+
+use alloc::collections::btree_map::BTreeMap;
+use alloc::sync::Arc;
+use core::str::FromStr;
+use std::fs;
+use std::path::Path as FsPath;
+
+use displaydoc::Display;
+use ibc::core::ics24_host::identifier::ChainId;
+use ibc::core::ics24_host::path::Path;
+use ibc::core::timestamp::Timestamp;
+use ibc::prelude::*;
+use ibc::proto::Any;
+use ibc::Height;
+use prost::Message;
+use serde::Deserialize;
+use subtle_encoding::hex;
+use tendermint::abci;
+
+use crate::hosts::block::HostType;
+use crate::testapp::ibc::core::types::{FixedClock, MockContext};
+use crate::utils::regression::{RecordedOutcome, RegressionCase};
+
+/// The number of blocks of history of the snapshots.
+const HISTORY_SIZE: u64 = 5;
+
+#[derive(Debug, Display)]
+pub enum CorpusError {
+    /// failed to read `{path}`: `{error}`
+    Io { path: String, error: std::io::Error },
+    /// malformed case: `{0}`
+    Json(String),
+    /// malformed host of case `{case}`: `{reason}`
+    Host { case: String, reason: String },
+    /// malformed hex in case `{case}`: `{value}`
+    Hex { case: String, value: String },
+    /// message `{msg}` of case `{case}` is not an encoded `Any`
+    Msg { case: String, msg: usize },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CorpusError {}
+
+#[derive(Deserialize)]
+struct RawCase {
+    name: String,
+    host: RawHost,
+    msgs: Vec<String>,
+    outcomes: Vec<RawOutcome>,
+}
+
+#[derive(Deserialize)]
+struct RawHost {
+    chain_id: String,
+    revision_number: u64,
+    height: u64,
+    timestamp: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RawOutcome {
+    Accepted {
+        events: Vec<RawEvent>,
+        writes: BTreeMap<String, Option<String>>,
+    },
+    Rejected {
+        reason: String,
+    },
+}
+
+#[derive(Deserialize)]
+struct RawEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    attributes: Vec<(String, String)>,
+}
+
+/// Loads every case of the `*.json` files of `dir`, in the order of their
+/// file names.
+pub fn load_corpus(dir: impl AsRef<FsPath>) -> Result<Vec<RegressionCase>, CorpusError> {
+    let io_error = |error| CorpusError::Io {
+        path: dir.as_ref().display().to_string(),
+        error,
+    };
+
+    let mut files = fs::read_dir(dir.as_ref())
+        .map_err(io_error)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(io_error)?;
+    files.retain(|file| file.extension() == Some("json".as_ref()));
+    files.sort();
+
+    files
+        .iter()
+        .map(|file| {
+            let bytes = fs::read(file).map_err(|error| CorpusError::Io {
+                path: file.display().to_string(),
+                error,
+            })?;
+            load_case(&bytes)
+        })
+        .collect()
+}
+
+/// Loads a case from its JSON encoding.
+pub fn load_case(json: &[u8]) -> Result<RegressionCase, CorpusError> {
+    let raw: RawCase =
+        serde_json::from_slice(json).map_err(|e| CorpusError::Json(e.to_string()))?;
+
+    let host_error = |reason: String| CorpusError::Host {
+        case: raw.name.clone(),
+        reason,
+    };
+    let chain_id = ChainId::new(&raw.host.chain_id).map_err(|e| host_error(e.to_string()))?;
+    let height = Height::new(raw.host.revision_number, raw.host.height)
+        .map_err(|e| host_error(e.to_string()))?;
+    let timestamp =
+        Timestamp::from_nanoseconds(raw.host.timestamp).map_err(|e| host_error(e.to_string()))?;
+    if chain_id.revision_number() != height.revision_number() {
+        return Err(host_error(format!(
+            "revision of `{chain_id}` differs from that of height `{height}`"
+        )));
+    }
+
+    let snapshot = MockContext::new_with_clock(
+        chain_id,
+        HostType::Mock,
+        HISTORY_SIZE,
+        height,
+        Arc::new(FixedClock(timestamp)),
+    );
+
+    let decode_hex = |value: &str| {
+        hex::decode(value).map_err(|_| CorpusError::Hex {
+            case: raw.name.clone(),
+            value: value.to_string(),
+        })
+    };
+
+    let msgs = raw
+        .msgs
+        .iter()
+        .enumerate()
+        .map(|(msg, value)| {
+            Any::decode(decode_hex(value)?.as_slice()).map_err(|_| CorpusError::Msg {
+                case: raw.name.clone(),
+                msg,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    let outcomes = raw
+        .outcomes
+        .iter()
+        .map(|outcome| match outcome {
+            RawOutcome::Accepted { events, writes } => Ok(RecordedOutcome::Accepted {
+                events: events
+                    .iter()
+                    .map(|event| {
+                        abci::Event::new(
+                            event.kind.clone(),
+                            event
+                                .attributes
+                                .iter()
+                                .map(|(key, value)| (key.clone(), value.clone())),
+                        )
+                    })
+                    .collect(),
+                writes: writes
+                    .iter()
+                    .map(|(path, value)| {
+                        Ok((path.clone(), value.as_deref().map(decode_hex).transpose()?))
+                    })
+                    .collect::<Result<_, _>>()?,
+            }),
+            RawOutcome::Rejected { reason } => Ok(RecordedOutcome::Rejected {
+                reason: reason.clone(),
+            }),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(RegressionCase {
+        name: raw.name.clone(),
+        snapshot,
+        msgs,
+        outcomes,
+        compares_path: is_compared,
+    })
+}
+
+/// Whether the writes under `path` are compared with those of ibc-go.
+fn is_compared(path: &str) -> bool {
+    matches!(
+        Path::from_str(path),
+        Ok(Path::ClientState(_)
+            | Path::ClientConsensusState(_)
+            | Path::Connection(_)
+            | Path::ChannelEnd(_)
+            | Path::Commitment(_)
+            | Path::Ack(_)
+            | Path::Receipt(_))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_case() {
+        let json = br#"{
+            "name": "sample",
+            "host": { "chain_id": "testchain1-1", "revision_number": 1, "height": 2, "timestamp": 1577923355000000000 },
+            "msgs": ["0a012f", "0a012f"],
+            "outcomes": [
+                { "accepted": {
+                    "events": [{ "type": "create_client", "attributes": [["client_id", "07-tendermint-0"]] }],
+                    "writes": { "clients/07-tendermint-0/clientState": "0102", "nextClientSequence": null }
+                } },
+                { "rejected": { "reason": "light client not found" } }
+            ]
+        }"#;
+
+        let case = load_case(json).unwrap();
+        assert_eq!(case.msgs[0].type_url, "/");
+        assert!(matches!(
+            &case.outcomes[0],
+            RecordedOutcome::Accepted { events, writes }
+                if events[0].kind == "create_client"
+                    && writes["clients/07-tendermint-0/clientState"] == Some(vec![1, 2])
+                    && writes["nextClientSequence"].is_none()
+        ));
+        assert!(matches!(case.outcomes[1], RecordedOutcome::Rejected { .. }));
+        assert!((case.compares_path)("clients/07-tendermint-0/clientState"));
+        assert!(!(case.compares_path)(
+            "nextSequenceSend/ports/transfer/channels/channel-0"
+        ));
+        assert!(!(case.compares_path)("nextClientSequence"));
+
+        assert!(load_case(br#"{ "name": "sample" }"#).is_err());
+    }
+}
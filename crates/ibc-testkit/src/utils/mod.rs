@@ -1,5 +1,14 @@
+pub mod adversarial;
+pub mod channel_model;
+pub mod conformance;
 mod dummies;
 mod fixture;
+#[cfg(feature = "ibc-go-integration")]
+pub mod ibc_go;
+pub mod proofs;
+pub mod regression;
+pub mod replay;
+pub mod strategies;
 
 pub use dummies::*;
 pub use fixture::*;
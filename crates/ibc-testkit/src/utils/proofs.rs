@@ -0,0 +1,228 @@
+//! Generation of genuine ICS-23 proofs of the IBC state of a
+//! [`MockContext`](crate::testapp::ibc::core::types::MockContext) at past
+//! heights, for handler tests that verify proofs rather than relying on the
+//! mock client skipping verification.
+
+use alloc::collections::btree_map::BTreeMap;
+
+use ibc::core::ics23_commitment::commitment::{CommitmentProofBytes, CommitmentRoot};
+use ibc::core::ics24_host::path::Path;
+use ibc::prelude::*;
+use ibc::Height;
+
+use crate::testapp::ibc::core::merkle::{
+    membership_proof, multistore_root, non_membership_proof, MerkleStore,
+};
+
+/// Keeps the IBC state committed by each block of a
+/// [`MockContext`](crate::testapp::ibc::core::types::MockContext), and proves
+/// any ICS-24 path against the state of any of those blocks.
+///
+/// Every context records in its `proofs` the state each block it produces
+/// commits to, for as long as the block is in its history. The
+/// block at height `h` commits to the state as it was when the block was
+/// produced, before any message of that block is delivered. On a synthetic
+/// Tendermint host, [`Self::root`] at `h` is the app hash of that block, so
+/// the proofs verify against the consensus state a counterparty client stores
+/// for `h`.
+#[derive(Clone, Debug)]
+pub struct ProofGenerator {
+    store_key: Vec<u8>,
+    snapshots: BTreeMap<Height, MerkleStore>,
+}
+
+impl ProofGenerator {
+    /// Creates a generator for the state committed under `store_key`, with no
+    /// block recorded yet.
+    pub fn new(store_key: Vec<u8>) -> Self {
+        Self {
+            store_key,
+            snapshots: BTreeMap::new(),
+        }
+    }
+
+    /// Records `store` as the state committed by the block at `height`.
+    pub fn record(&mut self, height: Height, store: MerkleStore) {
+        self.snapshots.insert(height, store);
+    }
+
+    /// Forgets the states committed by the blocks below `height`.
+    pub fn prune_below(&mut self, height: &Height) {
+        self.snapshots = self.snapshots.split_off(height);
+    }
+
+    /// Returns the heights recorded so far, in increasing order.
+    pub fn heights(&self) -> impl Iterator<Item = &Height> {
+        self.snapshots.keys()
+    }
+
+    /// Returns the root of the state committed at `height`, or `None` if that
+    /// height was not recorded.
+    pub fn root(&self, height: &Height) -> Option<CommitmentRoot> {
+        let store = self.snapshots.get(height)?;
+        Some(multistore_root(&self.store_key, &store.root()))
+    }
+
+    /// Returns the value stored under `path` at `height`, if any.
+    pub fn value(&self, path: &Path, height: &Height) -> Option<Vec<u8>> {
+        let store = self.snapshots.get(height)?;
+        store.get(path.to_string().as_bytes()).map(<[u8]>::to_vec)
+    }
+
+    /// Returns a proof that `path` is present at `height`, or `None` if it is
+    /// absent or the height was not recorded.
+    pub fn membership_proof(&self, path: &Path, height: &Height) -> Option<CommitmentProofBytes> {
+        let store = self.snapshots.get(height)?;
+        membership_proof(store, &self.store_key, path.to_string().as_bytes())
+    }
+
+    /// Returns a proof that `path` is absent at `height`, or `None` if it is
+    /// present or the height was not recorded.
+    pub fn non_membership_proof(
+        &self,
+        path: &Path,
+        height: &Height,
+    ) -> Option<CommitmentProofBytes> {
+        let store = self.snapshots.get(height)?;
+        non_membership_proof(store, &self.store_key, path.to_string().as_bytes())
+    }
+
+    /// Returns a proof of whatever holds for `path` at `height`: its
+    /// membership if it is present, its non-membership otherwise. Returns
+    /// `None` if the height was not recorded.
+    pub fn proof(&self, path: &Path, height: &Height) -> Option<CommitmentProofBytes> {
+        self.membership_proof(path, height)
+            .or_else(|| self.non_membership_proof(path, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::ics03_connection::connection::State as ConnectionState;
+    use ibc::core::ics04_channel::channel::State as ChannelState;
+    use ibc::core::ics23_commitment::commitment::CommitmentPrefix;
+    use ibc::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+    use ibc::core::ics23_commitment::specs::ProofSpecs;
+    use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use ibc::core::ics24_host::path::{ChannelEndPath, ConnectionPath};
+    use ibc::core::ValidationContext;
+    use ibc::proto::core::commitment::v1::MerkleProof as RawMerkleProof;
+
+    use super::*;
+    use crate::hosts::block::{HostBlock, HostType};
+    use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+    use crate::testapp::ibc::core::builder::TestChainBuilder;
+    use crate::testapp::ibc::core::types::MockContext;
+
+    fn verify(
+        ctx: &MockContext,
+        proof: CommitmentProofBytes,
+        root: CommitmentRoot,
+        path: &Path,
+        value: Option<Vec<u8>>,
+    ) -> bool {
+        let proof: MerkleProof = RawMerkleProof::try_from(proof).expect("valid proof").into();
+        let prefix = CommitmentPrefix::try_from(ctx.commitment_prefix().into_vec())
+            .expect("non-empty prefix");
+        let keys = apply_prefix(&prefix, vec![path.to_string()]);
+
+        match value {
+            Some(value) => proof
+                .verify_membership(&ProofSpecs::cosmos(), root.into(), keys, value, 0)
+                .is_ok(),
+            None => proof
+                .verify_non_membership(&ProofSpecs::cosmos(), root.into(), keys)
+                .is_ok(),
+        }
+    }
+
+    #[test]
+    fn proofs_verify_at_past_heights() {
+        let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+        let conn_id = ConnectionId::new(0);
+        let chan_id = ChannelId::new(0);
+
+        let mut ctx = TestChainBuilder::default()
+            .host_type(HostType::SyntheticTendermint)
+            .max_history_size(2)
+            .client(client_id.clone(), Height::new(0, 1).unwrap())
+            .connection(conn_id.clone(), client_id, ConnectionState::Open)
+            .build();
+        ctx.advance_host_chain_height();
+        let before = ctx.latest_height();
+
+        let chan_end = TestChainBuilder::default()
+            .channel(chan_id.clone(), conn_id.clone(), ChannelState::Open)
+            .build()
+            .channel_end(&ChannelEndPath::new(&PortId::transfer(), &chan_id))
+            .unwrap();
+        ctx = ctx.with_channel(PortId::transfer(), chan_id.clone(), chan_end);
+        ctx.advance_host_chain_height();
+        let after = ctx.latest_height();
+
+        let generator = &ctx.proofs;
+        for height in [before, after] {
+            let app_hash = ctx
+                .host_block(&height)
+                .cloned()
+                .and_then(HostBlock::try_into_tm_block)
+                .unwrap()
+                .header()
+                .app_hash
+                .as_bytes()
+                .to_vec();
+            assert_eq!(generator.root(&height).unwrap().into_vec(), app_hash);
+        }
+
+        let conn_path = Path::from(ConnectionPath::new(&conn_id));
+        let chan_path = Path::from(ChannelEndPath::new(&PortId::transfer(), &chan_id));
+
+        // The channel is absent before it is added, and present after.
+        let absent = generator.proof(&chan_path, &before).unwrap();
+        assert!(verify(
+            &ctx,
+            absent.clone(),
+            generator.root(&before).unwrap(),
+            &chan_path,
+            None
+        ));
+        assert!(!verify(
+            &ctx,
+            absent,
+            generator.root(&after).unwrap(),
+            &chan_path,
+            None
+        ));
+
+        let present = generator.proof(&chan_path, &after).unwrap();
+        assert!(verify(
+            &ctx,
+            present,
+            generator.root(&after).unwrap(),
+            &chan_path,
+            generator.value(&chan_path, &after)
+        ));
+
+        // The connection is present at both heights.
+        for height in [before, after] {
+            let proof = generator.membership_proof(&conn_path, &height).unwrap();
+            assert!(verify(
+                &ctx,
+                proof,
+                generator.root(&height).unwrap(),
+                &conn_path,
+                generator.value(&conn_path, &height)
+            ));
+        }
+
+        // The initial blocks of the context commit to no recorded state, and
+        // the state of the blocks pruned from its history is forgotten.
+        assert!(generator
+            .proof(&conn_path, &Height::new(0, 1).unwrap())
+            .is_none());
+        ctx.advance_host_chain_height();
+        assert!(ctx.proofs.proof(&conn_path, &before).is_none());
+        assert!(ctx.proofs.proof(&conn_path, &after).is_some());
+        assert_eq!(ctx.proofs.heights().count(), 2);
+    }
+}
@@ -0,0 +1,388 @@
+//! Regression testing of the handlers against snapshots of their outcomes.
+//!
+//! A [`RegressionCase`] holds a sequence of messages along with what a
+//! reference run did with each of them: whether it accepted it, the events it
+//! emitted and the provable state it wrote. [`record_case`] takes such a
+//! snapshot from this implementation, and [`run_corpus`] later dispatches the
+//! messages of every case on its initial state and reports each
+//! [`Divergence`], rather than stopping at the first one as
+//! [`replay`](crate::utils::replay::replay) does, so that a single run lists
+//! every change in behavior.
+//!
+//! The snapshots only catch the changes of this implementation since they were
+//! taken. The cases recorded from ibc-go, loaded by `utils::ibc_go` behind the
+//! `ibc-go-integration` feature, are run the same way to catch the divergences
+//! from it.
+
+use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::btree_set::BTreeSet;
+
+use displaydoc::Display;
+use ibc::core::simulation::SimulationRouter;
+use ibc::core::{dispatch, MsgEnvelope, RouterError};
+use ibc::prelude::*;
+use ibc::proto::Any;
+use tendermint::abci;
+
+use crate::testapp::ibc::core::types::MockContext;
+use crate::utils::replay::{abci_events_since, same_events};
+
+/// What the reference run did with a message.
+#[derive(Clone, Debug)]
+pub enum RecordedOutcome {
+    /// The message was accepted.
+    Accepted {
+        /// The events emitted by the IBC handlers, in order.
+        events: Vec<abci::Event>,
+        /// The value written under each ICS-24 path of the provable store, or
+        /// `None` if the path was deleted. Writes outside of the provable
+        /// store, such as client metadata, must be left out.
+        writes: BTreeMap<String, Option<Vec<u8>>>,
+    },
+    /// The message was rejected, and the transaction reverted. The reason
+    /// only shows up in reports, as error messages are not part of the
+    /// behavior under test.
+    Rejected { reason: String },
+}
+
+/// A sequence of messages along with their recorded outcomes, to be run on
+/// this implementation.
+#[derive(Clone, Debug)]
+pub struct RegressionCase {
+    /// The name of the case, reported along with its divergences.
+    pub name: String,
+    /// The IBC state of the chain right before the first message.
+    pub snapshot: MockContext,
+    /// The messages, in order, each recorded as a transaction of its own.
+    pub msgs: Vec<Any>,
+    /// The outcome of each message, in order.
+    pub outcomes: Vec<RecordedOutcome>,
+    /// Whether the writes under a path are compared, so that the cases
+    /// recorded by other implementations can leave out the paths whose values
+    /// are encoded by the host rather than by the handlers.
+    pub compares_path: fn(&str) -> bool,
+}
+
+/// A difference between the recorded outcome of a message and its outcome
+/// here.
+#[derive(Debug, Display)]
+pub enum Divergence {
+    /// case `{case}` records `{recorded}` outcomes for `{msgs}` messages
+    MalformedCase {
+        case: String,
+        msgs: usize,
+        recorded: usize,
+    },
+    /// message `{msg}` of case `{case}` was recorded as accepted but failed: `{error}`
+    UnexpectedRejection {
+        case: String,
+        msg: usize,
+        error: RouterError,
+    },
+    /// message `{msg}` of case `{case}` was recorded as rejected (`{reason}`) but succeeded
+    UnexpectedAcceptance {
+        case: String,
+        msg: usize,
+        reason: String,
+    },
+    /// message `{msg}` of case `{case}` emitted `{actual:?}` instead of `{expected:?}`
+    Events {
+        case: String,
+        msg: usize,
+        expected: Vec<abci::Event>,
+        actual: Vec<abci::Event>,
+    },
+    /// message `{msg}` of case `{case}` wrote `{actual:?}` under `{path}` instead of `{expected:?}`
+    Write {
+        case: String,
+        msg: usize,
+        path: String,
+        expected: Option<Vec<u8>>,
+        actual: Option<Vec<u8>>,
+    },
+}
+
+/// Dispatches `msgs` on `snapshot`, with a branch of `router`, and records
+/// their outcomes as a case named `name`.
+///
+/// A message failing reverts the state of the context and of the modules to
+/// what it was before it, as [`run_case`] does.
+pub fn record_case(
+    name: &str,
+    snapshot: MockContext,
+    msgs: Vec<Any>,
+    router: &impl SimulationRouter,
+) -> RegressionCase {
+    let mut ctx = snapshot.clone();
+    let mut router = router.branch();
+
+    let outcomes = msgs
+        .iter()
+        .map(|msg| {
+            let before = ctx.clone();
+            let router_before = router.branch();
+            let first_event = ctx.events.len();
+
+            let res = MsgEnvelope::try_from(msg.clone())
+                .and_then(|envelope| dispatch(&mut ctx, &mut router, envelope));
+
+            match res {
+                Ok(_) => RecordedOutcome::Accepted {
+                    events: abci_events_since(&ctx, first_event).unwrap_or_default(),
+                    writes: state_writes(&before, &ctx),
+                },
+                Err(e) => {
+                    ctx = before;
+                    router = router_before;
+                    RecordedOutcome::Rejected {
+                        reason: e.to_string(),
+                    }
+                }
+            }
+        })
+        .collect();
+
+    RegressionCase {
+        name: name.to_string(),
+        snapshot,
+        msgs,
+        outcomes,
+        compares_path: |_| true,
+    }
+}
+
+/// Runs every case of `corpus` through [`dispatch`] and returns all the
+/// divergences from the recorded outcomes, which is empty if there are none.
+pub fn run_corpus<'a>(
+    corpus: impl IntoIterator<Item = &'a RegressionCase>,
+    router: &impl SimulationRouter,
+) -> Vec<Divergence> {
+    corpus
+        .into_iter()
+        .flat_map(|case| run_case(case, router))
+        .collect()
+}
+
+/// Runs the messages of `case` on its snapshot, with a branch of `router`, and
+/// returns the divergences from the recorded outcomes.
+///
+/// A message failing here reverts the state of the context and of the modules
+/// to what it was before it, the way hosts revert its transaction, so that a
+/// case goes on with the recorded outcomes of the next messages. Events are
+/// compared by kind and attribute keys and values, ignoring whether
+/// attributes are indexed.
+pub fn run_case(case: &RegressionCase, router: &impl SimulationRouter) -> Vec<Divergence> {
+    if case.msgs.len() != case.outcomes.len() {
+        return vec![Divergence::MalformedCase {
+            case: case.name.clone(),
+            msgs: case.msgs.len(),
+            recorded: case.outcomes.len(),
+        }];
+    }
+
+    let mut ctx = case.snapshot.clone();
+    let mut router = router.branch();
+    let mut divergences = Vec::new();
+
+    for (msg_index, (msg, recorded)) in case.msgs.iter().zip(&case.outcomes).enumerate() {
+        let before = ctx.clone();
+        let router_before = router.branch();
+        let first_event = ctx.events.len();
+
+        let res = MsgEnvelope::try_from(msg.clone())
+            .and_then(|envelope| dispatch(&mut ctx, &mut router, envelope));
+
+        match (res, recorded) {
+            (Ok(_), RecordedOutcome::Accepted { events, writes }) => {
+                // Events of the IBC handlers always convert, or `dispatch`
+                // would not have emitted them.
+                let actual = abci_events_since(&ctx, first_event).unwrap_or_default();
+                if !same_events(&actual, events) {
+                    divergences.push(Divergence::Events {
+                        case: case.name.clone(),
+                        msg: msg_index,
+                        expected: events.clone(),
+                        actual,
+                    });
+                }
+
+                let actual_writes = state_writes(&before, &ctx);
+                let paths: BTreeSet<_> = writes
+                    .keys()
+                    .chain(actual_writes.keys())
+                    .filter(|path| (case.compares_path)(path))
+                    .collect();
+                for path in paths {
+                    let expected = writes.get(path);
+                    let actual = actual_writes.get(path);
+                    if expected != actual {
+                        divergences.push(Divergence::Write {
+                            case: case.name.clone(),
+                            msg: msg_index,
+                            path: path.clone(),
+                            expected: expected.cloned().flatten(),
+                            actual: actual.cloned().flatten(),
+                        });
+                    }
+                }
+            }
+            (Ok(_), RecordedOutcome::Rejected { reason }) => {
+                divergences.push(Divergence::UnexpectedAcceptance {
+                    case: case.name.clone(),
+                    msg: msg_index,
+                    reason: reason.clone(),
+                });
+            }
+            (Err(error), RecordedOutcome::Accepted { .. }) => {
+                divergences.push(Divergence::UnexpectedRejection {
+                    case: case.name.clone(),
+                    msg: msg_index,
+                    error,
+                });
+                ctx = before;
+                router = router_before;
+            }
+            (Err(_), RecordedOutcome::Rejected { .. }) => {
+                ctx = before;
+                router = router_before;
+            }
+        }
+    }
+
+    divergences
+}
+
+/// Returns the value written under each path of the provable store going from
+/// `before` to `after`, or `None` for the deleted paths.
+pub fn state_writes(
+    before: &MockContext,
+    after: &MockContext,
+) -> BTreeMap<String, Option<Vec<u8>>> {
+    let before = before.ibc_store.lock().commitment_store();
+    let after = after.ibc_store.lock().commitment_store();
+
+    let mut writes = BTreeMap::new();
+    for (key, value) in after.iter() {
+        if before.get(key) != Some(value) {
+            writes.insert(
+                String::from_utf8_lossy(key).into_owned(),
+                Some(value.to_vec()),
+            );
+        }
+    }
+    for (key, _) in before.iter() {
+        if after.get(key).is_none() {
+            writes.insert(String::from_utf8_lossy(key).into_owned(), None);
+        }
+    }
+    writes
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::ics03_connection::connection::State as ConnectionState;
+    use ibc::core::ics04_channel::channel::State as ChannelState;
+    use ibc::core::ics04_channel::msgs::MsgRecvPacket;
+    use ibc::core::ics04_channel::packet::Sequence;
+    use ibc::core::ics23_commitment::commitment::CommitmentProofBytes;
+    use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use ibc::core::Msg;
+    use ibc::Height;
+
+    use super::*;
+    use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+    use crate::testapp::ibc::core::builder::{unacked_packet, TestChainBuilder};
+    use crate::testapp::ibc::core::router::MockRouter;
+    use crate::utils::dummies::core::signer::dummy_account_id;
+
+    fn sample_case() -> RegressionCase {
+        let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+        let conn_id = ConnectionId::new(0);
+        let port_id = PortId::transfer();
+        let chan_id = ChannelId::new(0);
+        let client_height = Height::new(0, 20).unwrap();
+
+        let snapshot = TestChainBuilder::default()
+            .client(client_id.clone(), client_height)
+            .connection(conn_id.clone(), client_id, ConnectionState::Open)
+            .channel(chan_id.clone(), conn_id, ChannelState::Open)
+            .build();
+
+        let recv = |chan_id: &ChannelId, seq: u64| {
+            MsgRecvPacket {
+                packet: unacked_packet(&port_id, chan_id, Sequence::from(seq)),
+                proof_commitment_on_a: CommitmentProofBytes::try_from(b"proof".to_vec()).unwrap(),
+                proof_height_on_a: client_height,
+                signer: dummy_account_id(),
+            }
+            .to_any()
+        };
+
+        // The second message is rejected, as its channel does not exist.
+        let msgs = vec![
+            recv(&chan_id, 1),
+            recv(&ChannelId::new(7), 2),
+            recv(&chan_id, 3),
+        ];
+
+        record_case(
+            "recv_packets",
+            snapshot,
+            msgs,
+            &MockRouter::new_with_transfer(),
+        )
+    }
+
+    #[test]
+    fn recorded_outcomes_match() {
+        let case = sample_case();
+        assert!(matches!(
+            case.outcomes[0],
+            RecordedOutcome::Accepted { ref writes, .. } if !writes.is_empty()
+        ));
+        assert!(matches!(case.outcomes[1], RecordedOutcome::Rejected { .. }));
+
+        let divergences = run_corpus([&case], &MockRouter::new_with_transfer());
+        assert!(divergences.is_empty(), "{divergences:?}");
+    }
+
+    #[test]
+    fn every_divergence_is_reported() {
+        let mut case = sample_case();
+        if let RecordedOutcome::Accepted { events, writes } = &mut case.outcomes[0] {
+            events[0].attributes[0].value.push('x');
+            writes.insert("diverged".to_string(), Some(vec![1]));
+        }
+        case.outcomes[1] = case.outcomes[2].clone();
+        case.outcomes[2] = RecordedOutcome::Rejected {
+            reason: "recorded".to_string(),
+        };
+
+        let divergences = run_corpus([&case], &MockRouter::new_with_transfer());
+        assert!(matches!(divergences[0], Divergence::Events { msg: 0, .. }));
+        assert!(matches!(
+            divergences[1],
+            Divergence::Write { msg: 0, ref path, .. } if path == "diverged"
+        ));
+        assert!(matches!(
+            divergences[2],
+            Divergence::UnexpectedRejection { msg: 1, .. }
+        ));
+        assert!(matches!(
+            divergences[3],
+            Divergence::UnexpectedAcceptance { msg: 2, .. }
+        ));
+        assert_eq!(divergences.len(), 4);
+
+        case.outcomes.pop();
+        let divergences = run_corpus([&case], &MockRouter::new_with_transfer());
+        assert!(matches!(
+            divergences[..],
+            [Divergence::MalformedCase {
+                msgs: 3,
+                recorded: 2,
+                ..
+            }]
+        ));
+    }
+}
@@ -0,0 +1,288 @@
+//! Deterministic replay of IBC transactions recorded on a live chain, meant to
+//! serve as a correctness oracle against other implementations, such as
+//! ibc-go.
+//!
+//! A [`Recording`] holds a snapshot of the IBC state of a chain, the
+//! transactions later included by that chain along with the events they
+//! emitted, and the provable state they resulted in. [`replay`] dispatches the
+//! recorded messages on the snapshot and fails on the first divergence.
+
+use alloc::collections::btree_map::BTreeMap;
+
+use displaydoc::Display;
+use ibc::core::events::Error as EventError;
+use ibc::core::router::Router;
+use ibc::core::{dispatch, MsgEnvelope, RouterError};
+use ibc::prelude::*;
+use ibc::proto::Any;
+use tendermint::abci;
+
+use crate::testapp::ibc::core::types::MockContext;
+
+/// A transaction recorded on a live chain.
+#[derive(Clone, Debug)]
+pub struct RecordedTx {
+    /// The messages of the transaction, in order.
+    pub msgs: Vec<Any>,
+    /// The events emitted by the IBC handlers while processing the
+    /// transaction, in order. Events of other modules must be left out.
+    pub events: Vec<abci::Event>,
+}
+
+/// Everything needed to replay a sequence of transactions and check the
+/// outcome.
+#[derive(Clone, Debug)]
+pub struct Recording {
+    /// The IBC state of the chain right before the first transaction.
+    pub snapshot: MockContext,
+    /// The transactions to replay, in the order they were included.
+    pub txs: Vec<RecordedTx>,
+    /// The value expected under each listed ICS-24 path once every
+    /// transaction is replayed, or `None` if the path must be absent. Paths
+    /// that are not listed are not checked.
+    pub final_state: BTreeMap<String, Option<Vec<u8>>>,
+}
+
+#[derive(Debug, Display)]
+pub enum ReplayError {
+    /// message `{msg}` of transaction `{tx}` cannot be decoded: `{error}`
+    Decode {
+        tx: usize,
+        msg: usize,
+        error: RouterError,
+    },
+    /// message `{msg}` of transaction `{tx}` failed: `{error}`
+    Dispatch {
+        tx: usize,
+        msg: usize,
+        error: RouterError,
+    },
+    /// an event of transaction `{tx}` cannot be converted: `{error}`
+    EventConversion { tx: usize, error: EventError },
+    /// transaction `{tx}` emitted `{actual:?}` instead of `{expected:?}`
+    EventMismatch {
+        tx: usize,
+        expected: Vec<abci::Event>,
+        actual: Vec<abci::Event>,
+    },
+    /// value under `{path}` is `{actual:?}` instead of `{expected:?}`
+    StateMismatch {
+        path: String,
+        expected: Option<Vec<u8>>,
+        actual: Option<Vec<u8>>,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReplayError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Decode { error, .. } | Self::Dispatch { error, .. } => Some(error),
+            Self::EventConversion { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Replays the transactions of `recording` on its snapshot through
+/// [`dispatch`], checking the events of each transaction and the final state
+/// against the recorded ones. Returns the resulting context.
+///
+/// The host chain produces one block after each transaction, so that the
+/// outcome only depends on the recording. Events are compared by kind and
+/// attribute keys and values, ignoring whether attributes are indexed.
+#[allow(clippy::result_large_err)]
+pub fn replay(recording: &Recording, router: &mut impl Router) -> Result<MockContext, ReplayError> {
+    let mut ctx = recording.snapshot.clone();
+
+    for (tx_index, tx) in recording.txs.iter().enumerate() {
+        let first_event = ctx.events.len();
+
+        for (msg_index, msg) in tx.msgs.iter().enumerate() {
+            let envelope =
+                MsgEnvelope::try_from(msg.clone()).map_err(|error| ReplayError::Decode {
+                    tx: tx_index,
+                    msg: msg_index,
+                    error,
+                })?;
+            dispatch(&mut ctx, router, envelope).map_err(|error| ReplayError::Dispatch {
+                tx: tx_index,
+                msg: msg_index,
+                error,
+            })?;
+        }
+
+        let actual =
+            abci_events_since(&ctx, first_event).map_err(|error| ReplayError::EventConversion {
+                tx: tx_index,
+                error,
+            })?;
+        if !same_events(&actual, &tx.events) {
+            return Err(ReplayError::EventMismatch {
+                tx: tx_index,
+                expected: tx.events.clone(),
+                actual,
+            });
+        }
+
+        ctx.advance_host_chain_height();
+    }
+
+    let store = ctx.ibc_store.lock().commitment_store();
+    for (path, expected) in &recording.final_state {
+        let actual = store.get(path.as_bytes()).map(<[u8]>::to_vec);
+        if &actual != expected {
+            return Err(ReplayError::StateMismatch {
+                path: path.clone(),
+                expected: expected.clone(),
+                actual,
+            });
+        }
+    }
+
+    Ok(ctx)
+}
+
+/// Returns the events emitted on `ctx` since the `first_event`-th one, in the
+/// form expected by [`RecordedTx::events`].
+pub fn abci_events_since(
+    ctx: &MockContext,
+    first_event: usize,
+) -> Result<Vec<abci::Event>, EventError> {
+    ctx.events[first_event..]
+        .iter()
+        .cloned()
+        .map(abci::Event::try_from)
+        .collect()
+}
+
+pub(crate) fn same_events(lhs: &[abci::Event], rhs: &[abci::Event]) -> bool {
+    let key = |event: &abci::Event| {
+        (
+            event.kind.clone(),
+            event
+                .attributes
+                .iter()
+                .map(|attribute| (attribute.key.clone(), attribute.value.clone()))
+                .collect::<Vec<_>>(),
+        )
+    };
+
+    lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(l, r)| key(l) == key(r))
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::ics03_connection::connection::State as ConnectionState;
+    use ibc::core::ics04_channel::channel::State as ChannelState;
+    use ibc::core::ics04_channel::msgs::MsgRecvPacket;
+    use ibc::core::ics04_channel::packet::Sequence;
+    use ibc::core::ics23_commitment::commitment::CommitmentProofBytes;
+    use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use ibc::core::ics24_host::path::{AckPath, ReceiptPath};
+    use ibc::core::Msg;
+    use ibc::Height;
+
+    use super::*;
+    use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+    use crate::testapp::ibc::core::builder::{unacked_packet, TestChainBuilder};
+    use crate::testapp::ibc::core::router::MockRouter;
+    use crate::utils::dummies::core::signer::dummy_account_id;
+
+    /// Records the outcome of `txs` on `snapshot`, as a live chain would.
+    fn record(snapshot: MockContext, txs: Vec<Vec<Any>>, paths: &[String]) -> Recording {
+        let mut ctx = snapshot.clone();
+        let mut router = MockRouter::new_with_transfer();
+
+        let txs = txs
+            .into_iter()
+            .map(|msgs| {
+                let first_event = ctx.events.len();
+                for msg in &msgs {
+                    let envelope = MsgEnvelope::try_from(msg.clone()).unwrap();
+                    dispatch(&mut ctx, &mut router, envelope).unwrap();
+                }
+                let events = abci_events_since(&ctx, first_event).unwrap();
+                ctx.advance_host_chain_height();
+                RecordedTx { msgs, events }
+            })
+            .collect();
+
+        let store = ctx.ibc_store.lock().commitment_store();
+        let final_state = paths
+            .iter()
+            .map(|path| (path.clone(), store.get(path.as_bytes()).map(<[u8]>::to_vec)))
+            .collect();
+
+        Recording {
+            snapshot,
+            txs,
+            final_state,
+        }
+    }
+
+    fn sample_recording() -> Recording {
+        let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+        let conn_id = ConnectionId::new(0);
+        let port_id = PortId::transfer();
+        let chan_id = ChannelId::new(0);
+        let client_height = Height::new(0, 20).unwrap();
+
+        let snapshot = TestChainBuilder::default()
+            .client(client_id.clone(), client_height)
+            .connection(conn_id.clone(), client_id, ConnectionState::Open)
+            .channel(chan_id.clone(), conn_id, ChannelState::Open)
+            .build();
+
+        let recv = |seq: u64| {
+            MsgRecvPacket {
+                packet: unacked_packet(&port_id, &chan_id, Sequence::from(seq)),
+                proof_commitment_on_a: CommitmentProofBytes::try_from(b"proof".to_vec()).unwrap(),
+                proof_height_on_a: client_height,
+                signer: dummy_account_id(),
+            }
+            .to_any()
+        };
+
+        let paths = [
+            AckPath::new(&port_id, &chan_id, Sequence::from(1)).to_string(),
+            ReceiptPath::new(&port_id, &chan_id, Sequence::from(2)).to_string(),
+            ReceiptPath::new(&port_id, &chan_id, Sequence::from(3)).to_string(),
+        ];
+
+        record(snapshot, vec![vec![recv(1)], vec![recv(2)]], &paths)
+    }
+
+    #[test]
+    fn replay_matches_recording() {
+        let recording = sample_recording();
+        assert!(recording.txs.iter().all(|tx| !tx.events.is_empty()));
+        assert!(recording.final_state.values().any(Option::is_some));
+        assert!(recording.final_state.values().any(Option::is_none));
+
+        replay(&recording, &mut MockRouter::new_with_transfer()).unwrap();
+    }
+
+    #[test]
+    fn replay_detects_divergences() {
+        let mut recording = sample_recording();
+        recording.txs[1].events[0].attributes[0].value.push('x');
+        let res = replay(&recording, &mut MockRouter::new_with_transfer());
+        assert!(matches!(res, Err(ReplayError::EventMismatch { tx: 1, .. })));
+
+        let mut recording = sample_recording();
+        for expected in recording.final_state.values_mut() {
+            *expected = Some(b"diverged".to_vec());
+        }
+        let res = replay(&recording, &mut MockRouter::new_with_transfer());
+        assert!(matches!(res, Err(ReplayError::StateMismatch { .. })));
+
+        let mut recording = sample_recording();
+        recording.txs[0].msgs[0].type_url.push('x');
+        let res = replay(&recording, &mut MockRouter::new_with_transfer());
+        assert!(matches!(
+            res,
+            Err(ReplayError::Decode { tx: 0, msg: 0, .. })
+        ));
+    }
+}
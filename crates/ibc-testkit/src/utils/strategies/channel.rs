@@ -0,0 +1,342 @@
+use ibc::core::ics04_channel::acknowledgement::Acknowledgement;
+use ibc::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::ics04_channel::msgs::{
+    MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+    MsgTimeoutOnClose,
+};
+use ibc::core::ics04_channel::packet::Packet;
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::ics04_channel::Version;
+use ibc::core::ics24_host::identifier::ChannelId;
+use ibc::core::timestamp::Timestamp;
+use proptest::prelude::*;
+
+use super::host::{
+    arb_channel_id, arb_commitment_proof_bytes, arb_connection_id, arb_height, arb_port_id,
+    arb_sequence, arb_signer, arb_timeout_height, arb_timestamp,
+};
+
+/// Generates either channel ordering, excluding `Order::None`.
+pub fn arb_order() -> impl Strategy<Value = Order> {
+    prop_oneof![Just(Order::Unordered), Just(Order::Ordered)]
+}
+
+pub fn arb_channel_version() -> impl Strategy<Value = Version> {
+    "[a-z0-9-]{0,20}".prop_map(Version::new)
+}
+
+/// Generates a channel counterparty whose channel identifier is generated by
+/// `channel_id`.
+pub fn arb_channel_counterparty(
+    channel_id: impl Strategy<Value = Option<ChannelId>>,
+) -> impl Strategy<Value = Counterparty> {
+    (arb_port_id(), channel_id)
+        .prop_map(|(port_id, channel_id)| Counterparty::new(port_id, channel_id))
+}
+
+/// Generates an initialized channel end.
+pub fn arb_channel_end() -> impl Strategy<Value = ChannelEnd> {
+    (
+        prop_oneof![
+            Just(State::Init),
+            Just(State::TryOpen),
+            Just(State::Open),
+            Just(State::Closed),
+        ],
+        arb_order(),
+        arb_channel_counterparty(proptest::option::of(arb_channel_id())),
+        prop::collection::vec(arb_connection_id(), 1..3),
+        arb_channel_version(),
+    )
+        .prop_map(|(state, ordering, remote, connection_hops, version)| {
+            ChannelEnd::new(state, ordering, remote, connection_hops, version).expect("Never fails")
+        })
+}
+
+/// Generates a packet with non-empty data and at least one of its timeout
+/// height and timeout timestamp set.
+pub fn arb_packet() -> impl Strategy<Value = Packet> {
+    let timeouts = (arb_timeout_height(), arb_timestamp()).prop_filter(
+        "packet timeout height and timestamp cannot both be unset",
+        |(height, timestamp): &(TimeoutHeight, Timestamp)| height.is_set() || timestamp.is_set(),
+    );
+
+    (
+        arb_sequence(),
+        arb_port_id(),
+        arb_channel_id(),
+        arb_port_id(),
+        arb_channel_id(),
+        prop::collection::vec(any::<u8>(), 1..256),
+        timeouts,
+    )
+        .prop_map(
+            |(
+                seq_on_a,
+                port_id_on_a,
+                chan_id_on_a,
+                port_id_on_b,
+                chan_id_on_b,
+                data,
+                (timeout_height_on_b, timeout_timestamp_on_b),
+            )| Packet {
+                seq_on_a,
+                port_id_on_a,
+                chan_id_on_a,
+                port_id_on_b,
+                chan_id_on_b,
+                data,
+                timeout_height_on_b,
+                timeout_timestamp_on_b,
+            },
+        )
+}
+
+pub fn arb_acknowledgement() -> impl Strategy<Value = Acknowledgement> {
+    prop::collection::vec(any::<u8>(), 1..256)
+        .prop_map(|bytes| Acknowledgement::try_from(bytes).expect("Never fails"))
+}
+
+pub fn arb_msg_chan_open_init() -> impl Strategy<Value = MsgChannelOpenInit> {
+    (
+        arb_port_id(),
+        prop::collection::vec(arb_connection_id(), 1..3),
+        arb_port_id(),
+        arb_order(),
+        arb_signer(),
+        arb_channel_version(),
+    )
+        .prop_map(
+            |(
+                port_id_on_a,
+                connection_hops_on_a,
+                port_id_on_b,
+                ordering,
+                signer,
+                version_proposal,
+            )| {
+                MsgChannelOpenInit {
+                    port_id_on_a,
+                    connection_hops_on_a,
+                    port_id_on_b,
+                    ordering,
+                    signer,
+                    version_proposal,
+                }
+            },
+        )
+}
+
+/// Generates a `MsgChannelOpenTry` whose deprecated `version_proposal` is
+/// empty, as it is not carried by the raw message.
+pub fn arb_msg_chan_open_try() -> impl Strategy<Value = MsgChannelOpenTry> {
+    (
+        arb_port_id(),
+        prop::collection::vec(arb_connection_id(), 1..3),
+        arb_port_id(),
+        arb_channel_id(),
+        arb_channel_version(),
+        arb_commitment_proof_bytes(),
+        arb_height(),
+        arb_order(),
+        arb_signer(),
+    )
+        .prop_map(
+            |(
+                port_id_on_b,
+                connection_hops_on_b,
+                port_id_on_a,
+                chan_id_on_a,
+                version_supported_on_a,
+                proof_chan_end_on_a,
+                proof_height_on_a,
+                ordering,
+                signer,
+            )| {
+                #[allow(deprecated)]
+                MsgChannelOpenTry {
+                    port_id_on_b,
+                    connection_hops_on_b,
+                    port_id_on_a,
+                    chan_id_on_a,
+                    version_supported_on_a,
+                    proof_chan_end_on_a,
+                    proof_height_on_a,
+                    ordering,
+                    signer,
+                    version_proposal: Version::empty(),
+                }
+            },
+        )
+}
+
+pub fn arb_msg_chan_open_ack() -> impl Strategy<Value = MsgChannelOpenAck> {
+    (
+        arb_port_id(),
+        arb_channel_id(),
+        arb_channel_id(),
+        arb_channel_version(),
+        arb_commitment_proof_bytes(),
+        arb_height(),
+        arb_signer(),
+    )
+        .prop_map(
+            |(
+                port_id_on_a,
+                chan_id_on_a,
+                chan_id_on_b,
+                version_on_b,
+                proof_chan_end_on_b,
+                proof_height_on_b,
+                signer,
+            )| MsgChannelOpenAck {
+                port_id_on_a,
+                chan_id_on_a,
+                chan_id_on_b,
+                version_on_b,
+                proof_chan_end_on_b,
+                proof_height_on_b,
+                signer,
+            },
+        )
+}
+
+pub fn arb_msg_chan_open_confirm() -> impl Strategy<Value = MsgChannelOpenConfirm> {
+    (
+        arb_port_id(),
+        arb_channel_id(),
+        arb_commitment_proof_bytes(),
+        arb_height(),
+        arb_signer(),
+    )
+        .prop_map(
+            |(port_id_on_b, chan_id_on_b, proof_chan_end_on_a, proof_height_on_a, signer)| {
+                MsgChannelOpenConfirm {
+                    port_id_on_b,
+                    chan_id_on_b,
+                    proof_chan_end_on_a,
+                    proof_height_on_a,
+                    signer,
+                }
+            },
+        )
+}
+
+pub fn arb_msg_chan_close_init() -> impl Strategy<Value = MsgChannelCloseInit> {
+    (arb_port_id(), arb_channel_id(), arb_signer()).prop_map(
+        |(port_id_on_a, chan_id_on_a, signer)| MsgChannelCloseInit {
+            port_id_on_a,
+            chan_id_on_a,
+            signer,
+        },
+    )
+}
+
+pub fn arb_msg_chan_close_confirm() -> impl Strategy<Value = MsgChannelCloseConfirm> {
+    (
+        arb_port_id(),
+        arb_channel_id(),
+        arb_commitment_proof_bytes(),
+        arb_height(),
+        arb_signer(),
+    )
+        .prop_map(
+            |(port_id_on_b, chan_id_on_b, proof_chan_end_on_a, proof_height_on_a, signer)| {
+                MsgChannelCloseConfirm {
+                    port_id_on_b,
+                    chan_id_on_b,
+                    proof_chan_end_on_a,
+                    proof_height_on_a,
+                    signer,
+                }
+            },
+        )
+}
+
+pub fn arb_msg_recv_packet() -> impl Strategy<Value = MsgRecvPacket> {
+    (
+        arb_packet(),
+        arb_commitment_proof_bytes(),
+        arb_height(),
+        arb_signer(),
+    )
+        .prop_map(
+            |(packet, proof_commitment_on_a, proof_height_on_a, signer)| MsgRecvPacket {
+                packet,
+                proof_commitment_on_a,
+                proof_height_on_a,
+                signer,
+            },
+        )
+}
+
+pub fn arb_msg_acknowledgement() -> impl Strategy<Value = MsgAcknowledgement> {
+    (
+        arb_packet(),
+        arb_acknowledgement(),
+        arb_commitment_proof_bytes(),
+        arb_height(),
+        arb_signer(),
+    )
+        .prop_map(
+            |(packet, acknowledgement, proof_acked_on_b, proof_height_on_b, signer)| {
+                MsgAcknowledgement {
+                    packet,
+                    acknowledgement,
+                    proof_acked_on_b,
+                    proof_height_on_b,
+                    signer,
+                }
+            },
+        )
+}
+
+pub fn arb_msg_timeout() -> impl Strategy<Value = MsgTimeout> {
+    (
+        arb_packet(),
+        arb_sequence(),
+        arb_commitment_proof_bytes(),
+        arb_height(),
+        arb_signer(),
+    )
+        .prop_map(
+            |(packet, next_seq_recv_on_b, proof_unreceived_on_b, proof_height_on_b, signer)| {
+                MsgTimeout {
+                    packet,
+                    next_seq_recv_on_b,
+                    proof_unreceived_on_b,
+                    proof_height_on_b,
+                    signer,
+                }
+            },
+        )
+}
+
+pub fn arb_msg_timeout_on_close() -> impl Strategy<Value = MsgTimeoutOnClose> {
+    (
+        arb_packet(),
+        arb_sequence(),
+        arb_commitment_proof_bytes(),
+        arb_commitment_proof_bytes(),
+        arb_height(),
+        arb_signer(),
+    )
+        .prop_map(
+            |(
+                packet,
+                next_seq_recv_on_b,
+                proof_unreceived_on_b,
+                proof_close_on_b,
+                proof_height_on_b,
+                signer,
+            )| MsgTimeoutOnClose {
+                packet,
+                next_seq_recv_on_b,
+                proof_unreceived_on_b,
+                proof_close_on_b,
+                proof_height_on_b,
+                signer,
+            },
+        )
+}
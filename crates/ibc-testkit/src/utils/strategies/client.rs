@@ -0,0 +1,102 @@
+use ibc::core::ics02_client::msgs::create_client::MsgCreateClient;
+use ibc::core::ics02_client::msgs::misbehaviour::MsgSubmitMisbehaviour;
+use ibc::core::ics02_client::msgs::update_client::MsgUpdateClient;
+use ibc::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
+use ibc::proto::Any;
+use proptest::prelude::*;
+
+use super::host::{
+    arb_client_id, arb_commitment_proof_bytes, arb_height, arb_signer, arb_timestamp,
+};
+use crate::testapp::ibc::clients::mock::client_state::MockClientState;
+use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use crate::testapp::ibc::clients::mock::header::MockHeader;
+use crate::testapp::ibc::clients::mock::misbehaviour::Misbehaviour;
+
+pub fn arb_mock_header() -> impl Strategy<Value = MockHeader> {
+    (arb_height(), arb_timestamp())
+        .prop_map(|(height, timestamp)| MockHeader::new(height).with_timestamp(timestamp))
+}
+
+/// Generates a mock client state, encoded as an `Any`.
+pub fn arb_mock_client_state() -> impl Strategy<Value = Any> {
+    arb_mock_header().prop_map(|header| MockClientState::new(header).into())
+}
+
+/// Generates a mock consensus state, encoded as an `Any`.
+pub fn arb_mock_consensus_state() -> impl Strategy<Value = Any> {
+    arb_mock_header().prop_map(|header| MockConsensusState::new(header).into())
+}
+
+/// Generates a mock misbehaviour, encoded as an `Any`.
+pub fn arb_mock_misbehaviour() -> impl Strategy<Value = Any> {
+    (arb_client_id(), arb_mock_header(), arb_mock_header()).prop_map(
+        |(client_id, header1, header2)| {
+            Misbehaviour {
+                client_id,
+                header1,
+                header2,
+            }
+            .into()
+        },
+    )
+}
+
+pub fn arb_msg_create_client() -> impl Strategy<Value = MsgCreateClient> {
+    (
+        arb_mock_client_state(),
+        arb_mock_consensus_state(),
+        arb_signer(),
+    )
+        .prop_map(|(client_state, consensus_state, signer)| {
+            MsgCreateClient::new(client_state, consensus_state, signer)
+        })
+}
+
+pub fn arb_msg_update_client() -> impl Strategy<Value = MsgUpdateClient> {
+    (arb_client_id(), arb_mock_header(), arb_signer()).prop_map(|(client_id, header, signer)| {
+        MsgUpdateClient {
+            client_id,
+            client_message: header.into(),
+            signer,
+        }
+    })
+}
+
+pub fn arb_msg_upgrade_client() -> impl Strategy<Value = MsgUpgradeClient> {
+    (
+        arb_client_id(),
+        arb_mock_client_state(),
+        arb_mock_consensus_state(),
+        arb_commitment_proof_bytes(),
+        arb_commitment_proof_bytes(),
+        arb_signer(),
+    )
+        .prop_map(
+            |(
+                client_id,
+                upgraded_client_state,
+                upgraded_consensus_state,
+                proof_upgrade_client,
+                proof_upgrade_consensus_state,
+                signer,
+            )| MsgUpgradeClient {
+                client_id,
+                upgraded_client_state,
+                upgraded_consensus_state,
+                proof_upgrade_client,
+                proof_upgrade_consensus_state,
+                signer,
+            },
+        )
+}
+
+pub fn arb_msg_submit_misbehaviour() -> impl Strategy<Value = MsgSubmitMisbehaviour> {
+    (arb_client_id(), arb_mock_misbehaviour(), arb_signer()).prop_map(
+        |(client_id, misbehaviour, signer)| MsgSubmitMisbehaviour {
+            client_id,
+            misbehaviour,
+            signer,
+        },
+    )
+}
@@ -0,0 +1,197 @@
+use core::time::Duration;
+
+use ibc::core::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
+use ibc::core::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
+use ibc::core::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use ibc::core::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use ibc::core::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
+use ibc::core::ics03_connection::version::Version;
+use ibc::core::ics24_host::identifier::ConnectionId;
+use ibc::prelude::*;
+use ibc::proto::core::connection::v1::Version as RawVersion;
+use proptest::prelude::*;
+
+use super::client::arb_mock_client_state;
+use super::host::{
+    arb_client_id, arb_commitment_prefix, arb_commitment_proof_bytes, arb_connection_id,
+    arb_height, arb_signer,
+};
+
+pub fn arb_connection_version() -> impl Strategy<Value = Version> {
+    (
+        "[1-9][0-9]{0,2}",
+        prop::collection::vec("[A-Z_]{1,20}", 0..4),
+    )
+        .prop_map(|(identifier, features)| {
+            RawVersion {
+                identifier,
+                features,
+            }
+            .try_into()
+            .expect("Never fails")
+        })
+}
+
+pub fn arb_delay_period() -> impl Strategy<Value = Duration> {
+    any::<u64>().prop_map(Duration::from_nanos)
+}
+
+/// Generates a connection counterparty whose connection identifier is
+/// generated by `connection_id`.
+pub fn arb_connection_counterparty(
+    connection_id: impl Strategy<Value = Option<ConnectionId>>,
+) -> impl Strategy<Value = Counterparty> {
+    (arb_client_id(), connection_id, arb_commitment_prefix()).prop_map(
+        |(client_id, connection_id, prefix)| Counterparty::new(client_id, connection_id, prefix),
+    )
+}
+
+/// Generates an initialized connection end, holding the set of compatible
+/// versions in the `Init` state and the single negotiated version otherwise.
+pub fn arb_connection_end() -> impl Strategy<Value = ConnectionEnd> {
+    prop_oneof![Just(State::Init), Just(State::TryOpen), Just(State::Open)]
+        .prop_flat_map(|state| {
+            let versions = match state {
+                State::Init => 1..4,
+                _ => 1..2,
+            };
+            (
+                Just(state),
+                arb_client_id(),
+                arb_connection_counterparty(proptest::option::of(arb_connection_id())),
+                prop::collection::vec(arb_connection_version(), versions),
+                arb_delay_period(),
+            )
+        })
+        .prop_map(|(state, client_id, counterparty, versions, delay_period)| {
+            ConnectionEnd::new(state, client_id, counterparty, versions, delay_period)
+                .expect("Never fails")
+        })
+}
+
+pub fn arb_msg_conn_open_init() -> impl Strategy<Value = MsgConnectionOpenInit> {
+    (
+        arb_client_id(),
+        arb_connection_counterparty(Just(None)),
+        proptest::option::of(arb_connection_version()),
+        arb_delay_period(),
+        arb_signer(),
+    )
+        .prop_map(
+            |(client_id_on_a, counterparty, version, delay_period, signer)| MsgConnectionOpenInit {
+                client_id_on_a,
+                counterparty,
+                version,
+                delay_period,
+                signer,
+            },
+        )
+}
+
+pub fn arb_msg_conn_open_try() -> impl Strategy<Value = MsgConnectionOpenTry> {
+    (
+        (
+            arb_client_id(),
+            arb_mock_client_state(),
+            arb_connection_counterparty(arb_connection_id().prop_map(Some)),
+            prop::collection::vec(arb_connection_version(), 1..4),
+        ),
+        (
+            arb_commitment_proof_bytes(),
+            arb_commitment_proof_bytes(),
+            arb_commitment_proof_bytes(),
+            proptest::option::of(arb_commitment_proof_bytes()),
+        ),
+        (arb_height(), arb_height(), arb_delay_period(), arb_signer()),
+    )
+        .prop_map(
+            |(
+                (client_id_on_b, client_state_of_b_on_a, counterparty, versions_on_a),
+                (
+                    proof_conn_end_on_a,
+                    proof_client_state_of_b_on_a,
+                    proof_consensus_state_of_b_on_a,
+                    proof_consensus_state_of_b,
+                ),
+                (proofs_height_on_a, consensus_height_of_b_on_a, delay_period, signer),
+            )| {
+                #[allow(deprecated)]
+                MsgConnectionOpenTry {
+                    client_id_on_b,
+                    client_state_of_b_on_a,
+                    counterparty,
+                    versions_on_a,
+                    proof_conn_end_on_a,
+                    proof_client_state_of_b_on_a,
+                    proof_consensus_state_of_b_on_a,
+                    proofs_height_on_a,
+                    consensus_height_of_b_on_a,
+                    delay_period,
+                    signer,
+                    proof_consensus_state_of_b,
+                    previous_connection_id: String::new(),
+                }
+            },
+        )
+}
+
+pub fn arb_msg_conn_open_ack() -> impl Strategy<Value = MsgConnectionOpenAck> {
+    (
+        (
+            arb_connection_id(),
+            arb_connection_id(),
+            arb_mock_client_state(),
+            arb_connection_version(),
+        ),
+        (
+            arb_commitment_proof_bytes(),
+            arb_commitment_proof_bytes(),
+            arb_commitment_proof_bytes(),
+            proptest::option::of(arb_commitment_proof_bytes()),
+        ),
+        (arb_height(), arb_height(), arb_signer()),
+    )
+        .prop_map(
+            |(
+                (conn_id_on_a, conn_id_on_b, client_state_of_a_on_b, version),
+                (
+                    proof_conn_end_on_b,
+                    proof_client_state_of_a_on_b,
+                    proof_consensus_state_of_a_on_b,
+                    proof_consensus_state_of_a,
+                ),
+                (proofs_height_on_b, consensus_height_of_a_on_b, signer),
+            )| MsgConnectionOpenAck {
+                conn_id_on_a,
+                conn_id_on_b,
+                client_state_of_a_on_b,
+                proof_conn_end_on_b,
+                proof_client_state_of_a_on_b,
+                proof_consensus_state_of_a_on_b,
+                proofs_height_on_b,
+                consensus_height_of_a_on_b,
+                version,
+                signer,
+                proof_consensus_state_of_a,
+            },
+        )
+}
+
+pub fn arb_msg_conn_open_confirm() -> impl Strategy<Value = MsgConnectionOpenConfirm> {
+    (
+        arb_connection_id(),
+        arb_commitment_proof_bytes(),
+        arb_height(),
+        arb_signer(),
+    )
+        .prop_map(
+            |(conn_id_on_b, proof_conn_end_on_a, proof_height_on_a, signer)| {
+                MsgConnectionOpenConfirm {
+                    conn_id_on_b,
+                    proof_conn_end_on_a,
+                    proof_height_on_a,
+                    signer,
+                }
+            },
+        )
+}
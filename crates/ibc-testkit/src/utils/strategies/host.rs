@@ -0,0 +1,81 @@
+use ibc::clients::ics07_tendermint::client_type as tm_client_type;
+use ibc::core::ics02_client::client_type::ClientType;
+use ibc::core::ics04_channel::packet::Sequence;
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::timestamp::Timestamp;
+use ibc::prelude::*;
+use ibc::{Height, Signer};
+use proptest::prelude::*;
+
+use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+
+/// Generates a client type supported by the testkit.
+pub fn arb_client_type() -> impl Strategy<Value = ClientType> {
+    prop_oneof![Just(tm_client_type()), Just(mock_client_type())]
+}
+
+pub fn arb_client_id() -> impl Strategy<Value = ClientId> {
+    (arb_client_type(), any::<u64>()).prop_map(|(client_type, counter)| {
+        ClientId::new(client_type, counter).expect("Never fails")
+    })
+}
+
+pub fn arb_connection_id() -> impl Strategy<Value = ConnectionId> {
+    any::<u64>().prop_map(ConnectionId::new)
+}
+
+pub fn arb_channel_id() -> impl Strategy<Value = ChannelId> {
+    any::<u64>().prop_map(ChannelId::new)
+}
+
+pub fn arb_port_id() -> impl Strategy<Value = PortId> {
+    "[a-z][a-z0-9]{1,19}".prop_map(|id| PortId::new(id).expect("Never fails"))
+}
+
+/// Generates a chain identifier in the `{chain_name}-{revision_number}`
+/// format.
+pub fn arb_chain_id() -> impl Strategy<Value = ChainId> {
+    ("[a-z]{1,20}", any::<u64>()).prop_map(|(name, revision_number)| {
+        ChainId::new(&format!("{name}-{revision_number}")).expect("Never fails")
+    })
+}
+
+pub fn arb_height() -> impl Strategy<Value = Height> {
+    (any::<u64>(), 1..=u64::MAX).prop_map(|(revision_number, revision_height)| {
+        Height::new(revision_number, revision_height).expect("Never fails")
+    })
+}
+
+/// Generates a timestamp, which may be unset.
+pub fn arb_timestamp() -> impl Strategy<Value = Timestamp> {
+    any::<u64>().prop_map(|nanos| Timestamp::from_nanoseconds(nanos).expect("Never fails"))
+}
+
+pub fn arb_timeout_height() -> impl Strategy<Value = TimeoutHeight> {
+    prop_oneof![
+        Just(TimeoutHeight::Never),
+        arb_height().prop_map(TimeoutHeight::At),
+    ]
+}
+
+/// Generates a non-zero sequence number.
+pub fn arb_sequence() -> impl Strategy<Value = Sequence> {
+    (1..=u64::MAX).prop_map(Sequence::from)
+}
+
+pub fn arb_signer() -> impl Strategy<Value = Signer> {
+    "[a-z0-9]{1,64}".prop_map(Signer::from)
+}
+
+/// Generates opaque, non-empty proof bytes.
+pub fn arb_commitment_proof_bytes() -> impl Strategy<Value = CommitmentProofBytes> {
+    prop::collection::vec(any::<u8>(), 1..128)
+        .prop_map(|bytes| CommitmentProofBytes::try_from(bytes).expect("Never fails"))
+}
+
+pub fn arb_commitment_prefix() -> impl Strategy<Value = CommitmentPrefix> {
+    prop::collection::vec(any::<u8>(), 1..32)
+        .prop_map(|bytes| CommitmentPrefix::try_from(bytes).expect("Never fails"))
+}
@@ -0,0 +1,19 @@
+//! [`proptest`] strategies generating valid domain types, for fuzzing handlers
+//! and property-testing raw/domain conversions.
+//!
+//! Every generated value is accepted by the `TryFrom` conversion from its raw
+//! type, so that it round-trips through its protobuf encoding.
+
+mod channel;
+mod client;
+mod connection;
+mod host;
+#[cfg(feature = "serde")]
+mod transfer;
+
+pub use channel::*;
+pub use client::*;
+pub use connection::*;
+pub use host::*;
+#[cfg(feature = "serde")]
+pub use transfer::*;
@@ -0,0 +1,73 @@
+use ibc::applications::transfer::msgs::transfer::MsgTransfer;
+use ibc::applications::transfer::packet::PacketData;
+use ibc::applications::transfer::{Amount, Memo, PrefixedCoin, PrefixedDenom};
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::timestamp::Timestamp;
+use ibc::prelude::*;
+use proptest::prelude::*;
+
+use super::host::{arb_channel_id, arb_port_id, arb_signer, arb_timeout_height, arb_timestamp};
+
+/// Generates a denomination prefixed by a trace of up to two hops.
+pub fn arb_prefixed_denom() -> impl Strategy<Value = PrefixedDenom> {
+    (
+        prop::collection::vec((arb_port_id(), arb_channel_id()), 0..3),
+        "[a-z][a-z0-9]{2,15}",
+    )
+        .prop_map(|(trace, base_denom)| {
+            let denom = trace
+                .iter()
+                .map(|(port_id, chan_id)| format!("{port_id}/{chan_id}/"))
+                .collect::<String>()
+                + &base_denom;
+            denom.parse().expect("Never fails")
+        })
+}
+
+pub fn arb_prefixed_coin() -> impl Strategy<Value = PrefixedCoin> {
+    (arb_prefixed_denom(), any::<u64>()).prop_map(|(denom, amount)| PrefixedCoin {
+        denom,
+        amount: Amount::from(amount),
+    })
+}
+
+pub fn arb_memo() -> impl Strategy<Value = Memo> {
+    "[a-zA-Z0-9 ]{0,64}".prop_map(Memo::from)
+}
+
+pub fn arb_packet_data() -> impl Strategy<Value = PacketData> {
+    (arb_prefixed_coin(), arb_signer(), arb_signer(), arb_memo()).prop_map(
+        |(token, sender, receiver, memo)| PacketData {
+            token,
+            sender,
+            receiver,
+            memo,
+        },
+    )
+}
+
+/// Generates a `MsgTransfer` with at least one of its timeout height and
+/// timeout timestamp set.
+pub fn arb_msg_transfer() -> impl Strategy<Value = MsgTransfer> {
+    let timeouts = (arb_timeout_height(), arb_timestamp()).prop_filter(
+        "packet timeout height and timestamp cannot both be unset",
+        |(height, timestamp): &(TimeoutHeight, Timestamp)| height.is_set() || timestamp.is_set(),
+    );
+
+    (arb_port_id(), arb_channel_id(), arb_packet_data(), timeouts).prop_map(
+        |(
+            port_id_on_a,
+            chan_id_on_a,
+            packet_data,
+            (timeout_height_on_b, timeout_timestamp_on_b),
+        )| {
+            MsgTransfer {
+                port_id_on_a,
+                chan_id_on_a,
+                packet_data,
+                timeout_height_on_b,
+                timeout_timestamp_on_b,
+            }
+        },
+    )
+}
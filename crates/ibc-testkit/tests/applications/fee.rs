@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+
+use ibc::applications::fee::error::FeeError;
+use ibc::applications::fee::query::{
+    counterparty_payee, incentivized_packets, FeeQueryContext, IdentifiedPacketFees, PacketId,
+    PageRequest,
+};
+use ibc::core::ics04_channel::packet::Sequence;
+use ibc::core::ics24_host::identifier::{ChannelId, PortId};
+use ibc::prelude::*;
+use ibc::Signer;
+use test_log::test;
+
+/// A fee middleware whose packet fees are the amounts of their receive fees.
+#[derive(Default)]
+struct DummyFeeModule {
+    packet_fees: BTreeMap<PacketId, Vec<u64>>,
+    counterparty_payees: BTreeMap<(ChannelId, Signer), Signer>,
+}
+
+impl FeeQueryContext for DummyFeeModule {
+    type PacketFee = u64;
+
+    fn incentivized_packets_from(
+        &self,
+        start: Option<&PacketId>,
+        limit: usize,
+    ) -> Result<Vec<IdentifiedPacketFees<u64>>, FeeError> {
+        Ok(self
+            .packet_fees
+            .iter()
+            .filter(|(packet_id, _)| start.map_or(true, |start| *packet_id >= start))
+            .take(limit)
+            .map(|(packet_id, packet_fees)| IdentifiedPacketFees {
+                packet_id: packet_id.clone(),
+                packet_fees: packet_fees.clone(),
+            })
+            .collect())
+    }
+
+    fn get_counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, FeeError> {
+        Ok(self
+            .counterparty_payees
+            .get(&(channel_id.clone(), relayer.clone()))
+            .cloned())
+    }
+}
+
+fn packet_id(channel: u64, sequence: u64) -> PacketId {
+    PacketId {
+        port_id: PortId::transfer(),
+        channel_id: ChannelId::new(channel),
+        sequence: Sequence::from(sequence),
+    }
+}
+
+fn signer(s: &str) -> Signer {
+    Signer::from(s.to_string())
+}
+
+#[test]
+fn incentivized_packets_are_paginated() {
+    let mut ctx = DummyFeeModule::default();
+    for (channel, sequence) in [(0, 1), (0, 2), (1, 1), (1, 5), (2, 3)] {
+        ctx.packet_fees
+            .insert(packet_id(channel, sequence), vec![100 * sequence]);
+    }
+
+    let mut page = PageRequest {
+        key: None,
+        limit: 2,
+    };
+    let mut pages = Vec::new();
+    loop {
+        let response = incentivized_packets(&ctx, &page).unwrap();
+        pages.push(
+            response
+                .results
+                .into_iter()
+                .map(|packet| packet.packet_id)
+                .collect::<Vec<_>>(),
+        );
+        match response.next_key {
+            Some(key) => page.key = Some(key),
+            None => break,
+        }
+    }
+
+    assert_eq!(
+        pages,
+        vec![
+            vec![packet_id(0, 1), packet_id(0, 2)],
+            vec![packet_id(1, 1), packet_id(1, 5)],
+            vec![packet_id(2, 3)],
+        ]
+    );
+}
+
+#[test]
+fn incentivized_packets_default_limit() {
+    let ctx = DummyFeeModule {
+        packet_fees: (1..=150)
+            .map(|sequence| (packet_id(0, sequence), vec![sequence]))
+            .collect(),
+        ..Default::default()
+    };
+
+    let response = incentivized_packets(&ctx, &PageRequest::default()).unwrap();
+
+    assert_eq!(response.results.len(), 100);
+    assert_eq!(response.next_key, Some(packet_id(0, 101)));
+}
+
+#[test]
+fn counterparty_payee_lookup() {
+    let relayer = signer("cosmos1relayer");
+    let mut ctx = DummyFeeModule::default();
+    ctx.counterparty_payees
+        .insert((ChannelId::new(0), relayer.clone()), signer("osmo1payee"));
+
+    assert_eq!(
+        counterparty_payee(&ctx, &ChannelId::new(0), &relayer).unwrap(),
+        Some(signer("osmo1payee"))
+    );
+    assert_eq!(
+        counterparty_payee(&ctx, &ChannelId::new(1), &relayer).unwrap(),
+        None
+    );
+    assert!(matches!(
+        counterparty_payee(&ctx, &ChannelId::new(0), &signer("")),
+        Err(FeeError::EmptyRelayer)
+    ));
+}
@@ -0,0 +1,139 @@
+use core::time::Duration;
+
+use ibc::applications::interchain_accounts::controller::{
+    send_tx, send_tx_validate, InterchainAccountControllerContext,
+};
+use ibc::applications::interchain_accounts::error::InterchainAccountError;
+use ibc::applications::interchain_accounts::msgs::send_tx::MsgSendTx;
+use ibc::applications::interchain_accounts::packet::InterchainAccountPacketData;
+use ibc::core::events::IbcEvent;
+use ibc::core::ics02_client::height::Height;
+use ibc::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::ics04_channel::Version;
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::SeqSendPath;
+use ibc::core::timestamp::ZERO_DURATION;
+use ibc::core::ValidationContext;
+use ibc::prelude::*;
+use ibc::proto::Any;
+use ibc::Signer;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+const OWNER: &str = "cosmos1owner";
+
+/// A controller which only lets [`OWNER`] send transactions, over channel 0.
+struct DummyController;
+
+impl InterchainAccountControllerContext for DummyController {
+    fn active_channel_id(
+        &self,
+        _connection_id: &ConnectionId,
+        _port_id: &PortId,
+    ) -> Result<Option<ChannelId>, InterchainAccountError> {
+        Ok(Some(ChannelId::default()))
+    }
+
+    fn authorize_send_tx(&self, msg: &MsgSendTx) -> Result<(), InterchainAccountError> {
+        if msg.owner.as_ref() != OWNER {
+            return Err(InterchainAccountError::UnauthorizedSendTx {
+                owner: msg.owner.clone(),
+                reason: "unknown owner".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn port_id() -> PortId {
+    PortId::ica_controller(OWNER).unwrap()
+}
+
+fn ctx() -> MockContext {
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Ordered,
+        Counterparty::new(PortId::ica_host(), Some(ChannelId::default())),
+        vec![ConnectionId::default()],
+        Version::new("ics27-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    MockContext::default()
+        .with_client(&ClientId::default(), Height::new(0, 5).unwrap())
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_channel(port_id(), ChannelId::default(), chan_end_on_a)
+        .with_send_sequence(port_id(), ChannelId::default(), 1.into())
+}
+
+fn msg(owner: &str) -> MsgSendTx {
+    MsgSendTx {
+        owner: Signer::from(owner.to_string()),
+        connection_id: ConnectionId::default(),
+        packet_data: InterchainAccountPacketData::execute_tx(
+            vec![Any {
+                type_url: "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
+                value: vec![],
+            }],
+            String::new(),
+        ),
+        relative_timeout: Duration::from_secs(600),
+    }
+}
+
+#[test]
+fn test_send_tx() {
+    let mut ctx = ctx();
+
+    let sequence = send_tx(&mut ctx, &DummyController, msg(OWNER)).unwrap();
+    assert_eq!(sequence, 1.into());
+    assert_eq!(
+        ctx.get_next_sequence_send(&SeqSendPath::new(&port_id(), &ChannelId::default()))
+            .unwrap(),
+        2.into()
+    );
+
+    // The packet times out `relative_timeout` after the timestamp of the host.
+    let timeout_timestamp_on_b =
+        (ctx.host_timestamp().unwrap() + msg(OWNER).relative_timeout).unwrap();
+    assert!(ctx.events.iter().any(|event| matches!(
+        event,
+        IbcEvent::SendPacket(send_packet)
+            if *send_packet.timeout_timestamp_on_b() == timeout_timestamp_on_b
+    )));
+}
+
+#[test]
+fn test_send_tx_unauthorized() {
+    assert!(matches!(
+        send_tx_validate(&ctx(), &DummyController, msg("cosmos1other")),
+        Err(InterchainAccountError::UnauthorizedSendTx { .. })
+    ));
+}
+
+#[test]
+fn test_send_tx_zero_relative_timeout() {
+    let mut msg = msg(OWNER);
+    msg.relative_timeout = ZERO_DURATION;
+    assert!(matches!(
+        send_tx_validate(&ctx(), &DummyController, msg),
+        Err(InterchainAccountError::ZeroRelativeTimeout)
+    ));
+}
@@ -1 +1,3 @@
+pub mod fee;
+pub mod interchain_accounts;
 pub mod transfer;
@@ -1,14 +1,60 @@
+use core::cell::{Cell, RefCell};
+use core::convert::Infallible;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use ibc::applications::transfer::authorization::{
+    Allocation, TransferAuthorization, TransferAuthorizationContext,
+};
 use ibc::applications::transfer::context::{
-    cosmos_adr028_escrow_address, on_chan_open_init_execute, on_chan_open_init_validate,
-    on_chan_open_try_execute, on_chan_open_try_validate,
+    cosmos_adr028_escrow_address, on_acknowledgement_packet_execute,
+    on_acknowledgement_packet_validate, on_chan_close_confirm_execute, on_chan_close_init_execute,
+    on_chan_close_init_validate, on_chan_open_init_execute, on_chan_open_init_validate,
+    on_chan_open_try_execute, on_chan_open_try_validate, on_recv_packet_execute,
+    on_timeout_packet_execute, on_timeout_packet_validate, TokenTransferExecutionContext,
+    TokenTransferValidationContext,
+};
+use ibc::applications::transfer::error::TokenTransferError;
+use ibc::applications::transfer::msgs::transfer::MsgTransfer;
+use ibc::applications::transfer::packet_v2::{Hop, PacketDataV2};
+use ibc::applications::transfer::{
+    send_granted_transfer, send_transfer, send_transfer_validate, Amount, BaseCoin, PrefixedCoin,
+    PrefixedDenom, MAXIMUM_MEMO_LENGTH, VERSION, VERSION_V2,
 };
-use ibc::applications::transfer::VERSION;
-use ibc::core::ics04_channel::channel::{Counterparty, Order};
+use ibc::core::events::IbcEvent;
+use ibc::core::ics02_client::ClientExecutionContext;
+use ibc::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::ics04_channel::error::{ChannelCloseVeto, ChannelError, PacketError};
+use ibc::core::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+use ibc::core::ics04_channel::msgs::{ChannelMsg, MsgChannelCloseInit, PacketMsg};
+use ibc::core::ics04_channel::packet::{Packet, Sequence};
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
 use ibc::core::ics04_channel::Version;
-use ibc::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+use ibc::core::ics23_commitment::commitment::CommitmentProofBytes;
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::{ChannelEndPath, CommitmentPath};
+use ibc::core::router::{Module, ModuleExtras, ModuleId};
+use ibc::core::timestamp::ZERO_DURATION;
+use ibc::core::{dispatch, ExecutionContext, MsgEnvelope, ValidationContext};
 use ibc::prelude::*;
+use ibc::{
+    AccountResolver, AddressCodec, CachingAccountResolver, CodecAccountResolver, Height,
+    HexSignerValidator, Signer, SignerError, TryFromSignerResolver,
+};
 use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
-use subtle_encoding::bech32;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::utils::applications::transfer::{
+    extract_transfer_packet, MsgTransferConfig, PacketDataConfig,
+};
+use ibc_testkit::utils::core::signer::dummy_account_id;
+use primitive_types::U256;
+use subtle_encoding::{bech32, hex};
 
 fn get_defaults() -> (
     DummyTransferModule,
@@ -165,3 +211,1081 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+/// If the relayer or the counterparty propose ics20-2, then agree on it
+#[test]
+fn test_on_chan_open_v2_version() {
+    let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    let version_v2 = Version::new(VERSION_V2.to_string());
+
+    on_chan_open_init_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &version_v2,
+    )
+    .unwrap();
+    let (_, out_version) = on_chan_open_init_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &version_v2,
+    )
+    .unwrap();
+    assert_eq!(out_version, version_v2);
+
+    on_chan_open_try_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &version_v2,
+    )
+    .unwrap();
+    let (_, out_version) = on_chan_open_try_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &version_v2,
+    )
+    .unwrap();
+    assert_eq!(out_version, version_v2);
+}
+
+#[test]
+fn test_send_transfer_memo_too_long() {
+    let packet_data = PacketDataConfig::builder()
+        .token(
+            BaseCoin {
+                denom: "uatom".parse().unwrap(),
+                amount: U256::from(10).into(),
+            }
+            .into(),
+        )
+        .memo("x".repeat(MAXIMUM_MEMO_LENGTH + 1).into())
+        .build();
+    let msg = MsgTransferConfig::builder()
+        .packet_data(packet_data)
+        .build();
+
+    let res = send_transfer_validate(&MockContext::default(), &DummyTransferModule, msg);
+
+    assert!(matches!(
+        res,
+        Err(TokenTransferError::MemoTooLong {
+            length,
+            max_length: MAXIMUM_MEMO_LENGTH,
+        }) if length == MAXIMUM_MEMO_LENGTH + 1
+    ));
+}
+
+/// An account resolver counting the signers it parses.
+#[derive(Debug, Default)]
+struct CountingResolver {
+    calls: Cell<u32>,
+}
+
+impl AccountResolver for CountingResolver {
+    type AccountId = Signer;
+    type Error = Infallible;
+
+    fn resolve_account(&self, signer: &Signer) -> Result<Self::AccountId, Self::Error> {
+        self.calls.set(self.calls.get() + 1);
+        Ok(signer.clone())
+    }
+}
+
+/// A transfer module whose channels may be closed if `closable` is set,
+/// recording the coins it sends and keeping the transfer authorizations
+/// granted by its accounts.
+#[derive(Debug, Default)]
+struct RecordingTransferModule {
+    closable: bool,
+    sent_coins: Vec<(Signer, Signer, PrefixedCoin)>,
+    account_resolver: CachingAccountResolver<CountingResolver>,
+    authorizations: BTreeMap<(Signer, Signer), TransferAuthorization>,
+}
+
+impl TokenTransferValidationContext for RecordingTransferModule {
+    type AccountId = Signer;
+    type AccountResolver = CachingAccountResolver<CountingResolver>;
+
+    fn get_port(&self) -> Result<PortId, TokenTransferError> {
+        Ok(PortId::transfer())
+    }
+
+    fn account_resolver(&self) -> &Self::AccountResolver {
+        &self.account_resolver
+    }
+
+    fn get_escrow_account(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Self::AccountId, TokenTransferError> {
+        let addr = cosmos_adr028_escrow_address(port_id, channel_id);
+        Ok(bech32::encode("cosmos", addr).into())
+    }
+
+    fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn send_coins_validate(
+        &self,
+        _from_account: &Self::AccountId,
+        _to_account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn mint_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn allows_channel_closing(&self, _port_id: &PortId, _channel_id: &ChannelId) -> bool {
+        self.closable
+    }
+}
+
+impl TokenTransferExecutionContext for RecordingTransferModule {
+    fn send_coins_execute(
+        &mut self,
+        from_account: &Self::AccountId,
+        to_account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.sent_coins
+            .push((from_account.clone(), to_account.clone(), coin.clone()));
+        Ok(())
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+}
+
+impl TransferAuthorizationContext for RecordingTransferModule {
+    fn transfer_authorization(
+        &self,
+        granter: &Signer,
+        grantee: &Signer,
+    ) -> Result<Option<TransferAuthorization>, TokenTransferError> {
+        Ok(self
+            .authorizations
+            .get(&(granter.clone(), grantee.clone()))
+            .cloned())
+    }
+
+    fn store_transfer_authorization(
+        &mut self,
+        granter: &Signer,
+        grantee: &Signer,
+        authorization: TransferAuthorization,
+    ) -> Result<(), TokenTransferError> {
+        self.authorizations
+            .insert((granter.clone(), grantee.clone()), authorization);
+        Ok(())
+    }
+
+    fn delete_transfer_authorization(
+        &mut self,
+        granter: &Signer,
+        grantee: &Signer,
+    ) -> Result<(), TokenTransferError> {
+        self.authorizations
+            .remove(&(granter.clone(), grantee.clone()));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_on_chan_close_init_not_allowed_by_default() {
+    let (mut ctx, _, _, port_id, channel_id, _) = get_defaults();
+
+    let res = on_chan_close_init_validate(&ctx, &port_id, &channel_id);
+    assert!(matches!(res, Err(TokenTransferError::CantCloseChannel)));
+
+    let res = on_chan_close_init_execute(&mut ctx, &port_id, &channel_id);
+    assert!(matches!(res, Err(TokenTransferError::CantCloseChannel)));
+
+    // The refusal reaches core IBC as a veto of the closing.
+    assert!(matches!(
+        ChannelError::from(res.unwrap_err()),
+        ChannelError::CloseVetoed(ChannelCloseVeto::NotAllowed)
+    ));
+
+    assert!(on_chan_close_confirm_execute(&mut ctx, &port_id, &channel_id).is_ok());
+}
+
+#[test]
+fn test_on_chan_close_init_allowed_by_host_refunds_nothing() {
+    let mut ctx = RecordingTransferModule {
+        closable: true,
+        ..Default::default()
+    };
+    let (port_id, channel_id) = (PortId::transfer(), ChannelId::default());
+
+    on_chan_close_init_validate(&ctx, &port_id, &channel_id).unwrap();
+    on_chan_close_init_execute(&mut ctx, &port_id, &channel_id).unwrap();
+
+    // The packets in flight may still be received by the counterparty, and
+    // are only refunded once timed out on close.
+    assert!(ctx.sent_coins.is_empty());
+}
+
+#[test]
+fn test_on_timeout_packet_refunds_sender() {
+    let coin = uatom(10);
+    let msg = MsgTransferConfig::builder()
+        .packet_data(PacketDataConfig::builder().token(coin.clone()).build())
+        .build();
+    let packets: Vec<_> = [1, 2]
+        .into_iter()
+        .map(|seq| extract_transfer_packet(&msg, Sequence::from(seq)))
+        .collect();
+
+    let mut ctx = RecordingTransferModule::default();
+    let relayer = dummy_account_id();
+
+    for packet in &packets {
+        on_timeout_packet_validate(&ctx, packet, &relayer).unwrap();
+        let (extras, res) = on_timeout_packet_execute(&mut ctx, packet, &relayer);
+        res.unwrap();
+        assert_eq!(extras.events.len(), 1);
+    }
+
+    // The tokens, escrowed as they originate from the sender chain, are sent
+    // back to the sender.
+    let escrow = ctx
+        .get_escrow_account(&msg.port_id_on_a, &msg.chan_id_on_a)
+        .unwrap();
+    let refund = (escrow, msg.packet_data.sender.clone(), coin);
+    assert_eq!(ctx.sent_coins, vec![refund.clone(), refund]);
+
+    // The sender of both packets is parsed once, when validating the first
+    // refund, and found in the cache of the resolver afterwards.
+    assert_eq!(ctx.account_resolver.inner().calls.get(), 1);
+}
+
+/// An account of an EVM host, designated by its 20-byte address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct EvmAccount(Vec<u8>);
+
+impl From<Vec<u8>> for EvmAccount {
+    fn from(address: Vec<u8>) -> Self {
+        Self(address)
+    }
+}
+
+impl TryFrom<Signer> for EvmAccount {
+    type Error = SignerError;
+
+    fn try_from(signer: Signer) -> Result<Self, Self::Error> {
+        HexSignerValidator::new(20)
+            .decode_address(&signer)
+            .map(Self)
+    }
+}
+
+/// A transfer module of an EVM host, whose accounts are designated by their
+/// 20-byte hex addresses, recording the coins it mints.
+#[derive(Debug)]
+struct EvmTransferModule {
+    account_resolver: CodecAccountResolver<HexSignerValidator, EvmAccount>,
+    minted_coins: Vec<(EvmAccount, PrefixedCoin)>,
+}
+
+impl EvmTransferModule {
+    fn new() -> Self {
+        Self {
+            account_resolver: CodecAccountResolver::new(HexSignerValidator::new(20)),
+            minted_coins: Vec::new(),
+        }
+    }
+}
+
+impl TokenTransferValidationContext for EvmTransferModule {
+    type AccountId = EvmAccount;
+    type AccountResolver = CodecAccountResolver<HexSignerValidator, EvmAccount>;
+
+    fn get_port(&self) -> Result<PortId, TokenTransferError> {
+        Ok(PortId::transfer())
+    }
+
+    fn account_resolver(&self) -> &Self::AccountResolver {
+        &self.account_resolver
+    }
+
+    fn get_escrow_account(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Self::AccountId, TokenTransferError> {
+        let addr = cosmos_adr028_escrow_address(port_id, channel_id);
+        Ok(EvmAccount(addr[..20].to_vec()))
+    }
+
+    fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn send_coins_validate(
+        &self,
+        _from_account: &Self::AccountId,
+        _to_account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn mint_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+}
+
+impl TokenTransferExecutionContext for EvmTransferModule {
+    fn send_coins_execute(
+        &mut self,
+        _from_account: &Self::AccountId,
+        _to_account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.minted_coins.push((account.clone(), coin.clone()));
+        Ok(())
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+}
+
+fn transfer_packet_to(receiver: &str) -> Packet {
+    let packet_data = PacketDataConfig::builder()
+        .token(
+            BaseCoin {
+                denom: "uatom".parse().unwrap(),
+                amount: U256::from(10).into(),
+            }
+            .into(),
+        )
+        .receiver(receiver.to_string().into())
+        .build();
+    let msg = MsgTransferConfig::builder()
+        .packet_data(packet_data)
+        .build();
+
+    extract_transfer_packet(&msg, Sequence::from(1))
+}
+
+#[test]
+fn test_on_recv_packet_hex_receiver() {
+    let receiver = "0x52908400098527886E0F7030069857D2E4169EE7";
+    let mut ctx = EvmTransferModule::new();
+
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &transfer_packet_to(receiver));
+
+    let ack = AcknowledgementStatus::try_from(&ack).unwrap();
+    assert!(ack.is_successful(), "{ack:?}");
+    assert_eq!(ctx.minted_coins.len(), 1);
+    assert_eq!(
+        ctx.minted_coins[0].0,
+        EvmAccount(hex::decode("52908400098527886e0f7030069857d2e4169ee7").unwrap())
+    );
+}
+
+#[test]
+fn test_on_recv_packet_malformed_receiver() {
+    let mut ctx = EvmTransferModule::new();
+
+    let (_, ack) = on_recv_packet_execute(
+        &mut ctx,
+        &transfer_packet_to("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"),
+    );
+
+    let ack = AcknowledgementStatus::try_from(&ack).unwrap();
+    assert!(!ack.is_successful(), "{ack:?}");
+    assert!(ctx.minted_coins.is_empty());
+}
+
+fn uatom(amount: u64) -> PrefixedCoin {
+    BaseCoin {
+        denom: "uatom".parse().unwrap(),
+        amount: U256::from(amount).into(),
+    }
+    .into()
+}
+
+/// A host with an open transfer channel on which packets can be sent.
+fn host_with_transfer_channel() -> MockContext {
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::default())),
+        vec![ConnectionId::default()],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap();
+
+    MockContext::default()
+        .with_client(&ClientId::default(), Height::new(0, 5).unwrap())
+        .with_connection(ConnectionId::default(), conn_end)
+        .with_channel(PortId::transfer(), ChannelId::default(), chan_end)
+        .with_send_sequence(PortId::transfer(), ChannelId::default(), 1.into())
+}
+
+/// A transfer module in which the sender of the dummy transfers granted
+/// `grantee` the right to transfer up to `spend_limit` uatom on their behalf.
+fn module_with_grant(grantee: &Signer, spend_limit: u64) -> RecordingTransferModule {
+    let allocation = Allocation {
+        port_id: PortId::transfer(),
+        chan_id: ChannelId::default(),
+        spend_limit: vec![uatom(spend_limit)],
+        allow_list: vec![],
+    };
+
+    let mut module = RecordingTransferModule::default();
+    module.authorizations.insert(
+        (dummy_account_id(), grantee.clone()),
+        TransferAuthorization::new(vec![allocation]),
+    );
+    module
+}
+
+fn granted_transfer_msg(amount: u64) -> MsgTransfer {
+    MsgTransferConfig::builder()
+        .packet_data(PacketDataConfig::builder().token(uatom(amount)).build())
+        .timeout_height_on_b(TimeoutHeight::At(Height::new(0, 35).unwrap()))
+        .build()
+}
+
+#[test]
+fn test_send_granted_transfer_within_spend_limit() {
+    let grantee = Signer::from("cosmos1grantee".to_string());
+    let mut ctx = host_with_transfer_channel();
+    let mut module = module_with_grant(&grantee, 10);
+
+    send_granted_transfer(&mut ctx, &mut module, &grantee, granted_transfer_msg(4)).unwrap();
+
+    // The tokens are escrowed, and the packet committed, as for any transfer.
+    let escrow = module
+        .get_escrow_account(&PortId::transfer(), &ChannelId::default())
+        .unwrap();
+    assert_eq!(
+        module.sent_coins,
+        vec![(dummy_account_id(), escrow, uatom(4))]
+    );
+    assert!(ctx
+        .get_packet_commitment(&CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::default(),
+            1.into()
+        ))
+        .is_ok());
+
+    // The transfer is deducted from the authorization, whose remainder
+    // allows a transfer of the remaining tokens and uses it up.
+    let authorization = module
+        .transfer_authorization(&dummy_account_id(), &grantee)
+        .unwrap()
+        .unwrap();
+    assert_eq!(authorization.allocations[0].spend_limit, vec![uatom(6)]);
+
+    send_granted_transfer(&mut ctx, &mut module, &grantee, granted_transfer_msg(6)).unwrap();
+    assert_eq!(
+        module
+            .transfer_authorization(&dummy_account_id(), &grantee)
+            .unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_send_granted_transfer_exceeding_spend_limit() {
+    let grantee = Signer::from("cosmos1grantee".to_string());
+    let mut ctx = host_with_transfer_channel();
+    let mut module = module_with_grant(&grantee, 10);
+    let authorization = module
+        .transfer_authorization(&dummy_account_id(), &grantee)
+        .unwrap();
+
+    let res = send_granted_transfer(&mut ctx, &mut module, &grantee, granted_transfer_msg(11));
+    assert!(
+        matches!(res, Err(TokenTransferError::SpendLimitExceeded { .. })),
+        "{res:?}"
+    );
+
+    // Nothing is sent and the authorization is left as it was.
+    assert!(module.sent_coins.is_empty());
+    assert_eq!(
+        module
+            .transfer_authorization(&dummy_account_id(), &grantee)
+            .unwrap(),
+        authorization
+    );
+
+    // A grantee without any authorization cannot transfer at all.
+    let stranger = Signer::from("cosmos1stranger".to_string());
+    let res = send_granted_transfer(&mut ctx, &mut module, &stranger, granted_transfer_msg(1));
+    assert!(
+        matches!(
+            res,
+            Err(TokenTransferError::MissingTransferAuthorization { .. })
+        ),
+        "{res:?}"
+    );
+}
+
+/// A [`RecordingTransferModule`] bound to the transfer port of a router, and
+/// shared with the test to look at the coins it sent.
+#[derive(Clone, Debug, Default)]
+struct RoutedTransferModule(Rc<RefCell<RecordingTransferModule>>);
+
+impl Module for RoutedTransferModule {
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        on_chan_open_init_validate(
+            &*self.0.borrow(),
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )?;
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok(on_chan_open_init_execute(
+            &mut *self.0.borrow_mut(),
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )?)
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        on_chan_open_try_validate(
+            &*self.0.borrow(),
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )?;
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok(on_chan_open_try_execute(
+            &mut *self.0.borrow_mut(),
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Ok(on_chan_close_init_validate(
+            &*self.0.borrow(),
+            port_id,
+            channel_id,
+        )?)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(on_chan_close_init_execute(
+            &mut *self.0.borrow_mut(),
+            port_id,
+            channel_id,
+        )?)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        on_recv_packet_execute(&mut *self.0.borrow_mut(), packet)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        on_acknowledgement_packet_validate(&*self.0.borrow(), packet, acknowledgement, relayer)
+            .map_err(|e| PacketError::AppModule {
+                description: e.to_string(),
+            })
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, res) = on_acknowledgement_packet_execute(
+            &mut *self.0.borrow_mut(),
+            packet,
+            acknowledgement,
+            relayer,
+        );
+        let res = res.map_err(|e| PacketError::AppModule {
+            description: e.to_string(),
+        });
+        (extras, res)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        on_timeout_packet_validate(&*self.0.borrow(), packet, relayer).map_err(|e| {
+            PacketError::AppModule {
+                description: e.to_string(),
+            }
+        })
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, res) = on_timeout_packet_execute(&mut *self.0.borrow_mut(), packet, relayer);
+        let res = res.map_err(|e| PacketError::AppModule {
+            description: e.to_string(),
+        });
+        (extras, res)
+    }
+}
+
+/// Closes a transfer channel on which a transfer is in flight, whose tokens
+/// stay escrowed until the counterparty proves it did not receive the packet,
+/// upon which they are refunded to the sender.
+#[test]
+fn test_close_channel_then_refund_transfer_in_flight_on_timeout_on_close() {
+    let client_id = ClientId::default();
+    let proof_height = Height::new(0, 5).unwrap();
+    let mut ctx = host_with_transfer_channel();
+
+    let module = RoutedTransferModule::default();
+    module.0.borrow_mut().closable = true;
+    let module_id = ModuleId::new("transfer".to_string());
+    let mut router = MockRouter::default();
+    router.add_route(module_id.clone(), module.clone()).unwrap();
+    router
+        .scope_port_to_module(PortId::transfer(), module_id)
+        .unwrap();
+
+    let msg = MsgTransferConfig::builder()
+        .packet_data(PacketDataConfig::builder().token(uatom(10)).build())
+        .timeout_height_on_b(TimeoutHeight::At(Height::new(0, 35).unwrap()))
+        .build();
+    let sender = msg.packet_data.sender.clone();
+    let packet = extract_transfer_packet(&msg, 1.into());
+    send_transfer(&mut ctx, &mut *module.0.borrow_mut(), msg).unwrap();
+
+    let escrow = module
+        .0
+        .borrow()
+        .get_escrow_account(&PortId::transfer(), &ChannelId::default())
+        .unwrap();
+    let escrowed = (sender.clone(), escrow.clone(), uatom(10));
+    assert_eq!(module.0.borrow().sent_coins, vec![escrowed.clone()]);
+
+    // Closing the channel leaves the tokens escrowed, as the counterparty may
+    // still have received the packet.
+    let msg = MsgChannelCloseInit {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::default(),
+        signer: dummy_account_id(),
+    };
+    dispatch(
+        &mut ctx,
+        &mut router,
+        MsgEnvelope::from(ChannelMsg::from(msg)),
+    )
+    .unwrap();
+
+    let chan_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::default());
+    assert_eq!(
+        ctx.channel_end(&chan_end_path).unwrap().state,
+        State::Closed
+    );
+    assert_eq!(module.0.borrow().sent_coins, vec![escrowed.clone()]);
+
+    // The counterparty closed its end without receiving the packet.
+    let host_timestamp = ctx.host_timestamp().unwrap();
+    let client_ctx = ctx.get_client_execution_context();
+    client_ctx
+        .store_update_time(client_id.clone(), proof_height, host_timestamp)
+        .unwrap();
+    client_ctx
+        .store_update_height(client_id, proof_height, proof_height)
+        .unwrap();
+
+    let proof = || CommitmentProofBytes::try_from(vec![1]).unwrap();
+    let msg = MsgTimeoutOnClose {
+        packet: packet.clone(),
+        next_seq_recv_on_b: packet.seq_on_a,
+        proof_unreceived_on_b: proof(),
+        proof_close_on_b: proof(),
+        proof_height_on_b: proof_height,
+        signer: dummy_account_id(),
+    };
+    dispatch(
+        &mut ctx,
+        &mut router,
+        MsgEnvelope::from(PacketMsg::from(msg)),
+    )
+    .unwrap();
+
+    // The packet is settled, and its tokens released from escrow.
+    assert!(ctx
+        .get_packet_commitment(&CommitmentPath::new(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a
+        ))
+        .is_err());
+    assert_eq!(
+        module.0.borrow().sent_coins,
+        vec![escrowed, (escrow, sender, uatom(10))]
+    );
+    assert!(ctx
+        .events
+        .iter()
+        .any(|event| matches!(event, IbcEvent::TimeoutPacket(event) if event.is_on_close())));
+}
+
+/// A transfer module of a host whose transfer channels are of version 2,
+/// releasing the tokens it escrowed and recording the coins it credits.
+#[derive(Debug)]
+struct EscrowTransferModule {
+    account_resolver: TryFromSignerResolver<Signer>,
+    escrowed: BTreeMap<PrefixedDenom, Amount>,
+    received_coins: Vec<PrefixedCoin>,
+}
+
+impl EscrowTransferModule {
+    fn new(escrowed: Vec<PrefixedCoin>) -> Self {
+        Self {
+            account_resolver: TryFromSignerResolver::new(),
+            escrowed: escrowed
+                .into_iter()
+                .map(|coin| (coin.denom, coin.amount))
+                .collect(),
+            received_coins: Vec::new(),
+        }
+    }
+}
+
+impl TokenTransferValidationContext for EscrowTransferModule {
+    type AccountId = Signer;
+    type AccountResolver = TryFromSignerResolver<Signer>;
+
+    fn get_port(&self) -> Result<PortId, TokenTransferError> {
+        Ok(PortId::transfer())
+    }
+
+    fn account_resolver(&self) -> &Self::AccountResolver {
+        &self.account_resolver
+    }
+
+    fn get_escrow_account(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Self::AccountId, TokenTransferError> {
+        let addr = cosmos_adr028_escrow_address(port_id, channel_id);
+        Ok(bech32::encode("cosmos", addr).into())
+    }
+
+    fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn send_coins_validate(
+        &self,
+        _from_account: &Self::AccountId,
+        _to_account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        match self.escrowed.get(&coin.denom) {
+            Some(escrowed) if *escrowed >= coin.amount => Ok(()),
+            _ => Err(TokenTransferError::InvalidToken),
+        }
+    }
+
+    fn mint_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn channel_version(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<Version, TokenTransferError> {
+        Ok(Version::new(VERSION_V2.to_string()))
+    }
+}
+
+impl TokenTransferExecutionContext for EscrowTransferModule {
+    fn send_coins_execute(
+        &mut self,
+        _from_account: &Self::AccountId,
+        _to_account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.received_coins.push(coin.clone());
+        Ok(())
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.received_coins.push(coin.clone());
+        Ok(())
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+}
+
+fn coin(denom: &str, amount: u64) -> PrefixedCoin {
+    PrefixedCoin {
+        denom: denom.parse().unwrap(),
+        amount: U256::from(amount).into(),
+    }
+}
+
+/// A version 2 packet returning two amounts of uatom, escrowed on the
+/// receiving host, and sending uosmo, native to the sending one.
+fn multi_token_packet() -> (Packet, PacketDataV2) {
+    let msg = MsgTransferConfig::builder()
+        .packet_data(PacketDataConfig::builder().token(uatom(1)).build())
+        .build();
+
+    let mut data = PacketDataV2::from(msg.packet_data.clone());
+    data.tokens = vec![
+        coin("transfer/channel-0/uatom", 10),
+        coin("uosmo", 20),
+        coin("transfer/channel-0/uatom", 5),
+    ];
+
+    let mut packet = extract_transfer_packet(&msg, Sequence::from(1));
+    packet.data = data.to_json();
+
+    (packet, data)
+}
+
+#[test]
+fn test_on_recv_packet_multi_token() {
+    let (packet, _) = multi_token_packet();
+    let mut ctx = EscrowTransferModule::new(vec![uatom(15)]);
+
+    let (extras, ack) = on_recv_packet_execute(&mut ctx, &packet);
+
+    let ack = AcknowledgementStatus::try_from(&ack).unwrap();
+    assert!(ack.is_successful(), "{ack:?}");
+    assert_eq!(
+        ctx.received_coins,
+        vec![uatom(15), coin("transfer/channel-0/uosmo", 20)]
+    );
+    // A denomination trace for the vouchers, and a receipt event per token.
+    assert_eq!(extras.events.len(), 4);
+}
+
+#[test]
+fn test_on_recv_packet_multi_token_overdraw() {
+    let (packet, _) = multi_token_packet();
+    // Each amount of uatom is escrowed, but not both of them.
+    let mut ctx = EscrowTransferModule::new(vec![uatom(12)]);
+
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+
+    let ack = AcknowledgementStatus::try_from(&ack).unwrap();
+    assert!(!ack.is_successful(), "{ack:?}");
+    assert!(ctx.received_coins.is_empty());
+}
+
+#[test]
+fn test_on_recv_packet_v2_rejects_forwarding_and_v1_packets() {
+    let (mut packet, mut data) = multi_token_packet();
+    let mut ctx = EscrowTransferModule::new(vec![uatom(15)]);
+
+    data.forwarding.hops.push(Hop {
+        port_id: PortId::transfer(),
+        channel_id: ChannelId::new(1),
+    });
+    packet.data = data.to_json();
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+    assert!(!AcknowledgementStatus::try_from(&ack)
+        .unwrap()
+        .is_successful());
+
+    // The channel of the host is of version 2, on which version 1 packets
+    // cannot be received.
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &transfer_packet_to("cosmos1receiver"));
+    assert!(!AcknowledgementStatus::try_from(&ack)
+        .unwrap()
+        .is_successful());
+
+    assert!(ctx.received_coins.is_empty());
+}
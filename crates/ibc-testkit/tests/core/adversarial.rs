@@ -0,0 +1,740 @@
+use core::ops::{Add, Sub};
+use core::time::Duration;
+use std::sync::Arc;
+
+use ibc::clients::ics07_tendermint::client_type as tm_client_type;
+use ibc::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc::core::events::IbcEvent;
+use ibc::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation, Status};
+use ibc::core::ics02_client::error::ClientError;
+use ibc::core::ics02_client::msgs::misbehaviour::MsgSubmitMisbehaviour;
+use ibc::core::ics02_client::msgs::update_client::MsgUpdateClient;
+use ibc::core::ics02_client::msgs::ClientMsg;
+use ibc::core::ics02_client::ClientExecutionContext;
+use ibc::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::acknowledgement::Acknowledgement;
+use ibc::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::ics04_channel::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc::core::ics04_channel::error::{ChannelError, PacketError};
+use ibc::core::ics04_channel::msgs::recv_packet::{MsgRecvPacket, RecvOutcome};
+use ibc::core::ics04_channel::msgs::timeout::MsgTimeout;
+use ibc::core::ics04_channel::msgs::PacketMsg;
+use ibc::core::ics04_channel::packet::{Packet, Receipt};
+use ibc::core::ics04_channel::receipt_bitmap::{
+    ReceiptBitmap, ReceiptBitmapContext, ReceiptBitmapProof,
+};
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::ics04_channel::Version;
+use ibc::core::ics23_commitment::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc::core::ics23_commitment::error::CommitmentError;
+use ibc::core::ics23_commitment::merkle::ProofLimits;
+use ibc::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::{
+    AckPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path, ReceiptBitmapPath,
+    ReceiptPath,
+};
+use ibc::core::router::Router;
+use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
+use ibc::core::{
+    execute, recv_packet_execute, validate, ContextError, ExecutionContext, MsgEnvelope,
+    RouterError, ValidationContext,
+};
+use ibc::prelude::*;
+use ibc::proto::Any;
+use ibc::Height;
+use ibc_testkit::hosts::block::{HostBlock, HostType};
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{FixedClock, MockClientConfig, MockContext};
+use ibc_testkit::utils::adversarial::{
+    expired_tm_client_config, forked_tm_misbehaviour, tampered_ack, tampered_proof,
+    with_received_packet, wrong_path_proof,
+};
+use ibc_testkit::utils::clients::tendermint::{
+    dummy_tendermint_header, dummy_tm_client_state_from_header,
+};
+use ibc_testkit::utils::core::channel::{dummy_raw_msg_recv_packet, dummy_raw_msg_timeout};
+use ibc_testkit::utils::core::signer::dummy_account_id;
+use prost::Message;
+use rstest::rstest;
+use test_log::test;
+
+/// The fixed time of the hosts of these tests, so that they run the same way
+/// every time.
+fn now() -> Timestamp {
+    Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap()
+}
+
+fn host_with_fixed_clock(latest_height: Height) -> MockContext {
+    MockContext::new_with_clock(
+        ChainId::new(&format!("mockgaiaA-{}", latest_height.revision_number())).unwrap(),
+        HostType::Mock,
+        5,
+        latest_height,
+        Arc::new(FixedClock(now())),
+    )
+}
+
+/// A host whose Tendermint client `client_id` of `chain_id_b`, at
+/// `client_height`, was frozen by evidence of a fork submitted to it.
+fn host_with_frozen_tm_client(
+    client_id: &ClientId,
+    chain_id_b: &ChainId,
+    client_height: Height,
+) -> MockContext {
+    let mut ctx = host_with_fixed_clock(Height::new(1, 1).unwrap()).with_client_config(
+        MockClientConfig::builder()
+            .client_chain_id(chain_id_b.clone())
+            .client_id(client_id.clone())
+            .client_type(tm_client_type())
+            .client_state_height(client_height)
+            .latest_timestamp(now().sub(Duration::from_secs(30)).unwrap())
+            .build(),
+    );
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgSubmitMisbehaviour {
+        client_id: client_id.clone(),
+        misbehaviour: forked_tm_misbehaviour(
+            client_id.clone(),
+            chain_id_b.clone(),
+            client_height.increment(),
+            client_height,
+            now(),
+        )
+        .into(),
+        signer: dummy_account_id(),
+    }));
+
+    validate(&ctx, &router, msg_envelope.clone()).unwrap();
+    execute(&mut ctx, &mut router, msg_envelope).unwrap();
+
+    ctx
+}
+
+#[test]
+fn forked_misbehaviour_freezes_client() {
+    let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+    let client_height = Height::new(1, 20).unwrap();
+
+    let ctx = host_with_frozen_tm_client(&client_id, &chain_id_b, client_height);
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    assert_eq!(
+        client_state.status(&ctx, &client_id).unwrap(),
+        Status::Frozen
+    );
+}
+
+#[test]
+fn frozen_client_rejects_updates_and_proofs() {
+    let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+    let client_height = Height::new(1, 20).unwrap();
+
+    let ctx = host_with_frozen_tm_client(&client_id, &chain_id_b, client_height);
+    let router = MockRouter::new_with_transfer();
+
+    // A header the client accepted before being frozen.
+    let header = {
+        let mut tm_block = HostBlock::generate_tm_block(
+            chain_id_b,
+            client_height.increment().revision_height(),
+            now(),
+        );
+        tm_block.trusted_height = client_height;
+        tm_block
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: HostBlock::SyntheticTendermint(Box::new(header)).into(),
+        signer: dummy_account_id(),
+    }));
+
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ClientError(
+                ClientError::ClientNotActive {
+                    status: Status::Frozen
+                }
+            )))
+        ),
+        "frozen clients reject updates: {res:?}"
+    );
+
+    // A packet whose commitment on the frozen chain is proven to the host.
+    let mut msg =
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(client_height.revision_height()))
+            .unwrap();
+    msg.packet.timeout_height_on_b = TimeoutHeight::Never;
+    msg.packet.timeout_timestamp_on_b = now().add(Duration::from_secs(60)).unwrap();
+    let packet = msg.packet.clone();
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::default()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let ctx = ctx
+        .with_connection(ConnectionId::default(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        );
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(PacketMsg::from(msg)));
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ClientError(
+                ClientError::ClientNotActive {
+                    status: Status::Frozen
+                }
+            )))
+        ),
+        "frozen clients reject membership proofs: {res:?}"
+    );
+}
+
+#[test]
+fn expired_client_rejects_update() {
+    let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+    let client_height = Height::new(1, 20).unwrap();
+
+    let ctx = host_with_fixed_clock(Height::new(1, 1).unwrap()).with_client_config(
+        expired_tm_client_config(client_id.clone(), chain_id_b.clone(), client_height, now()),
+    );
+    let router = MockRouter::new_with_transfer();
+
+    let header = {
+        let mut tm_block = HostBlock::generate_tm_block(
+            chain_id_b,
+            client_height.increment().revision_height(),
+            now(),
+        );
+        tm_block.trusted_height = client_height;
+        tm_block
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id,
+        client_message: HostBlock::SyntheticTendermint(Box::new(header)).into(),
+        signer: dummy_account_id(),
+    }));
+
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ClientError(
+                ClientError::ClientNotActive {
+                    status: Status::Expired
+                }
+            )))
+        ),
+        "expired clients reject updates: {res:?}"
+    );
+}
+
+#[rstest]
+#[case::unordered(Order::Unordered)]
+#[case::ordered(Order::Ordered)]
+#[test_log::test]
+fn replayed_packet_is_noop(#[case] ordering: Order) {
+    let host_height = Height::new(0, 6).unwrap();
+    let client_height = host_height.increment();
+
+    let mut msg =
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(client_height.revision_height()))
+            .unwrap();
+    msg.packet.timeout_timestamp_on_b = now().add(Duration::from_secs(60)).unwrap();
+    let packet = msg.packet.clone();
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        ordering,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::default()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let ctx = host_with_fixed_clock(Height::new(0, 5).unwrap())
+        .with_client(&ClientId::default(), client_height)
+        .with_connection(ConnectionId::default(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+    let mut ctx = with_received_packet(ctx, &packet, ordering);
+    ctx.get_client_execution_context()
+        .store_update_time(ClientId::default(), client_height, now())
+        .unwrap();
+    ctx.get_client_execution_context()
+        .store_update_height(ClientId::default(), client_height, host_height)
+        .unwrap();
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg.clone()));
+    validate(&ctx, &router, msg_envelope).unwrap();
+
+    let module_id = router.lookup_module(&packet.port_id_on_b).unwrap();
+    let module = router.get_route_mut(&module_id).unwrap();
+    let outcome = recv_packet_execute(&mut ctx, module, msg).unwrap();
+
+    assert_eq!(outcome, RecvOutcome::AlreadyReceived);
+    assert!(!ctx
+        .events
+        .iter()
+        .any(|event| matches!(event, IbcEvent::WriteAcknowledgement(_))));
+}
+
+#[test]
+fn wrong_path_and_tampered_proofs_fail_verification() {
+    let client_id = ClientId::default();
+    let ctx = host_with_fixed_clock(Height::new(0, 5).unwrap())
+        .with_client(&client_id, Height::new(0, 2).unwrap());
+    let tm_client_state = dummy_tm_client_state_from_header(dummy_tendermint_header());
+
+    let prefix = ctx.commitment_prefix();
+    let root = ctx.commitment_root();
+    let client_state_path = Path::ClientState(ClientStatePath::new(&client_id));
+    let client_state_value = Any::from(ctx.client_state(&client_id).unwrap()).encode_to_vec();
+
+    let proof = wrong_path_proof(&ctx, &client_state_path);
+    tm_client_state
+        .verify_membership(
+            &prefix,
+            &proof,
+            &root,
+            client_state_path.clone(),
+            client_state_value.clone(),
+        )
+        .expect("the proof is genuine for the path it was generated for");
+
+    let res = tm_client_state.verify_membership(
+        &prefix,
+        &proof,
+        &root,
+        Path::Connection(ConnectionPath::new(&ConnectionId::default())),
+        client_state_value.clone(),
+    );
+    assert!(matches!(res, Err(ClientError::Ics23Verification(_))));
+
+    let res = tm_client_state.verify_membership(
+        &prefix,
+        &tampered_proof(&proof),
+        &root,
+        client_state_path,
+        client_state_value,
+    );
+    assert!(matches!(res, Err(ClientError::Ics23Verification(_))));
+}
+
+#[test]
+fn tampered_ack_fails_verification() {
+    let mut ctx = host_with_fixed_clock(Height::new(0, 5).unwrap());
+    let tm_client_state = dummy_tm_client_state_from_header(dummy_tendermint_header());
+
+    let ack = Acknowledgement::try_from(b"ack".to_vec()).unwrap();
+    let ack_path = AckPath::new(&PortId::transfer(), &ChannelId::default(), 1.into());
+    ctx.store_packet_acknowledgement(&ack_path, compute_ack_commitment(&ack))
+        .unwrap();
+
+    let proof = ctx.membership_proof(&ack_path.clone().into()).unwrap();
+    let verify = |ack: &Acknowledgement| {
+        tm_client_state.verify_membership(
+            &ctx.commitment_prefix(),
+            &proof,
+            &ctx.commitment_root(),
+            ack_path.clone().into(),
+            compute_ack_commitment(ack).into_vec(),
+        )
+    };
+
+    verify(&ack).expect("the acknowledgement written by the chain verifies");
+    assert!(matches!(
+        verify(&tampered_ack(&ack)),
+        Err(ClientError::Ics23Verification(_))
+    ));
+}
+
+#[test]
+fn proofs_beyond_the_client_limits_are_rejected() {
+    let mut ctx = host_with_fixed_clock(Height::new(0, 5).unwrap());
+    let tm_client_state = dummy_tm_client_state_from_header(dummy_tendermint_header());
+
+    let ack = Acknowledgement::try_from(b"ack".to_vec()).unwrap();
+    let ack_path = AckPath::new(&PortId::transfer(), &ChannelId::default(), 1.into());
+    ctx.store_packet_acknowledgement(&ack_path, compute_ack_commitment(&ack))
+        .unwrap();
+
+    let proof = ctx.membership_proof(&ack_path.clone().into()).unwrap();
+    let verify = |limits: ProofLimits| {
+        tm_client_state
+            .clone()
+            .with_proof_limits(limits)
+            .verify_membership(
+                &ctx.commitment_prefix(),
+                &proof,
+                &ctx.commitment_root(),
+                ack_path.clone().into(),
+                compute_ack_commitment(&ack).into_vec(),
+            )
+    };
+
+    verify(ProofLimits::default()).expect("the proof is within the default limits");
+    let res = verify(ProofLimits {
+        max_proof_size: Vec::<u8>::from(proof.clone()).len() - 1,
+        ..Default::default()
+    });
+    assert!(matches!(
+        res,
+        Err(ClientError::InvalidCommitmentProof(
+            CommitmentError::ProofTooLarge { .. }
+        ))
+    ));
+    let res = verify(ProofLimits {
+        max_ops: 1,
+        ..Default::default()
+    });
+    assert!(matches!(
+        res,
+        Err(ClientError::InvalidCommitmentProof(
+            CommitmentError::TooManyProofOps { .. }
+        ))
+    ));
+}
+
+/// Returns a host recording the receipts of the channel `packet` is sent on
+/// in bitmaps.
+fn bitmap_host_of(packet: &Packet) -> MockContext {
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::default()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    MockContext::default()
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_receipt_bitmap(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone())
+}
+
+/// Validates the timeout of `packet` on a host whose Tendermint client of the
+/// bitmap host B sees `root_on_b` at the proof height of the message.
+fn time_out_on_bitmap_channel(
+    packet: &Packet,
+    prefix_on_b: CommitmentPrefix,
+    root_on_b: CommitmentRoot,
+    proof_unreceived_on_b: CommitmentProofBytes,
+) -> Result<(), RouterError> {
+    let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let client_height = Height::new(1, 20).unwrap();
+    let router = MockRouter::new_with_transfer();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            prefix_on_b,
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(
+            packet.port_id_on_b.clone(),
+            Some(packet.chan_id_on_b.clone()),
+        ),
+        vec![ConnectionId::default()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let mut ctx_a = host_with_fixed_clock(Height::new(1, 1).unwrap())
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .client_state_height(client_height)
+                .latest_timestamp(now())
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_channel(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            chan_end_on_a,
+        )
+        .with_packet_commitment(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+            compute_packet_commitment(
+                &packet.data,
+                &packet.timeout_height_on_b,
+                &packet.timeout_timestamp_on_b,
+            ),
+        )
+        .with_receipt_bitmap(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
+
+    let consensus_state_path = ClientConsensusStatePath::new(&client_id, &client_height);
+    let mut consensus_state: TmConsensusState = ctx_a
+        .consensus_state(&consensus_state_path)
+        .unwrap()
+        .try_into()
+        .unwrap();
+    consensus_state.root = root_on_b;
+    let host_height = ctx_a.host_height().unwrap();
+    let client_ctx = ctx_a.get_client_execution_context();
+    client_ctx
+        .store_consensus_state(consensus_state_path, consensus_state.into())
+        .unwrap();
+    client_ctx
+        .store_update_time(client_id.clone(), client_height, now())
+        .unwrap();
+    client_ctx
+        .store_update_height(client_id, client_height, host_height)
+        .unwrap();
+
+    let mut msg =
+        MsgTimeout::try_from(dummy_raw_msg_timeout(client_height.revision_height(), 5, 0)).unwrap();
+    msg.proof_height_on_b = client_height;
+    msg.packet = packet.clone();
+    msg.proof_unreceived_on_b = proof_unreceived_on_b;
+
+    validate(&ctx_a, &router, MsgEnvelope::from(PacketMsg::from(msg)))
+}
+
+fn bitmap_path_of(packet: &Packet) -> Path {
+    Path::ReceiptBitmap(ReceiptBitmapPath::new(
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+        ReceiptBitmap::chunk(packet.seq_on_a),
+    ))
+}
+
+fn receipt_path_of(packet: &Packet) -> ReceiptPath {
+    ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a)
+}
+
+#[test]
+fn received_packet_on_bitmap_channel_cannot_time_out() {
+    let packet = MsgTimeout::try_from(dummy_raw_msg_timeout(20, 5, 0))
+        .unwrap()
+        .packet;
+    let bitmap_path_on_b = bitmap_path_of(&packet);
+
+    let mut ctx_b = bitmap_host_of(&packet);
+    let prefix_on_b = ctx_b.commitment_prefix();
+    let unreceived_root = ctx_b.commitment_root();
+    let unreceived_proof = ctx_b.non_membership_proof(&bitmap_path_on_b).unwrap();
+
+    ctx_b
+        .store_packet_receipt(&receipt_path_of(&packet), Receipt::Ok)
+        .unwrap();
+    assert!(ctx_b.non_membership_proof(&bitmap_path_on_b).is_none());
+    let received_root = ctx_b.commitment_root();
+    let receipt_key_proof = ctx_b
+        .non_membership_proof(&receipt_path_of(&packet).into())
+        .expect("the receipt is only recorded in the bitmap of its chunk");
+    let bitmap = ctx_b
+        .receipt_bitmap(&ReceiptBitmapPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            ReceiptBitmap::chunk(packet.seq_on_a),
+        ))
+        .unwrap()
+        .unwrap();
+    let bitmap_proof = ctx_b.membership_proof(&bitmap_path_on_b).unwrap();
+
+    time_out_on_bitmap_channel(
+        &packet,
+        prefix_on_b.clone(),
+        unreceived_root,
+        ReceiptBitmapProof::Absent(unreceived_proof).into(),
+    )
+    .expect("the absent bitmap of the chunk proves the packet was not received");
+
+    let res = time_out_on_bitmap_channel(
+        &packet,
+        prefix_on_b.clone(),
+        received_root.clone(),
+        ReceiptBitmapProof::Absent(receipt_key_proof.clone()).into(),
+    );
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::PacketError(
+                PacketError::Channel(ChannelError::PacketVerificationFailed { .. })
+            )))
+        ),
+        "the absence of the receipt key does not prove the packet was not received: {res:?}"
+    );
+
+    let res = time_out_on_bitmap_channel(
+        &packet,
+        prefix_on_b.clone(),
+        received_root.clone(),
+        receipt_key_proof,
+    );
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::PacketError(
+                PacketError::InvalidReceiptBitmapProof { .. }
+            )))
+        ),
+        "timeouts on bitmap channels carry a receipt bitmap proof: {res:?}"
+    );
+
+    let res = time_out_on_bitmap_channel(
+        &packet,
+        prefix_on_b,
+        received_root,
+        ReceiptBitmapProof::Present {
+            bitmap,
+            proof: bitmap_proof,
+        }
+        .into(),
+    );
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::PacketError(
+                PacketError::PacketReceived { .. }
+            )))
+        ),
+        "the bitmap of the chunk records the packet as received: {res:?}"
+    );
+}
+
+#[test]
+fn packet_on_bitmap_channel_times_out_once_others_of_its_chunk_received() {
+    let received_packet = MsgTimeout::try_from(dummy_raw_msg_timeout(20, 5, 0))
+        .unwrap()
+        .packet;
+    let packet = Packet {
+        seq_on_a: 2.into(),
+        ..received_packet.clone()
+    };
+    assert_eq!(
+        ReceiptBitmap::chunk(received_packet.seq_on_a),
+        ReceiptBitmap::chunk(packet.seq_on_a)
+    );
+    let bitmap_path_on_b = bitmap_path_of(&packet);
+
+    let mut ctx_b = bitmap_host_of(&packet);
+    ctx_b
+        .store_packet_receipt(&receipt_path_of(&received_packet), Receipt::Ok)
+        .unwrap();
+    let prefix_on_b = ctx_b.commitment_prefix();
+    let root_on_b = ctx_b.commitment_root();
+    let bitmap = ctx_b
+        .receipt_bitmap(&ReceiptBitmapPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            ReceiptBitmap::chunk(packet.seq_on_a),
+        ))
+        .unwrap()
+        .unwrap();
+
+    time_out_on_bitmap_channel(
+        &packet,
+        prefix_on_b.clone(),
+        root_on_b.clone(),
+        ReceiptBitmapProof::Present {
+            bitmap,
+            proof: ctx_b.membership_proof(&bitmap_path_on_b).unwrap(),
+        }
+        .into(),
+    )
+    .expect("the bit of the packet is clear in the committed bitmap of its chunk");
+
+    let mut forged_bitmap = bitmap;
+    forged_bitmap.insert(3.into());
+    let res = time_out_on_bitmap_channel(
+        &packet,
+        prefix_on_b,
+        root_on_b,
+        ReceiptBitmapProof::Present {
+            bitmap: forged_bitmap,
+            proof: ctx_b.membership_proof(&bitmap_path_on_b).unwrap(),
+        }
+        .into(),
+    );
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::PacketError(
+                PacketError::Channel(ChannelError::PacketVerificationFailed { .. })
+            )))
+        ),
+        "the bitmap must be the one committed under the path of the chunk: {res:?}"
+    );
+}
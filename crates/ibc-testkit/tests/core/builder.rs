@@ -0,0 +1,133 @@
+use ibc::core::ics03_connection::error::ConnectionError;
+use ibc::core::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use ibc::core::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
+use ibc::core::ics04_channel::error::ChannelError;
+use ibc::core::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
+use ibc::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+use ibc::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use ibc::core::ics24_host::identifier::ConnectionId;
+use ibc::prelude::*;
+use ibc_testkit::utils::core::channel::{
+    dummy_raw_msg_chan_close_confirm, dummy_raw_msg_chan_open_init, dummy_raw_msg_chan_open_try,
+};
+use ibc_testkit::utils::core::connection::{
+    dummy_msg_conn_open_init, dummy_raw_msg_conn_open_try,
+    msg_conn_open_init_with_counterparty_conn_id,
+};
+
+#[test]
+fn test_conn_open_init_builder() {
+    let expected = dummy_msg_conn_open_init();
+
+    let msg = MsgConnectionOpenInit::builder()
+        .client_id_on_a(expected.client_id_on_a.clone())
+        .counterparty(expected.counterparty.clone())
+        .version(expected.version.clone().unwrap())
+        .signer(expected.signer.clone())
+        .build()
+        .unwrap();
+    assert_eq!(msg, expected);
+
+    let with_conn_id = msg_conn_open_init_with_counterparty_conn_id(expected, 1);
+    let res = MsgConnectionOpenInit::builder()
+        .client_id_on_a(with_conn_id.client_id_on_a)
+        .counterparty(with_conn_id.counterparty)
+        .signer(with_conn_id.signer)
+        .build();
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_conn_open_try_builder() {
+    let mut expected = MsgConnectionOpenTry::try_from(dummy_raw_msg_conn_open_try(10, 34)).unwrap();
+    #[allow(deprecated)]
+    {
+        expected.previous_connection_id = String::new();
+    }
+
+    let builder = MsgConnectionOpenTry::builder()
+        .client_id_on_b(expected.client_id_on_b.clone())
+        .client_state_of_b_on_a(expected.client_state_of_b_on_a.clone())
+        .counterparty(expected.counterparty.clone())
+        .proof_conn_end_on_a(expected.proof_conn_end_on_a.clone())
+        .proof_client_state_of_b_on_a(expected.proof_client_state_of_b_on_a.clone())
+        .proof_consensus_state_of_b_on_a(expected.proof_consensus_state_of_b_on_a.clone())
+        .proofs_height_on_a(expected.proofs_height_on_a)
+        .consensus_height_of_b_on_a(expected.consensus_height_of_b_on_a)
+        .delay_period(expected.delay_period)
+        .signer(expected.signer.clone());
+
+    let msg = builder
+        .clone()
+        .versions_on_a(expected.versions_on_a.clone())
+        .build()
+        .unwrap();
+    assert_eq!(msg, expected);
+
+    let res = builder.versions_on_a(Vec::new()).build();
+    assert!(matches!(res, Err(ConnectionError::EmptyVersions)));
+}
+
+#[test]
+fn test_chan_open_init_builder() {
+    let expected = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+
+    let builder = MsgChannelOpenInit::builder()
+        .port_id_on_a(expected.port_id_on_a.clone())
+        .port_id_on_b(expected.port_id_on_b.clone())
+        .ordering(expected.ordering)
+        .signer(expected.signer.clone())
+        .version_proposal(expected.version_proposal.clone());
+
+    let msg = builder
+        .clone()
+        .connection_hops_on_a(expected.connection_hops_on_a.clone())
+        .build()
+        .unwrap();
+    assert_eq!(msg, expected);
+
+    let res = builder
+        .connection_hops_on_a(vec![ConnectionId::new(0), ConnectionId::new(1)])
+        .build();
+    assert!(matches!(
+        res,
+        Err(ChannelError::InvalidConnectionHopsLength {
+            expected: 1,
+            actual: 2
+        })
+    ));
+}
+
+#[test]
+fn test_chan_open_try_builder() {
+    let expected = MsgChannelOpenTry::try_from(dummy_raw_msg_chan_open_try(10)).unwrap();
+
+    let msg = MsgChannelOpenTry::builder()
+        .port_id_on_b(expected.port_id_on_b.clone())
+        .connection_hops_on_b(expected.connection_hops_on_b.clone())
+        .port_id_on_a(expected.port_id_on_a.clone())
+        .chan_id_on_a(expected.chan_id_on_a.clone())
+        .version_supported_on_a(expected.version_supported_on_a.clone())
+        .proof_chan_end_on_a(expected.proof_chan_end_on_a.clone())
+        .proof_height_on_a(expected.proof_height_on_a)
+        .ordering(expected.ordering)
+        .signer(expected.signer.clone())
+        .build()
+        .unwrap();
+    assert_eq!(msg, expected);
+}
+
+#[test]
+fn test_chan_close_confirm_builder() {
+    let expected = MsgChannelCloseConfirm::try_from(dummy_raw_msg_chan_close_confirm(10)).unwrap();
+
+    let msg = MsgChannelCloseConfirm::builder()
+        .port_id_on_b(expected.port_id_on_b.clone())
+        .chan_id_on_b(expected.chan_id_on_b.clone())
+        .proof_chan_end_on_a(expected.proof_chan_end_on_a.clone())
+        .proof_height_on_a(expected.proof_height_on_a)
+        .signer(expected.signer.clone())
+        .build()
+        .unwrap();
+    assert_eq!(msg, expected);
+}
@@ -0,0 +1,140 @@
+use ibc::core::genesis::{export_genesis, init_genesis, GenesisState};
+use ibc::core::ics02_client::error::ClientError;
+use ibc::core::ics02_client::genesis::ProcessedConsensusState;
+use ibc::core::ics02_client::{ClientExecutionContext, ClientValidationContext};
+use ibc::core::ics03_connection::connection::State as ConnectionState;
+use ibc::core::ics04_channel::packet::Receipt;
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::{AckPath, ReceiptPath};
+use ibc::core::timestamp::Timestamp;
+use ibc::core::{ContextError, ExecutionContext, ValidationContext};
+use ibc::prelude::*;
+use ibc::proto::Protobuf;
+use ibc::Height;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::builder::{ChannelConfig, TestChainBuilder};
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+fn populated_context() -> MockContext {
+    let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+    let conn_id = ConnectionId::new(0);
+    let chan_id = ChannelId::new(0);
+
+    let mut ctx = TestChainBuilder::default()
+        .client(client_id.clone(), Height::new(0, 5).unwrap())
+        .connection(conn_id.clone(), client_id, ConnectionState::Open)
+        .channel_config(
+            ChannelConfig::builder()
+                .chan_id(chan_id.clone())
+                .conn_id(conn_id)
+                .unacked_packets(2)
+                .build(),
+        )
+        .build();
+
+    ctx.store_packet_receipt(
+        &ReceiptPath::new(&PortId::transfer(), &chan_id, 3.into()),
+        Receipt::Ok,
+    )
+    .unwrap();
+    ctx.store_packet_acknowledgement(
+        &AckPath::new(&PortId::transfer(), &chan_id, 3.into()),
+        vec![7; 32].into(),
+    )
+    .unwrap();
+
+    ctx
+}
+
+#[test]
+fn genesis_round_trip() {
+    let ctx = populated_context();
+    let genesis = export_genesis(&ctx).unwrap();
+
+    assert_eq!(genesis.client_genesis.clients.len(), 1);
+    assert_eq!(genesis.client_genesis.next_client_sequence, 1);
+    assert_eq!(genesis.connection_genesis.connections.len(), 1);
+    assert_eq!(genesis.channel_genesis.channels.len(), 1);
+    assert_eq!(genesis.channel_genesis.commitments.len(), 2);
+    assert_eq!(genesis.channel_genesis.acknowledgements.len(), 1);
+    assert_eq!(genesis.channel_genesis.receipts.len(), 1);
+    assert_eq!(genesis.channel_genesis.send_sequences[0].seq, 3.into());
+
+    let decoded = GenesisState::decode_vec(&genesis.clone().encode_vec()).unwrap();
+    assert_eq!(decoded, genesis);
+
+    let mut imported = MockContext::default();
+    init_genesis(&mut imported, decoded).unwrap();
+
+    assert_eq!(export_genesis(&imported).unwrap(), genesis);
+    assert_eq!(
+        imported.channel_counter().unwrap(),
+        ctx.channel_counter().unwrap()
+    );
+}
+
+#[test]
+fn genesis_below_counter_fails() {
+    let genesis = export_genesis(&populated_context()).unwrap();
+
+    let mut imported = MockContext::default();
+    imported.increase_client_counter().unwrap();
+    imported.increase_client_counter().unwrap();
+
+    let res = init_genesis(&mut imported, genesis);
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(
+            ClientError::InvalidGenesis { .. }
+        ))
+    ));
+}
+
+#[test]
+fn genesis_round_trip_keeps_processed_times_and_heights() {
+    let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+    let client_height = Height::new(0, 5).unwrap();
+    let processed_time = Timestamp::from_nanoseconds(1_000).unwrap();
+    let processed_height = Height::new(0, 3).unwrap();
+
+    let mut ctx = populated_context();
+    let client_exec_ctx = ctx.get_client_execution_context();
+    client_exec_ctx
+        .store_update_time(client_id.clone(), client_height, processed_time)
+        .unwrap();
+    client_exec_ctx
+        .store_update_height(client_id.clone(), client_height, processed_height)
+        .unwrap();
+
+    let genesis = export_genesis(&ctx).unwrap();
+    assert_eq!(
+        genesis.client_genesis.clients[0].processed_consensus_states,
+        vec![ProcessedConsensusState {
+            height: client_height,
+            processed_time,
+            processed_height,
+        }]
+    );
+
+    let decoded = GenesisState::decode_vec(&genesis.clone().encode_vec()).unwrap();
+    assert_eq!(decoded, genesis);
+
+    let mut imported = MockContext::default();
+    init_genesis(&mut imported, decoded).unwrap();
+
+    let client_val_ctx = imported.get_client_validation_context();
+    assert_eq!(
+        client_val_ctx
+            .client_update_time(&client_id, &client_height)
+            .unwrap(),
+        processed_time
+    );
+    assert_eq!(
+        client_val_ctx
+            .client_update_height(&client_id, &client_height)
+            .unwrap(),
+        processed_height
+    );
+    assert_eq!(export_genesis(&imported).unwrap(), genesis);
+}
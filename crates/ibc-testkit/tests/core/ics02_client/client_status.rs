@@ -0,0 +1,92 @@
+use ibc::core::ics02_client::client_state::{ClientStateCommon, Status};
+use ibc::core::ics02_client::params::ClientParams;
+use ibc::core::ics02_client::ClientExecutionContext;
+use ibc::core::ics24_host::identifier::ClientId;
+use ibc::core::ics24_host::path::ClientStatePath;
+use ibc::core::{ExecutionContext, ValidationContext};
+use ibc::Height;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientBehavior, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+#[test]
+fn test_client_status() {
+    let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+    let client_height = Height::new(0, 42).unwrap();
+    let mut ctx = MockContext::default().with_client(&client_id, client_height);
+
+    assert_eq!(ctx.client_status(&client_id).unwrap(), Status::Active);
+
+    ctx.store_client_state(
+        ClientStatePath::new(&client_id),
+        MockClientState::new(MockHeader::new(client_height))
+            .with_frozen_height(Height::min(0))
+            .into(),
+    )
+    .unwrap();
+    assert_eq!(ctx.client_status(&client_id).unwrap(), Status::Frozen);
+
+    ctx.store_client_params(ClientParams::new(vec![]).unwrap())
+        .unwrap();
+    assert_eq!(ctx.client_status(&client_id).unwrap(), Status::Unauthorized);
+
+    let unknown_client_id = ClientId::new(mock_client_type(), 1).unwrap();
+    assert!(ctx.client_status(&unknown_client_id).is_err());
+}
+
+#[test]
+fn test_programmed_client_status() {
+    let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+    let client_height = Height::new(0, 42).unwrap();
+    let mut ctx = MockContext::default().with_client(&client_id, client_height);
+
+    let client_state = MockClientState::new(MockHeader::new(client_height));
+    for status in [Status::Expired, Status::Frozen] {
+        ctx.store_client_state(
+            ClientStatePath::new(&client_id),
+            client_state
+                .with_behavior(MockClientBehavior::builder().status(status).build())
+                .into(),
+        )
+        .unwrap();
+        assert_eq!(ctx.client_status(&client_id).unwrap(), status);
+    }
+
+    // Without a consensus state at the programmed latest height, the client
+    // is expired.
+    let latest_height = client_height.increment();
+    ctx.store_client_state(
+        ClientStatePath::new(&client_id),
+        client_state
+            .with_behavior(
+                MockClientBehavior::builder()
+                    .latest_height(latest_height)
+                    .build(),
+            )
+            .into(),
+    )
+    .unwrap();
+    assert_eq!(
+        ctx.client_state(&client_id).unwrap().latest_height(),
+        latest_height
+    );
+    assert_eq!(ctx.client_status(&client_id).unwrap(), Status::Expired);
+}
+
+#[test]
+fn test_client_status_strings_match_grpc() {
+    for status in [
+        Status::Active,
+        Status::Frozen,
+        Status::Expired,
+        Status::Unauthorized,
+    ] {
+        assert_eq!(status.to_string(), status.as_str());
+        assert_eq!(status.as_str().parse::<Status>().unwrap(), status);
+    }
+
+    assert!("active".parse::<Status>().is_err());
+}
@@ -0,0 +1,357 @@
+use core::time::Duration;
+
+use ibc::clients::ics07_tendermint::client_type as tm_client_type;
+use ibc::clients::ics07_tendermint::header::Header as TmHeader;
+use ibc::clients::ics07_tendermint::misbehaviour::Misbehaviour as TmMisbehaviour;
+use ibc::core::ics02_client::ClientExecutionContext;
+use ibc::core::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::ics24_host::identifier::{ChainId, ClientId, ConnectionId};
+use ibc::core::ics24_host::path::{ClientStatePath, ConnectionPath, Path};
+use ibc::core::timestamp::Timestamp;
+use ibc::core::{ContextError, ValidationContext};
+use ibc::prelude::*;
+use ibc::proto::Any;
+use ibc::Height;
+use ibc_testkit::hosts::block::{HostBlock, HostType};
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{MockClientBehavior, MockClientState};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use ibc_testkit::testapp::ibc::core::merkle::{
+    membership_proof, multistore_root, non_membership_proof, MerkleStore,
+};
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use ibc_testkit::utils::conformance::{
+    check_conformance, ClientConformance, ConformanceFailure, MembershipFixture,
+    NonMembershipFixture, UpgradeFixture,
+};
+use ibc_testkit::utils::core::context::MockContextConfig;
+use test_log::test;
+
+/// The mock client, created at height `0-42` with its consensus state
+/// timestamped at `timestamp`, which precedes the host timestamp of any
+/// context the fixture creates.
+struct MockClientFixture {
+    client_id: ClientId,
+    client_height: Height,
+    timestamp: Timestamp,
+}
+
+impl MockClientFixture {
+    fn new() -> Self {
+        Self {
+            client_id: ClientId::default(),
+            client_height: Height::new(0, 42).unwrap(),
+            timestamp: Timestamp::now(),
+        }
+    }
+
+    fn mock_header(&self, revision_height: u64) -> MockHeader {
+        MockHeader::new(Height::new(0, revision_height).unwrap()).with_timestamp(self.timestamp)
+    }
+}
+
+impl ClientConformance for MockClientFixture {
+    type Context = MockContext;
+    type ClientState = AnyClientState;
+
+    fn client_id(&self) -> ClientId {
+        self.client_id.clone()
+    }
+
+    fn context(&self) -> MockContext {
+        MockContext::default().with_client(&self.client_id, self.client_height)
+    }
+
+    fn client_state(&self, ctx: &MockContext) -> Result<AnyClientState, ContextError> {
+        ctx.client_state(&self.client_id)
+    }
+
+    fn header(&self) -> Any {
+        self.mock_header(46).into()
+    }
+
+    fn invalid_header(&self) -> Any {
+        // below the latest height of the client
+        self.mock_header(40).into()
+    }
+
+    fn misbehaviour(&self) -> Option<Any> {
+        Some(
+            MockMisbehaviour {
+                client_id: self.client_id.clone(),
+                header1: self.mock_header(46),
+                header2: self.mock_header(46),
+            }
+            .into(),
+        )
+    }
+
+    fn upgrade(&self) -> Option<UpgradeFixture> {
+        let header = self.mock_header(50);
+        Some(UpgradeFixture {
+            client_state: MockClientState::new(header).into(),
+            consensus_state: MockConsensusState::new(header).into(),
+            proof_client_state: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+            proof_consensus_state: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+            root: vec![1].into(),
+        })
+    }
+
+    fn expired_context(&self) -> Option<MockContext> {
+        let mut ctx = self.context();
+        // without a consensus state at its latest height
+        let behavior = MockClientBehavior::builder()
+            .latest_height(Height::new(0, 50).unwrap())
+            .build();
+        ctx.store_client_state(
+            ClientStatePath::new(&self.client_id),
+            MockClientState::new(self.mock_header(42))
+                .with_behavior(behavior)
+                .into(),
+        )
+        .unwrap();
+        Some(ctx)
+    }
+}
+
+/// The Tendermint client of a synthetic Tendermint chain, created at height
+/// `1-20` on a mock host.
+struct TendermintClientFixture {
+    client_id: ClientId,
+    client_height: Height,
+    header_height: Height,
+    chain_id: ChainId,
+    store_key: Vec<u8>,
+    store: MerkleStore,
+}
+
+impl TendermintClientFixture {
+    fn new() -> Self {
+        let mut store = MerkleStore::new();
+        store.set(Self::present_path().to_string(), b"connection end".to_vec());
+
+        Self {
+            client_id: ClientId::new(tm_client_type(), 0).unwrap(),
+            client_height: Height::new(1, 20).unwrap(),
+            header_height: Height::new(1, 21).unwrap(),
+            chain_id: ChainId::new("mockgaiaB-1").unwrap(),
+            store_key: b"ibc".to_vec(),
+            store,
+        }
+    }
+
+    fn present_path() -> Path {
+        ConnectionPath::new(&ConnectionId::new(0)).into()
+    }
+
+    fn absent_path() -> Path {
+        ConnectionPath::new(&ConnectionId::new(1)).into()
+    }
+
+    /// The counterparty chain, whose latest block is at the header height.
+    fn chain_b(&self) -> MockContext {
+        MockContext::new(
+            self.chain_id.clone(),
+            HostType::SyntheticTendermint,
+            5,
+            self.header_height,
+        )
+    }
+
+    fn host_block(&self, trusted_height: Height) -> HostBlock {
+        let mut block = self
+            .chain_b()
+            .host_block(&self.header_height)
+            .unwrap()
+            .clone();
+        block.set_trusted_height(trusted_height);
+        block
+    }
+
+    fn prefix(&self) -> CommitmentPrefix {
+        CommitmentPrefix::try_from(self.store_key.clone()).unwrap()
+    }
+}
+
+impl ClientConformance for TendermintClientFixture {
+    type Context = MockContext;
+    type ClientState = AnyClientState;
+
+    fn client_id(&self) -> ClientId {
+        self.client_id.clone()
+    }
+
+    fn context(&self) -> MockContext {
+        // The client trusts the block of B at its height, which the headers
+        // follow in time.
+        let trusted_timestamp = self
+            .chain_b()
+            .host_block(&self.client_height)
+            .unwrap()
+            .timestamp();
+
+        MockContext::new(
+            ChainId::new("mockgaiaA-1").unwrap(),
+            HostType::Mock,
+            5,
+            Height::new(1, 1).unwrap(),
+        )
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(self.chain_id.clone())
+                .client_id(self.client_id.clone())
+                .client_state_height(self.client_height)
+                .client_type(tm_client_type())
+                .latest_timestamp(trusted_timestamp)
+                .build(),
+        )
+    }
+
+    fn client_state(&self, ctx: &MockContext) -> Result<AnyClientState, ContextError> {
+        ctx.client_state(&self.client_id)
+    }
+
+    fn header(&self) -> Any {
+        self.host_block(self.client_height).into()
+    }
+
+    fn invalid_header(&self) -> Any {
+        // signed for another chain than the one of the client
+        let mut block = MockContext::new(
+            ChainId::new("mockgaiaC-1").unwrap(),
+            HostType::SyntheticTendermint,
+            5,
+            self.header_height,
+        )
+        .host_block(&self.header_height)
+        .unwrap()
+        .clone();
+        block.set_trusted_height(self.client_height);
+        block.into()
+    }
+
+    fn misbehaviour(&self) -> Option<Any> {
+        let header1: TmHeader = self
+            .host_block(self.client_height)
+            .try_into_tm_block()
+            .unwrap()
+            .into();
+
+        let header2 = {
+            let mut tm_block = HostBlock::generate_tm_block(
+                self.chain_id.clone(),
+                self.header_height.revision_height(),
+                Timestamp::now(),
+            );
+            tm_block.trusted_height = self.client_height;
+            tm_block.into()
+        };
+
+        Some(TmMisbehaviour::new(self.client_id.clone(), header1, header2).into())
+    }
+
+    fn membership(&self) -> Option<MembershipFixture> {
+        let path = Self::present_path();
+        Some(MembershipFixture {
+            prefix: self.prefix(),
+            proof: membership_proof(&self.store, &self.store_key, path.to_string().as_bytes())?,
+            root: multistore_root(&self.store_key, &self.store.root()),
+            path,
+            value: b"connection end".to_vec(),
+        })
+    }
+
+    fn non_membership(&self) -> Option<NonMembershipFixture> {
+        let path = Self::absent_path();
+        Some(NonMembershipFixture {
+            prefix: self.prefix(),
+            proof: non_membership_proof(&self.store, &self.store_key, path.to_string().as_bytes())?,
+            root: multistore_root(&self.store_key, &self.store.root()),
+            path,
+        })
+    }
+
+    fn expired_context(&self) -> Option<MockContext> {
+        let timestamp = Timestamp::now();
+        let trusting_period = Duration::from_secs(64);
+
+        let mut ctx = MockContextConfig::builder()
+            .host_id(ChainId::new("mockgaiaA-1").unwrap())
+            .latest_height(Height::new(1, 1).unwrap())
+            .latest_timestamp(timestamp)
+            .build()
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_chain_id(self.chain_id.clone())
+                    .client_id(self.client_id.clone())
+                    .client_state_height(self.client_height)
+                    .client_type(tm_client_type())
+                    .latest_timestamp(timestamp)
+                    .trusting_period(trusting_period)
+                    .build(),
+            );
+
+        ctx.advance_time(trusting_period);
+
+        Some(ctx)
+    }
+}
+
+#[test]
+fn mock_client_conforms() {
+    let failures = check_conformance(&MockClientFixture::new());
+    assert!(failures.is_empty(), "failures: {failures:?}");
+}
+
+#[test]
+fn tendermint_client_conforms() {
+    let failures = check_conformance(&TendermintClientFixture::new());
+    assert!(failures.is_empty(), "failures: {failures:?}");
+}
+
+/// The mock client skips proof verification, which the suite reports.
+#[test]
+fn mock_client_fails_proof_verification_checks() {
+    struct MockClientWithProofs(MockClientFixture);
+
+    impl ClientConformance for MockClientWithProofs {
+        type Context = MockContext;
+        type ClientState = AnyClientState;
+
+        fn client_id(&self) -> ClientId {
+            self.0.client_id()
+        }
+
+        fn context(&self) -> MockContext {
+            self.0.context()
+        }
+
+        fn client_state(&self, ctx: &MockContext) -> Result<AnyClientState, ContextError> {
+            self.0.client_state(ctx)
+        }
+
+        fn header(&self) -> Any {
+            ClientConformance::header(&self.0)
+        }
+
+        fn invalid_header(&self) -> Any {
+            self.0.invalid_header()
+        }
+
+        fn membership(&self) -> Option<MembershipFixture> {
+            TendermintClientFixture::new().membership()
+        }
+    }
+
+    let failures = check_conformance(&MockClientWithProofs(MockClientFixture::new()));
+
+    assert!(
+        matches!(
+            failures.as_slice(),
+            [ConformanceFailure::ForgedValueAccepted]
+        ),
+        "failures: {failures:?}"
+    );
+}
@@ -1,16 +1,23 @@
 use ibc::clients::ics07_tendermint::client_type as tm_client_type;
 use ibc::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
 use ibc::core::ics02_client::client_state::ClientStateCommon;
+use ibc::core::ics02_client::error::ClientError;
 use ibc::core::ics02_client::msgs::create_client::MsgCreateClient;
 use ibc::core::ics02_client::msgs::ClientMsg;
+use ibc::core::ics02_client::params::ClientParams;
 use ibc::core::ics24_host::identifier::ClientId;
-use ibc::core::{execute, validate, MsgEnvelope, ValidationContext};
+use ibc::core::{
+    execute, validate, ContextError, ExecutionContext, MsgEnvelope, RouterError, ValidationContext,
+};
+use ibc::prelude::*;
+use ibc::proto::Any;
 use ibc::Height;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
-    client_type as mock_client_type, MockClientState,
+    client_type as mock_client_type, MockClientState, MOCK_CLIENT_STATE_TYPE_URL,
 };
 use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::MockContext;
 use ibc_testkit::utils::clients::tendermint::{
@@ -93,3 +100,168 @@ fn test_tm_create_client_ok() {
     assert_eq!(expected_client_state.client_type(), client_type);
     assert_eq!(ctx.client_state(&client_id).unwrap(), expected_client_state);
 }
+
+#[test]
+fn test_create_duplicate_client_fails() {
+    let mut ctx = MockContext::default().with_duplicate_clients_rejected();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope.clone());
+    assert!(res.is_ok(), "first client creation succeeds");
+
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ClientError(
+                ClientError::DuplicateClient { .. }
+            )))
+        ),
+        "creating a client identical to an existing one must fail"
+    );
+}
+
+#[test]
+fn test_create_duplicate_client_ok_by_default() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    )));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope.clone());
+    assert!(res.is_ok(), "first client creation succeeds");
+
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(
+        res.is_ok(),
+        "hosts accept duplicate clients unless they opt out"
+    );
+}
+
+#[test]
+fn test_create_duplicate_malformed_client_fails_decoding() {
+    let ctx = MockContext::default().with_duplicate_clients_rejected();
+    let router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+        Any {
+            type_url: MOCK_CLIENT_STATE_TYPE_URL.to_string(),
+            value: vec![0xff],
+        },
+        MockConsensusState::new(MockHeader::new(Height::new(0, 42).unwrap())).into(),
+        dummy_account_id(),
+    )));
+
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(
+        !matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ClientError(
+                ClientError::DuplicateClient { .. }
+            )))
+        ) && res.is_err(),
+        "malformed client states fail decoding before the duplicate check"
+    );
+}
+
+#[test]
+fn test_create_client_not_allowed_fails() {
+    let mut ctx = MockContext::default();
+    let router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    )));
+
+    ctx.store_client_params(
+        ClientParams::new(vec![tm_client_type().as_str().to_string()]).unwrap(),
+    )
+    .unwrap();
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(matches!(
+        res,
+        Err(RouterError::ContextError(ContextError::ClientError(
+            ClientError::ClientTypeNotAllowed { client_type }
+        ))) if client_type == mock_client_type()
+    ));
+
+    ctx.store_client_params(
+        ClientParams::new(vec![mock_client_type().as_str().to_string()]).unwrap(),
+    )
+    .unwrap();
+
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(res.is_ok(), "listed client types are allowed");
+}
+
+#[test]
+fn test_create_client_unknown_type_fails() {
+    const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.ClientState";
+
+    let mut ctx = MockContext::default();
+    let router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+        Any {
+            type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: vec![],
+        },
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    )));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(
+        matches!(
+            &res,
+            Err(RouterError::UnknownClientStateType {
+                client_state_type,
+                known_types,
+            }) if client_state_type == SOLOMACHINE_CLIENT_STATE_TYPE_URL
+                && known_types.iter().any(|url| url == MOCK_CLIENT_STATE_TYPE_URL)
+        ),
+        "{res:?}"
+    );
+
+    // once registered by the host, the type is left to the decoder it
+    // registered for its client states
+    ctx.client_types = ctx.client_types.with_client_state_decoder(
+        SOLOMACHINE_CLIENT_STATE_TYPE_URL,
+        |client_state: Any| -> Result<AnyClientState, ClientError> {
+            Err(ClientError::Other {
+                description: format!("solomachine decoder called on {}", client_state.type_url),
+            })
+        },
+    );
+
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(
+        matches!(
+            &res,
+            Err(RouterError::ContextError(ContextError::ClientError(
+                ClientError::Other { description }
+            ))) if *description == format!(
+                "solomachine decoder called on {SOLOMACHINE_CLIENT_STATE_TYPE_URL}"
+            )
+        ),
+        "{res:?}"
+    );
+}
@@ -0,0 +1,138 @@
+use ibc::core::ics02_client::client_state::ClientStateCommon;
+use ibc::core::ics02_client::error::ClientError;
+use ibc::core::ics02_client::handler::migrate_client_store::{execute, validate};
+use ibc::core::ics02_client::migration::{MigrateClientStore, UNVERSIONED_STORE_VERSION};
+use ibc::core::ics02_client::msgs::migrate_client_store::MsgMigrateClientStore;
+use ibc::core::ics24_host::identifier::ClientId;
+use ibc::core::{ContextError, ValidationContext};
+use ibc::Height;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use ibc_testkit::testapp::ibc::core::client_ctx::MockClientRecord;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::utils::core::signer::dummy_account_id;
+use rstest::*;
+
+const LATEST_VERSION: u32 = 3;
+
+const CLIENT_HEIGHT: u64 = 42;
+
+fn client_id() -> ClientId {
+    ClientId::new(mock_client_type(), 0).unwrap()
+}
+
+/// A migration moving the stored mock client one height up, so that tests
+/// can tell how many migrations rewrote its store.
+fn increment_client_height(client_record: &mut MockClientRecord) -> Result<(), ClientError> {
+    match client_record.client_state.as_mut() {
+        Some(AnyClientState::Mock(client_state)) => {
+            client_state.header.height = client_state.header.height.increment();
+            Ok(())
+        }
+        _ => Err(ClientError::Other {
+            description: "not a mock client".to_string(),
+        }),
+    }
+}
+
+fn ctx() -> MockContext {
+    let ctx =
+        MockContext::default().with_client(&client_id(), Height::new(0, CLIENT_HEIGHT).unwrap());
+    {
+        let mut ibc_store = ctx.ibc_store.lock();
+        ibc_store.latest_client_store_version = LATEST_VERSION;
+        for version in UNVERSIONED_STORE_VERSION..LATEST_VERSION {
+            ibc_store
+                .client_store_migrations
+                .insert(version, increment_client_height);
+        }
+    }
+    ctx
+}
+
+fn stored_client_height(ctx: &MockContext) -> u64 {
+    ctx.client_state(&client_id())
+        .unwrap()
+        .latest_height()
+        .revision_height()
+}
+
+#[rstest]
+#[case::from_unversioned(UNVERSIONED_STORE_VERSION, LATEST_VERSION)]
+#[case::single_step(2, 3)]
+#[case::partial(0, 2)]
+#[case::up_to_date(LATEST_VERSION, LATEST_VERSION)]
+fn migrate_client_store_happy_path(#[case] stored: u32, #[case] target: u32) {
+    let mut ctx = ctx();
+    ctx.ibc_store
+        .lock()
+        .client_store_versions
+        .insert(client_id(), stored);
+
+    let msg = MsgMigrateClientStore::new(client_id(), target, dummy_account_id());
+
+    validate(&ctx, &msg).unwrap();
+    execute(&mut ctx, msg).unwrap();
+
+    assert_eq!(ctx.client_store_version(&client_id()).unwrap(), target);
+    // Each migration from the stored version to the target one rewrote the
+    // stored client state once.
+    assert_eq!(
+        stored_client_height(&ctx),
+        CLIENT_HEIGHT + u64::from(target - stored)
+    );
+}
+
+#[test]
+fn migrate_client_store_missing_migration() {
+    let mut ctx = ctx();
+    ctx.ibc_store.lock().client_store_migrations.remove(&1);
+
+    let msg = MsgMigrateClientStore::new(client_id(), LATEST_VERSION, dummy_account_id());
+
+    validate(&ctx, &msg).unwrap();
+    assert!(matches!(
+        execute(&mut ctx, msg),
+        Err(ContextError::ClientError(ClientError::Other { .. }))
+    ));
+
+    // The migrations up to the missing one were applied and recorded.
+    assert_eq!(ctx.client_store_version(&client_id()).unwrap(), 1);
+    assert_eq!(stored_client_height(&ctx), CLIENT_HEIGHT + 1);
+}
+
+#[rstest]
+#[case::downgrade(2, 1)]
+#[case::beyond_latest(0, LATEST_VERSION + 1)]
+fn migrate_client_store_invalid_version(#[case] stored: u32, #[case] target: u32) {
+    let ctx = ctx();
+    ctx.ibc_store
+        .lock()
+        .client_store_versions
+        .insert(client_id(), stored);
+
+    let msg = MsgMigrateClientStore::new(client_id(), target, dummy_account_id());
+
+    assert!(matches!(
+        validate(&ctx, &msg),
+        Err(ContextError::ClientError(
+            ClientError::InvalidStoreVersion { .. }
+        ))
+    ));
+}
+
+#[test]
+fn migrate_client_store_unknown_client() {
+    let msg = MsgMigrateClientStore::new(
+        ClientId::new(mock_client_type(), 1).unwrap(),
+        LATEST_VERSION,
+        dummy_account_id(),
+    );
+
+    assert!(matches!(
+        validate(&ctx(), &msg),
+        Err(ContextError::ClientError(
+            ClientError::ClientStateNotFound { .. }
+        ))
+    ));
+}
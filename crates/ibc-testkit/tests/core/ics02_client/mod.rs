@@ -1,3 +1,7 @@
+pub mod client_status;
+pub mod conformance;
 pub mod create_client;
+pub mod migrate_client_store;
+pub mod registry;
 pub mod update_client;
 pub mod upgrade_client;
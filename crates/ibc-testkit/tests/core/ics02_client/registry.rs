@@ -0,0 +1,117 @@
+use ibc::clients::ics07_tendermint::client_type as tm_client_type;
+use ibc::core::ics02_client::error::ClientError;
+use ibc::core::ics02_client::registry::ClientTypeRegistry;
+use ibc::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc::core::ValidationContext;
+use ibc::proto::core::client::v1::Height as RawHeight;
+use ibc::proto::Any;
+use ibc::Height;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState, MOCK_CLIENT_STATE_TYPE_URL,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::{
+    MockConsensusState, MOCK_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::clients::{client_type_registry, AnyClientState, AnyConsensusState};
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::utils::clients::tendermint::dummy_tm_client_state_from_raw;
+use test_log::test;
+
+#[test]
+fn test_registry_decodes_registered_types() {
+    let header = MockHeader::new(Height::new(0, 42).unwrap());
+    let client_state = MockClientState::new(header);
+    let consensus_state = MockConsensusState::new(header);
+
+    let registry = client_type_registry();
+
+    assert_eq!(
+        registry.decode_client_state(client_state.into()).unwrap(),
+        AnyClientState::Mock(client_state)
+    );
+    assert_eq!(
+        registry
+            .decode_consensus_state(consensus_state.clone().into())
+            .unwrap(),
+        AnyConsensusState::Mock(consensus_state)
+    );
+    assert!(registry.is_known_client_state(MOCK_CLIENT_STATE_TYPE_URL));
+    assert!(registry.is_known_consensus_state(MOCK_CONSENSUS_STATE_TYPE_URL));
+}
+
+#[test]
+fn test_registry_rejects_unknown_types() {
+    let registry = ClientTypeRegistry::<AnyClientState, AnyConsensusState>::new()
+        .with_client::<MockClientState, MockConsensusState>(
+            MOCK_CLIENT_STATE_TYPE_URL,
+            MOCK_CONSENSUS_STATE_TYPE_URL,
+        );
+    let unknown = Any {
+        type_url: "/unknown.v1.ClientState".to_string(),
+        value: vec![],
+    };
+
+    assert!(matches!(
+        registry.decode_client_state(unknown.clone()),
+        Err(ClientError::UnknownClientStateType { client_state_type })
+            if client_state_type == unknown.type_url
+    ));
+    assert!(matches!(
+        registry.decode_consensus_state(unknown.clone()),
+        Err(ClientError::UnknownConsensusStateType { consensus_state_type })
+            if consensus_state_type == unknown.type_url
+    ));
+    assert_eq!(
+        registry.client_state_type_urls().collect::<Vec<_>>(),
+        vec![MOCK_CLIENT_STATE_TYPE_URL]
+    );
+}
+
+#[test]
+fn test_registry_extracts_counterparty_chain_id() {
+    let registry = client_type_registry();
+
+    let tm_client_state = dummy_tm_client_state_from_raw(RawHeight::default()).unwrap();
+    assert_eq!(
+        registry
+            .counterparty_chain_id(tm_client_state.into())
+            .unwrap(),
+        Some(ChainId::new("ibc-0").unwrap())
+    );
+
+    let mock_client_state = MockClientState::new(MockHeader::new(Height::new(0, 42).unwrap()));
+    assert_eq!(
+        registry
+            .counterparty_chain_id(mock_client_state.into())
+            .unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_context_counterparty_chain_id() {
+    let chain_id = ChainId::new("mockgaiaB-1").unwrap();
+    let tm_client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let mock_client_id = ClientId::new(mock_client_type(), 0).unwrap();
+    let client_height = Height::new(1, 20).unwrap();
+
+    let ctx = MockContext::default()
+        .with_client_parametrized_with_chain_id(
+            chain_id.clone(),
+            &tm_client_id,
+            client_height,
+            Some(tm_client_type()),
+            None,
+        )
+        .with_client(&mock_client_id, client_height);
+
+    assert_eq!(
+        ctx.counterparty_chain_id(&tm_client_id).unwrap(),
+        Some(chain_id)
+    );
+    assert_eq!(ctx.counterparty_chain_id(&mock_client_id).unwrap(), None);
+
+    let unknown_client_id = ClientId::new(tm_client_type(), 1).unwrap();
+    assert!(ctx.counterparty_chain_id(&unknown_client_id).is_err());
+}
@@ -3,20 +3,24 @@ use core::time::Duration;
 
 use ibc::clients::ics07_tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::ics07_tendermint::client_type as tm_client_type;
+use ibc::clients::ics07_tendermint::error::Error as TmError;
 use ibc::clients::ics07_tendermint::header::Header as TmHeader;
+use ibc::clients::ics07_tendermint::header_batch::HeaderBatch;
 use ibc::clients::ics07_tendermint::misbehaviour::Misbehaviour as TmMisbehaviour;
 use ibc::core::events::{IbcEvent, MessageEvent};
-use ibc::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc::core::ics02_client::client_state::{ClientStateCommon, ClientStateValidation, UpdateKind};
 use ibc::core::ics02_client::client_type::ClientType;
+use ibc::core::ics02_client::error::ClientError;
 use ibc::core::ics02_client::msgs::misbehaviour::MsgSubmitMisbehaviour;
-use ibc::core::ics02_client::msgs::update_client::MsgUpdateClient;
+use ibc::core::ics02_client::msgs::update_client::{MsgUpdateClient, UpdateClientResult};
 use ibc::core::ics02_client::msgs::ClientMsg;
 use ibc::core::ics02_client::ClientValidationContext;
+use ibc::core::ics23_commitment::commitment::CommitmentRoot;
 use ibc::core::ics23_commitment::specs::ProofSpecs;
 use ibc::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc::core::ics24_host::path::ClientConsensusStatePath;
 use ibc::core::timestamp::Timestamp;
-use ibc::core::{execute, validate, MsgEnvelope, ValidationContext};
+use ibc::core::{execute, validate, ContextError, MsgEnvelope, MsgOutput, ValidationContext};
 use ibc::prelude::*;
 use ibc::proto::tendermint::v1::{ClientState as RawTmClientState, Fraction};
 use ibc::proto::Any;
@@ -26,8 +30,10 @@ use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
 };
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
-use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::{
+    Misbehaviour as MockMisbehaviour, MOCK_MISBEHAVIOUR_TYPE_URL,
+};
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use ibc_testkit::utils::core::context::MockContextConfig;
@@ -64,6 +70,14 @@ fn test_update_client_ok() {
 
     assert!(res.is_ok(), "execution happy path");
 
+    assert_eq!(
+        res.unwrap().result,
+        MsgOutput::UpdateClient(UpdateClientResult {
+            update_kind: UpdateKind::UpdateClient,
+            frozen: false,
+            consensus_heights: vec![height],
+        })
+    );
     assert_eq!(
         ctx.client_state(&msg.client_id).unwrap(),
         MockClientState::new(MockHeader::new(height).with_timestamp(timestamp)).into()
@@ -243,6 +257,85 @@ fn test_update_synthetic_tendermint_client_adjacent_ok() {
     assert_eq!(client_state.latest_height(), latest_header_height);
 }
 
+/// Updates a synthetic tendermint client, with a trusting period of 64000
+/// seconds, on a host warning of the clients expiring within `margin`.
+fn update_synthetic_tendermint_client_with_expiry_margin(margin: Duration) -> MockContext {
+    let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx = MockContext::new(
+        ChainId::new("mockgaiaA-1").unwrap(),
+        HostType::Mock,
+        5,
+        Height::new(1, 1).unwrap(),
+    )
+    .with_client_parametrized_with_chain_id(
+        chain_id_b.clone(),
+        &client_id,
+        client_height,
+        Some(tm_client_type()),
+        Some(client_height),
+    );
+    ctx.ibc_store.lock().client_expiry_warning_margin = Some(margin);
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let ctx_b = MockContext::new(chain_id_b, HostType::SyntheticTendermint, 5, update_height);
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().clone();
+    block.set_trusted_height(client_height);
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    ctx
+}
+
+#[test]
+fn test_update_client_near_expiry() {
+    let ctx = update_synthetic_tendermint_client_with_expiry_margin(Duration::from_secs(64000));
+
+    let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let remaining_trusting_period = ctx
+        .client_state(&client_id)
+        .unwrap()
+        .remaining_trusting_period(&ctx, &client_id)
+        .unwrap()
+        .unwrap();
+    assert!(remaining_trusting_period <= Duration::from_secs(64000));
+    assert!(remaining_trusting_period > Duration::from_secs(63000));
+
+    assert_eq!(ctx.events.len(), 3);
+    let near_expiry_event = downcast!(&ctx.events[2] => IbcEvent::ClientNearExpiry).unwrap();
+    assert_eq!(near_expiry_event.client_id(), &client_id);
+    assert_eq!(near_expiry_event.client_type(), &tm_client_type());
+    assert_eq!(
+        near_expiry_event.consensus_height(),
+        &Height::new(1, 21).unwrap()
+    );
+    assert_eq!(
+        near_expiry_event.remaining_trusting_period(),
+        Duration::from_secs(remaining_trusting_period.as_secs())
+    );
+}
+
+#[test]
+fn test_update_client_not_near_expiry() {
+    let ctx = update_synthetic_tendermint_client_with_expiry_margin(Duration::from_secs(3600));
+
+    assert_eq!(ctx.events.len(), 2);
+    assert!(downcast!(&ctx.events[1] => IbcEvent::UpdateClient).is_some());
+}
+
 #[test]
 fn test_update_synthetic_tendermint_client_validator_change_ok() {
     let client_id = ClientId::new(tm_client_type(), 0).unwrap();
@@ -475,6 +568,269 @@ fn test_update_synthetic_tendermint_client_non_adjacent_ok() {
     assert_eq!(client_state.latest_height(), latest_header_height);
 }
 
+#[test]
+fn test_update_synthetic_tendermint_client_missing_trusted_consensus_state() {
+    let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx = MockContext::new(
+        ChainId::new("mockgaiaA-1").unwrap(),
+        HostType::Mock,
+        5,
+        Height::new(1, 1).unwrap(),
+    )
+    .with_client_parametrized_history_with_chain_id(
+        chain_id_b.clone(),
+        &client_id,
+        client_height,
+        Some(tm_client_type()),
+        Some(client_height),
+    );
+
+    let client_state = match ctx.client_state(&client_id).unwrap() {
+        AnyClientState::Tendermint(client_state) => client_state,
+        _ => core::panic!("unexpected client state type"),
+    };
+    let (trusted_height, _) = client_state
+        .select_trusted_consensus_state(&ctx, &client_id, update_height)
+        .unwrap()
+        .unwrap();
+    assert_eq!(trusted_height, client_height);
+    assert!(client_state
+        .select_trusted_consensus_state(&ctx, &client_id, Height::new(1, 19).unwrap())
+        .unwrap()
+        .is_none());
+
+    let router = MockRouter::new_with_transfer();
+
+    let ctx_b = MockContext::new(chain_id_b, HostType::SyntheticTendermint, 5, update_height);
+
+    let header_trusted_at = |height: Height| {
+        let mut block = ctx_b.host_block(&height).unwrap().clone();
+        block.set_trusted_height(Height::new(1, 15).unwrap());
+        block
+    };
+
+    // The header is verified against the consensus state selected in place
+    // of the missing one at its trusted height.
+    let block = header_trusted_at(update_height);
+    let header = TmHeader::try_from(Any::from(block.clone())).unwrap();
+    assert!(!header.is_adjacent());
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    // No consensus state qualifies for a header below the latest height.
+    let stale_height = Height::new(1, 19).unwrap();
+    let stale_header = TmHeader::try_from(Any::from(header_trusted_at(stale_height))).unwrap();
+    let missing_trusted_consensus_state =
+        ClientError::from(TmError::MissingTrustedConsensusState {
+            trusted_height: Height::new(1, 15).unwrap(),
+            height: stale_height,
+        });
+    let res = client_state.verify_header(&ctx, &client_id, stale_header);
+    assert_eq!(
+        res.map_err(|e| e.to_string()),
+        Err(missing_trusted_consensus_state.to_string())
+    );
+
+    // Failures of the store are not mistaken for a missing consensus state.
+    let unavailable = ClientError::Other {
+        description: "unavailable".to_string(),
+    };
+    let res = client_state.verify_header(
+        &failing_host::UnavailableConsensusStates(&ctx),
+        &client_id,
+        header,
+    );
+    assert_eq!(res.map_err(|e| e.to_string()), Err(unavailable.to_string()));
+}
+
+/// Kept apart, as the methods of the Tendermint contexts share the names of
+/// the ones of [`ValidationContext`] called by the tests.
+mod failing_host {
+    use ibc::clients::ics07_tendermint::{
+        CommonContext as TmCommonContext, ValidationContext as TmValidationContext,
+    };
+
+    use super::*;
+
+    /// A host failing to read the consensus states of its clients.
+    pub struct UnavailableConsensusStates<'a>(pub &'a MockContext);
+
+    impl TmCommonContext for UnavailableConsensusStates<'_> {
+        type ConversionError = &'static str;
+        type AnyConsensusState = AnyConsensusState;
+
+        fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+            TmCommonContext::host_timestamp(self.0)
+        }
+
+        fn host_height(&self) -> Result<Height, ContextError> {
+            TmCommonContext::host_height(self.0)
+        }
+
+        fn consensus_state(
+            &self,
+            _client_cons_state_path: &ClientConsensusStatePath,
+        ) -> Result<AnyConsensusState, ContextError> {
+            Err(ClientError::Other {
+                description: "unavailable".to_string(),
+            }
+            .into())
+        }
+
+        fn consensus_state_heights(
+            &self,
+            client_id: &ClientId,
+        ) -> Result<Vec<Height>, ContextError> {
+            TmCommonContext::consensus_state_heights(self.0, client_id)
+        }
+    }
+
+    impl TmValidationContext for UnavailableConsensusStates<'_> {
+        fn next_consensus_state(
+            &self,
+            client_id: &ClientId,
+            height: &Height,
+        ) -> Result<Option<AnyConsensusState>, ContextError> {
+            TmValidationContext::next_consensus_state(self.0, client_id, height)
+        }
+
+        fn prev_consensus_state(
+            &self,
+            client_id: &ClientId,
+            height: &Height,
+        ) -> Result<Option<AnyConsensusState>, ContextError> {
+            TmValidationContext::prev_consensus_state(self.0, client_id, height)
+        }
+    }
+}
+
+#[test]
+fn test_update_synthetic_tendermint_client_header_batch_ok() {
+    let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 23).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = MockContext::new(
+        chain_id_b.clone(),
+        HostType::SyntheticTendermint,
+        5,
+        update_height,
+    );
+
+    // The client trusts the block of B at its height, which the headers of the
+    // batch follow in time.
+    let mut ctx = MockContext::new(
+        ChainId::new("mockgaiaA-1").unwrap(),
+        HostType::Mock,
+        5,
+        Height::new(1, 1).unwrap(),
+    )
+    .with_client_config(
+        MockClientConfig::builder()
+            .client_chain_id(chain_id_b)
+            .client_id(client_id.clone())
+            .client_type(tm_client_type())
+            .client_state_height(client_height)
+            .latest_timestamp(ctx_b.host_block(&client_height).unwrap().timestamp())
+            .build(),
+    );
+
+    let mut router = MockRouter::new_with_transfer();
+
+    // Headers at 21, 22 and 23, each trusted at the height of the previous one.
+    let headers: Vec<TmHeader> = (21..=23)
+        .map(|h| {
+            let mut block = ctx_b
+                .host_block(&Height::new(1, h).unwrap())
+                .unwrap()
+                .clone();
+            block.set_trusted_height(Height::new(1, h - 1).unwrap());
+            TmHeader::try_from(Any::from(block)).unwrap()
+        })
+        .collect();
+    let intermediate_height = Height::new(1, 22).unwrap();
+    let batch = HeaderBatch::new(headers, vec![intermediate_height]).unwrap();
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: batch.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), update_height);
+
+    // Only the consensus states of the selected intermediate header and of the
+    // last one are stored.
+    let stored = |h| {
+        ctx.consensus_state(&ClientConsensusStatePath::new(
+            &client_id,
+            &Height::new(1, h).unwrap(),
+        ))
+        .is_ok()
+    };
+    assert!(!stored(21));
+    assert!(stored(22));
+    assert!(stored(23));
+}
+
+#[test]
+fn test_header_batch_must_be_sequential() {
+    let ctx_b = MockContext::new(
+        ChainId::new("mockgaiaB-1").unwrap(),
+        HostType::SyntheticTendermint,
+        5,
+        Height::new(1, 23).unwrap(),
+    );
+
+    // Both headers are trusted at 20, so the second one does not build on the
+    // first one.
+    let headers: Vec<TmHeader> = [21, 23]
+        .into_iter()
+        .map(|h| {
+            let mut block = ctx_b
+                .host_block(&Height::new(1, h).unwrap())
+                .unwrap()
+                .clone();
+            block.set_trusted_height(Height::new(1, 20).unwrap());
+            TmHeader::try_from(Any::from(block)).unwrap()
+        })
+        .collect();
+
+    assert!(matches!(
+        HeaderBatch::new(headers.clone(), vec![]),
+        Err(TmError::NonSequentialHeaderBatch { .. })
+    ));
+    assert!(matches!(
+        HeaderBatch::new(headers[..1].to_vec(), vec![Height::new(1, 21).unwrap()]),
+        Err(TmError::InvalidIntermediateHeight { .. })
+    ));
+    assert!(matches!(
+        HeaderBatch::new(vec![], vec![]),
+        Err(TmError::EmptyHeaderBatch)
+    ));
+}
+
 #[test]
 fn test_update_synthetic_tendermint_client_duplicate_ok() {
     let client_id = ClientId::new(tm_client_type(), 0).unwrap();
@@ -601,6 +957,80 @@ fn test_update_synthetic_tendermint_client_duplicate_ok() {
     assert_eq!(client_state, ctx_a.latest_client_states(&msg.client_id));
 }
 
+#[test]
+fn test_update_synthetic_tendermint_client_conflicting_header() {
+    let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx = MockContext::new(
+        ChainId::new("mockgaiaA-1").unwrap(),
+        HostType::Mock,
+        5,
+        Height::new(1, 1).unwrap(),
+    )
+    .with_client_parametrized_with_chain_id(
+        chain_id_b.clone(),
+        &client_id,
+        client_height,
+        Some(tm_client_type()),
+        Some(client_height),
+    );
+
+    // The client already stores a consensus state at the update height, with
+    // a commitment root other than the one of the submitted header.
+    let conflicting_block = HostBlock::generate_tm_block_with_app_hash(
+        chain_id_b.clone(),
+        update_height.revision_height(),
+        Timestamp::now(),
+        CommitmentRoot::from(vec![1; 32]),
+    );
+    ctx.ibc_store
+        .lock()
+        .clients
+        .get_mut(&client_id)
+        .unwrap()
+        .consensus_states
+        .insert(update_height, conflicting_block.into());
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let ctx_b = MockContext::new(chain_id_b, HostType::SyntheticTendermint, 5, update_height);
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().clone();
+    block.set_trusted_height(client_height);
+
+    let header: Any = block.into();
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: header.clone(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    assert!(client_state.status(&ctx, &client_id).unwrap().is_frozen());
+
+    assert_eq!(ctx.events.len(), 3);
+    assert!(downcast!(&ctx.events[1] => IbcEvent::ClientMisbehaviour).is_some());
+    let conflicting_header_event =
+        downcast!(&ctx.events[2] => IbcEvent::ConflictingHeader).unwrap();
+    assert_eq!(conflicting_header_event.client_id(), &client_id);
+    assert_eq!(conflicting_header_event.client_type(), &tm_client_type());
+    assert_eq!(
+        conflicting_header_event.consensus_heights(),
+        &[update_height]
+    );
+    assert_eq!(conflicting_header_event.header(), &header.encode_to_vec());
+}
+
 #[test]
 fn test_update_synthetic_tendermint_client_lower_height() {
     let client_id = ClientId::new(tm_client_type(), 0).unwrap();
@@ -698,6 +1128,13 @@ fn ensure_misbehaviour(ctx: &MockContext, client_id: &ClientId, client_type: &Cl
         downcast!(&ctx.events[1] => IbcEvent::ClientMisbehaviour).unwrap();
     assert_eq!(misbehaviour_client_event.client_id(), client_id);
     assert_eq!(misbehaviour_client_event.client_type(), client_type);
+    assert!(!misbehaviour_client_event.evidence_heights().is_empty());
+
+    // check that the host was notified of the frozen client
+    assert_eq!(
+        ctx.ibc_store.lock().frozen_clients,
+        vec![misbehaviour_client_event.clone()]
+    );
 }
 
 /// Tests misbehaviour handling for the mock client.
@@ -729,6 +1166,17 @@ fn test_misbehaviour_client_ok() {
     assert!(res.is_ok());
 
     ensure_misbehaviour(&ctx, &client_id, &mock_client_type());
+
+    let misbehaviour_client_event =
+        downcast!(&ctx.events[1] => IbcEvent::ClientMisbehaviour).unwrap();
+    assert_eq!(
+        misbehaviour_client_event.evidence_type(),
+        MOCK_MISBEHAVIOUR_TYPE_URL
+    );
+    assert_eq!(
+        misbehaviour_client_event.evidence_heights(),
+        [height, height]
+    );
 }
 
 /// Tests misbehaviour handling failure for a non-existent client
@@ -820,6 +1268,82 @@ fn test_misbehaviour_synthetic_tendermint_equivocation() {
     ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type());
 }
 
+/// Tests that Tendermint misbehaviour submitted through a `MsgUpdateClient`
+/// is handled as misbehaviour rather than as a header.
+#[test]
+fn test_misbehaviour_synthetic_tendermint_through_update_client() {
+    let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+    let client_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx_a = MockContext::new(
+        ChainId::new("mockgaiaA-1").unwrap(),
+        HostType::Mock,
+        5,
+        Height::new(1, 1).unwrap(),
+    )
+    .with_client_parametrized_with_chain_id(
+        chain_id_b.clone(),
+        &client_id,
+        client_height,
+        Some(tm_client_type()),
+        Some(client_height),
+    );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let ctx_b = MockContext::new(
+        chain_id_b.clone(),
+        HostType::SyntheticTendermint,
+        5,
+        misbehaviour_height,
+    );
+
+    let header1: TmHeader = {
+        let mut block = ctx_b.host_block(&misbehaviour_height).unwrap().clone();
+        block.set_trusted_height(client_height);
+        block.try_into_tm_block().unwrap().into()
+    };
+
+    let header2 = {
+        let mut tm_block = HostBlock::generate_tm_block(
+            chain_id_b,
+            misbehaviour_height.revision_height(),
+            Timestamp::now(),
+        );
+        tm_block.trusted_height = client_height;
+        tm_block.into()
+    };
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: TmMisbehaviour::new(client_id.clone(), header1, header2).into(),
+        signer: dummy_account_id(),
+    };
+
+    let client_state = ctx_a.client_state(&client_id).unwrap();
+    assert_eq!(
+        client_state.update_kind(&msg.client_message),
+        UpdateKind::SubmitMisbehaviour
+    );
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a, &router_a, msg_envelope.clone());
+    assert!(res.is_ok());
+    let res = execute(&mut ctx_a, &mut router_a, msg_envelope);
+    assert_eq!(
+        res.unwrap().result,
+        MsgOutput::UpdateClient(UpdateClientResult {
+            update_kind: UpdateKind::SubmitMisbehaviour,
+            frozen: true,
+            consensus_heights: Vec::new(),
+        })
+    );
+    ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type());
+}
+
 #[test]
 fn test_misbehaviour_synthetic_tendermint_bft_time() {
     let client_id = ClientId::new(tm_client_type(), 0).unwrap();
@@ -913,10 +1437,7 @@ fn test_expired_client() {
                 .build(),
         );
 
-    while ctx.host_timestamp().expect("no error") < (timestamp + trusting_period).expect("no error")
-    {
-        ctx.advance_host_chain_height();
-    }
+    ctx.advance_time(trusting_period);
 
     let client_state = ctx.client_state(&client_id).unwrap();
 
@@ -1,22 +1,35 @@
+use ibc::clients::ics07_tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::ics07_tendermint::client_type;
+use ibc::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
 use ibc::core::events::{IbcEvent, MessageEvent};
+use ibc::core::ics02_client::client_state::{ClientStateCommon, ClientStateExecution};
 use ibc::core::ics02_client::error::{ClientError, UpgradeClientError};
 use ibc::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
 use ibc::core::ics02_client::msgs::ClientMsg;
-use ibc::core::ics24_host::identifier::ClientId;
-use ibc::core::ics24_host::path::ClientConsensusStatePath;
+use ibc::core::ics02_client::ClientExecutionContext;
+use ibc::core::ics23_commitment::commitment::{CommitmentProofBytes, CommitmentRoot};
+use ibc::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc::core::ics24_host::path::{
+    ClientConsensusStatePath, ClientStatePath, Path, UpgradeClientPath,
+};
+use ibc::core::timestamp::Timestamp;
 use ibc::core::{execute, validate, ContextError, MsgEnvelope, ValidationContext};
 use ibc::prelude::*;
+use ibc::proto::Any;
 use ibc::{downcast, Height};
+use ibc_testkit::hosts::block::{HostBlock, HostType};
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+use ibc_testkit::testapp::ibc::core::merkle::{membership_proof, multistore_root, MerkleStore};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use ibc_testkit::utils::clients::tendermint::{
     dummy_tendermint_header, dummy_tm_client_state_from_header,
+    ClientStateConfig as TmClientStateConfig,
 };
 use ibc_testkit::utils::core::client::dummy_msg_upgrade_client;
 use ibc_testkit::utils::{Expect, Fixture};
+use prost::Message;
 
 enum Ctx {
     Default,
@@ -163,3 +176,229 @@ fn upgrade_client_fail_unknown_upgraded_client_state() {
     });
     upgrade_client_validate(&fxt, Expect::Failure(Some(expected_err.into())));
 }
+
+/// The upgrade of a Tendermint client, as committed by its chain under the
+/// upgrade path of the client.
+struct TmUpgrade {
+    client_state: Any,
+    consensus_state: Any,
+    proof_client_state: CommitmentProofBytes,
+    proof_consensus_state: CommitmentProofBytes,
+    root: CommitmentRoot,
+}
+
+const TM_UPGRADE_STORE_KEY: &[u8] = b"upgrade";
+
+/// A Tendermint client of chain `mockgaiaB-1` at height `1-20`, on a mock host.
+fn tm_upgrade_fixture() -> (MockContext, ClientId, TmClientState) {
+    let chain_id = ChainId::new("mockgaiaB-1").unwrap();
+    let client_id = ClientId::new(client_type(), 0).unwrap();
+    let client_height = Height::new(1, 20).unwrap();
+
+    let client_state: TmClientState = TmClientStateConfig::builder()
+        .chain_id(chain_id.clone())
+        .latest_height(client_height)
+        .upgrade_path(vec!["upgrade".to_string(), "upgradedIBCState".to_string()])
+        .build()
+        .try_into()
+        .unwrap();
+
+    let mut ctx = MockContext::new(
+        ChainId::new("mockgaiaA-1").unwrap(),
+        HostType::Mock,
+        5,
+        Height::new(1, 1).unwrap(),
+    )
+    .with_client_config(
+        MockClientConfig::builder()
+            .client_chain_id(chain_id)
+            .client_id(client_id.clone())
+            .client_state_height(client_height)
+            .client_type(client_type())
+            .build(),
+    );
+    ctx.store_client_state(
+        ClientStatePath::new(&client_id),
+        client_state.clone().into(),
+    )
+    .unwrap();
+
+    (ctx, client_id, client_state)
+}
+
+/// Commits `upgraded_client_state`, along with the consensus state of the
+/// first block of the upgraded chain, under the upgrade path of
+/// `client_state`.
+fn commit_tm_upgrade(
+    client_state: &TmClientState,
+    upgraded_client_state: TmClientState,
+) -> TmUpgrade {
+    let upgraded_block = HostBlock::generate_tm_block(
+        upgraded_client_state.chain_id.clone(),
+        upgraded_client_state.latest_height.revision_height(),
+        Timestamp::now(),
+    );
+    let consensus_state: Any = TmConsensusState::from(upgraded_block.header().clone()).into();
+    let client_state_any: Any = upgraded_client_state.into();
+
+    let last_height = client_state.latest_height.revision_height();
+    let client_state_path =
+        Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(last_height)).to_string();
+    let consensus_state_path =
+        Path::UpgradeClient(UpgradeClientPath::UpgradedClientConsensusState(last_height))
+            .to_string();
+
+    let mut store = MerkleStore::new();
+    store.set(client_state_path.clone(), client_state_any.encode_to_vec());
+    store.set(
+        consensus_state_path.clone(),
+        consensus_state.encode_to_vec(),
+    );
+
+    TmUpgrade {
+        client_state: client_state_any,
+        consensus_state,
+        proof_client_state: membership_proof(
+            &store,
+            TM_UPGRADE_STORE_KEY,
+            client_state_path.as_bytes(),
+        )
+        .unwrap(),
+        proof_consensus_state: membership_proof(
+            &store,
+            TM_UPGRADE_STORE_KEY,
+            consensus_state_path.as_bytes(),
+        )
+        .unwrap(),
+        root: multistore_root(TM_UPGRADE_STORE_KEY, &store.root()),
+    }
+}
+
+fn verify_tm_upgrade(client_state: &TmClientState, upgrade: &TmUpgrade) -> Result<(), ClientError> {
+    client_state.verify_upgrade_client(
+        upgrade.client_state.clone(),
+        upgrade.consensus_state.clone(),
+        upgrade.proof_client_state.clone(),
+        upgrade.proof_consensus_state.clone(),
+        &upgrade.root,
+    )
+}
+
+#[test]
+fn tm_upgraded_client_state_zeroes_custom_fields() {
+    let (_, _, client_state) = tm_upgrade_fixture();
+
+    let upgraded_client_state = client_state
+        .upgraded_client_state(Height::new(2, 1).unwrap())
+        .unwrap();
+    assert_eq!(
+        upgraded_client_state.chain_id,
+        ChainId::new("mockgaiaB-2").unwrap()
+    );
+    assert_eq!(
+        upgraded_client_state.latest_height,
+        Height::new(2, 1).unwrap()
+    );
+    assert_eq!(
+        upgraded_client_state.unbonding_period,
+        client_state.unbonding_period
+    );
+    assert!(upgraded_client_state.trusting_period.is_zero());
+
+    // an upgrade within the same revision keeps the chain identifier
+    let upgraded_client_state = client_state
+        .upgraded_client_state(Height::new(1, 30).unwrap())
+        .unwrap();
+    assert_eq!(upgraded_client_state.chain_id, client_state.chain_id);
+
+    let res = client_state.upgraded_client_state(Height::new(1, 20).unwrap());
+    assert!(matches!(
+        res,
+        Err(ClientError::Upgrade(
+            UpgradeClientError::LowUpgradeHeight { .. }
+        ))
+    ));
+}
+
+#[test]
+fn tm_upgrade_client_across_revisions() {
+    let (mut ctx, client_id, client_state) = tm_upgrade_fixture();
+    let upgraded_height = Height::new(2, 1).unwrap();
+
+    let upgrade = commit_tm_upgrade(
+        &client_state,
+        client_state.upgraded_client_state(upgraded_height).unwrap(),
+    );
+    verify_tm_upgrade(&client_state, &upgrade).unwrap();
+
+    let latest_height = client_state
+        .update_state_on_upgrade(
+            &mut ctx,
+            &client_id,
+            upgrade.client_state,
+            upgrade.consensus_state,
+        )
+        .unwrap();
+    assert_eq!(latest_height, upgraded_height);
+
+    let new_client_state = match ctx.client_state(&client_id).unwrap() {
+        AnyClientState::Tendermint(client_state) => client_state,
+        _ => core::panic!("unexpected client state type"),
+    };
+    assert_eq!(
+        new_client_state.chain_id,
+        ChainId::new("mockgaiaB-2").unwrap()
+    );
+    assert_eq!(new_client_state.latest_height, upgraded_height);
+    // the custom fields are carried over from the upgrading client
+    assert_eq!(
+        new_client_state.trusting_period,
+        client_state.trusting_period
+    );
+    assert_eq!(new_client_state.trust_level, client_state.trust_level);
+
+    assert!(ctx
+        .consensus_state(&ClientConsensusStatePath::new(&client_id, &upgraded_height))
+        .is_ok());
+}
+
+#[test]
+fn tm_upgrade_client_fail_chain_name_mismatch() {
+    let (_, _, client_state) = tm_upgrade_fixture();
+
+    let mut upgraded_client_state = client_state
+        .upgraded_client_state(Height::new(2, 1).unwrap())
+        .unwrap();
+    upgraded_client_state.chain_id = ChainId::new("othergaia-2").unwrap();
+
+    let upgrade = commit_tm_upgrade(&client_state, upgraded_client_state);
+    let res = verify_tm_upgrade(&client_state, &upgrade);
+
+    assert!(matches!(
+        res,
+        Err(ClientError::Upgrade(
+            UpgradeClientError::ChainNameMismatch { .. }
+        ))
+    ));
+}
+
+#[test]
+fn tm_upgrade_client_fail_revision_mismatch() {
+    let (_, _, client_state) = tm_upgrade_fixture();
+
+    // the height moves to revision 3 while the chain identifier stays at revision 2
+    let mut upgraded_client_state = client_state
+        .upgraded_client_state(Height::new(2, 1).unwrap())
+        .unwrap();
+    upgraded_client_state.latest_height = Height::new(3, 1).unwrap();
+
+    let upgrade = commit_tm_upgrade(&client_state, upgraded_client_state);
+    let res = verify_tm_upgrade(&client_state, &upgrade);
+
+    assert!(matches!(
+        res,
+        Err(ClientError::Upgrade(
+            UpgradeClientError::RevisionMismatch { .. }
+        ))
+    ));
+}
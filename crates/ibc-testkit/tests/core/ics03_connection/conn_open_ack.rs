@@ -1,4 +1,5 @@
 use core::str::FromStr;
+use core::time::Duration;
 
 use ibc::core::events::{IbcEvent, MessageEvent};
 use ibc::core::ics02_client::height::Height;
@@ -8,12 +9,16 @@ use ibc::core::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
 use ibc::core::ics03_connection::msgs::ConnectionMsg;
 use ibc::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc::core::ics24_host::path::ConnectionPath;
 use ibc::core::timestamp::ZERO_DURATION;
 use ibc::core::{execute, validate, ContextError, MsgEnvelope, RouterError, ValidationContext};
 use ibc::prelude::*;
+use ibc::proto::Protobuf;
 use ibc_testkit::hosts::block::HostType;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientBehavior;
+use ibc_testkit::testapp::ibc::core::merkle::{membership_proof, MerkleStore};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use ibc_testkit::utils::core::connection::dummy_msg_conn_open_ack;
 use ibc_testkit::utils::{Expect, Fixture};
 use test_log::test;
@@ -21,6 +26,7 @@ use test_log::test;
 enum Ctx {
     New,
     NewWithConnection,
+    NewWithConnectionFailingProofs,
     NewWithConnectionEndOpen,
     DefaultWithConnection,
 }
@@ -68,6 +74,23 @@ fn conn_open_ack_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenAck> {
         Ctx::NewWithConnection => ctx_new
             .with_client(&client_id, proof_height)
             .with_connection(conn_id, default_conn_end),
+        Ctx::NewWithConnectionFailingProofs => {
+            let client_chain_id = ctx_new.host_chain_id.clone();
+            ctx_new
+                .with_client_config(
+                    MockClientConfig::builder()
+                        .client_chain_id(client_chain_id)
+                        .client_id(client_id.clone())
+                        .client_state_height(proof_height)
+                        .behavior(
+                            MockClientBehavior::builder()
+                                .fail_proof_verification(true)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .with_connection(conn_id, default_conn_end)
+        }
         Ctx::DefaultWithConnection => ctx_default
             .with_client(&client_id, proof_height)
             .with_connection(conn_id, default_conn_end),
@@ -192,3 +215,69 @@ fn conn_open_ack_connection_mismatch() {
     });
     conn_open_ack_validate(&fxt, Expect::Failure(Some(expected_err.into())));
 }
+
+/// Validates a `ConnOpenAck` whose proof proves the connection end the
+/// handler expects, once changed by `commit`, on a host whose client of the
+/// counterparty fails to verify proofs.
+fn conn_open_ack_validate_committed(commit: impl FnOnce(&mut ConnectionEnd)) -> RouterError {
+    let Fixture { ctx, mut msg } = conn_open_ack_fixture(Ctx::NewWithConnectionFailingProofs);
+
+    let conn_end_on_a = ctx.connection_end(&msg.conn_id_on_a).unwrap();
+    let mut committed_conn_end_on_b = ConnectionEnd::new(
+        State::TryOpen,
+        conn_end_on_a.counterparty().client_id().clone(),
+        Counterparty::new(
+            conn_end_on_a.client_id().clone(),
+            Some(msg.conn_id_on_a.clone()),
+            ctx.commitment_prefix(),
+        ),
+        vec![msg.version.clone()],
+        conn_end_on_a.delay_period(),
+    )
+    .unwrap();
+    commit(&mut committed_conn_end_on_b);
+
+    let path = ConnectionPath::new(&msg.conn_id_on_b).to_string();
+    let mut store = MerkleStore::new();
+    store.set(path.clone(), committed_conn_end_on_b.encode_vec());
+    msg.proof_conn_end_on_b = membership_proof(&store, b"ibc", path.as_bytes()).unwrap();
+
+    let router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(msg));
+    validate(&ctx, &router, msg_envelope).unwrap_err()
+}
+
+#[test]
+fn conn_open_ack_delay_period_mismatch() {
+    let err = conn_open_ack_validate_committed(|conn_end| {
+        conn_end.set_delay_period(Duration::from_secs(10))
+    });
+
+    assert!(
+        matches!(
+            err,
+            RouterError::ContextError(ContextError::ConnectionError(
+                ConnectionError::DelayPeriodMismatch { expected, committed }
+            )) if expected == ZERO_DURATION && committed == Duration::from_secs(10)
+        ),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn conn_open_ack_conn_end_mismatch() {
+    let err = conn_open_ack_validate_committed(|conn_end| {
+        conn_end.set_delay_period(Duration::from_secs(10));
+        conn_end.set_state(State::Open);
+    });
+
+    assert!(
+        matches!(
+            err,
+            RouterError::ContextError(ContextError::ConnectionError(
+                ConnectionError::VerifyConnectionState(_)
+            ))
+        ),
+        "{err:?}"
+    );
+}
@@ -1,13 +1,25 @@
+use core::time::Duration;
+
 use ibc::core::events::{IbcEvent, MessageEvent};
-use ibc::core::ics03_connection::connection::State;
+use ibc::core::ics02_client::error::ClientError;
+use ibc::core::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
+use ibc::core::ics03_connection::error::ConnectionError;
+use ibc::core::ics03_connection::handler::connection_proof_error;
 use ibc::core::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
 use ibc::core::ics03_connection::msgs::ConnectionMsg;
-use ibc::core::ics24_host::identifier::ChainId;
-use ibc::core::{execute, validate, MsgEnvelope, ValidationContext};
+use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::ics24_host::identifier::{ChainId, ClientId, ConnectionId};
+use ibc::core::ics24_host::path::ConnectionPath;
+use ibc::core::{execute, validate, ContextError, MsgEnvelope, RouterError, ValidationContext};
+use ibc::prelude::*;
+use ibc::proto::Protobuf;
 use ibc::Height;
 use ibc_testkit::hosts::block::HostType;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientBehavior;
+use ibc_testkit::testapp::ibc::core::merkle::{membership_proof, MerkleStore};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use ibc_testkit::utils::core::connection::dummy_msg_conn_open_try;
 use ibc_testkit::utils::{Expect, Fixture};
 use test_log::test;
@@ -145,3 +157,143 @@ fn conn_open_try_no_client() {
     let fxt = conn_open_try_fixture(Ctx::Default, Msg::Default);
     conn_open_try_validate(&fxt, Expect::Failure(None));
 }
+
+fn init_conn_end(delay_period: Duration) -> ConnectionEnd {
+    ConnectionEnd::new(
+        State::Init,
+        ClientId::default(),
+        Counterparty::new(
+            ClientId::default(),
+            None,
+            CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+        ),
+        get_compatible_versions(),
+        delay_period,
+    )
+    .unwrap()
+}
+
+/// Returns a membership proof of the connection end the counterparty
+/// committed to.
+fn conn_end_proof(committed_conn_end: &ConnectionEnd) -> CommitmentProofBytes {
+    let path = ConnectionPath::new(&ConnectionId::new(0)).to_string();
+    let mut store = MerkleStore::new();
+    store.set(path.clone(), committed_conn_end.clone().encode_vec());
+    membership_proof(&store, b"ibc", path.as_bytes()).unwrap()
+}
+
+/// Returns the error reported when the proof of `committed_conn_end` fails
+/// to verify against the expected connection end `expected_conn_end`.
+fn conn_end_proof_error(
+    committed_conn_end: &ConnectionEnd,
+    expected_conn_end: &ConnectionEnd,
+) -> ConnectionError {
+    connection_proof_error(
+        &conn_end_proof(committed_conn_end),
+        expected_conn_end,
+        ClientError::Other {
+            description: "proof verification failed".to_string(),
+        },
+    )
+}
+
+#[test]
+fn conn_open_try_delay_period_mismatch() {
+    let err = conn_end_proof_error(
+        &init_conn_end(Duration::from_secs(10)),
+        &init_conn_end(Duration::from_secs(20)),
+    );
+
+    assert!(
+        matches!(
+            err,
+            ConnectionError::DelayPeriodMismatch { expected, committed }
+                if expected == Duration::from_secs(20) && committed == Duration::from_secs(10)
+        ),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn conn_open_try_conn_end_mismatch() {
+    let mut committed_conn_end = init_conn_end(Duration::from_secs(10));
+    committed_conn_end.set_state(State::TryOpen);
+
+    let err = conn_end_proof_error(&committed_conn_end, &init_conn_end(Duration::from_secs(20)));
+
+    assert!(
+        matches!(err, ConnectionError::VerifyConnectionState(_)),
+        "{err:?}"
+    );
+}
+
+/// Validates a `ConnOpenTry` whose proof proves the connection end the
+/// handler expects, once changed by `commit`, on a host whose client of the
+/// counterparty fails to verify proofs.
+fn conn_open_try_validate_committed(commit: impl FnOnce(&mut ConnectionEnd)) -> RouterError {
+    let Fixture { ctx, mut msg } = conn_open_try_fixture(Ctx::WithClient, Msg::Default);
+
+    let client_chain_id = ctx.host_chain_id.clone();
+    let ctx = ctx.with_client_config(
+        MockClientConfig::builder()
+            .client_chain_id(client_chain_id)
+            .client_id(msg.client_id_on_b.clone())
+            .client_state_height(msg.proofs_height_on_a)
+            .behavior(
+                MockClientBehavior::builder()
+                    .fail_proof_verification(true)
+                    .build(),
+            )
+            .build(),
+    );
+
+    let mut committed_conn_end_on_a = ConnectionEnd::new(
+        State::Init,
+        msg.counterparty.client_id().clone(),
+        Counterparty::new(msg.client_id_on_b.clone(), None, ctx.commitment_prefix()),
+        msg.versions_on_a.clone(),
+        msg.delay_period,
+    )
+    .unwrap();
+    commit(&mut committed_conn_end_on_a);
+    msg.proof_conn_end_on_a = conn_end_proof(&committed_conn_end_on_a);
+
+    let router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(msg));
+    validate(&ctx, &router, msg_envelope).unwrap_err()
+}
+
+#[test]
+fn conn_open_try_handler_delay_period_mismatch() {
+    let err = conn_open_try_validate_committed(|conn_end| {
+        conn_end.set_delay_period(conn_end.delay_period() + Duration::from_secs(10))
+    });
+
+    assert!(
+        matches!(
+            err,
+            RouterError::ContextError(ContextError::ConnectionError(
+                ConnectionError::DelayPeriodMismatch { expected, committed }
+            )) if committed == expected + Duration::from_secs(10)
+        ),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn conn_open_try_handler_conn_end_mismatch() {
+    let err = conn_open_try_validate_committed(|conn_end| {
+        conn_end.set_delay_period(conn_end.delay_period() + Duration::from_secs(10));
+        conn_end.set_state(State::TryOpen);
+    });
+
+    assert!(
+        matches!(
+            err,
+            RouterError::ContextError(ContextError::ConnectionError(
+                ConnectionError::VerifyConnectionState(_)
+            ))
+        ),
+        "{err:?}"
+    );
+}
@@ -0,0 +1,500 @@
+use core::ops::{Add, Sub};
+use core::time::Duration;
+use std::sync::Arc;
+
+use ibc::core::events::IbcEvent;
+use ibc::core::ics02_client::ClientExecutionContext;
+use ibc::core::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
+use ibc::core::ics03_connection::error::ConnectionError;
+use ibc::core::ics03_connection::handler::{
+    conn_upgrade_ack, conn_upgrade_cancel, conn_upgrade_init, conn_upgrade_timeout,
+    conn_upgrade_try,
+};
+use ibc::core::ics03_connection::msgs::conn_upgrade_ack::MsgConnectionUpgradeAck;
+use ibc::core::ics03_connection::msgs::conn_upgrade_cancel::MsgConnectionUpgradeCancel;
+use ibc::core::ics03_connection::msgs::conn_upgrade_confirm::MsgConnectionUpgradeConfirm;
+use ibc::core::ics03_connection::msgs::conn_upgrade_init::MsgConnectionUpgradeInit;
+use ibc::core::ics03_connection::msgs::conn_upgrade_timeout::MsgConnectionUpgradeTimeout;
+use ibc::core::ics03_connection::msgs::conn_upgrade_try::MsgConnectionUpgradeTry;
+use ibc::core::ics03_connection::msgs::ConnectionMsg;
+use ibc::core::ics03_connection::upgrade::{ConnectionUpgrade, ConnectionUpgradeContext};
+use ibc::core::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::ics24_host::identifier::{ChainId, ClientId, ConnectionId};
+use ibc::core::ics24_host::path::{ClientStatePath, ConnectionUpgradePath};
+use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
+use ibc::core::{execute, validate, ContextError, MsgEnvelope, RouterError, ValidationContext};
+use ibc::prelude::*;
+use ibc::Height;
+use ibc_testkit::hosts::block::HostType;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientBehavior, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{FixedClock, MockContext};
+use ibc_testkit::utils::core::channel::dummy_proof;
+use ibc_testkit::utils::core::signer::dummy_account_id;
+use rstest::rstest;
+use test_log::test;
+
+const DELAY_PERIOD: Duration = Duration::from_secs(10);
+
+/// The fixed time of the hosts of these tests, which is also the timestamp of
+/// the consensus states of their clients.
+fn now() -> Timestamp {
+    Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap()
+}
+
+fn timeout_timestamp() -> Timestamp {
+    now().add(Duration::from_secs(3600)).unwrap()
+}
+
+fn past_timestamp() -> Timestamp {
+    now().sub(Duration::from_secs(1)).unwrap()
+}
+
+fn client_id() -> ClientId {
+    ClientId::new(mock_client_type(), 0).unwrap()
+}
+
+fn proof_height() -> Height {
+    Height::new(0, 10).unwrap()
+}
+
+fn proof() -> CommitmentProofBytes {
+    dummy_proof().try_into().unwrap()
+}
+
+/// Returns a context whose connection `conn_id` is open with `counterparty_conn_id`.
+fn ctx(conn_id: &ConnectionId, counterparty_conn_id: &ConnectionId) -> MockContext {
+    let ctx = MockContext::new_with_clock(
+        ChainId::new("mockgaia-0").unwrap(),
+        HostType::Mock,
+        5,
+        Height::new(0, 5).unwrap(),
+        Arc::new(FixedClock(now())),
+    )
+    .with_client(&client_id(), proof_height());
+    let conn_end = ConnectionEnd::new(
+        State::Open,
+        client_id(),
+        Counterparty::new(
+            client_id(),
+            Some(counterparty_conn_id.clone()),
+            CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+        ),
+        ValidationContext::get_compatible_versions(&ctx),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    ctx.with_connection(conn_id.clone(), conn_end)
+}
+
+/// Records `upgrade` as the upgrade in progress of `conn_id`.
+fn with_upgrade(
+    mut ctx: MockContext,
+    conn_id: &ConnectionId,
+    upgrade: ConnectionUpgrade,
+) -> MockContext {
+    ctx.store_connection_upgrade(&ConnectionUpgradePath::new(conn_id), upgrade)
+        .unwrap();
+    ctx
+}
+
+/// Programs the client of the connection to fail the verification of proofs.
+fn with_failing_proofs(mut ctx: MockContext) -> MockContext {
+    ctx.store_client_state(
+        ClientStatePath::new(&client_id()),
+        MockClientState::new(MockHeader::new(proof_height()).with_timestamp(now()))
+            .with_behavior(
+                MockClientBehavior::builder()
+                    .fail_proof_verification(true)
+                    .build(),
+            )
+            .into(),
+    )
+    .unwrap();
+    ctx
+}
+
+fn deliver(ctx: &mut MockContext, msg: impl Into<ConnectionMsg>) -> Result<(), RouterError> {
+    let mut router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(msg.into());
+
+    validate(ctx, &router, msg_envelope.clone())?;
+    execute(ctx, &mut router, msg_envelope)?;
+    Ok(())
+}
+
+fn msg_try(conn_id_on_b: &ConnectionId, timeout_timestamp: Timestamp) -> MsgConnectionUpgradeTry {
+    MsgConnectionUpgradeTry {
+        conn_id_on_b: conn_id_on_b.clone(),
+        delay_period: DELAY_PERIOD,
+        timeout_timestamp,
+        proof_upgrade_on_a: proof(),
+        proof_height_on_a: proof_height(),
+        signer: dummy_account_id(),
+    }
+}
+
+fn msg_ack(conn_id_on_a: &ConnectionId) -> MsgConnectionUpgradeAck {
+    MsgConnectionUpgradeAck {
+        conn_id_on_a: conn_id_on_a.clone(),
+        proof_upgrade_on_b: proof(),
+        proof_height_on_b: proof_height(),
+        signer: dummy_account_id(),
+    }
+}
+
+fn msg_confirm(conn_id_on_b: &ConnectionId) -> MsgConnectionUpgradeConfirm {
+    MsgConnectionUpgradeConfirm {
+        conn_id_on_b: conn_id_on_b.clone(),
+        proof_conn_end_on_a: proof(),
+        proof_height_on_a: proof_height(),
+        signer: dummy_account_id(),
+    }
+}
+
+fn msg_timeout(conn_id: &ConnectionId) -> MsgConnectionUpgradeTimeout {
+    MsgConnectionUpgradeTimeout {
+        conn_id: conn_id.clone(),
+        proof_conn_end_on_counterparty: proof(),
+        proof_height_on_counterparty: proof_height(),
+        signer: dummy_account_id(),
+    }
+}
+
+#[test]
+fn conn_upgrade_handshake() {
+    let conn_id_on_a = ConnectionId::new(0);
+    let conn_id_on_b = ConnectionId::new(1);
+    let mut ctx_a = ctx(&conn_id_on_a, &conn_id_on_b);
+    let mut ctx_b = ctx(&conn_id_on_b, &conn_id_on_a);
+
+    let msg_init = MsgConnectionUpgradeInit::new(
+        conn_id_on_a.clone(),
+        DELAY_PERIOD,
+        timeout_timestamp(),
+        dummy_account_id(),
+    );
+    deliver(&mut ctx_a, msg_init).unwrap();
+    assert_eq!(
+        ctx_a.connection_upgrade(&conn_id_on_a).unwrap(),
+        Some(ConnectionUpgrade::proposed(
+            DELAY_PERIOD,
+            timeout_timestamp()
+        ))
+    );
+    assert!(matches!(
+        ctx_a.events.last(),
+        Some(IbcEvent::UpgradeInitConnection(e)) if e.delay_period() == DELAY_PERIOD
+    ));
+
+    deliver(&mut ctx_b, msg_try(&conn_id_on_b, timeout_timestamp())).unwrap();
+    assert_eq!(
+        ctx_b.connection_upgrade(&conn_id_on_b).unwrap(),
+        Some(ConnectionUpgrade::accepted(
+            DELAY_PERIOD,
+            timeout_timestamp()
+        ))
+    );
+
+    deliver(&mut ctx_a, msg_ack(&conn_id_on_a)).unwrap();
+    assert_eq!(
+        ctx_a.connection_end(&conn_id_on_a).unwrap().delay_period(),
+        DELAY_PERIOD
+    );
+    assert_eq!(ctx_a.connection_upgrade(&conn_id_on_a).unwrap(), None);
+
+    deliver(&mut ctx_b, msg_confirm(&conn_id_on_b)).unwrap();
+    assert_eq!(
+        ctx_b.connection_end(&conn_id_on_b).unwrap().delay_period(),
+        DELAY_PERIOD
+    );
+    assert_eq!(ctx_b.connection_upgrade(&conn_id_on_b).unwrap(), None);
+    assert!(matches!(
+        ctx_b.events.last(),
+        Some(IbcEvent::UpgradeConfirmConnection(_))
+    ));
+}
+
+#[test]
+fn conn_upgrade_init_rejects_pending_and_unchanged_upgrades() {
+    let conn_id_on_a = ConnectionId::new(0);
+    let mut ctx_a = ctx(&conn_id_on_a, &ConnectionId::new(1));
+
+    let unchanged = MsgConnectionUpgradeInit::new(
+        conn_id_on_a.clone(),
+        ZERO_DURATION,
+        timeout_timestamp(),
+        dummy_account_id(),
+    );
+    assert!(matches!(
+        conn_upgrade_init::validate(&ctx_a, &unchanged),
+        Err(ContextError::ConnectionError(
+            ConnectionError::UnchangedUpgrade { .. }
+        ))
+    ));
+
+    let msg = MsgConnectionUpgradeInit::new(
+        conn_id_on_a,
+        DELAY_PERIOD,
+        timeout_timestamp(),
+        dummy_account_id(),
+    );
+    conn_upgrade_init::execute(&mut ctx_a, &msg).unwrap();
+    assert!(matches!(
+        conn_upgrade_init::validate(&ctx_a, &msg),
+        Err(ContextError::ConnectionError(
+            ConnectionError::UpgradeInProgress { .. }
+        ))
+    ));
+}
+
+#[test]
+fn conn_upgrade_init_rejects_missing_and_past_timeouts() {
+    let conn_id_on_a = ConnectionId::new(0);
+    let ctx_a = ctx(&conn_id_on_a, &ConnectionId::new(1));
+
+    let msg = MsgConnectionUpgradeInit::new(
+        conn_id_on_a.clone(),
+        DELAY_PERIOD,
+        Timestamp::none(),
+        dummy_account_id(),
+    );
+    assert!(matches!(
+        conn_upgrade_init::validate(&ctx_a, &msg),
+        Err(ContextError::ConnectionError(
+            ConnectionError::MissingUpgradeTimeout { .. }
+        ))
+    ));
+
+    let msg = MsgConnectionUpgradeInit::new(
+        conn_id_on_a,
+        DELAY_PERIOD,
+        past_timestamp(),
+        dummy_account_id(),
+    );
+    assert!(matches!(
+        conn_upgrade_init::validate(&ctx_a, &msg),
+        Err(ContextError::ConnectionError(
+            ConnectionError::UpgradeTimedOut { .. }
+        ))
+    ));
+}
+
+#[test]
+fn conn_upgrade_ack_requires_pending_upgrade() {
+    let conn_id_on_a = ConnectionId::new(0);
+    let ctx_a = ctx(&conn_id_on_a, &ConnectionId::new(1));
+
+    assert!(matches!(
+        conn_upgrade_ack::validate(&ctx_a, &msg_ack(&conn_id_on_a)),
+        Err(ContextError::ConnectionError(
+            ConnectionError::MissingUpgrade { .. }
+        ))
+    ));
+}
+
+#[test]
+fn conn_upgrade_try_rejects_bad_proof() {
+    let conn_id_on_b = ConnectionId::new(1);
+    let mut ctx_b = with_failing_proofs(ctx(&conn_id_on_b, &ConnectionId::new(0)));
+
+    let res = deliver(&mut ctx_b, msg_try(&conn_id_on_b, timeout_timestamp()));
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ConnectionError(
+                ConnectionError::VerifyConnectionUpgrade(_)
+            )))
+        ),
+        "{res:?}"
+    );
+    assert_eq!(ctx_b.connection_upgrade(&conn_id_on_b).unwrap(), None);
+}
+
+#[test]
+fn conn_upgrade_ack_rejects_bad_proof() {
+    let conn_id_on_a = ConnectionId::new(0);
+    let upgrade = ConnectionUpgrade::proposed(DELAY_PERIOD, timeout_timestamp());
+    let mut ctx_a = with_failing_proofs(with_upgrade(
+        ctx(&conn_id_on_a, &ConnectionId::new(1)),
+        &conn_id_on_a,
+        upgrade.clone(),
+    ));
+
+    let res = deliver(&mut ctx_a, msg_ack(&conn_id_on_a));
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ConnectionError(
+                ConnectionError::VerifyConnectionUpgrade(_)
+            )))
+        ),
+        "{res:?}"
+    );
+    assert_eq!(
+        ctx_a.connection_end(&conn_id_on_a).unwrap().delay_period(),
+        ZERO_DURATION
+    );
+    assert_eq!(
+        ctx_a.connection_upgrade(&conn_id_on_a).unwrap(),
+        Some(upgrade)
+    );
+}
+
+#[test]
+fn conn_upgrade_confirm_rejects_bad_proof() {
+    let conn_id_on_b = ConnectionId::new(1);
+    let mut ctx_b = with_failing_proofs(with_upgrade(
+        ctx(&conn_id_on_b, &ConnectionId::new(0)),
+        &conn_id_on_b,
+        ConnectionUpgrade::accepted(DELAY_PERIOD, timeout_timestamp()),
+    ));
+
+    let res = deliver(&mut ctx_b, msg_confirm(&conn_id_on_b));
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ConnectionError(
+                ConnectionError::VerifyConnectionState(_)
+            )))
+        ),
+        "{res:?}"
+    );
+    assert_eq!(
+        ctx_b.connection_end(&conn_id_on_b).unwrap().delay_period(),
+        ZERO_DURATION
+    );
+}
+
+#[test]
+fn conn_upgrade_rejects_steps_past_timeout() {
+    let conn_id_on_a = ConnectionId::new(0);
+    let conn_id_on_b = ConnectionId::new(1);
+
+    let ctx_b = ctx(&conn_id_on_b, &conn_id_on_a);
+    assert!(matches!(
+        conn_upgrade_try::validate(&ctx_b, &msg_try(&conn_id_on_b, past_timestamp())),
+        Err(ContextError::ConnectionError(
+            ConnectionError::UpgradeTimedOut { .. }
+        ))
+    ));
+
+    let ctx_a = with_upgrade(
+        ctx(&conn_id_on_a, &conn_id_on_b),
+        &conn_id_on_a,
+        ConnectionUpgrade::proposed(DELAY_PERIOD, past_timestamp()),
+    );
+    assert!(matches!(
+        conn_upgrade_ack::validate(&ctx_a, &msg_ack(&conn_id_on_a)),
+        Err(ContextError::ConnectionError(
+            ConnectionError::UpgradeTimedOut { .. }
+        ))
+    ));
+}
+
+#[rstest]
+#[case::proposer(ConnectionUpgrade::proposed(DELAY_PERIOD, past_timestamp()))]
+#[case::committer(ConnectionUpgrade::accepted(DELAY_PERIOD, past_timestamp()))]
+#[test_log::test]
+fn conn_upgrade_timeout_drops_timed_out_upgrade(#[case] upgrade: ConnectionUpgrade) {
+    let conn_id = ConnectionId::new(0);
+    let mut ctx = with_upgrade(ctx(&conn_id, &ConnectionId::new(1)), &conn_id, upgrade);
+
+    deliver(&mut ctx, msg_timeout(&conn_id)).unwrap();
+
+    assert_eq!(ctx.connection_upgrade(&conn_id).unwrap(), None);
+    assert_eq!(
+        ctx.connection_end(&conn_id).unwrap().delay_period(),
+        ZERO_DURATION
+    );
+    assert!(matches!(
+        ctx.events.last(),
+        Some(IbcEvent::UpgradeTimeoutConnection(e)) if e.delay_period() == DELAY_PERIOD
+    ));
+}
+
+#[test]
+fn conn_upgrade_timeout_rejects_upgrade_not_timed_out() {
+    let conn_id = ConnectionId::new(0);
+    let ctx = with_upgrade(
+        ctx(&conn_id, &ConnectionId::new(1)),
+        &conn_id,
+        ConnectionUpgrade::proposed(DELAY_PERIOD, timeout_timestamp()),
+    );
+
+    assert!(matches!(
+        conn_upgrade_timeout::validate(&ctx, &msg_timeout(&conn_id)),
+        Err(ContextError::ConnectionError(
+            ConnectionError::UpgradeNotTimedOut { .. }
+        ))
+    ));
+}
+
+#[test]
+fn conn_upgrade_timeout_rejects_bad_proof() {
+    let conn_id = ConnectionId::new(0);
+    let upgrade = ConnectionUpgrade::proposed(DELAY_PERIOD, past_timestamp());
+    let mut ctx = with_failing_proofs(with_upgrade(
+        ctx(&conn_id, &ConnectionId::new(1)),
+        &conn_id,
+        upgrade.clone(),
+    ));
+
+    let res = deliver(&mut ctx, msg_timeout(&conn_id));
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ConnectionError(
+                ConnectionError::VerifyConnectionState(_)
+            )))
+        ),
+        "{res:?}"
+    );
+    assert_eq!(ctx.connection_upgrade(&conn_id).unwrap(), Some(upgrade));
+}
+
+#[test]
+fn conn_upgrade_cancel_drops_proposed_upgrade_only() {
+    let conn_id_on_a = ConnectionId::new(0);
+    let conn_id_on_b = ConnectionId::new(1);
+    let msg_cancel = |conn_id_on_a: &ConnectionId| MsgConnectionUpgradeCancel {
+        conn_id_on_a: conn_id_on_a.clone(),
+        signer: dummy_account_id(),
+    };
+
+    let mut ctx_a = with_upgrade(
+        ctx(&conn_id_on_a, &conn_id_on_b),
+        &conn_id_on_a,
+        ConnectionUpgrade::proposed(DELAY_PERIOD, timeout_timestamp()),
+    );
+    deliver(&mut ctx_a, msg_cancel(&conn_id_on_a)).unwrap();
+    assert_eq!(ctx_a.connection_upgrade(&conn_id_on_a).unwrap(), None);
+    assert!(matches!(
+        ctx_a.events.last(),
+        Some(IbcEvent::UpgradeCancelConnection(_))
+    ));
+
+    // The end which committed to the upgrade of its counterparty may only
+    // drop it once timed out, as its counterparty may have applied it.
+    let ctx_b = with_upgrade(
+        ctx(&conn_id_on_b, &conn_id_on_a),
+        &conn_id_on_b,
+        ConnectionUpgrade::accepted(DELAY_PERIOD, timeout_timestamp()),
+    );
+    assert!(matches!(
+        conn_upgrade_cancel::validate(&ctx_b, &msg_cancel(&conn_id_on_b)),
+        Err(ContextError::ConnectionError(
+            ConnectionError::UpgradeProposedByCounterparty { .. }
+        ))
+    ));
+    assert!(matches!(
+        conn_upgrade_ack::validate(&ctx_b, &msg_ack(&conn_id_on_b)),
+        Err(ContextError::ConnectionError(
+            ConnectionError::UpgradeProposedByCounterparty { .. }
+        ))
+    ));
+}
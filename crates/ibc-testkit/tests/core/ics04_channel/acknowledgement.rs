@@ -6,13 +6,16 @@ use ibc::core::ics03_connection::connection::{
 };
 use ibc::core::ics03_connection::version::get_compatible_versions;
 use ibc::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
-use ibc::core::ics04_channel::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::ics04_channel::commitment::{
+    compute_ack_commitment, compute_packet_commitment, PacketCommitment,
+};
 use ibc::core::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
 use ibc::core::ics04_channel::msgs::PacketMsg;
+use ibc::core::ics04_channel::timeout_queue::PendingTimeout;
 use ibc::core::ics04_channel::Version;
 use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
-use ibc::core::{execute, validate, ExecutionContext, MsgEnvelope};
+use ibc::core::{execute, send_packet_execute, validate, ExecutionContext, MsgEnvelope};
 use ibc::prelude::*;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::MockContext;
@@ -211,6 +214,7 @@ fn ack_unordered_chan_execute(fixture: Fixture) {
             packet_commitment,
         );
 
+    let ack_commitment = compute_ack_commitment(&msg.acknowledgement);
     let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
 
     let res = execute(&mut ctx, &mut router, msg_envelope);
@@ -222,7 +226,10 @@ fn ack_unordered_chan_execute(fixture: Fixture) {
         ctx.events[0],
         IbcEvent::Message(MessageEvent::Channel)
     ));
-    assert!(matches!(ctx.events[1], IbcEvent::AcknowledgePacket(_)));
+    assert!(matches!(
+        &ctx.events[1],
+        IbcEvent::AcknowledgePacket(e) if e.ack_commitment() == &ack_commitment
+    ));
 }
 
 #[rstest]
@@ -263,3 +270,43 @@ fn ack_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ctx.events[1], IbcEvent::AcknowledgePacket(_)));
 }
+
+#[rstest]
+fn ack_execute_dequeues_packet_timeout(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        msg,
+        conn_end_on_a,
+        chan_end_on_a_unordered,
+        ..
+    } = fixture;
+    let mut ctx = ctx
+        .with_timeout_queue()
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::default(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_send_sequence(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+        );
+
+    send_packet_execute(&mut ctx, msg.packet.clone()).unwrap();
+
+    let timeout_queue = ctx.timeout_queue.as_ref().unwrap();
+    assert_eq!(
+        timeout_queue.iter().collect::<Vec<_>>(),
+        vec![&PendingTimeout::from(&msg.packet)]
+    );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution fails unexpectedly: {res:?}");
+    assert!(ctx.timeout_queue.unwrap().is_empty());
+}
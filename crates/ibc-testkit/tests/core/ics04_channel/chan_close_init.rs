@@ -1,19 +1,32 @@
-use ibc::core::events::{IbcEvent, MessageEvent};
+use ibc::core::events::{IbcEvent, MessageEvent, ModuleEvent};
+use ibc::core::ics02_client::ClientExecutionContext;
 use ibc::core::ics03_connection::connection::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::acknowledgement::Acknowledgement;
 use ibc::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
-use ibc::core::ics04_channel::msgs::{ChannelMsg, MsgChannelCloseInit};
+use ibc::core::ics04_channel::commitment::compute_packet_commitment;
+use ibc::core::ics04_channel::error::{ChannelCloseVeto, ChannelError, PacketError};
+use ibc::core::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+use ibc::core::ics04_channel::msgs::{ChannelMsg, MsgChannelCloseInit, PacketMsg};
+use ibc::core::ics04_channel::packet::Packet;
 use ibc::core::ics04_channel::Version;
-use ibc::core::ics24_host::identifier::{ClientId, ConnectionId};
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::{ChannelEndPath, CommitmentPath};
+use ibc::core::router::{Module, ModuleExtras, ModuleId};
 use ibc::core::timestamp::ZERO_DURATION;
-use ibc::core::{execute, validate, MsgEnvelope, ValidationContext};
+use ibc::core::{
+    execute, validate, ContextError, ExecutionContext, MsgEnvelope, RouterError, ValidationContext,
+};
 use ibc::prelude::*;
+use ibc::Signer;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::MockContext;
-use ibc_testkit::utils::core::channel::dummy_raw_msg_chan_close_init;
+use ibc_testkit::utils::core::channel::{
+    dummy_raw_msg_chan_close_init, dummy_raw_msg_timeout_on_close,
+};
 use ibc_testkit::utils::core::connection::dummy_raw_counterparty_conn;
 
 #[test]
@@ -131,3 +144,293 @@ fn test_chan_close_init_execute() {
 
     assert!(matches!(context.events[1], IbcEvent::CloseInitChannel(_)));
 }
+
+/// A module closing its channels unless it vetoes it, and emitting a `refund`
+/// event for each packet it is told timed out.
+#[derive(Clone, Debug, Default)]
+struct ClosingModule {
+    veto: Option<ChannelCloseVeto>,
+}
+
+impl Module for ClosingModule {
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), version.clone()))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), counterparty_version.clone()))
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        match &self.veto {
+            Some(veto) => Err(veto.clone().into()),
+            None => Ok(()),
+        }
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.on_chan_close_init_validate(port_id, channel_id)?;
+
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        (
+            ModuleExtras::empty(),
+            Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+        )
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let refund = ModuleEvent {
+            kind: "refund".to_string(),
+            attributes: vec![("sequence", packet.seq_on_a).into()],
+        };
+
+        (
+            ModuleExtras {
+                events: vec![refund],
+                log: Vec::new(),
+            },
+            Ok(()),
+        )
+    }
+}
+
+/// A router binding the transfer port to `module`.
+fn router_with(module: ClosingModule) -> MockRouter {
+    let module_id = ModuleId::new("closing".to_string());
+    let mut router = MockRouter::default();
+    router.add_route(module_id.clone(), module).unwrap();
+    router
+        .scope_port_to_module(PortId::transfer(), module_id)
+        .unwrap();
+    router
+}
+
+/// A host with an open channel on the transfer port, along with the message
+/// closing it.
+fn host_closing_channel() -> (MockContext, MsgChannelCloseInit) {
+    let client_id = ClientId::new(mock_client_type(), 24).unwrap();
+    let conn_id = ConnectionId::new(2);
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg = MsgChannelCloseInit::try_from(dummy_raw_msg_chan_close_init()).unwrap();
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::default(),
+        Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+        vec![conn_id.clone()],
+        Version::default(),
+    )
+    .unwrap();
+
+    let default_context = MockContext::default();
+    let client_consensus_state_height = default_context.host_height().unwrap();
+
+    let context = default_context
+        .with_client(&client_id, client_consensus_state_height)
+        .with_connection(conn_id, conn_end)
+        .with_channel(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), chan_end);
+
+    (context, msg)
+}
+
+fn channel_state(ctx: &MockContext, msg: &MsgChannelCloseInit) -> ChannelState {
+    ctx.channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap()
+        .state
+}
+
+fn has_commitment(ctx: &MockContext, packet: &Packet) -> bool {
+    ctx.get_packet_commitment(&CommitmentPath::new(
+        &packet.port_id_on_a,
+        &packet.chan_id_on_a,
+        packet.seq_on_a,
+    ))
+    .is_ok()
+}
+
+#[test]
+fn test_chan_close_init_vetoed_by_module() {
+    let (mut context, msg) = host_closing_channel();
+    let mut router = router_with(ClosingModule {
+        veto: Some(ChannelCloseVeto::NotAllowed),
+    });
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ChannelError(
+                ChannelError::CloseVetoed(ChannelCloseVeto::NotAllowed)
+            )))
+        ),
+        "{res:?}"
+    );
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ChannelError(
+                ChannelError::CloseVetoed(ChannelCloseVeto::NotAllowed)
+            )))
+        ),
+        "{res:?}"
+    );
+    assert_eq!(channel_state(&context, &msg), ChannelState::Open);
+}
+
+#[test]
+fn test_chan_close_init_refunds_packets_in_flight_on_timeout_on_close() {
+    let (context, msg) = host_closing_channel();
+    let mut router = router_with(ClosingModule::default());
+
+    // A packet sent on the channel, which the counterparty may or may not
+    // have received by the time the channel closes.
+    let proof_height = context.host_height().unwrap();
+    let msg_timeout = MsgTimeoutOnClose::try_from(dummy_raw_msg_timeout_on_close(
+        proof_height.revision_height(),
+        5,
+    ))
+    .unwrap();
+    let packet = msg_timeout.packet.clone();
+    let mut context = context.with_packet_commitment(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        packet.seq_on_a,
+        compute_packet_commitment(
+            &packet.data,
+            &packet.timeout_height_on_b,
+            &packet.timeout_timestamp_on_b,
+        ),
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+    validate(&context, &router, msg_envelope.clone()).unwrap();
+    execute(&mut context, &mut router, msg_envelope).unwrap();
+
+    // Closing the channel leaves the packet in flight, as refunding it now
+    // would pay twice were it received.
+    assert_eq!(channel_state(&context, &msg), ChannelState::Closed);
+    assert!(has_commitment(&context, &packet));
+    assert!(!context
+        .events
+        .iter()
+        .any(|event| matches!(event, IbcEvent::Module(event) if event.kind == "refund")));
+
+    // Once the counterparty proves it closed the channel without receiving
+    // the packet, the module refunds it.
+    let client_id = ClientId::new(mock_client_type(), 24).unwrap();
+    let host_timestamp = context.host_timestamp().unwrap();
+    let client_ctx = context.get_client_execution_context();
+    client_ctx
+        .store_update_time(client_id.clone(), proof_height, host_timestamp)
+        .unwrap();
+    client_ctx
+        .store_update_height(client_id, proof_height, proof_height)
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg_timeout));
+    validate(&context, &router, msg_envelope.clone()).unwrap();
+    execute(&mut context, &mut router, msg_envelope).unwrap();
+
+    assert!(!has_commitment(&context, &packet));
+    assert!(context
+        .events
+        .iter()
+        .any(|event| matches!(event, IbcEvent::Module(event) if event.kind == "refund")));
+}
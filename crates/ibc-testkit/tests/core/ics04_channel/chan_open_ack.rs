@@ -10,7 +10,7 @@ use ibc::core::ics04_channel::msgs::ChannelMsg;
 use ibc::core::ics24_host::identifier::{ClientId, ConnectionId};
 use ibc::core::router::ModuleId;
 use ibc::core::timestamp::ZERO_DURATION;
-use ibc::core::{execute, validate, MsgEnvelope};
+use ibc::core::{execute, validate, MsgEnvelope, ValidationContext};
 use ibc::prelude::*;
 use ibc::Height;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
@@ -143,6 +143,64 @@ fn chan_open_ack_execute_happy_path(fixture: Fixture) {
     assert!(matches!(context.events[1], IbcEvent::OpenAckChannel(_)));
 }
 
+#[rstest]
+fn chan_open_ack_counterparty_reverse_lookup(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+        proof_height,
+        ..
+    } = fixture;
+
+    let client_id_on_b = conn_end_on_a.counterparty().client_id().clone();
+    let conn_id_on_b = conn_end_on_a
+        .counterparty()
+        .connection_id()
+        .cloned()
+        .unwrap();
+
+    let mut context = context
+        .with_client(&client_id_on_a, Height::new(0, proof_height).unwrap())
+        .with_connection(conn_id_on_a.clone(), conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    assert_eq!(
+        context
+            .connection_id_by_counterparty(&client_id_on_b, &conn_id_on_b)
+            .unwrap(),
+        Some(conn_id_on_a)
+    );
+    assert_eq!(
+        context
+            .channel_id_by_counterparty(&client_id_on_b, &msg.port_id_on_a, &msg.chan_id_on_b)
+            .unwrap(),
+        Some((msg.port_id_on_a.clone(), msg.chan_id_on_a.clone()))
+    );
+
+    let unknown_client_id = ClientId::new(mock_client_type(), 99).unwrap();
+    assert_eq!(
+        context
+            .channel_id_by_counterparty(&unknown_client_id, &msg.port_id_on_a, &msg.chan_id_on_b)
+            .unwrap(),
+        None
+    );
+}
+
 #[rstest]
 fn chan_open_ack_fail_no_connection(fixture: Fixture) {
     let Fixture {
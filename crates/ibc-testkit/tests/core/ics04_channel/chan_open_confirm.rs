@@ -12,9 +12,11 @@ use ibc::core::timestamp::ZERO_DURATION;
 use ibc::core::{execute, validate, MsgEnvelope};
 use ibc::prelude::*;
 use ibc::Height;
-use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientBehavior,
+};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use ibc_testkit::utils::core::channel::dummy_raw_msg_chan_open_confirm;
 use ibc_testkit::utils::core::connection::dummy_raw_counterparty_conn;
 use rstest::*;
@@ -207,3 +209,48 @@ fn chan_open_confirm_fail_channel_wrong_state(fixture: Fixture) {
         "Validation fails because channel is in the wrong state"
     )
 }
+
+#[rstest]
+fn chan_open_confirm_fail_proof_verification(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        client_id_on_b,
+        conn_id_on_b,
+        conn_end_on_b,
+        chan_end_on_b,
+        proof_height,
+        ..
+    } = fixture;
+
+    let client_chain_id = context.host_chain_id.clone();
+    let context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(client_chain_id)
+                .client_id(client_id_on_b)
+                .client_state_height(Height::new(0, proof_height).unwrap())
+                .behavior(
+                    MockClientBehavior::builder()
+                        .fail_proof_verification(true)
+                        .build(),
+                )
+                .build(),
+        )
+        .with_connection(conn_id_on_b, conn_end_on_b)
+        .with_channel(
+            msg.port_id_on_b.clone(),
+            ChannelId::default(),
+            chan_end_on_b,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the proof of the channel end does not verify"
+    )
+}
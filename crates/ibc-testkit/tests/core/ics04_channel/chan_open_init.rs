@@ -5,8 +5,8 @@ use ibc::core::ics03_connection::connection::{ConnectionEnd, State as Connection
 use ibc::core::ics03_connection::version::get_compatible_versions;
 use ibc::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
 use ibc::core::ics04_channel::msgs::ChannelMsg;
-use ibc::core::ics24_host::identifier::{ClientId, ConnectionId};
-use ibc::core::{execute, validate, MsgEnvelope, ValidationContext};
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId};
+use ibc::core::{execute, validate, MsgEnvelope, MsgOutput, ValidationContext};
 use ibc::prelude::*;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::MockContext;
@@ -100,7 +100,22 @@ fn chan_open_init_execute_happy_path(fixture: Fixture) {
         ctx.events[0],
         IbcEvent::Message(MessageEvent::Channel)
     ));
-    assert!(matches!(ctx.events[1], IbcEvent::OpenInitChannel(_)));
+    let event = match &ctx.events[1] {
+        IbcEvent::OpenInitChannel(event) => event,
+        event => panic!("unexpected event: {event:?}"),
+    };
+
+    let output = res.unwrap();
+    assert_eq!(output.events, ctx.events);
+
+    match output.result {
+        MsgOutput::ChanOpenInit(output) => {
+            assert_eq!(output.chan_id_on_a, ChannelId::new(0));
+            assert_eq!(&output.chan_id_on_a, event.chan_id_on_a());
+            assert_eq!(&output.version, event.version_on_a());
+        }
+        output => panic!("unexpected output: {output:?}"),
+    }
 }
 
 #[rstest]
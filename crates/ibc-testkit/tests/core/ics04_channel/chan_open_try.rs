@@ -3,17 +3,18 @@ use ibc::core::ics03_connection::connection::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::channel::State;
 use ibc::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
 use ibc::core::ics04_channel::msgs::ChannelMsg;
-use ibc::core::ics24_host::identifier::{ClientId, ConnectionId};
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId};
 use ibc::core::timestamp::ZERO_DURATION;
-use ibc::core::{execute, validate, MsgEnvelope, ValidationContext};
+use ibc::core::{execute, validate, MsgEnvelope, MsgOutput, ValidationContext};
 use ibc::prelude::*;
 use ibc::Height;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::MockContext;
-use ibc_testkit::utils::core::channel::dummy_raw_msg_chan_open_try;
+use ibc_testkit::utils::core::channel::{ChannelEndConfig, MsgChannelOpenTryConfig};
 use ibc_testkit::utils::core::connection::dummy_raw_counterparty_conn;
 use rstest::*;
 use test_log::test;
@@ -44,13 +45,20 @@ fn fixture() -> Fixture {
     )
     .unwrap();
 
-    // We're going to test message processing against this message.
-    // Note: we make the counterparty's channel_id `None`.
-    let mut msg_chan_open_try =
-        MsgChannelOpenTry::try_from(dummy_raw_msg_chan_open_try(proof_height)).unwrap();
-
-    let hops = vec![conn_id_on_b.clone()];
-    msg_chan_open_try.connection_hops_on_b = hops;
+    // We're going to test message processing against this message, for a
+    // channel over the connection above.
+    let chan_end_on_b = ChannelEndConfig::builder()
+        .state(State::TryOpen)
+        .counterparty_channel_id(ChannelId::default())
+        .connection_hops(vec![conn_id_on_b.clone()])
+        .build();
+    let msg_chan_open_try = MsgChannelOpenTry::try_from(
+        MsgChannelOpenTryConfig::builder()
+            .chan_end_on_b(chan_end_on_b)
+            .proof_height_on_a(Height::new(0, proof_height).unwrap())
+            .build(),
+    )
+    .unwrap();
 
     let msg = MsgEnvelope::from(ChannelMsg::from(msg_chan_open_try));
 
@@ -120,7 +128,22 @@ fn chan_open_try_execute_happy_path(fixture: Fixture) {
         ctx.events[0],
         IbcEvent::Message(MessageEvent::Channel)
     ));
-    assert!(matches!(ctx.events[1], IbcEvent::OpenTryChannel(_)));
+    let event = match &ctx.events[1] {
+        IbcEvent::OpenTryChannel(event) => event,
+        event => panic!("unexpected event: {event:?}"),
+    };
+
+    let output = res.unwrap();
+    assert_eq!(output.events, ctx.events);
+
+    match output.result {
+        MsgOutput::ChanOpenTry(output) => {
+            assert_eq!(output.chan_id_on_b, ChannelId::new(0));
+            assert_eq!(&output.chan_id_on_b, event.chan_id_on_b());
+            assert_eq!(&output.version, event.version_on_b());
+        }
+        output => panic!("unexpected output: {output:?}"),
+    }
 }
 
 #[rstest]
@@ -0,0 +1,115 @@
+use ibc::core::ics03_connection::connection::State as ConnectionState;
+use ibc::core::ics04_channel::channel::Order;
+use ibc::core::ics04_channel::invariants::{
+    check_channel_invariants, check_invariants, InvariantViolation,
+};
+use ibc::core::ics04_channel::packet::{Receipt, Sequence};
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::{AckPath, ChannelEndPath, ReceiptPath};
+use ibc::core::ExecutionContext;
+use ibc::prelude::*;
+use ibc::Height;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::builder::{ChannelConfig, TestChainBuilder};
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+fn context(ordering: Order) -> (MockContext, ChannelEndPath) {
+    let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+    let conn_id = ConnectionId::new(0);
+    let chan_id = ChannelId::new(0);
+
+    let ctx = TestChainBuilder::default()
+        .client(client_id.clone(), Height::new(0, 5).unwrap())
+        .connection(conn_id.clone(), client_id, ConnectionState::Open)
+        .channel_config(
+            ChannelConfig::builder()
+                .chan_id(chan_id.clone())
+                .conn_id(conn_id)
+                .ordering(ordering)
+                .unacked_packets(2)
+                .build(),
+        )
+        .build();
+
+    (ctx, ChannelEndPath::new(&PortId::transfer(), &chan_id))
+}
+
+fn store_ack(ctx: &mut MockContext, channel_end_path: &ChannelEndPath, seq: u64) {
+    let ChannelEndPath(port_id, chan_id) = channel_end_path;
+    ctx.store_packet_acknowledgement(
+        &AckPath::new(port_id, chan_id, seq.into()),
+        vec![1; 32].into(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn unordered_channel_invariants() {
+    let (mut ctx, channel_end_path) = context(Order::Unordered);
+    assert!(check_channel_invariants(&ctx, &channel_end_path)
+        .unwrap()
+        .is_empty());
+
+    store_ack(&mut ctx, &channel_end_path, 7);
+
+    let violations = check_invariants(&ctx, [channel_end_path.clone()]).unwrap();
+    assert_eq!(
+        violations,
+        vec![(
+            channel_end_path,
+            InvariantViolation::AckWithoutReceipt {
+                sequence: Sequence::from(7)
+            }
+        )]
+    );
+}
+
+#[test]
+fn unordered_bitmap_channel_invariants() {
+    let (ctx, channel_end_path) = context(Order::Unordered);
+    let ChannelEndPath(port_id, chan_id) = channel_end_path.clone();
+    let mut ctx = ctx.with_receipt_bitmap(port_id.clone(), chan_id.clone());
+
+    ctx.store_packet_receipt(&ReceiptPath::new(&port_id, &chan_id, 7.into()), Receipt::Ok)
+        .unwrap();
+    store_ack(&mut ctx, &channel_end_path, 7);
+    assert!(check_channel_invariants(&ctx, &channel_end_path)
+        .unwrap()
+        .is_empty());
+
+    store_ack(&mut ctx, &channel_end_path, 8);
+    assert_eq!(
+        check_channel_invariants(&ctx, &channel_end_path).unwrap(),
+        vec![InvariantViolation::AckWithoutReceipt {
+            sequence: Sequence::from(8)
+        }]
+    );
+}
+
+#[test]
+fn ordered_channel_invariants() {
+    let (ctx, channel_end_path) = context(Order::Ordered);
+    assert!(check_channel_invariants(&ctx, &channel_end_path)
+        .unwrap()
+        .is_empty());
+
+    let ChannelEndPath(port_id, chan_id) = channel_end_path.clone();
+    let mut ctx = ctx.with_packet_commitment(port_id, chan_id, 3.into(), vec![0; 32].into());
+    store_ack(&mut ctx, &channel_end_path, 1);
+
+    let violations = check_channel_invariants(&ctx, &channel_end_path).unwrap();
+    assert_eq!(
+        violations,
+        vec![
+            InvariantViolation::CommitmentNotSent {
+                sequence: 3.into(),
+                next_seq_send: 3.into(),
+            },
+            InvariantViolation::AckNotReceived {
+                sequence: 1.into(),
+                next_seq_recv: 1.into(),
+            },
+        ]
+    );
+}
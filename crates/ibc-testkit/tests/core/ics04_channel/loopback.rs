@@ -0,0 +1,289 @@
+use ibc::core::events::IbcEvent;
+use ibc::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::ics04_channel::error::PacketError;
+use ibc::core::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
+use ibc::core::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
+use ibc::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+use ibc::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use ibc::core::ics04_channel::msgs::recv_packet::RecvOutcome;
+use ibc::core::ics04_channel::msgs::ChannelMsg;
+use ibc::core::ics04_channel::packet::Packet;
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::ics04_channel::Version;
+use ibc::core::ics23_commitment::commitment::CommitmentProofBytes;
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::{AckPath, ChannelEndPath, CommitmentPath, ReceiptPath};
+use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
+use ibc::core::{
+    dispatch, loopback_packet, send_packet, send_packet_with_loopback, ContextError, MsgEnvelope,
+    RouterError, ValidationContext,
+};
+use ibc::prelude::*;
+use ibc::Height;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::utils::core::signer::dummy_account_id;
+use test_log::test;
+
+/// A context with a connection of the given client, whose counterparty is the
+/// connection itself.
+fn connection_context(client_id: &ClientId) -> MockContext {
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    MockContext::default()
+        .with_client(client_id, Height::new(0, 1).unwrap())
+        .with_connection(ConnectionId::default(), conn_end)
+}
+
+/// A context with channels `channel-0` and `channel-1` of the transfer port,
+/// the counterparties of each other, over a connection of the given client.
+fn loopback_context(client_id: &ClientId) -> MockContext {
+    let chan_end = |counterparty_chan_id: ChannelId| {
+        ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(counterparty_chan_id)),
+            vec![ConnectionId::default()],
+            Version::new("ics20-1".to_string()),
+        )
+        .unwrap()
+    };
+
+    connection_context(client_id)
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::new(0),
+            chan_end(ChannelId::new(1)),
+        )
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::new(1),
+            chan_end(ChannelId::new(0)),
+        )
+        .with_send_sequence(PortId::transfer(), ChannelId::new(0), 1.into())
+}
+
+fn loopback_packet_on_channel_0() -> Packet {
+    Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: vec![1],
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 1000).unwrap()),
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+#[test]
+fn loopback_packet_happy_path() {
+    let mut ctx = loopback_context(&ClientId::localhost());
+    let mut router = MockRouter::new_with_transfer();
+    let packet = loopback_packet_on_channel_0();
+
+    send_packet(&mut ctx, packet.clone()).unwrap();
+
+    let outcome = loopback_packet(&mut ctx, &mut router, packet.clone(), &dummy_account_id());
+    assert_eq!(outcome.unwrap(), RecvOutcome::Received);
+
+    // received and acknowledged on channel-1
+    assert!(ctx
+        .get_packet_receipt(&ReceiptPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            packet.seq_on_a
+        ))
+        .is_ok());
+    assert!(ctx
+        .get_packet_acknowledgement(&AckPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            packet.seq_on_a
+        ))
+        .is_ok());
+
+    // the acknowledgement settled the packet on channel-0
+    assert!(ctx
+        .get_packet_commitment(&CommitmentPath::new(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a
+        ))
+        .is_err());
+
+    let packet_events: Vec<_> = ctx
+        .events
+        .iter()
+        .filter(|event| !matches!(event, IbcEvent::Message(_)))
+        .collect();
+    assert!(matches!(
+        packet_events.as_slice(),
+        [
+            IbcEvent::SendPacket(_),
+            IbcEvent::ReceivePacket(_),
+            IbcEvent::WriteAcknowledgement(_),
+            IbcEvent::AcknowledgePacket(_),
+        ]
+    ));
+}
+
+#[test]
+fn loopback_packet_fail_not_sent() {
+    let mut ctx = loopback_context(&ClientId::localhost());
+    let mut router = MockRouter::new_with_transfer();
+
+    let res = loopback_packet(
+        &mut ctx,
+        &mut router,
+        loopback_packet_on_channel_0(),
+        &dummy_account_id(),
+    );
+
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::PacketError(
+                PacketError::PacketCommitmentNotFound { .. }
+            )))
+        ),
+        "{res:?}"
+    );
+}
+
+#[test]
+fn loopback_packet_fail_not_localhost_channel() {
+    let mut ctx = loopback_context(&ClientId::default());
+    let mut router = MockRouter::new_with_transfer();
+    let packet = loopback_packet_on_channel_0();
+
+    send_packet(&mut ctx, packet.clone()).unwrap();
+
+    let res = loopback_packet(&mut ctx, &mut router, packet, &dummy_account_id());
+
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::PacketError(
+                PacketError::NotLocalhostChannel { .. }
+            )))
+        ),
+        "{res:?}"
+    );
+}
+
+/// Opens `channel-0` and `channel-1` of the transfer port on the localhost
+/// connection through `dispatch`, then sends a packet on `channel-0`, which is
+/// looped back to `channel-1` along with its acknowledgement.
+#[test]
+fn send_packet_with_loopback_on_channel_opened_through_dispatch() {
+    let mut ctx = connection_context(&ClientId::localhost());
+    let mut router = MockRouter::new_with_transfer();
+    let proof_height = Height::new(0, 1).unwrap();
+    let proof = || CommitmentProofBytes::try_from(vec![1]).unwrap();
+    let version = Version::new("ics20-1".to_string());
+
+    let msgs = [
+        ChannelMsg::OpenInit(MsgChannelOpenInit {
+            port_id_on_a: PortId::transfer(),
+            connection_hops_on_a: vec![ConnectionId::default()],
+            port_id_on_b: PortId::transfer(),
+            ordering: Order::Unordered,
+            signer: dummy_account_id(),
+            version_proposal: version.clone(),
+        }),
+        #[allow(deprecated)]
+        ChannelMsg::OpenTry(MsgChannelOpenTry {
+            port_id_on_b: PortId::transfer(),
+            connection_hops_on_b: vec![ConnectionId::default()],
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            version_supported_on_a: version.clone(),
+            proof_chan_end_on_a: proof(),
+            proof_height_on_a: proof_height,
+            ordering: Order::Unordered,
+            signer: dummy_account_id(),
+            version_proposal: Version::empty(),
+        }),
+        ChannelMsg::OpenAck(MsgChannelOpenAck {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            chan_id_on_b: ChannelId::new(1),
+            version_on_b: version,
+            proof_chan_end_on_b: proof(),
+            proof_height_on_b: proof_height,
+            signer: dummy_account_id(),
+        }),
+        ChannelMsg::OpenConfirm(MsgChannelOpenConfirm {
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            proof_chan_end_on_a: proof(),
+            proof_height_on_a: proof_height,
+            signer: dummy_account_id(),
+        }),
+    ];
+    for msg in msgs {
+        dispatch(&mut ctx, &mut router, MsgEnvelope::from(msg)).unwrap();
+    }
+    for chan_id in [ChannelId::new(0), ChannelId::new(1)] {
+        let chan_end = ctx
+            .channel_end(&ChannelEndPath::new(&PortId::transfer(), &chan_id))
+            .unwrap();
+        assert_eq!(chan_end.state, State::Open);
+    }
+
+    let packet = loopback_packet_on_channel_0();
+    let outcome =
+        send_packet_with_loopback(&mut ctx, &mut router, packet.clone(), &dummy_account_id());
+    assert_eq!(outcome.unwrap(), Some(RecvOutcome::Received));
+
+    assert!(ctx
+        .get_packet_acknowledgement(&AckPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            packet.seq_on_a
+        ))
+        .is_ok());
+    assert!(ctx
+        .get_packet_commitment(&CommitmentPath::new(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a
+        ))
+        .is_err());
+}
+
+/// Packets sent to other chains are left to relayers.
+#[test]
+fn send_packet_with_loopback_not_localhost_channel() {
+    let mut ctx = loopback_context(&ClientId::default());
+    let mut router = MockRouter::new_with_transfer();
+    let packet = loopback_packet_on_channel_0();
+
+    let outcome =
+        send_packet_with_loopback(&mut ctx, &mut router, packet.clone(), &dummy_account_id());
+    assert_eq!(outcome.unwrap(), None);
+
+    assert!(ctx
+        .get_packet_commitment(&CommitmentPath::new(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a
+        ))
+        .is_ok());
+}
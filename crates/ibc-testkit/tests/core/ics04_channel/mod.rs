@@ -5,6 +5,10 @@ pub mod chan_open_ack;
 pub mod chan_open_confirm;
 pub mod chan_open_init;
 pub mod chan_open_try;
+pub mod invariants;
+pub mod loopback;
+pub mod model;
+pub mod query;
 pub mod recv_packet;
 pub mod send_packet;
 pub mod timeout;
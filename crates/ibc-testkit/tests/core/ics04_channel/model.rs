@@ -0,0 +1,160 @@
+//! Checks that the channel handlers agree with the executable channel model of
+//! `ibc_testkit::utils::channel_model` on random interleavings of handshake
+//! and closing steps submitted on two chains.
+
+use ibc::core::ics03_connection::connection::State as ConnectionState;
+use ibc::core::ics04_channel::channel::{Order, State as ChannelState};
+use ibc::core::ics04_channel::msgs::{
+    ChannelMsg, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry,
+};
+use ibc::core::ics04_channel::Version;
+use ibc::core::ics23_commitment::commitment::CommitmentProofBytes;
+use ibc::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::ChannelEndPath;
+use ibc::core::{dispatch, MsgEnvelope, ValidationContext};
+use ibc::prelude::*;
+use ibc::Height;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::builder::TestChainBuilder;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::utils::channel_model::{arb_channel_steps, ChannelModel, ChannelStep, Side};
+use ibc_testkit::utils::core::signer::dummy_account_id;
+use proptest::prelude::*;
+
+/// Each chain tracks the other with a mock client, and the single channel of
+/// the model is the first one opened over the connection between them.
+fn client_id() -> ClientId {
+    ClientId::new(mock_client_type(), 0).unwrap()
+}
+
+fn client_height() -> Height {
+    Height::new(0, 10).unwrap()
+}
+
+fn chan_id() -> ChannelId {
+    ChannelId::new(0)
+}
+
+fn version() -> Version {
+    Version::new("ics20-1".to_string())
+}
+
+fn chain(chain_id: &str) -> MockContext {
+    TestChainBuilder::new(ChainId::new(chain_id).unwrap())
+        .client(client_id(), client_height())
+        .connection(ConnectionId::new(0), client_id(), ConnectionState::Open)
+        .build()
+}
+
+/// Builds the message submitting `step` on one chain. Mock clients accept any
+/// proof, so proofs only need to be non-empty.
+#[allow(deprecated)]
+fn msg(step: ChannelStep) -> ChannelMsg {
+    let proof = CommitmentProofBytes::try_from(b"proof".to_vec()).unwrap();
+
+    match step {
+        ChannelStep::OpenInit => MsgChannelOpenInit {
+            port_id_on_a: PortId::transfer(),
+            connection_hops_on_a: vec![ConnectionId::new(0)],
+            port_id_on_b: PortId::transfer(),
+            ordering: Order::Unordered,
+            signer: dummy_account_id(),
+            version_proposal: version(),
+        }
+        .into(),
+        ChannelStep::OpenTry => MsgChannelOpenTry {
+            port_id_on_b: PortId::transfer(),
+            connection_hops_on_b: vec![ConnectionId::new(0)],
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: chan_id(),
+            version_supported_on_a: version(),
+            proof_chan_end_on_a: proof,
+            proof_height_on_a: client_height(),
+            ordering: Order::Unordered,
+            signer: dummy_account_id(),
+            version_proposal: Version::empty(),
+        }
+        .into(),
+        ChannelStep::OpenAck => MsgChannelOpenAck {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: chan_id(),
+            chan_id_on_b: chan_id(),
+            version_on_b: version(),
+            proof_chan_end_on_b: proof,
+            proof_height_on_b: client_height(),
+            signer: dummy_account_id(),
+        }
+        .into(),
+        ChannelStep::OpenConfirm => MsgChannelOpenConfirm {
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: chan_id(),
+            proof_chan_end_on_a: proof,
+            proof_height_on_a: client_height(),
+            signer: dummy_account_id(),
+        }
+        .into(),
+        ChannelStep::CloseInit => MsgChannelCloseInit {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: chan_id(),
+            signer: dummy_account_id(),
+        }
+        .into(),
+        ChannelStep::CloseConfirm => MsgChannelCloseConfirm {
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: chan_id(),
+            proof_chan_end_on_a: proof,
+            proof_height_on_a: client_height(),
+            signer: dummy_account_id(),
+        }
+        .into(),
+    }
+}
+
+fn channel_state(ctx: &MockContext) -> Option<ChannelState> {
+    ctx.channel_end(&ChannelEndPath::new(&PortId::transfer(), &chan_id()))
+        .ok()
+        .map(|chan_end| *chan_end.state())
+}
+
+proptest! {
+    #[test]
+    fn channel_handlers_agree_with_model(steps in arb_channel_steps(24)) {
+        let mut model = ChannelModel::default();
+        let mut ctx_a = chain("mockgaiaA-1");
+        let mut ctx_b = chain("mockgaiaB-1");
+        let mut router = MockRouter::new_with_transfer();
+
+        for (side, step) in steps {
+            if !model.is_submittable(side, step) {
+                continue;
+            }
+
+            let ctx = match side {
+                Side::A => &mut ctx_a,
+                Side::B => &mut ctx_b,
+            };
+            let before = model;
+            // Like a chain, drops the writes of the steps that fail
+            let mut tx_ctx = ctx.clone();
+            let res = dispatch(&mut tx_ctx, &mut router, MsgEnvelope::from(msg(step)));
+            if res.is_ok() {
+                *ctx = tx_ctx;
+            }
+            let expected = model.apply(side, step);
+
+            prop_assert_eq!(
+                res.is_ok(),
+                expected,
+                "{:?} on {:?} in {:?}: {:?}",
+                step,
+                side,
+                before,
+                res
+            );
+            prop_assert_eq!(channel_state(&ctx_a), model.state(Side::A));
+            prop_assert_eq!(channel_state(&ctx_b), model.state(Side::B));
+        }
+    }
+}
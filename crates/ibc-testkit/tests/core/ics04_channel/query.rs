@@ -0,0 +1,101 @@
+use ibc::core::ics03_connection::connection::State as ConnectionState;
+use ibc::core::ics04_channel::channel::Order;
+use ibc::core::ics04_channel::error::PacketError;
+use ibc::core::ics04_channel::packet::{Receipt, Sequence};
+use ibc::core::ics04_channel::query::{unreceived_acks, unreceived_packets};
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::{ChannelEndPath, ReceiptPath, SeqRecvPath};
+use ibc::core::{ContextError, ExecutionContext};
+use ibc::prelude::*;
+use ibc::Height;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::builder::{ChannelConfig, TestChainBuilder};
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use rstest::*;
+use test_log::test;
+
+fn chan_end_path() -> ChannelEndPath {
+    ChannelEndPath::new(&PortId::transfer(), &ChannelId::new(0))
+}
+
+fn seqs(seqs: &[u64]) -> Vec<Sequence> {
+    seqs.iter().copied().map(Sequence::from).collect()
+}
+
+/// A chain with an open channel of the given ordering, on which 3 packets
+/// were sent and not acknowledged yet.
+fn chain(ordering: Order) -> MockContext {
+    let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+
+    TestChainBuilder::default()
+        .client(client_id.clone(), Height::new(0, 10).unwrap())
+        .connection(ConnectionId::new(0), client_id, ConnectionState::Open)
+        .channel_config(
+            ChannelConfig::builder()
+                .chan_id(ChannelId::new(0))
+                .conn_id(ConnectionId::new(0))
+                .ordering(ordering)
+                .unacked_packets(3)
+                .build(),
+        )
+        .build()
+}
+
+#[test]
+fn unreceived_packets_on_unordered_channel() {
+    let mut ctx = chain(Order::Unordered);
+    for seq in [1, 3] {
+        let receipt_path = ReceiptPath::new(&PortId::transfer(), &ChannelId::new(0), seq.into());
+        ctx.store_packet_receipt(&receipt_path, Receipt::Ok)
+            .unwrap();
+    }
+
+    let unreceived = unreceived_packets(&ctx, &chan_end_path(), seqs(&[4, 3, 1, 2])).unwrap();
+    assert_eq!(unreceived, seqs(&[4, 2]));
+}
+
+#[test]
+fn unreceived_packets_on_ordered_channel() {
+    let mut ctx = chain(Order::Ordered);
+    let seq_recv_path = SeqRecvPath::new(&PortId::transfer(), &ChannelId::new(0));
+    ctx.store_next_sequence_recv(&seq_recv_path, Sequence::from(3))
+        .unwrap();
+
+    let unreceived = unreceived_packets(&ctx, &chan_end_path(), seqs(&[4, 1, 3, 2])).unwrap();
+    assert_eq!(unreceived, seqs(&[4, 3]));
+}
+
+#[rstest]
+#[case(Order::Unordered)]
+#[case(Order::Ordered)]
+#[test_log::test]
+fn unreceived_acks_follow_packet_commitments(#[case] ordering: Order) {
+    let ctx = chain(ordering);
+
+    let unreceived = unreceived_acks(&ctx, &chan_end_path(), seqs(&[5, 2, 4, 1])).unwrap();
+    assert_eq!(unreceived, seqs(&[2, 1]));
+
+    let unreceived = unreceived_acks(&ctx, &chan_end_path(), seqs(&[])).unwrap();
+    assert!(unreceived.is_empty());
+}
+
+#[test]
+fn unreceived_queries_reject_invalid_requests() {
+    let ctx = chain(Order::Unordered);
+
+    let res = unreceived_packets(&ctx, &chan_end_path(), seqs(&[1, 0]));
+    assert!(matches!(
+        res,
+        Err(ContextError::PacketError(PacketError::ZeroPacketSequence))
+    ));
+
+    let res = unreceived_acks(&ctx, &chan_end_path(), seqs(&[0]));
+    assert!(matches!(
+        res,
+        Err(ContextError::PacketError(PacketError::ZeroPacketSequence))
+    ));
+
+    let unknown_chan = ChannelEndPath::new(&PortId::transfer(), &ChannelId::new(1));
+    assert!(unreceived_packets(&ctx, &unknown_chan, seqs(&[1])).is_err());
+    assert!(unreceived_acks(&ctx, &unknown_chan, seqs(&[1])).is_err());
+}
@@ -1,17 +1,32 @@
+use core::str::FromStr;
+use core::time::Duration;
+
 use ibc::core::events::{IbcEvent, MessageEvent};
+use ibc::core::ics02_client::error::ClientError;
 use ibc::core::ics02_client::ClientExecutionContext;
 use ibc::core::ics03_connection::connection::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
+use ibc::core::ics03_connection::error::ConnectionError;
 use ibc::core::ics03_connection::version::get_compatible_versions;
 use ibc::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
-use ibc::core::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use ibc::core::ics04_channel::error::{ChannelError, PacketError};
+use ibc::core::ics04_channel::msgs::recv_packet::{MsgRecvPacket, RecvOutcome};
 use ibc::core::ics04_channel::msgs::PacketMsg;
 use ibc::core::ics04_channel::packet::Packet;
 use ibc::core::ics04_channel::Version;
-use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics23_commitment::commitment::{CommitmentProofBytes, ProofHash};
+use ibc::core::ics23_commitment::error::CommitmentError;
+use ibc::core::ics24_host::identifier::{
+    ChannelId, ClientId, ConnectionId, IdentifierError, IdentifierValidationConfig, LengthBounds,
+    PortId, SpecialChars,
+};
+use ibc::core::router::Router;
 use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
-use ibc::core::{execute, validate, ExecutionContext, MsgEnvelope};
+use ibc::core::{
+    dispatch, execute, recv_packet_execute, validate, ContextError, ExecutionContext, MsgEnvelope,
+    RouterError, ValidationContext,
+};
 use ibc::prelude::*;
 use ibc::Height;
 use ibc_testkit::relayer::context::RelayerContext;
@@ -161,6 +176,341 @@ fn recv_packet_validate_happy_path(fixture: Fixture) {
     )
 }
 
+/// Identifiers are parsed against ICS-24, but validated against the config of
+/// the host, which may forbid some of the special characters of ICS-24.
+#[rstest]
+fn recv_packet_validate_identifier_config(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        ..
+    } = fixture;
+
+    msg.packet.chan_id_on_b = ChannelId::from_str("channel[0]").unwrap();
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    // Under ICS-24, the message only fails for lack of a channel
+    let res = validate(&context, &router, msg_envelope.clone());
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ChannelError(
+                ChannelError::ChannelNotFound { .. }
+            )))
+        ),
+        "unexpected error: {res:?}"
+    );
+
+    let context = context.with_identifier_validation_config(
+        IdentifierValidationConfig::ics24().with_special_chars(SpecialChars::Strict),
+    );
+    let res = validate(&context, &router, msg_envelope);
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::PacketError(
+                PacketError::InvalidIdentifier(IdentifierError::InvalidCharacter { .. })
+            )))
+        ),
+        "unexpected error: {res:?}"
+    );
+}
+
+/// Hosts may also tighten the length bounds of identifiers, e.g. to channel
+/// identifiers of at most 9 characters.
+#[rstest]
+fn recv_packet_validate_identifier_length(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        ..
+    } = fixture;
+
+    msg.packet.chan_id_on_b = ChannelId::new(10);
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    // Under ICS-24, the message only fails for lack of a channel
+    let res = validate(&context, &router, msg_envelope.clone());
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ChannelError(
+                ChannelError::ChannelNotFound { .. }
+            )))
+        ),
+        "unexpected error: {res:?}"
+    );
+
+    let context = context.with_identifier_validation_config(
+        IdentifierValidationConfig::ics24()
+            .with_channel_id_length(LengthBounds::new(8, 9).unwrap())
+            .unwrap(),
+    );
+    let res = validate(&context, &router, msg_envelope);
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::PacketError(
+                PacketError::InvalidIdentifier(IdentifierError::InvalidLength {
+                    length: 10,
+                    max: 9,
+                    ..
+                })
+            )))
+        ),
+        "unexpected error: {res:?}"
+    );
+}
+
+/// The packet of the fixture times out 9 seconds after its creation, which
+/// the host reaches by travelling in time, without producing any block.
+#[rstest]
+#[case::not_timed_out(Duration::from_secs(1), true)]
+#[case::timed_out(Duration::from_secs(10), false)]
+#[test_log::test]
+fn recv_packet_validate_timeout_timestamp(
+    fixture: Fixture,
+    #[case] elapsed_time: Duration,
+    #[case] valid: bool,
+) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_client(&ClientId::default(), client_height)
+        .with_connection(ConnectionId::default(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+    context
+        .get_client_execution_context()
+        .store_update_time(
+            ClientId::default(),
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+        )
+        .unwrap();
+    context
+        .get_client_execution_context()
+        .store_update_height(
+            ClientId::default(),
+            client_height,
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    context.advance_time(elapsed_time);
+    assert_eq!(context.host_height().unwrap(), host_height);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    if valid {
+        assert!(res.is_ok(), "validation should succeed. err: {res:?}");
+    } else {
+        assert!(
+            matches!(
+                res,
+                Err(RouterError::ContextError(ContextError::PacketError(
+                    PacketError::LowPacketTimestamp
+                )))
+            ),
+            "validation should fail once the packet timed out. res: {res:?}"
+        );
+    }
+}
+
+/// Proofs referred to by hash are verified once the host received them out of
+/// band, on hosts with a proof resolver.
+#[rstest]
+fn recv_packet_validate_proof_by_hash(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_client(&ClientId::default(), client_height)
+        .with_connection(ConnectionId::default(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height)
+        .with_proof_resolver()
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+    context
+        .get_client_execution_context()
+        .store_update_time(
+            ClientId::default(),
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+        )
+        .unwrap();
+    context
+        .get_client_execution_context()
+        .store_update_height(
+            ClientId::default(),
+            client_height,
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    let proof = msg.proof_commitment_on_a.as_ref().to_vec();
+    msg.proof_commitment_on_a = CommitmentProofBytes::from(ProofHash::of(&proof));
+
+    // Hosts without a resolver handle references as any other proof
+    assert_eq!(
+        MockContext::default()
+            .resolve_proof(&msg.proof_commitment_on_a)
+            .unwrap()
+            .as_ref(),
+        &msg.proof_commitment_on_a
+    );
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ClientError(
+                ClientError::InvalidCommitmentProof(CommitmentError::UnresolvedProof { .. })
+            )))
+        ),
+        "{res:?}"
+    );
+
+    let context = context.with_out_of_band_proof(proof);
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(res.is_ok(), "{res:?}");
+}
+
+/// The delay period of the connection must have passed since the update of
+/// the client both in time, here 10 seconds, and in blocks, here 4 blocks of at
+/// most 3 seconds.
+#[rstest]
+#[case::time_not_elapsed(Duration::from_secs(9), 4, false)]
+#[case::blocks_not_elapsed(Duration::from_secs(10), 3, false)]
+#[case::delay_passed(Duration::from_secs(10), 4, true)]
+#[test_log::test]
+fn recv_packet_connection_delay(
+    fixture: Fixture,
+    #[case] elapsed_time: Duration,
+    #[case] elapsed_blocks: u64,
+    #[case] delay_passed: bool,
+) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    let conn_end_on_b = ConnectionEnd::new(
+        *conn_end_on_b.state(),
+        conn_end_on_b.client_id().clone(),
+        conn_end_on_b.counterparty().clone(),
+        conn_end_on_b.versions().to_vec(),
+        Duration::from_secs(10),
+    )
+    .unwrap();
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_client(&ClientId::default(), client_height)
+        .with_connection(ConnectionId::default(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+    let host_timestamp = context.host_timestamp().unwrap();
+    let update_time =
+        Timestamp::from_nanoseconds(host_timestamp.nanoseconds() - elapsed_time.as_nanos() as u64)
+            .unwrap();
+    let update_height = Height::new(
+        host_height.revision_number(),
+        host_height.revision_height() - elapsed_blocks,
+    )
+    .unwrap();
+
+    context
+        .get_client_execution_context()
+        .store_update_time(ClientId::default(), client_height, update_time)
+        .unwrap();
+    context
+        .get_client_execution_context()
+        .store_update_height(ClientId::default(), client_height, update_height)
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    if delay_passed {
+        assert!(res.is_ok(), "validation should succeed. err: {res:?}");
+    } else {
+        assert!(
+            matches!(
+                res,
+                Err(RouterError::ContextError(ContextError::ConnectionError(
+                    ConnectionError::NotEnoughTimeElapsed { .. }
+                        | ConnectionError::NotEnoughBlocksElapsed { .. }
+                )))
+            ),
+            "validation should fail before the delay passes. res: {res:?}"
+        );
+    }
+}
+
 #[rstest]
 fn recv_packet_timeout_expired(fixture: Fixture) {
     let Fixture {
@@ -225,6 +575,8 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
         .with_connection(ConnectionId::default(), conn_end_on_b)
         .with_channel(PortId::transfer(), ChannelId::default(), chan_end_on_b);
 
+    let relayer = msg.signer.clone();
+    let host_timestamp = ctx.host_timestamp().unwrap();
     let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
 
     let res = execute(&mut ctx, &mut router, msg_env);
@@ -236,10 +588,84 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
         &ctx.events[0],
         &IbcEvent::Message(MessageEvent::Channel)
     ));
-    assert!(matches!(&ctx.events[1], &IbcEvent::ReceivePacket(_)));
+    match &ctx.events[1] {
+        IbcEvent::ReceivePacket(event) => {
+            assert_eq!(event.recv_timestamp(), Some(&host_timestamp));
+            assert_eq!(event.relayer(), Some(&relayer));
+        }
+        event => panic!("unexpected event: {event:?}"),
+    }
     assert!(matches!(
         &ctx.events[2],
         &IbcEvent::Message(MessageEvent::Channel)
     ));
     assert!(matches!(&ctx.events[3], &IbcEvent::WriteAcknowledgement(_)));
 }
+
+#[rstest]
+#[case(Order::Unordered)]
+#[case(Order::Ordered)]
+#[test_log::test]
+fn recv_packet_redundant_relay_is_noop(fixture: Fixture, #[case] ordering: Order) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        mut chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+    chan_end_on_b.ordering = ordering;
+
+    let packet = &msg.packet;
+    let mut ctx = context
+        .with_client(&ClientId::default(), client_height)
+        .with_connection(ConnectionId::default(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+    ctx.get_client_execution_context()
+        .store_update_time(
+            ClientId::default(),
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+        )
+        .unwrap();
+    ctx.get_client_execution_context()
+        .store_update_height(
+            ClientId::default(),
+            client_height,
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg.clone()));
+    dispatch(&mut ctx, &mut router, msg_envelope.clone()).unwrap();
+    let n_events = ctx.events.len();
+
+    // Relaying the packet again succeeds without duplicating its effects
+    dispatch(&mut ctx, &mut router, msg_envelope.clone()).unwrap();
+    assert_eq!(ctx.events.len(), n_events);
+    assert_eq!(
+        ctx.logs.last().map(String::as_str),
+        Some("no-op: packet already received")
+    );
+
+    validate(&ctx, &router, msg_envelope).unwrap();
+    let module_id = router.lookup_module(&packet.port_id_on_b).unwrap();
+    let module = router.get_route_mut(&module_id).unwrap();
+    let outcome = recv_packet_execute(&mut ctx, module, msg).unwrap();
+    assert_eq!(outcome, RecvOutcome::AlreadyReceived);
+    assert_eq!(ctx.events.len(), n_events);
+}
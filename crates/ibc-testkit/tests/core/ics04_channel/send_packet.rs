@@ -101,9 +101,47 @@ fn send_packet_processing() {
                     chan_end_on_a.clone(),
                 )
                 .with_send_sequence(PortId::transfer(), ChannelId::default(), 1.into()),
-            packet,
+            packet: packet.clone(),
+            want_pass: true,
+        },
+        Test {
+            name: "Packet data at the host limit".to_string(),
+            ctx: {
+                let ctx = context
+                    .clone()
+                    .with_client(&ClientId::default(), client_height)
+                    .with_connection(ConnectionId::default(), conn_end_on_a.clone())
+                    .with_channel(
+                        PortId::transfer(),
+                        ChannelId::default(),
+                        chan_end_on_a.clone(),
+                    )
+                    .with_send_sequence(PortId::transfer(), ChannelId::default(), 1.into());
+                ctx.ibc_store.lock().max_packet_data_size = Some(packet.data.len());
+                ctx
+            },
+            packet: packet.clone(),
             want_pass: true,
         },
+        Test {
+            name: "Packet data larger than the host limit".to_string(),
+            ctx: {
+                let ctx = context
+                    .clone()
+                    .with_client(&ClientId::default(), client_height)
+                    .with_connection(ConnectionId::default(), conn_end_on_a.clone())
+                    .with_channel(
+                        PortId::transfer(),
+                        ChannelId::default(),
+                        chan_end_on_a.clone(),
+                    )
+                    .with_send_sequence(PortId::transfer(), ChannelId::default(), 1.into());
+                ctx.ibc_store.lock().max_packet_data_size = Some(packet.data.len() - 1);
+                ctx
+            },
+            packet,
+            want_pass: false,
+        },
         Test {
             name: "Packet timeout height same as destination chain height".to_string(),
             ctx: context
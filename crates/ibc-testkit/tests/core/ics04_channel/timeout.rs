@@ -382,7 +382,10 @@ fn timeout_unordered_chan_execute(fixture: Fixture) {
         ctx.events[0],
         IbcEvent::Message(MessageEvent::Channel)
     ));
-    assert!(matches!(ctx.events[1], IbcEvent::TimeoutPacket(_)));
+    assert!(matches!(
+        &ctx.events[1],
+        IbcEvent::TimeoutPacket(event) if !event.is_on_close()
+    ));
 }
 
 #[rstest]
@@ -422,7 +425,10 @@ fn timeout_ordered_chan_execute(fixture: Fixture) {
         ctx.events[0],
         IbcEvent::Message(MessageEvent::Channel)
     ));
-    assert!(matches!(ctx.events[1], IbcEvent::TimeoutPacket(_)));
+    assert!(matches!(
+        &ctx.events[1],
+        IbcEvent::TimeoutPacket(event) if !event.is_on_close()
+    ));
     assert!(matches!(
         ctx.events[2],
         IbcEvent::Message(MessageEvent::Channel)
@@ -1,3 +1,4 @@
+use ibc::core::events::{IbcEvent, MessageEvent};
 use ibc::core::ics02_client::ClientExecutionContext;
 use ibc::core::ics03_connection::connection::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
@@ -9,8 +10,9 @@ use ibc::core::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
 use ibc::core::ics04_channel::msgs::PacketMsg;
 use ibc::core::ics04_channel::Version;
 use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::ChannelEndPath;
 use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
-use ibc::core::{validate, ExecutionContext, MsgEnvelope};
+use ibc::core::{execute, validate, ExecutionContext, MsgEnvelope, ValidationContext};
 use ibc::prelude::*;
 use ibc::Height;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
@@ -171,3 +173,67 @@ fn timeout_on_close_success_happy_path(fixture: Fixture) {
         "Happy path: validation should succeed. err: {res:?}"
     )
 }
+
+#[rstest]
+#[case::ordered(Order::Ordered, State::Closed)]
+#[case::unordered(Order::Unordered, State::Open)]
+fn timeout_on_close_execute_emits_timeout_on_close(
+    fixture: Fixture,
+    #[case] ordering: Order,
+    #[case] expected_state: State,
+) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        mut chan_end_on_a,
+        ..
+    } = fixture;
+    chan_end_on_a.ordering = ordering;
+    let mut context = context
+        .with_channel(PortId::transfer(), ChannelId::default(), chan_end_on_a)
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution should succeed. err: {res:?}");
+    assert!(matches!(
+        context.events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    // The timeout is reported as caused by the closing of the counterparty
+    // channel end, whatever the ordering of the channel.
+    assert!(matches!(
+        &context.events[1],
+        IbcEvent::TimeoutPacket(event) if event.is_on_close()
+    ));
+    // Only the ordered channel end is closed by the timeout, and reported so.
+    if expected_state == State::Closed {
+        assert_eq!(context.events.len(), 4);
+        assert!(matches!(
+            context.events[2],
+            IbcEvent::Message(MessageEvent::Channel)
+        ));
+        assert!(matches!(context.events[3], IbcEvent::ChannelClosed(_)));
+    } else {
+        assert_eq!(context.events.len(), 2);
+    }
+
+    let chan_end_on_a = context
+        .channel_end(&ChannelEndPath::new(
+            &PortId::transfer(),
+            &ChannelId::default(),
+        ))
+        .unwrap();
+    assert_eq!(chan_end_on_a.state, expected_state);
+}
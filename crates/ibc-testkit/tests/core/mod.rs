@@ -1,4 +1,9 @@
+pub mod adversarial;
+pub mod builder;
+pub mod genesis;
 pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
+pub mod relayer;
 pub mod router;
+pub mod simulation;
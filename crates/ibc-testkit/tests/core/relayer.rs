@@ -0,0 +1,318 @@
+use ibc::core::ics02_client::ClientExecutionContext;
+use ibc::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::ics04_channel::commitment::compute_packet_commitment;
+use ibc::core::ics04_channel::msgs::{ChannelMsg, PacketMsg};
+use ibc::core::ics04_channel::packet::Packet;
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::ics04_channel::Version;
+use ibc::core::ics23_commitment::commitment::CommitmentProofBytes;
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::relayer::{
+    build_chan_close_confirm, build_chan_open_ack, build_chan_open_confirm, build_chan_open_try,
+    build_timeout,
+};
+use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
+use ibc::core::{validate, ExecutionContext, MsgEnvelope};
+use ibc::prelude::*;
+use ibc::Height;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::utils::core::channel::PacketConfig;
+use ibc_testkit::utils::core::signer::dummy_account_id;
+use rstest::*;
+use test_log::test;
+
+/// The two ends of a connection between chains `A` and `B`, over which the
+/// messages built from the queries to one chain are submitted to the other.
+pub struct Fixture {
+    pub router: MockRouter,
+    pub proof_height: Height,
+    pub client_id_on_a: ClientId,
+    pub conn_id_on_a: ConnectionId,
+    pub conn_end_on_a: ConnectionEnd,
+    pub chan_id_on_a: ChannelId,
+    pub client_id_on_b: ClientId,
+    pub conn_id_on_b: ConnectionId,
+    pub conn_end_on_b: ConnectionEnd,
+    pub chan_id_on_b: ChannelId,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id_on_a = ClientId::new(mock_client_type(), 12).unwrap();
+    let conn_id_on_a = ConnectionId::new(1);
+    let client_id_on_b = ClientId::new(mock_client_type(), 45).unwrap();
+    let conn_id_on_b = ConnectionId::new(2);
+
+    let conn_end =
+        |client_id: &ClientId, counterparty_client_id: &ClientId, counterparty_conn_id| {
+            ConnectionEnd::new(
+                ConnectionState::Open,
+                client_id.clone(),
+                ConnectionCounterparty::new(
+                    counterparty_client_id.clone(),
+                    Some(counterparty_conn_id),
+                    b"ibc".to_vec().try_into().unwrap(),
+                ),
+                get_compatible_versions(),
+                ZERO_DURATION,
+            )
+            .unwrap()
+        };
+
+    Fixture {
+        router: MockRouter::new_with_transfer(),
+        proof_height: Height::new(0, 10).unwrap(),
+        conn_end_on_a: conn_end(&client_id_on_a, &client_id_on_b, conn_id_on_b.clone()),
+        conn_end_on_b: conn_end(&client_id_on_b, &client_id_on_a, conn_id_on_a.clone()),
+        client_id_on_a,
+        conn_id_on_a,
+        chan_id_on_a: ChannelId::new(3),
+        client_id_on_b,
+        conn_id_on_b,
+        chan_id_on_b: ChannelId::new(4),
+    }
+}
+
+fn chan_end(
+    state: State,
+    counterparty_chan_id: Option<ChannelId>,
+    conn_id: &ConnectionId,
+) -> ChannelEnd {
+    ChannelEnd::new(
+        state,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), counterparty_chan_id),
+        vec![conn_id.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap()
+}
+
+fn dummy_proof() -> CommitmentProofBytes {
+    vec![1].try_into().unwrap()
+}
+
+#[rstest]
+fn build_chan_open_try_validates_on_b(fixture: Fixture) {
+    let Fixture {
+        router,
+        proof_height,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_id_on_a,
+        client_id_on_b,
+        conn_id_on_b,
+        conn_end_on_b,
+        ..
+    } = fixture;
+
+    let chan_end_on_a = chan_end(State::Init, None, &conn_id_on_a);
+
+    let msg = build_chan_open_try(
+        PortId::transfer(),
+        chan_id_on_a,
+        &chan_end_on_a,
+        &conn_end_on_a,
+        dummy_proof(),
+        proof_height,
+        dummy_account_id(),
+    )
+    .unwrap();
+
+    let ctx_b = MockContext::default()
+        .with_client(&client_id_on_b, proof_height)
+        .with_connection(conn_id_on_b, conn_end_on_b);
+
+    let res = validate(&ctx_b, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(res.is_ok(), "Validation failed: {res:?}")
+}
+
+#[rstest]
+fn build_chan_open_ack_validates_on_a(fixture: Fixture) {
+    let Fixture {
+        router,
+        proof_height,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_id_on_a,
+        conn_id_on_b,
+        chan_id_on_b,
+        ..
+    } = fixture;
+
+    let chan_end_on_b = chan_end(State::TryOpen, Some(chan_id_on_a.clone()), &conn_id_on_b);
+
+    let msg = build_chan_open_ack(
+        chan_id_on_b,
+        &chan_end_on_b,
+        dummy_proof(),
+        proof_height,
+        dummy_account_id(),
+    )
+    .unwrap();
+
+    let ctx_a = MockContext::default()
+        .with_client(&client_id_on_a, proof_height)
+        .with_connection(conn_id_on_a.clone(), conn_end_on_a)
+        .with_channel(
+            PortId::transfer(),
+            chan_id_on_a,
+            chan_end(State::Init, None, &conn_id_on_a),
+        );
+
+    let res = validate(&ctx_a, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(res.is_ok(), "Validation failed: {res:?}")
+}
+
+#[rstest]
+fn build_chan_open_confirm_validates_on_b(fixture: Fixture) {
+    let Fixture {
+        router,
+        proof_height,
+        conn_id_on_a,
+        chan_id_on_a,
+        client_id_on_b,
+        conn_id_on_b,
+        conn_end_on_b,
+        chan_id_on_b,
+        ..
+    } = fixture;
+
+    let chan_end_on_a = chan_end(State::Open, Some(chan_id_on_b.clone()), &conn_id_on_a);
+
+    let msg = build_chan_open_confirm(
+        &chan_end_on_a,
+        dummy_proof(),
+        proof_height,
+        dummy_account_id(),
+    )
+    .unwrap();
+
+    let ctx_b = MockContext::default()
+        .with_client(&client_id_on_b, proof_height)
+        .with_connection(conn_id_on_b.clone(), conn_end_on_b)
+        .with_channel(
+            PortId::transfer(),
+            chan_id_on_b,
+            chan_end(State::TryOpen, Some(chan_id_on_a), &conn_id_on_b),
+        );
+
+    let res = validate(&ctx_b, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(res.is_ok(), "Validation failed: {res:?}")
+}
+
+#[rstest]
+fn build_chan_close_confirm_validates_on_b(fixture: Fixture) {
+    let Fixture {
+        router,
+        proof_height,
+        conn_id_on_a,
+        chan_id_on_a,
+        client_id_on_b,
+        conn_id_on_b,
+        conn_end_on_b,
+        chan_id_on_b,
+        ..
+    } = fixture;
+
+    let chan_end_on_a = chan_end(State::Closed, Some(chan_id_on_b.clone()), &conn_id_on_a);
+
+    let msg = build_chan_close_confirm(
+        &chan_end_on_a,
+        dummy_proof(),
+        proof_height,
+        dummy_account_id(),
+    )
+    .unwrap();
+
+    let ctx_b = MockContext::default()
+        .with_client(&client_id_on_b, proof_height)
+        .with_connection(conn_id_on_b.clone(), conn_end_on_b)
+        .with_channel(
+            PortId::transfer(),
+            chan_id_on_b,
+            chan_end(State::Open, Some(chan_id_on_a), &conn_id_on_b),
+        );
+
+    let res = validate(&ctx_b, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(res.is_ok(), "Validation failed: {res:?}")
+}
+
+#[rstest]
+fn build_timeout_validates_on_a(fixture: Fixture) {
+    let Fixture {
+        router,
+        proof_height,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_id_on_a,
+        chan_id_on_b,
+        ..
+    } = fixture;
+
+    // The packet timed out on `B` before the height the proof is at.
+    let packet: Packet = PacketConfig::builder()
+        .seq_on_a(1.into())
+        .chan_id_on_a(chan_id_on_a.clone())
+        .chan_id_on_b(chan_id_on_b.clone())
+        .data(vec![0])
+        .timeout_height_on_b(TimeoutHeight::At(Height::new(0, 5).unwrap()))
+        .build();
+    let packet_commitment = compute_packet_commitment(
+        &packet.data,
+        &packet.timeout_height_on_b,
+        &packet.timeout_timestamp_on_b,
+    );
+
+    let msg = build_timeout(
+        packet.clone(),
+        2.into(),
+        dummy_proof(),
+        proof_height,
+        dummy_account_id(),
+    );
+
+    let mut ctx_a = MockContext::default()
+        .with_client(&client_id_on_a, proof_height)
+        .with_connection(conn_id_on_a.clone(), conn_end_on_a)
+        .with_channel(
+            PortId::transfer(),
+            chan_id_on_a.clone(),
+            chan_end(State::Open, Some(chan_id_on_b), &conn_id_on_a),
+        )
+        .with_packet_commitment(
+            PortId::transfer(),
+            chan_id_on_a,
+            packet.seq_on_a,
+            packet_commitment,
+        );
+
+    ctx_a
+        .get_client_execution_context()
+        .store_update_time(
+            client_id_on_a.clone(),
+            proof_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+        )
+        .unwrap();
+    ctx_a
+        .get_client_execution_context()
+        .store_update_height(client_id_on_a, proof_height, Height::new(0, 5).unwrap())
+        .unwrap();
+
+    let res = validate(&ctx_a, &router, MsgEnvelope::from(PacketMsg::from(msg)));
+
+    assert!(res.is_ok(), "Validation failed: {res:?}")
+}
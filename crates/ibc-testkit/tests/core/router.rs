@@ -1,7 +1,8 @@
 use ibc::applications::transfer::error::TokenTransferError;
 use ibc::applications::transfer::msgs::transfer::MsgTransfer;
-use ibc::applications::transfer::{send_transfer, BaseCoin};
+use ibc::applications::transfer::{send_transfer, BaseCoin, MODULE_ID_STR};
 use ibc::core::events::{IbcEvent, MessageEvent};
+use ibc::core::ics02_client::error::ClientError;
 use ibc::core::ics02_client::msgs::create_client::MsgCreateClient;
 use ibc::core::ics02_client::msgs::update_client::MsgUpdateClient;
 use ibc::core::ics02_client::msgs::ClientMsg;
@@ -17,12 +18,16 @@ use ibc::core::ics04_channel::msgs::recv_packet::MsgRecvPacket;
 use ibc::core::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
 use ibc::core::ics04_channel::msgs::{ChannelMsg, PacketMsg};
 use ibc::core::ics04_channel::timeout::TimeoutHeight;
-use ibc::core::ics24_host::identifier::ConnectionId;
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::ics24_host::path::CommitmentPath;
+use ibc::core::router::{Binding, ModuleId, Router};
 use ibc::core::timestamp::Timestamp;
-use ibc::core::{dispatch, MsgEnvelope, RouterError, ValidationContext};
+use ibc::core::{
+    dispatch, dispatch_with_expiry, validate, validate_with_expiry, ContextError, MsgEnvelope,
+    MsgExpiry, RouterError, ValidationContext,
+};
 use ibc::prelude::*;
-use ibc::Height;
+use ibc::{Height, Signer};
 use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
 use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
@@ -42,7 +47,7 @@ use ibc_testkit::utils::core::connection::{
     dummy_msg_conn_open_ack, dummy_msg_conn_open_init, dummy_msg_conn_open_init_with_client_id,
     dummy_msg_conn_open_try, msg_conn_open_try_with_client_id,
 };
-use ibc_testkit::utils::core::signer::dummy_account_id;
+use ibc_testkit::utils::core::signer::{dummy_account_id, dummy_bech32_account};
 use primitive_types::U256;
 use test_log::test;
 
@@ -445,3 +450,224 @@ fn routing_module_and_keepers() {
         }
     }
 }
+
+#[test]
+fn messages_past_their_expiry_are_rejected() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let host_height = ctx.host_height().unwrap();
+    let host_timestamp = ctx.host_timestamp().unwrap();
+    let past_height = Height::new(0, host_height.revision_height() - 1).unwrap();
+    let past_timestamp = Timestamp::from_nanoseconds(host_timestamp.nanoseconds() - 1).unwrap();
+
+    let client_height = Height::new(0, 5).unwrap();
+    let msg = MsgEnvelope::Client(ClientMsg::CreateClient(MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(client_height)).into(),
+        MockConsensusState::new(MockHeader::new(client_height)).into(),
+        dummy_account_id(),
+    )));
+
+    let expiry = MsgExpiry {
+        height: Some(past_height),
+        timestamp: None,
+    };
+    let res = dispatch_with_expiry(&mut ctx, &mut router, msg.clone(), &expiry);
+    assert!(matches!(
+        res,
+        Err(RouterError::MessageExpiredAtHeight { .. })
+    ));
+
+    let expiry = MsgExpiry {
+        height: Some(host_height),
+        timestamp: Some(past_timestamp),
+    };
+    let res = validate_with_expiry(&ctx, &router, msg.clone(), &expiry);
+    assert!(matches!(
+        res,
+        Err(RouterError::MessageExpiredAtTimestamp { .. })
+    ));
+
+    let expiry = MsgExpiry {
+        height: Some(host_height),
+        timestamp: Some(host_timestamp),
+    };
+    dispatch_with_expiry(&mut ctx, &mut router, msg.clone(), &expiry).unwrap();
+    dispatch_with_expiry(&mut ctx, &mut router, msg, &MsgExpiry::default()).unwrap();
+}
+
+#[test]
+fn messages_rejected_by_the_host_filter() {
+    let router = MockRouter::new_with_transfer();
+
+    let msg_chan_init = MsgEnvelope::Channel(ChannelMsg::OpenInit(
+        MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap(),
+    ));
+    let msg_recv_packet = MsgEnvelope::Packet(PacketMsg::Recv(
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(35)).unwrap(),
+    ));
+    let msg_create_client = MsgEnvelope::Client(ClientMsg::CreateClient(MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(Height::new(0, 5).unwrap())).into(),
+        MockConsensusState::new(MockHeader::new(Height::new(0, 5).unwrap())).into(),
+        dummy_account_id(),
+    )));
+
+    // every message on a blocked port is rejected, before any store access
+    let ctx = MockContext::default().with_blocked_ports([PortId::transfer()]);
+    for msg in [msg_chan_init.clone(), msg_recv_packet.clone()] {
+        let res = validate(&ctx, &router, msg);
+        assert!(
+            matches!(res, Err(RouterError::MessageRejected { .. })),
+            "{res:?}"
+        );
+    }
+    validate(&ctx, &router, msg_create_client.clone()).unwrap();
+
+    // only the packets received on a disabled channel are rejected
+    let ctx =
+        MockContext::default().with_disabled_channel(PortId::transfer(), ChannelId::default());
+    let res = validate(&ctx, &router, msg_recv_packet);
+    assert!(
+        matches!(res, Err(RouterError::MessageRejected { .. })),
+        "{res:?}"
+    );
+    let res = validate(&ctx, &router, msg_chan_init);
+    assert!(
+        !matches!(res, Err(RouterError::MessageRejected { .. })),
+        "{res:?}"
+    );
+    validate(&ctx, &router, msg_create_client).unwrap();
+}
+
+#[test]
+fn messages_with_signers_rejected_by_the_host() {
+    let mut router = MockRouter::new_with_transfer();
+    let mut ctx = MockContext::default().with_bech32_signers("cosmos");
+
+    let msg_create_client = |signer: Signer| {
+        MsgEnvelope::Client(ClientMsg::CreateClient(MsgCreateClient::new(
+            MockClientState::new(MockHeader::new(Height::new(0, 5).unwrap())).into(),
+            MockConsensusState::new(MockHeader::new(Height::new(0, 5).unwrap())).into(),
+            signer,
+        )))
+    };
+
+    let res = validate(&ctx, &router, msg_create_client(dummy_account_id()));
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ClientError(
+                ClientError::InvalidSigner { .. }
+            )))
+        ),
+        "{res:?}"
+    );
+
+    let res = validate(
+        &ctx,
+        &router,
+        msg_create_client(
+            "osmo1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2m5evw96"
+                .to_string()
+                .into(),
+        ),
+    );
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::ContextError(ContextError::ClientError(
+                ClientError::InvalidSigner { .. }
+            )))
+        ),
+        "{res:?}"
+    );
+    assert_eq!(ctx.client_counter().unwrap(), 0);
+
+    dispatch(
+        &mut ctx,
+        &mut router,
+        msg_create_client(dummy_bech32_account().into()),
+    )
+    .unwrap();
+    assert_eq!(ctx.client_counter().unwrap(), 1);
+}
+
+#[test]
+fn modules_bound_to_ports_connections_and_clients() {
+    let mut router = MockRouter::new_with_transfer();
+    let transfer_module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    let middleware_id = ModuleId::new("middleware".to_string());
+
+    router
+        .bindings
+        .bind(ConnectionId::default(), middleware_id.clone())
+        .unwrap();
+    router
+        .bindings
+        .bind(ClientId::default(), middleware_id.clone())
+        .unwrap();
+    // binding again to the same module is a no-op
+    router
+        .bindings
+        .bind(ClientId::default(), middleware_id.clone())
+        .unwrap();
+
+    assert_eq!(
+        router.lookup_module(&PortId::transfer()),
+        Some(transfer_module_id.clone())
+    );
+    assert_eq!(
+        router.lookup_module_by_connection(&ConnectionId::default()),
+        Some(middleware_id.clone())
+    );
+    assert_eq!(
+        router.lookup_module_by_client(&ClientId::default()),
+        Some(middleware_id.clone())
+    );
+    assert_eq!(
+        router.lookup_module_by_connection(&ConnectionId::new(1)),
+        None
+    );
+    assert_eq!(router.module_ids(), vec![transfer_module_id.clone()]);
+    assert_eq!(
+        router
+            .bindings
+            .bindings_of(&middleware_id)
+            .collect::<Vec<_>>(),
+        vec![
+            &Binding::Connection(ConnectionId::default()),
+            &Binding::Client(ClientId::default()),
+        ]
+    );
+
+    let res = router
+        .bindings
+        .bind(PortId::transfer(), middleware_id.clone());
+    assert!(
+        matches!(
+            res,
+            Err(RouterError::BindingConflict {
+                binding: Binding::Port(_),
+                ref bound_module_id,
+                ..
+            }) if *bound_module_id == transfer_module_id
+        ),
+        "{res:?}"
+    );
+    assert_eq!(
+        router.lookup_module(&PortId::transfer()),
+        Some(transfer_module_id)
+    );
+
+    router
+        .bindings
+        .unbind(&Binding::Port(PortId::transfer()))
+        .unwrap();
+    router
+        .scope_port_to_module(PortId::transfer(), middleware_id.clone())
+        .unwrap();
+    assert_eq!(
+        router.lookup_module(&PortId::transfer()),
+        Some(middleware_id)
+    );
+}
@@ -0,0 +1,277 @@
+use ibc::core::events::{IbcEvent, MessageEvent};
+use ibc::core::ics02_client::msgs::create_client::MsgCreateClient;
+use ibc::core::ics02_client::msgs::ClientMsg;
+use ibc::core::ics03_connection::connection::{ConnectionEnd, State as ConnectionState};
+use ibc::core::ics03_connection::msgs::ConnectionMsg;
+use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::acknowledgement::Acknowledgement;
+use ibc::core::ics04_channel::channel::{Counterparty, Order};
+use ibc::core::ics04_channel::error::{ChannelError, PacketError};
+use ibc::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+use ibc::core::ics04_channel::msgs::ChannelMsg;
+use ibc::core::ics04_channel::packet::Packet;
+use ibc::core::ics04_channel::Version;
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::ics24_host::path::{
+    ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath,
+};
+use ibc::core::router::{Module, ModuleExtras, ModuleId};
+use ibc::core::simulation::{GasCosts, StateWrite};
+use ibc::core::{dispatch, dispatch_simulate, MsgEnvelope, MsgOutput, ValidationContext};
+use ibc::prelude::*;
+use ibc::{Height, Signer};
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::utils::core::channel::dummy_raw_msg_chan_open_init;
+use ibc_testkit::utils::core::connection::dummy_msg_conn_open_init;
+use ibc_testkit::utils::core::signer::dummy_account_id;
+use test_log::test;
+
+/// A module counting the channels opened on it, which it reports as their
+/// version.
+#[derive(Clone, Debug, Default)]
+struct CountingModule {
+    channels: u64,
+}
+
+impl Module for CountingModule {
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.channels += 1;
+        Ok((
+            ModuleExtras::empty(),
+            Version::new(format!("channel-count-{}", self.channels)),
+        ))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), counterparty_version.clone()))
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        (
+            ModuleExtras::empty(),
+            Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+        )
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
+
+#[test]
+fn simulate_conn_open_init() {
+    let msg = dummy_msg_conn_open_init();
+    let ctx = MockContext::default().with_client(&msg.client_id_on_a, Height::new(0, 10).unwrap());
+    let router = MockRouter::new_with_transfer();
+
+    let report = dispatch_simulate(
+        &ctx,
+        &router,
+        MsgEnvelope::from(ConnectionMsg::from(msg.clone())),
+    )
+    .unwrap();
+
+    let conn_id = ConnectionId::new(0);
+    match report.output {
+        MsgOutput::ConnOpenInit(result) => assert_eq!(result.conn_id_on_a, conn_id),
+        output => core::panic!("unexpected output {output:?}"),
+    }
+
+    assert!(matches!(
+        report.writes.as_slice(),
+        [
+            StateWrite::ConnectionCounter,
+            StateWrite::ConnectionToClient(client_conn_path, written_conn_id),
+            StateWrite::Connection(conn_path, _),
+        ] if *client_conn_path == ClientConnectionPath::new(&msg.client_id_on_a)
+            && *written_conn_id == conn_id
+            && *conn_path == ConnectionPath::new(&conn_id)
+    ));
+    assert_eq!(report.events.len(), 2);
+    assert!(matches!(
+        report.events[0],
+        IbcEvent::Message(MessageEvent::Connection)
+    ));
+    assert!(matches!(report.events[1], IbcEvent::OpenInitConnection(_)));
+    assert_eq!(report.logs.len(), 1);
+    assert!(report.gas_estimate > 3 * GasCosts::default().write);
+
+    // Nothing reached the store of the host.
+    assert_eq!(ctx.connection_counter().unwrap(), 0);
+    assert!(ctx.connection_end(&conn_id).is_err());
+    assert!(ctx.events.is_empty());
+}
+
+#[test]
+fn simulate_failing_message() {
+    let ctx = MockContext::default();
+    let router = MockRouter::new_with_transfer();
+
+    let res = dispatch_simulate(
+        &ctx,
+        &router,
+        MsgEnvelope::from(ConnectionMsg::from(dummy_msg_conn_open_init())),
+    );
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn simulate_create_client_reports_client_writes() {
+    let ctx = MockContext::default();
+    let router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let report = dispatch_simulate(
+        &ctx,
+        &router,
+        MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+            MockClientState::new(MockHeader::new(height)).into(),
+            MockConsensusState::new(MockHeader::new(height)).into(),
+            dummy_account_id(),
+        ))),
+    )
+    .unwrap();
+
+    let client_id = ClientId::new(mock_client_type(), 0).unwrap();
+    assert!(report.writes.iter().any(|write| matches!(
+        write,
+        StateWrite::ClientState(path) if *path == ClientStatePath::new(&client_id)
+    )));
+    assert!(report.writes.iter().any(|write| matches!(
+        write,
+        StateWrite::ConsensusState(path)
+            if *path == ClientConsensusStatePath::new(&client_id, &height)
+    )));
+
+    // The client only exists in the branch of the simulation.
+    assert!(ctx.client_state(&client_id).is_err());
+}
+
+#[test]
+fn simulate_leaves_the_router_unchanged() {
+    let msg_conn_init = dummy_msg_conn_open_init();
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Init,
+        msg_conn_init.client_id_on_a.clone(),
+        msg_conn_init.counterparty,
+        get_compatible_versions(),
+        msg_conn_init.delay_period,
+    )
+    .unwrap();
+    let mut ctx = MockContext::default()
+        .with_client(&msg_conn_init.client_id_on_a, Height::new(0, 10).unwrap())
+        .with_connection(ConnectionId::default(), conn_end_on_a);
+
+    let module_id = ModuleId::new("counting".to_string());
+    let mut router = MockRouter::default();
+    router
+        .add_route(module_id.clone(), CountingModule::default())
+        .unwrap();
+    router
+        .scope_port_to_module(PortId::transfer(), module_id)
+        .unwrap();
+
+    let msg = MsgEnvelope::from(ChannelMsg::from(
+        MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap(),
+    ));
+    let channel_count = |output: MsgOutput| match output {
+        MsgOutput::ChanOpenInit(result) => result.version,
+        output => core::panic!("unexpected output {output:?}"),
+    };
+
+    for _ in 0..2 {
+        let report = dispatch_simulate(&ctx, &router, msg.clone()).unwrap();
+        assert_eq!(
+            channel_count(report.output),
+            Version::new("channel-count-1".to_string())
+        );
+    }
+
+    // The module of the router only counts the channel opened for real.
+    let output = dispatch(&mut ctx, &mut router, msg).unwrap();
+    assert_eq!(
+        channel_count(output.result),
+        Version::new("channel-count-1".to_string())
+    );
+}
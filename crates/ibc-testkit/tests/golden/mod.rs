@@ -0,0 +1,203 @@
+//! Golden vectors shared with ibc-go: the canonical protobuf bytes,
+//! commitment bytes and event attributes of a matrix of inputs, committed
+//! under `tests/data/golden`. The same fixture is checked against ibc-go by
+//! `ci/ibc-go-vectors`, so that any divergence between the two
+//! implementations, or between releases, fails one of them.
+//!
+//! The inputs are those `ci/ibc-go-vectors` can produce events for on ibc-go's
+//! test chains, whose first transfer channels and connections are
+//! `channel-0` and `connection-0` on both ends.
+
+use std::fs;
+
+use ibc::clients::ics07_tendermint::client_type as tm_client_type;
+use ibc::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::acknowledgement::Acknowledgement;
+use ibc::core::ics04_channel::channel::{
+    ChannelEnd, Counterparty as ChannelCounterparty, Order, State as ChannelState,
+};
+use ibc::core::ics04_channel::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc::core::ics04_channel::events::{SendPacket, WriteAcknowledgement};
+use ibc::core::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use ibc::core::ics04_channel::packet::{Packet, Sequence};
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::ics04_channel::Version as ChannelVersion;
+use ibc::core::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
+use ibc::prelude::*;
+use ibc::proto::core::channel::v1::{
+    Channel as RawChannel, MsgRecvPacket as RawMsgRecvPacket, Packet as RawPacket,
+};
+use ibc::proto::core::connection::v1::ConnectionEnd as RawConnectionEnd;
+use ibc::{Height, Signer};
+use prost::Message;
+use subtle_encoding::hex;
+use tendermint::abci;
+
+const FIXTURE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/golden/vectors.txt");
+
+const PACKET_DATA: &[u8] =
+    br#"{"amount":"100","denom":"uatom","receiver":"cosmos1receiver","sender":"cosmos1sender"}"#;
+
+const ACK: &[u8] = br#"{"result":"AQ=="}"#;
+
+const TIMEOUT_TIMESTAMP: u64 = 1_700_000_000_000_000_000;
+
+fn packet(timeout_height_on_b: TimeoutHeight) -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(0),
+        data: PACKET_DATA.to_vec(),
+        timeout_height_on_b,
+        timeout_timestamp_on_b: Timestamp::from_nanoseconds(TIMEOUT_TIMESTAMP).unwrap(),
+    }
+}
+
+fn hex_string(bytes: impl AsRef<[u8]>) -> String {
+    String::from_utf8(hex::encode(bytes)).unwrap()
+}
+
+/// Computes the vectors, in fixture order.
+fn vectors() -> Vec<(String, String)> {
+    let packet_with_timeout = packet(TimeoutHeight::At(Height::new(1, 1000).unwrap()));
+    let packet_without_timeout = packet(TimeoutHeight::Never);
+
+    let channel_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        ChannelCounterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+        vec![ConnectionId::new(0)],
+        ChannelVersion::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let connection_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::new(tm_client_type(), 0).unwrap(),
+        ConnectionCounterparty::new(
+            ClientId::new(tm_client_type(), 1).unwrap(),
+            Some(ConnectionId::new(1)),
+            CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg_recv_packet = MsgRecvPacket {
+        packet: packet_with_timeout.clone(),
+        proof_commitment_on_a: CommitmentProofBytes::try_from(b"proof".to_vec()).unwrap(),
+        proof_height_on_a: Height::new(0, 5).unwrap(),
+        signer: Signer::from("cosmos1relayer".to_string()),
+    };
+
+    let ack = Acknowledgement::try_from(ACK.to_vec()).unwrap();
+
+    let commitment = |packet: &Packet| {
+        hex_string(compute_packet_commitment(
+            &packet.data,
+            &packet.timeout_height_on_b,
+            &packet.timeout_timestamp_on_b,
+        ))
+    };
+
+    let mut vectors = vec![
+        (
+            "proto.packet",
+            hex_string(RawPacket::from(packet_with_timeout.clone()).encode_to_vec()),
+        ),
+        (
+            "proto.packet_no_timeout_height",
+            hex_string(RawPacket::from(packet_without_timeout.clone()).encode_to_vec()),
+        ),
+        (
+            "proto.channel_end",
+            hex_string(RawChannel::from(channel_end).encode_to_vec()),
+        ),
+        (
+            "proto.connection_end",
+            hex_string(RawConnectionEnd::from(connection_end).encode_to_vec()),
+        ),
+        (
+            "proto.msg_recv_packet",
+            hex_string(RawMsgRecvPacket::from(msg_recv_packet).encode_to_vec()),
+        ),
+        ("commitment.packet", commitment(&packet_with_timeout)),
+        (
+            "commitment.packet_no_timeout_height",
+            commitment(&packet_without_timeout),
+        ),
+        ("commitment.ack", hex_string(compute_ack_commitment(&ack))),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_string(), value))
+    .collect::<Vec<_>>();
+
+    let events = [
+        abci::Event::try_from(SendPacket::new(
+            packet_with_timeout.clone(),
+            Order::Unordered,
+            ConnectionId::new(0),
+        ))
+        .unwrap(),
+        abci::Event::try_from(WriteAcknowledgement::new(
+            packet_with_timeout,
+            ack,
+            ConnectionId::new(0),
+        ))
+        .unwrap(),
+    ];
+    for event in events {
+        for attribute in event.attributes {
+            vectors.push((
+                format!("event.{}.{}", event.kind, attribute.key),
+                attribute.value,
+            ));
+        }
+    }
+
+    vectors
+}
+
+fn parse(fixture: &str) -> Vec<(String, String)> {
+    fixture
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (key, value) = line
+                .split_once(" = ")
+                .unwrap_or_else(|| core::panic!("malformed golden vector: {line}"));
+            (key.to_string(), value.to_string())
+        })
+        .collect()
+}
+
+#[test]
+fn golden_vectors_match_ibc_go() {
+    let actual = vectors();
+    let expected = parse(&fs::read_to_string(FIXTURE_PATH).unwrap());
+
+    // Either implementation may emit event attributes the other does not,
+    // such as the acknowledgement commitment of ibc-rs: only those of the
+    // fixture, which both emit, are compared.
+    let is_event = |key: &String| key.starts_with("event.");
+    let expected_keys: Vec<_> = expected.iter().map(|(key, _)| key).collect();
+    let actual_keys: Vec<_> = actual
+        .iter()
+        .map(|(key, _)| key)
+        .filter(|key| !is_event(key) || expected_keys.contains(key))
+        .collect();
+    assert_eq!(actual_keys, expected_keys, "golden vector keys diverged");
+
+    for (key, expected) in &expected {
+        let (_, actual) = actual.iter().find(|(k, _)| k == key).unwrap();
+        assert_eq!(actual, expected, "golden vector `{key}` diverged");
+    }
+}
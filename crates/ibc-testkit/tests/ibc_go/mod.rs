@@ -0,0 +1,39 @@
+//! Runs the regression cases recorded from ibc-go by `ci/ibc-go-vectors` into
+//! the directory named by `IBC_GO_CORPUS_DIR`, and fails on any divergence of
+//! the events, errors or state writes of ibc-rs from those of ibc-go. The test
+//! is ignored unless asked for, as in the `ibc-go vectors` workflow:
+//!
+//! ```sh
+//! IBC_GO_CORPUS_DIR=... cargo test -p ibc-testkit --features ibc-go-integration ibc_go -- --ignored
+//! ```
+
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::utils::ibc_go::load_corpus;
+use ibc_testkit::utils::regression::run_corpus;
+
+const CORPUS_DIR_VAR: &str = "IBC_GO_CORPUS_DIR";
+
+#[test]
+#[ignore = "runs against a corpus recorded by `ci/ibc-go-vectors`"]
+fn handlers_match_ibc_go() {
+    let dir = std::env::var(CORPUS_DIR_VAR).unwrap_or_else(|_| {
+        panic!(
+            "`{CORPUS_DIR_VAR}` must name the directory the corpus of ibc-go was recorded into, \
+             by running `go test -run TestRecordCorpus ./...` in `ci/ibc-go-vectors`"
+        )
+    });
+
+    let corpus = load_corpus(&dir).unwrap();
+    assert!(!corpus.is_empty(), "no case was recorded into `{dir}`");
+
+    let divergences = run_corpus(&corpus, &MockRouter::new_with_transfer());
+    assert!(
+        divergences.is_empty(),
+        "{}",
+        divergences
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
@@ -8,3 +8,7 @@
 )]
 pub mod applications;
 pub mod core;
+pub mod golden;
+#[cfg(feature = "ibc-go-integration")]
+pub mod ibc_go;
+pub mod roundtrip;
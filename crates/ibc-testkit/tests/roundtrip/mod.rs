@@ -0,0 +1,106 @@
+//! Checks that every domain type generated by the testkit strategies
+//! round-trips through its raw type.
+
+use ibc::core::ics02_client::msgs::create_client::MsgCreateClient;
+use ibc::core::ics02_client::msgs::misbehaviour::MsgSubmitMisbehaviour;
+use ibc::core::ics02_client::msgs::update_client::MsgUpdateClient;
+use ibc::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
+use ibc::core::ics03_connection::connection::ConnectionEnd;
+use ibc::core::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
+use ibc::core::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use ibc::core::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use ibc::core::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
+use ibc::core::ics03_connection::version::Version as ConnectionVersion;
+use ibc::core::ics04_channel::channel::ChannelEnd;
+use ibc::core::ics04_channel::msgs::{
+    MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+    MsgTimeoutOnClose,
+};
+use ibc::core::ics04_channel::packet::Packet;
+use ibc::proto::core::channel::v1::{
+    Channel as RawChannel, MsgAcknowledgement as RawMsgAcknowledgement,
+    MsgChannelCloseConfirm as RawMsgChannelCloseConfirm,
+    MsgChannelCloseInit as RawMsgChannelCloseInit, MsgChannelOpenAck as RawMsgChannelOpenAck,
+    MsgChannelOpenConfirm as RawMsgChannelOpenConfirm, MsgChannelOpenInit as RawMsgChannelOpenInit,
+    MsgChannelOpenTry as RawMsgChannelOpenTry, MsgRecvPacket as RawMsgRecvPacket,
+    MsgTimeout as RawMsgTimeout, MsgTimeoutOnClose as RawMsgTimeoutOnClose, Packet as RawPacket,
+};
+use ibc::proto::core::client::v1::{
+    Height as RawHeight, MsgCreateClient as RawMsgCreateClient,
+    MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour, MsgUpdateClient as RawMsgUpdateClient,
+    MsgUpgradeClient as RawMsgUpgradeClient,
+};
+use ibc::proto::core::connection::v1::{
+    ConnectionEnd as RawConnectionEnd, MsgConnectionOpenAck as RawMsgConnectionOpenAck,
+    MsgConnectionOpenConfirm as RawMsgConnectionOpenConfirm,
+    MsgConnectionOpenInit as RawMsgConnectionOpenInit,
+    MsgConnectionOpenTry as RawMsgConnectionOpenTry, Version as RawConnectionVersion,
+};
+use ibc::Height;
+use ibc_testkit::utils::strategies::*;
+use proptest::prelude::*;
+
+macro_rules! roundtrip_tests {
+    ($($name:ident: $strategy:expr => $domain:ty, $raw:ty;)*) => {
+        proptest! {
+            $(
+                #[test]
+                fn $name(value in $strategy) {
+                    let raw: $raw = value.clone().into();
+                    let decoded = <$domain>::try_from(raw);
+                    prop_assert_eq!(decoded.ok(), Some(value));
+                }
+            )*
+        }
+    };
+}
+
+roundtrip_tests! {
+    height: arb_height() => Height, RawHeight;
+    connection_version: arb_connection_version() => ConnectionVersion, RawConnectionVersion;
+    connection_end: arb_connection_end() => ConnectionEnd, RawConnectionEnd;
+    channel_end: arb_channel_end() => ChannelEnd, RawChannel;
+    packet: arb_packet() => Packet, RawPacket;
+
+    msg_create_client: arb_msg_create_client() => MsgCreateClient, RawMsgCreateClient;
+    msg_update_client: arb_msg_update_client() => MsgUpdateClient, RawMsgUpdateClient;
+    msg_upgrade_client: arb_msg_upgrade_client() => MsgUpgradeClient, RawMsgUpgradeClient;
+    msg_submit_misbehaviour: arb_msg_submit_misbehaviour() =>
+        MsgSubmitMisbehaviour, RawMsgSubmitMisbehaviour;
+
+    msg_conn_open_init: arb_msg_conn_open_init() =>
+        MsgConnectionOpenInit, RawMsgConnectionOpenInit;
+    msg_conn_open_try: arb_msg_conn_open_try() => MsgConnectionOpenTry, RawMsgConnectionOpenTry;
+    msg_conn_open_ack: arb_msg_conn_open_ack() => MsgConnectionOpenAck, RawMsgConnectionOpenAck;
+    msg_conn_open_confirm: arb_msg_conn_open_confirm() =>
+        MsgConnectionOpenConfirm, RawMsgConnectionOpenConfirm;
+
+    msg_chan_open_init: arb_msg_chan_open_init() => MsgChannelOpenInit, RawMsgChannelOpenInit;
+    msg_chan_open_try: arb_msg_chan_open_try() => MsgChannelOpenTry, RawMsgChannelOpenTry;
+    msg_chan_open_ack: arb_msg_chan_open_ack() => MsgChannelOpenAck, RawMsgChannelOpenAck;
+    msg_chan_open_confirm: arb_msg_chan_open_confirm() =>
+        MsgChannelOpenConfirm, RawMsgChannelOpenConfirm;
+    msg_chan_close_init: arb_msg_chan_close_init() => MsgChannelCloseInit, RawMsgChannelCloseInit;
+    msg_chan_close_confirm: arb_msg_chan_close_confirm() =>
+        MsgChannelCloseConfirm, RawMsgChannelCloseConfirm;
+    msg_recv_packet: arb_msg_recv_packet() => MsgRecvPacket, RawMsgRecvPacket;
+    msg_acknowledgement: arb_msg_acknowledgement() => MsgAcknowledgement, RawMsgAcknowledgement;
+    msg_timeout: arb_msg_timeout() => MsgTimeout, RawMsgTimeout;
+    msg_timeout_on_close: arb_msg_timeout_on_close() => MsgTimeoutOnClose, RawMsgTimeoutOnClose;
+}
+
+#[cfg(feature = "serde")]
+mod transfer {
+    use ibc::applications::transfer::msgs::transfer::MsgTransfer;
+    use ibc::applications::transfer::packet::PacketData;
+    use ibc::proto::transfer::v1::MsgTransfer as RawMsgTransfer;
+    use ibc::proto::transfer::v2::FungibleTokenPacketData as RawPacketData;
+    use ibc_testkit::utils::strategies::{arb_msg_transfer, arb_packet_data};
+    use proptest::prelude::*;
+
+    roundtrip_tests! {
+        packet_data: arb_packet_data() => PacketData, RawPacketData;
+        msg_transfer: arb_msg_transfer() => MsgTransfer, RawMsgTransfer;
+    }
+}
@@ -1,4 +1,8 @@
 //! Implementation of IBC applications
 
+pub use ibc_apps_fee as fee;
 #[cfg(feature = "serde")]
-pub mod transfer;
+pub use ibc_apps_interchain_accounts as interchain_accounts;
+#[cfg(feature = "serde")]
+pub use ibc_apps_transfer as transfer;
+pub use ibc_core_router::pass_through;
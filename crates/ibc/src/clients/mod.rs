@@ -2,7 +2,7 @@
 
 use core::any::Any;
 
-pub mod ics07_tendermint;
+pub use ibc_clients_tendermint as ics07_tendermint;
 
 /// Allows type to be converted to `&dyn Any`
 pub trait AsAny: Any {
@@ -0,0 +1,5 @@
+//! Builders of the messages of the connection and channel handshakes, e.g.
+//! `MsgConnectionOpenTry::builder().client_id_on_b(..)...build()`.
+
+pub use ibc_core_channel_types::builder::*;
+pub use ibc_core_connection_types::builder::*;
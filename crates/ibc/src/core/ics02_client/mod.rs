@@ -1,13 +1,9 @@
 //! ICS 02: Client implementation for verifying remote IBC-enabled chains.
 
-pub mod client_state;
-pub mod client_type;
-pub mod consensus_state;
-pub mod error;
-pub mod events;
-pub mod handler;
-pub mod height;
-pub mod msgs;
-
-mod context;
-pub use context::{ClientExecutionContext, ClientValidationContext};
+pub use ibc_core_client::{handler, migration};
+pub use ibc_core_client_context::context::{ClientExecutionContext, ClientValidationContext};
+pub use ibc_core_client_context::{client_state, registry};
+pub use ibc_core_client_types::{
+    consensus_state, error, events, genesis, height, msgs, params, status,
+};
+pub use ibc_core_host_types::client_type;
@@ -1,7 +0,0 @@
-//! This module implements the processing logic for ICS3 (connection open
-//! handshake) messages.
-
-pub mod conn_open_ack;
-pub mod conn_open_confirm;
-pub mod conn_open_init;
-pub mod conn_open_try;
@@ -1,11 +1,7 @@
 //! ICS 03: Connection implementation for connecting a client
 //! on the local chain with a client on a remote chain.
 
-pub mod connection;
-pub(crate) mod delay;
-pub mod error;
-pub mod events;
-/// Message processing logic (protocol) for ICS 03.
-pub mod handler;
-pub mod msgs;
-pub mod version;
+pub use ibc_core_connection::{delay, handler};
+pub use ibc_core_connection_types::{connection, error, events, genesis, msgs, params, version};
+#[cfg(feature = "connection-upgrade")]
+pub use ibc_core_host::upgrade;
@@ -1,13 +0,0 @@
-//! This module implements the processing logic for ICS4 (channel) messages.
-
-pub(crate) mod acknowledgement;
-pub(crate) mod chan_close_confirm;
-pub(crate) mod chan_close_init;
-pub(crate) mod chan_open_ack;
-pub(crate) mod chan_open_confirm;
-pub(crate) mod chan_open_init;
-pub(crate) mod chan_open_try;
-pub(crate) mod recv_packet;
-pub(crate) mod send_packet;
-pub(crate) mod timeout;
-pub(crate) mod timeout_on_close;
@@ -1,17 +1,12 @@
 //! ICS 04: Channel implementation that facilitates communication between
 //! applications and the chains those applications are built upon.
 
-pub mod channel;
-pub mod context;
-pub mod error;
-pub mod events;
-
-pub(crate) mod handler;
-pub mod msgs;
-pub mod packet;
-pub mod timeout;
-
-pub mod acknowledgement;
-pub mod commitment;
-mod version;
-pub use version::Version;
+pub use ibc_core_channel::{context, query};
+pub use ibc_core_channel_types::version::Version;
+#[cfg(feature = "serde")]
+pub use ibc_core_channel_types::version_metadata;
+pub use ibc_core_channel_types::{
+    acknowledgement, channel, commitment, error, events, genesis, msgs, packet, timeout,
+};
+pub use ibc_core_handler::invariants;
+pub use ibc_core_host::{receipt_bitmap, timeout_queue};
@@ -1,5 +1,5 @@
 //! ICS 24: Host defines the minimal set of interfaces that a
 //! state machine hosting an IBC-enabled chain must implement.
 
-pub mod identifier;
-pub mod path;
+pub use ibc_core_handler_types::{path, storage};
+pub use ibc_core_host_types::{allocator, identifier};
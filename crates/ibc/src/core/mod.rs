@@ -22,23 +22,34 @@
 // `client_state_of_a_on_b` means "the client state of light client for chain A
 // stored on chain B"
 
+#[cfg(feature = "builder")]
+pub mod builder;
 pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
-pub mod ics23_commitment;
 pub mod ics24_host;
-pub mod router;
 
-pub mod events;
-pub mod timestamp;
-
-mod context;
-mod handler;
-mod msgs;
-
-pub use context::*;
-pub use handler::{dispatch, execute, validate};
-pub use ics04_channel::handler::send_packet::{
+#[cfg(feature = "localhost")]
+pub use ibc_core_channel::handler::loopback::{
+    is_localhost_channel, loopback_packet, loopback_packet_execute, loopback_packet_validate,
+};
+pub use ibc_core_channel::handler::recv_packet::{recv_packet_execute, recv_packet_validate};
+pub use ibc_core_channel::handler::send_packet::{
     send_packet, send_packet_execute, send_packet_validate,
 };
-pub use msgs::{Msg, MsgEnvelope};
+pub use ibc_core_commitment_types as ics23_commitment;
+pub use ibc_core_handler::handler::{
+    dispatch, dispatch_with_expiry, execute, validate, validate_with_expiry,
+};
+#[cfg(feature = "localhost")]
+pub use ibc_core_handler::handler::send_packet_with_loopback;
+pub use ibc_core_handler::simulation::dispatch_simulate;
+pub use ibc_core_handler::{genesis, relayer, simulation};
+pub use ibc_core_handler_types::msgs::{
+    AcceptAllMsgs, BlockedPorts, HandlerOutput, Msg, MsgEnvelope, MsgExpiry, MsgFilter, MsgOutput,
+    ValidateBasic,
+};
+pub use ibc_core_handler_types::{events, module};
+pub use ibc_core_host::context::*;
+pub use ibc_core_router::router;
+pub use ibc_primitives::{error, timestamp};
@@ -0,0 +1,928 @@
+//! Implementation of the IBC contexts on top of the storage, API and
+//! environment of a CosmWasm contract.
+
+use core::marker::PhantomData;
+
+use cosmwasm_std::{Api, CustomQuery, Deps, DepsMut, Env, Event as CwEvent};
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::connection::v1::ClientPaths as RawClientPaths;
+use ibc_proto::Protobuf;
+use prost::Message;
+use tendermint::abci;
+
+use super::store::{
+    host_consensus_state_key, processed_height_key, processed_time_key, CwStore, CLIENT_PARAMS_KEY,
+    CONNECTION_PARAMS_KEY, NEXT_CHANNEL_SEQUENCE_KEY, NEXT_CLIENT_SEQUENCE_KEY,
+    NEXT_CONNECTION_SEQUENCE_KEY,
+};
+use crate::clients::ics07_tendermint::client_state::ClientState as TmClientState;
+use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use crate::clients::ics07_tendermint::{
+    CommonContext as TmCommonContext, ValidationContext as TmValidationContext,
+};
+use crate::core::events::{Error as EventError, IbcEvent};
+use crate::core::ics02_client::client_state::ClientState;
+use crate::core::ics02_client::consensus_state::ConsensusState;
+use crate::core::ics02_client::error::ClientError;
+use crate::core::ics02_client::params::ClientParams;
+use crate::core::ics02_client::{ClientExecutionContext, ClientValidationContext};
+use crate::core::ics03_connection::connection::ConnectionEnd;
+use crate::core::ics03_connection::error::ConnectionError;
+use crate::core::ics03_connection::params::ConnectionParams;
+use crate::core::ics04_channel::channel::ChannelEnd;
+use crate::core::ics04_channel::commitment::{AcknowledgementCommitment, PacketCommitment};
+use crate::core::ics04_channel::error::{ChannelError, PacketError};
+use crate::core::ics04_channel::packet::{Receipt, Sequence};
+use crate::core::ics23_commitment::commitment::CommitmentPrefix;
+use crate::core::ics24_host::identifier::{ChainId, ClientId, ConnectionId};
+use crate::core::ics24_host::path::{
+    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use crate::core::timestamp::Timestamp;
+use crate::core::{ContextError, ExecutionContext, ValidationContext};
+use crate::prelude::*;
+use crate::{Height, Signer};
+
+/// The commitment prefix of the IBC state of a contract, unless configured
+/// otherwise.
+pub const DEFAULT_COMMITMENT_PREFIX: &[u8] = b"ibc";
+
+/// The IBC context of a CosmWasm contract, generic over the client and
+/// consensus states `C` and `S` of the light clients the contract supports,
+/// typically enums deriving `ClientState` and `ConsensusState`, the former
+/// implementing [`CwClientState`].
+///
+/// Both are stored as encoded `Any`s. Events are buffered until the contract
+/// turns them into the events of its response with
+/// [`CwContext::into_cw_events`].
+///
+/// Contracts cannot introspect the consensus of the chain they run on, so the
+/// consensus states returned by [`ValidationContext::host_consensus_state`]
+/// must be recorded by the contract itself with
+/// [`CwContext::store_host_consensus_state`].
+pub struct CwContext<'a, C, S> {
+    store: CwStore<'a>,
+    api: &'a dyn Api,
+    env: Env,
+    commitment_prefix: CommitmentPrefix,
+    events: Vec<IbcEvent>,
+    _marker: PhantomData<(C, S)>,
+}
+
+/// The client states `C` of a [`CwContext`] whose consensus states are `S`.
+///
+/// Requiring `ClientState<CwContext<..>, CwContext<..>>` directly on the
+/// contexts would make the trait solver loop between the client state and the
+/// context, so client states opt in with an empty implementation instead,
+/// checked once where it is written:
+///
+/// ```ignore
+/// impl<'a> CwClientState<'a, AnyConsensusState> for AnyClientState {}
+/// ```
+pub trait CwClientState<'a, S>:
+    ClientState<CwContext<'a, Self, S>, CwContext<'a, Self, S>>
+    + Into<Any>
+    + TryFrom<Any, Error = ClientError>
+where
+    S: ConsensusState + Into<Any> + TryFrom<Any, Error = ClientError>,
+{
+}
+
+impl<'a> CwClientState<'a, TmConsensusState> for TmClientState {}
+
+impl<'a, C, S> CwContext<'a, C, S> {
+    /// Context of the `execute` and `sudo` entrypoints of a contract, through
+    /// which all the handlers can run.
+    pub fn new<Q: CustomQuery>(deps: DepsMut<'a, Q>, env: Env) -> Self {
+        Self::with_store(CwStore::new_mut(deps.storage), deps.api, env)
+    }
+
+    /// Context of the `query` entrypoint of a contract, through which only
+    /// the validation of messages and the queries can run.
+    pub fn new_ref<Q: CustomQuery>(deps: Deps<'a, Q>, env: Env) -> Self {
+        Self::with_store(CwStore::new_ref(deps.storage), deps.api, env)
+    }
+
+    fn with_store(store: CwStore<'a>, api: &'a dyn Api, env: Env) -> Self {
+        Self {
+            store,
+            api,
+            env,
+            commitment_prefix: CommitmentPrefix::try_from(DEFAULT_COMMITMENT_PREFIX.to_vec())
+                .expect("Never fails"),
+            events: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Stores the IBC state under `prefix` instead of
+    /// [`DEFAULT_KEY_PREFIX`](super::store::DEFAULT_KEY_PREFIX).
+    pub fn with_key_prefix(self, prefix: Vec<u8>) -> Self {
+        Self {
+            store: self.store.with_prefix(prefix),
+            ..self
+        }
+    }
+
+    /// Sets the prefix under which counterparties verify the proofs of the
+    /// IBC state of the contract.
+    pub fn with_commitment_prefix(self, commitment_prefix: CommitmentPrefix) -> Self {
+        Self {
+            commitment_prefix,
+            ..self
+        }
+    }
+
+    pub fn store(&self) -> &CwStore<'a> {
+        &self.store
+    }
+
+    /// Returns the events emitted by the handlers so far.
+    pub fn events(&self) -> &[IbcEvent] {
+        &self.events
+    }
+
+    /// Converts the events emitted by the handlers into the events of the
+    /// response of the contract.
+    pub fn into_cw_events(self) -> Result<Vec<CwEvent>, EventError> {
+        self.events
+            .into_iter()
+            .map(|event| {
+                let event = abci::Event::try_from(event)?;
+                Ok(CwEvent::new(event.kind).add_attributes(
+                    event
+                        .attributes
+                        .into_iter()
+                        .map(|attr| (attr.key, attr.value)),
+                ))
+            })
+            .collect()
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        let chain_id = ChainId::new(&self.env.block.chain_id).map_err(|e| ClientError::Other {
+            description: format!("invalid host chain identifier: {e}"),
+        })?;
+        Ok(Height::new(
+            chain_id.revision_number(),
+            self.env.block.height,
+        )?)
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        Timestamp::from_nanoseconds(self.env.block.time.nanos()).map_err(|e| {
+            ClientError::Other {
+                description: format!("invalid host timestamp: {e}"),
+            }
+            .into()
+        })
+    }
+}
+
+impl<'a, C, S> CwContext<'a, C, S>
+where
+    S: TryFrom<Any, Error = ClientError>,
+{
+    /// Records the consensus state of the host at `height`, for
+    /// counterparties to check their client of the contract against it
+    /// during connection handshakes.
+    pub fn store_host_consensus_state(
+        &mut self,
+        height: &Height,
+        consensus_state: S,
+    ) -> Result<(), ContextError>
+    where
+        S: Into<Any>,
+    {
+        self.store.set(
+            host_consensus_state_key(height),
+            Into::<Any>::into(consensus_state).encode_to_vec(),
+        )
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<S, ContextError> {
+        let height = Height::new(client_cons_state_path.epoch, client_cons_state_path.height)?;
+        let bytes = self.store.get(client_cons_state_path).ok_or_else(|| {
+            ClientError::ConsensusStateNotFound {
+                client_id: client_cons_state_path.client_id.clone(),
+                height,
+            }
+        })?;
+
+        Ok(decode_any(&bytes)?.try_into()?)
+    }
+}
+
+fn decode_any(bytes: &[u8]) -> Result<Any, ClientError> {
+    Any::decode(bytes).map_err(ClientError::Decode)
+}
+
+impl<'a, C, S> ValidationContext for CwContext<'a, C, S>
+where
+    C: CwClientState<'a, S>,
+    S: ConsensusState + Into<Any> + TryFrom<Any, Error = ClientError>,
+{
+    type V = Self;
+    type E = Self;
+    type AnyConsensusState = S;
+    type AnyClientState = C;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self
+    }
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::AnyClientState, ContextError> {
+        let bytes = self
+            .store
+            .get(ClientStatePath::new(client_id))
+            .ok_or_else(|| ClientError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            })?;
+
+        self.decode_client_state(decode_any(&bytes)?)
+    }
+
+    fn decode_client_state(&self, client_state: Any) -> Result<Self::AnyClientState, ContextError> {
+        Ok(C::try_from(client_state)?)
+    }
+
+    fn client_params(&self) -> Result<ClientParams, ContextError> {
+        match self.store.get(CLIENT_PARAMS_KEY) {
+            Some(bytes) => Ok(ClientParams::decode_vec(&bytes).map_err(|e| {
+                ClientError::InvalidClientParams {
+                    reason: e.to_string(),
+                }
+            })?),
+            None => Ok(ClientParams::default()),
+        }
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        CwContext::consensus_state(self, client_cons_state_path)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        CwContext::host_height(self)
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        CwContext::host_timestamp(self)
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        let bytes = self
+            .store
+            .get(host_consensus_state_key(height))
+            .ok_or(ClientError::MissingLocalConsensusState { height: *height })
+            .map_err(ConnectionError::Client)?;
+
+        Ok(decode_any(&bytes)?.try_into()?)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        Ok(self
+            .store
+            .get_u64(NEXT_CLIENT_SEQUENCE_KEY)?
+            .unwrap_or_default())
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        let bytes = self
+            .store
+            .get(ConnectionPath::new(conn_id))
+            .ok_or_else(|| ConnectionError::ConnectionNotFound {
+                connection_id: conn_id.clone(),
+            })?;
+
+        Ok(
+            ConnectionEnd::decode_vec(&bytes).map_err(|e| ConnectionError::Other {
+                description: e.to_string(),
+            })?,
+        )
+    }
+
+    /// Checks that the client of the contract on the counterparty decodes to
+    /// one of the supported client states, on the revision of the host and at
+    /// a height it reached already. Contracts cannot check more about their
+    /// host.
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Any,
+    ) -> Result<(), ContextError> {
+        let client_state = self
+            .decode_client_state(client_state_of_host_on_counterparty)
+            .map_err(|e| ConnectionError::InvalidClientState {
+                reason: e.to_string(),
+            })?;
+        let latest_height = client_state.latest_height();
+        let host_height = CwContext::host_height(self)?;
+
+        if latest_height.revision_number() != host_height.revision_number() {
+            return Err(ConnectionError::InvalidClientState {
+                reason: format!(
+                    "client is not in the same revision as the chain. expected: {}, got: {}",
+                    host_height.revision_number(),
+                    latest_height.revision_number()
+                ),
+            }
+            .into());
+        }
+
+        if latest_height >= host_height {
+            return Err(ConnectionError::InvalidClientState {
+                reason: format!(
+                    "client has latest height {latest_height} greater than or equal to chain height {host_height}"
+                ),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.commitment_prefix.clone()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        Ok(self
+            .store
+            .get_u64(NEXT_CONNECTION_SEQUENCE_KEY)?
+            .unwrap_or_default())
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        let bytes =
+            self.store
+                .get(channel_end_path)
+                .ok_or_else(|| ChannelError::ChannelNotFound {
+                    port_id: channel_end_path.0.clone(),
+                    channel_id: channel_end_path.1.clone(),
+                })?;
+
+        Ok(
+            ChannelEnd::decode_vec(&bytes).map_err(|e| ChannelError::Other {
+                description: e.to_string(),
+            })?,
+        )
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        let seq =
+            self.store
+                .get_u64(seq_send_path)?
+                .ok_or_else(|| PacketError::MissingNextSendSeq {
+                    port_id: seq_send_path.0.clone(),
+                    channel_id: seq_send_path.1.clone(),
+                })?;
+
+        Ok(seq.into())
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        let seq =
+            self.store
+                .get_u64(seq_recv_path)?
+                .ok_or_else(|| PacketError::MissingNextRecvSeq {
+                    port_id: seq_recv_path.0.clone(),
+                    channel_id: seq_recv_path.1.clone(),
+                })?;
+
+        Ok(seq.into())
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        let seq =
+            self.store
+                .get_u64(seq_ack_path)?
+                .ok_or_else(|| PacketError::MissingNextAckSeq {
+                    port_id: seq_ack_path.0.clone(),
+                    channel_id: seq_ack_path.1.clone(),
+                })?;
+
+        Ok(seq.into())
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        let bytes =
+            self.store
+                .get(commitment_path)
+                .ok_or(PacketError::PacketCommitmentNotFound {
+                    sequence: commitment_path.sequence,
+                })?;
+
+        Ok(bytes.into())
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        match self.store.get(receipt_path) {
+            Some(_) => Ok(Receipt::Ok),
+            None => Err(PacketError::PacketReceiptNotFound {
+                sequence: receipt_path.sequence,
+            }
+            .into()),
+        }
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        let bytes = self
+            .store
+            .get(ack_path)
+            .ok_or(PacketError::PacketAcknowledgementNotFound {
+                sequence: ack_path.sequence,
+            })?;
+
+        Ok(bytes.into())
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        Ok(self
+            .store
+            .get_u64(NEXT_CHANNEL_SEQUENCE_KEY)?
+            .unwrap_or_default())
+    }
+
+    fn connection_params(&self) -> Result<ConnectionParams, ContextError> {
+        match self.store.get(CONNECTION_PARAMS_KEY) {
+            Some(bytes) => {
+                Ok(
+                    ConnectionParams::decode_vec(&bytes).map_err(|e| ConnectionError::Other {
+                        description: e.to_string(),
+                    })?,
+                )
+            }
+            None => Ok(ConnectionParams::default()),
+        }
+    }
+
+    /// Signers must be valid addresses of the chain the contract runs on.
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.api
+            .addr_validate(signer.as_ref())
+            .map_err(|e| ClientError::InvalidSigner {
+                reason: e.to_string(),
+            })?;
+        Ok(())
+    }
+}
+
+impl<'a, C, S> ExecutionContext for CwContext<'a, C, S>
+where
+    C: CwClientState<'a, S>,
+    S: ConsensusState + Into<Any> + TryFrom<Any, Error = ClientError>,
+{
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        let counter = self.client_counter()?;
+        self.store.set_u64(NEXT_CLIENT_SEQUENCE_KEY, counter + 1)
+    }
+
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.store.set(CLIENT_PARAMS_KEY, params.encode_vec())
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.store.set(connection_path, connection_end.encode_vec())
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        let mut client_paths = match self.store.get(client_connection_path) {
+            Some(bytes) => {
+                RawClientPaths::decode(bytes.as_slice()).map_err(|e| ConnectionError::Other {
+                    description: e.to_string(),
+                })?
+            }
+            None => RawClientPaths::default(),
+        };
+        client_paths.paths.push(conn_id.to_string());
+
+        self.store
+            .set(client_connection_path, client_paths.encode_to_vec())
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        let counter = self.connection_counter()?;
+        self.store
+            .set_u64(NEXT_CONNECTION_SEQUENCE_KEY, counter + 1)
+    }
+
+    fn store_connection_params(&mut self, params: ConnectionParams) -> Result<(), ContextError> {
+        self.store.set(CONNECTION_PARAMS_KEY, params.encode_vec())
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.store.set(commitment_path, commitment.into_vec())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.store.remove(commitment_path)
+    }
+
+    /// Receipts are stored as a single `1` byte, as ibc-go does.
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        _receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.store.set(receipt_path, vec![1])
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.store.set(ack_path, ack_commitment.into_vec())
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.store.remove(ack_path)
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.store.set(channel_end_path, channel_end.encode_vec())
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.store.set_u64(seq_send_path, seq.into())
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.store.set_u64(seq_recv_path, seq.into())
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.store.set_u64(seq_ack_path, seq.into())
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        let counter = self.channel_counter()?;
+        self.store.set_u64(NEXT_CHANNEL_SEQUENCE_KEY, counter + 1)
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.events.push(event);
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.api.debug(&message);
+        Ok(())
+    }
+}
+
+impl<'a, C, S> ClientValidationContext for CwContext<'a, C, S> {
+    fn client_update_time(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Timestamp, ContextError> {
+        let nanos = self
+            .store
+            .get_u64(processed_time_key(client_id, height))?
+            .ok_or_else(|| ClientError::ProcessedTimeNotFound {
+                client_id: client_id.clone(),
+                height: *height,
+            })?;
+
+        Ok(
+            Timestamp::from_nanoseconds(nanos).map_err(|e| ClientError::Other {
+                description: e.to_string(),
+            })?,
+        )
+    }
+
+    fn client_update_height(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Height, ContextError> {
+        let bytes = self
+            .store
+            .get(processed_height_key(client_id, height))
+            .ok_or_else(|| ClientError::ProcessedHeightNotFound {
+                client_id: client_id.clone(),
+                height: *height,
+            })?;
+
+        Ok(Height::decode_vec(&bytes).map_err(|e| ClientError::Other {
+            description: e.to_string(),
+        })?)
+    }
+}
+
+impl<'a, C, S> ClientExecutionContext for CwContext<'a, C, S>
+where
+    C: CwClientState<'a, S>,
+    S: ConsensusState + Into<Any> + TryFrom<Any, Error = ClientError>,
+{
+    type V = Self;
+    type AnyClientState = C;
+    type AnyConsensusState = S;
+
+    fn store_client_state(
+        &mut self,
+        client_state_path: ClientStatePath,
+        client_state: Self::AnyClientState,
+    ) -> Result<(), ContextError> {
+        self.store.set(
+            client_state_path,
+            Into::<Any>::into(client_state).encode_to_vec(),
+        )
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::AnyConsensusState,
+    ) -> Result<(), ContextError> {
+        let height = Height::new(consensus_state_path.epoch, consensus_state_path.height)?;
+        self.store
+            .insert_consensus_height(&consensus_state_path.client_id, height)?;
+        self.store.set(
+            consensus_state_path,
+            Into::<Any>::into(consensus_state).encode_to_vec(),
+        )
+    }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        let height = Height::new(consensus_state_path.epoch, consensus_state_path.height)?;
+        self.store
+            .remove_consensus_height(&consensus_state_path.client_id, &height)?;
+        self.store.remove(consensus_state_path)
+    }
+
+    fn store_update_time(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+    ) -> Result<(), ContextError> {
+        self.store.set_u64(
+            processed_time_key(&client_id, &height),
+            host_timestamp.nanoseconds(),
+        )
+    }
+
+    fn store_update_height(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_height: Height,
+    ) -> Result<(), ContextError> {
+        self.store.set(
+            processed_height_key(&client_id, &height),
+            host_height.encode_vec(),
+        )
+    }
+
+    fn delete_update_time(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.store.remove(processed_time_key(&client_id, &height))
+    }
+
+    fn delete_update_height(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.store.remove(processed_height_key(&client_id, &height))
+    }
+}
+
+impl<'a, C, S> TmCommonContext for CwContext<'a, C, S>
+where
+    S: TryFrom<Any, Error = ClientError> + TryInto<TmConsensusState>,
+    <S as TryInto<TmConsensusState>>::Error: ToString,
+{
+    type ConversionError = <S as TryInto<TmConsensusState>>::Error;
+    type AnyConsensusState = S;
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        CwContext::host_timestamp(self)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        CwContext::host_height(self)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        CwContext::consensus_state(self, client_cons_state_path)
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        self.store.consensus_heights(client_id)
+    }
+}
+
+impl<'a, C, S> TmValidationContext for CwContext<'a, C, S>
+where
+    S: TryFrom<Any, Error = ClientError> + TryInto<TmConsensusState>,
+    <S as TryInto<TmConsensusState>>::Error: ToString,
+{
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::AnyConsensusState>, ContextError> {
+        self.store
+            .consensus_heights(client_id)?
+            .into_iter()
+            .find(|h| h > height)
+            .map(|h| {
+                CwContext::consensus_state(self, &ClientConsensusStatePath::new(client_id, &h))
+            })
+            .transpose()
+    }
+
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::AnyConsensusState>, ContextError> {
+        self.store
+            .consensus_heights(client_id)?
+            .into_iter()
+            .rev()
+            .find(|h| h < height)
+            .map(|h| {
+                CwContext::consensus_state(self, &ClientConsensusStatePath::new(client_id, &h))
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{Storage, Timestamp as CwTimestamp};
+    use ibc_testkit::utils::clients::tendermint::{
+        dummy_tendermint_header, dummy_tm_client_state_from_header,
+    };
+
+    use super::*;
+    use crate::clients::ics07_tendermint::client_type as tm_client_type;
+    use crate::core::ics02_client::handler::create_client;
+    use crate::core::ics02_client::msgs::create_client::MsgCreateClient;
+    use crate::core::ics03_connection::connection::{Counterparty, State as ConnectionState};
+    use crate::core::ics03_connection::handler::conn_open_init;
+    use crate::core::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+    use crate::core::ics24_host::path::ClientConnectionPath;
+
+    type TmCwContext<'a> = CwContext<'a, TmClientState, TmConsensusState>;
+
+    #[test]
+    fn test_create_client_and_conn_open_init() {
+        let tm_header = dummy_tendermint_header();
+        let client_state = dummy_tm_client_state_from_header(tm_header.clone());
+        let consensus_state = TmConsensusState::from(tm_header.clone());
+
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        // The client must not be expired, so the contract runs right after
+        // the header it is created from.
+        let header_nanos = u64::try_from(tm_header.time.unix_timestamp_nanos()).unwrap();
+        env.block.time = CwTimestamp::from_nanos(header_nanos).plus_seconds(10);
+
+        let mut ctx = TmCwContext::new(deps.as_mut(), env);
+        let signer = Signer::from("creator".to_string());
+
+        let msg = MsgCreateClient::new(
+            client_state.clone().into(),
+            consensus_state.clone().into(),
+            signer.clone(),
+        );
+        create_client::validate(&ctx, msg.clone()).unwrap();
+        create_client::execute(&mut ctx, msg).unwrap();
+
+        let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+        assert_eq!(ctx.client_counter().unwrap(), 1);
+        assert_eq!(ctx.client_state(&client_id).unwrap(), client_state);
+        assert_eq!(
+            ValidationContext::consensus_state(
+                &ctx,
+                &ClientConsensusStatePath::new(&client_id, &client_state.latest_height),
+            )
+            .unwrap(),
+            consensus_state
+        );
+
+        let counterparty = Counterparty::new(
+            ClientId::new(tm_client_type(), 7).unwrap(),
+            None,
+            ctx.commitment_prefix(),
+        );
+        let msg = MsgConnectionOpenInit {
+            client_id_on_a: client_id.clone(),
+            counterparty,
+            version: None,
+            delay_period: Duration::ZERO,
+            signer,
+        };
+        conn_open_init::validate(&ctx, msg.clone()).unwrap();
+        conn_open_init::execute(&mut ctx, msg).unwrap();
+
+        let conn_id = ConnectionId::new(0);
+        let conn_end = ctx.connection_end(&conn_id).unwrap();
+        assert_eq!(conn_end.state, ConnectionState::Init);
+        assert_eq!(conn_end.client_id(), &client_id);
+        assert_eq!(ctx.connection_counter().unwrap(), 1);
+        assert!(ctx
+            .store()
+            .get(ClientConnectionPath::new(&client_id))
+            .is_some());
+        assert!(matches!(
+            ctx.events().last(),
+            Some(IbcEvent::OpenInitConnection(_))
+        ));
+
+        // The state is stored under the prefixed ICS-24 paths.
+        drop(ctx);
+        assert!(deps
+            .storage
+            .get(format!("ibc/{}", ClientStatePath::new(&client_id)).as_bytes())
+            .is_some());
+        assert!(deps
+            .storage
+            .get(format!("ibc/{}", ConnectionPath::new(&conn_id)).as_bytes())
+            .is_some());
+    }
+
+    #[test]
+    fn test_rejects_invalid_signer() {
+        let mut deps = mock_dependencies();
+        let ctx = TmCwContext::new(deps.as_mut(), mock_env());
+
+        assert!(matches!(
+            ctx.validate_message_signer(&Signer::from(String::new())),
+            Err(ContextError::ClientError(ClientError::InvalidSigner { .. }))
+        ));
+    }
+}
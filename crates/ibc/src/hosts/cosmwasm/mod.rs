@@ -0,0 +1,13 @@
+//! Implementation of the IBC contexts on top of the storage of a CosmWasm
+//! contract, so that contracts can run the handlers of this crate, e.g. to
+//! host light clients or to implement IBC themselves.
+//!
+//! The IBC state is stored under the ICS-24 paths, prefixed by
+//! [`DEFAULT_KEY_PREFIX`] unless configured otherwise, with the encodings of
+//! ibc-go.
+
+mod context;
+mod store;
+
+pub use context::*;
+pub use store::*;
@@ -0,0 +1,284 @@
+//! Key layout and encodings of the IBC state kept in the storage of a CosmWasm
+//! contract.
+//!
+//! The provable state lives under the ICS-24 paths, with the encodings of
+//! ibc-go, so that counterparties can verify proofs of it. The bookkeeping
+//! state of the handlers lives under keys that no ICS-24 path can collide with.
+
+use core::fmt::Display;
+
+use cosmwasm_std::Storage;
+
+use crate::core::ics02_client::error::ClientError;
+use crate::core::ics24_host::identifier::ClientId;
+use crate::core::ContextError;
+use crate::prelude::*;
+use crate::Height;
+
+/// The prefix of all the keys of the IBC state in the storage of a contract,
+/// unless configured otherwise.
+pub const DEFAULT_KEY_PREFIX: &[u8] = b"ibc/";
+
+/// The key of the number of clients created so far.
+pub const NEXT_CLIENT_SEQUENCE_KEY: &str = "nextClientSequence";
+/// The key of the number of connections created so far.
+pub const NEXT_CONNECTION_SEQUENCE_KEY: &str = "nextConnectionSequence";
+/// The key of the number of channels created so far.
+pub const NEXT_CHANNEL_SEQUENCE_KEY: &str = "nextChannelSequence";
+/// The key of the parameters of the client module.
+pub const CLIENT_PARAMS_KEY: &str = "params/clients";
+/// The key of the parameters of the connection module.
+pub const CONNECTION_PARAMS_KEY: &str = "params/connections";
+
+/// The key of the host timestamp at which the client `client_id` was updated
+/// to `height`.
+pub fn processed_time_key(client_id: &ClientId, height: &Height) -> String {
+    format!("clients/{client_id}/processedTime/{height}")
+}
+
+/// The key of the host height at which the client `client_id` was updated to
+/// `height`.
+pub fn processed_height_key(client_id: &ClientId, height: &Height) -> String {
+    format!("clients/{client_id}/processedHeight/{height}")
+}
+
+/// The key of the sorted heights of the consensus states stored for the client
+/// `client_id`, which stand in for the range queries contracts may not have.
+pub fn consensus_heights_key(client_id: &ClientId) -> String {
+    format!("clients/{client_id}/consensusHeights")
+}
+
+/// The key of the consensus state of the host at `height`, as recorded by the
+/// contract itself.
+pub fn host_consensus_state_key(height: &Height) -> String {
+    format!("hostConsensusStates/{height}")
+}
+
+/// Storage of a contract, either borrowed for queries or mutably for
+/// transactions.
+enum StorageRef<'a> {
+    ReadOnly(&'a dyn Storage),
+    ReadWrite(&'a mut dyn Storage),
+}
+
+/// Typed access to the IBC state in the storage of a contract, under a fixed
+/// key prefix.
+pub struct CwStore<'a> {
+    storage: StorageRef<'a>,
+    prefix: Vec<u8>,
+}
+
+impl<'a> CwStore<'a> {
+    /// Read-only access to `storage`, as in the `query` entrypoint of a
+    /// contract. Writes fail.
+    pub fn new_ref(storage: &'a dyn Storage) -> Self {
+        Self {
+            storage: StorageRef::ReadOnly(storage),
+            prefix: DEFAULT_KEY_PREFIX.to_vec(),
+        }
+    }
+
+    /// Read-write access to `storage`, as in the `execute` and `sudo`
+    /// entrypoints of a contract.
+    pub fn new_mut(storage: &'a mut dyn Storage) -> Self {
+        Self {
+            storage: StorageRef::ReadWrite(storage),
+            prefix: DEFAULT_KEY_PREFIX.to_vec(),
+        }
+    }
+
+    /// Stores the IBC state under `prefix` instead of [`DEFAULT_KEY_PREFIX`].
+    pub fn with_prefix(self, prefix: Vec<u8>) -> Self {
+        Self { prefix, ..self }
+    }
+
+    /// Returns the key of `path`, one of the ICS-24 paths or of the keys of
+    /// this module, in the storage of the contract.
+    pub fn key(&self, path: impl Display) -> Vec<u8> {
+        [self.prefix.as_slice(), path.to_string().as_bytes()].concat()
+    }
+
+    pub fn get(&self, path: impl Display) -> Option<Vec<u8>> {
+        let key = self.key(path);
+        match &self.storage {
+            StorageRef::ReadOnly(storage) => storage.get(&key),
+            StorageRef::ReadWrite(storage) => storage.get(&key),
+        }
+    }
+
+    pub fn set(&mut self, path: impl Display, value: Vec<u8>) -> Result<(), ContextError> {
+        let key = self.key(path);
+        self.storage_mut()?.set(&key, &value);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, path: impl Display) -> Result<(), ContextError> {
+        let key = self.key(path);
+        self.storage_mut()?.remove(&key);
+        Ok(())
+    }
+
+    /// Reads a big-endian `u64`, as ibc-go stores sequences and counters.
+    pub fn get_u64(&self, path: impl Display) -> Result<Option<u64>, ContextError> {
+        self.get(path)
+            .map(|bytes| {
+                <[u8; 8]>::try_from(bytes.as_slice())
+                    .map(u64::from_be_bytes)
+                    .map_err(|_| {
+                        ContextError::from(ClientError::Other {
+                            description: "stored value is not a big-endian u64".to_string(),
+                        })
+                    })
+            })
+            .transpose()
+    }
+
+    pub fn set_u64(&mut self, path: impl Display, value: u64) -> Result<(), ContextError> {
+        self.set(path, value.to_be_bytes().to_vec())
+    }
+
+    /// Returns the heights of the consensus states stored for `client_id`, in
+    /// ascending order.
+    pub fn consensus_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        let bytes = self
+            .get(consensus_heights_key(client_id))
+            .unwrap_or_default();
+        if bytes.len() % 16 != 0 {
+            return Err(ClientError::Other {
+                description: format!("consensus heights of client {client_id} are corrupted"),
+            }
+            .into());
+        }
+
+        bytes
+            .chunks_exact(16)
+            .map(|chunk| {
+                let (revision_number, revision_height) = chunk.split_at(8);
+                let revision_number =
+                    u64::from_be_bytes(revision_number.try_into().expect("8 bytes"));
+                let revision_height =
+                    u64::from_be_bytes(revision_height.try_into().expect("8 bytes"));
+                Height::new(revision_number, revision_height).map_err(ContextError::from)
+            })
+            .collect()
+    }
+
+    /// Adds `height` to the heights of the consensus states of `client_id`.
+    pub fn insert_consensus_height(
+        &mut self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        let mut heights = self.consensus_heights(client_id)?;
+        if let Err(pos) = heights.binary_search(&height) {
+            heights.insert(pos, height);
+            self.set_consensus_heights(client_id, &heights)?;
+        }
+        Ok(())
+    }
+
+    /// Removes `height` from the heights of the consensus states of
+    /// `client_id`.
+    pub fn remove_consensus_height(
+        &mut self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(), ContextError> {
+        let mut heights = self.consensus_heights(client_id)?;
+        if let Ok(pos) = heights.binary_search(height) {
+            heights.remove(pos);
+            self.set_consensus_heights(client_id, &heights)?;
+        }
+        Ok(())
+    }
+
+    fn set_consensus_heights(
+        &mut self,
+        client_id: &ClientId,
+        heights: &[Height],
+    ) -> Result<(), ContextError> {
+        let bytes = heights
+            .iter()
+            .flat_map(|height| {
+                [height.revision_number(), height.revision_height()]
+                    .into_iter()
+                    .flat_map(u64::to_be_bytes)
+            })
+            .collect();
+        self.set(consensus_heights_key(client_id), bytes)
+    }
+
+    fn storage_mut(&mut self) -> Result<&mut (dyn Storage + 'a), ContextError> {
+        match &mut self.storage {
+            StorageRef::ReadOnly(_) => Err(ClientError::Other {
+                description: "the contract storage is read-only".to_string(),
+            }
+            .into()),
+            StorageRef::ReadWrite(storage) => Ok(&mut **storage),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+    use crate::core::ics24_host::identifier::{ChannelId, PortId};
+    use crate::core::ics24_host::path::SeqSendPath;
+
+    #[test]
+    fn test_keys_are_prefixed_ics24_paths() {
+        let mut storage = MockStorage::new();
+        let mut store = CwStore::new_mut(&mut storage);
+        let path = SeqSendPath::new(&PortId::transfer(), &ChannelId::new(0));
+
+        store.set_u64(&path, 1).unwrap();
+        assert_eq!(store.get_u64(&path).unwrap(), Some(1));
+        assert_eq!(
+            storage.get(b"ibc/nextSequenceSend/ports/transfer/channels/channel-0"),
+            Some(vec![0, 0, 0, 0, 0, 0, 0, 1])
+        );
+
+        let store = CwStore::new_ref(&storage);
+        assert_eq!(store.get_u64(NEXT_CLIENT_SEQUENCE_KEY).unwrap(), None);
+
+        let store = store.with_prefix(b"other/".to_vec());
+        assert_eq!(store.get(&path), None);
+    }
+
+    #[test]
+    fn test_read_only_store_rejects_writes() {
+        let storage = MockStorage::new();
+        let mut store = CwStore::new_ref(&storage);
+
+        assert!(store.set_u64(NEXT_CLIENT_SEQUENCE_KEY, 1).is_err());
+        assert!(store.remove(NEXT_CLIENT_SEQUENCE_KEY).is_err());
+    }
+
+    #[test]
+    fn test_consensus_heights_stay_sorted() {
+        let mut storage = MockStorage::new();
+        let mut store = CwStore::new_mut(&mut storage);
+        let client_id = ClientId::default();
+        let height = |h| Height::new(1, h).unwrap();
+
+        for h in [5, 2, 9, 2] {
+            store
+                .insert_consensus_height(&client_id, height(h))
+                .unwrap();
+        }
+        assert_eq!(
+            store.consensus_heights(&client_id).unwrap(),
+            vec![height(2), height(5), height(9)]
+        );
+
+        store
+            .remove_consensus_height(&client_id, &height(5))
+            .unwrap();
+        assert_eq!(
+            store.consensus_heights(&client_id).unwrap(),
+            vec![height(2), height(9)]
+        );
+    }
+}
@@ -1,2 +1,4 @@
 //! Provides convenience implementations for various hosts
+#[cfg(feature = "cosmwasm")]
+pub mod cosmwasm;
 pub mod tendermint;
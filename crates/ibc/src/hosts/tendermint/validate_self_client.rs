@@ -149,3 +149,226 @@ pub trait ValidateSelfClientContext {
     /// Returns the host upgrade path. May be empty.
     fn upgrade_path(&self) -> &[String];
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::lightclients::tendermint::v1::{ClientState as RawTmClientState, Fraction};
+    use prost::Message;
+
+    use super::*;
+    use crate::clients::ics07_tendermint::client_state::{
+        AllowUpdate, TENDERMINT_CLIENT_STATE_TYPE_URL,
+    };
+    use crate::clients::ics07_tendermint::trust_threshold::TrustThreshold;
+    use crate::prelude::*;
+
+    struct Host {
+        chain_id: ChainId,
+        height: Height,
+        proof_specs: ProofSpecs,
+        upgrade_path: Vec<String>,
+    }
+
+    impl ValidateSelfClientContext for Host {
+        fn chain_id(&self) -> &ChainId {
+            &self.chain_id
+        }
+
+        fn host_current_height(&self) -> Height {
+            self.height
+        }
+
+        fn proof_specs(&self) -> &ProofSpecs {
+            &self.proof_specs
+        }
+
+        fn unbonding_period(&self) -> Duration {
+            Duration::from_secs(128000)
+        }
+
+        fn upgrade_path(&self) -> &[String] {
+            &self.upgrade_path
+        }
+    }
+
+    struct ClientStateParams {
+        chain_id: ChainId,
+        trust_level: TrustThreshold,
+        latest_height: Height,
+        unbonding_period: Duration,
+        proof_specs: ProofSpecs,
+        upgrade_path: Vec<String>,
+        frozen: bool,
+    }
+
+    impl ClientStateParams {
+        fn into_client_state(self) -> Any {
+            let client_state = TmClientState::new(
+                self.chain_id,
+                self.trust_level,
+                Duration::from_secs(64000),
+                self.unbonding_period,
+                Duration::from_secs(3),
+                self.latest_height,
+                self.proof_specs,
+                self.upgrade_path,
+                AllowUpdate {
+                    after_expiry: false,
+                    after_misbehaviour: false,
+                },
+            )
+            .expect("Never fails");
+
+            if self.frozen {
+                client_state
+                    .with_frozen_height(Height::new(1, 1).expect("Never fails"))
+                    .into()
+            } else {
+                client_state.into()
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_self_tendermint_client() {
+        let host = Host {
+            chain_id: ChainId::new("ibc-1").unwrap(),
+            height: Height::new(1, 20).unwrap(),
+            proof_specs: ProofSpecs::default(),
+            upgrade_path: vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+        };
+
+        let default_params = || ClientStateParams {
+            chain_id: host.chain_id.clone(),
+            trust_level: TrustThreshold::ONE_THIRD,
+            latest_height: Height::new(1, 10).unwrap(),
+            unbonding_period: host.unbonding_period(),
+            proof_specs: ProofSpecs::default(),
+            upgrade_path: host.upgrade_path.clone(),
+            frozen: false,
+        };
+
+        struct Test {
+            name: &'static str,
+            client_state: Any,
+            want_pass: bool,
+        }
+
+        let tests = vec![
+            Test {
+                name: "Valid client state",
+                client_state: default_params().into_client_state(),
+                want_pass: true,
+            },
+            Test {
+                name: "Valid client state without upgrade path",
+                client_state: ClientStateParams {
+                    upgrade_path: Vec::new(),
+                    ..default_params()
+                }
+                .into_client_state(),
+                want_pass: true,
+            },
+            Test {
+                name: "Valid client state with a non-default trust level",
+                client_state: ClientStateParams {
+                    trust_level: TrustThreshold::new(2, 3).unwrap(),
+                    ..default_params()
+                }
+                .into_client_state(),
+                want_pass: true,
+            },
+            Test {
+                name: "Trust level below one third",
+                client_state: {
+                    let mut raw_client_state = RawTmClientState::decode(
+                        default_params().into_client_state().value.as_slice(),
+                    )
+                    .unwrap();
+                    raw_client_state.trust_level = Some(Fraction {
+                        numerator: 1,
+                        denominator: 4,
+                    });
+                    Any {
+                        type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
+                        value: raw_client_state.encode_to_vec(),
+                    }
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Not a tendermint client state",
+                client_state: Any {
+                    type_url: "/ibc.mock.ClientState".to_string(),
+                    value: Vec::new(),
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Frozen client",
+                client_state: ClientStateParams {
+                    frozen: true,
+                    ..default_params()
+                }
+                .into_client_state(),
+                want_pass: false,
+            },
+            Test {
+                name: "Other chain",
+                client_state: ClientStateParams {
+                    chain_id: ChainId::new("other-1").unwrap(),
+                    ..default_params()
+                }
+                .into_client_state(),
+                want_pass: false,
+            },
+            Test {
+                name: "Latest height not reached by the host yet",
+                client_state: ClientStateParams {
+                    latest_height: host.height,
+                    ..default_params()
+                }
+                .into_client_state(),
+                want_pass: false,
+            },
+            Test {
+                name: "Other proof specs",
+                client_state: ClientStateParams {
+                    proof_specs: vec![ics23::iavl_spec()].into(),
+                    ..default_params()
+                }
+                .into_client_state(),
+                want_pass: false,
+            },
+            Test {
+                name: "Other unbonding period",
+                client_state: ClientStateParams {
+                    unbonding_period: Duration::from_secs(100000),
+                    ..default_params()
+                }
+                .into_client_state(),
+                want_pass: false,
+            },
+            Test {
+                name: "Other upgrade path",
+                client_state: ClientStateParams {
+                    upgrade_path: vec!["upgrade".to_string()],
+                    ..default_params()
+                }
+                .into_client_state(),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = host.validate_self_tendermint_client(test.client_state);
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "test `{}` failed: {res:?}",
+                test.name
+            );
+        }
+    }
+}
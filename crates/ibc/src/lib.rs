@@ -45,7 +45,12 @@ extern crate alloc;
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
-pub use signer::Signer;
+pub use ibc_primitives::prelude;
+pub use ibc_primitives::signer::{
+    AccountResolver, AddressCodec, Bech32SignerValidator, CachingAccountResolver,
+    CodecAccountResolver, HexSignerValidator, OpaqueSignerValidator, Signer, SignerError,
+    SignerValidator, TryFromSignerResolver,
+};
 
 /// Represents a block height
 pub use crate::core::ics02_client::height::Height;
@@ -55,12 +60,9 @@ pub mod clients;
 pub mod core;
 pub mod hosts;
 
-pub mod prelude;
-mod signer;
-pub mod utils;
-
-#[cfg(feature = "serde")]
-mod serializers;
+pub use ibc_core_host_types::{
+    channel_id, client_id, client_type, connection_id, downcast, port_id,
+};
 
 /// Re-exports pertinent ibc proto types from the `ibc-proto-rs` crate for added convenience
 pub mod proto {
@@ -70,3 +72,35 @@ pub mod proto {
     pub use ibc_proto::ibc::{core, mock};
     pub use ibc_proto::{ics23, Protobuf};
 }
+
+#[cfg(all(test, feature = "arbitrary", feature = "serde"))]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::applications::transfer::BaseDenom;
+    use crate::core::ics24_host::identifier::{ChainId, ClientId, PortId};
+    use crate::core::MsgEnvelope;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_arbitrary_values_are_valid() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(1 << 16).collect();
+        let mut u = Unstructured::new(&bytes);
+
+        for _ in 0..16 {
+            let chain_id = ChainId::arbitrary(&mut u).unwrap();
+            assert_eq!(chain_id.as_str().parse::<ChainId>().unwrap(), chain_id);
+
+            let client_id = ClientId::arbitrary(&mut u).unwrap();
+            assert_eq!(client_id.as_str().parse::<ClientId>().unwrap(), client_id);
+
+            let port_id = PortId::arbitrary(&mut u).unwrap();
+            assert_eq!(port_id.as_str().parse::<PortId>().unwrap(), port_id);
+
+            let base_denom = BaseDenom::arbitrary(&mut u).unwrap();
+            assert!(base_denom.validate_bank_compatible().is_ok());
+
+            assert!(MsgEnvelope::arbitrary(&mut u).is_ok());
+        }
+    }
+}
@@ -1,3 +0,0 @@
-//! Various utilities used internally
-pub(crate) mod macros;
-pub(crate) mod pretty;
@@ -0,0 +1,41 @@
+#![no_main]
+
+use ibc::applications::transfer::packet::PacketData;
+use ibc::applications::transfer::PrefixedDenom;
+use ibc::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, IdentifiedConnectionEnd,
+};
+use ibc::core::ics03_connection::version::Version as ConnectionVersion;
+use ibc::core::ics04_channel::channel::{
+    ChannelEnd, Counterparty as ChannelCounterparty, IdentifiedChannelEnd,
+};
+use ibc::core::ics04_channel::packet::{Packet, PacketState};
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::ics23_commitment::merkle::MerkleProof;
+use ibc::proto::core::channel::v1 as channel;
+use ibc::proto::core::client::v1 as client;
+use ibc::proto::core::commitment::v1 as commitment;
+use ibc::proto::core::connection::v1 as connection;
+use ibc::proto::transfer::{v1 as transfer_v1, v2 as transfer_v2};
+use ibc::Height;
+use ibc_fuzz::{decode, decode_raw, dispatch};
+use libfuzzer_sys::fuzz_target;
+
+const DECODERS: &[fn(&[u8])] = &[
+    decode::<Height, client::Height>,
+    decode_raw::<TimeoutHeight, client::Height>,
+    decode::<ConnectionEnd, connection::ConnectionEnd>,
+    decode::<IdentifiedConnectionEnd, connection::IdentifiedConnection>,
+    decode::<ConnectionCounterparty, connection::Counterparty>,
+    decode::<ConnectionVersion, connection::Version>,
+    decode::<ChannelEnd, channel::Channel>,
+    decode::<IdentifiedChannelEnd, channel::IdentifiedChannel>,
+    decode::<ChannelCounterparty, channel::Counterparty>,
+    decode_raw::<Packet, channel::Packet>,
+    decode_raw::<PacketState, channel::PacketState>,
+    decode_raw::<MerkleProof, commitment::MerkleProof>,
+    decode_raw::<PacketData, transfer_v2::FungibleTokenPacketData>,
+    decode_raw::<PrefixedDenom, transfer_v1::DenomTrace>,
+];
+
+fuzz_target!(|data: &[u8]| dispatch(data, DECODERS));
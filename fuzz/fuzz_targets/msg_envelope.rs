@@ -0,0 +1,37 @@
+#![no_main]
+
+use ibc::core::MsgEnvelope;
+use ibc::proto::Any;
+use libfuzzer_sys::fuzz_target;
+
+/// The type URLs of all the messages routed by `MsgEnvelope`.
+const TYPE_URLS: &[&str] = &[
+    "/ibc.core.client.v1.MsgCreateClient",
+    "/ibc.core.client.v1.MsgUpdateClient",
+    "/ibc.core.client.v1.MsgUpgradeClient",
+    "/ibc.core.client.v1.MsgSubmitMisbehaviour",
+    "/ibc.core.connection.v1.MsgConnectionOpenInit",
+    "/ibc.core.connection.v1.MsgConnectionOpenTry",
+    "/ibc.core.connection.v1.MsgConnectionOpenAck",
+    "/ibc.core.connection.v1.MsgConnectionOpenConfirm",
+    "/ibc.core.channel.v1.MsgChannelOpenInit",
+    "/ibc.core.channel.v1.MsgChannelOpenTry",
+    "/ibc.core.channel.v1.MsgChannelOpenAck",
+    "/ibc.core.channel.v1.MsgChannelOpenConfirm",
+    "/ibc.core.channel.v1.MsgChannelCloseInit",
+    "/ibc.core.channel.v1.MsgChannelCloseConfirm",
+    "/ibc.core.channel.v1.MsgRecvPacket",
+    "/ibc.core.channel.v1.MsgAcknowledgement",
+    "/ibc.core.channel.v1.MsgTimeout",
+    "/ibc.core.channel.v1.MsgTimeoutOnClose",
+];
+
+fuzz_target!(|data: &[u8]| {
+    if let Some((selector, value)) = data.split_first() {
+        let any_msg = Any {
+            type_url: TYPE_URLS[usize::from(*selector) % TYPE_URLS.len()].to_string(),
+            value: value.to_vec(),
+        };
+        let _ = MsgEnvelope::try_from(any_msg);
+    }
+});
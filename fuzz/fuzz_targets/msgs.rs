@@ -0,0 +1,46 @@
+#![no_main]
+
+use ibc::applications::transfer::msgs::transfer::MsgTransfer;
+use ibc::core::ics02_client::msgs::create_client::MsgCreateClient;
+use ibc::core::ics02_client::msgs::misbehaviour::MsgSubmitMisbehaviour;
+use ibc::core::ics02_client::msgs::update_client::MsgUpdateClient;
+use ibc::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
+use ibc::core::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
+use ibc::core::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use ibc::core::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use ibc::core::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
+use ibc::core::ics04_channel::msgs::{
+    MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+    MsgTimeoutOnClose,
+};
+use ibc::proto::core::channel::v1 as channel;
+use ibc::proto::core::client::v1 as client;
+use ibc::proto::core::connection::v1 as connection;
+use ibc::proto::transfer::v1 as transfer;
+use ibc_fuzz::{decode, dispatch};
+use libfuzzer_sys::fuzz_target;
+
+const DECODERS: &[fn(&[u8])] = &[
+    decode::<MsgCreateClient, client::MsgCreateClient>,
+    decode::<MsgUpdateClient, client::MsgUpdateClient>,
+    decode::<MsgUpgradeClient, client::MsgUpgradeClient>,
+    decode::<MsgSubmitMisbehaviour, client::MsgSubmitMisbehaviour>,
+    decode::<MsgConnectionOpenInit, connection::MsgConnectionOpenInit>,
+    decode::<MsgConnectionOpenTry, connection::MsgConnectionOpenTry>,
+    decode::<MsgConnectionOpenAck, connection::MsgConnectionOpenAck>,
+    decode::<MsgConnectionOpenConfirm, connection::MsgConnectionOpenConfirm>,
+    decode::<MsgChannelOpenInit, channel::MsgChannelOpenInit>,
+    decode::<MsgChannelOpenTry, channel::MsgChannelOpenTry>,
+    decode::<MsgChannelOpenAck, channel::MsgChannelOpenAck>,
+    decode::<MsgChannelOpenConfirm, channel::MsgChannelOpenConfirm>,
+    decode::<MsgChannelCloseInit, channel::MsgChannelCloseInit>,
+    decode::<MsgChannelCloseConfirm, channel::MsgChannelCloseConfirm>,
+    decode::<MsgRecvPacket, channel::MsgRecvPacket>,
+    decode::<MsgAcknowledgement, channel::MsgAcknowledgement>,
+    decode::<MsgTimeout, channel::MsgTimeout>,
+    decode::<MsgTimeoutOnClose, channel::MsgTimeoutOnClose>,
+    decode::<MsgTransfer, transfer::MsgTransfer>,
+];
+
+fuzz_target!(|data: &[u8]| dispatch(data, DECODERS));
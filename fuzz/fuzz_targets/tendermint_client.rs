@@ -0,0 +1,23 @@
+#![no_main]
+
+use ibc::clients::ics07_tendermint::client_state::ClientState;
+use ibc::clients::ics07_tendermint::consensus_state::ConsensusState;
+use ibc::clients::ics07_tendermint::header::Header;
+use ibc::clients::ics07_tendermint::misbehaviour::Misbehaviour;
+use ibc::proto::tendermint::v1 as tendermint;
+use ibc::proto::Any;
+use ibc_fuzz::{decode, dispatch};
+use libfuzzer_sys::fuzz_target;
+
+const DECODERS: &[fn(&[u8])] = &[
+    decode::<ClientState, tendermint::ClientState>,
+    decode::<ClientState, Any>,
+    decode::<ConsensusState, tendermint::ConsensusState>,
+    decode::<ConsensusState, Any>,
+    decode::<Header, tendermint::Header>,
+    decode::<Header, Any>,
+    decode::<Misbehaviour, tendermint::Misbehaviour>,
+    decode::<Misbehaviour, Any>,
+];
+
+fuzz_target!(|data: &[u8]| dispatch(data, DECODERS));
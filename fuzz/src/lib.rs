@@ -0,0 +1,36 @@
+//! Helpers shared by the fuzz targets.
+
+use core::fmt::Display;
+
+use ibc::proto::Protobuf;
+
+/// Decodes `bytes` as the protobuf encoding of `R`, converted into `T`.
+///
+/// Only panics are of interest, so decoding errors are discarded.
+pub fn decode<T, R>(bytes: &[u8])
+where
+    T: Protobuf<R> + TryFrom<R>,
+    R: prost::Message + From<T> + Default,
+    <T as TryFrom<R>>::Error: Display,
+{
+    let _ = T::decode_vec(bytes);
+}
+
+/// Feeds `data` to one of `decoders`, selected by its first byte.
+pub fn dispatch(data: &[u8], decoders: &[fn(&[u8])]) {
+    if let Some((selector, bytes)) = data.split_first() {
+        decoders[usize::from(*selector) % decoders.len()](bytes);
+    }
+}
+
+/// Decodes `bytes` as the protobuf encoding of `R`, converted into `T`, for
+/// domain types that do not implement `Protobuf`.
+pub fn decode_raw<T, R>(bytes: &[u8])
+where
+    T: TryFrom<R>,
+    R: prost::Message + Default,
+{
+    if let Ok(raw) = R::decode(bytes) {
+        let _ = T::try_from(raw);
+    }
+}